@@ -21,10 +21,26 @@ SET time_zone = '+00:00';
 -- 表结构定义
 -- ==============================================
 
+-- 租户表
+DROP TABLE IF EXISTS `tenants`;
+CREATE TABLE `tenants` (
+  `id` varchar(64) NOT NULL COMMENT '租户ID',
+  `code` varchar(32) NOT NULL COMMENT '租户编码',
+  `name` varchar(100) NOT NULL COMMENT '租户名称',
+  `host` varchar(255) DEFAULT NULL COMMENT '绑定的访问域名',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-启用，1-禁用，2-删除',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_tenants_code` (`code`),
+  UNIQUE KEY `uk_tenants_host` (`host`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='租户表';
+
 -- 用户表
 DROP TABLE IF EXISTS `users`;
 CREATE TABLE `users` (
   `id` varchar(255) NOT NULL COMMENT '用户ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
   `username` varchar(50) NOT NULL COMMENT '用户名',
   `email` varchar(100) NOT NULL COMMENT '邮箱',
   `password` varchar(255) NOT NULL COMMENT '密码哈希',
@@ -32,33 +48,74 @@ CREATE TABLE `users` (
   `phone` varchar(20) DEFAULT NULL COMMENT '手机号',
   `avatar` varchar(255) DEFAULT NULL COMMENT '头像URL',
   `status` int NOT NULL DEFAULT '1' COMMENT '状态：0-禁用，1-启用',
+  `department_id` varchar(255) DEFAULT NULL COMMENT '所属部门ID',
+  `created_by` varchar(255) DEFAULT NULL COMMENT '创建者ID',
   `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
   `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  `last_login_at` timestamp NULL DEFAULT NULL COMMENT '最近登录时间',
+  `last_login_ip` varchar(64) DEFAULT NULL COMMENT '最近登录IP',
   PRIMARY KEY (`id`),
-  UNIQUE KEY `idx_username` (`username`),
-  UNIQUE KEY `idx_email` (`email`),
-  KEY `idx_status` (`status`)
+  UNIQUE KEY `uk_users_tenant_username` (`tenant_id`,`username`),
+  UNIQUE KEY `uk_users_tenant_email` (`tenant_id`,`email`),
+  KEY `idx_status` (`status`),
+  KEY `idx_department_id` (`department_id`),
+  KEY `idx_users_tenant_id` (`tenant_id`)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='用户表';
 
 -- 角色表
 DROP TABLE IF EXISTS `roles`;
 CREATE TABLE `roles` (
   `id` varchar(255) NOT NULL COMMENT '角色ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
   `code` varchar(50) NOT NULL COMMENT '角色唯一标识',
   `name` varchar(50) NOT NULL COMMENT '角色名称',
   `description` varchar(255) DEFAULT NULL COMMENT '角色描述',
   `status` int NOT NULL DEFAULT '1' COMMENT '状态：0-禁用，1-启用',
+  `data_scope` int NOT NULL DEFAULT '0' COMMENT '数据范围：0-全部，1-自定义，2-本部门，3-本部门及子部门，4-仅本人',
+  `built_in` tinyint(1) NOT NULL DEFAULT '0' COMMENT '是否内置：内置角色不可修改、禁用或删除',
   `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
   `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
   PRIMARY KEY (`id`),
-  UNIQUE KEY `idx_code` (`code`),
-  UNIQUE KEY `idx_name` (`name`)
+  UNIQUE KEY `uk_roles_tenant_code` (`tenant_id`,`code`),
+  UNIQUE KEY `uk_roles_tenant_name` (`tenant_id`,`name`),
+  KEY `idx_roles_tenant_id` (`tenant_id`)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='角色表';
 
+-- 角色数据范围部门表
+DROP TABLE IF EXISTS `role_departments`;
+CREATE TABLE `role_departments` (
+  `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `role_id` varchar(255) NOT NULL COMMENT '角色ID',
+  `department_id` varchar(255) NOT NULL COMMENT '部门ID',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx_role_department` (`role_id`,`department_id`),
+  CONSTRAINT `fk_role_departments_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='角色数据范围部门表';
+
+-- 部门表
+DROP TABLE IF EXISTS `departments`;
+CREATE TABLE `departments` (
+  `id` varchar(255) NOT NULL COMMENT '部门ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `name` varchar(50) NOT NULL COMMENT '部门名称',
+  `parent_id` varchar(255) DEFAULT NULL COMMENT '上级部门ID',
+  `sort` int NOT NULL DEFAULT '0' COMMENT '排序',
+  `leader_id` varchar(255) DEFAULT NULL COMMENT '负责人用户ID',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-启用，1-禁用，2-删除',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_departments_parent_id` (`parent_id`),
+  KEY `idx_departments_tenant_id` (`tenant_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='部门表';
+
 -- 权限表
 DROP TABLE IF EXISTS `permissions`;
 CREATE TABLE `permissions` (
   `id` varchar(255) NOT NULL COMMENT '权限ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
   `name` varchar(100) NOT NULL COMMENT '权限名称',
   `code` varchar(100) DEFAULT NULL COMMENT '权限代码',
   `type` int NOT NULL DEFAULT '0' COMMENT '权限类型：0-菜单，1-按钮，2-API',
@@ -68,20 +125,24 @@ CREATE TABLE `permissions` (
   `icon` varchar(100) DEFAULT NULL COMMENT '图标',
   `sort` int NOT NULL DEFAULT '0' COMMENT '排序',
   `status` int NOT NULL DEFAULT '1' COMMENT '状态：0-禁用，1-启用',
+  `built_in` tinyint(1) NOT NULL DEFAULT '0' COMMENT '是否内置：内置权限不可修改或删除',
   `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
   `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
   PRIMARY KEY (`id`),
   KEY `idx_code` (`code`),
   KEY `idx_parent_id` (`parent_id`),
-  KEY `idx_type` (`type`)
+  KEY `idx_type` (`type`),
+  KEY `idx_permissions_tenant_id` (`tenant_id`)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='权限表';
 
 -- 用户角色关联表
 DROP TABLE IF EXISTS `user_roles`;
 CREATE TABLE `user_roles` (
   `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
   `user_id` varchar(255) NOT NULL COMMENT '用户ID',
   `role_id` varchar(255) NOT NULL COMMENT '角色ID',
+  `access_request_id` varchar(255) DEFAULT NULL COMMENT '经权限申请授予时对应的申请ID',
   `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
   `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
   PRIMARY KEY (`id`),
@@ -89,15 +150,354 @@ CREATE TABLE `user_roles` (
   KEY `idx_user_id` (`user_id`),
   KEY `idx_role_id` (`role_id`),
   CONSTRAINT `fk_user_roles_user` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE CASCADE,
-  CONSTRAINT `fk_user_roles_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE
+  CONSTRAINT `fk_user_roles_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE,
+  KEY `idx_user_roles_tenant_id` (`tenant_id`)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='用户角色关联表';
 
+-- 用户组表（groups 为 MySQL 保留字）
+DROP TABLE IF EXISTS `user_groups`;
+CREATE TABLE `user_groups` (
+  `id` varchar(255) NOT NULL COMMENT '用户组ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `name` varchar(50) NOT NULL COMMENT '用户组名称',
+  `description` varchar(200) DEFAULT NULL COMMENT '用户组描述',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-启用，1-禁用，2-删除',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_user_groups_tenant_id` (`tenant_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='用户组表';
+
+-- 用户组成员表
+DROP TABLE IF EXISTS `user_group_members`;
+CREATE TABLE `user_group_members` (
+  `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `group_id` varchar(255) NOT NULL COMMENT '用户组ID',
+  `user_id` varchar(255) NOT NULL COMMENT '用户ID',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx_group_member` (`group_id`,`user_id`),
+  KEY `idx_user_group_members_user_id` (`user_id`),
+  CONSTRAINT `fk_user_group_members_group` FOREIGN KEY (`group_id`) REFERENCES `user_groups` (`id`) ON DELETE CASCADE,
+  CONSTRAINT `fk_user_group_members_user` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='用户组成员表';
+
+-- 用户组角色关联表
+DROP TABLE IF EXISTS `user_group_roles`;
+CREATE TABLE `user_group_roles` (
+  `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `group_id` varchar(255) NOT NULL COMMENT '用户组ID',
+  `role_id` varchar(255) NOT NULL COMMENT '角色ID',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx_group_role` (`group_id`,`role_id`),
+  CONSTRAINT `fk_user_group_roles_group` FOREIGN KEY (`group_id`) REFERENCES `user_groups` (`id`) ON DELETE CASCADE,
+  CONSTRAINT `fk_user_group_roles_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='用户组角色关联表';
+
+-- 职责分离规则表
+DROP TABLE IF EXISTS `sod_rules`;
+CREATE TABLE `sod_rules` (
+  `id` varchar(255) NOT NULL COMMENT '规则ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `name` varchar(50) NOT NULL COMMENT '规则名称',
+  `rule_type` int NOT NULL DEFAULT '0' COMMENT '规则类型：0-角色互斥，1-每角色人数上限，2-每人角色上限',
+  `max_count` int NOT NULL DEFAULT '1' COMMENT '上限',
+  `enabled` tinyint(1) NOT NULL DEFAULT '1' COMMENT '是否启用',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_sod_rules_tenant_id` (`tenant_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='职责分离规则表';
+
+-- 职责分离规则角色关联表
+DROP TABLE IF EXISTS `sod_rule_roles`;
+CREATE TABLE `sod_rule_roles` (
+  `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `rule_id` varchar(255) NOT NULL COMMENT '规则ID',
+  `role_id` varchar(255) NOT NULL COMMENT '角色ID',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx_sod_rule_role` (`rule_id`,`role_id`),
+  CONSTRAINT `fk_sod_rule_roles_rule` FOREIGN KEY (`rule_id`) REFERENCES `sod_rules` (`id`) ON DELETE CASCADE,
+  CONSTRAINT `fk_sod_rule_roles_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='职责分离规则角色关联表';
+
+-- 访问策略表（ABAC）
+DROP TABLE IF EXISTS `access_policies`;
+CREATE TABLE `access_policies` (
+  `id` varchar(255) NOT NULL COMMENT '策略ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `name` varchar(50) NOT NULL COMMENT '策略名称',
+  `description` varchar(255) DEFAULT NULL COMMENT '策略描述',
+  `action` varchar(255) NOT NULL COMMENT '作用的操作（权限码，支持通配）',
+  `effect` int NOT NULL DEFAULT '0' COMMENT '效果：0-允许，1-拒绝',
+  `condition` text NOT NULL COMMENT '条件表达式',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-启用，1-禁用，2-删除',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_access_policies_tenant_id` (`tenant_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='访问策略表';
+
+-- 权限申请表
+DROP TABLE IF EXISTS `access_requests`;
+CREATE TABLE `access_requests` (
+  `id` varchar(255) NOT NULL COMMENT '申请ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `requester_id` varchar(255) NOT NULL COMMENT '申请人ID',
+  `role_id` varchar(255) NOT NULL COMMENT '申请的角色ID',
+  `justification` varchar(500) NOT NULL COMMENT '申请理由',
+  `duration_days` int NOT NULL COMMENT '授权时长（天）',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-待审批，1-已批准，2-已驳回，3-已撤回，4-已过期',
+  `decided_by` varchar(255) DEFAULT NULL COMMENT '审批人ID',
+  `decision_comment` varchar(255) DEFAULT NULL COMMENT '审批意见',
+  `decided_at` timestamp NULL DEFAULT NULL COMMENT '审批时间',
+  `expires_at` timestamp NOT NULL COMMENT '待审批截止时间',
+  `grant_expires_at` timestamp NULL DEFAULT NULL COMMENT '授权到期时间',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_access_requests_tenant_status` (`tenant_id`,`status`),
+  KEY `idx_access_requests_requester_id` (`requester_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='权限申请表';
+
+-- 角色审批人表
+DROP TABLE IF EXISTS `role_approvers`;
+CREATE TABLE `role_approvers` (
+  `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `role_id` varchar(255) NOT NULL COMMENT '角色ID',
+  `user_id` varchar(255) NOT NULL COMMENT '审批人ID',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx_role_approver` (`role_id`,`user_id`),
+  CONSTRAINT `fk_role_approvers_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE,
+  CONSTRAINT `fk_role_approvers_user` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='角色审批人表';
+
+-- 访问复核活动表
+DROP TABLE IF EXISTS `access_reviews`;
+CREATE TABLE `access_reviews` (
+  `id` varchar(255) NOT NULL COMMENT '活动ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `name` varchar(100) NOT NULL COMMENT '活动名称',
+  `deadline` timestamp NOT NULL COMMENT '截止时间',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-进行中，1-已完成',
+  `created_by` varchar(255) NOT NULL COMMENT '发起人ID',
+  `completed_at` timestamp NULL DEFAULT NULL COMMENT '完成时间',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_access_reviews_tenant_status` (`tenant_id`,`status`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='访问复核活动表';
+
+-- 访问复核角色范围表
+DROP TABLE IF EXISTS `access_review_roles`;
+CREATE TABLE `access_review_roles` (
+  `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `review_id` varchar(255) NOT NULL COMMENT '活动ID',
+  `role_id` varchar(255) NOT NULL COMMENT '角色ID',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `idx_access_review_role` (`review_id`,`role_id`),
+  CONSTRAINT `fk_access_review_roles_review` FOREIGN KEY (`review_id`) REFERENCES `access_reviews` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='访问复核角色范围表';
+
+-- 访问复核项表（用户与角色不设外键，角色删除后仍保留复核记录）
+DROP TABLE IF EXISTS `access_review_items`;
+CREATE TABLE `access_review_items` (
+  `id` varchar(255) NOT NULL COMMENT '复核项ID（UUID）',
+  `review_id` varchar(255) NOT NULL COMMENT '活动ID',
+  `user_id` varchar(255) NOT NULL COMMENT '被复核用户ID',
+  `role_id` varchar(255) NOT NULL COMMENT '被复核角色ID',
+  `reviewer_id` varchar(255) NOT NULL COMMENT '复核人ID',
+  `decision` int NOT NULL DEFAULT '0' COMMENT '结论：0-待复核，1-确认保留，2-收回，3-到期自动收回',
+  `comment` varchar(255) DEFAULT NULL COMMENT '复核意见',
+  `decided_at` timestamp NULL DEFAULT NULL COMMENT '复核时间',
+  PRIMARY KEY (`id`),
+  KEY `idx_access_review_items_review_id` (`review_id`),
+  KEY `idx_access_review_items_reviewer_id` (`reviewer_id`),
+  CONSTRAINT `fk_access_review_items_review` FOREIGN KEY (`review_id`) REFERENCES `access_reviews` (`id`) ON DELETE CASCADE
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='访问复核项表';
+
+-- 审计日志表
+DROP TABLE IF EXISTS `audit_logs`;
+CREATE TABLE `audit_logs` (
+  `id` varchar(255) NOT NULL COMMENT '日志ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `actor_id` varchar(255) DEFAULT NULL COMMENT '操作人ID',
+  `actor_name` varchar(255) DEFAULT NULL COMMENT '操作人用户名',
+  `action` varchar(100) NOT NULL COMMENT '操作名称，如 user.create',
+  `target_type` varchar(50) NOT NULL COMMENT '操作对象类型',
+  `target_id` varchar(255) DEFAULT NULL COMMENT '操作对象ID',
+  `params` text COMMENT '命令参数（JSON，已脱敏）',
+  `changes` text COMMENT '字段变更（JSON）',
+  `client_ip` varchar(64) DEFAULT NULL COMMENT '客户端IP',
+  `user_agent` varchar(512) DEFAULT NULL COMMENT 'User-Agent',
+  `request_id` varchar(64) DEFAULT NULL COMMENT '请求ID',
+  `outcome` int NOT NULL DEFAULT '0' COMMENT '结果：0-成功，1-失败',
+  `error_message` text COMMENT '失败原因',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '操作时间',
+  `seq` bigint NOT NULL DEFAULT '0' COMMENT '租户内哈希链序号',
+  `prev_hash` varchar(64) NOT NULL DEFAULT '' COMMENT '前一条记录的哈希',
+  `hash` varchar(64) NOT NULL DEFAULT '' COMMENT '本条记录的哈希（SHA-256）',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_audit_logs_tenant_seq` (`tenant_id`,`seq`),
+  KEY `idx_audit_logs_tenant_created_at` (`tenant_id`,`created_at`),
+  KEY `idx_audit_logs_actor_id` (`actor_id`),
+  KEY `idx_audit_logs_target` (`target_type`,`target_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='审计日志表';
+
+-- 登录日志表
+DROP TABLE IF EXISTS `login_logs`;
+CREATE TABLE `login_logs` (
+  `id` varchar(255) NOT NULL COMMENT '日志ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `user_id` varchar(255) DEFAULT NULL COMMENT '用户ID，用户不存在时为空',
+  `username` varchar(255) NOT NULL COMMENT '登录时提交的用户名',
+  `method` int NOT NULL DEFAULT '0' COMMENT '登录方式：0-用户名密码',
+  `success` tinyint(1) NOT NULL COMMENT '是否成功',
+  `failure_reason` varchar(50) DEFAULT NULL COMMENT '失败原因',
+  `client_ip` varchar(64) DEFAULT NULL COMMENT '客户端IP',
+  `user_agent` varchar(512) DEFAULT NULL COMMENT 'User-Agent',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '登录时间',
+  PRIMARY KEY (`id`),
+  KEY `idx_login_logs_tenant_created_at` (`tenant_id`,`created_at`),
+  KEY `idx_login_logs_user_id` (`user_id`,`created_at`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='登录日志表';
+
+-- 审计检查点表
+DROP TABLE IF EXISTS `audit_checkpoints`;
+CREATE TABLE `audit_checkpoints` (
+  `id` varchar(255) NOT NULL COMMENT '检查点ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `seq` bigint NOT NULL COMMENT '覆盖到的审计日志序号',
+  `hash` varchar(64) NOT NULL COMMENT '该审计日志的哈希',
+  `signature` varchar(128) NOT NULL COMMENT '签名',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '创建时间',
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `uk_audit_checkpoints_tenant_seq` (`tenant_id`,`seq`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='审计检查点表';
+
+-- 事件发件箱表
+DROP TABLE IF EXISTS `outbox_messages`;
+CREATE TABLE `outbox_messages` (
+  `seq` bigint NOT NULL AUTO_INCREMENT COMMENT '写入序号，决定投递顺序',
+  `id` varchar(255) NOT NULL COMMENT '消息ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `aggregate_type` varchar(50) NOT NULL COMMENT '聚合类型',
+  `aggregate_id` varchar(255) NOT NULL COMMENT '聚合ID',
+  `event_type` varchar(100) NOT NULL COMMENT '事件类型',
+  `payload` text NOT NULL COMMENT '事件内容（JSON）',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-待投递，1-已投递，2-死信',
+  `attempts` int NOT NULL DEFAULT '0' COMMENT '已尝试投递次数',
+  `next_attempt_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '下次可投递时间',
+  `last_error` text DEFAULT NULL COMMENT '最近一次投递失败原因',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '写入时间',
+  `published_at` timestamp NULL DEFAULT NULL COMMENT '投递成功时间',
+  PRIMARY KEY (`seq`),
+  UNIQUE KEY `uk_outbox_messages_id` (`id`),
+  KEY `idx_outbox_messages_status_tenant_seq` (`status`,`tenant_id`,`seq`),
+  KEY `idx_outbox_messages_tenant_created_at` (`tenant_id`,`created_at`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='事件发件箱表';
+
+-- Webhook 端点表
+DROP TABLE IF EXISTS `webhooks`;
+CREATE TABLE `webhooks` (
+  `id` varchar(255) NOT NULL COMMENT '端点ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `name` varchar(100) NOT NULL COMMENT '端点名称',
+  `url` varchar(2048) NOT NULL COMMENT '投递地址',
+  `secret` varchar(255) NOT NULL COMMENT 'HMAC-SHA256 签名密钥',
+  `event_types` text NOT NULL COMMENT '订阅的事件类型（JSON 数组，* 表示全部）',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-启用，1-停用',
+  `consecutive_failures` int NOT NULL DEFAULT '0' COMMENT '连续投递失败次数',
+  `disabled_reason` varchar(255) DEFAULT NULL COMMENT '自动停用原因',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`id`),
+  KEY `idx_webhooks_tenant_id` (`tenant_id`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Webhook 端点表';
+
+-- Webhook 投递记录表
+DROP TABLE IF EXISTS `webhook_deliveries`;
+CREATE TABLE `webhook_deliveries` (
+  `seq` bigint NOT NULL AUTO_INCREMENT COMMENT '写入序号，决定投递顺序',
+  `id` varchar(255) NOT NULL COMMENT '投递记录ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `webhook_id` varchar(255) NOT NULL COMMENT '端点ID',
+  `event_type` varchar(100) NOT NULL COMMENT '事件类型',
+  `payload` text NOT NULL COMMENT '请求体（JSON）',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-待投递，1-成功，2-失败',
+  `attempts` int NOT NULL DEFAULT '0' COMMENT '已尝试投递次数',
+  `next_attempt_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '下次可投递时间',
+  `response_status` int DEFAULT NULL COMMENT '最近一次响应的 HTTP 状态码',
+  `last_error` text DEFAULT NULL COMMENT '最近一次投递失败原因',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '写入时间',
+  `delivered_at` timestamp NULL DEFAULT NULL COMMENT '投递成功时间',
+  `redelivery_of` varchar(255) DEFAULT NULL COMMENT '重新投递时的原投递记录ID',
+  PRIMARY KEY (`seq`),
+  UNIQUE KEY `uk_webhook_deliveries_id` (`id`),
+  KEY `idx_webhook_deliveries_status_next_attempt_at` (`status`,`next_attempt_at`),
+  KEY `idx_webhook_deliveries_tenant_webhook_seq` (`tenant_id`,`webhook_id`,`seq`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='Webhook 投递记录表';
+
+-- 定时任务表（平台级）
+DROP TABLE IF EXISTS `scheduled_jobs`;
+CREATE TABLE `scheduled_jobs` (
+  `name` varchar(64) NOT NULL COMMENT '任务名称',
+  `description` varchar(255) NOT NULL COMMENT '任务说明',
+  `cron` varchar(100) NOT NULL COMMENT 'cron 表达式（秒 分 时 日 月 周，UTC）',
+  `paused` tinyint(1) NOT NULL DEFAULT '0' COMMENT '是否暂停',
+  `next_run_at` timestamp NULL DEFAULT NULL COMMENT '下次执行时间',
+  `last_run_at` timestamp NULL DEFAULT NULL COMMENT '最近一次执行的开始时间',
+  `last_status` int DEFAULT NULL COMMENT '最近一次执行状态：0-执行中，1-成功，2-失败',
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`name`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='定时任务表';
+
+-- 定时任务执行记录表（平台级）
+DROP TABLE IF EXISTS `job_runs`;
+CREATE TABLE `job_runs` (
+  `id` varchar(255) NOT NULL COMMENT '执行记录ID（UUID）',
+  `job_name` varchar(64) NOT NULL COMMENT '任务名称',
+  `trigger` int NOT NULL DEFAULT '0' COMMENT '触发方式：0-按计划，1-手动',
+  `status` int NOT NULL DEFAULT '0' COMMENT '状态：0-执行中，1-成功，2-失败',
+  `triggered_by` varchar(255) DEFAULT NULL COMMENT '手动触发者ID',
+  `instance` varchar(255) NOT NULL COMMENT '执行所在的实例',
+  `started_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP COMMENT '开始时间',
+  `finished_at` timestamp NULL DEFAULT NULL COMMENT '结束时间',
+  `duration_ms` bigint DEFAULT NULL COMMENT '耗时（毫秒）',
+  `affected` bigint DEFAULT NULL COMMENT '处理的记录数',
+  `error` text DEFAULT NULL COMMENT '失败原因',
+  PRIMARY KEY (`id`),
+  KEY `idx_job_runs_job_name_started_at` (`job_name`,`started_at`),
+  KEY `idx_job_runs_started_at` (`started_at`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='定时任务执行记录表';
+
+-- 功能开关表（平台级）
+DROP TABLE IF EXISTS `feature_flags`;
+CREATE TABLE `feature_flags` (
+  `key` varchar(64) NOT NULL COMMENT '开关键',
+  `description` varchar(255) NOT NULL DEFAULT '' COMMENT '说明',
+  `enabled` tinyint(1) NOT NULL DEFAULT '0' COMMENT '是否开启',
+  `rollout_percentage` int NOT NULL DEFAULT '0' COMMENT '灰度比例（0-100），按用户ID哈希分桶',
+  `allowed_roles` text NOT NULL COMMENT '白名单角色编码（JSON数组）',
+  `allowed_tenants` text NOT NULL COMMENT '白名单租户ID（JSON数组）',
+  `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  PRIMARY KEY (`key`)
+) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='功能开关表（平台级）';
+
 -- 角色权限关联表
 DROP TABLE IF EXISTS `role_permissions`;
 CREATE TABLE `role_permissions` (
   `id` varchar(255) NOT NULL COMMENT '关联ID（UUID）',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
   `role_id` varchar(255) NOT NULL COMMENT '角色ID',
   `permission_id` varchar(255) NOT NULL COMMENT '权限ID',
+  `effect` int NOT NULL DEFAULT '0' COMMENT '授权效果：0-允许，1-拒绝',
   `created_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,
   `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
   PRIMARY KEY (`id`),
@@ -105,7 +505,8 @@ CREATE TABLE `role_permissions` (
   KEY `idx_role_id` (`role_id`),
   KEY `idx_permission_id` (`permission_id`),
   CONSTRAINT `fk_role_permissions_role` FOREIGN KEY (`role_id`) REFERENCES `roles` (`id`) ON DELETE CASCADE,
-  CONSTRAINT `fk_role_permissions_permission` FOREIGN KEY (`permission_id`) REFERENCES `permissions` (`id`) ON DELETE CASCADE
+  CONSTRAINT `fk_role_permissions_permission` FOREIGN KEY (`permission_id`) REFERENCES `permissions` (`id`) ON DELETE CASCADE,
+  KEY `idx_role_permissions_tenant_id` (`tenant_id`)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COLLATE=utf8mb4_unicode_ci COMMENT='角色权限关联表';
 
 -- Token 黑名单表
@@ -126,16 +527,23 @@ CREATE TABLE `token_blacklist` (
 DROP TABLE IF EXISTS `system_settings`;
 CREATE TABLE `system_settings` (
   `id` varchar(64) NOT NULL PRIMARY KEY,
-  `key` varchar(64) NOT NULL UNIQUE COMMENT '参数名',
+  `tenant_id` varchar(64) NOT NULL DEFAULT 'default' COMMENT '所属租户ID',
+  `key` varchar(64) NOT NULL COMMENT '参数名',
   `value` varchar(255) NOT NULL COMMENT '参数值',
   `description` varchar(255) DEFAULT NULL COMMENT '描述',
-  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+  `updated_at` timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+  UNIQUE KEY `uk_system_settings_tenant_key` (`tenant_id`,`key`),
+  KEY `idx_system_settings_tenant_id` (`tenant_id`)
 ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4;
 
 -- ==============================================
 -- 初始数据插入
 -- ==============================================
 
+-- 插入平台默认租户（未指定租户的数据均归属该租户）
+INSERT INTO `tenants` (`id`, `code`, `name`, `host`, `status`, `created_at`, `updated_at`) VALUES
+('default', 'default', '平台', NULL, 0, NOW(), NOW());
+
 -- 插入角色数据
 INSERT INTO `roles` (`id`, `code`, `name`, `description`, `status`, `created_at`, `updated_at`) VALUES
 ('550e8400-e29b-41d4-a716-446655440002', 'super_admin', '超级管理员', '系统超级管理员，拥有所有权限', 1, NOW(), NOW()),
@@ -169,7 +577,14 @@ INSERT INTO `permissions` (`id`, `name`, `code`, `type`, `parent_id`, `path`, `c
 ('550e8400-e29b-41d4-a716-446655440016', '用户管理', 'user_management', 0, '550e8400-e29b-41d4-a716-446655440004', '/system/user-management', NULL, 'user', 1, 1, NOW(), NOW()),
 ('550e8400-e29b-41d4-a716-446655440005', '用户列表', 'user:list', 0, '550e8400-e29b-41d4-a716-446655440016', '/system/user-management/users', 'system/users', 'user', 1, 1, NOW(), NOW()),
 ('550e8400-e29b-41d4-a716-446655440006', '角色管理', 'role:list', 0, '550e8400-e29b-41d4-a716-446655440016', '/system/user-management/roles', 'system/roles', 'team', 2, 1, NOW(), NOW()),
-('550e8400-e29b-41d4-a716-446655440007', '权限管理', 'permission:list', 0, '550e8400-e29b-41d4-a716-446655440016', '/system/user-management/permissions', 'system/permissions', 'lock', 3, 1, NOW(), NOW());
+('550e8400-e29b-41d4-a716-446655440007', '权限管理', 'permission:list', 0, '550e8400-e29b-41d4-a716-446655440016', '/system/user-management/permissions', 'system/permissions', 'lock', 3, 1, NOW(), NOW()),
+
+-- 维护模式与只读模式豁免（API）
+('550e8400-e29b-41d4-a716-446655440017', '维护模式豁免', 'maintenance:bypass', 2, NULL, NULL, NULL, NULL, 0, 1, NOW(), NOW());
+
+-- 标记内置角色与权限
+UPDATE `roles` SET `built_in` = 1 WHERE `code` = 'super_admin';
+UPDATE `permissions` SET `built_in` = 1 WHERE `code` LIKE 'super_admin%' OR `code` IN ('system', 'user_management', 'user:list', 'role:list', 'permission:list', 'maintenance:bypass');
 
 -- 分配超级管理员权限（所有权限）
 INSERT INTO `role_permissions` (`id`, `role_id`, `permission_id`, `created_at`, `updated_at`) VALUES
@@ -187,6 +602,7 @@ INSERT INTO `role_permissions` (`id`, `role_id`, `permission_id`, `created_at`,
 ('550e8400-e29b-41d4-a716-44665544000a', '550e8400-e29b-41d4-a716-446655440002', '550e8400-e29b-41d4-a716-446655440005', NOW(), NOW()),
 ('550e8400-e29b-41d4-a716-44665544000b', '550e8400-e29b-41d4-a716-446655440002', '550e8400-e29b-41d4-a716-446655440006', NOW(), NOW()),
 ('550e8400-e29b-41d4-a716-44665544000c', '550e8400-e29b-41d4-a716-446655440002', '550e8400-e29b-41d4-a716-446655440007', NOW(), NOW()),
+('550e8400-e29b-41d4-a716-446655440026', '550e8400-e29b-41d4-a716-446655440002', '550e8400-e29b-41d4-a716-446655440017', NOW(), NOW()),  -- 维护模式豁免
 
 -- 分配普通管理员权限（系统管理权限，但不包括超级管理员控制台）
 ('550e8400-e29b-41d4-a716-446655440040', '550e8400-e29b-41d4-a716-446655440003', '550e8400-e29b-41d4-a716-446655440004', NOW(), NOW()),  -- 系统管理
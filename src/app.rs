@@ -14,10 +14,16 @@ use tradewinds_infrastructure::dependency_injection::init_application_service;
 
 // API 层
 use tradewinds_api::api::controllers::{
-    AuthController, PermissionController, RoleController, SystemSettingController, UserController,
+    AccessRequestController, AccessReviewController, AuditLogController, AuthController, DepartmentController,
+    FeatureFlagController, GroupController, JobController, LoginLogController, OutboxController, PermissionController,
+    PolicyController, RoleController, SystemSettingController, TenantController, UserController, WebhookController,
+};
+use tradewinds_api::api::middlewares::{context, maintenance, security, tenant};
+use tradewinds_api::api::routes::{
+    access_request_routes, access_review_routes, audit_log_routes, auth_routes, department_routes, feature_flag_routes,
+    group_routes, job_routes, login_log_routes, outbox_routes, permission_routes, policy_routes, role_routes,
+    system_setting_routes, tenant_routes, user_routes, webhook_routes,
 };
-use tradewinds_api::api::middlewares::security;
-use tradewinds_api::api::routes::{auth_routes, permission_routes, role_routes, user_routes};
 use tradewinds_api::api::state::AppState;
 
 // 领域服务
@@ -25,7 +31,9 @@ use tradewinds_domain::services::auth::token_service::TokenService;
 
 // Application interfaces
 use tradewinds_application::interfaces::{
-    IAuthService, IPermissionService, IRoleService, ISystemSettingService, IUserService,
+    IAccessRequestService, IAccessReviewService, IAuditLogService, IAuthService, IDepartmentService,
+    IFeatureFlagService, IGroupService, IJobService, ILoginLogService, IOutboxService, IPermissionService,
+    IPolicyService, IRoleService, ISystemSettingService, ITenantService, IUserService, IWebhookService,
 };
 
 pub struct App {
@@ -36,39 +44,130 @@ pub struct App {
 impl App {
     pub async fn new(config: AppConfig) -> AppResult<Self> {
         // 初始化服务
-        let (auth_service, user_service, role_service, permission_service, token_service, system_setting_service): (
+        let (
+            auth_service,
+            user_service,
+            role_service,
+            permission_service,
+            token_service,
+            system_setting_service,
+            department_service,
+            group_service,
+            tenant_service,
+            policy_service,
+            access_request_service,
+            access_review_service,
+            audit_log_service,
+            login_log_service,
+            outbox_service,
+            webhook_service,
+            job_service,
+            feature_flag_service,
+        ): (
             Arc<dyn IAuthService>,
             Arc<dyn IUserService>,
             Arc<dyn IRoleService>,
             Arc<dyn IPermissionService>,
             Arc<dyn TokenService>,
             Arc<dyn ISystemSettingService>,
+            Arc<dyn IDepartmentService>,
+            Arc<dyn IGroupService>,
+            Arc<dyn ITenantService>,
+            Arc<dyn IPolicyService>,
+            Arc<dyn IAccessRequestService>,
+            Arc<dyn IAccessReviewService>,
+            Arc<dyn IAuditLogService>,
+            Arc<dyn ILoginLogService>,
+            Arc<dyn IOutboxService>,
+            Arc<dyn IWebhookService>,
+            Arc<dyn IJobService>,
+            Arc<dyn IFeatureFlagService>,
         ) = init_application_service(&config).await.map_err(|e| AppError::System(e.to_string()))?;
 
-        let system_setting_controller = SystemSettingController::assemble(system_setting_service.clone());
-        let auth_controller = AuthController::assemble(auth_service.clone());
-        let user_controller = UserController::assemble(user_service.clone(), system_setting_service.clone());
-        let role_controller = RoleController::assemble(role_service.clone());
-        let permission_controller = PermissionController::assemble(permission_service.clone());
-
         // 创建共享状态（含认证服务）
-        let state = AppState::new(
-            auth_controller,
-            user_controller,
-            role_controller,
-            permission_controller,
-            system_setting_controller,
+        let state = AppState {
+            auth_controller: Arc::new(AuthController::assemble(
+                auth_service.clone(),
+                audit_log_service.clone(),
+                feature_flag_service.clone(),
+            )),
+            user_controller: Arc::new(UserController::assemble(
+                user_service.clone(),
+                system_setting_service.clone(),
+                audit_log_service.clone(),
+            )),
+            role_controller: Arc::new(RoleController::assemble(role_service.clone(), audit_log_service.clone())),
+            permission_controller: Arc::new(PermissionController::assemble(
+                permission_service.clone(),
+                audit_log_service.clone(),
+            )),
+            system_setting_controller: Arc::new(SystemSettingController::assemble(
+                system_setting_service.clone(),
+                audit_log_service.clone(),
+            )),
+            department_controller: Arc::new(DepartmentController::assemble(
+                department_service.clone(),
+                audit_log_service.clone(),
+            )),
+            group_controller: Arc::new(GroupController::assemble(group_service.clone(), audit_log_service.clone())),
+            tenant_controller: Arc::new(TenantController::assemble(tenant_service.clone(), audit_log_service.clone())),
+            policy_controller: Arc::new(PolicyController::assemble(policy_service.clone(), audit_log_service.clone())),
+            access_request_controller: Arc::new(AccessRequestController::assemble(
+                access_request_service.clone(),
+                audit_log_service.clone(),
+            )),
+            access_review_controller: Arc::new(AccessReviewController::assemble(
+                access_review_service.clone(),
+                audit_log_service.clone(),
+            )),
+            audit_log_controller: Arc::new(AuditLogController::assemble(audit_log_service.clone())),
+            login_log_controller: Arc::new(LoginLogController::assemble(
+                login_log_service.clone(),
+                audit_log_service.clone(),
+            )),
+            outbox_controller: Arc::new(OutboxController::assemble(outbox_service.clone(), audit_log_service.clone())),
+            webhook_controller: Arc::new(WebhookController::assemble(
+                webhook_service.clone(),
+                audit_log_service.clone(),
+            )),
+            job_controller: Arc::new(JobController::assemble(job_service.clone(), audit_log_service.clone())),
+            feature_flag_controller: Arc::new(FeatureFlagController::assemble(
+                feature_flag_service.clone(),
+                audit_log_service.clone(),
+            )),
             token_service,
-        );
+        };
 
         // 构建 router，注入状态
         let protected_routes = Router::new()
             .merge(user_routes::user_routes())
             .merge(role_routes::role_routes())
             .merge(permission_routes::permission_routes())
+            .merge(department_routes::department_routes())
+            .merge(group_routes::group_routes())
+            .merge(tenant_routes::tenant_routes())
+            .merge(policy_routes::policy_routes())
+            .merge(access_request_routes::access_request_routes())
+            .merge(access_review_routes::access_review_routes())
+            .merge(audit_log_routes::audit_log_routes())
+            .merge(login_log_routes::login_log_routes())
+            .merge(outbox_routes::outbox_routes())
+            .merge(webhook_routes::webhook_routes())
+            .merge(job_routes::job_routes())
+            .merge(feature_flag_routes::feature_flag_routes())
+            .merge(system_setting_routes::system_setting_routes())
             .layer(middleware::from_fn_with_state(state.clone(), security::auth));
 
-        let router = Router::new().merge(auth_routes::auth_routes()).merge(protected_routes).with_state(state);
+        // 租户解析包裹全部路由，认证与业务处理均在解析出的租户范围内执行；
+        // 维护模式与只读模式在租户解析之后、认证之前拦截请求；
+        // 请求上下文（客户端地址、请求ID等）位于最外层，供访问策略、审计日志等读取
+        let router = Router::new()
+            .merge(auth_routes::auth_routes())
+            .merge(protected_routes)
+            .layer(middleware::from_fn_with_state(state.clone(), maintenance::enforce_system_mode))
+            .layer(middleware::from_fn_with_state(state.clone(), tenant::resolve_tenant))
+            .layer(middleware::from_fn(context::request_context))
+            .with_state(state);
 
         Ok(Self { config, router })
     }
@@ -0,0 +1,57 @@
+use dotenv::dotenv;
+use sea_orm_migration::sea_orm::Database;
+use tradewinds_application::QueryHandler;
+use tradewinds_application::queries::audit_log::{VerifyAuditChainHandler, VerifyAuditChainQuery};
+use tradewinds_error::{AppError, AppResult};
+use tradewinds_infrastructure::config::AppConfig;
+use tradewinds_infrastructure::di::audit_log_di::init_audit_chain_service;
+
+/// 校验审计日志哈希链
+///
+/// 用法：verify-audit [租户ID]，不指定租户时校验全部租户。
+/// 任一租户的链断裂时以非零状态退出，并输出第一处断点。
+#[tokio::main]
+async fn main() -> AppResult<()> {
+    // 加载环境变量
+    dotenv().ok();
+
+    // 加载配置
+    let config = AppConfig::from_env()?;
+
+    // 连接数据库
+    let connection = Database::connect(&config.database_url)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("数据库连接失败: {}", e)))?;
+
+    // 校验哈希链
+    let handler = VerifyAuditChainHandler::new(init_audit_chain_service(&connection, &config));
+    let reports = handler.handle(VerifyAuditChainQuery { tenant_id: std::env::args().nth(1) }).await?;
+
+    let mut intact = true;
+    for report in &reports {
+        match &report.result {
+            Ok(summary) => println!(
+                "租户 {}: 完整，共 {} 条记录，{} 个检查点，链尾序号 {}",
+                report.tenant_id, summary.records, summary.checkpoints, summary.head_seq
+            ),
+            Err(broken) => {
+                intact = false;
+                println!(
+                    "租户 {}: 在序号 {} 处断裂（记录 {}）：{}",
+                    report.tenant_id,
+                    broken.seq,
+                    broken.log_id.as_deref().unwrap_or("-"),
+                    broken.reason
+                );
+            }
+        }
+    }
+    if reports.is_empty() {
+        println!("没有审计日志");
+    }
+    if !intact {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::access_request::handlers::{
+    ApproveAccessRequestHandler, CancelAccessRequestHandler, ExpireAccessRequestsHandler, RejectAccessRequestHandler,
+    SetRoleApproversHandler, SubmitAccessRequestHandler,
+};
+use tradewinds_application::commands::access_request::*;
+use tradewinds_application::interfaces::{IAccessRequestService, IAuditLogService};
+use tradewinds_application::queries::access_request::handlers::{
+    GetAccessRequestByIdHandler, GetRoleApproversHandler, ListAccessRequestsHandler,
+};
+use tradewinds_application::queries::access_request::*;
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::{access_request::AccessRequest, user::User};
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::access_request_dto::*,
+    mappers::access_request_mapper,
+};
+
+/// 权限申请控制器
+pub struct AccessRequestController {
+    submit_request: Arc<dyn CommandHandler<SubmitAccessRequestCommand, AccessRequest>>,
+    approve_request: Arc<dyn CommandHandler<ApproveAccessRequestCommand, ()>>,
+    reject_request: Arc<dyn CommandHandler<RejectAccessRequestCommand, ()>>,
+    cancel_request: Arc<dyn CommandHandler<CancelAccessRequestCommand, ()>>,
+    expire_requests: Arc<dyn CommandHandler<ExpireAccessRequestsCommand, u64>>,
+    set_role_approvers: Arc<dyn CommandHandler<SetRoleApproversCommand, ()>>,
+    get_request_by_id: Arc<dyn QueryHandler<GetAccessRequestByIdQuery, AccessRequest>>,
+    list_requests: Arc<dyn QueryHandler<ListAccessRequestsQuery, PaginatedResult<AccessRequest>>>,
+    get_role_approvers: Arc<dyn QueryHandler<GetRoleApproversQuery, Vec<User>>>,
+}
+
+impl AccessRequestController {
+    pub fn assemble(
+        access_request_service: Arc<dyn IAccessRequestService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+    ) -> Self {
+        Self {
+            submit_request: audited(
+                SubmitAccessRequestHandler::new(access_request_service.clone()),
+                &audit_log_service,
+            ),
+            approve_request: audited(
+                ApproveAccessRequestHandler::new(access_request_service.clone()),
+                &audit_log_service,
+            ),
+            reject_request: audited(
+                RejectAccessRequestHandler::new(access_request_service.clone()),
+                &audit_log_service,
+            ),
+            cancel_request: audited(
+                CancelAccessRequestHandler::new(access_request_service.clone()),
+                &audit_log_service,
+            ),
+            expire_requests: audited(
+                ExpireAccessRequestsHandler::new(access_request_service.clone()),
+                &audit_log_service,
+            ),
+            set_role_approvers: audited(
+                SetRoleApproversHandler::new(access_request_service.clone()),
+                &audit_log_service,
+            ),
+            get_request_by_id: Arc::new(GetAccessRequestByIdHandler::new(access_request_service.clone())),
+            list_requests: Arc::new(ListAccessRequestsHandler::new(access_request_service.clone())),
+            get_role_approvers: Arc::new(GetRoleApproversHandler::new(access_request_service.clone())),
+        }
+    }
+
+    pub async fn submit_request(
+        &self,
+        actor_id: String,
+        req: SubmitAccessRequestRequest,
+    ) -> AppResult<SubmitAccessRequestResponse> {
+        let command = access_request_mapper::to_submit_access_request_command(actor_id, req)?;
+        let request = self.submit_request.handle(command).await?;
+        Ok(SubmitAccessRequestResponse { request: request.into() })
+    }
+
+    pub async fn approve_request(
+        &self,
+        actor_id: String,
+        req: DecideAccessRequestRequest,
+    ) -> AppResult<ApproveAccessRequestResponse> {
+        let command = access_request_mapper::to_approve_access_request_command(actor_id, req)?;
+        self.approve_request.handle(command).await?;
+        Ok(ApproveAccessRequestResponse)
+    }
+
+    pub async fn reject_request(
+        &self,
+        actor_id: String,
+        req: DecideAccessRequestRequest,
+    ) -> AppResult<RejectAccessRequestResponse> {
+        let command = access_request_mapper::to_reject_access_request_command(actor_id, req)?;
+        self.reject_request.handle(command).await?;
+        Ok(RejectAccessRequestResponse)
+    }
+
+    pub async fn cancel_request(
+        &self,
+        actor_id: String,
+        req: CancelAccessRequestRequest,
+    ) -> AppResult<CancelAccessRequestResponse> {
+        let command = access_request_mapper::to_cancel_access_request_command(actor_id, req)?;
+        self.cancel_request.handle(command).await?;
+        Ok(CancelAccessRequestResponse)
+    }
+
+    pub async fn expire_requests(&self, actor_id: String) -> AppResult<ExpireAccessRequestsResponse> {
+        let command = access_request_mapper::to_expire_access_requests_command(actor_id)?;
+        let expired = self.expire_requests.handle(command).await?;
+        Ok(ExpireAccessRequestsResponse { expired })
+    }
+
+    pub async fn get_request_by_id(
+        &self,
+        actor_id: String,
+        req: GetAccessRequestByIdRequest,
+    ) -> AppResult<GetAccessRequestByIdResponse> {
+        let query = access_request_mapper::to_get_access_request_by_id_query(actor_id, req)?;
+        let request = self.get_request_by_id.handle(query).await?;
+        Ok(GetAccessRequestByIdResponse { request: request.into() })
+    }
+
+    pub async fn list_requests(
+        &self,
+        actor_id: String,
+        req: ListAccessRequestsRequest,
+    ) -> AppResult<ListAccessRequestsResponse> {
+        let query = access_request_mapper::to_list_access_requests_query(actor_id, req)?;
+        let result = self.list_requests.handle(query).await?;
+        Ok(ListAccessRequestsResponse {
+            requests: result.items.into_iter().map(Into::into).collect(),
+            total: result.total,
+        })
+    }
+
+    pub async fn get_role_approvers(&self, req: GetRoleApproversRequest) -> AppResult<GetRoleApproversResponse> {
+        let query = access_request_mapper::to_get_role_approvers_query(req)?;
+        let approvers = self.get_role_approvers.handle(query).await?;
+        Ok(GetRoleApproversResponse { approvers: approvers.into_iter().map(Into::into).collect() })
+    }
+
+    pub async fn set_role_approvers(
+        &self,
+        actor_id: String,
+        req: SetRoleApproversRequest,
+    ) -> AppResult<SetRoleApproversResponse> {
+        let command = access_request_mapper::to_set_role_approvers_command(actor_id, req)?;
+        self.set_role_approvers.handle(command).await?;
+        Ok(SetRoleApproversResponse)
+    }
+}
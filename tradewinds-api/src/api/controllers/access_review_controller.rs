@@ -0,0 +1,125 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::access_review::handlers::{
+    CloseDueAccessReviewsHandler, DecideAccessReviewItemHandler, LaunchAccessReviewHandler,
+};
+use tradewinds_application::commands::access_review::*;
+use tradewinds_application::interfaces::{IAccessReviewService, IAuditLogService};
+use tradewinds_application::queries::access_review::handlers::{
+    GetAccessReviewByIdHandler, GetAccessReviewReportHandler, ListAccessReviewItemsHandler, ListAccessReviewsHandler,
+};
+use tradewinds_application::queries::access_review::*;
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_domain::entities::access_review::AccessReviewItem;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::access_review_dto::*,
+    mappers::access_review_mapper,
+};
+
+/// 访问复核控制器
+pub struct AccessReviewController {
+    launch_review: Arc<dyn CommandHandler<LaunchAccessReviewCommand, AccessReviewAggregate>>,
+    decide_item: Arc<dyn CommandHandler<DecideAccessReviewItemCommand, ()>>,
+    close_due_reviews: Arc<dyn CommandHandler<CloseDueAccessReviewsCommand, u64>>,
+    get_review_by_id: Arc<dyn QueryHandler<GetAccessReviewByIdQuery, AccessReviewAggregate>>,
+    list_reviews: Arc<dyn QueryHandler<ListAccessReviewsQuery, PaginatedResult<AccessReviewAggregate>>>,
+    list_items: Arc<dyn QueryHandler<ListAccessReviewItemsQuery, Vec<AccessReviewItem>>>,
+    get_report: Arc<dyn QueryHandler<GetAccessReviewReportQuery, AccessReviewReport>>,
+}
+
+impl AccessReviewController {
+    pub fn new(
+        launch_review: Arc<dyn CommandHandler<LaunchAccessReviewCommand, AccessReviewAggregate>>,
+        decide_item: Arc<dyn CommandHandler<DecideAccessReviewItemCommand, ()>>,
+        close_due_reviews: Arc<dyn CommandHandler<CloseDueAccessReviewsCommand, u64>>,
+        get_review_by_id: Arc<dyn QueryHandler<GetAccessReviewByIdQuery, AccessReviewAggregate>>,
+        list_reviews: Arc<dyn QueryHandler<ListAccessReviewsQuery, PaginatedResult<AccessReviewAggregate>>>,
+        list_items: Arc<dyn QueryHandler<ListAccessReviewItemsQuery, Vec<AccessReviewItem>>>,
+        get_report: Arc<dyn QueryHandler<GetAccessReviewReportQuery, AccessReviewReport>>,
+    ) -> Self {
+        Self { launch_review, decide_item, close_due_reviews, get_review_by_id, list_reviews, list_items, get_report }
+    }
+
+    pub fn assemble(
+        access_review_service: Arc<dyn IAccessReviewService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+    ) -> Self {
+        Self::new(
+            audited(LaunchAccessReviewHandler::new(access_review_service.clone()), &audit_log_service),
+            audited(DecideAccessReviewItemHandler::new(access_review_service.clone()), &audit_log_service),
+            audited(CloseDueAccessReviewsHandler::new(access_review_service.clone()), &audit_log_service),
+            Arc::new(GetAccessReviewByIdHandler::new(access_review_service.clone())),
+            Arc::new(ListAccessReviewsHandler::new(access_review_service.clone())),
+            Arc::new(ListAccessReviewItemsHandler::new(access_review_service.clone())),
+            Arc::new(GetAccessReviewReportHandler::new(access_review_service.clone())),
+        )
+    }
+
+    pub async fn launch_review(
+        &self,
+        actor_id: String,
+        req: LaunchAccessReviewRequest,
+    ) -> AppResult<LaunchAccessReviewResponse> {
+        let command = access_review_mapper::to_launch_access_review_command(actor_id, req)?;
+        let review = self.launch_review.handle(command).await?;
+        Ok(LaunchAccessReviewResponse { review: review.into() })
+    }
+
+    pub async fn decide_item(
+        &self,
+        actor_id: String,
+        req: DecideAccessReviewItemRequest,
+    ) -> AppResult<DecideAccessReviewItemResponse> {
+        let command = access_review_mapper::to_decide_access_review_item_command(actor_id, req)?;
+        self.decide_item.handle(command).await?;
+        Ok(DecideAccessReviewItemResponse)
+    }
+
+    pub async fn close_due_reviews(&self, actor_id: String) -> AppResult<CloseDueAccessReviewsResponse> {
+        let command = access_review_mapper::to_close_due_access_reviews_command(actor_id)?;
+        let completed = self.close_due_reviews.handle(command).await?;
+        Ok(CloseDueAccessReviewsResponse { completed })
+    }
+
+    pub async fn get_review_by_id(&self, req: GetAccessReviewByIdRequest) -> AppResult<GetAccessReviewByIdResponse> {
+        let query = access_review_mapper::to_get_access_review_by_id_query(req)?;
+        let review = self.get_review_by_id.handle(query).await?;
+        Ok(GetAccessReviewByIdResponse { review: review.into() })
+    }
+
+    pub async fn list_reviews(
+        &self,
+        actor_id: String,
+        req: ListAccessReviewsRequest,
+    ) -> AppResult<ListAccessReviewsResponse> {
+        let query = access_review_mapper::to_list_access_reviews_query(actor_id, req)?;
+        let result = self.list_reviews.handle(query).await?;
+        Ok(ListAccessReviewsResponse {
+            reviews: result.items.into_iter().map(Into::into).collect(),
+            total: result.total,
+        })
+    }
+
+    pub async fn list_items(
+        &self,
+        actor_id: String,
+        req: ListAccessReviewItemsRequest,
+    ) -> AppResult<ListAccessReviewItemsResponse> {
+        let query = access_review_mapper::to_list_access_review_items_query(actor_id, req)?;
+        let items = self.list_items.handle(query).await?;
+        Ok(ListAccessReviewItemsResponse { items: items.into_iter().map(Into::into).collect() })
+    }
+
+    /// 导出复核报告，返回 CSV 文本
+    pub async fn export_report(&self, req: GetAccessReviewReportRequest) -> AppResult<String> {
+        let query = access_review_mapper::to_get_access_review_report_query(req)?;
+        let report = self.get_report.handle(query).await?;
+        Ok(access_review_mapper::to_access_review_report_csv(report))
+    }
+}
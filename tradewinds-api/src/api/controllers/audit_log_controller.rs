@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use tradewinds_application::QueryHandler;
+use tradewinds_application::interfaces::IAuditLogService;
+use tradewinds_application::queries::audit_log::handlers::{ExportAuditLogsHandler, ListAuditLogsHandler};
+use tradewinds_application::queries::audit_log::*;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::audit_log_dto::*,
+    mappers::audit_log_mapper,
+};
+
+/// 审计日志控制器
+pub struct AuditLogController {
+    list_logs: Arc<dyn QueryHandler<ListAuditLogsQuery, PaginatedResult<AuditLog>>>,
+    export_logs: Arc<dyn QueryHandler<ExportAuditLogsQuery, Vec<AuditLog>>>,
+}
+
+impl AuditLogController {
+    pub fn new(
+        list_logs: Arc<dyn QueryHandler<ListAuditLogsQuery, PaginatedResult<AuditLog>>>,
+        export_logs: Arc<dyn QueryHandler<ExportAuditLogsQuery, Vec<AuditLog>>>,
+    ) -> Self {
+        Self { list_logs, export_logs }
+    }
+
+    pub fn assemble(audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self::new(
+            Arc::new(ListAuditLogsHandler::new(audit_log_service.clone())),
+            Arc::new(ExportAuditLogsHandler::new(audit_log_service.clone())),
+        )
+    }
+
+    pub async fn list_logs(
+        &self,
+        filter: AuditLogFilterRequest,
+        req: ListAuditLogsRequest,
+    ) -> AppResult<ListAuditLogsResponse> {
+        let query = audit_log_mapper::to_list_audit_logs_query(filter, req)?;
+        let result = self.list_logs.handle(query).await?;
+        Ok(ListAuditLogsResponse { logs: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
+    /// 导出审计日志（CSV 文本）
+    pub async fn export_logs(&self, filter: AuditLogFilterRequest) -> AppResult<String> {
+        let query = audit_log_mapper::to_export_audit_logs_query(filter)?;
+        let logs = self.export_logs.handle(query).await?;
+        Ok(audit_log_mapper::to_audit_logs_csv(logs))
+    }
+}
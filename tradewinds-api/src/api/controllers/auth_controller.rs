@@ -1,21 +1,31 @@
-use std::sync::Arc;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock};
+use std::time::Instant;
 
 // 应用层命令与处理器
+use tradewinds_application::audit::audited;
 use tradewinds_application::commands::auth::{
     ChangePasswordCommand, LoginCommand, LogoutCommand, RegisterCommand,
     handlers::{ChangePasswordHandler, LoginHandler, LogoutHandler, RegisterHandler},
 };
 
 // 查询与处理器
+use tradewinds_application::queries::audit_log::{ListAuditLogsHandler, ListAuditLogsQuery};
 use tradewinds_application::queries::auth::{
-    CurrentUserInfo, GetCurrentUserQuery, GetUserMenusQuery, MenuInfo,
-    handlers::{GetCurrentUserHandler, GetUserMenusHandler},
+    CurrentUserInfo, DashboardStats, GetCurrentUserQuery, GetDashboardStatsQuery, GetLoginHistoryQuery,
+    GetUserMenusQuery, MenuInfo,
+    handlers::{GetCurrentUserHandler, GetDashboardStatsHandler, GetLoginHistoryHandler, GetUserMenusHandler},
 };
+use tradewinds_application::queries::feature_flag::{GetUserFeatureFlagsHandler, GetUserFeatureFlagsQuery};
 use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
 
 // 领域对象
-use tradewinds_application::interfaces::IAuthService;
-use tradewinds_domain::value_objects::Token;
+use tradewinds_application::interfaces::{IAuditLogService, IAuthService, IFeatureFlagService};
+use tradewinds_domain::entities::{audit_log::AuditLog, login_log::LoginLog};
+use tradewinds_domain::repositories::AuditLogFilter;
+use tradewinds_domain::value_objects::{Token, UserId};
 
 // 错误类型
 use tradewinds_error::AppResult;
@@ -23,6 +33,12 @@ use tradewinds_error::AppResult;
 // crate 内部
 use crate::api::{dtos::auth_dto::*, mappers::auth_mapper};
 
+/// 仪表盘展示的最近活动条数
+const RECENT_ACTIVITY_LIMIT: u64 = 10;
+
+/// 进程启动时间，用于计算仪表盘的运行时长
+static STARTED_AT: LazyLock<Instant> = LazyLock::new(Instant::now);
+
 /// 认证控制器，负责协调认证相关的用例
 pub struct AuthController {
     pub register: Arc<dyn CommandHandler<RegisterCommand, ()>>,
@@ -31,29 +47,31 @@ pub struct AuthController {
     pub change_password: Arc<dyn CommandHandler<ChangePasswordCommand, ()>>,
     pub get_current_user: Arc<dyn QueryHandler<GetCurrentUserQuery, CurrentUserInfo>>,
     pub get_user_menus: Arc<dyn QueryHandler<GetUserMenusQuery, Vec<MenuInfo>>>,
+    pub get_login_history: Arc<dyn QueryHandler<GetLoginHistoryQuery, PaginatedResult<LoginLog>>>,
+    pub list_audit_logs: Arc<dyn QueryHandler<ListAuditLogsQuery, PaginatedResult<AuditLog>>>,
+    pub get_user_feature_flags: Arc<dyn QueryHandler<GetUserFeatureFlagsQuery, BTreeMap<String, bool>>>,
+    pub get_dashboard_stats: Arc<dyn QueryHandler<GetDashboardStatsQuery, DashboardStats>>,
 }
 
 impl AuthController {
-    pub fn new(
-        register: Arc<dyn CommandHandler<RegisterCommand, ()>>,
-        login: Arc<dyn CommandHandler<LoginCommand, Token>>,
-        logout: Arc<dyn CommandHandler<LogoutCommand, ()>>,
-        change_password: Arc<dyn CommandHandler<ChangePasswordCommand, ()>>,
-        get_current_user: Arc<dyn QueryHandler<GetCurrentUserQuery, CurrentUserInfo>>,
-        get_user_menus: Arc<dyn QueryHandler<GetUserMenusQuery, Vec<MenuInfo>>>,
+    pub fn assemble(
+        auth_service: Arc<dyn IAuthService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+        feature_flag_service: Arc<dyn IFeatureFlagService>,
     ) -> Self {
-        Self { register, login, logout, change_password, get_current_user, get_user_menus }
-    }
-
-    pub fn assemble(auth_service: Arc<dyn IAuthService>) -> Self {
-        Self::new(
-            Arc::new(RegisterHandler::new(auth_service.clone())),
-            Arc::new(LoginHandler::new(auth_service.clone())),
-            Arc::new(LogoutHandler::new(auth_service.clone())),
-            Arc::new(ChangePasswordHandler::new(auth_service.clone())),
-            Arc::new(GetCurrentUserHandler::new(auth_service.clone())),
-            Arc::new(GetUserMenusHandler::new(auth_service.clone())),
-        )
+        LazyLock::force(&STARTED_AT);
+        Self {
+            register: audited(RegisterHandler::new(auth_service.clone()), &audit_log_service),
+            login: audited(LoginHandler::new(auth_service.clone()), &audit_log_service),
+            logout: audited(LogoutHandler::new(auth_service.clone()), &audit_log_service),
+            change_password: audited(ChangePasswordHandler::new(auth_service.clone()), &audit_log_service),
+            get_current_user: Arc::new(GetCurrentUserHandler::new(auth_service.clone())),
+            get_user_menus: Arc::new(GetUserMenusHandler::new(auth_service.clone())),
+            get_login_history: Arc::new(GetLoginHistoryHandler::new(auth_service.clone())),
+            list_audit_logs: Arc::new(ListAuditLogsHandler::new(audit_log_service.clone())),
+            get_user_feature_flags: Arc::new(GetUserFeatureFlagsHandler::new(feature_flag_service.clone())),
+            get_dashboard_stats: Arc::new(GetDashboardStatsHandler::new(auth_service.clone())),
+        }
     }
 
     /// 用户注册
@@ -90,11 +108,19 @@ impl AuthController {
         Ok(ChangePasswordResponse { message: "密码修改成功".to_string() })
     }
 
-    /// 获取当前用户
-    pub async fn get_current_user(&self, req: GetCurrentUserRequest) -> AppResult<GetCurrentUserResponse> {
+    /// 校验令牌并获取当前用户，供认证中间件使用
+    pub async fn authenticate(&self, req: GetCurrentUserRequest) -> AppResult<CurrentUserInfoResponse> {
         let query = auth_mapper::to_get_current_user_query(req)?;
         let user_info = self.get_current_user.handle(query).await?;
-        Ok(GetCurrentUserResponse { user: auth_mapper::to_current_user_info_response(user_info) })
+        Ok(auth_mapper::to_current_user_info_response(user_info))
+    }
+
+    /// 获取当前用户及其功能开关状态
+    pub async fn get_current_user(&self, req: GetCurrentUserRequest) -> AppResult<GetCurrentUserResponse> {
+        let user = self.authenticate(req).await?;
+        let query = GetUserFeatureFlagsQuery { user_id: UserId::from_str(&user.user.id)? };
+        let feature_flags = self.get_user_feature_flags.handle(query).await?;
+        Ok(GetCurrentUserResponse { user, feature_flags })
     }
 
     /// 获取用户菜单权限
@@ -104,6 +130,13 @@ impl AuthController {
         Ok(GetUserMenusResponse { menus: auth_mapper::to_menu_responses(menus) })
     }
 
+    /// 获取当前用户登录历史
+    pub async fn get_login_history(&self, req: GetLoginHistoryRequest) -> AppResult<GetLoginHistoryResponse> {
+        let query = auth_mapper::to_get_login_history_query(req)?;
+        let result = self.get_login_history.handle(query).await?;
+        Ok(GetLoginHistoryResponse { logs: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
     /// 获取超级管理员仪表盘数据
     pub async fn get_super_admin_dashboard(
         &self,
@@ -112,55 +145,46 @@ impl AuthController {
         // 验证用户身份和权限
         let get_user_req = GetCurrentUserRequest { token: req.token };
         let query = auth_mapper::to_get_current_user_query(get_user_req)?;
-        let user_info = self.get_current_user.handle(query).await?;
+        self.get_current_user.handle(query).await?;
 
         // 去掉超级管理员权限校验，允许所有登录用户访问
 
-        // 构建仪表盘数据（这里使用模拟数据，实际项目中应该从数据库获取）
+        let stats = self.get_dashboard_stats.handle(GetDashboardStatsQuery).await?;
         let system_stats = SystemStats {
-            total_users: 1250,
-            active_users: 89,
-            total_roles: 8,
-            total_permissions: 45,
-            database_size: "2.3 GB".to_string(),
-            uptime: "15 天 8 小时 32 分钟".to_string(),
+            total_users: stats.total_users,
+            active_users: stats.active_users,
+            total_roles: stats.total_roles,
+            total_permissions: stats.total_permissions,
+            database_size: None,
+            uptime: format_uptime(STARTED_AT.elapsed().as_secs()),
         };
 
-        let user_stats =
-            UserStats { new_users_today: 12, new_users_this_week: 87, new_users_this_month: 324, active_sessions: 156 };
-
-        let recent_activities = vec![
-            RecentActivity {
-                id: "act_001".to_string(),
-                user_id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
-                username: "admin".to_string(),
-                action: "登录系统".to_string(),
-                resource: "系统".to_string(),
-                timestamp: chrono::Utc::now().timestamp(),
-                ip_address: "192.168.1.100".to_string(),
-                user_agent: "Mozilla/5.0 (Windows NT 10.0; Win64; x64)".to_string(),
-            },
-            RecentActivity {
-                id: "act_002".to_string(),
-                user_id: "550e8400-e29b-41d4-a716-446655440003".to_string(),
-                username: "user001".to_string(),
-                action: "创建用户".to_string(),
-                resource: "用户管理".to_string(),
-                timestamp: chrono::Utc::now().timestamp() - 3600,
-                ip_address: "192.168.1.101".to_string(),
-                user_agent: "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7)".to_string(),
-            },
-        ];
+        let user_stats = UserStats {
+            new_users_today: stats.new_users_today,
+            new_users_this_week: stats.new_users_this_week,
+            new_users_this_month: stats.new_users_this_month,
+            active_sessions: None,
+        };
 
+        // 最近活动取自审计日志
+        let query = ListAuditLogsQuery { filter: AuditLogFilter::default(), page: 1, page_size: RECENT_ACTIVITY_LIMIT };
+        let recent_activities = self.list_audit_logs.handle(query).await?.items.into_iter().map(Into::into).collect();
+
+        // 以上统计均查询成功即说明数据库可用；其余指标尚未采集
         let system_health = SystemHealth {
-            cpu_usage: 23.5,
-            memory_usage: 68.2,
-            disk_usage: 45.8,
+            cpu_usage: None,
+            memory_usage: None,
+            disk_usage: None,
             database_status: "正常".to_string(),
-            redis_status: "正常".to_string(),
-            rabbitmq_status: "正常".to_string(),
+            redis_status: None,
+            rabbitmq_status: None,
         };
 
         Ok(GetSuperAdminDashboardResponse { system_stats, user_stats, recent_activities, system_health })
     }
 }
+
+/// 运行时长，格式如 "15 天 8 小时 32 分钟"
+fn format_uptime(secs: u64) -> String {
+    format!("{} 天 {} 小时 {} 分钟", secs / 86_400, secs % 86_400 / 3_600, secs % 3_600 / 60)
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::department::handlers::{
+    CreateDepartmentHandler, DeleteDepartmentHandler, UpdateDepartmentHandler,
+};
+use tradewinds_application::commands::department::*;
+use tradewinds_application::interfaces::{IAuditLogService, IDepartmentService};
+use tradewinds_application::queries::department::handlers::{
+    GetDepartmentByIdHandler, ListAllDepartmentsHandler, ListDepartmentsHandler,
+};
+use tradewinds_application::queries::department::*;
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::department_dto::*,
+    mappers::department_mapper,
+};
+
+/// 部门控制器
+pub struct DepartmentController {
+    create_department: Arc<dyn CommandHandler<CreateDepartmentCommand, Department>>,
+    update_department: Arc<dyn CommandHandler<UpdateDepartmentCommand, ()>>,
+    delete_department: Arc<dyn CommandHandler<DeleteDepartmentCommand, ()>>,
+    get_department_by_id: Arc<dyn QueryHandler<GetDepartmentByIdQuery, Department>>,
+    list_departments: Arc<dyn QueryHandler<ListDepartmentsQuery, PaginatedResult<Department>>>,
+    list_all_departments: Arc<dyn QueryHandler<ListAllDepartmentsQuery, Vec<Department>>>,
+}
+
+impl DepartmentController {
+    pub fn new(
+        create_department: Arc<dyn CommandHandler<CreateDepartmentCommand, Department>>,
+        update_department: Arc<dyn CommandHandler<UpdateDepartmentCommand, ()>>,
+        delete_department: Arc<dyn CommandHandler<DeleteDepartmentCommand, ()>>,
+        get_department_by_id: Arc<dyn QueryHandler<GetDepartmentByIdQuery, Department>>,
+        list_departments: Arc<dyn QueryHandler<ListDepartmentsQuery, PaginatedResult<Department>>>,
+        list_all_departments: Arc<dyn QueryHandler<ListAllDepartmentsQuery, Vec<Department>>>,
+    ) -> Self {
+        Self {
+            create_department,
+            update_department,
+            delete_department,
+            get_department_by_id,
+            list_departments,
+            list_all_departments,
+        }
+    }
+
+    pub fn assemble(
+        department_service: Arc<dyn IDepartmentService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+    ) -> Self {
+        Self::new(
+            audited(CreateDepartmentHandler::new(department_service.clone()), &audit_log_service),
+            audited(UpdateDepartmentHandler::new(department_service.clone()), &audit_log_service),
+            audited(DeleteDepartmentHandler::new(department_service.clone()), &audit_log_service),
+            Arc::new(GetDepartmentByIdHandler::new(department_service.clone())),
+            Arc::new(ListDepartmentsHandler::new(department_service.clone())),
+            Arc::new(ListAllDepartmentsHandler::new(department_service.clone())),
+        )
+    }
+
+    pub async fn create_department(
+        &self,
+        actor_id: String,
+        req: CreateDepartmentRequest,
+    ) -> AppResult<CreateDepartmentResponse> {
+        let command = department_mapper::to_create_department_command(actor_id, req)?;
+        let department = self.create_department.handle(command).await?;
+        Ok(CreateDepartmentResponse { department: department.into() })
+    }
+
+    pub async fn update_department(
+        &self,
+        actor_id: String,
+        req: UpdateDepartmentRequest,
+    ) -> AppResult<UpdateDepartmentResponse> {
+        let command = department_mapper::to_update_department_command(actor_id, req)?;
+        self.update_department.handle(command).await?;
+        Ok(UpdateDepartmentResponse)
+    }
+
+    pub async fn delete_department(
+        &self,
+        actor_id: String,
+        req: DeleteDepartmentRequest,
+    ) -> AppResult<DeleteDepartmentResponse> {
+        let command = department_mapper::to_delete_department_command(actor_id, req)?;
+        self.delete_department.handle(command).await?;
+        Ok(DeleteDepartmentResponse)
+    }
+
+    pub async fn get_department_by_id(&self, req: GetDepartmentByIdRequest) -> AppResult<GetDepartmentByIdResponse> {
+        let query = department_mapper::to_get_department_by_id_query(req)?;
+        let department = self.get_department_by_id.handle(query).await?;
+        Ok(GetDepartmentByIdResponse { department: department.into() })
+    }
+
+    pub async fn list_departments(&self, req: ListDepartmentsRequest) -> AppResult<ListDepartmentsResponse> {
+        let query = department_mapper::to_list_departments_query(req)?;
+        let result = self.list_departments.handle(query).await?;
+        Ok(ListDepartmentsResponse {
+            departments: result.items.into_iter().map(Into::into).collect(),
+            total: result.total,
+        })
+    }
+
+    pub async fn get_department_tree(&self) -> AppResult<Vec<DepartmentTreeResponse>> {
+        let flat = self.list_all_departments.handle(ListAllDepartmentsQuery).await?;
+        Ok(department_mapper::to_tree_responses(flat))
+    }
+}
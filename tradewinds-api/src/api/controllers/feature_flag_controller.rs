@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::feature_flag::{
+    CreateFeatureFlagCommand, CreateFeatureFlagHandler, DeleteFeatureFlagCommand, DeleteFeatureFlagHandler,
+    UpdateFeatureFlagCommand, UpdateFeatureFlagHandler,
+};
+use tradewinds_application::interfaces::{IAuditLogService, IFeatureFlagService};
+use tradewinds_application::queries::feature_flag::{ListFeatureFlagsHandler, ListFeatureFlagsQuery};
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_domain::entities::FeatureFlag;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::feature_flag_dto::*,
+    mappers::feature_flag_mapper,
+};
+
+/// 功能开关控制器
+pub struct FeatureFlagController {
+    create_flag: Arc<dyn CommandHandler<CreateFeatureFlagCommand, FeatureFlag>>,
+    update_flag: Arc<dyn CommandHandler<UpdateFeatureFlagCommand, FeatureFlag>>,
+    delete_flag: Arc<dyn CommandHandler<DeleteFeatureFlagCommand, ()>>,
+    list_flags: Arc<dyn QueryHandler<ListFeatureFlagsQuery, Vec<FeatureFlag>>>,
+}
+
+impl FeatureFlagController {
+    pub fn new(
+        create_flag: Arc<dyn CommandHandler<CreateFeatureFlagCommand, FeatureFlag>>,
+        update_flag: Arc<dyn CommandHandler<UpdateFeatureFlagCommand, FeatureFlag>>,
+        delete_flag: Arc<dyn CommandHandler<DeleteFeatureFlagCommand, ()>>,
+        list_flags: Arc<dyn QueryHandler<ListFeatureFlagsQuery, Vec<FeatureFlag>>>,
+    ) -> Self {
+        Self { create_flag, update_flag, delete_flag, list_flags }
+    }
+
+    pub fn assemble(
+        feature_flag_service: Arc<dyn IFeatureFlagService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+    ) -> Self {
+        Self::new(
+            audited(CreateFeatureFlagHandler::new(feature_flag_service.clone()), &audit_log_service),
+            audited(UpdateFeatureFlagHandler::new(feature_flag_service.clone()), &audit_log_service),
+            audited(DeleteFeatureFlagHandler::new(feature_flag_service.clone()), &audit_log_service),
+            Arc::new(ListFeatureFlagsHandler::new(feature_flag_service.clone())),
+        )
+    }
+
+    pub async fn create_flag(&self, actor_id: String, req: CreateFeatureFlagRequest) -> AppResult<FeatureFlagResponse> {
+        let command = feature_flag_mapper::to_create_feature_flag_command(actor_id, req)?;
+        Ok(self.create_flag.handle(command).await?.into())
+    }
+
+    pub async fn update_flag(&self, actor_id: String, req: UpdateFeatureFlagRequest) -> AppResult<FeatureFlagResponse> {
+        let command = feature_flag_mapper::to_update_feature_flag_command(actor_id, req)?;
+        Ok(self.update_flag.handle(command).await?.into())
+    }
+
+    pub async fn delete_flag(&self, actor_id: String, key: String) -> AppResult<()> {
+        let command = feature_flag_mapper::to_delete_feature_flag_command(actor_id, key)?;
+        self.delete_flag.handle(command).await
+    }
+
+    pub async fn list_flags(&self) -> AppResult<ListFeatureFlagsResponse> {
+        let flags = self.list_flags.handle(ListFeatureFlagsQuery).await?;
+        Ok(ListFeatureFlagsResponse { flags: flags.into_iter().map(Into::into).collect() })
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::group::handlers::{
+    AddGroupMembersHandler, CreateGroupHandler, DeleteGroupHandler, RemoveGroupMemberHandler, UpdateGroupHandler,
+};
+use tradewinds_application::commands::group::*;
+use tradewinds_application::interfaces::{IAuditLogService, IGroupService};
+use tradewinds_application::queries::group::handlers::{
+    GetGroupByIdHandler, ListGroupMembersHandler, ListGroupsHandler,
+};
+use tradewinds_application::queries::group::*;
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_domain::entities::{group::Group, user::User};
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::group_dto::*,
+    mappers::group_mapper,
+};
+
+/// 用户组控制器
+pub struct GroupController {
+    create_group: Arc<dyn CommandHandler<CreateGroupCommand, Group>>,
+    update_group: Arc<dyn CommandHandler<UpdateGroupCommand, ()>>,
+    delete_group: Arc<dyn CommandHandler<DeleteGroupCommand, ()>>,
+    add_group_members: Arc<dyn CommandHandler<AddGroupMembersCommand, ()>>,
+    remove_group_member: Arc<dyn CommandHandler<RemoveGroupMemberCommand, ()>>,
+    get_group_by_id: Arc<dyn QueryHandler<GetGroupByIdQuery, GroupAggregate>>,
+    list_groups: Arc<dyn QueryHandler<ListGroupsQuery, PaginatedResult<Group>>>,
+    list_group_members: Arc<dyn QueryHandler<ListGroupMembersQuery, Vec<User>>>,
+}
+
+impl GroupController {
+    pub fn assemble(group_service: Arc<dyn IGroupService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self {
+            create_group: audited(CreateGroupHandler::new(group_service.clone()), &audit_log_service),
+            update_group: audited(UpdateGroupHandler::new(group_service.clone()), &audit_log_service),
+            delete_group: audited(DeleteGroupHandler::new(group_service.clone()), &audit_log_service),
+            add_group_members: audited(AddGroupMembersHandler::new(group_service.clone()), &audit_log_service),
+            remove_group_member: audited(RemoveGroupMemberHandler::new(group_service.clone()), &audit_log_service),
+            get_group_by_id: Arc::new(GetGroupByIdHandler::new(group_service.clone())),
+            list_groups: Arc::new(ListGroupsHandler::new(group_service.clone())),
+            list_group_members: Arc::new(ListGroupMembersHandler::new(group_service.clone())),
+        }
+    }
+
+    pub async fn create_group(&self, actor_id: String, req: CreateGroupRequest) -> AppResult<CreateGroupResponse> {
+        let command = group_mapper::to_create_group_command(actor_id, req)?;
+        let group = self.create_group.handle(command).await?;
+        Ok(CreateGroupResponse { group: group.into() })
+    }
+
+    pub async fn update_group(&self, actor_id: String, req: UpdateGroupRequest) -> AppResult<UpdateGroupResponse> {
+        let command = group_mapper::to_update_group_command(actor_id, req)?;
+        self.update_group.handle(command).await?;
+        Ok(UpdateGroupResponse)
+    }
+
+    pub async fn delete_group(&self, actor_id: String, req: DeleteGroupRequest) -> AppResult<DeleteGroupResponse> {
+        let command = group_mapper::to_delete_group_command(actor_id, req)?;
+        self.delete_group.handle(command).await?;
+        Ok(DeleteGroupResponse)
+    }
+
+    pub async fn add_group_members(
+        &self,
+        actor_id: String,
+        req: AddGroupMembersRequest,
+    ) -> AppResult<AddGroupMembersResponse> {
+        let command = group_mapper::to_add_group_members_command(actor_id, req)?;
+        self.add_group_members.handle(command).await?;
+        Ok(AddGroupMembersResponse)
+    }
+
+    pub async fn remove_group_member(
+        &self,
+        actor_id: String,
+        req: RemoveGroupMemberRequest,
+    ) -> AppResult<RemoveGroupMemberResponse> {
+        let command = group_mapper::to_remove_group_member_command(actor_id, req)?;
+        self.remove_group_member.handle(command).await?;
+        Ok(RemoveGroupMemberResponse)
+    }
+
+    pub async fn get_group_by_id(&self, req: GetGroupByIdRequest) -> AppResult<GetGroupByIdResponse> {
+        let query = group_mapper::to_get_group_by_id_query(req)?;
+        let group = self.get_group_by_id.handle(query).await?;
+        Ok(GetGroupByIdResponse { group: group.into() })
+    }
+
+    pub async fn list_groups(&self, req: ListGroupsRequest) -> AppResult<ListGroupsResponse> {
+        let query = group_mapper::to_list_groups_query(req)?;
+        let result = self.list_groups.handle(query).await?;
+        Ok(ListGroupsResponse { groups: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
+    pub async fn list_group_members(&self, req: ListGroupMembersRequest) -> AppResult<ListGroupMembersResponse> {
+        let query = group_mapper::to_list_group_members_query(req)?;
+        let members = self.list_group_members.handle(query).await?;
+        Ok(ListGroupMembersResponse { members: members.into_iter().map(Into::into).collect() })
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::job::{
+    PauseJobCommand, PauseJobHandler, ResumeJobCommand, ResumeJobHandler, TriggerJobCommand, TriggerJobHandler,
+};
+use tradewinds_application::interfaces::{IAuditLogService, IJobService};
+use tradewinds_application::queries::job::{ListJobRunsHandler, ListJobRunsQuery, ListJobsHandler, ListJobsQuery};
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::{JobRun, ScheduledJob};
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::job_dto::*,
+    mappers::job_mapper,
+};
+
+/// 定时任务控制器
+pub struct JobController {
+    list_jobs: Arc<dyn QueryHandler<ListJobsQuery, Vec<ScheduledJob>>>,
+    list_runs: Arc<dyn QueryHandler<ListJobRunsQuery, PaginatedResult<JobRun>>>,
+    trigger_job: Arc<dyn CommandHandler<TriggerJobCommand, JobRun>>,
+    pause_job: Arc<dyn CommandHandler<PauseJobCommand, ()>>,
+    resume_job: Arc<dyn CommandHandler<ResumeJobCommand, ()>>,
+}
+
+impl JobController {
+    pub fn new(
+        list_jobs: Arc<dyn QueryHandler<ListJobsQuery, Vec<ScheduledJob>>>,
+        list_runs: Arc<dyn QueryHandler<ListJobRunsQuery, PaginatedResult<JobRun>>>,
+        trigger_job: Arc<dyn CommandHandler<TriggerJobCommand, JobRun>>,
+        pause_job: Arc<dyn CommandHandler<PauseJobCommand, ()>>,
+        resume_job: Arc<dyn CommandHandler<ResumeJobCommand, ()>>,
+    ) -> Self {
+        Self { list_jobs, list_runs, trigger_job, pause_job, resume_job }
+    }
+
+    pub fn assemble(job_service: Arc<dyn IJobService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self::new(
+            Arc::new(ListJobsHandler::new(job_service.clone())),
+            Arc::new(ListJobRunsHandler::new(job_service.clone())),
+            audited(TriggerJobHandler::new(job_service.clone()), &audit_log_service),
+            audited(PauseJobHandler::new(job_service.clone()), &audit_log_service),
+            audited(ResumeJobHandler::new(job_service.clone()), &audit_log_service),
+        )
+    }
+
+    pub async fn list_jobs(&self) -> AppResult<ListJobsResponse> {
+        let jobs = self.list_jobs.handle(ListJobsQuery).await?;
+        Ok(ListJobsResponse { jobs: jobs.into_iter().map(Into::into).collect() })
+    }
+
+    pub async fn list_runs(
+        &self,
+        filter: JobRunFilterRequest,
+        req: ListJobRunsRequest,
+    ) -> AppResult<ListJobRunsResponse> {
+        let query = job_mapper::to_list_job_runs_query(filter, req)?;
+        let result = self.list_runs.handle(query).await?;
+        Ok(ListJobRunsResponse { runs: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
+    /// 立即执行一次，不等待执行结束
+    pub async fn trigger_job(&self, name: String, actor_id: String) -> AppResult<TriggerJobResponse> {
+        let command = job_mapper::to_trigger_job_command(name, actor_id)?;
+        let run = self.trigger_job.handle(command).await?;
+        Ok(TriggerJobResponse { run: run.into() })
+    }
+
+    pub async fn pause_job(&self, name: String, actor_id: String) -> AppResult<()> {
+        let command = job_mapper::to_pause_job_command(name, actor_id)?;
+        self.pause_job.handle(command).await
+    }
+
+    pub async fn resume_job(&self, name: String, actor_id: String) -> AppResult<()> {
+        let command = job_mapper::to_resume_job_command(name, actor_id)?;
+        self.resume_job.handle(command).await
+    }
+}
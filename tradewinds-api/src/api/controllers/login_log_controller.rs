@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::login_log::{PurgeLoginLogsCommand, handlers::PurgeLoginLogsHandler};
+use tradewinds_application::interfaces::{IAuditLogService, ILoginLogService};
+use tradewinds_application::queries::login_log::{ListLoginLogsHandler, ListLoginLogsQuery};
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::login_log::LoginLog;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::login_log_dto::*,
+    mappers::login_log_mapper,
+};
+
+/// 登录日志控制器
+pub struct LoginLogController {
+    list_logs: Arc<dyn QueryHandler<ListLoginLogsQuery, PaginatedResult<LoginLog>>>,
+    purge_logs: Arc<dyn CommandHandler<PurgeLoginLogsCommand, u64>>,
+}
+
+impl LoginLogController {
+    pub fn new(
+        list_logs: Arc<dyn QueryHandler<ListLoginLogsQuery, PaginatedResult<LoginLog>>>,
+        purge_logs: Arc<dyn CommandHandler<PurgeLoginLogsCommand, u64>>,
+    ) -> Self {
+        Self { list_logs, purge_logs }
+    }
+
+    pub fn assemble(
+        login_log_service: Arc<dyn ILoginLogService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+    ) -> Self {
+        Self::new(
+            Arc::new(ListLoginLogsHandler::new(login_log_service.clone())),
+            audited(PurgeLoginLogsHandler::new(login_log_service.clone()), &audit_log_service),
+        )
+    }
+
+    pub async fn list_logs(
+        &self,
+        filter: LoginLogFilterRequest,
+        req: ListLoginLogsRequest,
+    ) -> AppResult<ListLoginLogsResponse> {
+        let query = login_log_mapper::to_list_login_logs_query(filter, req)?;
+        let result = self.list_logs.handle(query).await?;
+        Ok(ListLoginLogsResponse { logs: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
+    /// 清理超过保留天数的登录日志
+    pub async fn purge_logs(&self, actor_id: String) -> AppResult<PurgeLoginLogsResponse> {
+        let command = login_log_mapper::to_purge_login_logs_command(actor_id)?;
+        let purged = self.purge_logs.handle(command).await?;
+        Ok(PurgeLoginLogsResponse { purged })
+    }
+}
@@ -1,11 +1,35 @@
+pub mod access_request_controller;
+pub mod access_review_controller;
+pub mod audit_log_controller;
 pub mod auth_controller;
+pub mod department_controller;
+pub mod feature_flag_controller;
+pub mod group_controller;
+pub mod job_controller;
+pub mod login_log_controller;
+pub mod outbox_controller;
 pub mod permission_controller;
+pub mod policy_controller;
 pub mod role_controller;
 pub mod system_setting_controller;
+pub mod tenant_controller;
 pub mod user_controller;
+pub mod webhook_controller;
 
+pub use access_request_controller::*;
+pub use access_review_controller::*;
+pub use audit_log_controller::*;
 pub use auth_controller::*;
+pub use department_controller::*;
+pub use feature_flag_controller::*;
+pub use group_controller::*;
+pub use job_controller::*;
+pub use login_log_controller::*;
+pub use outbox_controller::*;
 pub use permission_controller::*;
+pub use policy_controller::*;
 pub use role_controller::*;
 pub use system_setting_controller::*;
+pub use tenant_controller::*;
 pub use user_controller::*;
+pub use webhook_controller::*;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::outbox::{RetryOutboxMessageCommand, RetryOutboxMessageHandler};
+use tradewinds_application::interfaces::{IAuditLogService, IOutboxService};
+use tradewinds_application::queries::outbox::{
+    GetOutboxStatsHandler, GetOutboxStatsQuery, ListOutboxMessagesHandler, ListOutboxMessagesQuery, OutboxStats,
+};
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::OutboxMessage;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::outbox_dto::*,
+    mappers::outbox_mapper,
+};
+
+/// 发件箱控制器
+pub struct OutboxController {
+    list_messages: Arc<dyn QueryHandler<ListOutboxMessagesQuery, PaginatedResult<OutboxMessage>>>,
+    get_stats: Arc<dyn QueryHandler<GetOutboxStatsQuery, OutboxStats>>,
+    retry_message: Arc<dyn CommandHandler<RetryOutboxMessageCommand, ()>>,
+}
+
+impl OutboxController {
+    pub fn new(
+        list_messages: Arc<dyn QueryHandler<ListOutboxMessagesQuery, PaginatedResult<OutboxMessage>>>,
+        get_stats: Arc<dyn QueryHandler<GetOutboxStatsQuery, OutboxStats>>,
+        retry_message: Arc<dyn CommandHandler<RetryOutboxMessageCommand, ()>>,
+    ) -> Self {
+        Self { list_messages, get_stats, retry_message }
+    }
+
+    pub fn assemble(outbox_service: Arc<dyn IOutboxService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self::new(
+            Arc::new(ListOutboxMessagesHandler::new(outbox_service.clone())),
+            Arc::new(GetOutboxStatsHandler::new(outbox_service.clone())),
+            audited(RetryOutboxMessageHandler::new(outbox_service.clone()), &audit_log_service),
+        )
+    }
+
+    pub async fn list_messages(
+        &self,
+        filter: OutboxFilterRequest,
+        req: ListOutboxMessagesRequest,
+    ) -> AppResult<ListOutboxMessagesResponse> {
+        let query = outbox_mapper::to_list_outbox_messages_query(filter, req)?;
+        let result = self.list_messages.handle(query).await?;
+        Ok(ListOutboxMessagesResponse {
+            messages: result.items.into_iter().map(Into::into).collect(),
+            total: result.total,
+        })
+    }
+
+    pub async fn get_stats(&self) -> AppResult<OutboxStatsResponse> {
+        let stats = self.get_stats.handle(GetOutboxStatsQuery).await?;
+        Ok(stats.into())
+    }
+
+    /// 将失败或死信消息重新放回待投递队列
+    pub async fn retry_message(&self, id: String, actor_id: String) -> AppResult<()> {
+        let command = outbox_mapper::to_retry_outbox_message_command(id, actor_id)?;
+        self.retry_message.handle(command).await
+    }
+}
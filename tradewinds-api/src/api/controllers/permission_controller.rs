@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
+use tradewinds_application::audit::audited;
 use tradewinds_application::commands::permission::handlers::{
     CreatePermissionHandler, DeletePermissionHandler, UpdatePermissionHandler,
 };
 use tradewinds_application::commands::permission::*;
-use tradewinds_application::interfaces::IPermissionService;
+use tradewinds_application::interfaces::{IAuditLogService, IPermissionService};
 use tradewinds_application::queries::permission::handlers::{
     GetPermissionByCodeHandler, GetPermissionByIdHandler, GetPermissionByNameHandler, ListPermissionsByParentIdHandler,
     ListPermissionsByTypeHandler, ListPermissionsHandler,
@@ -37,49 +38,24 @@ pub struct PermissionController {
 }
 
 impl PermissionController {
-    pub fn new(
-        create_permission: Arc<dyn CommandHandler<CreatePermissionCommand, Permission>>,
-        update_permission: Arc<dyn CommandHandler<UpdatePermissionCommand, ()>>,
-        delete_permission: Arc<dyn CommandHandler<DeletePermissionCommand, ()>>,
-        get_permission_by_id: Arc<dyn QueryHandler<GetPermissionByIdQuery, Permission>>,
-        get_permission_by_name: Arc<dyn QueryHandler<GetPermissionByNameQuery, Permission>>,
-        get_permission_by_code: Arc<dyn QueryHandler<GetPermissionByCodeQuery, Permission>>,
-        list_permissions: Arc<dyn QueryHandler<ListPermissionsQuery, PaginatedResult<Permission>>>,
-        list_permissions_by_type: Arc<dyn QueryHandler<ListPermissionsByTypeQuery, PaginatedResult<Permission>>>,
-        list_permissions_by_parent_id: Arc<
-            dyn QueryHandler<ListPermissionsByParentIdQuery, PaginatedResult<Permission>>,
-        >,
-        list_all_permissions: Arc<dyn QueryHandler<ListAllPermissionsQuery, Vec<Permission>>>,
+    pub fn assemble(
+        permission_service: Arc<dyn IPermissionService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
     ) -> Self {
         Self {
-            create_permission,
-            update_permission,
-            delete_permission,
-            get_permission_by_id,
-            get_permission_by_name,
-            get_permission_by_code,
-            list_permissions,
-            list_permissions_by_type,
-            list_permissions_by_parent_id,
-            list_all_permissions,
+            create_permission: audited(CreatePermissionHandler::new(permission_service.clone()), &audit_log_service),
+            update_permission: audited(UpdatePermissionHandler::new(permission_service.clone()), &audit_log_service),
+            delete_permission: audited(DeletePermissionHandler::new(permission_service.clone()), &audit_log_service),
+            get_permission_by_id: Arc::new(GetPermissionByIdHandler::new(permission_service.clone())),
+            get_permission_by_name: Arc::new(GetPermissionByNameHandler::new(permission_service.clone())),
+            get_permission_by_code: Arc::new(GetPermissionByCodeHandler::new(permission_service.clone())),
+            list_permissions: Arc::new(ListPermissionsHandler::new(permission_service.clone())),
+            list_permissions_by_type: Arc::new(ListPermissionsByTypeHandler::new(permission_service.clone())),
+            list_permissions_by_parent_id: Arc::new(ListPermissionsByParentIdHandler::new(permission_service.clone())),
+            list_all_permissions: Arc::new(ListAllPermissionsHandler::new(permission_service.clone())),
         }
     }
 
-    pub fn assemble(permission_service: Arc<dyn IPermissionService>) -> Self {
-        Self::new(
-            Arc::new(CreatePermissionHandler::new(permission_service.clone())),
-            Arc::new(UpdatePermissionHandler::new(permission_service.clone())),
-            Arc::new(DeletePermissionHandler::new(permission_service.clone())),
-            Arc::new(GetPermissionByIdHandler::new(permission_service.clone())),
-            Arc::new(GetPermissionByNameHandler::new(permission_service.clone())),
-            Arc::new(GetPermissionByCodeHandler::new(permission_service.clone())),
-            Arc::new(ListPermissionsHandler::new(permission_service.clone())),
-            Arc::new(ListPermissionsByTypeHandler::new(permission_service.clone())),
-            Arc::new(ListPermissionsByParentIdHandler::new(permission_service.clone())),
-            Arc::new(ListAllPermissionsHandler::new(permission_service.clone())),
-        )
-    }
-
     pub async fn create_permission(
         &self,
         actor_id: String,
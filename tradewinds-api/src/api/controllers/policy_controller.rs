@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::policy::handlers::{
+    CreateAccessPolicyHandler, DeleteAccessPolicyHandler, UpdateAccessPolicyHandler,
+};
+use tradewinds_application::commands::policy::*;
+use tradewinds_application::interfaces::{IAuditLogService, IPolicyService};
+use tradewinds_application::queries::policy::handlers::{
+    EvaluateAccessPolicyHandler, GetAccessPolicyByIdHandler, ListAccessPoliciesHandler,
+};
+use tradewinds_application::queries::policy::*;
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::policies::AccessEvaluation;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::policy_dto::*,
+    mappers::policy_mapper,
+};
+
+/// 访问策略控制器
+pub struct PolicyController {
+    create_policy: Arc<dyn CommandHandler<CreateAccessPolicyCommand, AccessPolicy>>,
+    update_policy: Arc<dyn CommandHandler<UpdateAccessPolicyCommand, ()>>,
+    delete_policy: Arc<dyn CommandHandler<DeleteAccessPolicyCommand, ()>>,
+    get_policy_by_id: Arc<dyn QueryHandler<GetAccessPolicyByIdQuery, AccessPolicy>>,
+    list_policies: Arc<dyn QueryHandler<ListAccessPoliciesQuery, PaginatedResult<AccessPolicy>>>,
+    evaluate: Arc<dyn QueryHandler<EvaluateAccessPolicyQuery, AccessEvaluation>>,
+}
+
+impl PolicyController {
+    pub fn new(
+        create_policy: Arc<dyn CommandHandler<CreateAccessPolicyCommand, AccessPolicy>>,
+        update_policy: Arc<dyn CommandHandler<UpdateAccessPolicyCommand, ()>>,
+        delete_policy: Arc<dyn CommandHandler<DeleteAccessPolicyCommand, ()>>,
+        get_policy_by_id: Arc<dyn QueryHandler<GetAccessPolicyByIdQuery, AccessPolicy>>,
+        list_policies: Arc<dyn QueryHandler<ListAccessPoliciesQuery, PaginatedResult<AccessPolicy>>>,
+        evaluate: Arc<dyn QueryHandler<EvaluateAccessPolicyQuery, AccessEvaluation>>,
+    ) -> Self {
+        Self { create_policy, update_policy, delete_policy, get_policy_by_id, list_policies, evaluate }
+    }
+
+    pub fn assemble(policy_service: Arc<dyn IPolicyService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self::new(
+            audited(CreateAccessPolicyHandler::new(policy_service.clone()), &audit_log_service),
+            audited(UpdateAccessPolicyHandler::new(policy_service.clone()), &audit_log_service),
+            audited(DeleteAccessPolicyHandler::new(policy_service.clone()), &audit_log_service),
+            Arc::new(GetAccessPolicyByIdHandler::new(policy_service.clone())),
+            Arc::new(ListAccessPoliciesHandler::new(policy_service.clone())),
+            Arc::new(EvaluateAccessPolicyHandler::new(policy_service.clone())),
+        )
+    }
+
+    pub async fn create_policy(&self, actor_id: String, req: CreatePolicyRequest) -> AppResult<CreatePolicyResponse> {
+        let command = policy_mapper::to_create_policy_command(actor_id, req)?;
+        let policy = self.create_policy.handle(command).await?;
+        Ok(CreatePolicyResponse { policy: policy.into() })
+    }
+
+    pub async fn update_policy(&self, actor_id: String, req: UpdatePolicyRequest) -> AppResult<UpdatePolicyResponse> {
+        let command = policy_mapper::to_update_policy_command(actor_id, req)?;
+        self.update_policy.handle(command).await?;
+        Ok(UpdatePolicyResponse)
+    }
+
+    pub async fn delete_policy(&self, actor_id: String, req: DeletePolicyRequest) -> AppResult<DeletePolicyResponse> {
+        let command = policy_mapper::to_delete_policy_command(actor_id, req)?;
+        self.delete_policy.handle(command).await?;
+        Ok(DeletePolicyResponse)
+    }
+
+    pub async fn get_policy_by_id(&self, req: GetPolicyByIdRequest) -> AppResult<GetPolicyByIdResponse> {
+        let query = policy_mapper::to_get_policy_by_id_query(req)?;
+        let policy = self.get_policy_by_id.handle(query).await?;
+        Ok(GetPolicyByIdResponse { policy: policy.into() })
+    }
+
+    pub async fn list_policies(&self, req: ListPoliciesRequest) -> AppResult<ListPoliciesResponse> {
+        let query = policy_mapper::to_list_policies_query(req)?;
+        let result = self.list_policies.handle(query).await?;
+        Ok(ListPoliciesResponse { policies: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
+    /// 试运行访问判定
+    pub async fn evaluate(&self, actor_id: String, req: EvaluatePolicyRequest) -> AppResult<EvaluatePolicyResponse> {
+        let query = policy_mapper::to_evaluate_policy_query(actor_id, req)?;
+        let evaluation = self.evaluate.handle(query).await?;
+        Ok(evaluation.into())
+    }
+}
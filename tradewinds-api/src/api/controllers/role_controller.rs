@@ -1,10 +1,11 @@
 use std::sync::Arc;
 
+use tradewinds_application::audit::audited;
 use tradewinds_application::commands::role::handlers::{
     AssignPermissionHandler, CreateRoleHandler, DeleteRoleHandler, RevokePermissionHandler, UpdateRoleHandler,
 };
 use tradewinds_application::commands::role::*;
-use tradewinds_application::interfaces::IRoleService;
+use tradewinds_application::interfaces::{IAuditLogService, IRoleService};
 use tradewinds_application::queries::role::handlers::{
     GetRoleByIdHandler, GetRoleByNameHandler, GetRolePermissionsHandler, ListRolesHandler,
 };
@@ -36,47 +37,23 @@ pub struct RoleController {
 }
 
 impl RoleController {
-    pub fn new(
-        create_role: Arc<dyn CommandHandler<CreateRoleCommand, Role>>,
-        update_role: Arc<dyn CommandHandler<UpdateRoleCommand, ()>>,
-        delete_role: Arc<dyn CommandHandler<DeleteRoleCommand, ()>>,
-        assign_permission: Arc<dyn CommandHandler<AssignPermissionCommand, ()>>,
-        revoke_permission: Arc<dyn CommandHandler<RevokePermissionCommand, ()>>,
-        get_role_by_id: Arc<dyn QueryHandler<GetRoleByIdQuery, Role>>,
-        get_role_by_name: Arc<dyn QueryHandler<GetRoleByNameQuery, Role>>,
-        get_role_permissions: Arc<dyn QueryHandler<GetRolePermissionsQuery, Vec<Permission>>>,
-        list_roles: Arc<dyn QueryHandler<ListRolesQuery, PaginatedResult<(Role, Vec<Permission>)>>>,
-    ) -> Self {
+    pub fn assemble(role_service: Arc<dyn IRoleService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
         Self {
-            create_role,
-            update_role,
-            delete_role,
-            assign_permission,
-            revoke_permission,
-            get_role_by_id,
-            get_role_by_name,
-            get_role_permissions,
-            list_roles,
+            create_role: audited(CreateRoleHandler::new(role_service.clone()), &audit_log_service),
+            update_role: audited(UpdateRoleHandler::new(role_service.clone()), &audit_log_service),
+            delete_role: audited(DeleteRoleHandler::new(role_service.clone()), &audit_log_service),
+            assign_permission: audited(AssignPermissionHandler::new(role_service.clone()), &audit_log_service),
+            revoke_permission: audited(RevokePermissionHandler::new(role_service.clone()), &audit_log_service),
+            get_role_by_id: Arc::new(GetRoleByIdHandler::new(role_service.clone())),
+            get_role_by_name: Arc::new(GetRoleByNameHandler::new(role_service.clone())),
+            get_role_permissions: Arc::new(GetRolePermissionsHandler::new(role_service.clone())),
+            list_roles: Arc::new(ListRolesHandler::new(role_service.clone())),
         }
     }
 
-    pub fn assemble(role_service: Arc<dyn IRoleService>) -> Self {
-        Self::new(
-            Arc::new(CreateRoleHandler::new(role_service.clone())),
-            Arc::new(UpdateRoleHandler::new(role_service.clone())),
-            Arc::new(DeleteRoleHandler::new(role_service.clone())),
-            Arc::new(AssignPermissionHandler::new(role_service.clone())),
-            Arc::new(RevokePermissionHandler::new(role_service.clone())),
-            Arc::new(GetRoleByIdHandler::new(role_service.clone())),
-            Arc::new(GetRoleByNameHandler::new(role_service.clone())),
-            Arc::new(GetRolePermissionsHandler::new(role_service.clone())),
-            Arc::new(ListRolesHandler::new(role_service.clone())),
-        )
-    }
-
     /// 创建角色
     pub async fn create_role(&self, actor_id: String, req: CreateRoleRequest) -> AppResult<CreateRoleResponse> {
-        let command = role_mapper::to_create_role_command(req)?;
+        let command = role_mapper::to_create_role_command(actor_id, req)?;
         let role = self.create_role.handle(command).await?;
         Ok(CreateRoleResponse { role: role.into() })
     }
@@ -2,54 +2,80 @@ use std::sync::Arc;
 
 use crate::api::{
     dtos::system_setting_dto::{
-        GetSystemSettingRequest, GetSystemSettingResponse, SetSystemSettingRequest, SetSystemSettingResponse,
+        GetSystemSettingRequest, ListSystemSettingsRequest, ListSystemSettingsResponse, SetSystemSettingRequest,
+        SetSystemSettingResponse, SystemSettingResponse,
     },
     mappers::system_setting_mapper,
 };
 use tradewinds_application::CommandHandler;
 use tradewinds_application::QueryHandler;
+use tradewinds_application::audit::audited;
 use tradewinds_application::commands::system_setting::handlers::set_system_setting_handler::SetSystemSettingHandler;
 use tradewinds_application::commands::system_setting::set_system_setting_command::SetSystemSettingCommand;
+use tradewinds_application::interfaces::audit_log_service::IAuditLogService;
 use tradewinds_application::interfaces::system_setting_service::ISystemSettingService;
-use tradewinds_application::queries::system_setting::get_system_setting_query::GetSystemSettingQuery;
-use tradewinds_application::queries::system_setting::handlers::get_system_setting_handler::GetSystemSettingHandler;
-use tradewinds_domain::entities::system_setting::SystemSetting;
+use tradewinds_application::queries::system_setting::{
+    GetSystemModeHandler, GetSystemModeQuery, GetSystemSettingHandler, GetSystemSettingQuery,
+    ListSystemSettingsHandler, ListSystemSettingsQuery,
+};
+use tradewinds_domain::entities::system_setting::EffectiveSetting;
+use tradewinds_domain::value_objects::system_setting::SystemMode;
 use tradewinds_error::AppResult;
 
 #[derive(Clone)]
 pub struct SystemSettingController {
-    get_handler: Arc<dyn QueryHandler<GetSystemSettingQuery, SystemSetting>>,
+    get_handler: Arc<dyn QueryHandler<GetSystemSettingQuery, EffectiveSetting>>,
+    list_handler: Arc<dyn QueryHandler<ListSystemSettingsQuery, Vec<EffectiveSetting>>>,
     set_handler: Arc<dyn CommandHandler<SetSystemSettingCommand, ()>>,
+    mode_handler: Arc<dyn QueryHandler<GetSystemModeQuery, SystemMode>>,
 }
 
 impl SystemSettingController {
     pub fn new(
-        get_handler: Arc<dyn QueryHandler<GetSystemSettingQuery, SystemSetting>>,
+        get_handler: Arc<dyn QueryHandler<GetSystemSettingQuery, EffectiveSetting>>,
+        list_handler: Arc<dyn QueryHandler<ListSystemSettingsQuery, Vec<EffectiveSetting>>>,
         set_handler: Arc<dyn CommandHandler<SetSystemSettingCommand, ()>>,
+        mode_handler: Arc<dyn QueryHandler<GetSystemModeQuery, SystemMode>>,
     ) -> Self {
-        Self { get_handler, set_handler }
+        Self { get_handler, list_handler, set_handler, mode_handler }
     }
 
-    pub async fn get_by_key(&self, req: GetSystemSettingRequest) -> AppResult<GetSystemSettingResponse> {
-        let query = system_setting_mapper::to_get_system_setting_query(req);
+    pub async fn get_by_key(&self, req: GetSystemSettingRequest) -> AppResult<SystemSettingResponse> {
+        let query = system_setting_mapper::to_get_system_setting_query(req)?;
         let setting = self.get_handler.handle(query).await?;
-        Ok(GetSystemSettingResponse {
-            key: setting.key.value().to_string(),
-            value: setting.value.value().to_string(),
-            description: setting.description,
-        })
+        Ok(setting.into())
+    }
+
+    pub async fn list(&self, req: ListSystemSettingsRequest) -> AppResult<ListSystemSettingsResponse> {
+        let query = system_setting_mapper::to_list_system_settings_query(req)?;
+        let settings = self.list_handler.handle(query).await?;
+        Ok(ListSystemSettingsResponse { settings: settings.into_iter().map(Into::into).collect() })
     }
 
-    pub async fn set_value(&self, req: SetSystemSettingRequest) -> AppResult<SetSystemSettingResponse> {
-        let cmd = system_setting_mapper::to_set_system_setting_command(req);
+    pub async fn set_value(
+        &self,
+        actor_id: String,
+        req: SetSystemSettingRequest,
+    ) -> AppResult<SetSystemSettingResponse> {
+        let cmd = system_setting_mapper::to_set_system_setting_command(actor_id, req)?;
         self.set_handler.handle(cmd).await?;
         Ok(SetSystemSettingResponse { success: true })
     }
 
-    pub fn assemble(system_setting_service: Arc<dyn ISystemSettingService>) -> Self {
+    /// 维护模式与只读模式的当前状态，供中间件在每个请求上读取
+    pub async fn system_mode(&self) -> AppResult<SystemMode> {
+        self.mode_handler.handle(GetSystemModeQuery).await
+    }
+
+    pub fn assemble(
+        system_setting_service: Arc<dyn ISystemSettingService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
+    ) -> Self {
         Self::new(
             Arc::new(GetSystemSettingHandler::new(system_setting_service.clone())),
-            Arc::new(SetSystemSettingHandler::new(system_setting_service.clone())),
+            Arc::new(ListSystemSettingsHandler::new(system_setting_service.clone())),
+            audited(SetSystemSettingHandler::new(system_setting_service.clone()), &audit_log_service),
+            Arc::new(GetSystemModeHandler::new(system_setting_service.clone())),
         )
     }
 }
@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::tenant::handlers::{CreateTenantHandler, UpdateTenantHandler};
+use tradewinds_application::commands::tenant::*;
+use tradewinds_application::interfaces::{IAuditLogService, ITenantService};
+use tradewinds_application::queries::tenant::handlers::{
+    GetTenantByIdHandler, ListTenantsHandler, ResolveTenantHandler,
+};
+use tradewinds_application::queries::tenant::*;
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::tenant_dto::*,
+    mappers::tenant_mapper,
+};
+
+/// 租户控制器（平台级）
+pub struct TenantController {
+    create_tenant: Arc<dyn CommandHandler<CreateTenantCommand, Tenant>>,
+    update_tenant: Arc<dyn CommandHandler<UpdateTenantCommand, ()>>,
+    get_tenant_by_id: Arc<dyn QueryHandler<GetTenantByIdQuery, Tenant>>,
+    list_tenants: Arc<dyn QueryHandler<ListTenantsQuery, PaginatedResult<Tenant>>>,
+    resolve_tenant: Arc<dyn QueryHandler<ResolveTenantQuery, Tenant>>,
+}
+
+impl TenantController {
+    pub fn new(
+        create_tenant: Arc<dyn CommandHandler<CreateTenantCommand, Tenant>>,
+        update_tenant: Arc<dyn CommandHandler<UpdateTenantCommand, ()>>,
+        get_tenant_by_id: Arc<dyn QueryHandler<GetTenantByIdQuery, Tenant>>,
+        list_tenants: Arc<dyn QueryHandler<ListTenantsQuery, PaginatedResult<Tenant>>>,
+        resolve_tenant: Arc<dyn QueryHandler<ResolveTenantQuery, Tenant>>,
+    ) -> Self {
+        Self { create_tenant, update_tenant, get_tenant_by_id, list_tenants, resolve_tenant }
+    }
+
+    pub fn assemble(tenant_service: Arc<dyn ITenantService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self::new(
+            audited(CreateTenantHandler::new(tenant_service.clone()), &audit_log_service),
+            audited(UpdateTenantHandler::new(tenant_service.clone()), &audit_log_service),
+            Arc::new(GetTenantByIdHandler::new(tenant_service.clone())),
+            Arc::new(ListTenantsHandler::new(tenant_service.clone())),
+            Arc::new(ResolveTenantHandler::new(tenant_service.clone())),
+        )
+    }
+
+    pub async fn create_tenant(&self, actor_id: String, req: CreateTenantRequest) -> AppResult<CreateTenantResponse> {
+        let command = tenant_mapper::to_create_tenant_command(actor_id, req)?;
+        let tenant = self.create_tenant.handle(command).await?;
+        Ok(CreateTenantResponse { tenant: tenant.into() })
+    }
+
+    pub async fn update_tenant(&self, actor_id: String, req: UpdateTenantRequest) -> AppResult<UpdateTenantResponse> {
+        let command = tenant_mapper::to_update_tenant_command(actor_id, req)?;
+        self.update_tenant.handle(command).await?;
+        Ok(UpdateTenantResponse)
+    }
+
+    pub async fn get_tenant_by_id(
+        &self,
+        actor_id: String,
+        req: GetTenantByIdRequest,
+    ) -> AppResult<GetTenantByIdResponse> {
+        let query = tenant_mapper::to_get_tenant_by_id_query(actor_id, req)?;
+        let tenant = self.get_tenant_by_id.handle(query).await?;
+        Ok(GetTenantByIdResponse { tenant: tenant.into() })
+    }
+
+    pub async fn list_tenants(&self, actor_id: String, req: ListTenantsRequest) -> AppResult<ListTenantsResponse> {
+        let query = tenant_mapper::to_list_tenants_query(actor_id, req)?;
+        let result = self.list_tenants.handle(query).await?;
+        Ok(ListTenantsResponse { tenants: result.items.into_iter().map(Into::into).collect(), total: result.total })
+    }
+
+    /// 解析请求所属租户，返回启用租户的ID
+    pub async fn resolve_tenant(&self, req: ResolveTenantRequest) -> AppResult<String> {
+        let query = tenant_mapper::to_resolve_tenant_query(req)?;
+        let tenant = self.resolve_tenant.handle(query).await?;
+        Ok(tenant.id.to_string())
+    }
+}
@@ -1,18 +1,21 @@
 use std::sync::Arc;
 
+use tradewinds_application::audit::audited;
 use tradewinds_application::commands::user::handlers::{
     AssignRoleHandler, CreateUserHandler, DeleteUserHandler, ResetPasswordHandler, RevokeRoleHandler, UpdateUserHandler,
 };
 use tradewinds_application::commands::user::*;
-use tradewinds_application::interfaces::{ISystemSettingService, IUserService};
+use tradewinds_application::interfaces::{IAuditLogService, ISystemSettingService, IUserService};
 use tradewinds_application::queries::user::handlers::{
-    GetUserByEmailHandler, GetUserByIdHandler, GetUserByUsernameHandler, GetUserPermissionsHandler,
-    GetUserRolesHandler, ListUsersHandler,
+    ExplainUserAccessHandler, GetUserByEmailHandler, GetUserByIdHandler, GetUserByUsernameHandler,
+    GetUserPermissionsHandler, GetUserRolesHandler, ListUsersHandler,
 };
 use tradewinds_application::queries::user::*;
 use tradewinds_application::{CommandHandler, QueryHandler};
 use tradewinds_common::PaginatedResult;
 use tradewinds_domain::entities::{permission::Permission, role::Role, user::User};
+use tradewinds_domain::policies::AccessExplanation;
+use tradewinds_domain::value_objects::RoleSource;
 use tradewinds_error::AppResult;
 
 #[rustfmt::skip]
@@ -32,42 +35,13 @@ pub struct UserController {
     get_user_by_id: Arc<dyn QueryHandler<GetUserByIdQuery, User>>,
     get_user_by_username: Arc<dyn QueryHandler<GetUserByUsernameQuery, User>>,
     get_user_by_email: Arc<dyn QueryHandler<GetUserByEmailQuery, User>>,
-    get_user_roles: Arc<dyn QueryHandler<GetUserRolesQuery, Vec<Role>>>,
+    get_user_roles: Arc<dyn QueryHandler<GetUserRolesQuery, Vec<(Role, Vec<RoleSource>)>>>,
     get_user_permissions: Arc<dyn QueryHandler<GetUserPermissionsQuery, Vec<Permission>>>,
+    explain_user_access: Arc<dyn QueryHandler<ExplainUserAccessQuery, AccessExplanation>>,
     list_users: Arc<dyn QueryHandler<ListUsersQuery, PaginatedResult<(User, Vec<Role>)>>>,
 }
 
 impl UserController {
-    pub fn new(
-        create_user: Arc<dyn CommandHandler<CreateUserCommand, User>>,
-        update_user: Arc<dyn CommandHandler<UpdateUserCommand, ()>>,
-        delete_user: Arc<dyn CommandHandler<DeleteUserCommand, ()>>,
-        assign_role: Arc<dyn CommandHandler<AssignRoleCommand, ()>>,
-        revoke_role: Arc<dyn CommandHandler<RevokeRoleCommand, ()>>,
-        reset_password: Arc<dyn CommandHandler<ResetPasswordCommand, ()>>,
-        get_user_by_id: Arc<dyn QueryHandler<GetUserByIdQuery, User>>,
-        get_user_by_username: Arc<dyn QueryHandler<GetUserByUsernameQuery, User>>,
-        get_user_by_email: Arc<dyn QueryHandler<GetUserByEmailQuery, User>>,
-        get_user_roles: Arc<dyn QueryHandler<GetUserRolesQuery, Vec<Role>>>,
-        get_user_permissions: Arc<dyn QueryHandler<GetUserPermissionsQuery, Vec<Permission>>>,
-        list_users: Arc<dyn QueryHandler<ListUsersQuery, PaginatedResult<(User, Vec<Role>)>>>,
-    ) -> Self {
-        Self {
-            create_user,
-            update_user,
-            delete_user,
-            assign_role,
-            revoke_role,
-            reset_password,
-            get_user_by_id,
-            get_user_by_username,
-            get_user_by_email,
-            get_user_roles,
-            get_user_permissions,
-            list_users,
-        }
-    }
-
     /// 创建用户
     pub async fn create_user(&self, actor_id: String, req: CreateUserRequest) -> AppResult<CreateUserResponse> {
         let command = user_mapper::to_create_user_command(actor_id, req)?;
@@ -111,23 +85,35 @@ impl UserController {
     }
 
     /// 根据ID获取用户
-    pub async fn get_user_by_id(&self, req: GetUserByIdRequest) -> AppResult<GetUserByIdResponse> {
-        let query = user_mapper::to_get_user_by_id_query(req)?;
+    pub async fn get_user_by_id(
+        &self,
+        principal_id: String,
+        req: GetUserByIdRequest,
+    ) -> AppResult<GetUserByIdResponse> {
+        let query = user_mapper::to_get_user_by_id_query(principal_id, req)?;
         let user = self.get_user_by_id.handle(query).await?;
         Ok(GetUserByIdResponse { user: user.into() })
     }
 
     /// 根据用户名获取用户
-    pub async fn get_user_by_username(&self, req: GetUserByUsernameRequest) -> AppResult<GetUserByUsernameResponse> {
-        let query = user_mapper::to_get_user_by_username_query(req)?;
+    pub async fn get_user_by_username(
+        &self,
+        principal_id: String,
+        req: GetUserByUsernameRequest,
+    ) -> AppResult<GetUserByUsernameResponse> {
+        let query = user_mapper::to_get_user_by_username_query(principal_id, req)?;
         let user = self.get_user_by_username.handle(query).await?;
         Ok(GetUserByUsernameResponse { user: user.into() })
     }
 
     /// 根据邮箱获取用户
-    pub async fn get_user_by_email(&self, req: GetUserByEmailRequest) -> AppResult<GetUserByEmailResponse> {
+    pub async fn get_user_by_email(
+        &self,
+        principal_id: String,
+        req: GetUserByEmailRequest,
+    ) -> AppResult<GetUserByEmailResponse> {
         // FIXME: 需要验证邮箱是否存在
-        let query = user_mapper::to_get_user_by_email_query(req)?;
+        let query = user_mapper::to_get_user_by_email_query(principal_id, req)?;
         let user = self.get_user_by_email.handle(query).await?;
         Ok(GetUserByEmailResponse { user: user.into() })
     }
@@ -146,9 +132,16 @@ impl UserController {
         Ok(GetUserPermissionsResponse { permissions: permissions.into_iter().map(Into::into).collect() })
     }
 
+    /// 说明用户为何拥有或缺少某个权限
+    pub async fn explain_user_access(&self, req: ExplainUserAccessRequest) -> AppResult<ExplainUserAccessResponse> {
+        let query = user_mapper::to_explain_user_access_query(req)?;
+        let explanation = self.explain_user_access.handle(query).await?;
+        Ok(explanation.into())
+    }
+
     /// 获取用户列表
-    pub async fn list_users(&self, req: ListUsersRequest) -> AppResult<ListUsersResponse> {
-        let query = user_mapper::to_list_users_query(req)?;
+    pub async fn list_users(&self, principal_id: String, req: ListUsersRequest) -> AppResult<ListUsersResponse> {
+        let query = user_mapper::to_list_users_query(principal_id, req)?;
         let result = self.list_users.handle(query).await?;
         let users = result.items.into_iter().map(|(user, roles)| UserWithRolesResponse::new(user, roles)).collect();
         Ok(ListUsersResponse { users, total: result.total })
@@ -157,20 +150,25 @@ impl UserController {
     pub fn assemble(
         user_service: Arc<dyn IUserService>,
         system_setting_service: Arc<dyn ISystemSettingService>,
+        audit_log_service: Arc<dyn IAuditLogService>,
     ) -> Self {
-        Self::new(
-            Arc::new(CreateUserHandler::new(user_service.clone())),
-            Arc::new(UpdateUserHandler::new(user_service.clone())),
-            Arc::new(DeleteUserHandler::new(user_service.clone())),
-            Arc::new(AssignRoleHandler::new(user_service.clone())),
-            Arc::new(RevokeRoleHandler::new(user_service.clone())),
-            Arc::new(ResetPasswordHandler::new(user_service.clone(), system_setting_service.clone())),
-            Arc::new(GetUserByIdHandler::new(user_service.clone())),
-            Arc::new(GetUserByUsernameHandler::new(user_service.clone())),
-            Arc::new(GetUserByEmailHandler::new(user_service.clone())),
-            Arc::new(GetUserRolesHandler::new(user_service.clone())),
-            Arc::new(GetUserPermissionsHandler::new(user_service.clone())),
-            Arc::new(ListUsersHandler::new(user_service.clone())),
-        )
+        Self {
+            create_user: audited(CreateUserHandler::new(user_service.clone()), &audit_log_service),
+            update_user: audited(UpdateUserHandler::new(user_service.clone()), &audit_log_service),
+            delete_user: audited(DeleteUserHandler::new(user_service.clone()), &audit_log_service),
+            assign_role: audited(AssignRoleHandler::new(user_service.clone()), &audit_log_service),
+            revoke_role: audited(RevokeRoleHandler::new(user_service.clone()), &audit_log_service),
+            reset_password: audited(
+                ResetPasswordHandler::new(user_service.clone(), system_setting_service.clone()),
+                &audit_log_service,
+            ),
+            get_user_by_id: Arc::new(GetUserByIdHandler::new(user_service.clone())),
+            get_user_by_username: Arc::new(GetUserByUsernameHandler::new(user_service.clone())),
+            get_user_by_email: Arc::new(GetUserByEmailHandler::new(user_service.clone())),
+            get_user_roles: Arc::new(GetUserRolesHandler::new(user_service.clone())),
+            get_user_permissions: Arc::new(GetUserPermissionsHandler::new(user_service.clone())),
+            explain_user_access: Arc::new(ExplainUserAccessHandler::new(user_service.clone())),
+            list_users: Arc::new(ListUsersHandler::new(user_service.clone())),
+        }
     }
 }
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use tradewinds_application::audit::audited;
+use tradewinds_application::commands::webhook::{
+    CreateWebhookCommand, CreateWebhookHandler, DeleteWebhookCommand, DeleteWebhookHandler,
+    RedeliverWebhookDeliveryCommand, RedeliverWebhookDeliveryHandler, UpdateWebhookCommand, UpdateWebhookHandler,
+};
+use tradewinds_application::interfaces::{IAuditLogService, IWebhookService};
+use tradewinds_application::queries::webhook::{
+    ListWebhookDeliveriesHandler, ListWebhookDeliveriesQuery, ListWebhooksHandler, ListWebhooksQuery,
+};
+use tradewinds_application::{CommandHandler, QueryHandler};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::{Webhook, WebhookDelivery};
+use tradewinds_error::AppResult;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::webhook_dto::*,
+    mappers::webhook_mapper,
+};
+
+/// Webhook 控制器
+pub struct WebhookController {
+    create_webhook: Arc<dyn CommandHandler<CreateWebhookCommand, Webhook>>,
+    update_webhook: Arc<dyn CommandHandler<UpdateWebhookCommand, ()>>,
+    delete_webhook: Arc<dyn CommandHandler<DeleteWebhookCommand, ()>>,
+    list_webhooks: Arc<dyn QueryHandler<ListWebhooksQuery, Vec<Webhook>>>,
+    list_deliveries: Arc<dyn QueryHandler<ListWebhookDeliveriesQuery, PaginatedResult<WebhookDelivery>>>,
+    redeliver: Arc<dyn CommandHandler<RedeliverWebhookDeliveryCommand, WebhookDelivery>>,
+}
+
+impl WebhookController {
+    pub fn new(
+        create_webhook: Arc<dyn CommandHandler<CreateWebhookCommand, Webhook>>,
+        update_webhook: Arc<dyn CommandHandler<UpdateWebhookCommand, ()>>,
+        delete_webhook: Arc<dyn CommandHandler<DeleteWebhookCommand, ()>>,
+        list_webhooks: Arc<dyn QueryHandler<ListWebhooksQuery, Vec<Webhook>>>,
+        list_deliveries: Arc<dyn QueryHandler<ListWebhookDeliveriesQuery, PaginatedResult<WebhookDelivery>>>,
+        redeliver: Arc<dyn CommandHandler<RedeliverWebhookDeliveryCommand, WebhookDelivery>>,
+    ) -> Self {
+        Self { create_webhook, update_webhook, delete_webhook, list_webhooks, list_deliveries, redeliver }
+    }
+
+    pub fn assemble(webhook_service: Arc<dyn IWebhookService>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self::new(
+            audited(CreateWebhookHandler::new(webhook_service.clone()), &audit_log_service),
+            audited(UpdateWebhookHandler::new(webhook_service.clone()), &audit_log_service),
+            audited(DeleteWebhookHandler::new(webhook_service.clone()), &audit_log_service),
+            Arc::new(ListWebhooksHandler::new(webhook_service.clone())),
+            Arc::new(ListWebhookDeliveriesHandler::new(webhook_service.clone())),
+            audited(RedeliverWebhookDeliveryHandler::new(webhook_service.clone()), &audit_log_service),
+        )
+    }
+
+    pub async fn create_webhook(
+        &self,
+        actor_id: String,
+        req: CreateWebhookRequest,
+    ) -> AppResult<CreateWebhookResponse> {
+        let command = webhook_mapper::to_create_webhook_command(actor_id, req)?;
+        let webhook = self.create_webhook.handle(command).await?;
+        let secret = webhook.secret.clone();
+        Ok(CreateWebhookResponse { webhook: webhook.into(), secret })
+    }
+
+    pub async fn update_webhook(&self, actor_id: String, req: UpdateWebhookRequest) -> AppResult<()> {
+        let command = webhook_mapper::to_update_webhook_command(actor_id, req)?;
+        self.update_webhook.handle(command).await
+    }
+
+    pub async fn delete_webhook(&self, actor_id: String, id: String) -> AppResult<()> {
+        let command = webhook_mapper::to_delete_webhook_command(actor_id, id)?;
+        self.delete_webhook.handle(command).await
+    }
+
+    pub async fn list_webhooks(&self) -> AppResult<ListWebhooksResponse> {
+        let webhooks = self.list_webhooks.handle(ListWebhooksQuery).await?;
+        Ok(ListWebhooksResponse { webhooks: webhooks.into_iter().map(Into::into).collect() })
+    }
+
+    pub async fn list_deliveries(
+        &self,
+        webhook_id: String,
+        filter: WebhookDeliveryFilterRequest,
+        req: ListWebhookDeliveriesRequest,
+    ) -> AppResult<ListWebhookDeliveriesResponse> {
+        let query = webhook_mapper::to_list_webhook_deliveries_query(webhook_id, filter, req)?;
+        let result = self.list_deliveries.handle(query).await?;
+        Ok(ListWebhookDeliveriesResponse {
+            deliveries: result.items.into_iter().map(Into::into).collect(),
+            total: result.total,
+        })
+    }
+
+    /// 以原请求体重新投递
+    pub async fn redeliver(&self, id: String, actor_id: String) -> AppResult<RedeliverWebhookResponse> {
+        let command = webhook_mapper::to_redeliver_command(id, actor_id)?;
+        let delivery = self.redeliver.handle(command).await?;
+        Ok(RedeliverWebhookResponse { delivery: delivery.into() })
+    }
+}
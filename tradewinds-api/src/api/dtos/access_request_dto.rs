@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::dtos::user_dto::UserResponse;
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::access_request::AccessRequest;
+
+/// 提交权限申请请求
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitAccessRequestRequest {
+    #[serde(rename = "roleId")]
+    pub role_id: String,
+    pub justification: String,
+    /// 授权时长（天）
+    #[serde(rename = "durationDays")]
+    pub duration_days: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SubmitAccessRequestResponse {
+    pub request: AccessRequestResponse,
+}
+
+/// 批准或驳回权限申请请求
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DecideAccessRequestRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApproveAccessRequestResponse;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RejectAccessRequestResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct CancelAccessRequestRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CancelAccessRequestResponse;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExpireAccessRequestsResponse {
+    /// 本次过期的申请数
+    pub expired: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetAccessRequestByIdRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetAccessRequestByIdResponse {
+    pub request: AccessRequestResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessRequestsRequest {
+    /// mine（默认）/ approvals / all
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub scope: Option<String>,
+    pub status: Option<i32>,
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessRequestsResponse {
+    pub requests: Vec<AccessRequestResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetRoleApproversRequest {
+    pub role_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetRoleApproversResponse {
+    pub approvers: Vec<UserResponse>,
+}
+
+/// 设置角色审批人请求，整体替换
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetRoleApproversRequest {
+    #[serde(default)]
+    pub role_id: String,
+    #[serde(rename = "userIds")]
+    pub user_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SetRoleApproversResponse;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessRequestResponse {
+    pub id: String,
+    #[serde(rename = "requesterId")]
+    pub requester_id: String,
+    #[serde(rename = "roleId")]
+    pub role_id: String,
+    pub justification: String,
+    #[serde(rename = "durationDays")]
+    pub duration_days: u32,
+    pub status: String,
+    #[serde(rename = "decidedBy")]
+    pub decided_by: Option<String>,
+    #[serde(rename = "decisionComment")]
+    pub decision_comment: Option<String>,
+    #[serde(rename = "decidedAt")]
+    pub decided_at: Option<i64>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: i64,
+    #[serde(rename = "grantExpiresAt")]
+    pub grant_expires_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<AccessRequest> for AccessRequestResponse {
+    fn from(request: AccessRequest) -> Self {
+        Self {
+            id: request.id.to_string(),
+            requester_id: request.requester_id.to_string(),
+            role_id: request.role_id.to_string(),
+            justification: request.justification.to_string(),
+            duration_days: request.duration.days(),
+            status: request.status.to_string(),
+            decided_by: request.decided_by.map(|v| v.to_string()),
+            decision_comment: request.decision_comment,
+            decided_at: request.decided_at,
+            expires_at: request.expires_at,
+            grant_expires_at: request.grant_expires_at,
+            created_at: request.created_at,
+            updated_at: request.updated_at,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_domain::entities::access_review::AccessReviewItem;
+use tradewinds_domain::value_objects::ReviewDecision;
+
+/// 发起访问复核活动请求
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LaunchAccessReviewRequest {
+    pub name: String,
+    #[serde(rename = "roleIds")]
+    pub role_ids: Vec<String>,
+    /// 截止时间（Unix 时间戳，秒）
+    pub deadline: i64,
+    /// 兜底复核人，角色未配置审批人时使用，缺省为发起人
+    #[serde(rename = "reviewerId", default, deserialize_with = "empty_string_as_none")]
+    pub reviewer_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LaunchAccessReviewResponse {
+    pub review: AccessReviewResponse,
+}
+
+/// 复核项决定请求
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DecideAccessReviewItemRequest {
+    #[serde(default)]
+    pub review_id: String,
+    #[serde(default)]
+    pub item_id: String,
+    /// confirm / revoke
+    pub decision: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DecideAccessReviewItemResponse;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloseDueAccessReviewsResponse {
+    /// 本次完成的活动数
+    pub completed: u64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetAccessReviewByIdRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetAccessReviewByIdResponse {
+    pub review: AccessReviewResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessReviewsRequest {
+    /// assigned（默认）/ all
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub scope: Option<String>,
+    pub status: Option<i32>,
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessReviewsResponse {
+    pub reviews: Vec<AccessReviewResponse>,
+    pub total: u64,
+}
+
+/// 查询复核项请求，`mine` 为 true 时仅返回指派给当前用户的复核项
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessReviewItemsRequest {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub mine: bool,
+    pub decision: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAccessReviewItemsResponse {
+    pub items: Vec<AccessReviewItemResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GetAccessReviewReportRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessReviewResponse {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "roleIds")]
+    pub role_ids: Vec<String>,
+    pub deadline: i64,
+    pub status: String,
+    #[serde(rename = "createdBy")]
+    pub created_by: String,
+    #[serde(rename = "completedAt")]
+    pub completed_at: Option<i64>,
+    /// 复核进度
+    pub progress: AccessReviewProgress,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AccessReviewProgress {
+    pub total: usize,
+    pub pending: usize,
+    pub confirmed: usize,
+    pub revoked: usize,
+    #[serde(rename = "autoRevoked")]
+    pub auto_revoked: usize,
+}
+
+impl From<AccessReviewAggregate> for AccessReviewResponse {
+    fn from(aggregate: AccessReviewAggregate) -> Self {
+        let mut progress = AccessReviewProgress { total: aggregate.items.len(), ..Default::default() };
+        for item in &aggregate.items {
+            match item.decision {
+                ReviewDecision::Pending => progress.pending += 1,
+                ReviewDecision::Confirmed => progress.confirmed += 1,
+                ReviewDecision::Revoked => progress.revoked += 1,
+                ReviewDecision::AutoRevoked => progress.auto_revoked += 1,
+            }
+        }
+        let campaign = aggregate.campaign;
+        Self {
+            id: campaign.id.to_string(),
+            name: campaign.name.to_string(),
+            role_ids: campaign.role_ids.iter().map(|r| r.to_string()).collect(),
+            deadline: campaign.deadline,
+            status: campaign.status.to_string(),
+            created_by: campaign.created_by.to_string(),
+            completed_at: campaign.completed_at,
+            progress,
+            created_at: campaign.created_at,
+            updated_at: campaign.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessReviewItemResponse {
+    pub id: String,
+    #[serde(rename = "userId")]
+    pub user_id: String,
+    #[serde(rename = "roleId")]
+    pub role_id: String,
+    #[serde(rename = "reviewerId")]
+    pub reviewer_id: String,
+    pub decision: String,
+    pub comment: Option<String>,
+    #[serde(rename = "decidedAt")]
+    pub decided_at: Option<i64>,
+}
+
+impl From<AccessReviewItem> for AccessReviewItemResponse {
+    fn from(item: AccessReviewItem) -> Self {
+        Self {
+            id: item.id.to_string(),
+            user_id: item.user_id.to_string(),
+            role_id: item.role_id.to_string(),
+            reviewer_id: item.reviewer_id.to_string(),
+            decision: item.decision.to_string(),
+            comment: item.comment,
+            decided_at: item.decided_at,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::audit_log::AuditLog;
+
+/// 审计日志查询条件
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct AuditLogFilterRequest {
+    #[serde(rename = "actorId", default, deserialize_with = "empty_string_as_none")]
+    pub actor_id: Option<String>,
+    /// 操作名称前缀，如 `user.` 或 `user.create`
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub action: Option<String>,
+    #[serde(rename = "targetType", default, deserialize_with = "empty_string_as_none")]
+    pub target_type: Option<String>,
+    #[serde(rename = "targetId", default, deserialize_with = "empty_string_as_none")]
+    pub target_id: Option<String>,
+    /// success / failure
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub outcome: Option<String>,
+    /// 起始时间（Unix 时间戳，秒，含）
+    pub from: Option<i64>,
+    /// 截止时间（Unix 时间戳，秒，不含）
+    pub to: Option<i64>,
+}
+
+/// 审计日志分页参数
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAuditLogsRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListAuditLogsResponse {
+    pub logs: Vec<AuditLogResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditLogResponse {
+    pub id: String,
+    #[serde(rename = "actorId")]
+    pub actor_id: Option<String>,
+    #[serde(rename = "actorName")]
+    pub actor_name: Option<String>,
+    pub action: String,
+    #[serde(rename = "targetType")]
+    pub target_type: String,
+    #[serde(rename = "targetId")]
+    pub target_id: Option<String>,
+    pub params: Option<Value>,
+    pub changes: Option<Value>,
+    #[serde(rename = "clientIp")]
+    pub client_ip: Option<String>,
+    #[serde(rename = "userAgent")]
+    pub user_agent: Option<String>,
+    #[serde(rename = "requestId")]
+    pub request_id: Option<String>,
+    pub outcome: String,
+    #[serde(rename = "errorMessage")]
+    pub error_message: Option<String>,
+    pub created_at: i64,
+    pub seq: u64,
+    pub hash: String,
+}
+
+impl From<AuditLog> for AuditLogResponse {
+    fn from(log: AuditLog) -> Self {
+        Self {
+            id: log.id.to_string(),
+            actor_id: log.actor_id.map(|id| id.to_string()),
+            actor_name: log.actor_name,
+            action: log.action,
+            target_type: log.target_type,
+            target_id: log.target_id,
+            params: log.params,
+            changes: log.changes,
+            client_ip: log.client_ip,
+            user_agent: log.user_agent,
+            request_id: log.request_id,
+            outcome: log.outcome.to_string(),
+            error_message: log.error_message,
+            created_at: log.created_at,
+            seq: log.seq,
+            hash: log.hash,
+        }
+    }
+}
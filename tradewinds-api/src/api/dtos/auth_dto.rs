@@ -1,10 +1,13 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use tradewinds_application::queries::auth::menu_info::MenuInfo;
+use tradewinds_domain::entities::audit_log::AuditLog;
 
 #[rustfmt::skip]
 use crate::api::dtos::{
     user_dto::UserResponse,
+    login_log_dto::LoginLogResponse,
     role_dto::RoleResponse, 
     permission_dto::PermissionResponse, 
 };
@@ -78,6 +81,9 @@ pub struct GetCurrentUserRequest {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GetCurrentUserResponse {
     pub user: CurrentUserInfoResponse,
+    /// 全部功能开关对当前用户是否开启
+    #[serde(rename = "featureFlags")]
+    pub feature_flags: BTreeMap<String, bool>,
 }
 
 // 验证令牌请求
@@ -111,6 +117,20 @@ pub struct GetUserMenusResponse {
     pub menus: Vec<MenuResponse>,
 }
 
+// 获取当前用户登录历史
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetLoginHistoryRequest {
+    pub token: String,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetLoginHistoryResponse {
+    pub logs: Vec<LoginLogResponse>,
+    pub total: u64,
+}
+
 // 超级管理员仪表盘数据
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetSuperAdminDashboardRequest {
@@ -131,7 +151,8 @@ pub struct SystemStats {
     pub active_users: u64,
     pub total_roles: u64,
     pub total_permissions: u64,
-    pub database_size: String,
+    /// 暂未采集
+    pub database_size: Option<String>,
     pub uptime: String,
 }
 
@@ -140,7 +161,8 @@ pub struct UserStats {
     pub new_users_today: u64,
     pub new_users_this_week: u64,
     pub new_users_this_month: u64,
-    pub active_sessions: u64,
+    /// 令牌为无状态 JWT，暂无会话统计
+    pub active_sessions: Option<u64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -155,14 +177,34 @@ pub struct RecentActivity {
     pub user_agent: String,
 }
 
+impl From<AuditLog> for RecentActivity {
+    fn from(log: AuditLog) -> Self {
+        let resource = match &log.target_id {
+            Some(target_id) => format!("{}:{}", log.target_type, target_id),
+            None => log.target_type.clone(),
+        };
+        Self {
+            id: log.id.to_string(),
+            user_id: log.actor_id.map(|id| id.to_string()).unwrap_or_default(),
+            username: log.actor_name.unwrap_or_default(),
+            action: log.action,
+            resource,
+            timestamp: log.created_at,
+            ip_address: log.client_ip.unwrap_or_default(),
+            user_agent: log.user_agent.unwrap_or_default(),
+        }
+    }
+}
+
+/// 系统健康状况，未采集的指标为空
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SystemHealth {
-    pub cpu_usage: f64,
-    pub memory_usage: f64,
-    pub disk_usage: f64,
+    pub cpu_usage: Option<f64>,
+    pub memory_usage: Option<f64>,
+    pub disk_usage: Option<f64>,
     pub database_status: String,
-    pub redis_status: String,
-    pub rabbitmq_status: String,
+    pub redis_status: Option<String>,
+    pub rabbitmq_status: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_common::utils::{empty_string_as_none, null_string_as_none_nested};
+use tradewinds_domain::entities::department::Department;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateDepartmentRequest {
+    pub name: String,
+    #[serde(rename = "parentId", default, deserialize_with = "empty_string_as_none")]
+    pub parent_id: Option<String>,
+    pub sort: Option<i32>,
+    #[serde(rename = "leaderId", default, deserialize_with = "empty_string_as_none")]
+    pub leader_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateDepartmentResponse {
+    pub department: DepartmentResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateDepartmentRequest {
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(rename = "parentId", default, deserialize_with = "null_string_as_none_nested")]
+    pub parent_id: Option<Option<String>>,
+    pub sort: Option<i32>,
+    #[serde(rename = "leaderId", default, deserialize_with = "null_string_as_none_nested")]
+    pub leader_id: Option<Option<String>>,
+    pub status: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateDepartmentResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteDepartmentRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteDepartmentResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct GetDepartmentByIdRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetDepartmentByIdResponse {
+    pub department: DepartmentResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDepartmentsRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+    pub name: Option<String>,
+    #[serde(rename = "parentId", default, deserialize_with = "empty_string_as_none")]
+    pub parent_id: Option<String>,
+    pub status: Option<i32>,
+    #[serde(rename = "showDeleted")]
+    pub show_deleted: Option<bool>,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListDepartmentsResponse {
+    pub departments: Vec<DepartmentResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DepartmentResponse {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<String>,
+    pub sort: i32,
+    #[serde(rename = "leaderId")]
+    pub leader_id: Option<String>,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<Department> for DepartmentResponse {
+    fn from(department: Department) -> Self {
+        Self {
+            id: department.id.to_string(),
+            name: department.name.to_string(),
+            parent_id: department.parent_id.map(|p| p.to_string()),
+            sort: department.sort.value(),
+            leader_id: department.leader_id.map(|l| l.to_string()),
+            status: department.status.to_string(),
+            created_at: department.created_at,
+            updated_at: department.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct DepartmentTreeResponse {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "parentId")]
+    pub parent_id: Option<String>,
+    pub sort: i32,
+    #[serde(rename = "leaderId")]
+    pub leader_id: Option<String>,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub children: Vec<DepartmentTreeResponse>,
+}
+
+impl DepartmentTreeResponse {
+    /// 将扁平部门列表组装为树，同级按 sort 升序；上级部门不在列表中的节点作为根节点
+    pub fn build_tree(flat: Vec<DepartmentTreeResponse>) -> Vec<DepartmentTreeResponse> {
+        let ids: std::collections::HashSet<String> = flat.iter().map(|node| node.id.clone()).collect();
+        let mut children: std::collections::HashMap<String, Vec<DepartmentTreeResponse>> =
+            std::collections::HashMap::new();
+        let mut roots = Vec::new();
+        for node in flat {
+            match node.parent_id.clone().filter(|parent_id| ids.contains(parent_id)) {
+                Some(parent_id) => children.entry(parent_id).or_default().push(node),
+                None => roots.push(node),
+            }
+        }
+        Self::attach(&mut roots, &mut children);
+        roots
+    }
+
+    fn attach(
+        nodes: &mut [DepartmentTreeResponse],
+        children: &mut std::collections::HashMap<String, Vec<DepartmentTreeResponse>>,
+    ) {
+        nodes.sort_by_key(|node| node.sort);
+        for node in nodes.iter_mut() {
+            if let Some(mut sub) = children.remove(&node.id) {
+                Self::attach(&mut sub, children);
+                node.children = sub;
+            }
+        }
+    }
+}
+
+impl From<Department> for DepartmentTreeResponse {
+    fn from(department: Department) -> Self {
+        DepartmentTreeResponse {
+            id: department.id.to_string(),
+            name: department.name.to_string(),
+            parent_id: department.parent_id.map(|p| p.to_string()),
+            sort: department.sort.value(),
+            leader_id: department.leader_id.map(|l| l.to_string()),
+            status: department.status.to_string(),
+            created_at: department.created_at,
+            updated_at: department.updated_at,
+            children: Vec::new(),
+        }
+    }
+}
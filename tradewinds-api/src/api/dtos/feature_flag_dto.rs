@@ -0,0 +1,72 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::entities::FeatureFlag;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateFeatureFlagRequest {
+    pub key: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub enabled: bool,
+    /// 灰度比例，0-100
+    #[serde(rename = "rolloutPercentage", default)]
+    pub rollout_percentage: u8,
+    /// 白名单角色编码
+    #[serde(rename = "allowedRoles", default)]
+    pub allowed_roles: Vec<String>,
+    /// 白名单租户ID
+    #[serde(rename = "allowedTenants", default)]
+    pub allowed_tenants: Vec<String>,
+}
+
+/// 更新开关请求，未传的字段保持不变
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateFeatureFlagRequest {
+    /// 取自路径参数
+    #[serde(default)]
+    pub key: String,
+    pub description: Option<String>,
+    pub enabled: Option<bool>,
+    #[serde(rename = "rolloutPercentage")]
+    pub rollout_percentage: Option<u8>,
+    #[serde(rename = "allowedRoles")]
+    pub allowed_roles: Option<Vec<String>>,
+    #[serde(rename = "allowedTenants")]
+    pub allowed_tenants: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListFeatureFlagsResponse {
+    pub flags: Vec<FeatureFlagResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FeatureFlagResponse {
+    pub key: String,
+    pub description: String,
+    pub enabled: bool,
+    #[serde(rename = "rolloutPercentage")]
+    pub rollout_percentage: u8,
+    #[serde(rename = "allowedRoles")]
+    pub allowed_roles: Vec<String>,
+    #[serde(rename = "allowedTenants")]
+    pub allowed_tenants: Vec<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<FeatureFlag> for FeatureFlagResponse {
+    fn from(flag: FeatureFlag) -> Self {
+        Self {
+            key: flag.key,
+            description: flag.description,
+            enabled: flag.enabled,
+            rollout_percentage: flag.rollout_percentage,
+            allowed_roles: flag.allowed_roles,
+            allowed_tenants: flag.allowed_tenants,
+            created_at: flag.created_at,
+            updated_at: flag.updated_at,
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::dtos::user_dto::UserResponse;
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_domain::entities::group::Group;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateGroupRequest {
+    pub name: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub description: Option<String>,
+    #[serde(rename = "roleIds", default)]
+    pub role_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateGroupResponse {
+    pub group: GroupResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateGroupRequest {
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<i32>,
+    #[serde(rename = "roleIds")]
+    pub role_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateGroupResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteGroupRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeleteGroupResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct GetGroupByIdRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetGroupByIdResponse {
+    pub group: GroupDetailResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListGroupsRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+    pub name: Option<String>,
+    pub status: Option<i32>,
+    #[serde(rename = "showDeleted")]
+    pub show_deleted: Option<bool>,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListGroupsResponse {
+    pub groups: Vec<GroupResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddGroupMembersRequest {
+    #[serde(skip)]
+    pub group_id: String,
+    #[serde(rename = "userIds")]
+    pub user_ids: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AddGroupMembersResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct RemoveGroupMemberRequest {
+    pub group_id: String,
+    pub user_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RemoveGroupMemberResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct ListGroupMembersRequest {
+    pub group_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListGroupMembersResponse {
+    pub members: Vec<UserResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupResponse {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<Group> for GroupResponse {
+    fn from(group: Group) -> Self {
+        Self {
+            id: group.id.to_string(),
+            name: group.name.to_string(),
+            description: group.description.map(|d| d.to_string()),
+            status: group.status.to_string(),
+            created_at: group.created_at,
+            updated_at: group.updated_at,
+        }
+    }
+}
+
+/// 用户组详情：附带携带的角色与成员
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GroupDetailResponse {
+    #[serde(flatten)]
+    pub group: GroupResponse,
+    #[serde(rename = "roleIds")]
+    pub role_ids: Vec<String>,
+    #[serde(rename = "memberIds")]
+    pub member_ids: Vec<String>,
+}
+
+impl From<GroupAggregate> for GroupDetailResponse {
+    fn from(aggregate: GroupAggregate) -> Self {
+        Self {
+            group: aggregate.group.into(),
+            role_ids: aggregate.roles.iter().map(|r| r.to_string()).collect(),
+            member_ids: aggregate.members.iter().map(|m| m.to_string()).collect(),
+        }
+    }
+}
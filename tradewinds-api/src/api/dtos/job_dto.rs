@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::{JobRun, ScheduledJob};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListJobsResponse {
+    pub jobs: Vec<JobResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobResponse {
+    pub name: String,
+    pub description: String,
+    pub cron: String,
+    pub paused: bool,
+    #[serde(rename = "nextRunAt")]
+    pub next_run_at: Option<i64>,
+    #[serde(rename = "lastRunAt")]
+    pub last_run_at: Option<i64>,
+    #[serde(rename = "lastStatus")]
+    pub last_status: Option<String>,
+    pub updated_at: i64,
+}
+
+impl From<ScheduledJob> for JobResponse {
+    fn from(job: ScheduledJob) -> Self {
+        Self {
+            name: job.name,
+            description: job.description,
+            cron: job.cron,
+            paused: job.paused,
+            next_run_at: job.next_run_at,
+            last_run_at: job.last_run_at,
+            last_status: job.last_status.map(|status| status.to_string()),
+            updated_at: job.updated_at,
+        }
+    }
+}
+
+/// 执行记录查询条件
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct JobRunFilterRequest {
+    #[serde(rename = "jobName", default, deserialize_with = "empty_string_as_none")]
+    pub job_name: Option<String>,
+    /// running / succeeded / failed
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub status: Option<String>,
+}
+
+/// 执行记录分页参数
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListJobRunsRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListJobRunsResponse {
+    pub runs: Vec<JobRunResponse>,
+    pub total: u64,
+}
+
+/// 手动触发响应，任务在后台执行，返回的执行记录为执行中状态
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TriggerJobResponse {
+    pub run: JobRunResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobRunResponse {
+    pub id: String,
+    #[serde(rename = "jobName")]
+    pub job_name: String,
+    pub trigger: String,
+    pub status: String,
+    #[serde(rename = "triggeredBy")]
+    pub triggered_by: Option<String>,
+    pub instance: String,
+    #[serde(rename = "startedAt")]
+    pub started_at: i64,
+    #[serde(rename = "finishedAt")]
+    pub finished_at: Option<i64>,
+    #[serde(rename = "durationMs")]
+    pub duration_ms: Option<u64>,
+    pub affected: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl From<JobRun> for JobRunResponse {
+    fn from(run: JobRun) -> Self {
+        Self {
+            id: run.id.to_string(),
+            job_name: run.job_name,
+            trigger: run.trigger.to_string(),
+            status: run.status.to_string(),
+            triggered_by: run.triggered_by.map(|id| id.to_string()),
+            instance: run.instance,
+            started_at: run.started_at,
+            finished_at: run.finished_at,
+            duration_ms: run.duration_ms,
+            affected: run.affected,
+            error: run.error,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::login_log::LoginLog;
+
+/// 登录日志查询条件
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LoginLogFilterRequest {
+    #[serde(rename = "userId", default, deserialize_with = "empty_string_as_none")]
+    pub user_id: Option<String>,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub username: Option<String>,
+    pub success: Option<bool>,
+    #[serde(rename = "clientIp", default, deserialize_with = "empty_string_as_none")]
+    pub client_ip: Option<String>,
+    /// 起始时间（Unix 时间戳，秒，含）
+    pub from: Option<i64>,
+    /// 截止时间（Unix 时间戳，秒，不含）
+    pub to: Option<i64>,
+}
+
+/// 登录日志分页参数
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListLoginLogsRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListLoginLogsResponse {
+    pub logs: Vec<LoginLogResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurgeLoginLogsResponse {
+    /// 本次删除的日志条数
+    pub purged: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoginLogResponse {
+    pub id: String,
+    #[serde(rename = "userId")]
+    pub user_id: Option<String>,
+    pub username: String,
+    pub method: String,
+    pub success: bool,
+    #[serde(rename = "failureReason")]
+    pub failure_reason: Option<String>,
+    #[serde(rename = "clientIp")]
+    pub client_ip: Option<String>,
+    #[serde(rename = "userAgent")]
+    pub user_agent: Option<String>,
+    pub created_at: i64,
+}
+
+impl From<LoginLog> for LoginLogResponse {
+    fn from(log: LoginLog) -> Self {
+        Self {
+            id: log.id.to_string(),
+            user_id: log.user_id.map(|id| id.to_string()),
+            username: log.username,
+            method: log.method.to_string(),
+            success: log.success,
+            failure_reason: log.failure_reason.map(|r| r.to_string()),
+            client_ip: log.client_ip,
+            user_agent: log.user_agent,
+            created_at: log.created_at,
+        }
+    }
+}
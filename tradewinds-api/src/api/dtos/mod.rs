@@ -1,11 +1,35 @@
+pub mod access_request_dto;
+pub mod access_review_dto;
+pub mod audit_log_dto;
 pub mod auth_dto;
+pub mod department_dto;
+pub mod feature_flag_dto;
+pub mod group_dto;
+pub mod job_dto;
+pub mod login_log_dto;
+pub mod outbox_dto;
 pub mod permission_dto;
+pub mod policy_dto;
 pub mod role_dto;
+pub mod tenant_dto;
 pub mod user_dto;
+pub mod webhook_dto;
 pub mod system_setting_dto;
 
+pub use access_request_dto::*;
+pub use access_review_dto::*;
+pub use audit_log_dto::*;
 pub use auth_dto::*;
+pub use department_dto::*;
+pub use feature_flag_dto::*;
+pub use group_dto::*;
+pub use job_dto::*;
+pub use login_log_dto::*;
+pub use outbox_dto::*;
 pub use permission_dto::*;
+pub use policy_dto::*;
 pub use role_dto::*;
+pub use tenant_dto::*;
 pub use user_dto::*;
+pub use webhook_dto::*;
 pub use system_setting_dto::*;
\ No newline at end of file
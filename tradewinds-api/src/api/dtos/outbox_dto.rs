@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_application::queries::outbox::OutboxStats;
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::OutboxMessage;
+
+/// 发件箱消息查询条件
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct OutboxFilterRequest {
+    /// pending / published / dead
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub status: Option<String>,
+    #[serde(rename = "eventType", default, deserialize_with = "empty_string_as_none")]
+    pub event_type: Option<String>,
+    #[serde(rename = "aggregateId", default, deserialize_with = "empty_string_as_none")]
+    pub aggregate_id: Option<String>,
+    /// 仅查询滞留消息
+    pub stuck: Option<bool>,
+}
+
+/// 发件箱消息分页参数
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListOutboxMessagesRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListOutboxMessagesResponse {
+    pub messages: Vec<OutboxMessageResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OutboxMessageResponse {
+    pub id: String,
+    #[serde(rename = "aggregateType")]
+    pub aggregate_type: String,
+    #[serde(rename = "aggregateId")]
+    pub aggregate_id: String,
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: u32,
+    #[serde(rename = "nextAttemptAt")]
+    pub next_attempt_at: i64,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub published_at: Option<i64>,
+}
+
+impl From<OutboxMessage> for OutboxMessageResponse {
+    fn from(message: OutboxMessage) -> Self {
+        Self {
+            id: message.id.to_string(),
+            aggregate_type: message.aggregate_type,
+            aggregate_id: message.aggregate_id,
+            event_type: message.event_type,
+            payload: message.payload,
+            status: message.status.to_string(),
+            attempts: message.attempts,
+            next_attempt_at: message.next_attempt_at,
+            last_error: message.last_error,
+            created_at: message.created_at,
+            published_at: message.published_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OutboxStatsResponse {
+    pub pending: u64,
+    pub published: u64,
+    pub dead: u64,
+    #[serde(rename = "oldestPendingAt")]
+    pub oldest_pending_at: Option<i64>,
+    #[serde(rename = "publishedTotal")]
+    pub published_total: u64,
+    #[serde(rename = "failedTotal")]
+    pub failed_total: u64,
+    #[serde(rename = "deadLetteredTotal")]
+    pub dead_lettered_total: u64,
+    #[serde(rename = "lastRunAt")]
+    pub last_run_at: Option<i64>,
+}
+
+impl From<OutboxStats> for OutboxStatsResponse {
+    fn from(stats: OutboxStats) -> Self {
+        Self {
+            pending: stats.pending,
+            published: stats.published,
+            dead: stats.dead,
+            oldest_pending_at: stats.oldest_pending_at,
+            published_total: stats.relay.published_total,
+            failed_total: stats.relay.failed_total,
+            dead_lettered_total: stats.relay.dead_lettered_total,
+            last_run_at: stats.relay.last_run_at,
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{self, Deserialize, Deserializer, Serialize};
 
 use tradewinds_application::queries::auth::user_info::PermissionInfo;
+use tradewinds_common::utils::null_string_as_none_nested;
 use tradewinds_domain::entities::permission::Permission;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -61,6 +62,8 @@ pub struct PermissionResponse {
     pub path: Option<String>,
     pub sort: i32,
     pub status: String,
+    #[serde(rename = "builtIn")]
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -78,6 +81,7 @@ impl From<Permission> for PermissionResponse {
             icon: permission.icon.map(|i| i.to_string()),
             sort: *permission.sort,
             status: permission.status.to_string(),
+            built_in: permission.built_in,
             created_at: permission.created_at,
             updated_at: permission.updated_at,
         }
@@ -97,6 +101,7 @@ impl From<PermissionInfo> for PermissionResponse {
             icon: info.icon,
             sort: info.sort,
             status: info.status,
+            built_in: info.built_in,
             created_at: info.created_at,
             updated_at: info.updated_at,
         }
@@ -215,6 +220,8 @@ pub struct PermissionTreeResponse {
     pub path: Option<String>,
     pub sort: i32,
     pub status: String,
+    #[serde(rename = "builtIn")]
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
     pub children: Vec<PermissionTreeResponse>,
@@ -253,22 +260,10 @@ impl From<Permission> for PermissionTreeResponse {
             path: permission.path.map(|p| p.to_string()),
             sort: permission.sort.value(),
             status: permission.status.to_string(),
+            built_in: permission.built_in,
             created_at: permission.created_at,
             updated_at: permission.updated_at,
             children: Vec::new(),
         }
     }
 }
-
-// 嵌套Option的自定义反序列化，兼容字符串"null"和null
-fn null_string_as_none_nested<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    let opt = Option::<Option<String>>::deserialize(deserializer)?;
-    match opt {
-        None => Ok(Some(None)), // 兼容 null
-        Some(Some(ref s)) if s == "null" => Ok(Some(None)),
-        _ => Ok(opt),
-    }
-}
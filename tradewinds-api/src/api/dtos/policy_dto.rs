@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::dtos::user_dto::ExplainUserAccessResponse;
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::policies::{AbacDecision, AccessEvaluation, Attributes, PolicyOutcome};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePolicyRequest {
+    pub name: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub description: Option<String>,
+    /// 作用的操作（权限码，支持通配），须覆盖至少一个经过访问策略校验的操作，
+    /// 即 user:update、user:delete、user:password:reset、user:role:assign、user:role:revoke
+    pub action: String,
+    /// allow / deny
+    pub effect: String,
+    /// 条件表达式
+    pub condition: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePolicyResponse {
+    pub policy: PolicyResponse,
+}
+
+/// 更新访问策略请求
+///
+/// `description` 传空字符串时清空描述，不传时保持不变
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdatePolicyRequest {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub action: Option<String>,
+    pub effect: Option<String>,
+    pub condition: Option<String>,
+    pub status: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdatePolicyResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct DeletePolicyRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DeletePolicyResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct GetPolicyByIdRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetPolicyByIdResponse {
+    pub policy: PolicyResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPoliciesRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub keyword: Option<String>,
+    pub status: Option<i32>,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPoliciesResponse {
+    pub policies: Vec<PolicyResponse>,
+    pub total: u64,
+}
+
+/// 试运行访问判定请求
+///
+/// 未指定 `userId` 时以当前用户为主体；`environment` 覆盖按当前请求生成的环境属性（如 hour、ip）
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EvaluatePolicyRequest {
+    #[serde(rename = "userId", default, deserialize_with = "empty_string_as_none")]
+    pub user_id: Option<String>,
+    pub action: String,
+    #[serde(default)]
+    pub resource: Attributes,
+    #[serde(default)]
+    pub environment: Attributes,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EvaluatePolicyResponse {
+    pub action: String,
+    /// RBAC 与 ABAC 均通过时为 true
+    pub allowed: bool,
+    pub rbac: ExplainUserAccessResponse,
+    pub abac: AbacEvaluationResponse,
+    /// 实际参与求值的属性
+    pub subject: Attributes,
+    pub resource: Attributes,
+    pub environment: Attributes,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AbacEvaluationResponse {
+    /// not_applicable / permit / deny
+    pub decision: String,
+    pub policies: Vec<PolicyOutcomeResponse>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PolicyOutcomeResponse {
+    #[serde(rename = "policyId")]
+    pub policy_id: String,
+    pub name: String,
+    pub effect: String,
+    pub matched: bool,
+    pub error: Option<String>,
+}
+
+impl From<AccessEvaluation> for EvaluatePolicyResponse {
+    fn from(evaluation: AccessEvaluation) -> Self {
+        let decision = match evaluation.abac.decision {
+            AbacDecision::NotApplicable => "not_applicable",
+            AbacDecision::Permit => "permit",
+            AbacDecision::Deny => "deny",
+        };
+        Self {
+            action: evaluation.context.action.to_string(),
+            allowed: evaluation.allowed,
+            rbac: evaluation.rbac.into(),
+            abac: AbacEvaluationResponse {
+                decision: decision.to_string(),
+                policies: evaluation.abac.outcomes.into_iter().map(Into::into).collect(),
+            },
+            subject: evaluation.context.subject,
+            resource: evaluation.context.resource,
+            environment: evaluation.context.environment,
+        }
+    }
+}
+
+impl From<PolicyOutcome> for PolicyOutcomeResponse {
+    fn from(outcome: PolicyOutcome) -> Self {
+        Self {
+            policy_id: outcome.policy_id.to_string(),
+            name: outcome.name.to_string(),
+            effect: outcome.effect.to_string(),
+            matched: outcome.matched,
+            error: outcome.error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PolicyResponse {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub action: String,
+    pub effect: String,
+    pub condition: String,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<AccessPolicy> for PolicyResponse {
+    fn from(policy: AccessPolicy) -> Self {
+        Self {
+            id: policy.id.to_string(),
+            name: policy.name.to_string(),
+            description: policy.description,
+            action: policy.action.to_string(),
+            effect: policy.effect.to_string(),
+            condition: policy.condition.to_string(),
+            status: policy.status.to_string(),
+            created_at: policy.created_at,
+            updated_at: policy.updated_at,
+        }
+    }
+}
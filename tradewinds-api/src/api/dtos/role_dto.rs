@@ -11,7 +11,13 @@ pub struct CreateRoleRequest {
     pub description: Option<String>,
     #[serde(rename = "permissionIds")]
     pub permission_ids: Option<Vec<String>>,
+    #[serde(rename = "deniedPermissionIds")]
+    pub denied_permission_ids: Option<Vec<String>>,
     pub status: Option<i32>, // 新增，支持指定角色状态
+    #[serde(rename = "dataScope")]
+    pub data_scope: Option<i32>,
+    #[serde(rename = "dataScopeDepartmentIds")]
+    pub data_scope_department_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -27,6 +33,12 @@ pub struct UpdateRoleRequest {
     pub status: Option<i32>,
     #[serde(rename = "permissionIds")]
     pub permission_ids: Option<Vec<String>>,
+    #[serde(rename = "deniedPermissionIds")]
+    pub denied_permission_ids: Option<Vec<String>>,
+    #[serde(rename = "dataScope")]
+    pub data_scope: Option<i32>,
+    #[serde(rename = "dataScopeDepartmentIds")]
+    pub data_scope_department_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -104,6 +116,10 @@ pub struct RoleResponse {
     pub name: String,
     pub description: Option<String>,
     pub status: i32,
+    #[serde(rename = "dataScope")]
+    pub data_scope: i32,
+    #[serde(rename = "builtIn")]
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -116,6 +132,8 @@ impl From<Role> for RoleResponse {
             name: role.name.value().to_string(),
             description: role.description.map(|d| d.value().to_string()),
             status: role.status.value(),
+            data_scope: role.data_scope.value(),
+            built_in: role.built_in,
             created_at: role.created_at,
             updated_at: role.updated_at,
         }
@@ -130,6 +148,8 @@ impl From<RoleInfo> for RoleResponse {
             name: info.name,
             description: info.description,
             status: info.status,
+            data_scope: info.data_scope,
+            built_in: info.built_in,
             created_at: info.created_at,
             updated_at: info.updated_at,
         }
@@ -143,6 +163,10 @@ pub struct RoleWithPermissionsResponse {
     pub name: String,
     pub description: Option<String>,
     pub status: i32,
+    #[serde(rename = "dataScope")]
+    pub data_scope: i32,
+    #[serde(rename = "builtIn")]
+    pub built_in: bool,
     pub permissions: Vec<PermissionResponse>,
     pub created_at: i64,
     pub updated_at: i64,
@@ -156,6 +180,8 @@ impl RoleWithPermissionsResponse {
             name: role.name.to_string(),
             description: role.description.map(|d| d.to_string()),
             status: role.status.value(),
+            data_scope: role.data_scope.value(),
+            built_in: role.built_in,
             permissions: permissions.into_iter().map(|permission| permission.into()).collect(),
             created_at: role.created_at,
             updated_at: role.updated_at,
@@ -1,22 +1,72 @@
 use serde::{Deserialize, Serialize};
 
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::system_setting::EffectiveSetting;
+
 /// 获取系统设置请求
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetSystemSettingRequest {
     pub key: String,
 }
 
-/// 获取系统设置响应
+/// 系统设置列表查询条件
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListSystemSettingsRequest {
+    /// account / security / audit / system
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub category: Option<String>,
+}
+
+/// 系统设置列表响应
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListSystemSettingsResponse {
+    pub settings: Vec<SystemSettingResponse>,
+}
+
+/// 系统设置，敏感设置的值与默认值以占位值代替
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GetSystemSettingResponse {
+pub struct SystemSettingResponse {
     pub key: String,
     pub value: String,
-    pub description: Option<String>,
+    /// bool / int / duration / string / json
+    #[serde(rename = "type")]
+    pub setting_type: String,
+    #[serde(rename = "defaultValue")]
+    pub default_value: String,
+    pub category: String,
+    pub sensitive: bool,
+    /// 平台级设置只能在平台默认租户内修改
+    pub platform: bool,
+    /// 是否修改过，未修改时为默认值
+    pub customized: bool,
+    pub description: String,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<i64>,
+}
+
+impl From<EffectiveSetting> for SystemSettingResponse {
+    fn from(setting: EffectiveSetting) -> Self {
+        let definition = setting.definition;
+        Self {
+            key: definition.key.to_string(),
+            value: setting.display_value().to_string(),
+            setting_type: definition.setting_type.to_string(),
+            default_value: definition.display_value(definition.default_value).to_string(),
+            category: definition.category.to_string(),
+            sensitive: definition.sensitive,
+            platform: definition.platform,
+            customized: setting.customized,
+            description: definition.description.to_string(),
+            updated_at: setting.updated_at.map(|updated_at| updated_at.and_utc().timestamp()),
+        }
+    }
 }
 
 /// 设置系统设置请求
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetSystemSettingRequest {
+    /// 取自路径参数
+    #[serde(default)]
     pub key: String,
     pub value: String,
 }
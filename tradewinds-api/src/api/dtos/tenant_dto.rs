@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::tenant::Tenant;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateTenantRequest {
+    pub code: String,
+    pub name: String,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub host: Option<String>,
+    #[serde(rename = "adminUsername")]
+    pub admin_username: String,
+    #[serde(rename = "adminEmail")]
+    pub admin_email: String,
+    #[serde(rename = "adminPassword")]
+    pub admin_password: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateTenantResponse {
+    pub tenant: TenantResponse,
+}
+
+/// 更新租户请求
+///
+/// `host` 传空字符串时解除域名绑定，不传时保持不变
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateTenantRequest {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub host: Option<String>,
+    pub status: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateTenantResponse;
+
+#[derive(Debug, Deserialize)]
+pub struct GetTenantByIdRequest {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetTenantByIdResponse {
+    pub tenant: TenantResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListTenantsRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub keyword: Option<String>,
+    pub status: Option<i32>,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListTenantsResponse {
+    pub tenants: Vec<TenantResponse>,
+    pub total: u64,
+}
+
+/// 租户解析请求，由租户中间件从令牌、请求头与 Host 中提取
+#[derive(Debug, Default)]
+pub struct ResolveTenantRequest {
+    pub id: Option<String>,
+    pub code: Option<String>,
+    pub host: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TenantResponse {
+    pub id: String,
+    pub code: String,
+    pub name: String,
+    pub host: Option<String>,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<Tenant> for TenantResponse {
+    fn from(tenant: Tenant) -> Self {
+        Self {
+            id: tenant.id.to_string(),
+            code: tenant.code.to_string(),
+            name: tenant.name.to_string(),
+            host: tenant.host.map(|h| h.to_string()),
+            status: tenant.status.to_string(),
+            created_at: tenant.created_at,
+            updated_at: tenant.updated_at,
+        }
+    }
+}
@@ -2,13 +2,15 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 use tradewinds_domain::entities::role::Role;
 use tradewinds_domain::entities::user::User;
+use tradewinds_domain::policies::{AccessBlocker, AccessExplanation, GrantEvidence, PermissionDecision};
+use tradewinds_domain::value_objects::RoleSource;
 #[rustfmt::skip]
 use crate::api::dtos::{
     permission_dto::PermissionResponse, 
     role_dto::RoleResponse
 };
 use tradewinds_application::queries::auth::user_info::UserInfo;
-use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_common::utils::{empty_string_as_none, null_string_as_none_nested};
 
 #[derive(Debug, Deserialize)]
 pub struct CreateUserRequest {
@@ -21,6 +23,8 @@ pub struct CreateUserRequest {
     pub avatar: Option<String>,
     #[serde(rename = "roleIds")]
     pub role_ids: Option<Vec<String>>,
+    #[serde(rename = "departmentId", default, deserialize_with = "empty_string_as_none")]
+    pub department_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -42,6 +46,8 @@ pub struct UpdateUserRequest {
     pub status: Option<i32>,
     #[serde(rename = "roleIds")]
     pub role_ids: Option<Vec<String>>,
+    #[serde(rename = "departmentId", default, deserialize_with = "null_string_as_none_nested")]
+    pub department_id: Option<Option<String>>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -102,6 +108,8 @@ pub struct ListUsersRequest {
     pub email: Option<String>,
     #[serde(alias = "showDeleted")]
     pub show_deleted: Option<bool>,
+    #[serde(rename = "departmentId", default, deserialize_with = "empty_string_as_none")]
+    pub department_id: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -147,7 +155,46 @@ pub struct GetUserRolesRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct GetUserRolesResponse {
-    pub roles: Vec<RoleResponse>,
+    pub roles: Vec<UserRoleResponse>,
+}
+
+/// 用户的有效角色及其来源
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserRoleResponse {
+    #[serde(flatten)]
+    pub role: RoleResponse,
+    pub sources: Vec<RoleSourceResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum RoleSourceResponse {
+    /// 直接分配给用户
+    Direct,
+    /// 经由用户组获得
+    Group {
+        #[serde(rename = "groupId")]
+        group_id: String,
+        #[serde(rename = "groupName")]
+        group_name: String,
+    },
+}
+
+impl From<(Role, Vec<RoleSource>)> for UserRoleResponse {
+    fn from((role, sources): (Role, Vec<RoleSource>)) -> Self {
+        Self { role: role.into(), sources: sources.into_iter().map(Into::into).collect() }
+    }
+}
+
+impl From<RoleSource> for RoleSourceResponse {
+    fn from(source: RoleSource) -> Self {
+        match source {
+            RoleSource::Direct => RoleSourceResponse::Direct,
+            RoleSource::Group { group_id, group_name } => {
+                RoleSourceResponse::Group { group_id: group_id.to_string(), group_name: group_name.to_string() }
+            }
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -160,6 +207,126 @@ pub struct GetUserPermissionsResponse {
     pub permissions: Vec<PermissionResponse>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ExplainUserAccessRequest {
+    #[serde(default)]
+    pub id: String,
+    pub code: String,
+}
+
+/// 访问说明：判定结果及完整证据链
+#[derive(Debug, Serialize)]
+pub struct ExplainUserAccessResponse {
+    pub code: String,
+    pub allowed: bool,
+    /// 仅按启用角色授权的判定：allowed / denied / not_granted
+    pub decision: String,
+    pub permission: Option<PermissionResponse>,
+    /// 上级菜单链，由近及远
+    #[serde(rename = "parentMenus")]
+    pub parent_menus: Vec<PermissionResponse>,
+    pub grants: Vec<AccessGrantResponse>,
+    pub blockers: Vec<AccessBlockerResponse>,
+}
+
+/// 命中该权限的授权及其所在角色
+#[derive(Debug, Serialize)]
+pub struct AccessGrantResponse {
+    #[serde(rename = "roleId")]
+    pub role_id: String,
+    #[serde(rename = "roleCode")]
+    pub role_code: String,
+    #[serde(rename = "roleName")]
+    pub role_name: String,
+    #[serde(rename = "roleStatus")]
+    pub role_status: i32,
+    #[serde(rename = "permissionId")]
+    pub permission_id: String,
+    /// 授权上的权限码，可能为通配码
+    #[serde(rename = "grantedCode")]
+    pub granted_code: Option<String>,
+    pub effect: String,
+    pub sources: Vec<RoleSourceResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccessBlockerResponse {
+    UserInactive,
+    NotGranted,
+    DeniedByRole {
+        #[serde(rename = "roleId")]
+        role_id: String,
+        #[serde(rename = "permissionId")]
+        permission_id: String,
+    },
+    RoleInactive {
+        #[serde(rename = "roleId")]
+        role_id: String,
+    },
+    PermissionInactive {
+        #[serde(rename = "permissionId")]
+        permission_id: String,
+    },
+    ParentInactive {
+        #[serde(rename = "permissionId")]
+        permission_id: String,
+    },
+}
+
+impl From<AccessExplanation> for ExplainUserAccessResponse {
+    fn from(explanation: AccessExplanation) -> Self {
+        let decision = match explanation.decision {
+            PermissionDecision::Allowed => "allowed",
+            PermissionDecision::Denied => "denied",
+            PermissionDecision::NotGranted => "not_granted",
+        };
+        Self {
+            code: explanation.code.to_string(),
+            allowed: explanation.allowed,
+            decision: decision.to_string(),
+            permission: explanation.permission.map(Into::into),
+            parent_menus: explanation.ancestors.into_iter().map(Into::into).collect(),
+            grants: explanation.grants.into_iter().map(Into::into).collect(),
+            blockers: explanation.blockers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<GrantEvidence> for AccessGrantResponse {
+    fn from(evidence: GrantEvidence) -> Self {
+        Self {
+            role_id: evidence.role.id.to_string(),
+            role_code: evidence.role.code.to_string(),
+            role_name: evidence.role.name.to_string(),
+            role_status: evidence.role.status.value(),
+            permission_id: evidence.grant.permission_id.to_string(),
+            granted_code: evidence.grant.code.map(|c| c.to_string()),
+            effect: evidence.grant.effect.to_string(),
+            sources: evidence.sources.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<AccessBlocker> for AccessBlockerResponse {
+    fn from(blocker: AccessBlocker) -> Self {
+        match blocker {
+            AccessBlocker::UserInactive => Self::UserInactive,
+            AccessBlocker::NotGranted => Self::NotGranted,
+            AccessBlocker::DeniedByRole { role_id, permission_id } => {
+                Self::DeniedByRole { role_id: role_id.to_string(), permission_id: permission_id.to_string() }
+            }
+            AccessBlocker::RoleInactive { role_id } => Self::RoleInactive { role_id: role_id.to_string() },
+            AccessBlocker::PermissionInactive { permission_id } => {
+                Self::PermissionInactive { permission_id: permission_id.to_string() }
+            }
+            AccessBlocker::ParentInactive { permission_id } => {
+                Self::ParentInactive { permission_id: permission_id.to_string() }
+            }
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct UserResponse {
     pub id: String,
@@ -169,8 +336,14 @@ pub struct UserResponse {
     pub phone: Option<String>,
     pub avatar: Option<String>,
     pub status: String,
+    #[serde(rename = "departmentId")]
+    pub department_id: Option<String>,
     pub created_at: i64,
     pub updated_at: i64,
+    #[serde(rename = "lastLoginAt")]
+    pub last_login_at: Option<i64>,
+    #[serde(rename = "lastLoginIp")]
+    pub last_login_ip: Option<String>,
 }
 
 impl From<User> for UserResponse {
@@ -183,8 +356,11 @@ impl From<User> for UserResponse {
             phone: user.phone.map(|v| v.to_string()),
             avatar: user.avatar.map(|v| v.to_string()),
             status: user.status.to_string(),
+            department_id: user.department_id.map(|v| v.to_string()),
             created_at: user.created_at,
             updated_at: user.updated_at,
+            last_login_at: user.last_login_at,
+            last_login_ip: user.last_login_ip,
         }
     }
 }
@@ -199,8 +375,11 @@ impl From<UserInfo> for UserResponse {
             phone: info.phone,
             avatar: info.avatar,
             status: info.status,
+            department_id: info.department_id,
             created_at: info.created_at,
             updated_at: info.updated_at,
+            last_login_at: info.last_login_at,
+            last_login_ip: info.last_login_ip,
         }
     }
 }
@@ -224,9 +403,15 @@ pub struct UserWithRolesResponse {
     pub phone: Option<String>,
     pub avatar: Option<String>,
     pub status: String,
+    #[serde(rename = "departmentId")]
+    pub department_id: Option<String>,
     pub roles: Vec<RoleResponse>,
     pub created_at: i64,
     pub updated_at: i64,
+    #[serde(rename = "lastLoginAt")]
+    pub last_login_at: Option<i64>,
+    #[serde(rename = "lastLoginIp")]
+    pub last_login_ip: Option<String>,
 }
 
 impl UserWithRolesResponse {
@@ -239,9 +424,12 @@ impl UserWithRolesResponse {
             phone: user.phone.map(|v| v.to_string()),
             avatar: user.avatar.map(|v| v.to_string()),
             status: user.status.to_string(),
+            department_id: user.department_id.map(|v| v.to_string()),
             roles: roles.into_iter().map(|role| role.into()).collect(),
             created_at: user.created_at,
             updated_at: user.updated_at,
+            last_login_at: user.last_login_at,
+            last_login_ip: user.last_login_ip,
         }
     }
 }
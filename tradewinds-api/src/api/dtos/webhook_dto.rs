@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_common::utils::empty_string_as_none;
+use tradewinds_domain::entities::{Webhook, WebhookDelivery};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub name: String,
+    pub url: String,
+    /// 签名密钥，不传时自动生成
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub secret: Option<String>,
+    /// 订阅的事件类型，`*` 表示全部
+    #[serde(rename = "eventTypes")]
+    pub event_types: Vec<String>,
+}
+
+/// 创建端点响应，签名密钥仅在此返回一次
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateWebhookResponse {
+    pub webhook: WebhookResponse,
+    pub secret: String,
+}
+
+/// 更新端点请求，`enabled` 为 true 时重新启用并清零连续失败次数
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateWebhookRequest {
+    #[serde(default)]
+    pub id: String,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    #[serde(rename = "eventTypes")]
+    pub event_types: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListWebhooksResponse {
+    pub webhooks: Vec<WebhookResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookResponse {
+    pub id: String,
+    pub name: String,
+    pub url: String,
+    #[serde(rename = "eventTypes")]
+    pub event_types: Vec<String>,
+    pub status: String,
+    #[serde(rename = "consecutiveFailures")]
+    pub consecutive_failures: u32,
+    #[serde(rename = "disabledReason")]
+    pub disabled_reason: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<Webhook> for WebhookResponse {
+    fn from(webhook: Webhook) -> Self {
+        Self {
+            id: webhook.id.to_string(),
+            name: webhook.name,
+            url: webhook.url,
+            event_types: webhook.event_types,
+            status: webhook.status.to_string(),
+            consecutive_failures: webhook.consecutive_failures,
+            disabled_reason: webhook.disabled_reason,
+            created_at: webhook.created_at,
+            updated_at: webhook.updated_at,
+        }
+    }
+}
+
+/// 投递记录查询条件
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct WebhookDeliveryFilterRequest {
+    /// pending / succeeded / failed
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub status: Option<String>,
+    #[serde(rename = "eventType", default, deserialize_with = "empty_string_as_none")]
+    pub event_type: Option<String>,
+}
+
+/// 投递记录分页参数
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListWebhookDeliveriesRequest {
+    #[serde(default = "default_page")]
+    pub page: u64,
+    #[serde(rename = "pageSize", default = "default_page_size")]
+    pub page_size: u64,
+}
+
+fn default_page() -> u64 {
+    1
+}
+fn default_page_size() -> u64 {
+    10
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListWebhookDeliveriesResponse {
+    pub deliveries: Vec<WebhookDeliveryResponse>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RedeliverWebhookResponse {
+    pub delivery: WebhookDeliveryResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookDeliveryResponse {
+    pub id: String,
+    #[serde(rename = "webhookId")]
+    pub webhook_id: String,
+    #[serde(rename = "eventType")]
+    pub event_type: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: u32,
+    #[serde(rename = "nextAttemptAt")]
+    pub next_attempt_at: i64,
+    #[serde(rename = "responseStatus")]
+    pub response_status: Option<u16>,
+    #[serde(rename = "lastError")]
+    pub last_error: Option<String>,
+    #[serde(rename = "redeliveryOf")]
+    pub redelivery_of: Option<String>,
+    pub created_at: i64,
+    pub delivered_at: Option<i64>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id.to_string(),
+            webhook_id: delivery.webhook_id.to_string(),
+            event_type: delivery.event_type,
+            payload: delivery.payload,
+            status: delivery.status.to_string(),
+            attempts: delivery.attempts,
+            next_attempt_at: delivery.next_attempt_at,
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            redelivery_of: delivery.redelivery_of.map(|id| id.to_string()),
+            created_at: delivery.created_at,
+            delivered_at: delivery.delivered_at,
+        }
+    }
+}
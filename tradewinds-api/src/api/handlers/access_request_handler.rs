@@ -0,0 +1,115 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::access_request_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct AccessRequestHandler;
+
+impl AccessRequestHandler {
+    /// 提交权限申请
+    pub async fn handle_submit_request(
+        State(state): State<AppState>,
+        Json(req): Json<SubmitAccessRequestRequest>,
+    ) -> AppResult<Json<ApiResponse<SubmitAccessRequestResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.access_request_controller.submit_request(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取权限申请列表
+    pub async fn handle_list_requests(
+        State(state): State<AppState>,
+        Query(query): Query<ListAccessRequestsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListAccessRequestsResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.access_request_controller.list_requests(actor_id, query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取权限申请详情
+    pub async fn handle_get_request(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetAccessRequestByIdResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = GetAccessRequestByIdRequest { id };
+        let resp = state.access_request_controller.get_request_by_id(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 批准权限申请
+    pub async fn handle_approve_request(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        req: Option<Json<DecideAccessRequestRequest>>,
+    ) -> AppResult<Json<ApiResponse<ApproveAccessRequestResponse>>> {
+        let actor_id = current_actor_id()?;
+        let mut req = req.map(|Json(req)| req).unwrap_or_default();
+        req.id = id;
+        let resp = state.access_request_controller.approve_request(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 驳回权限申请
+    pub async fn handle_reject_request(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        req: Option<Json<DecideAccessRequestRequest>>,
+    ) -> AppResult<Json<ApiResponse<RejectAccessRequestResponse>>> {
+        let actor_id = current_actor_id()?;
+        let mut req = req.map(|Json(req)| req).unwrap_or_default();
+        req.id = id;
+        let resp = state.access_request_controller.reject_request(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 撤回权限申请
+    pub async fn handle_cancel_request(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<CancelAccessRequestResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = CancelAccessRequestRequest { id };
+        let resp = state.access_request_controller.cancel_request(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 处理到期申请：待审批超时的申请过期，到期的授权收回角色
+    ///
+    /// 定时任务 access_request_expiry 会按计划处理全部租户，此接口只立即处理当前租户
+    pub async fn handle_expire_requests(
+        State(state): State<AppState>,
+    ) -> AppResult<Json<ApiResponse<ExpireAccessRequestsResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.access_request_controller.expire_requests(actor_id).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取角色审批人
+    pub async fn handle_get_role_approvers(
+        State(state): State<AppState>,
+        Path(role_id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetRoleApproversResponse>>> {
+        let req = GetRoleApproversRequest { role_id };
+        let resp = state.access_request_controller.get_role_approvers(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 设置角色审批人
+    pub async fn handle_set_role_approvers(
+        State(state): State<AppState>,
+        Path(role_id): Path<String>,
+        Json(mut req): Json<SetRoleApproversRequest>,
+    ) -> AppResult<Json<ApiResponse<SetRoleApproversResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.role_id = role_id;
+        let resp = state.access_request_controller.set_role_approvers(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
@@ -0,0 +1,95 @@
+use axum::extract::{Json, Path, Query, State};
+use axum::http::header;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::access_review_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct AccessReviewHandler;
+
+impl AccessReviewHandler {
+    /// 发起访问复核活动
+    pub async fn handle_launch_review(
+        State(state): State<AppState>,
+        Json(req): Json<LaunchAccessReviewRequest>,
+    ) -> AppResult<Json<ApiResponse<LaunchAccessReviewResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.access_review_controller.launch_review(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取访问复核活动列表
+    pub async fn handle_list_reviews(
+        State(state): State<AppState>,
+        Query(query): Query<ListAccessReviewsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListAccessReviewsResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.access_review_controller.list_reviews(actor_id, query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取访问复核活动详情
+    pub async fn handle_get_review(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetAccessReviewByIdResponse>>> {
+        let req = GetAccessReviewByIdRequest { id };
+        let resp = state.access_review_controller.get_review_by_id(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取复核项
+    pub async fn handle_list_items(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Query(mut query): Query<ListAccessReviewItemsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListAccessReviewItemsResponse>>> {
+        let actor_id = current_actor_id()?;
+        query.id = id;
+        let resp = state.access_review_controller.list_items(actor_id, query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 确认或收回复核项
+    pub async fn handle_decide_item(
+        State(state): State<AppState>,
+        Path((review_id, item_id)): Path<(String, String)>,
+        Json(mut req): Json<DecideAccessReviewItemRequest>,
+    ) -> AppResult<Json<ApiResponse<DecideAccessReviewItemResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.review_id = review_id;
+        req.item_id = item_id;
+        let resp = state.access_review_controller.decide_item(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 处理到期活动：未决项自动收回
+    ///
+    /// 定时任务 access_review_close 会按计划处理全部租户，此接口只立即处理当前租户
+    pub async fn handle_close_due_reviews(
+        State(state): State<AppState>,
+    ) -> AppResult<Json<ApiResponse<CloseDueAccessReviewsResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.access_review_controller.close_due_reviews(actor_id).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 导出复核报告（CSV）
+    pub async fn handle_export_report(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<([(header::HeaderName, String); 2], String)> {
+        let disposition = format!("attachment; filename=\"access-review-{}.csv\"", id);
+        let req = GetAccessReviewReportRequest { id };
+        let csv = state.access_review_controller.export_report(req).await?;
+        Ok((
+            [(header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()), (header::CONTENT_DISPOSITION, disposition)],
+            csv,
+        ))
+    }
+}
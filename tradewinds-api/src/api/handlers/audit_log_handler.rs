@@ -0,0 +1,39 @@
+use axum::extract::{Json, Query, State};
+use axum::http::header;
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::audit_log_dto::*,
+    state::AppState,
+};
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct AuditLogHandler;
+
+impl AuditLogHandler {
+    /// 获取审计日志列表
+    pub async fn handle_list_logs(
+        State(state): State<AppState>,
+        Query(filter): Query<AuditLogFilterRequest>,
+        Query(req): Query<ListAuditLogsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListAuditLogsResponse>>> {
+        let resp = state.audit_log_controller.list_logs(filter, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 导出审计日志（CSV）
+    pub async fn handle_export_logs(
+        State(state): State<AppState>,
+        Query(filter): Query<AuditLogFilterRequest>,
+    ) -> AppResult<([(header::HeaderName, String); 2], String)> {
+        let csv = state.audit_log_controller.export_logs(filter).await?;
+        Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (header::CONTENT_DISPOSITION, "attachment; filename=\"audit-logs.csv\"".to_string()),
+            ],
+            csv,
+        ))
+    }
+}
@@ -1,4 +1,8 @@
-use axum::{Json, extract::State, http::HeaderMap};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::HeaderMap,
+};
 use std::sync::Arc;
 
 use tradewinds_common::{ApiResponse, utils::get_current_user_token};
@@ -13,6 +17,7 @@ use crate::api::{
         ChangePasswordRequest, ChangePasswordResponse, 
         GetCurrentUserRequest, GetCurrentUserResponse,
         GetUserMenusRequest, GetUserMenusResponse,
+        GetLoginHistoryRequest, GetLoginHistoryResponse, ListLoginLogsRequest,
         GetSuperAdminDashboardRequest, GetSuperAdminDashboardResponse, 
     },
     AppState, AuthController,
@@ -86,6 +91,18 @@ impl AuthHandler {
         Ok(Json(ApiResponse::success(resp)))
     }
 
+    /// 获取当前用户登录历史
+    pub async fn handle_get_login_history(
+        State(state): State<AppState>,
+        headers: HeaderMap,
+        Query(page): Query<ListLoginLogsRequest>,
+    ) -> AppResult<Json<ApiResponse<GetLoginHistoryResponse>>> {
+        let token = get_current_user_token(&headers).await?;
+        let req = GetLoginHistoryRequest { token, page: page.page, page_size: page.page_size };
+        let resp = state.auth_controller.get_login_history(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
     /// 获取超级管理员仪表盘数据
     pub async fn handle_get_super_admin_dashboard(
         State(state): State<AppState>,
@@ -0,0 +1,72 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::department_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct DepartmentHandler;
+
+impl DepartmentHandler {
+    /// 创建部门
+    pub async fn handle_create_department(
+        State(state): State<AppState>,
+        Json(req): Json<CreateDepartmentRequest>,
+    ) -> AppResult<Json<ApiResponse<CreateDepartmentResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.department_controller.create_department(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 更新部门（含调整上级部门）
+    pub async fn handle_update_department(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(mut req): Json<UpdateDepartmentRequest>,
+    ) -> AppResult<Json<ApiResponse<UpdateDepartmentResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.id = id;
+        let resp = state.department_controller.update_department(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 删除部门
+    pub async fn handle_delete_department(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<DeleteDepartmentResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = DeleteDepartmentRequest { id };
+        let resp = state.department_controller.delete_department(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取部门详情
+    pub async fn handle_get_department(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetDepartmentByIdResponse>>> {
+        let req = GetDepartmentByIdRequest { id };
+        let resp = state.department_controller.get_department_by_id(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取部门列表
+    pub async fn handle_list_departments(
+        State(state): State<AppState>,
+        Query(query): Query<ListDepartmentsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListDepartmentsResponse>>> {
+        let resp = state.department_controller.list_departments(query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
+
+/// 获取部门树
+pub async fn handle_get_department_tree(State(state): State<AppState>) -> AppResult<Json<Vec<DepartmentTreeResponse>>> {
+    let tree = state.department_controller.get_department_tree().await?;
+    Ok(Json(tree))
+}
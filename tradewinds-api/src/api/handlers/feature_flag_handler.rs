@@ -0,0 +1,54 @@
+use axum::extract::{Json, Path, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::feature_flag_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct FeatureFlagHandler;
+
+impl FeatureFlagHandler {
+    /// 创建功能开关
+    pub async fn handle_create_flag(
+        State(state): State<AppState>,
+        Json(req): Json<CreateFeatureFlagRequest>,
+    ) -> AppResult<Json<ApiResponse<FeatureFlagResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.feature_flag_controller.create_flag(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取功能开关列表
+    pub async fn handle_list_flags(
+        State(state): State<AppState>,
+    ) -> AppResult<Json<ApiResponse<ListFeatureFlagsResponse>>> {
+        let resp = state.feature_flag_controller.list_flags().await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 更新功能开关
+    pub async fn handle_update_flag(
+        State(state): State<AppState>,
+        Path(key): Path<String>,
+        Json(mut req): Json<UpdateFeatureFlagRequest>,
+    ) -> AppResult<Json<ApiResponse<FeatureFlagResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.key = key;
+        let resp = state.feature_flag_controller.update_flag(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 删除功能开关
+    pub async fn handle_delete_flag(
+        State(state): State<AppState>,
+        Path(key): Path<String>,
+    ) -> AppResult<Json<ApiResponse<()>>> {
+        let actor_id = current_actor_id()?;
+        state.feature_flag_controller.delete_flag(actor_id, key).await?;
+        Ok(Json(ApiResponse::success(())))
+    }
+}
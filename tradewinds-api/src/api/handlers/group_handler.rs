@@ -0,0 +1,99 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::group_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct GroupHandler;
+
+impl GroupHandler {
+    /// 创建用户组
+    pub async fn handle_create_group(
+        State(state): State<AppState>,
+        Json(req): Json<CreateGroupRequest>,
+    ) -> AppResult<Json<ApiResponse<CreateGroupResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.group_controller.create_group(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 更新用户组（含整体替换角色）
+    pub async fn handle_update_group(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(mut req): Json<UpdateGroupRequest>,
+    ) -> AppResult<Json<ApiResponse<UpdateGroupResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.id = id;
+        let resp = state.group_controller.update_group(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 删除用户组
+    pub async fn handle_delete_group(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<DeleteGroupResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = DeleteGroupRequest { id };
+        let resp = state.group_controller.delete_group(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取用户组详情
+    pub async fn handle_get_group(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetGroupByIdResponse>>> {
+        let req = GetGroupByIdRequest { id };
+        let resp = state.group_controller.get_group_by_id(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取用户组列表
+    pub async fn handle_list_groups(
+        State(state): State<AppState>,
+        Query(query): Query<ListGroupsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListGroupsResponse>>> {
+        let resp = state.group_controller.list_groups(query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取用户组成员
+    pub async fn handle_list_group_members(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<ListGroupMembersResponse>>> {
+        let req = ListGroupMembersRequest { group_id: id };
+        let resp = state.group_controller.list_group_members(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 添加用户组成员
+    pub async fn handle_add_group_members(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(mut req): Json<AddGroupMembersRequest>,
+    ) -> AppResult<Json<ApiResponse<AddGroupMembersResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.group_id = id;
+        let resp = state.group_controller.add_group_members(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 移除用户组成员
+    pub async fn handle_remove_group_member(
+        State(state): State<AppState>,
+        Path((id, user_id)): Path<(String, String)>,
+    ) -> AppResult<Json<ApiResponse<RemoveGroupMemberResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = RemoveGroupMemberRequest { group_id: id, user_id };
+        let resp = state.group_controller.remove_group_member(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
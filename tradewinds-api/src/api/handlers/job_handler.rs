@@ -0,0 +1,60 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::job_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct JobHandler;
+
+impl JobHandler {
+    /// 获取定时任务列表
+    pub async fn handle_list_jobs(State(state): State<AppState>) -> AppResult<Json<ApiResponse<ListJobsResponse>>> {
+        let resp = state.job_controller.list_jobs().await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取执行记录
+    pub async fn handle_list_runs(
+        State(state): State<AppState>,
+        Query(filter): Query<JobRunFilterRequest>,
+        Query(req): Query<ListJobRunsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListJobRunsResponse>>> {
+        let resp = state.job_controller.list_runs(filter, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 手动触发
+    pub async fn handle_trigger_job(
+        State(state): State<AppState>,
+        Path(name): Path<String>,
+    ) -> AppResult<Json<ApiResponse<TriggerJobResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.job_controller.trigger_job(name, actor_id).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 暂停
+    pub async fn handle_pause_job(
+        State(state): State<AppState>,
+        Path(name): Path<String>,
+    ) -> AppResult<Json<ApiResponse<()>>> {
+        let actor_id = current_actor_id()?;
+        state.job_controller.pause_job(name, actor_id).await?;
+        Ok(Json(ApiResponse::success(())))
+    }
+
+    /// 恢复
+    pub async fn handle_resume_job(
+        State(state): State<AppState>,
+        Path(name): Path<String>,
+    ) -> AppResult<Json<ApiResponse<()>>> {
+        let actor_id = current_actor_id()?;
+        state.job_controller.resume_job(name, actor_id).await?;
+        Ok(Json(ApiResponse::success(())))
+    }
+}
@@ -0,0 +1,33 @@
+use axum::extract::{Json, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::login_log_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct LoginLogHandler;
+
+impl LoginLogHandler {
+    /// 获取登录日志列表
+    pub async fn handle_list_logs(
+        State(state): State<AppState>,
+        Query(filter): Query<LoginLogFilterRequest>,
+        Query(req): Query<ListLoginLogsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListLoginLogsResponse>>> {
+        let resp = state.login_log_controller.list_logs(filter, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 清理超过保留天数的登录日志
+    pub async fn handle_purge_logs(
+        State(state): State<AppState>,
+    ) -> AppResult<Json<ApiResponse<PurgeLoginLogsResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.login_log_controller.purge_logs(actor_id).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
@@ -1,10 +1,34 @@
+pub mod access_request_handler;
+pub mod access_review_handler;
+pub mod audit_log_handler;
 pub mod auth_handler;
+pub mod department_handler;
+pub mod feature_flag_handler;
+pub mod group_handler;
+pub mod job_handler;
+pub mod login_log_handler;
+pub mod outbox_handler;
 pub mod permission_handler;
+pub mod policy_handler;
 pub mod role_handler;
+pub mod tenant_handler;
 pub mod user_handler;
+pub mod webhook_handler;
 pub mod system_setting_handler;
 
+pub use access_request_handler::*;
+pub use access_review_handler::*;
+pub use audit_log_handler::*;
 pub use auth_handler::*;
+pub use department_handler::*;
+pub use feature_flag_handler::*;
+pub use group_handler::*;
+pub use job_handler::*;
+pub use login_log_handler::*;
+pub use outbox_handler::*;
 pub use permission_handler::*;
+pub use policy_handler::*;
+pub use tenant_handler::*;
 pub use user_handler::*;
+pub use webhook_handler::*;
 pub use system_setting_handler::*;
\ No newline at end of file
@@ -0,0 +1,40 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::outbox_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct OutboxHandler;
+
+impl OutboxHandler {
+    /// 获取发件箱消息列表
+    pub async fn handle_list_messages(
+        State(state): State<AppState>,
+        Query(filter): Query<OutboxFilterRequest>,
+        Query(req): Query<ListOutboxMessagesRequest>,
+    ) -> AppResult<Json<ApiResponse<ListOutboxMessagesResponse>>> {
+        let resp = state.outbox_controller.list_messages(filter, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取发件箱统计
+    pub async fn handle_get_stats(State(state): State<AppState>) -> AppResult<Json<ApiResponse<OutboxStatsResponse>>> {
+        let resp = state.outbox_controller.get_stats().await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 重新投递发件箱消息
+    pub async fn handle_retry_message(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<()>>> {
+        let actor_id = current_actor_id()?;
+        state.outbox_controller.retry_message(id, actor_id).await?;
+        Ok(Json(ApiResponse::success(())))
+    }
+}
@@ -17,6 +17,7 @@ use crate::api::dtos::{
     ListPermissionsRequest, ListPermissionsResponse, UpdatePermissionRequest, UpdatePermissionResponse,
 };
 use crate::api::mappers::permission_mapper::to_permission_tree_response;
+use crate::api::middlewares::security::current_actor_id;
 use tradewinds_common::ApiResponse;
 use tradewinds_error::AppResult;
 
@@ -30,7 +31,7 @@ impl PermissionHandler {
         State(state): State<AppState>,
         Json(req): Json<CreatePermissionRequest>,
     ) -> AppResult<Json<ApiResponse<CreatePermissionResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string();
+        let actor_id = current_actor_id()?;
         let resp = state.permission_controller.create_permission(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -40,8 +41,7 @@ impl PermissionHandler {
         State(state): State<AppState>,
         Json(req): Json<UpdatePermissionRequest>,
     ) -> AppResult<Json<ApiResponse<UpdatePermissionResponse>>> {
-        // FIXME: Use a real actor_id from token
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string();
+        let actor_id = current_actor_id()?;
         let resp = state.permission_controller.update_permission(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -51,7 +51,7 @@ impl PermissionHandler {
         State(state): State<AppState>,
         Path(id): Path<String>,
     ) -> AppResult<Json<ApiResponse<DeletePermissionResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string();
+        let actor_id = current_actor_id()?;
         let req = DeletePermissionRequest { id };
         let resp = state.permission_controller.delete_permission(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
@@ -0,0 +1,76 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::policy_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct PolicyHandler;
+
+impl PolicyHandler {
+    /// 创建访问策略
+    pub async fn handle_create_policy(
+        State(state): State<AppState>,
+        Json(req): Json<CreatePolicyRequest>,
+    ) -> AppResult<Json<ApiResponse<CreatePolicyResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.policy_controller.create_policy(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 更新访问策略
+    pub async fn handle_update_policy(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(mut req): Json<UpdatePolicyRequest>,
+    ) -> AppResult<Json<ApiResponse<UpdatePolicyResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.id = id;
+        let resp = state.policy_controller.update_policy(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 删除访问策略
+    pub async fn handle_delete_policy(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<DeletePolicyResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = DeletePolicyRequest { id };
+        let resp = state.policy_controller.delete_policy(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取访问策略详情
+    pub async fn handle_get_policy(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetPolicyByIdResponse>>> {
+        let req = GetPolicyByIdRequest { id };
+        let resp = state.policy_controller.get_policy_by_id(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取访问策略列表
+    pub async fn handle_list_policies(
+        State(state): State<AppState>,
+        Query(query): Query<ListPoliciesRequest>,
+    ) -> AppResult<Json<ApiResponse<ListPoliciesResponse>>> {
+        let resp = state.policy_controller.list_policies(query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 试运行访问判定（RBAC + ABAC），不产生副作用
+    pub async fn handle_evaluate_policy(
+        State(state): State<AppState>,
+        Json(req): Json<EvaluatePolicyRequest>,
+    ) -> AppResult<Json<ApiResponse<EvaluatePolicyResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.policy_controller.evaluate(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
@@ -14,6 +14,7 @@ use crate::api::dtos::{
     GetRolePermissionsRequest, GetRolePermissionsResponse, ListRolesRequest, ListRolesResponse,
     RevokePermissionRequest, RevokePermissionResponse, UpdateRoleRequest, UpdateRoleResponse,
 };
+use crate::api::middlewares::security::current_actor_id;
 use tradewinds_common::ApiResponse;
 use tradewinds_error::AppResult;
 
@@ -28,7 +29,7 @@ impl RoleHandler {
         State(state): State<AppState>,
         Json(req): Json<CreateRoleRequest>,
     ) -> AppResult<Json<ApiResponse<CreateRoleResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.role_controller.create_role(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -41,7 +42,7 @@ impl RoleHandler {
     ) -> AppResult<Json<ApiResponse<UpdateRoleResponse>>> {
         // 从路径参数设置id
         req.id = id;
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.role_controller.update_role(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -51,7 +52,7 @@ impl RoleHandler {
         State(state): State<AppState>,
         Path(id): Path<String>,
     ) -> AppResult<Json<ApiResponse<DeleteRoleResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let req = DeleteRoleRequest { id };
         let resp = state.role_controller.delete_role(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
@@ -62,7 +63,7 @@ impl RoleHandler {
         State(state): State<AppState>,
         Json(req): Json<AssignPermissionRequest>,
     ) -> AppResult<Json<ApiResponse<AssignPermissionResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let _ = state.role_controller.assign_permission(actor_id, req).await?;
         Ok(Json(ApiResponse::success(AssignPermissionResponse)))
     }
@@ -72,7 +73,7 @@ impl RoleHandler {
         State(state): State<AppState>,
         Json(req): Json<RevokePermissionRequest>,
     ) -> AppResult<Json<ApiResponse<RevokePermissionResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let _ = state.role_controller.revoke_permission(actor_id, req).await?;
         Ok(Json(ApiResponse::success(RevokePermissionResponse)))
     }
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use axum::extract::{Json, State};
+use axum::extract::{Json, Path, Query, State};
 
 #[rustfmt::skip]
 use crate::api::{
@@ -8,9 +8,7 @@ use crate::api::{
     dtos::system_setting_dto::*,
     state::AppState,
 };
-use crate::api::dtos::{
-    GetSystemSettingRequest, GetSystemSettingResponse, SetSystemSettingRequest, SetSystemSettingResponse,
-};
+use crate::api::middlewares::security::current_actor_id;
 use tradewinds_common::ApiResponse;
 use tradewinds_error::AppResult;
 
@@ -19,22 +17,34 @@ pub struct SystemSettingHandler {
 }
 
 impl SystemSettingHandler {
+    /// 获取系统设置列表
+    pub async fn handle_list_system_settings(
+        State(state): State<AppState>,
+        Query(req): Query<ListSystemSettingsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListSystemSettingsResponse>>> {
+        let resp = state.system_setting_controller.list(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
 
     /// 获取系统设置
     pub async fn handle_get_system_setting(
         State(state): State<AppState>,
-        Json(req): Json<GetSystemSettingRequest>,
-    ) -> AppResult<Json<ApiResponse<GetSystemSettingResponse>>> {
-        let resp = state.system_setting_controller.get_by_key(req).await?;
+        Path(key): Path<String>,
+    ) -> AppResult<Json<ApiResponse<SystemSettingResponse>>> {
+        let resp = state.system_setting_controller.get_by_key(GetSystemSettingRequest { key }).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 
     /// 设置系统设置
     pub async fn handle_set_system_setting(
         State(state): State<AppState>,
-        Json(req): Json<SetSystemSettingRequest>,
+        Path(key): Path<String>,
+        Json(mut req): Json<SetSystemSettingRequest>,
     ) -> AppResult<Json<ApiResponse<SetSystemSettingResponse>>> {
-        let resp = state.system_setting_controller.set_value(req).await?;
+        // 从路径参数设置key
+        req.key = key;
+        let actor_id = current_actor_id()?;
+        let resp = state.system_setting_controller.set_value(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 }
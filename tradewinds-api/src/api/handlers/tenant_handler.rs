@@ -0,0 +1,57 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::tenant_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct TenantHandler;
+
+impl TenantHandler {
+    /// 创建租户（含初始管理员）
+    pub async fn handle_create_tenant(
+        State(state): State<AppState>,
+        Json(req): Json<CreateTenantRequest>,
+    ) -> AppResult<Json<ApiResponse<CreateTenantResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.tenant_controller.create_tenant(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 更新租户
+    pub async fn handle_update_tenant(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(mut req): Json<UpdateTenantRequest>,
+    ) -> AppResult<Json<ApiResponse<UpdateTenantResponse>>> {
+        let actor_id = current_actor_id()?;
+        req.id = id;
+        let resp = state.tenant_controller.update_tenant(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取租户详情
+    pub async fn handle_get_tenant(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<GetTenantByIdResponse>>> {
+        let actor_id = current_actor_id()?;
+        let req = GetTenantByIdRequest { id };
+        let resp = state.tenant_controller.get_tenant_by_id(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取租户列表
+    pub async fn handle_list_tenants(
+        State(state): State<AppState>,
+        Query(query): Query<ListTenantsRequest>,
+    ) -> AppResult<Json<ApiResponse<ListTenantsResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.tenant_controller.list_tenants(actor_id, query).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
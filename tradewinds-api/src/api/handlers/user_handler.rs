@@ -1,20 +1,18 @@
 use std::sync::Arc;
 
 use axum::extract::{Json, Path, Query, State};
-use axum::http::HeaderMap;
 
 use crate::api::dtos::{
     AssignRoleRequest, AssignRoleResponse, CreateUserRequest, CreateUserResponse, DeleteUserRequest,
-    DeleteUserResponse, GetSystemSettingRequest, GetUserByEmailRequest, GetUserByEmailResponse, GetUserByIdRequest,
-    GetUserByIdResponse, GetUserByUsernameRequest, GetUserByUsernameResponse, GetUserPermissionsRequest,
-    GetUserPermissionsResponse, GetUserRolesRequest, GetUserRolesResponse, ListUsersRequest, ListUsersResponse,
-    ResetPasswordRequest, ResetPasswordResponse, RevokeRoleRequest, RevokeRoleResponse, UpdateCurrentUserRequest,
-    UpdateUserRequest, UpdateUserResponse,
+    DeleteUserResponse, ExplainUserAccessRequest, ExplainUserAccessResponse, GetSystemSettingRequest,
+    GetUserByEmailRequest, GetUserByEmailResponse, GetUserByIdRequest, GetUserByIdResponse, GetUserByUsernameRequest,
+    GetUserByUsernameResponse, GetUserPermissionsRequest, GetUserPermissionsResponse, GetUserRolesRequest,
+    GetUserRolesResponse, ListUsersRequest, ListUsersResponse, ResetPasswordRequest, ResetPasswordResponse,
+    RevokeRoleRequest, RevokeRoleResponse, UpdateCurrentUserRequest, UpdateUserRequest, UpdateUserResponse,
 };
+use crate::api::middlewares::security::current_actor_id;
 use crate::api::{AppState, UserController};
 use tradewinds_common::ApiResponse;
-use tradewinds_common::get_current_user_token;
-use tradewinds_domain::value_objects::Token;
 use tradewinds_error::AppResult;
 
 pub struct UserHandler {
@@ -22,13 +20,12 @@ pub struct UserHandler {
 }
 
 impl UserHandler {
-
     /// 创建用户
     pub async fn handle_create_user(
         State(state): State<AppState>,
         Json(req): Json<CreateUserRequest>,
     ) -> AppResult<Json<ApiResponse<CreateUserResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.user_controller.create_user(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -38,7 +35,7 @@ impl UserHandler {
         State(state): State<AppState>,
         Json(req): Json<UpdateUserRequest>,
     ) -> AppResult<Json<ApiResponse<UpdateUserResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.user_controller.update_user(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -46,13 +43,10 @@ impl UserHandler {
     /// 更新当前用户
     pub async fn handle_update_current_user(
         State(state): State<AppState>,
-        headers: HeaderMap,
-        Json(mut req): Json<UpdateCurrentUserRequest>,
+        Json(req): Json<UpdateCurrentUserRequest>,
     ) -> AppResult<Json<ApiResponse<UpdateUserResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
-        let token = get_current_user_token(&headers).await?;
-        let token = Token::new(token)?;
-        let user_id = state.token_service.get_user_id_from_token(&token).await?.to_string();
+        // 修改本人信息，操作人即当前用户
+        let user_id = current_actor_id()?;
         let req = UpdateUserRequest {
             id: user_id.clone(),
             real_name: req.real_name,
@@ -61,8 +55,9 @@ impl UserHandler {
             email: req.email,
             role_ids: None,
             status: None,
+            department_id: None,
         };
-        let resp = state.user_controller.update_user(actor_id, req).await?;
+        let resp = state.user_controller.update_user(user_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 
@@ -71,7 +66,7 @@ impl UserHandler {
         State(state): State<AppState>,
         Path(id): Path<String>,
     ) -> AppResult<Json<ApiResponse<DeleteUserResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let req = DeleteUserRequest { id };
         let resp = state.user_controller.delete_user(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
@@ -82,7 +77,7 @@ impl UserHandler {
         State(state): State<AppState>,
         Path(id): Path<String>,
     ) -> AppResult<Json<ApiResponse<ResetPasswordResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.user_controller.reset_password(actor_id, id).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -92,7 +87,7 @@ impl UserHandler {
         State(state): State<AppState>,
         Json(req): Json<AssignRoleRequest>,
     ) -> AppResult<Json<ApiResponse<AssignRoleResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.user_controller.assign_role(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -102,7 +97,7 @@ impl UserHandler {
         State(state): State<AppState>,
         Json(req): Json<RevokeRoleRequest>,
     ) -> AppResult<Json<ApiResponse<RevokeRoleResponse>>> {
-        let actor_id = "00000000-0000-0000-0000-000000000000".to_string(); // FIXME
+        let actor_id = current_actor_id()?;
         let resp = state.user_controller.revoke_role(actor_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -112,7 +107,8 @@ impl UserHandler {
         State(state): State<AppState>,
         Json(req): Json<GetUserByIdRequest>,
     ) -> AppResult<Json<ApiResponse<GetUserByIdResponse>>> {
-        let resp = state.user_controller.get_user_by_id(req).await?;
+        let principal_id = current_actor_id()?;
+        let resp = state.user_controller.get_user_by_id(principal_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 
@@ -121,7 +117,8 @@ impl UserHandler {
         State(state): State<AppState>,
         Json(req): Json<GetUserByUsernameRequest>,
     ) -> AppResult<Json<ApiResponse<GetUserByUsernameResponse>>> {
-        let resp = state.user_controller.get_user_by_username(req).await?;
+        let principal_id = current_actor_id()?;
+        let resp = state.user_controller.get_user_by_username(principal_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 
@@ -130,15 +127,17 @@ impl UserHandler {
         State(state): State<AppState>,
         Json(req): Json<GetUserByEmailRequest>,
     ) -> AppResult<Json<ApiResponse<GetUserByEmailResponse>>> {
-        let resp = state.user_controller.get_user_by_email(req).await?;
+        let principal_id = current_actor_id()?;
+        let resp = state.user_controller.get_user_by_email(principal_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 
     /// 获取用户角色
     pub async fn handle_get_user_roles(
         State(state): State<AppState>,
-        Json(req): Json<GetUserRolesRequest>,
+        Path(id): Path<String>,
     ) -> AppResult<Json<ApiResponse<GetUserRolesResponse>>> {
+        let req = GetUserRolesRequest { id };
         let resp = state.user_controller.get_user_roles(req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
@@ -152,12 +151,25 @@ impl UserHandler {
         Ok(Json(ApiResponse::success(resp)))
     }
 
+    /// 说明用户为何拥有或缺少某个权限
+    pub async fn handle_explain_user_access(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Query(mut req): Query<ExplainUserAccessRequest>,
+    ) -> AppResult<Json<ApiResponse<ExplainUserAccessResponse>>> {
+        req.id = id;
+        let resp = state.user_controller.explain_user_access(req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
     /// 获取用户列表
     pub async fn handle_list_users(
         State(state): State<AppState>,
         Query(req): Query<ListUsersRequest>,
     ) -> AppResult<Json<ApiResponse<ListUsersResponse>>> {
-        let resp = state.user_controller.list_users(req).await?;
+        // 按当前用户角色的数据范围过滤
+        let principal_id = current_actor_id()?;
+        let resp = state.user_controller.list_users(principal_id, req).await?;
         Ok(Json(ApiResponse::success(resp)))
     }
 }
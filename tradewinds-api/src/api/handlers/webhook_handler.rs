@@ -0,0 +1,75 @@
+use axum::extract::{Json, Path, Query, State};
+
+#[rustfmt::skip]
+use crate::api::{
+    dtos::webhook_dto::*,
+    state::AppState,
+};
+use crate::api::middlewares::security::current_actor_id;
+use tradewinds_common::ApiResponse;
+use tradewinds_error::AppResult;
+
+pub struct WebhookHandler;
+
+impl WebhookHandler {
+    /// 创建 Webhook 端点
+    pub async fn handle_create_webhook(
+        State(state): State<AppState>,
+        Json(req): Json<CreateWebhookRequest>,
+    ) -> AppResult<Json<ApiResponse<CreateWebhookResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.webhook_controller.create_webhook(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 获取 Webhook 端点列表
+    pub async fn handle_list_webhooks(
+        State(state): State<AppState>,
+    ) -> AppResult<Json<ApiResponse<ListWebhooksResponse>>> {
+        let resp = state.webhook_controller.list_webhooks().await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 更新 Webhook 端点
+    pub async fn handle_update_webhook(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Json(mut req): Json<UpdateWebhookRequest>,
+    ) -> AppResult<Json<ApiResponse<()>>> {
+        let actor_id = current_actor_id()?;
+        req.id = id;
+        state.webhook_controller.update_webhook(actor_id, req).await?;
+        Ok(Json(ApiResponse::success(())))
+    }
+
+    /// 删除 Webhook 端点
+    pub async fn handle_delete_webhook(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<()>>> {
+        let actor_id = current_actor_id()?;
+        state.webhook_controller.delete_webhook(actor_id, id).await?;
+        Ok(Json(ApiResponse::success(())))
+    }
+
+    /// 获取端点的投递记录
+    pub async fn handle_list_deliveries(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+        Query(filter): Query<WebhookDeliveryFilterRequest>,
+        Query(req): Query<ListWebhookDeliveriesRequest>,
+    ) -> AppResult<Json<ApiResponse<ListWebhookDeliveriesResponse>>> {
+        let resp = state.webhook_controller.list_deliveries(id, filter, req).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+
+    /// 重新投递
+    pub async fn handle_redeliver(
+        State(state): State<AppState>,
+        Path(id): Path<String>,
+    ) -> AppResult<Json<ApiResponse<RedeliverWebhookResponse>>> {
+        let actor_id = current_actor_id()?;
+        let resp = state.webhook_controller.redeliver(id, actor_id).await?;
+        Ok(Json(ApiResponse::success(resp)))
+    }
+}
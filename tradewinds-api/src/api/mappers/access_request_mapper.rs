@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use crate::api::dtos::access_request_dto::{
+    CancelAccessRequestRequest, DecideAccessRequestRequest, GetAccessRequestByIdRequest, GetRoleApproversRequest,
+    ListAccessRequestsRequest, SetRoleApproversRequest, SubmitAccessRequestRequest,
+};
+use tradewinds_application::commands::access_request::{
+    ApproveAccessRequestCommand, CancelAccessRequestCommand, ExpireAccessRequestsCommand, RejectAccessRequestCommand,
+    SetRoleApproversCommand, SubmitAccessRequestCommand,
+};
+use tradewinds_application::queries::access_request::{
+    AccessRequestScope, GetAccessRequestByIdQuery, GetRoleApproversQuery, ListAccessRequestsQuery,
+};
+use tradewinds_domain::value_objects::{
+    AccessDuration, AccessJustification, AccessRequestId, AccessRequestStatus, RoleId, UserId,
+};
+use tradewinds_error::AppResult;
+
+pub fn to_submit_access_request_command(
+    actor_id: String,
+    req: SubmitAccessRequestRequest,
+) -> AppResult<SubmitAccessRequestCommand> {
+    Ok(SubmitAccessRequestCommand {
+        requester_id: UserId::from_str(&actor_id)?,
+        role_id: RoleId::new(req.role_id)?,
+        justification: AccessJustification::new(req.justification)?,
+        duration: AccessDuration::from_days(req.duration_days)?,
+    })
+}
+
+pub fn to_approve_access_request_command(
+    actor_id: String,
+    req: DecideAccessRequestRequest,
+) -> AppResult<ApproveAccessRequestCommand> {
+    Ok(ApproveAccessRequestCommand {
+        id: AccessRequestId::new(req.id)?,
+        approver_id: UserId::from_str(&actor_id)?,
+        comment: req.comment,
+    })
+}
+
+pub fn to_reject_access_request_command(
+    actor_id: String,
+    req: DecideAccessRequestRequest,
+) -> AppResult<RejectAccessRequestCommand> {
+    Ok(RejectAccessRequestCommand {
+        id: AccessRequestId::new(req.id)?,
+        approver_id: UserId::from_str(&actor_id)?,
+        comment: req.comment,
+    })
+}
+
+pub fn to_cancel_access_request_command(
+    actor_id: String,
+    req: CancelAccessRequestRequest,
+) -> AppResult<CancelAccessRequestCommand> {
+    Ok(CancelAccessRequestCommand { id: AccessRequestId::new(req.id)?, requester_id: UserId::from_str(&actor_id)? })
+}
+
+pub fn to_expire_access_requests_command(actor_id: String) -> AppResult<ExpireAccessRequestsCommand> {
+    Ok(ExpireAccessRequestsCommand { triggered_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_get_access_request_by_id_query(
+    actor_id: String,
+    req: GetAccessRequestByIdRequest,
+) -> AppResult<GetAccessRequestByIdQuery> {
+    Ok(GetAccessRequestByIdQuery { actor_id: UserId::from_str(&actor_id)?, id: AccessRequestId::new(req.id)? })
+}
+
+pub fn to_list_access_requests_query(
+    actor_id: String,
+    req: ListAccessRequestsRequest,
+) -> AppResult<ListAccessRequestsQuery> {
+    Ok(ListAccessRequestsQuery {
+        actor_id: UserId::from_str(&actor_id)?,
+        scope: req.scope.as_deref().map(AccessRequestScope::from_str).transpose()?.unwrap_or_default(),
+        status: req.status.map(AccessRequestStatus::from_i32).transpose()?,
+        page: req.page,
+        page_size: req.page_size,
+    })
+}
+
+pub fn to_get_role_approvers_query(req: GetRoleApproversRequest) -> AppResult<GetRoleApproversQuery> {
+    Ok(GetRoleApproversQuery { role_id: RoleId::new(req.role_id)? })
+}
+
+pub fn to_set_role_approvers_command(
+    actor_id: String,
+    req: SetRoleApproversRequest,
+) -> AppResult<SetRoleApproversCommand> {
+    Ok(SetRoleApproversCommand {
+        role_id: RoleId::new(req.role_id)?,
+        approver_ids: req.user_ids.iter().map(|id| UserId::from_str(id)).collect::<AppResult<Vec<_>>>()?,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
@@ -0,0 +1,119 @@
+use std::str::FromStr;
+
+use chrono::DateTime;
+
+use crate::api::dtos::access_review_dto::{
+    DecideAccessReviewItemRequest, GetAccessReviewByIdRequest, GetAccessReviewReportRequest, LaunchAccessReviewRequest,
+    ListAccessReviewItemsRequest, ListAccessReviewsRequest,
+};
+use tradewinds_application::commands::access_review::{
+    CloseDueAccessReviewsCommand, DecideAccessReviewItemCommand, LaunchAccessReviewCommand,
+};
+use tradewinds_application::queries::access_review::{
+    AccessReviewReport, AccessReviewScope, GetAccessReviewByIdQuery, GetAccessReviewReportQuery,
+    ListAccessReviewItemsQuery, ListAccessReviewsQuery,
+};
+use tradewinds_common::utils::csv_row;
+use tradewinds_domain::value_objects::{
+    AccessReviewId, AccessReviewItemId, AccessReviewName, AccessReviewStatus, ReviewDecision, RoleId, UserId,
+};
+use tradewinds_error::AppResult;
+
+pub fn to_launch_access_review_command(
+    actor_id: String,
+    req: LaunchAccessReviewRequest,
+) -> AppResult<LaunchAccessReviewCommand> {
+    Ok(LaunchAccessReviewCommand {
+        created_by: UserId::from_str(&actor_id)?,
+        name: AccessReviewName::new(req.name)?,
+        role_ids: req.role_ids.into_iter().map(RoleId::new).collect::<AppResult<Vec<_>>>()?,
+        deadline: req.deadline,
+        reviewer_id: req.reviewer_id.as_deref().map(UserId::from_str).transpose()?,
+    })
+}
+
+pub fn to_decide_access_review_item_command(
+    actor_id: String,
+    req: DecideAccessReviewItemRequest,
+) -> AppResult<DecideAccessReviewItemCommand> {
+    Ok(DecideAccessReviewItemCommand {
+        review_id: AccessReviewId::new(req.review_id)?,
+        item_id: AccessReviewItemId::new(req.item_id)?,
+        reviewer_id: UserId::from_str(&actor_id)?,
+        decision: ReviewDecision::from_str(&req.decision)?,
+        comment: req.comment,
+    })
+}
+
+pub fn to_close_due_access_reviews_command(actor_id: String) -> AppResult<CloseDueAccessReviewsCommand> {
+    Ok(CloseDueAccessReviewsCommand { triggered_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_get_access_review_by_id_query(req: GetAccessReviewByIdRequest) -> AppResult<GetAccessReviewByIdQuery> {
+    Ok(GetAccessReviewByIdQuery { id: AccessReviewId::new(req.id)? })
+}
+
+pub fn to_list_access_reviews_query(
+    actor_id: String,
+    req: ListAccessReviewsRequest,
+) -> AppResult<ListAccessReviewsQuery> {
+    Ok(ListAccessReviewsQuery {
+        actor_id: UserId::from_str(&actor_id)?,
+        scope: req.scope.as_deref().map(AccessReviewScope::from_str).transpose()?.unwrap_or_default(),
+        status: req.status.map(AccessReviewStatus::from_i32).transpose()?,
+        page: req.page,
+        page_size: req.page_size,
+    })
+}
+
+pub fn to_list_access_review_items_query(
+    actor_id: String,
+    req: ListAccessReviewItemsRequest,
+) -> AppResult<ListAccessReviewItemsQuery> {
+    Ok(ListAccessReviewItemsQuery {
+        id: AccessReviewId::new(req.id)?,
+        reviewer_id: if req.mine { Some(UserId::from_str(&actor_id)?) } else { None },
+        decision: req.decision.map(ReviewDecision::from_i32).transpose()?,
+    })
+}
+
+pub fn to_get_access_review_report_query(req: GetAccessReviewReportRequest) -> AppResult<GetAccessReviewReportQuery> {
+    Ok(GetAccessReviewReportQuery { id: AccessReviewId::new(req.id)? })
+}
+
+/// 复核报告导出为 CSV，每个复核项一行，时间为 RFC 3339 格式
+pub fn to_access_review_report_csv(report: AccessReviewReport) -> String {
+    let format_time = |secs: i64| DateTime::from_timestamp(secs, 0).map(|t| t.to_rfc3339()).unwrap_or_default();
+    let campaign = &report.campaign;
+    let mut csv = csv_row(&[
+        "campaign_id",
+        "campaign_name",
+        "deadline",
+        "user_id",
+        "username",
+        "role_id",
+        "role_name",
+        "reviewer_id",
+        "reviewer_username",
+        "decision",
+        "comment",
+        "decided_at",
+    ]);
+    for row in report.rows {
+        csv.push_str(&csv_row(&[
+            campaign.id.to_string(),
+            campaign.name.to_string(),
+            format_time(campaign.deadline),
+            row.user_id,
+            row.username,
+            row.role_id,
+            row.role_name,
+            row.reviewer_id,
+            row.reviewer_username,
+            row.decision.as_str().to_string(),
+            row.comment.unwrap_or_default(),
+            row.decided_at.map(format_time).unwrap_or_default(),
+        ]));
+    }
+    csv
+}
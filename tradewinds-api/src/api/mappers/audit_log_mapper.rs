@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use chrono::DateTime;
+use serde_json::Value;
+
+use crate::api::dtos::audit_log_dto::{AuditLogFilterRequest, ListAuditLogsRequest};
+use tradewinds_application::queries::audit_log::{ExportAuditLogsQuery, ListAuditLogsQuery};
+use tradewinds_common::utils::csv_row;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_domain::repositories::AuditLogFilter;
+use tradewinds_domain::value_objects::{AuditOutcome, UserId};
+use tradewinds_error::AppResult;
+
+fn to_audit_log_filter(req: AuditLogFilterRequest) -> AppResult<AuditLogFilter> {
+    Ok(AuditLogFilter {
+        actor_id: req.actor_id.as_deref().map(UserId::from_str).transpose()?,
+        action: req.action,
+        target_type: req.target_type,
+        target_id: req.target_id,
+        outcome: req.outcome.as_deref().map(AuditOutcome::from_str).transpose()?,
+        from: req.from,
+        to: req.to,
+    })
+}
+
+pub fn to_list_audit_logs_query(
+    filter: AuditLogFilterRequest,
+    req: ListAuditLogsRequest,
+) -> AppResult<ListAuditLogsQuery> {
+    Ok(ListAuditLogsQuery { filter: to_audit_log_filter(filter)?, page: req.page, page_size: req.page_size })
+}
+
+pub fn to_export_audit_logs_query(filter: AuditLogFilterRequest) -> AppResult<ExportAuditLogsQuery> {
+    Ok(ExportAuditLogsQuery { filter: to_audit_log_filter(filter)? })
+}
+
+/// 审计日志导出为 CSV，参数与变更以 JSON 文本输出
+pub fn to_audit_logs_csv(logs: Vec<AuditLog>) -> String {
+    let format_time = |secs: i64| DateTime::from_timestamp(secs, 0).map(|t| t.to_rfc3339()).unwrap_or_default();
+    let json_text = |value: Option<Value>| value.map(|v| v.to_string()).unwrap_or_default();
+    let mut csv = csv_row(&[
+        "id",
+        "created_at",
+        "actor_id",
+        "actor_name",
+        "action",
+        "target_type",
+        "target_id",
+        "outcome",
+        "error_message",
+        "params",
+        "changes",
+        "client_ip",
+        "user_agent",
+        "request_id",
+        "seq",
+        "hash",
+    ]);
+    for log in logs {
+        csv.push_str(&csv_row(&[
+            log.id.to_string(),
+            format_time(log.created_at),
+            log.actor_id.map(|id| id.to_string()).unwrap_or_default(),
+            log.actor_name.unwrap_or_default(),
+            log.action,
+            log.target_type,
+            log.target_id.unwrap_or_default(),
+            log.outcome.to_string(),
+            log.error_message.unwrap_or_default(),
+            json_text(log.params),
+            json_text(log.changes),
+            log.client_ip.unwrap_or_default(),
+            log.user_agent.unwrap_or_default(),
+            log.request_id.unwrap_or_default(),
+            log.seq.to_string(),
+            log.hash,
+        ]));
+    }
+    csv
+}
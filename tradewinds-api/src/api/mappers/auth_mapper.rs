@@ -1,13 +1,13 @@
-use crate::api::dtos::auth_dto::{GetUserMenusRequest, MenuResponse};
+use crate::api::dtos::auth_dto::{GetLoginHistoryRequest, GetUserMenusRequest, MenuResponse};
 use crate::api::dtos::{
     ChangePasswordRequest, CurrentUserInfoResponse, GetCurrentUserRequest, LoginRequest, LogoutRequest,
     PermissionResponse, RegisterRequest, RoleResponse, UserResponse,
 };
 use tradewinds_application::commands::{ChangePasswordCommand, LoginCommand, LogoutCommand, RegisterCommand};
 use tradewinds_application::queries::GetCurrentUserQuery;
-use tradewinds_application::queries::auth::GetUserMenusQuery;
 use tradewinds_application::queries::auth::menu_info::MenuInfo;
 use tradewinds_application::queries::auth::user_info::CurrentUserInfo;
+use tradewinds_application::queries::auth::{GetLoginHistoryQuery, GetUserMenusQuery};
 use tradewinds_domain::value_objects::{AuthUsername, Email, Password, Phone, RealName, Token};
 use tradewinds_error::AppResult;
 
@@ -46,6 +46,10 @@ pub fn to_get_user_menus_query(req: GetUserMenusRequest) -> AppResult<GetUserMen
     Ok(GetUserMenusQuery { token })
 }
 
+pub fn to_get_login_history_query(req: GetLoginHistoryRequest) -> AppResult<GetLoginHistoryQuery> {
+    Ok(GetLoginHistoryQuery { token: Token::new(req.token)?, page: req.page, page_size: req.page_size })
+}
+
 pub fn to_current_user_info_response(info: CurrentUserInfo) -> CurrentUserInfoResponse {
     CurrentUserInfoResponse {
         user: UserResponse::from(info.user),
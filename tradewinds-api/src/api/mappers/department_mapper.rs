@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use crate::api::dtos::department_dto::{
+    CreateDepartmentRequest, DeleteDepartmentRequest, DepartmentTreeResponse, GetDepartmentByIdRequest,
+    ListDepartmentsRequest, UpdateDepartmentRequest,
+};
+use tradewinds_application::commands::department::{
+    CreateDepartmentCommand, DeleteDepartmentCommand, UpdateDepartmentCommand,
+};
+use tradewinds_application::queries::department::{GetDepartmentByIdQuery, ListDepartmentsQuery};
+use tradewinds_domain::entities::department::Department;
+use tradewinds_domain::value_objects::{DepartmentId, DepartmentName, DepartmentSort, DepartmentStatus, UserId};
+use tradewinds_error::AppResult;
+
+pub fn to_create_department_command(
+    actor_id: String,
+    req: CreateDepartmentRequest,
+) -> AppResult<CreateDepartmentCommand> {
+    Ok(CreateDepartmentCommand {
+        name: DepartmentName::new(req.name)?,
+        parent_id: req.parent_id.map(DepartmentId::new).transpose()?,
+        sort: req.sort.map(DepartmentSort::new).transpose()?.unwrap_or_default(),
+        leader_id: req.leader_id.map(|id| UserId::from_str(&id)).transpose()?,
+        created_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_update_department_command(
+    actor_id: String,
+    req: UpdateDepartmentRequest,
+) -> AppResult<UpdateDepartmentCommand> {
+    Ok(UpdateDepartmentCommand {
+        id: DepartmentId::new(req.id)?,
+        name: req.name.map(DepartmentName::new).transpose()?,
+        parent_id: match req.parent_id {
+            None => None,                                           // 不修改上级部门
+            Some(None) => Some(None),                               // 调整为顶级部门
+            Some(Some(pid)) => Some(Some(DepartmentId::new(pid)?)), // 调整到指定上级部门
+        },
+        sort: req.sort.map(DepartmentSort::new).transpose()?,
+        leader_id: match req.leader_id {
+            None => None,
+            Some(None) => Some(None),
+            Some(Some(id)) => Some(Some(UserId::from_str(&id)?)),
+        },
+        status: req.status.map(DepartmentStatus::from_i32).transpose()?,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_delete_department_command(
+    actor_id: String,
+    req: DeleteDepartmentRequest,
+) -> AppResult<DeleteDepartmentCommand> {
+    Ok(DeleteDepartmentCommand {
+        department_id: DepartmentId::new(req.id)?,
+        deleted_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_get_department_by_id_query(req: GetDepartmentByIdRequest) -> AppResult<GetDepartmentByIdQuery> {
+    Ok(GetDepartmentByIdQuery { department_id: DepartmentId::new(req.id)? })
+}
+
+pub fn to_list_departments_query(req: ListDepartmentsRequest) -> AppResult<ListDepartmentsQuery> {
+    Ok(ListDepartmentsQuery {
+        page: req.page,
+        page_size: req.page_size,
+        name: req.name.map(DepartmentName::new).transpose()?,
+        parent_id: req.parent_id.map(DepartmentId::new).transpose()?,
+        status: req.status.map(DepartmentStatus::from_i32).transpose()?,
+        show_deleted: req.show_deleted,
+    })
+}
+
+pub fn to_tree_responses(flat: Vec<Department>) -> Vec<DepartmentTreeResponse> {
+    let flat: Vec<DepartmentTreeResponse> = flat.into_iter().map(DepartmentTreeResponse::from).collect();
+    DepartmentTreeResponse::build_tree(flat)
+}
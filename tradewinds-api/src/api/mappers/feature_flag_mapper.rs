@@ -0,0 +1,42 @@
+use std::str::FromStr;
+
+use crate::api::dtos::feature_flag_dto::{CreateFeatureFlagRequest, UpdateFeatureFlagRequest};
+use tradewinds_application::commands::feature_flag::{
+    CreateFeatureFlagCommand, DeleteFeatureFlagCommand, UpdateFeatureFlagCommand,
+};
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_error::AppResult;
+
+pub fn to_create_feature_flag_command(
+    actor_id: String,
+    req: CreateFeatureFlagRequest,
+) -> AppResult<CreateFeatureFlagCommand> {
+    Ok(CreateFeatureFlagCommand {
+        key: req.key,
+        description: req.description,
+        enabled: req.enabled,
+        rollout_percentage: req.rollout_percentage,
+        allowed_roles: req.allowed_roles,
+        allowed_tenants: req.allowed_tenants,
+        created_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_update_feature_flag_command(
+    actor_id: String,
+    req: UpdateFeatureFlagRequest,
+) -> AppResult<UpdateFeatureFlagCommand> {
+    Ok(UpdateFeatureFlagCommand {
+        key: req.key,
+        description: req.description,
+        enabled: req.enabled,
+        rollout_percentage: req.rollout_percentage,
+        allowed_roles: req.allowed_roles,
+        allowed_tenants: req.allowed_tenants,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_delete_feature_flag_command(actor_id: String, key: String) -> AppResult<DeleteFeatureFlagCommand> {
+    Ok(DeleteFeatureFlagCommand { key, deleted_by: Some(UserId::from_str(&actor_id)?) })
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use crate::api::dtos::group_dto::{
+    AddGroupMembersRequest, CreateGroupRequest, DeleteGroupRequest, GetGroupByIdRequest, ListGroupMembersRequest,
+    ListGroupsRequest, RemoveGroupMemberRequest, UpdateGroupRequest,
+};
+use tradewinds_application::commands::group::{
+    AddGroupMembersCommand, CreateGroupCommand, DeleteGroupCommand, RemoveGroupMemberCommand, UpdateGroupCommand,
+};
+use tradewinds_application::queries::group::{GetGroupByIdQuery, ListGroupMembersQuery, ListGroupsQuery};
+use tradewinds_domain::value_objects::{GroupDescription, GroupId, GroupName, GroupStatus, RoleId, UserId};
+use tradewinds_error::AppResult;
+
+pub fn to_create_group_command(actor_id: String, req: CreateGroupRequest) -> AppResult<CreateGroupCommand> {
+    Ok(CreateGroupCommand {
+        name: GroupName::new(req.name)?,
+        description: req.description.map(GroupDescription::new).transpose()?,
+        role_ids: req.role_ids.into_iter().map(RoleId::new).collect::<AppResult<Vec<_>>>()?,
+        created_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_update_group_command(actor_id: String, req: UpdateGroupRequest) -> AppResult<UpdateGroupCommand> {
+    Ok(UpdateGroupCommand {
+        id: GroupId::new(req.id)?,
+        name: req.name.map(GroupName::new).transpose()?,
+        description: req.description.map(GroupDescription::new).transpose()?,
+        status: req.status.map(GroupStatus::from_i32).transpose()?,
+        role_ids: req
+            .role_ids
+            .map(|ids| ids.into_iter().map(RoleId::new).collect::<AppResult<Vec<_>>>())
+            .transpose()?,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_delete_group_command(actor_id: String, req: DeleteGroupRequest) -> AppResult<DeleteGroupCommand> {
+    Ok(DeleteGroupCommand { group_id: GroupId::new(req.id)?, deleted_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_add_group_members_command(
+    actor_id: String,
+    req: AddGroupMembersRequest,
+) -> AppResult<AddGroupMembersCommand> {
+    Ok(AddGroupMembersCommand {
+        group_id: GroupId::new(req.group_id)?,
+        user_ids: req.user_ids.iter().map(|id| UserId::from_str(id)).collect::<AppResult<Vec<_>>>()?,
+        added_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_remove_group_member_command(
+    actor_id: String,
+    req: RemoveGroupMemberRequest,
+) -> AppResult<RemoveGroupMemberCommand> {
+    Ok(RemoveGroupMemberCommand {
+        group_id: GroupId::new(req.group_id)?,
+        user_id: UserId::from_str(&req.user_id)?,
+        removed_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_get_group_by_id_query(req: GetGroupByIdRequest) -> AppResult<GetGroupByIdQuery> {
+    Ok(GetGroupByIdQuery { group_id: GroupId::new(req.id)? })
+}
+
+pub fn to_list_group_members_query(req: ListGroupMembersRequest) -> AppResult<ListGroupMembersQuery> {
+    Ok(ListGroupMembersQuery { group_id: GroupId::new(req.group_id)? })
+}
+
+pub fn to_list_groups_query(req: ListGroupsRequest) -> AppResult<ListGroupsQuery> {
+    Ok(ListGroupsQuery {
+        page: req.page,
+        page_size: req.page_size,
+        name: req.name.map(GroupName::new).transpose()?,
+        status: req.status.map(GroupStatus::from_i32).transpose()?,
+        show_deleted: req.show_deleted,
+    })
+}
@@ -0,0 +1,32 @@
+use std::str::FromStr;
+
+use crate::api::dtos::job_dto::{JobRunFilterRequest, ListJobRunsRequest};
+use tradewinds_application::commands::job::{PauseJobCommand, ResumeJobCommand, TriggerJobCommand};
+use tradewinds_application::queries::job::ListJobRunsQuery;
+use tradewinds_domain::repositories::JobRunFilter;
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_domain::value_objects::job::JobRunStatus;
+use tradewinds_error::AppResult;
+
+pub fn to_list_job_runs_query(filter: JobRunFilterRequest, req: ListJobRunsRequest) -> AppResult<ListJobRunsQuery> {
+    Ok(ListJobRunsQuery {
+        filter: JobRunFilter {
+            job_name: filter.job_name,
+            status: filter.status.as_deref().map(JobRunStatus::from_str).transpose()?,
+        },
+        page: req.page,
+        page_size: req.page_size,
+    })
+}
+
+pub fn to_trigger_job_command(name: String, actor_id: String) -> AppResult<TriggerJobCommand> {
+    Ok(TriggerJobCommand { name, triggered_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_pause_job_command(name: String, actor_id: String) -> AppResult<PauseJobCommand> {
+    Ok(PauseJobCommand { name, paused_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_resume_job_command(name: String, actor_id: String) -> AppResult<ResumeJobCommand> {
+    Ok(ResumeJobCommand { name, resumed_by: Some(UserId::from_str(&actor_id)?) })
+}
@@ -0,0 +1,30 @@
+use std::str::FromStr;
+
+use crate::api::dtos::login_log_dto::{ListLoginLogsRequest, LoginLogFilterRequest};
+use tradewinds_application::commands::login_log::PurgeLoginLogsCommand;
+use tradewinds_application::queries::login_log::ListLoginLogsQuery;
+use tradewinds_domain::repositories::LoginLogFilter;
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_error::AppResult;
+
+fn to_login_log_filter(req: LoginLogFilterRequest) -> AppResult<LoginLogFilter> {
+    Ok(LoginLogFilter {
+        user_id: req.user_id.as_deref().map(UserId::from_str).transpose()?,
+        username: req.username,
+        success: req.success,
+        client_ip: req.client_ip,
+        from: req.from,
+        to: req.to,
+    })
+}
+
+pub fn to_list_login_logs_query(
+    filter: LoginLogFilterRequest,
+    req: ListLoginLogsRequest,
+) -> AppResult<ListLoginLogsQuery> {
+    Ok(ListLoginLogsQuery { filter: to_login_log_filter(filter)?, page: req.page, page_size: req.page_size })
+}
+
+pub fn to_purge_login_logs_command(actor_id: String) -> AppResult<PurgeLoginLogsCommand> {
+    Ok(PurgeLoginLogsCommand { triggered_by: Some(UserId::from_str(&actor_id)?) })
+}
@@ -1,6 +1,18 @@
 //! src/interfaces/api/mappers/mod.rs
+pub mod access_request_mapper;
+pub mod access_review_mapper;
+pub mod audit_log_mapper;
 pub mod auth_mapper;
+pub mod department_mapper;
+pub mod feature_flag_mapper;
+pub mod group_mapper;
+pub mod job_mapper;
+pub mod login_log_mapper;
+pub mod outbox_mapper;
 pub mod permission_mapper;
+pub mod policy_mapper;
 pub mod role_mapper;
+pub mod tenant_mapper;
 pub mod user_mapper;
+pub mod webhook_mapper;
 pub mod system_setting_mapper;
@@ -0,0 +1,37 @@
+use std::str::FromStr;
+
+use crate::api::dtos::outbox_dto::{ListOutboxMessagesRequest, OutboxFilterRequest};
+use tradewinds_application::commands::outbox::RetryOutboxMessageCommand;
+use tradewinds_application::queries::outbox::ListOutboxMessagesQuery;
+use tradewinds_domain::repositories::OutboxFilter;
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_domain::value_objects::outbox::{OutboxMessageId, OutboxStatus};
+use tradewinds_error::AppResult;
+
+fn to_outbox_filter(req: &OutboxFilterRequest) -> AppResult<OutboxFilter> {
+    Ok(OutboxFilter {
+        status: req.status.as_deref().map(OutboxStatus::from_str).transpose()?,
+        event_type: req.event_type.clone(),
+        aggregate_id: req.aggregate_id.clone(),
+        stuck_before: None,
+    })
+}
+
+pub fn to_list_outbox_messages_query(
+    filter: OutboxFilterRequest,
+    req: ListOutboxMessagesRequest,
+) -> AppResult<ListOutboxMessagesQuery> {
+    Ok(ListOutboxMessagesQuery {
+        filter: to_outbox_filter(&filter)?,
+        stuck: filter.stuck.unwrap_or(false),
+        page: req.page,
+        page_size: req.page_size,
+    })
+}
+
+pub fn to_retry_outbox_message_command(id: String, actor_id: String) -> AppResult<RetryOutboxMessageCommand> {
+    Ok(RetryOutboxMessageCommand {
+        id: OutboxMessageId::from_str(&id)?,
+        triggered_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
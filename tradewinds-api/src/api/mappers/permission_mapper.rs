@@ -119,6 +119,7 @@ pub fn to_permission_tree_response(
         icon: permission.icon.as_ref().map(|i| i.to_string()),
         sort: *permission.sort,
         status: permission.status.to_string(),
+        built_in: permission.built_in,
         created_at: permission.created_at,
         updated_at: permission.updated_at,
         children,
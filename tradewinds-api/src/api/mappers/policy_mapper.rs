@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use crate::api::dtos::policy_dto::{
+    CreatePolicyRequest, DeletePolicyRequest, EvaluatePolicyRequest, GetPolicyByIdRequest, ListPoliciesRequest,
+    UpdatePolicyRequest,
+};
+use tradewinds_application::commands::policy::{
+    CreateAccessPolicyCommand, DeleteAccessPolicyCommand, UpdateAccessPolicyCommand,
+};
+use tradewinds_application::queries::policy::{
+    EvaluateAccessPolicyQuery, GetAccessPolicyByIdQuery, ListAccessPoliciesQuery,
+};
+use tradewinds_domain::value_objects::{
+    AccessPolicyId, AccessPolicyName, AccessPolicyStatus, PermissionCode, PermissionEffect, PolicyExpression, UserId,
+};
+use tradewinds_error::AppResult;
+
+pub fn to_create_policy_command(actor_id: String, req: CreatePolicyRequest) -> AppResult<CreateAccessPolicyCommand> {
+    Ok(CreateAccessPolicyCommand {
+        name: AccessPolicyName::new(req.name)?,
+        description: req.description,
+        action: PermissionCode::new(req.action)?,
+        effect: PermissionEffect::from_str(&req.effect)?,
+        condition: PolicyExpression::new(req.condition)?,
+        created_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_update_policy_command(actor_id: String, req: UpdatePolicyRequest) -> AppResult<UpdateAccessPolicyCommand> {
+    Ok(UpdateAccessPolicyCommand {
+        id: AccessPolicyId::new(req.id)?,
+        name: req.name.map(AccessPolicyName::new).transpose()?,
+        description: req.description.map(|d| if d.trim().is_empty() { None } else { Some(d) }),
+        action: req.action.map(PermissionCode::new).transpose()?,
+        effect: req.effect.as_deref().map(PermissionEffect::from_str).transpose()?,
+        condition: req.condition.map(PolicyExpression::new).transpose()?,
+        status: req.status.map(AccessPolicyStatus::from_i32).transpose()?,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_delete_policy_command(actor_id: String, req: DeletePolicyRequest) -> AppResult<DeleteAccessPolicyCommand> {
+    Ok(DeleteAccessPolicyCommand { id: AccessPolicyId::new(req.id)?, deleted_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_get_policy_by_id_query(req: GetPolicyByIdRequest) -> AppResult<GetAccessPolicyByIdQuery> {
+    Ok(GetAccessPolicyByIdQuery { id: AccessPolicyId::new(req.id)? })
+}
+
+pub fn to_list_policies_query(req: ListPoliciesRequest) -> AppResult<ListAccessPoliciesQuery> {
+    Ok(ListAccessPoliciesQuery {
+        page: req.page,
+        page_size: req.page_size,
+        keyword: req.keyword,
+        status: req.status.map(AccessPolicyStatus::from_i32).transpose()?,
+    })
+}
+
+pub fn to_evaluate_policy_query(actor_id: String, req: EvaluatePolicyRequest) -> AppResult<EvaluateAccessPolicyQuery> {
+    Ok(EvaluateAccessPolicyQuery {
+        actor_id: UserId::from_str(&actor_id)?,
+        subject_id: req.user_id.as_deref().map(UserId::from_str).transpose()?,
+        action: PermissionCode::new(req.action)?,
+        resource: req.resource,
+        environment: req.environment,
+    })
+}
@@ -11,10 +11,12 @@ use tradewinds_application::queries::role::{
     GetRoleByIdQuery, GetRoleByNameQuery, GetRolePermissionsQuery, ListRolesQuery,
 };
 use tradewinds_domain::value_objects::role::RoleCode;
-use tradewinds_domain::value_objects::{PermissionId, RoleDescription, RoleId, RoleName, RoleStatus, UserId};
+use tradewinds_domain::value_objects::{
+    DataScopeType, DepartmentId, PermissionId, RoleDescription, RoleId, RoleName, RoleStatus, UserId,
+};
 use tradewinds_error::AppResult;
 
-pub fn to_create_role_command(req: CreateRoleRequest) -> AppResult<CreateRoleCommand> {
+pub fn to_create_role_command(actor_id: String, req: CreateRoleRequest) -> AppResult<CreateRoleCommand> {
     Ok(CreateRoleCommand {
         name: RoleName::new(req.name)?,
         code: RoleCode::new(req.code)?,
@@ -25,7 +27,21 @@ pub fn to_create_role_command(req: CreateRoleRequest) -> AppResult<CreateRoleCom
             .into_iter()
             .map(PermissionId::new)
             .collect::<AppResult<_>>()?,
+        denied_permissions: req
+            .denied_permission_ids
+            .unwrap_or_default()
+            .into_iter()
+            .map(PermissionId::new)
+            .collect::<AppResult<_>>()?,
         status: req.status.map(RoleStatus::from_i32).transpose()?, // 修正类型转换
+        data_scope: req.data_scope.map(DataScopeType::from_i32).transpose()?,
+        data_scope_departments: req
+            .data_scope_department_ids
+            .unwrap_or_default()
+            .into_iter()
+            .map(DepartmentId::new)
+            .collect::<AppResult<_>>()?,
+        created_by: Some(UserId::from_str(&actor_id)?),
     })
 }
 
@@ -40,6 +56,15 @@ pub fn to_update_role_command(actor_id: String, req: UpdateRoleRequest) -> AppRe
             .permission_ids
             .map(|ids| ids.into_iter().map(PermissionId::new).collect::<AppResult<_>>())
             .transpose()?,
+        denied_permissions: req
+            .denied_permission_ids
+            .map(|ids| ids.into_iter().map(PermissionId::new).collect::<AppResult<_>>())
+            .transpose()?,
+        data_scope: req.data_scope.map(DataScopeType::from_i32).transpose()?,
+        data_scope_departments: req
+            .data_scope_department_ids
+            .map(|ids| ids.into_iter().map(DepartmentId::new).collect::<AppResult<_>>())
+            .transpose()?,
     })
 }
 
@@ -1,15 +1,29 @@
-use crate::api::dtos::system_setting_dto::{GetSystemSettingRequest, SetSystemSettingRequest};
+use std::str::FromStr;
+
+use crate::api::dtos::system_setting_dto::{
+    GetSystemSettingRequest, ListSystemSettingsRequest, SetSystemSettingRequest,
+};
 use tradewinds_application::commands::system_setting::set_system_setting_command::SetSystemSettingCommand;
-use tradewinds_application::queries::system_setting::get_system_setting_query::GetSystemSettingQuery;
-use tradewinds_domain::value_objects::system_setting::{SystemSettingKey, SystemSettingValue};
+use tradewinds_application::queries::system_setting::{GetSystemSettingQuery, ListSystemSettingsQuery};
+use tradewinds_domain::value_objects::system_setting::{SettingCategory, SystemSettingKey, SystemSettingValue};
+use tradewinds_domain::value_objects::user::UserId;
+use tradewinds_error::{AppError, AppResult};
+
+pub fn to_get_system_setting_query(req: GetSystemSettingRequest) -> AppResult<GetSystemSettingQuery> {
+    Ok(GetSystemSettingQuery { key: SystemSettingKey::new(req.key).map_err(AppError::Validation)? })
+}
 
-pub fn to_get_system_setting_query(req: GetSystemSettingRequest) -> GetSystemSettingQuery {
-    GetSystemSettingQuery { key: SystemSettingKey::new(req.key).unwrap() }
+pub fn to_list_system_settings_query(req: ListSystemSettingsRequest) -> AppResult<ListSystemSettingsQuery> {
+    Ok(ListSystemSettingsQuery { category: req.category.as_deref().map(SettingCategory::from_str).transpose()? })
 }
 
-pub fn to_set_system_setting_command(req: SetSystemSettingRequest) -> SetSystemSettingCommand {
-    SetSystemSettingCommand {
-        key: SystemSettingKey::new(req.key).unwrap(),
-        value: SystemSettingValue::new(req.value).unwrap(),
-    }
+pub fn to_set_system_setting_command(
+    actor_id: String,
+    req: SetSystemSettingRequest,
+) -> AppResult<SetSystemSettingCommand> {
+    Ok(SetSystemSettingCommand {
+        key: SystemSettingKey::new(req.key).map_err(AppError::Validation)?,
+        value: SystemSettingValue::new(req.value).map_err(AppError::Validation)?,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
 }
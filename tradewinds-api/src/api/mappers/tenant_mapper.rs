@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use crate::api::dtos::tenant_dto::{
+    CreateTenantRequest, GetTenantByIdRequest, ListTenantsRequest, ResolveTenantRequest, UpdateTenantRequest,
+};
+use tradewinds_application::commands::tenant::{CreateTenantCommand, UpdateTenantCommand};
+use tradewinds_application::queries::tenant::{GetTenantByIdQuery, ListTenantsQuery, ResolveTenantQuery};
+use tradewinds_domain::value_objects::{
+    AuthUsername, Email, Password, TenantCode, TenantHost, TenantId, TenantName, TenantStatus, UserId,
+};
+use tradewinds_error::AppResult;
+
+pub fn to_create_tenant_command(actor_id: String, req: CreateTenantRequest) -> AppResult<CreateTenantCommand> {
+    Ok(CreateTenantCommand {
+        code: TenantCode::new(req.code)?,
+        name: TenantName::new(req.name)?,
+        host: req.host.map(TenantHost::new).transpose()?,
+        admin_username: AuthUsername::new(req.admin_username)?,
+        admin_email: Email::new(req.admin_email)?,
+        admin_password: Password::new(req.admin_password)?,
+        created_by: UserId::from_str(&actor_id)?,
+    })
+}
+
+pub fn to_update_tenant_command(actor_id: String, req: UpdateTenantRequest) -> AppResult<UpdateTenantCommand> {
+    Ok(UpdateTenantCommand {
+        id: TenantId::new(req.id)?,
+        name: req.name.map(TenantName::new).transpose()?,
+        host: req
+            .host
+            .map(|host| if host.trim().is_empty() { Ok(None) } else { TenantHost::new(host).map(Some) })
+            .transpose()?,
+        status: req.status.map(TenantStatus::from_i32).transpose()?,
+        updated_by: UserId::from_str(&actor_id)?,
+    })
+}
+
+pub fn to_get_tenant_by_id_query(actor_id: String, req: GetTenantByIdRequest) -> AppResult<GetTenantByIdQuery> {
+    Ok(GetTenantByIdQuery { tenant_id: TenantId::new(req.id)?, actor_id: UserId::from_str(&actor_id)? })
+}
+
+pub fn to_list_tenants_query(actor_id: String, req: ListTenantsRequest) -> AppResult<ListTenantsQuery> {
+    Ok(ListTenantsQuery {
+        page: req.page,
+        page_size: req.page_size,
+        keyword: req.keyword,
+        status: req.status.map(TenantStatus::from_i32).transpose()?,
+        actor_id: UserId::from_str(&actor_id)?,
+    })
+}
+
+pub fn to_resolve_tenant_query(req: ResolveTenantRequest) -> AppResult<ResolveTenantQuery> {
+    Ok(ResolveTenantQuery {
+        id: req.id.map(TenantId::new).transpose()?,
+        code: req.code.map(TenantCode::new).transpose()?,
+        // Host 不合法时按未绑定处理，回退到平台默认租户
+        host: req.host.and_then(|host| TenantHost::new(host).ok()),
+    })
+}
@@ -1,19 +1,19 @@
 use std::str::FromStr;
 
 use crate::api::dtos::{
-    AssignRoleRequest, CreateUserRequest, DeleteUserRequest, GetUserByEmailRequest, GetUserByIdRequest,
-    GetUserByUsernameRequest, GetUserPermissionsRequest, GetUserRolesRequest, ListUsersRequest, RevokeRoleRequest,
-    UpdateUserRequest,
+    AssignRoleRequest, CreateUserRequest, DeleteUserRequest, ExplainUserAccessRequest, GetUserByEmailRequest,
+    GetUserByIdRequest, GetUserByUsernameRequest, GetUserPermissionsRequest, GetUserRolesRequest, ListUsersRequest,
+    RevokeRoleRequest, UpdateUserRequest,
 };
 use tradewinds_application::commands::user::{
     AssignRoleCommand, CreateUserCommand, DeleteUserCommand, ResetPasswordCommand, RevokeRoleCommand, UpdateUserCommand,
 };
 use tradewinds_application::queries::user::{
-    GetUserByEmailQuery, GetUserByIdQuery, GetUserByUsernameQuery, GetUserPermissionsQuery, GetUserRolesQuery,
-    ListUsersQuery,
+    ExplainUserAccessQuery, GetUserByEmailQuery, GetUserByIdQuery, GetUserByUsernameQuery, GetUserPermissionsQuery,
+    GetUserRolesQuery, ListUsersQuery,
 };
 use tradewinds_domain::value_objects::{
-    AuthUsername, Avatar, Email, Password, Phone, RealName, RoleId, UserId, UserStatus,
+    AuthUsername, Avatar, DepartmentId, Email, Password, PermissionCode, Phone, RealName, RoleId, UserId, UserStatus,
 };
 
 use tradewinds_error::AppResult;
@@ -37,6 +37,7 @@ pub fn to_create_user_command(actor_id: String, req: CreateUserRequest) -> AppRe
         phone: req.phone.map(Phone::new).transpose()?,
         avatar: req.avatar.map(Avatar::new).transpose()?,
         role_ids,
+        department_id: req.department_id.map(DepartmentId::new).transpose()?,
         created_by: Some(UserId::from_str(&actor_id)?),
     })
 }
@@ -59,6 +60,11 @@ pub fn to_update_user_command(actor_id: String, req: UpdateUserRequest) -> AppRe
         status: req.status.map(|s| UserStatus::from_i32(s)).transpose()?,
         email: req.email.map(Email::new).transpose()?,
         role_ids,
+        department_id: match req.department_id {
+            None => None,                                         // 不修改所属部门
+            Some(None) => Some(None),                             // 移出部门
+            Some(Some(id)) => Some(Some(DepartmentId::new(id)?)), // 调整为指定部门
+        },
         updated_by: Some(UserId::from_str(&actor_id)?),
     })
 }
@@ -72,6 +78,7 @@ pub fn to_assign_role_command(actor_id: String, req: AssignRoleRequest) -> AppRe
         user_id: UserId::from_str(&req.user_id)?,
         role_id: RoleId::new(req.role_id)?,
         assigned_by: Some(UserId::from_str(&actor_id)?),
+        access_request_id: None,
     })
 }
 
@@ -83,16 +90,22 @@ pub fn to_revoke_role_command(actor_id: String, req: RevokeRoleRequest) -> AppRe
     })
 }
 
-pub fn to_get_user_by_id_query(req: GetUserByIdRequest) -> AppResult<GetUserByIdQuery> {
-    Ok(GetUserByIdQuery { user_id: UserId::from_str(&req.id)? })
+pub fn to_get_user_by_id_query(principal_id: String, req: GetUserByIdRequest) -> AppResult<GetUserByIdQuery> {
+    Ok(GetUserByIdQuery { user_id: UserId::from_str(&req.id)?, principal_id: Some(UserId::from_str(&principal_id)?) })
 }
 
-pub fn to_get_user_by_username_query(req: GetUserByUsernameRequest) -> AppResult<GetUserByUsernameQuery> {
-    Ok(GetUserByUsernameQuery { username: AuthUsername::new(req.username)? })
+pub fn to_get_user_by_username_query(
+    principal_id: String,
+    req: GetUserByUsernameRequest,
+) -> AppResult<GetUserByUsernameQuery> {
+    Ok(GetUserByUsernameQuery {
+        username: AuthUsername::new(req.username)?,
+        principal_id: Some(UserId::from_str(&principal_id)?),
+    })
 }
 
-pub fn to_get_user_by_email_query(req: GetUserByEmailRequest) -> AppResult<GetUserByEmailQuery> {
-    Ok(GetUserByEmailQuery { user_email: Email::new(req.email)? })
+pub fn to_get_user_by_email_query(principal_id: String, req: GetUserByEmailRequest) -> AppResult<GetUserByEmailQuery> {
+    Ok(GetUserByEmailQuery { user_email: Email::new(req.email)?, principal_id: Some(UserId::from_str(&principal_id)?) })
 }
 
 pub fn to_get_user_roles_query(req: GetUserRolesRequest) -> AppResult<GetUserRolesQuery> {
@@ -103,7 +116,11 @@ pub fn to_get_user_permissions_query(req: GetUserPermissionsRequest) -> AppResul
     Ok(GetUserPermissionsQuery { user_id: UserId::from_str(&req.id)? })
 }
 
-pub fn to_list_users_query(req: ListUsersRequest) -> AppResult<ListUsersQuery> {
+pub fn to_explain_user_access_query(req: ExplainUserAccessRequest) -> AppResult<ExplainUserAccessQuery> {
+    Ok(ExplainUserAccessQuery { user_id: UserId::from_str(&req.id)?, code: PermissionCode::new(req.code)? })
+}
+
+pub fn to_list_users_query(principal_id: String, req: ListUsersRequest) -> AppResult<ListUsersQuery> {
     Ok(ListUsersQuery {
         page: req.page,
         page_size: req.page_size,
@@ -112,12 +129,11 @@ pub fn to_list_users_query(req: ListUsersRequest) -> AppResult<ListUsersQuery> {
         status: req.status,
         email: req.email,
         show_deleted: req.show_deleted,
+        department_id: req.department_id.map(DepartmentId::new).transpose()?,
+        principal_id: Some(UserId::from_str(&principal_id)?),
     })
 }
 
 pub fn to_reset_password_command(actor_id: String, id: String) -> AppResult<ResetPasswordCommand> {
-    Ok(ResetPasswordCommand {
-        id: UserId::from_str(&id)?,
-        reset_by: Some(UserId::from_str(&actor_id)?),
-    })
+    Ok(ResetPasswordCommand { id: UserId::from_str(&id)?, reset_by: Some(UserId::from_str(&actor_id)?) })
 }
@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use crate::api::dtos::webhook_dto::{
+    CreateWebhookRequest, ListWebhookDeliveriesRequest, UpdateWebhookRequest, WebhookDeliveryFilterRequest,
+};
+use tradewinds_application::commands::webhook::{
+    CreateWebhookCommand, DeleteWebhookCommand, RedeliverWebhookDeliveryCommand, UpdateWebhookCommand,
+};
+use tradewinds_application::queries::webhook::ListWebhookDeliveriesQuery;
+use tradewinds_domain::repositories::WebhookDeliveryFilter;
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_domain::value_objects::webhook::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookId};
+use tradewinds_error::AppResult;
+
+pub fn to_create_webhook_command(actor_id: String, req: CreateWebhookRequest) -> AppResult<CreateWebhookCommand> {
+    Ok(CreateWebhookCommand {
+        name: req.name,
+        url: req.url,
+        secret: req.secret,
+        event_types: req.event_types,
+        created_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_update_webhook_command(actor_id: String, req: UpdateWebhookRequest) -> AppResult<UpdateWebhookCommand> {
+    Ok(UpdateWebhookCommand {
+        id: WebhookId::new(req.id)?,
+        name: req.name,
+        url: req.url,
+        secret: req.secret,
+        event_types: req.event_types,
+        enabled: req.enabled,
+        updated_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
+
+pub fn to_delete_webhook_command(actor_id: String, id: String) -> AppResult<DeleteWebhookCommand> {
+    Ok(DeleteWebhookCommand { id: WebhookId::new(id)?, deleted_by: Some(UserId::from_str(&actor_id)?) })
+}
+
+pub fn to_list_webhook_deliveries_query(
+    webhook_id: String,
+    filter: WebhookDeliveryFilterRequest,
+    req: ListWebhookDeliveriesRequest,
+) -> AppResult<ListWebhookDeliveriesQuery> {
+    Ok(ListWebhookDeliveriesQuery {
+        filter: WebhookDeliveryFilter {
+            webhook_id: Some(WebhookId::new(webhook_id)?),
+            status: filter.status.as_deref().map(WebhookDeliveryStatus::from_str).transpose()?,
+            event_type: filter.event_type,
+        },
+        page: req.page,
+        page_size: req.page_size,
+    })
+}
+
+pub fn to_redeliver_command(id: String, actor_id: String) -> AppResult<RedeliverWebhookDeliveryCommand> {
+    Ok(RedeliverWebhookDeliveryCommand {
+        id: WebhookDeliveryId::from_str(&id)?,
+        triggered_by: Some(UserId::from_str(&actor_id)?),
+    })
+}
@@ -0,0 +1,76 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    body::Body,
+    extract::ConnectInfo,
+    http::{HeaderMap, HeaderValue, Request, Response, header::USER_AGENT},
+    middleware::Next,
+};
+use tradewinds_common::request_context::{RequestContext, with_request_context};
+
+/// 反向代理传递客户端地址的请求头
+const FORWARDED_FOR_HEADER: &str = "X-Forwarded-For";
+const REAL_IP_HEADER: &str = "X-Real-IP";
+/// 请求ID请求头，响应中原样返回
+const REQUEST_ID_HEADER: &str = "X-Request-Id";
+/// 采信的客户端请求ID最大长度
+const MAX_REQUEST_ID_LEN: usize = 64;
+
+/// 请求上下文中间件
+///
+/// 解析客户端地址、User-Agent 与请求ID，后续处理在该上下文内执行。
+/// 请求ID 优先采用客户端传入的 `X-Request-Id`（仅限字母、数字与 `-_.`），否则生成新的 UUID，并写回响应头。
+/// 仅当直连地址为本机或内网（即经由反向代理）时才采信转发请求头，避免客户端伪造来源地址。
+pub async fn request_context(req: Request<Body>, next: Next) -> Response<Body> {
+    let peer = req.extensions().get::<ConnectInfo<SocketAddr>>().map(|ConnectInfo(addr)| addr.ip());
+    let headers = req.headers();
+    let client_ip = match peer {
+        Some(peer) if is_trusted_proxy(peer) => Some(forwarded_ip(headers).unwrap_or(peer)),
+        Some(peer) => Some(peer),
+        None => forwarded_ip(headers),
+    };
+    let request_id = request_id(headers).unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+    let context = RequestContext {
+        client_ip: client_ip.map(|ip| ip.to_canonical().to_string()),
+        user_agent: headers.get(USER_AGENT).and_then(|v| v.to_str().ok()).map(str::to_string),
+        request_id: Some(request_id.clone()),
+        actor_id: None,
+    };
+    let mut response = with_request_context(context, next.run(req)).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
+
+/// 客户端传入的合法请求ID
+fn request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::trim)
+        .filter(|v| {
+            !v.is_empty()
+                && v.len() <= MAX_REQUEST_ID_LEN
+                && v.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+        .map(str::to_string)
+}
+
+fn is_trusted_proxy(ip: IpAddr) -> bool {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => ip.is_loopback() || ip.is_private(),
+        IpAddr::V6(ip) => ip.is_loopback() || ip.is_unique_local(),
+    }
+}
+
+/// 转发链中最早的客户端地址
+fn forwarded_ip(headers: &HeaderMap) -> Option<IpAddr> {
+    let forwarded_for = headers
+        .get(FORWARDED_FOR_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(',').next())
+        .and_then(|v| v.trim().parse().ok());
+    forwarded_for
+        .or_else(|| headers.get(REAL_IP_HEADER).and_then(|v| v.to_str().ok()).and_then(|v| v.trim().parse().ok()))
+}
@@ -59,6 +59,14 @@ pub async fn error_handler(err: AppError) -> Response {
             warn!("Middleware - Parse error: {}", msg);
             (StatusCode::BAD_REQUEST, msg.clone())
         }
+        AppError::Maintenance(msg) => {
+            warn!("Middleware - Maintenance: {}", msg);
+            (StatusCode::SERVICE_UNAVAILABLE, msg.clone())
+        }
+        AppError::ReadOnly(msg) => {
+            warn!("Middleware - Read-only: {}", msg);
+            (StatusCode::LOCKED, msg.clone())
+        }
     };
 
     // 记录完整错误用于调试
@@ -0,0 +1,57 @@
+use crate::api::dtos::auth_dto::GetCurrentUserRequest;
+use crate::api::state::AppState;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderMap, Method, Request, Response},
+    middleware::Next,
+};
+use tradewinds_common::get_current_user_token;
+use tradewinds_common::tenant::is_platform_tenant;
+use tradewinds_domain::value_objects::system_setting::MAINTENANCE_BYPASS_PERMISSION;
+use tradewinds_error::AppError;
+
+/// 任何模式下都放行的路径，持有豁免权限的用户需要先登录才能关闭维护模式或只读模式
+///
+/// 只读模式下登录仍会写入登录日志与最近登录信息，见 [`READ_ONLY_MODE`](tradewinds_domain::value_objects::system_setting::READ_ONLY_MODE)
+const EXEMPT_PATHS: &[&str] = &["/auth/login"];
+
+/// 维护模式与只读模式中间件
+///
+/// 维护模式下所有请求返回 503 与维护提示；只读模式下 GET 以外的写请求返回 423（错误类型 `read_only`）。
+/// 持有豁免权限的用户不受两种模式限制，以便在维护期间操作并关闭模式。
+/// 模式取自平台级系统设置，在每个实例内只短暂缓存，切换后数秒内在所有实例生效。
+pub async fn enforce_system_mode(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response<Body>, AppError> {
+    let mode = state.system_setting_controller.system_mode().await?;
+    if !mode.is_restricted() || EXEMPT_PATHS.contains(&req.uri().path()) {
+        return Ok(next.run(req).await);
+    }
+
+    let is_read = matches!(*req.method(), Method::GET | Method::HEAD | Method::OPTIONS);
+    if let Err(e) = mode.check(is_read)
+        && !can_bypass(&state, req.headers()).await
+    {
+        return Err(e);
+    }
+    Ok(next.run(req).await)
+}
+
+/// 当前用户是否持有豁免权限；无令牌或令牌无效时视为不持有
+///
+/// 两种模式对全部租户生效，只认平台默认租户内授予的豁免权限，租户不能自行创建同名权限绕过
+async fn can_bypass(state: &AppState, headers: &HeaderMap) -> bool {
+    if !is_platform_tenant() {
+        return false;
+    }
+    let Ok(token) = get_current_user_token(headers).await else {
+        return false;
+    };
+    let Ok(user) = state.auth_controller.authenticate(GetCurrentUserRequest { token }).await else {
+        return false;
+    };
+    user.permissions.iter().any(|permission| permission.code.as_deref() == Some(MAINTENANCE_BYPASS_PERMISSION))
+}
@@ -1,6 +1,21 @@
 pub mod security {
     mod auth_middleware;
-    pub use auth_middleware::auth;
+    pub use auth_middleware::{auth, current_actor_id};
+}
+
+pub mod tenant {
+    mod tenant_middleware;
+    pub use tenant_middleware::{TENANT_HEADER, resolve_tenant};
+}
+
+pub mod maintenance {
+    mod system_mode_middleware;
+    pub use system_mode_middleware::enforce_system_mode;
+}
+
+pub mod context {
+    mod request_context;
+    pub use request_context::request_context;
 }
 
 pub mod logging {
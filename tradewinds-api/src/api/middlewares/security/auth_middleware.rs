@@ -7,7 +7,8 @@ use axum::{
     middleware::Next,
 };
 use tradewinds_common::get_current_user_token;
-use tradewinds_error::AppError;
+use tradewinds_common::request_context::{RequestContext, current_request_context, with_request_context};
+use tradewinds_error::{AppError, AppResult};
 
 pub async fn auth(State(state): State<AppState>, req: Request<Body>, next: Next) -> Result<Response<Body>, AppError> {
     let auth_controller = &state.auth_controller;
@@ -17,15 +18,22 @@ pub async fn auth(State(state): State<AppState>, req: Request<Body>, next: Next)
 
     // 2. 校验token
     let get_user_req = GetCurrentUserRequest { token: token.clone() };
-    let user_result = auth_controller.get_current_user(get_user_req).await;
-    if user_result.is_err() {
+    let Ok(user_result) = auth_controller.authenticate(get_user_req).await else {
         return Err(AppError::Unauthorized("Invalid or expired token".to_string()));
-    }
+    };
 
     // 3. 可选：将用户信息插入extensions，后续handler可用
     // let user_info = user_result.unwrap();
     // req.extensions_mut().insert(user_info.user.user.id.clone());
 
-    // 4. 放行
-    Ok(next.run(req).await)
+    // 4. 放行：在请求上下文中记录当前操作人，供审计日志等使用
+    let context = RequestContext { actor_id: Some(user_result.user.id), ..current_request_context() };
+    Ok(with_request_context(context, next.run(req)).await)
+}
+
+/// 当前操作人用户ID，取自认证中间件写入请求上下文的操作人
+///
+/// 处理器、审计日志与事件使用同一来源；不在已认证的请求内时返回未认证错误。
+pub fn current_actor_id() -> AppResult<String> {
+    current_request_context().actor_id.ok_or_else(|| AppError::Unauthorized("Missing authenticated actor".to_string()))
 }
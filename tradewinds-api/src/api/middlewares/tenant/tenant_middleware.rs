@@ -0,0 +1,63 @@
+use crate::api::dtos::tenant_dto::ResolveTenantRequest;
+use crate::api::state::AppState;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderMap, Request, Response, header::HOST},
+    middleware::Next,
+};
+use tradewinds_common::get_current_user_token;
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_domain::value_objects::Token;
+use tradewinds_error::AppError;
+
+/// 指定租户编码的请求头
+pub const TENANT_HEADER: &str = "X-Tenant-Code";
+
+/// 租户解析中间件
+///
+/// 按令牌中的租户声明 > `X-Tenant-Code` 请求头 > Host 的顺序解析租户，
+/// 都未命中时归属平台默认租户；后续处理在该租户范围内执行。
+pub async fn resolve_tenant(
+    State(state): State<AppState>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response<Body>, AppError> {
+    let headers = req.headers();
+    let code = headers
+        .get(TENANT_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    let host = headers.get(HOST).and_then(|v| v.to_str().ok()).map(str::to_string);
+
+    let tenant_id = match token_tenant_id(&state, headers).await {
+        Some(id) => {
+            let tenant_id = state
+                .tenant_controller
+                .resolve_tenant(ResolveTenantRequest { id: Some(id), ..Default::default() })
+                .await?;
+            // 令牌已绑定租户时，请求头不得切换到其他租户
+            if let Some(code) = code {
+                let requested = state
+                    .tenant_controller
+                    .resolve_tenant(ResolveTenantRequest { code: Some(code), ..Default::default() })
+                    .await?;
+                if requested != tenant_id {
+                    return Err(AppError::Forbidden("Token does not belong to the requested tenant".to_string()));
+                }
+            }
+            tenant_id
+        }
+        None => state.tenant_controller.resolve_tenant(ResolveTenantRequest { id: None, code, host }).await?,
+    };
+
+    Ok(with_tenant(tenant_id, next.run(req)).await)
+}
+
+/// 令牌中的租户声明；无令牌或令牌无效时忽略，交由认证中间件处理
+async fn token_tenant_id(state: &AppState, headers: &HeaderMap) -> Option<String> {
+    let token = get_current_user_token(headers).await.ok()?;
+    let token = Token::new(token).ok()?;
+    state.token_service.get_tenant_id_from_token(&token).await.ok().flatten().map(|id| id.to_string())
+}
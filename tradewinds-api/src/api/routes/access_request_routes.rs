@@ -0,0 +1,35 @@
+use axum::{
+    Router,
+    routing::{get, post, put},
+};
+
+use crate::api::{handlers::access_request_handler::AccessRequestHandler, state::AppState};
+
+/// 权限申请相关路由
+///
+/// - /system/access-requests 提交申请、申请列表
+/// - /system/access-requests/expire 立即处理到期申请（平时由定时任务 access_request_expiry 处理）
+/// - /system/access-requests/{id} 申请详情
+/// - /system/access-requests/{id}/approve|reject|cancel 批准、驳回、撤回
+/// - /system/roles/{id}/approvers 角色审批人
+pub fn access_request_routes() -> Router<AppState> {
+    Router::new()
+        // 提交权限申请
+        .route("/system/access-requests", post(AccessRequestHandler::handle_submit_request))
+        // 获取权限申请列表
+        .route("/system/access-requests", get(AccessRequestHandler::handle_list_requests))
+        // 处理到期申请
+        .route("/system/access-requests/expire", post(AccessRequestHandler::handle_expire_requests))
+        // 获取权限申请详情
+        .route("/system/access-requests/{id}", get(AccessRequestHandler::handle_get_request))
+        // 批准权限申请
+        .route("/system/access-requests/{id}/approve", post(AccessRequestHandler::handle_approve_request))
+        // 驳回权限申请
+        .route("/system/access-requests/{id}/reject", post(AccessRequestHandler::handle_reject_request))
+        // 撤回权限申请
+        .route("/system/access-requests/{id}/cancel", post(AccessRequestHandler::handle_cancel_request))
+        // 获取角色审批人
+        .route("/system/roles/{id}/approvers", get(AccessRequestHandler::handle_get_role_approvers))
+        // 设置角色审批人
+        .route("/system/roles/{id}/approvers", put(AccessRequestHandler::handle_set_role_approvers))
+}
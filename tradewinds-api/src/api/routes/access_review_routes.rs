@@ -0,0 +1,32 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::api::{handlers::access_review_handler::AccessReviewHandler, state::AppState};
+
+/// 访问复核相关路由
+///
+/// - /system/access-reviews 发起活动、活动列表
+/// - /system/access-reviews/close-due 立即处理到期活动（平时由定时任务 access_review_close 处理）
+/// - /system/access-reviews/{id} 活动详情
+/// - /system/access-reviews/{id}/items 复核项
+/// - /system/access-reviews/{id}/items/{item_id}/decision 确认或收回
+/// - /system/access-reviews/{id}/report 导出报告（CSV）
+pub fn access_review_routes() -> Router<AppState> {
+    Router::new()
+        // 发起访问复核活动
+        .route("/system/access-reviews", post(AccessReviewHandler::handle_launch_review))
+        // 获取访问复核活动列表
+        .route("/system/access-reviews", get(AccessReviewHandler::handle_list_reviews))
+        // 处理到期活动
+        .route("/system/access-reviews/close-due", post(AccessReviewHandler::handle_close_due_reviews))
+        // 获取访问复核活动详情
+        .route("/system/access-reviews/{id}", get(AccessReviewHandler::handle_get_review))
+        // 获取复核项
+        .route("/system/access-reviews/{id}/items", get(AccessReviewHandler::handle_list_items))
+        // 确认或收回复核项
+        .route("/system/access-reviews/{id}/items/{item_id}/decision", post(AccessReviewHandler::handle_decide_item))
+        // 导出复核报告
+        .route("/system/access-reviews/{id}/report", get(AccessReviewHandler::handle_export_report))
+}
@@ -0,0 +1,15 @@
+use axum::{Router, routing::get};
+
+use crate::api::{handlers::audit_log_handler::AuditLogHandler, state::AppState};
+
+/// 审计日志相关路由
+///
+/// - /system/audit-logs 审计日志列表（支持按操作人、操作、对象、结果与时间过滤）
+/// - /system/audit-logs/export 导出审计日志（CSV）
+pub fn audit_log_routes() -> Router<AppState> {
+    Router::new()
+        // 获取审计日志列表
+        .route("/system/audit-logs", get(AuditLogHandler::handle_list_logs))
+        // 导出审计日志
+        .route("/system/audit-logs/export", get(AuditLogHandler::handle_export_logs))
+}
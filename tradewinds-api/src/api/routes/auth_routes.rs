@@ -13,6 +13,7 @@ use crate::api::{handlers::auth_handler::AuthHandler, state::AppState};
 /// - /auth/change-password 修改密码
 /// - /auth/me 获取当前用户信息
 /// - /auth/menus 获取当前用户菜单
+/// - /auth/login-history 获取当前用户登录历史
 /// - /auth/super-admin/dashboard 获取超级管理员仪表盘
 pub fn auth_routes() -> Router<AppState> {
     Router::new()
@@ -28,6 +29,8 @@ pub fn auth_routes() -> Router<AppState> {
         .route("/auth/me", get(AuthHandler::handle_get_current_user))
         // 获取当前用户菜单
         .route("/auth/menus", get(AuthHandler::handle_get_user_menus))
+        // 获取当前用户登录历史
+        .route("/auth/login-history", get(AuthHandler::handle_get_login_history))
         // 获取超级管理员仪表盘
         .route("/auth/super-admin/dashboard", get(AuthHandler::handle_get_super_admin_dashboard))
 }
@@ -0,0 +1,32 @@
+use axum::{
+    Router,
+    routing::{delete, get, patch, post, put},
+};
+
+use crate::api::{
+    handlers::department_handler::{DepartmentHandler, handle_get_department_tree},
+    state::AppState,
+};
+
+/// 部门管理相关路由
+///
+/// - /system/departments 部门创建、列表
+/// - /system/departments/{id} 部门详情、更新（含调整上级）、删除
+/// - /system/departments/tree 获取部门树
+pub fn department_routes() -> Router<AppState> {
+    Router::new()
+        // 创建部门
+        .route("/system/departments", post(DepartmentHandler::handle_create_department))
+        // 获取部门列表
+        .route("/system/departments", get(DepartmentHandler::handle_list_departments))
+        // 获取部门树
+        .route("/system/departments/tree", get(handle_get_department_tree))
+        // 获取部门详情
+        .route("/system/departments/{id}", get(DepartmentHandler::handle_get_department))
+        // 更新部门
+        .route("/system/departments/{id}", put(DepartmentHandler::handle_update_department))
+        // 局部更新部门
+        .route("/system/departments/{id}", patch(DepartmentHandler::handle_update_department))
+        // 删除部门
+        .route("/system/departments/{id}", delete(DepartmentHandler::handle_delete_department))
+}
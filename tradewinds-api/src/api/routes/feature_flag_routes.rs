@@ -0,0 +1,24 @@
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+use crate::api::{handlers::feature_flag_handler::FeatureFlagHandler, state::AppState};
+
+/// 功能开关相关路由
+///
+/// - /system/feature-flags 开关创建、列表
+/// - /system/feature-flags/{key} 开关更新（开启/关闭、灰度比例、白名单）、删除
+///
+/// 当前用户的开关状态随 /auth/me 返回
+pub fn feature_flag_routes() -> Router<AppState> {
+    Router::new()
+        // 创建功能开关
+        .route("/system/feature-flags", post(FeatureFlagHandler::handle_create_flag))
+        // 获取功能开关列表
+        .route("/system/feature-flags", get(FeatureFlagHandler::handle_list_flags))
+        // 更新功能开关
+        .route("/system/feature-flags/{key}", put(FeatureFlagHandler::handle_update_flag))
+        // 删除功能开关
+        .route("/system/feature-flags/{key}", delete(FeatureFlagHandler::handle_delete_flag))
+}
@@ -0,0 +1,34 @@
+use axum::{
+    Router,
+    routing::{delete, get, patch, post, put},
+};
+
+use crate::api::{handlers::group_handler::GroupHandler, state::AppState};
+
+/// 用户组管理相关路由
+///
+/// - /system/groups 用户组创建、列表
+/// - /system/groups/{id} 用户组详情、更新（含携带角色）、删除
+/// - /system/groups/{id}/members 用户组成员列表、添加成员
+/// - /system/groups/{id}/members/{user_id} 移除成员
+pub fn group_routes() -> Router<AppState> {
+    Router::new()
+        // 创建用户组
+        .route("/system/groups", post(GroupHandler::handle_create_group))
+        // 获取用户组列表
+        .route("/system/groups", get(GroupHandler::handle_list_groups))
+        // 获取用户组详情
+        .route("/system/groups/{id}", get(GroupHandler::handle_get_group))
+        // 更新用户组
+        .route("/system/groups/{id}", put(GroupHandler::handle_update_group))
+        // 局部更新用户组
+        .route("/system/groups/{id}", patch(GroupHandler::handle_update_group))
+        // 删除用户组
+        .route("/system/groups/{id}", delete(GroupHandler::handle_delete_group))
+        // 获取用户组成员
+        .route("/system/groups/{id}/members", get(GroupHandler::handle_list_group_members))
+        // 添加用户组成员
+        .route("/system/groups/{id}/members", post(GroupHandler::handle_add_group_members))
+        // 移除用户组成员
+        .route("/system/groups/{id}/members/{user_id}", delete(GroupHandler::handle_remove_group_member))
+}
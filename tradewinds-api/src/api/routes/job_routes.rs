@@ -0,0 +1,27 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::api::{handlers::job_handler::JobHandler, state::AppState};
+
+/// 定时任务相关路由，仅平台租户可用
+///
+/// - /system/jobs 任务列表（含暂停状态、下次执行时间、最近一次执行结果）
+/// - /system/jobs/runs 执行记录（支持按任务名称、状态过滤）
+/// - /system/jobs/{name}/trigger 手动触发
+/// - /system/jobs/{name}/pause 暂停
+/// - /system/jobs/{name}/resume 恢复
+pub fn job_routes() -> Router<AppState> {
+    Router::new()
+        // 获取定时任务列表
+        .route("/system/jobs", get(JobHandler::handle_list_jobs))
+        // 获取执行记录
+        .route("/system/jobs/runs", get(JobHandler::handle_list_runs))
+        // 手动触发
+        .route("/system/jobs/{name}/trigger", post(JobHandler::handle_trigger_job))
+        // 暂停
+        .route("/system/jobs/{name}/pause", post(JobHandler::handle_pause_job))
+        // 恢复
+        .route("/system/jobs/{name}/resume", post(JobHandler::handle_resume_job))
+}
@@ -0,0 +1,18 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::api::{handlers::login_log_handler::LoginLogHandler, state::AppState};
+
+/// 登录日志相关路由
+///
+/// - /system/login-logs 登录日志列表（支持按用户、结果、来源IP与时间过滤）
+/// - /system/login-logs/purge 清理超过保留天数的登录日志
+pub fn login_log_routes() -> Router<AppState> {
+    Router::new()
+        // 获取登录日志列表
+        .route("/system/login-logs", get(LoginLogHandler::handle_list_logs))
+        // 清理过期登录日志
+        .route("/system/login-logs/purge", post(LoginLogHandler::handle_purge_logs))
+}
@@ -1,9 +1,21 @@
 // 基础能力路由模块
+pub mod access_request_routes; // 权限申请与审批
+pub mod access_review_routes; // 访问复核（权限再认证）
+pub mod audit_log_routes; // 审计日志
 pub mod auth_routes; // 认证与登录
+pub mod department_routes; // 部门管理
+pub mod feature_flag_routes; // 功能开关
+pub mod group_routes; // 用户组管理
+pub mod job_routes; // 定时任务
+pub mod login_log_routes; // 登录日志
+pub mod outbox_routes; // 事件发件箱
 pub mod permission_routes; // 权限管理
+pub mod policy_routes; // 访问策略管理
 pub mod role_routes; // 角色管理
 pub mod system_setting_routes; // 系统设置
+pub mod tenant_routes; // 平台租户管理
 pub mod user_routes; // 用户管理
+pub mod webhook_routes; // Webhook 推送
 
 // 业务能力路由模块（如有业务模块可在此添加）
 // pub mod order_routes;       // 订单管理
@@ -11,11 +23,23 @@ pub mod user_routes; // 用户管理
 // pub mod report_routes;      // 报表管理
 
 // 统一导出基础能力路由
+pub use access_request_routes::*;
+pub use access_review_routes::*;
+pub use audit_log_routes::*;
 pub use auth_routes::*;
+pub use department_routes::*;
+pub use feature_flag_routes::*;
+pub use group_routes::*;
+pub use job_routes::*;
+pub use login_log_routes::*;
+pub use outbox_routes::*;
 pub use permission_routes::*;
+pub use policy_routes::*;
 pub use role_routes::*;
 pub use system_setting_routes::*;
+pub use tenant_routes::*;
 pub use user_routes::*;
+pub use webhook_routes::*;
 
 // 统一导出业务能力路由（如有业务模块可在此添加）
 // pub use order_routes::*;
@@ -0,0 +1,21 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::api::{handlers::outbox_handler::OutboxHandler, state::AppState};
+
+/// 发件箱相关路由
+///
+/// - /system/outbox 发件箱消息列表（支持按状态、事件类型、聚合过滤，stuck=true 查询滞留消息）
+/// - /system/outbox/stats 发件箱统计与中继指标
+/// - /system/outbox/{id}/retry 重新投递消息
+pub fn outbox_routes() -> Router<AppState> {
+    Router::new()
+        // 获取发件箱消息列表
+        .route("/system/outbox", get(OutboxHandler::handle_list_messages))
+        // 获取发件箱统计
+        .route("/system/outbox/stats", get(OutboxHandler::handle_get_stats))
+        // 重新投递消息
+        .route("/system/outbox/{id}/retry", post(OutboxHandler::handle_retry_message))
+}
@@ -0,0 +1,29 @@
+use axum::{
+    Router,
+    routing::{delete, get, patch, post, put},
+};
+
+use crate::api::{handlers::policy_handler::PolicyHandler, state::AppState};
+
+/// 访问策略（ABAC）相关路由
+///
+/// - /system/policies 策略创建、列表
+/// - /system/policies/evaluate 试运行访问判定
+/// - /system/policies/{id} 策略详情、更新（含启用/禁用）、删除
+pub fn policy_routes() -> Router<AppState> {
+    Router::new()
+        // 创建访问策略
+        .route("/system/policies", post(PolicyHandler::handle_create_policy))
+        // 获取访问策略列表
+        .route("/system/policies", get(PolicyHandler::handle_list_policies))
+        // 试运行访问判定
+        .route("/system/policies/evaluate", post(PolicyHandler::handle_evaluate_policy))
+        // 获取访问策略详情
+        .route("/system/policies/{id}", get(PolicyHandler::handle_get_policy))
+        // 更新访问策略
+        .route("/system/policies/{id}", put(PolicyHandler::handle_update_policy))
+        // 局部更新访问策略
+        .route("/system/policies/{id}", patch(PolicyHandler::handle_update_policy))
+        // 删除访问策略
+        .route("/system/policies/{id}", delete(PolicyHandler::handle_delete_policy))
+}
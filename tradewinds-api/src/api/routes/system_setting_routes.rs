@@ -4,10 +4,14 @@ use axum::{
     Router,
     routing::{get, put},
 };
-use std::sync::Arc;
 
+/// 系统设置相关路由
+///
+/// - /system/settings 全部设置及生效值（支持按分类过滤，敏感设置不展示实际值）
+/// - /system/settings/{key} 获取、修改单个设置；平台级设置（维护模式、只读模式等）只能在平台默认租户内修改
 pub fn system_setting_routes() -> Router<AppState> {
     Router::new()
+        .route("/system/settings", get(SystemSettingHandler::handle_list_system_settings))
         .route("/system/settings/{key}", get(SystemSettingHandler::handle_get_system_setting))
         .route("/system/settings/{key}", put(SystemSettingHandler::handle_set_system_setting))
 }
@@ -0,0 +1,24 @@
+use axum::{
+    Router,
+    routing::{get, patch, post, put},
+};
+
+use crate::api::{handlers::tenant_handler::TenantHandler, state::AppState};
+
+/// 平台租户管理相关路由（仅平台管理员可用）
+///
+/// - /platform/tenants 租户创建、列表
+/// - /platform/tenants/{id} 租户详情、更新（名称、域名、启用/禁用）
+pub fn tenant_routes() -> Router<AppState> {
+    Router::new()
+        // 创建租户
+        .route("/platform/tenants", post(TenantHandler::handle_create_tenant))
+        // 获取租户列表
+        .route("/platform/tenants", get(TenantHandler::handle_list_tenants))
+        // 获取租户详情
+        .route("/platform/tenants/{id}", get(TenantHandler::handle_get_tenant))
+        // 更新租户
+        .route("/platform/tenants/{id}", put(TenantHandler::handle_update_tenant))
+        // 局部更新租户
+        .route("/platform/tenants/{id}", patch(TenantHandler::handle_update_tenant))
+}
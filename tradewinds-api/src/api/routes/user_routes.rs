@@ -22,4 +22,5 @@ pub fn user_routes() -> Router<AppState> {
         .route("/system/users/{id}/reset-password", post(UserHandler::handle_reset_password))
         .route("/system/users/{id}/roles", get(UserHandler::handle_get_user_roles))
         .route("/system/users/{id}/permissions", get(UserHandler::handle_get_user_permissions))
+        .route("/system/users/{id}/access/explain", get(UserHandler::handle_explain_user_access))
 }
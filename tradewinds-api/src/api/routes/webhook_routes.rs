@@ -0,0 +1,28 @@
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+use crate::api::{handlers::webhook_handler::WebhookHandler, state::AppState};
+
+/// Webhook 相关路由
+///
+/// - /system/webhooks 端点创建、列表
+/// - /system/webhooks/{id} 端点更新（含停用/重新启用）、删除
+/// - /system/webhooks/{id}/deliveries 端点的投递记录（支持按状态、事件类型过滤）
+/// - /system/webhooks/deliveries/{id}/redeliver 重新投递
+pub fn webhook_routes() -> Router<AppState> {
+    Router::new()
+        // 创建 Webhook 端点
+        .route("/system/webhooks", post(WebhookHandler::handle_create_webhook))
+        // 获取 Webhook 端点列表
+        .route("/system/webhooks", get(WebhookHandler::handle_list_webhooks))
+        // 更新 Webhook 端点
+        .route("/system/webhooks/{id}", put(WebhookHandler::handle_update_webhook))
+        // 删除 Webhook 端点
+        .route("/system/webhooks/{id}", delete(WebhookHandler::handle_delete_webhook))
+        // 获取投递记录
+        .route("/system/webhooks/{id}/deliveries", get(WebhookHandler::handle_list_deliveries))
+        // 重新投递
+        .route("/system/webhooks/deliveries/{id}/redeliver", post(WebhookHandler::handle_redeliver))
+}
@@ -22,10 +22,22 @@ use tradewinds_domain::services::auth::token_service::TokenService;
 #[rustfmt::skip]
 use crate::api::controllers::{
     auth_controller::AuthController,
+    department_controller::DepartmentController,
+    group_controller::GroupController,
     role_controller::RoleController,
     permission_controller::PermissionController,
     user_controller::UserController,
     system_setting_controller::SystemSettingController,
+    tenant_controller::TenantController,
+    policy_controller::PolicyController,
+    access_request_controller::AccessRequestController,
+    access_review_controller::AccessReviewController,
+    audit_log_controller::AuditLogController,
+    login_log_controller::LoginLogController,
+    outbox_controller::OutboxController,
+    webhook_controller::WebhookController,
+    job_controller::JobController,
+    feature_flag_controller::FeatureFlagController,
 };
 
 #[derive(Clone)]
@@ -35,31 +47,23 @@ pub struct AppState {
     pub role_controller: Arc<RoleController>,
     pub permission_controller: Arc<PermissionController>,
     pub system_setting_controller: Arc<SystemSettingController>,
+    pub department_controller: Arc<DepartmentController>,
+    pub group_controller: Arc<GroupController>,
+    pub tenant_controller: Arc<TenantController>,
+    pub policy_controller: Arc<PolicyController>,
+    pub access_request_controller: Arc<AccessRequestController>,
+    pub access_review_controller: Arc<AccessReviewController>,
+    pub audit_log_controller: Arc<AuditLogController>,
+    pub login_log_controller: Arc<LoginLogController>,
+    pub outbox_controller: Arc<OutboxController>,
+    pub webhook_controller: Arc<WebhookController>,
+    pub job_controller: Arc<JobController>,
+    pub feature_flag_controller: Arc<FeatureFlagController>,
     // FIXME: 这里需要一个更好的方式来管理 token_service
     // 因为 token_service 需要被多个控制器共享，所以需要一个更好的方式来管理它
     // 目前这个方式是临时的，后续需要优化
     pub token_service: Arc<dyn TokenService>,
 }
 
-impl AppState {
-    pub fn new(
-        auth_controller: AuthController,
-        user_controller: UserController,
-        role_controller: RoleController,
-        permission_controller: PermissionController,
-        system_setting_controller: SystemSettingController,
-        token_service: Arc<dyn TokenService>,
-    ) -> Self {
-        Self {
-            auth_controller: Arc::new(auth_controller),
-            user_controller: Arc::new(user_controller),
-            role_controller: Arc::new(role_controller),
-            permission_controller: Arc::new(permission_controller),
-            system_setting_controller: Arc::new(system_setting_controller),
-            token_service,
-        }
-    }
-}
-
 // unsafe impl Send for AppState {}
 // unsafe impl Sync for AppState {}
@@ -0,0 +1,82 @@
+//! 命令审计覆盖测试
+//!
+//! 列出应用层全部命令处理器，检查每个处理器都在控制器中登记，且每处登记都经 `audited` 包裹
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn manifest_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
+
+fn rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+/// 应用层实现了 `CommandHandler` 的处理器名称
+fn command_handlers() -> Vec<String> {
+    let mut files = Vec::new();
+    rust_files(&manifest_dir().join("../tradewinds-application/src/commands"), &mut files);
+    let mut handlers: Vec<String> = files
+        .iter()
+        .flat_map(|path| {
+            fs::read_to_string(path)
+                .unwrap()
+                .lines()
+                .filter(|line| line.starts_with("impl CommandHandler<"))
+                .filter_map(|line| line.split(" for ").nth(1))
+                .map(|rest| rest.trim_end_matches('{').trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    handlers.sort();
+    handlers
+}
+
+/// 控制器源码，按文件名与内容成对返回
+fn controller_sources() -> Vec<(String, String)> {
+    let mut files = Vec::new();
+    rust_files(&manifest_dir().join("src/api/controllers"), &mut files);
+    files
+        .into_iter()
+        .map(|path| {
+            let name = path.file_name().unwrap().to_string_lossy().to_string();
+            (name, fs::read_to_string(&path).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn every_command_handler_is_audited() {
+    let handlers = command_handlers();
+    assert!(!handlers.is_empty(), "no command handlers found");
+    let sources = controller_sources();
+
+    let mut unregistered = Vec::new();
+    let mut unaudited = Vec::new();
+    for handler in &handlers {
+        let constructor = format!("{handler}::new(");
+        let mut registered = false;
+        for (file, source) in &sources {
+            for (index, _) in source.match_indices(&constructor) {
+                registered = true;
+                if !source[..index].trim_end().ends_with("audited(") {
+                    unaudited.push(format!("{handler} in {file}"));
+                }
+            }
+        }
+        if !registered {
+            unregistered.push(handler.clone());
+        }
+    }
+
+    assert!(unregistered.is_empty(), "command handlers not registered in any controller: {unregistered:?}");
+    assert!(unaudited.is_empty(), "command handlers registered without audited(...): {unaudited:?}");
+}
@@ -0,0 +1,733 @@
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::commands::{
+    access_request::{
+        ApproveAccessRequestCommand, CancelAccessRequestCommand, ExpireAccessRequestsCommand,
+        RejectAccessRequestCommand, SetRoleApproversCommand, SubmitAccessRequestCommand,
+    },
+    access_review::{CloseDueAccessReviewsCommand, DecideAccessReviewItemCommand, LaunchAccessReviewCommand},
+    auth::{ChangePasswordCommand, LoginCommand, LogoutCommand, RegisterCommand},
+    department::{CreateDepartmentCommand, DeleteDepartmentCommand, UpdateDepartmentCommand},
+    feature_flag::{CreateFeatureFlagCommand, DeleteFeatureFlagCommand, UpdateFeatureFlagCommand},
+    group::{
+        AddGroupMembersCommand, CreateGroupCommand, DeleteGroupCommand, RemoveGroupMemberCommand, UpdateGroupCommand,
+    },
+    job::{PauseJobCommand, ResumeJobCommand, TriggerJobCommand},
+    login_log::PurgeLoginLogsCommand,
+    outbox::RetryOutboxMessageCommand,
+    permission::{CreatePermissionCommand, DeletePermissionCommand, UpdatePermissionCommand},
+    policy::{CreateAccessPolicyCommand, DeleteAccessPolicyCommand, UpdateAccessPolicyCommand},
+    role::{AssignPermissionCommand, CreateRoleCommand, DeleteRoleCommand, RevokePermissionCommand, UpdateRoleCommand},
+    system_setting::SetSystemSettingCommand,
+    tenant::{CreateTenantCommand, UpdateTenantCommand},
+    user::{
+        AssignRoleCommand, CreateUserCommand, DeleteUserCommand, ResetPasswordCommand, RevokeRoleCommand,
+        UpdateUserCommand,
+    },
+    webhook::{CreateWebhookCommand, DeleteWebhookCommand, RedeliverWebhookDeliveryCommand, UpdateWebhookCommand},
+};
+use tradewinds_domain::aggregates::AccessReviewAggregate;
+use tradewinds_domain::entities::{
+    AccessPolicy, AccessRequest, Department, FeatureFlag, Group, JobRun, Tenant, Webhook, WebhookDelivery,
+    audit_log::{AuditLog, REDACTED},
+    permission::Permission,
+    role::Role,
+    user::User,
+};
+use tradewinds_domain::value_objects::auth::auth_token::Token;
+
+/// 审计对象类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditTargetType {
+    User,
+    Role,
+    Permission,
+    SystemSetting,
+    Group,
+    Department,
+    Tenant,
+    AccessPolicy,
+    AccessRequest,
+    AccessReview,
+    FeatureFlag,
+    Webhook,
+    WebhookDelivery,
+    Job,
+    OutboxMessage,
+    LoginLog,
+}
+
+impl AuditTargetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditTargetType::User => "user",
+            AuditTargetType::Role => "role",
+            AuditTargetType::Permission => "permission",
+            AuditTargetType::SystemSetting => "system_setting",
+            AuditTargetType::Group => "group",
+            AuditTargetType::Department => "department",
+            AuditTargetType::Tenant => "tenant",
+            AuditTargetType::AccessPolicy => "access_policy",
+            AuditTargetType::AccessRequest => "access_request",
+            AuditTargetType::AccessReview => "access_review",
+            AuditTargetType::FeatureFlag => "feature_flag",
+            AuditTargetType::Webhook => "webhook",
+            AuditTargetType::WebhookDelivery => "webhook_delivery",
+            AuditTargetType::Job => "job",
+            AuditTargetType::OutboxMessage => "outbox_message",
+            AuditTargetType::LoginLog => "login_log",
+        }
+    }
+}
+
+/// 审计对象
+#[derive(Debug, Clone)]
+pub struct AuditTarget {
+    pub target_type: AuditTargetType,
+    /// 对象ID；创建类命令执行前为空，由执行结果补全
+    pub target_id: Option<String>,
+}
+
+impl AuditTarget {
+    fn new(target_type: AuditTargetType, target_id: impl Into<Option<String>>) -> Self {
+        Self { target_type, target_id: target_id.into() }
+    }
+}
+
+/// 可审计的命令
+pub trait AuditableCommand: Serialize + Send + Sync + 'static {
+    /// 操作名称，如 `user.create`
+    fn audit_action(&self) -> &'static str;
+
+    fn audit_target(&self) -> AuditTarget;
+
+    /// 记录到审计日志的命令参数，默认为脱敏后的命令本身
+    fn audit_params(&self) -> Option<Value> {
+        serde_json::to_value(self).ok().map(AuditLog::redact)
+    }
+}
+
+/// 可审计的命令执行结果
+pub trait AuditableOutput: Send + 'static {
+    /// 结果对应的对象ID，用于补全创建类命令的审计对象
+    fn audit_target_id(&self) -> Option<String> {
+        None
+    }
+}
+
+impl AuditableOutput for () {}
+
+/// 批量命令的处理条数
+impl AuditableOutput for u64 {}
+
+/// 登录令牌不作为审计对象
+impl AuditableOutput for Token {}
+
+/// 执行记录归属的任务由命令指定
+impl AuditableOutput for JobRun {}
+
+/// 重新投递以原投递记录为审计对象
+impl AuditableOutput for WebhookDelivery {}
+
+impl AuditableOutput for User {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for Role {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for Permission {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.value().to_string())
+    }
+}
+
+impl AuditableOutput for Group {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for Department {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for Tenant {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for AccessPolicy {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for AccessRequest {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableOutput for AccessReviewAggregate {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.campaign.id.to_string())
+    }
+}
+
+impl AuditableOutput for FeatureFlag {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.key.clone())
+    }
+}
+
+impl AuditableOutput for Webhook {
+    fn audit_target_id(&self) -> Option<String> {
+        Some(self.id.to_string())
+    }
+}
+
+impl AuditableCommand for CreateUserCommand {
+    fn audit_action(&self) -> &'static str {
+        "user.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, None)
+    }
+}
+
+impl AuditableCommand for UpdateUserCommand {
+    fn audit_action(&self) -> &'static str {
+        "user.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for DeleteUserCommand {
+    fn audit_action(&self) -> &'static str {
+        "user.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for AssignRoleCommand {
+    fn audit_action(&self) -> &'static str {
+        "user.assign_role"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, self.user_id.to_string())
+    }
+}
+
+impl AuditableCommand for RevokeRoleCommand {
+    fn audit_action(&self) -> &'static str {
+        "user.revoke_role"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, self.user_id.to_string())
+    }
+}
+
+impl AuditableCommand for ResetPasswordCommand {
+    fn audit_action(&self) -> &'static str {
+        "user.reset_password"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for CreateRoleCommand {
+    fn audit_action(&self) -> &'static str {
+        "role.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Role, None)
+    }
+}
+
+impl AuditableCommand for UpdateRoleCommand {
+    fn audit_action(&self) -> &'static str {
+        "role.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Role, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for DeleteRoleCommand {
+    fn audit_action(&self) -> &'static str {
+        "role.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Role, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for AssignPermissionCommand {
+    fn audit_action(&self) -> &'static str {
+        "role.assign_permission"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Role, self.role_id.to_string())
+    }
+}
+
+impl AuditableCommand for RevokePermissionCommand {
+    fn audit_action(&self) -> &'static str {
+        "role.revoke_permission"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Role, self.role_id.to_string())
+    }
+}
+
+impl AuditableCommand for CreatePermissionCommand {
+    fn audit_action(&self) -> &'static str {
+        "permission.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Permission, None)
+    }
+}
+
+impl AuditableCommand for UpdatePermissionCommand {
+    fn audit_action(&self) -> &'static str {
+        "permission.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Permission, self.id.value().to_string())
+    }
+}
+
+impl AuditableCommand for DeletePermissionCommand {
+    fn audit_action(&self) -> &'static str {
+        "permission.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Permission, self.permission_id.value().to_string())
+    }
+}
+
+impl AuditableCommand for SetSystemSettingCommand {
+    fn audit_action(&self) -> &'static str {
+        "system_setting.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::SystemSetting, self.key.value().to_string())
+    }
+
+    /// 敏感设置（如密钥）只记录键名
+    fn audit_params(&self) -> Option<Value> {
+        let value = if AuditLog::is_sensitive_field(self.key.value()) { REDACTED } else { self.value.value() };
+        Some(json!({ "key": self.key.value(), "value": value }))
+    }
+}
+
+impl AuditableCommand for CreateGroupCommand {
+    fn audit_action(&self) -> &'static str {
+        "group.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Group, None)
+    }
+}
+
+impl AuditableCommand for UpdateGroupCommand {
+    fn audit_action(&self) -> &'static str {
+        "group.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Group, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for DeleteGroupCommand {
+    fn audit_action(&self) -> &'static str {
+        "group.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Group, self.group_id.to_string())
+    }
+}
+
+impl AuditableCommand for AddGroupMembersCommand {
+    fn audit_action(&self) -> &'static str {
+        "group.add_members"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Group, self.group_id.to_string())
+    }
+}
+
+impl AuditableCommand for RemoveGroupMemberCommand {
+    fn audit_action(&self) -> &'static str {
+        "group.remove_member"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Group, self.group_id.to_string())
+    }
+}
+
+impl AuditableCommand for CreateDepartmentCommand {
+    fn audit_action(&self) -> &'static str {
+        "department.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Department, None)
+    }
+}
+
+impl AuditableCommand for UpdateDepartmentCommand {
+    fn audit_action(&self) -> &'static str {
+        "department.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Department, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for DeleteDepartmentCommand {
+    fn audit_action(&self) -> &'static str {
+        "department.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Department, self.department_id.to_string())
+    }
+}
+
+impl AuditableCommand for CreateTenantCommand {
+    fn audit_action(&self) -> &'static str {
+        "tenant.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Tenant, None)
+    }
+}
+
+impl AuditableCommand for UpdateTenantCommand {
+    fn audit_action(&self) -> &'static str {
+        "tenant.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Tenant, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for CreateAccessPolicyCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_policy.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessPolicy, None)
+    }
+}
+
+impl AuditableCommand for UpdateAccessPolicyCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_policy.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessPolicy, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for DeleteAccessPolicyCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_policy.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessPolicy, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for SubmitAccessRequestCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_request.submit"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessRequest, None)
+    }
+}
+
+impl AuditableCommand for ApproveAccessRequestCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_request.approve"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessRequest, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for RejectAccessRequestCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_request.reject"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessRequest, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for CancelAccessRequestCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_request.cancel"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessRequest, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for ExpireAccessRequestsCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_request.expire"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessRequest, None)
+    }
+}
+
+impl AuditableCommand for SetRoleApproversCommand {
+    fn audit_action(&self) -> &'static str {
+        "role.set_approvers"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Role, self.role_id.to_string())
+    }
+}
+
+impl AuditableCommand for LaunchAccessReviewCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_review.launch"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessReview, None)
+    }
+}
+
+impl AuditableCommand for DecideAccessReviewItemCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_review.decide_item"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessReview, self.review_id.to_string())
+    }
+}
+
+impl AuditableCommand for CloseDueAccessReviewsCommand {
+    fn audit_action(&self) -> &'static str {
+        "access_review.close_due"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::AccessReview, None)
+    }
+}
+
+impl AuditableCommand for CreateFeatureFlagCommand {
+    fn audit_action(&self) -> &'static str {
+        "feature_flag.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::FeatureFlag, None)
+    }
+}
+
+impl AuditableCommand for UpdateFeatureFlagCommand {
+    fn audit_action(&self) -> &'static str {
+        "feature_flag.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::FeatureFlag, self.key.clone())
+    }
+}
+
+impl AuditableCommand for DeleteFeatureFlagCommand {
+    fn audit_action(&self) -> &'static str {
+        "feature_flag.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::FeatureFlag, self.key.clone())
+    }
+}
+
+impl AuditableCommand for CreateWebhookCommand {
+    fn audit_action(&self) -> &'static str {
+        "webhook.create"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Webhook, None)
+    }
+}
+
+impl AuditableCommand for UpdateWebhookCommand {
+    fn audit_action(&self) -> &'static str {
+        "webhook.update"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Webhook, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for DeleteWebhookCommand {
+    fn audit_action(&self) -> &'static str {
+        "webhook.delete"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Webhook, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for RedeliverWebhookDeliveryCommand {
+    fn audit_action(&self) -> &'static str {
+        "webhook.redeliver"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::WebhookDelivery, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for TriggerJobCommand {
+    fn audit_action(&self) -> &'static str {
+        "job.trigger"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Job, self.name.clone())
+    }
+}
+
+impl AuditableCommand for PauseJobCommand {
+    fn audit_action(&self) -> &'static str {
+        "job.pause"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Job, self.name.clone())
+    }
+}
+
+impl AuditableCommand for ResumeJobCommand {
+    fn audit_action(&self) -> &'static str {
+        "job.resume"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::Job, self.name.clone())
+    }
+}
+
+impl AuditableCommand for RetryOutboxMessageCommand {
+    fn audit_action(&self) -> &'static str {
+        "outbox.retry"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::OutboxMessage, self.id.to_string())
+    }
+}
+
+impl AuditableCommand for PurgeLoginLogsCommand {
+    fn audit_action(&self) -> &'static str {
+        "login_log.purge"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::LoginLog, None)
+    }
+}
+
+impl AuditableCommand for RegisterCommand {
+    fn audit_action(&self) -> &'static str {
+        "auth.register"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, None)
+    }
+}
+
+impl AuditableCommand for LoginCommand {
+    fn audit_action(&self) -> &'static str {
+        "auth.login"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, None)
+    }
+}
+
+impl AuditableCommand for LogoutCommand {
+    fn audit_action(&self) -> &'static str {
+        "auth.logout"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, None)
+    }
+}
+
+impl AuditableCommand for ChangePasswordCommand {
+    fn audit_action(&self) -> &'static str {
+        "auth.change_password"
+    }
+
+    fn audit_target(&self) -> AuditTarget {
+        AuditTarget::new(AuditTargetType::User, None)
+    }
+}
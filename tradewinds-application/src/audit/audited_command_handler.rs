@@ -0,0 +1,102 @@
+use std::str::FromStr;
+use std::sync::Arc;
+
+use chrono::Utc;
+use serde_json::Value;
+
+use crate::CommandHandler;
+use crate::audit::{AuditTarget, AuditableCommand, AuditableOutput};
+use crate::interfaces::IAuditLogService;
+use tradewinds_common::request_context::current_request_context;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_domain::value_objects::{AuditLogId, AuditOutcome, UserId};
+use tradewinds_error::AppResult;
+
+/// 审计命令处理器
+///
+/// 包裹命令处理器：执行前后分别对操作对象取快照，执行后按结果写入审计日志，
+/// 操作人、客户端地址与请求ID 取自当前请求上下文。
+/// 审计日志写入失败只记录告警，不影响命令本身的结果。
+pub struct AuditedCommandHandler<C, O> {
+    inner: Arc<dyn CommandHandler<C, O>>,
+    audit_log_service: Arc<dyn IAuditLogService>,
+}
+
+impl<C, O> AuditedCommandHandler<C, O> {
+    pub fn new(inner: Arc<dyn CommandHandler<C, O>>, audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self { inner, audit_log_service }
+    }
+
+    /// 对象快照，对象不存在或快照失败时为空
+    async fn snapshot(&self, target: &AuditTarget) -> Option<Value> {
+        match self.audit_log_service.snapshot(target).await {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to snapshot {} {:?} for audit: {}",
+                    target.target_type.as_str(),
+                    target.target_id,
+                    e
+                );
+                None
+            }
+        }
+    }
+}
+
+/// 以审计处理器包裹命令处理器
+pub fn audited<C, O, H>(handler: H, audit_log_service: &Arc<dyn IAuditLogService>) -> Arc<AuditedCommandHandler<C, O>>
+where
+    H: CommandHandler<C, O> + 'static,
+{
+    Arc::new(AuditedCommandHandler::new(Arc::new(handler), audit_log_service.clone()))
+}
+
+#[async_trait::async_trait]
+impl<C: AuditableCommand, O: AuditableOutput> CommandHandler<C, O> for AuditedCommandHandler<C, O> {
+    async fn handle(&self, command: C) -> AppResult<O> {
+        let action = command.audit_action();
+        let params = command.audit_params();
+        let mut target = command.audit_target();
+        let before = self.snapshot(&target).await;
+
+        let result = self.inner.handle(command).await;
+
+        let (outcome, error_message, changes) = match &result {
+            Ok(output) => {
+                if target.target_id.is_none() {
+                    target.target_id = output.audit_target_id();
+                }
+                let after = self.snapshot(&target).await;
+                (AuditOutcome::Success, None, AuditLog::diff(before.as_ref(), after.as_ref()))
+            }
+            Err(e) => (AuditOutcome::Failure, Some(e.to_string()), None),
+        };
+
+        let context = current_request_context();
+        let log = AuditLog {
+            id: AuditLogId::new_v4(),
+            actor_id: context.actor_id.as_deref().and_then(|id| UserId::from_str(id).ok()),
+            actor_name: None,
+            action: action.to_string(),
+            target_type: target.target_type.as_str().to_string(),
+            target_id: target.target_id,
+            params,
+            changes,
+            client_ip: context.client_ip,
+            user_agent: context.user_agent,
+            request_id: context.request_id,
+            outcome,
+            error_message,
+            created_at: Utc::now().timestamp(),
+            seq: 0,
+            prev_hash: String::new(),
+            hash: String::new(),
+        };
+        if let Err(e) = self.audit_log_service.record(log).await {
+            tracing::warn!("Failed to record audit log for {}: {}", action, e);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,9 @@
+//! 命令审计
+//!
+//! `AuditedCommandHandler` 包裹任意命令处理器，在命令执行前后记录审计日志；
+//! 需要审计的命令实现 `AuditableCommand`，描述操作名称与操作对象。
+pub mod auditable;
+pub mod audited_command_handler;
+
+pub use auditable::{AuditTarget, AuditTargetType, AuditableCommand, AuditableOutput};
+pub use audited_command_handler::{AuditedCommandHandler, audited};
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_request::AccessRequestId,
+    user::UserId,
+};
+
+/// 撤回权限申请命令
+///
+/// 参数：
+/// - id: 申请ID
+/// - requester_id: 申请人ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CancelAccessRequestCommand {
+    pub id: AccessRequestId,
+    pub requester_id: UserId,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 处理到期权限申请命令
+///
+/// 待审批超时的申请置为已过期；已批准且授权到期的申请收回角色后置为已过期。
+/// 定时任务 `access_request_expiry` 按 `JOB_ACCESS_REQUEST_EXPIRY_CRON` 逐个租户执行，
+/// 手动接口只处理当前租户，用于不等下一次调度立即处理
+///
+/// 参数：
+/// - triggered_by: 触发者ID（定时任务触发时为空）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpireAccessRequestsCommand {
+    pub triggered_by: Option<UserId>,
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    commands::access_request::approve_access_request_command::ApproveAccessRequestCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 批准权限申请命令处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 批准权限申请命令处理器
+pub struct ApproveAccessRequestHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl ApproveAccessRequestHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<ApproveAccessRequestCommand, ()> for ApproveAccessRequestHandler {
+    async fn handle(&self, command: ApproveAccessRequestCommand) -> AppResult<()> {
+        self.access_request_service.approve_request(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    commands::access_request::cancel_access_request_command::CancelAccessRequestCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 撤回权限申请命令处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 撤回权限申请命令处理器
+pub struct CancelAccessRequestHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl CancelAccessRequestHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CancelAccessRequestCommand, ()> for CancelAccessRequestHandler {
+    async fn handle(&self, command: CancelAccessRequestCommand) -> AppResult<()> {
+        self.access_request_service.cancel_request(command).await
+    }
+}
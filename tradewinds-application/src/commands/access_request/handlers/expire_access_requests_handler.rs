@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    commands::access_request::expire_access_requests_command::ExpireAccessRequestsCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 处理到期权限申请命令处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 处理到期权限申请命令处理器
+pub struct ExpireAccessRequestsHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl ExpireAccessRequestsHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<ExpireAccessRequestsCommand, u64> for ExpireAccessRequestsHandler {
+    async fn handle(&self, command: ExpireAccessRequestsCommand) -> AppResult<u64> {
+        self.access_request_service.expire_requests(command).await
+    }
+}
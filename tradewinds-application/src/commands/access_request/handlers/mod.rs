@@ -0,0 +1,13 @@
+pub mod approve_access_request_handler;
+pub mod cancel_access_request_handler;
+pub mod expire_access_requests_handler;
+pub mod reject_access_request_handler;
+pub mod set_role_approvers_handler;
+pub mod submit_access_request_handler;
+
+pub use approve_access_request_handler::ApproveAccessRequestHandler;
+pub use cancel_access_request_handler::CancelAccessRequestHandler;
+pub use expire_access_requests_handler::ExpireAccessRequestsHandler;
+pub use reject_access_request_handler::RejectAccessRequestHandler;
+pub use set_role_approvers_handler::SetRoleApproversHandler;
+pub use submit_access_request_handler::SubmitAccessRequestHandler;
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    commands::access_request::reject_access_request_command::RejectAccessRequestCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 驳回权限申请命令处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 驳回权限申请命令处理器
+pub struct RejectAccessRequestHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl RejectAccessRequestHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<RejectAccessRequestCommand, ()> for RejectAccessRequestHandler {
+    async fn handle(&self, command: RejectAccessRequestCommand) -> AppResult<()> {
+        self.access_request_service.reject_request(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    commands::access_request::set_role_approvers_command::SetRoleApproversCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 设置角色审批人命令处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 设置角色审批人命令处理器
+pub struct SetRoleApproversHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl SetRoleApproversHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<SetRoleApproversCommand, ()> for SetRoleApproversHandler {
+    async fn handle(&self, command: SetRoleApproversCommand) -> AppResult<()> {
+        self.access_request_service.set_role_approvers(command).await
+    }
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    commands::access_request::submit_access_request_command::SubmitAccessRequestCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::access_request::AccessRequest;
+use tradewinds_error::AppResult;
+
+/// 提交权限申请命令处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 提交权限申请命令处理器
+pub struct SubmitAccessRequestHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl SubmitAccessRequestHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<SubmitAccessRequestCommand, AccessRequest> for SubmitAccessRequestHandler {
+    async fn handle(&self, command: SubmitAccessRequestCommand) -> AppResult<AccessRequest> {
+        self.access_request_service.submit_request(command).await
+    }
+}
@@ -0,0 +1,21 @@
+pub mod approve_access_request_command;
+pub mod cancel_access_request_command;
+pub mod expire_access_requests_command;
+pub mod handlers;
+pub mod reject_access_request_command;
+pub mod set_role_approvers_command;
+pub mod submit_access_request_command;
+
+pub use approve_access_request_command::ApproveAccessRequestCommand;
+pub use cancel_access_request_command::CancelAccessRequestCommand;
+pub use expire_access_requests_command::ExpireAccessRequestsCommand;
+pub use reject_access_request_command::RejectAccessRequestCommand;
+pub use set_role_approvers_command::SetRoleApproversCommand;
+pub use submit_access_request_command::SubmitAccessRequestCommand;
+
+pub use handlers::ApproveAccessRequestHandler;
+pub use handlers::CancelAccessRequestHandler;
+pub use handlers::ExpireAccessRequestsHandler;
+pub use handlers::RejectAccessRequestHandler;
+pub use handlers::SetRoleApproversHandler;
+pub use handlers::SubmitAccessRequestHandler;
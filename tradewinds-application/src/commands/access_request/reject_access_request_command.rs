@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_request::AccessRequestId,
+    user::UserId,
+};
+
+/// 驳回权限申请命令
+///
+/// 参数：
+/// - id: 申请ID
+/// - approver_id: 审批人ID
+/// - comment: 驳回理由
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectAccessRequestCommand {
+    pub id: AccessRequestId,
+    pub approver_id: UserId,
+    pub comment: Option<String>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    role::RoleId,
+    user::UserId,
+};
+
+/// 设置角色审批人命令
+///
+/// 参数：
+/// - role_id: 角色ID
+/// - approver_ids: 审批人ID列表（整体替换，为空时该角色不再接受申请）
+/// - updated_by: 更新者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetRoleApproversCommand {
+    pub role_id: RoleId,
+    pub approver_ids: Vec<UserId>,
+    pub updated_by: Option<UserId>,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_request::{
+        AccessDuration,
+        AccessJustification,
+    },
+    role::RoleId,
+    user::UserId,
+};
+
+/// 提交权限申请命令
+///
+/// 参数：
+/// - requester_id: 申请人ID
+/// - role_id: 申请的角色ID
+/// - justification: 申请理由
+/// - duration: 授权时长
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmitAccessRequestCommand {
+    pub requester_id: UserId,
+    pub role_id: RoleId,
+    pub justification: AccessJustification,
+    pub duration: AccessDuration,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 处理到期访问复核活动命令
+///
+/// 已到截止时间的活动中未决的复核项经由撤销角色流程收回，全部处理后活动完成。
+/// 定时任务 `access_review_close` 按 `JOB_ACCESS_REVIEW_CLOSE_CRON` 逐个租户执行，
+/// 手动接口只处理当前租户，用于不等下一次调度立即处理
+///
+/// 参数：
+/// - triggered_by: 触发者ID（定时任务触发时为空）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CloseDueAccessReviewsCommand {
+    pub triggered_by: Option<UserId>,
+}
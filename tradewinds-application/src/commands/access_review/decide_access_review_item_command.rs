@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_review::{
+        AccessReviewId,
+        AccessReviewItemId,
+        ReviewDecision,
+    },
+    user::UserId,
+};
+
+/// 复核项决定命令
+///
+/// 收回决定经由撤销角色流程立即生效
+///
+/// 参数：
+/// - review_id: 活动ID
+/// - item_id: 复核项ID
+/// - reviewer_id: 复核人ID
+/// - decision: 复核结论，仅可确认或收回
+/// - comment: 复核意见
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecideAccessReviewItemCommand {
+    pub review_id: AccessReviewId,
+    pub item_id: AccessReviewItemId,
+    pub reviewer_id: UserId,
+    pub decision: ReviewDecision,
+    pub comment: Option<String>,
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    commands::access_review::close_due_access_reviews_command::CloseDueAccessReviewsCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 处理到期访问复核活动命令处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 处理到期访问复核活动命令处理器
+pub struct CloseDueAccessReviewsHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl CloseDueAccessReviewsHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CloseDueAccessReviewsCommand, u64> for CloseDueAccessReviewsHandler {
+    async fn handle(&self, command: CloseDueAccessReviewsCommand) -> AppResult<u64> {
+        self.access_review_service.close_due_reviews(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    commands::access_review::decide_access_review_item_command::DecideAccessReviewItemCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 复核项决定命令处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 复核项决定命令处理器
+pub struct DecideAccessReviewItemHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl DecideAccessReviewItemHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<DecideAccessReviewItemCommand, ()> for DecideAccessReviewItemHandler {
+    async fn handle(&self, command: DecideAccessReviewItemCommand) -> AppResult<()> {
+        self.access_review_service.decide_item(command).await
+    }
+}
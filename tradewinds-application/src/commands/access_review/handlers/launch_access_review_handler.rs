@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    commands::access_review::launch_access_review_command::LaunchAccessReviewCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_error::AppResult;
+
+/// 发起访问复核活动命令处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 发起访问复核活动命令处理器
+pub struct LaunchAccessReviewHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl LaunchAccessReviewHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<LaunchAccessReviewCommand, AccessReviewAggregate> for LaunchAccessReviewHandler {
+    async fn handle(&self, command: LaunchAccessReviewCommand) -> AppResult<AccessReviewAggregate> {
+        self.access_review_service.launch_review(command).await
+    }
+}
@@ -0,0 +1,7 @@
+pub mod close_due_access_reviews_handler;
+pub mod decide_access_review_item_handler;
+pub mod launch_access_review_handler;
+
+pub use close_due_access_reviews_handler::CloseDueAccessReviewsHandler;
+pub use decide_access_review_item_handler::DecideAccessReviewItemHandler;
+pub use launch_access_review_handler::LaunchAccessReviewHandler;
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_review::AccessReviewName,
+    role::RoleId,
+    user::UserId,
+};
+
+/// 发起访问复核活动命令
+///
+/// 参数：
+/// - created_by: 发起人ID
+/// - name: 活动名称
+/// - role_ids: 复核范围内的角色ID
+/// - deadline: 截止时间（Unix 时间戳，秒）
+/// - reviewer_id: 兜底复核人ID，角色未配置审批人时使用，为空时由发起人复核
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchAccessReviewCommand {
+    pub created_by: UserId,
+    pub name: AccessReviewName,
+    pub role_ids: Vec<RoleId>,
+    pub deadline: i64,
+    pub reviewer_id: Option<UserId>,
+}
@@ -0,0 +1,12 @@
+pub mod close_due_access_reviews_command;
+pub mod decide_access_review_item_command;
+pub mod handlers;
+pub mod launch_access_review_command;
+
+pub use close_due_access_reviews_command::CloseDueAccessReviewsCommand;
+pub use decide_access_review_item_command::DecideAccessReviewItemCommand;
+pub use launch_access_review_command::LaunchAccessReviewCommand;
+
+pub use handlers::CloseDueAccessReviewsHandler;
+pub use handlers::DecideAccessReviewItemHandler;
+pub use handlers::LaunchAccessReviewHandler;
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    department::{
+        DepartmentId,
+        DepartmentName,
+        DepartmentSort,
+    },
+    user::UserId,
+};
+
+/// 创建部门命令
+///
+/// 参数：
+/// - name: 部门名称
+/// - parent_id: 上级部门ID
+/// - sort: 排序
+/// - leader_id: 负责人用户ID
+/// - created_by: 创建者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDepartmentCommand {
+    pub name: DepartmentName,
+    pub parent_id: Option<DepartmentId>,
+    pub sort: DepartmentSort,
+    pub leader_id: Option<UserId>,
+    pub created_by: Option<UserId>,
+}
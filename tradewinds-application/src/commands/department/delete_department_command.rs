@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    department::DepartmentId,
+    user::UserId,
+};
+
+/// 删除部门命令
+///
+/// 参数：
+/// - department_id: 部门ID
+/// - deleted_by: 删除者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteDepartmentCommand {
+    pub department_id: DepartmentId,
+    pub deleted_by: Option<UserId>,
+}
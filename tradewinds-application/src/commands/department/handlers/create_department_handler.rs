@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::department_service::IDepartmentService,
+    commands::department::create_department_command::CreateDepartmentCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_error::AppResult;
+
+/// 创建部门命令处理器
+///
+/// 参数：
+/// - department_service: 部门服务
+///
+/// 返回：
+/// - 创建部门命令处理器
+pub struct CreateDepartmentHandler {
+    department_service: Arc<dyn IDepartmentService>,
+}
+
+impl CreateDepartmentHandler {
+    pub fn new(department_service: Arc<dyn IDepartmentService>) -> Self {
+        Self { department_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CreateDepartmentCommand, Department> for CreateDepartmentHandler {
+    async fn handle(&self, command: CreateDepartmentCommand) -> AppResult<Department> {
+        self.department_service.create_department(command).await
+    }
+}
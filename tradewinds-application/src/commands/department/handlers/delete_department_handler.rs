@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::department_service::IDepartmentService,
+    commands::department::delete_department_command::DeleteDepartmentCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 删除部门命令处理器
+///
+/// 参数：
+/// - department_service: 部门服务
+///
+/// 返回：
+/// - 删除部门命令处理器
+pub struct DeleteDepartmentHandler {
+    department_service: Arc<dyn IDepartmentService>,
+}
+
+impl DeleteDepartmentHandler {
+    pub fn new(department_service: Arc<dyn IDepartmentService>) -> Self {
+        Self { department_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<DeleteDepartmentCommand, ()> for DeleteDepartmentHandler {
+    async fn handle(&self, command: DeleteDepartmentCommand) -> AppResult<()> {
+        self.department_service.delete_department(command).await
+    }
+}
@@ -0,0 +1,7 @@
+pub mod create_department_handler;
+pub mod delete_department_handler;
+pub mod update_department_handler;
+
+pub use create_department_handler::CreateDepartmentHandler;
+pub use delete_department_handler::DeleteDepartmentHandler;
+pub use update_department_handler::UpdateDepartmentHandler;
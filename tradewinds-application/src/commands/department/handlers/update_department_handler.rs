@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::department_service::IDepartmentService,
+    commands::department::update_department_command::UpdateDepartmentCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 更新部门命令处理器
+///
+/// 参数：
+/// - department_service: 部门服务
+///
+/// 返回：
+/// - 更新部门命令处理器
+pub struct UpdateDepartmentHandler {
+    department_service: Arc<dyn IDepartmentService>,
+}
+
+impl UpdateDepartmentHandler {
+    pub fn new(department_service: Arc<dyn IDepartmentService>) -> Self {
+        Self { department_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<UpdateDepartmentCommand, ()> for UpdateDepartmentHandler {
+    async fn handle(&self, command: UpdateDepartmentCommand) -> AppResult<()> {
+        self.department_service.update_department(command).await
+    }
+}
@@ -0,0 +1,12 @@
+pub mod create_department_command;
+pub mod delete_department_command;
+pub mod handlers;
+pub mod update_department_command;
+
+pub use create_department_command::CreateDepartmentCommand;
+pub use delete_department_command::DeleteDepartmentCommand;
+pub use update_department_command::UpdateDepartmentCommand;
+
+pub use handlers::CreateDepartmentHandler;
+pub use handlers::DeleteDepartmentHandler;
+pub use handlers::UpdateDepartmentHandler;
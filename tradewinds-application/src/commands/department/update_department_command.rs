@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    department::{
+        DepartmentId,
+        DepartmentName,
+        DepartmentSort,
+        DepartmentStatus,
+    },
+    user::UserId,
+};
+
+/// 更新部门命令
+///
+/// 参数：
+/// - id: 部门ID
+/// - name: 部门名称
+/// - parent_id: 上级部门ID（`Some(None)` 表示调整为顶级部门）
+/// - sort: 排序
+/// - leader_id: 负责人用户ID（`Some(None)` 表示清空负责人）
+/// - status: 部门状态
+/// - updated_by: 更新者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateDepartmentCommand {
+    pub id: DepartmentId,
+    pub name: Option<DepartmentName>,
+    pub parent_id: Option<Option<DepartmentId>>,
+    pub sort: Option<DepartmentSort>,
+    pub leader_id: Option<Option<UserId>>,
+    pub status: Option<DepartmentStatus>,
+    pub updated_by: Option<UserId>,
+}
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 创建功能开关命令
+///
+/// 参数：
+/// - key: 开关键
+/// - description: 说明
+/// - enabled: 是否开启，关闭时对所有人关闭
+/// - rollout_percentage: 灰度比例，0-100
+/// - allowed_roles: 白名单角色编码
+/// - allowed_tenants: 白名单租户ID
+/// - created_by: 创建者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateFeatureFlagCommand {
+    pub key: String,
+    pub description: String,
+    pub enabled: bool,
+    pub rollout_percentage: u8,
+    pub allowed_roles: Vec<String>,
+    pub allowed_tenants: Vec<String>,
+    pub created_by: Option<UserId>,
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 删除功能开关命令
+///
+/// 参数：
+/// - key: 开关键
+/// - deleted_by: 删除者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteFeatureFlagCommand {
+    pub key: String,
+    pub deleted_by: Option<UserId>,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::feature_flag_service::IFeatureFlagService,
+    commands::feature_flag::create_feature_flag_command::CreateFeatureFlagCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_error::AppResult;
+
+/// 创建功能开关命令处理器
+///
+/// 参数：
+/// - feature_flag_service: 功能开关服务
+///
+/// 返回：
+/// - 创建功能开关命令处理器
+pub struct CreateFeatureFlagHandler {
+    feature_flag_service: Arc<dyn IFeatureFlagService>,
+}
+
+impl CreateFeatureFlagHandler {
+    pub fn new(feature_flag_service: Arc<dyn IFeatureFlagService>) -> Self {
+        Self { feature_flag_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CreateFeatureFlagCommand, FeatureFlag> for CreateFeatureFlagHandler {
+    async fn handle(&self, command: CreateFeatureFlagCommand) -> AppResult<FeatureFlag> {
+        self.feature_flag_service.create_flag(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::feature_flag_service::IFeatureFlagService,
+    commands::feature_flag::delete_feature_flag_command::DeleteFeatureFlagCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 删除功能开关命令处理器
+///
+/// 参数：
+/// - feature_flag_service: 功能开关服务
+///
+/// 返回：
+/// - 删除功能开关命令处理器
+pub struct DeleteFeatureFlagHandler {
+    feature_flag_service: Arc<dyn IFeatureFlagService>,
+}
+
+impl DeleteFeatureFlagHandler {
+    pub fn new(feature_flag_service: Arc<dyn IFeatureFlagService>) -> Self {
+        Self { feature_flag_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<DeleteFeatureFlagCommand, ()> for DeleteFeatureFlagHandler {
+    async fn handle(&self, command: DeleteFeatureFlagCommand) -> AppResult<()> {
+        self.feature_flag_service.delete_flag(command).await
+    }
+}
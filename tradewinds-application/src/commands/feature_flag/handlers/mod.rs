@@ -0,0 +1,7 @@
+pub mod create_feature_flag_handler;
+pub mod delete_feature_flag_handler;
+pub mod update_feature_flag_handler;
+
+pub use create_feature_flag_handler::CreateFeatureFlagHandler;
+pub use delete_feature_flag_handler::DeleteFeatureFlagHandler;
+pub use update_feature_flag_handler::UpdateFeatureFlagHandler;
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::feature_flag_service::IFeatureFlagService,
+    commands::feature_flag::update_feature_flag_command::UpdateFeatureFlagCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_error::AppResult;
+
+/// 更新功能开关命令处理器
+///
+/// 参数：
+/// - feature_flag_service: 功能开关服务
+///
+/// 返回：
+/// - 更新功能开关命令处理器
+pub struct UpdateFeatureFlagHandler {
+    feature_flag_service: Arc<dyn IFeatureFlagService>,
+}
+
+impl UpdateFeatureFlagHandler {
+    pub fn new(feature_flag_service: Arc<dyn IFeatureFlagService>) -> Self {
+        Self { feature_flag_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<UpdateFeatureFlagCommand, FeatureFlag> for UpdateFeatureFlagHandler {
+    async fn handle(&self, command: UpdateFeatureFlagCommand) -> AppResult<FeatureFlag> {
+        self.feature_flag_service.update_flag(command).await
+    }
+}
@@ -0,0 +1,12 @@
+pub mod create_feature_flag_command;
+pub mod delete_feature_flag_command;
+pub mod handlers;
+pub mod update_feature_flag_command;
+
+pub use create_feature_flag_command::CreateFeatureFlagCommand;
+pub use delete_feature_flag_command::DeleteFeatureFlagCommand;
+pub use update_feature_flag_command::UpdateFeatureFlagCommand;
+
+pub use handlers::CreateFeatureFlagHandler;
+pub use handlers::DeleteFeatureFlagHandler;
+pub use handlers::UpdateFeatureFlagHandler;
@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 更新功能开关命令
+///
+/// 参数：
+/// - key: 开关键
+/// - description: 说明
+/// - enabled: 是否开启
+/// - rollout_percentage: 灰度比例，0-100
+/// - allowed_roles: 白名单角色编码
+/// - allowed_tenants: 白名单租户ID
+/// - updated_by: 更新者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateFeatureFlagCommand {
+    pub key: String,
+    pub description: Option<String>,
+    pub enabled: Option<bool>,
+    pub rollout_percentage: Option<u8>,
+    pub allowed_roles: Option<Vec<String>>,
+    pub allowed_tenants: Option<Vec<String>>,
+    pub updated_by: Option<UserId>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    group::GroupId,
+    user::UserId,
+};
+
+/// 添加用户组成员命令
+///
+/// 参数：
+/// - group_id: 用户组ID
+/// - user_ids: 要加入的用户ID列表
+/// - added_by: 操作者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGroupMembersCommand {
+    pub group_id: GroupId,
+    pub user_ids: Vec<UserId>,
+    pub added_by: Option<UserId>,
+}
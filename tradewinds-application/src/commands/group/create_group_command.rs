@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    group::{
+        GroupDescription,
+        GroupName,
+    },
+    role::RoleId,
+    user::UserId,
+};
+
+/// 创建用户组命令
+///
+/// 参数：
+/// - name: 用户组名称
+/// - description: 用户组描述
+/// - role_ids: 用户组携带的角色ID列表
+/// - created_by: 创建者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateGroupCommand {
+    pub name: GroupName,
+    pub description: Option<GroupDescription>,
+    pub role_ids: Vec<RoleId>,
+    pub created_by: Option<UserId>,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    group::GroupId,
+    user::UserId,
+};
+
+/// 删除用户组命令
+///
+/// 参数：
+/// - group_id: 用户组ID
+/// - deleted_by: 删除者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteGroupCommand {
+    pub group_id: GroupId,
+    pub deleted_by: Option<UserId>,
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::group_service::IGroupService,
+    commands::group::add_group_members_command::AddGroupMembersCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 添加用户组成员命令处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 添加用户组成员命令处理器
+pub struct AddGroupMembersHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl AddGroupMembersHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<AddGroupMembersCommand, ()> for AddGroupMembersHandler {
+    async fn handle(&self, command: AddGroupMembersCommand) -> AppResult<()> {
+        self.group_service.add_group_members(command).await
+    }
+}
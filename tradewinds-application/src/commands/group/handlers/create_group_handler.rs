@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::group_service::IGroupService,
+    commands::group::create_group_command::CreateGroupCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::group::Group;
+use tradewinds_error::AppResult;
+
+/// 创建用户组命令处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 创建用户组命令处理器
+pub struct CreateGroupHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl CreateGroupHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CreateGroupCommand, Group> for CreateGroupHandler {
+    async fn handle(&self, command: CreateGroupCommand) -> AppResult<Group> {
+        self.group_service.create_group(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::group_service::IGroupService,
+    commands::group::delete_group_command::DeleteGroupCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 删除用户组命令处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 删除用户组命令处理器
+pub struct DeleteGroupHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl DeleteGroupHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<DeleteGroupCommand, ()> for DeleteGroupHandler {
+    async fn handle(&self, command: DeleteGroupCommand) -> AppResult<()> {
+        self.group_service.delete_group(command).await
+    }
+}
@@ -0,0 +1,11 @@
+pub mod add_group_members_handler;
+pub mod create_group_handler;
+pub mod delete_group_handler;
+pub mod remove_group_member_handler;
+pub mod update_group_handler;
+
+pub use add_group_members_handler::AddGroupMembersHandler;
+pub use create_group_handler::CreateGroupHandler;
+pub use delete_group_handler::DeleteGroupHandler;
+pub use remove_group_member_handler::RemoveGroupMemberHandler;
+pub use update_group_handler::UpdateGroupHandler;
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::group_service::IGroupService,
+    commands::group::remove_group_member_command::RemoveGroupMemberCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 移除用户组成员命令处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 移除用户组成员命令处理器
+pub struct RemoveGroupMemberHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl RemoveGroupMemberHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<RemoveGroupMemberCommand, ()> for RemoveGroupMemberHandler {
+    async fn handle(&self, command: RemoveGroupMemberCommand) -> AppResult<()> {
+        self.group_service.remove_group_member(command).await
+    }
+}
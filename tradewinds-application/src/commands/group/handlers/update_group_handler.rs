@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::group_service::IGroupService,
+    commands::group::update_group_command::UpdateGroupCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 更新用户组命令处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 更新用户组命令处理器
+pub struct UpdateGroupHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl UpdateGroupHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<UpdateGroupCommand, ()> for UpdateGroupHandler {
+    async fn handle(&self, command: UpdateGroupCommand) -> AppResult<()> {
+        self.group_service.update_group(command).await
+    }
+}
@@ -0,0 +1,18 @@
+pub mod add_group_members_command;
+pub mod create_group_command;
+pub mod delete_group_command;
+pub mod handlers;
+pub mod remove_group_member_command;
+pub mod update_group_command;
+
+pub use add_group_members_command::AddGroupMembersCommand;
+pub use create_group_command::CreateGroupCommand;
+pub use delete_group_command::DeleteGroupCommand;
+pub use remove_group_member_command::RemoveGroupMemberCommand;
+pub use update_group_command::UpdateGroupCommand;
+
+pub use handlers::AddGroupMembersHandler;
+pub use handlers::CreateGroupHandler;
+pub use handlers::DeleteGroupHandler;
+pub use handlers::RemoveGroupMemberHandler;
+pub use handlers::UpdateGroupHandler;
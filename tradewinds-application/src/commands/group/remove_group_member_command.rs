@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    group::GroupId,
+    user::UserId,
+};
+
+/// 移除用户组成员命令
+///
+/// 参数：
+/// - group_id: 用户组ID
+/// - user_id: 要移除的用户ID
+/// - removed_by: 操作者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoveGroupMemberCommand {
+    pub group_id: GroupId,
+    pub user_id: UserId,
+    pub removed_by: Option<UserId>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    group::{
+        GroupDescription,
+        GroupId,
+        GroupName,
+        GroupStatus,
+    },
+    role::RoleId,
+    user::UserId,
+};
+
+/// 更新用户组命令
+///
+/// 参数：
+/// - id: 用户组ID
+/// - name: 用户组名称
+/// - description: 用户组描述
+/// - status: 用户组状态
+/// - role_ids: 用户组携带的角色ID列表（整体替换）
+/// - updated_by: 更新者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateGroupCommand {
+    pub id: GroupId,
+    pub name: Option<GroupName>,
+    pub description: Option<GroupDescription>,
+    pub status: Option<GroupStatus>,
+    pub role_ids: Option<Vec<RoleId>>,
+    pub updated_by: Option<UserId>,
+}
@@ -0,0 +1,7 @@
+pub mod pause_job_handler;
+pub mod resume_job_handler;
+pub mod trigger_job_handler;
+
+pub use pause_job_handler::PauseJobHandler;
+pub use resume_job_handler::ResumeJobHandler;
+pub use trigger_job_handler::TriggerJobHandler;
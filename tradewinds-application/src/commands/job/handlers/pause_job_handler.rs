@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::job_service::IJobService,
+    commands::job::pause_job_command::PauseJobCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 暂停定时任务命令处理器
+///
+/// 参数：
+/// - job_service: 定时任务服务
+///
+/// 返回：
+/// - 暂停定时任务命令处理器
+pub struct PauseJobHandler {
+    job_service: Arc<dyn IJobService>,
+}
+
+impl PauseJobHandler {
+    pub fn new(job_service: Arc<dyn IJobService>) -> Self {
+        Self { job_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<PauseJobCommand, ()> for PauseJobHandler {
+    async fn handle(&self, command: PauseJobCommand) -> AppResult<()> {
+        self.job_service.pause_job(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::job_service::IJobService,
+    commands::job::resume_job_command::ResumeJobCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 恢复定时任务命令处理器
+///
+/// 参数：
+/// - job_service: 定时任务服务
+///
+/// 返回：
+/// - 恢复定时任务命令处理器
+pub struct ResumeJobHandler {
+    job_service: Arc<dyn IJobService>,
+}
+
+impl ResumeJobHandler {
+    pub fn new(job_service: Arc<dyn IJobService>) -> Self {
+        Self { job_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<ResumeJobCommand, ()> for ResumeJobHandler {
+    async fn handle(&self, command: ResumeJobCommand) -> AppResult<()> {
+        self.job_service.resume_job(command).await
+    }
+}
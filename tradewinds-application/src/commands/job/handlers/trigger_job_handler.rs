@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::job_service::IJobService,
+    commands::job::trigger_job_command::TriggerJobCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_error::AppResult;
+
+/// 手动触发定时任务命令处理器
+///
+/// 参数：
+/// - job_service: 定时任务服务
+///
+/// 返回：
+/// - 手动触发定时任务命令处理器
+pub struct TriggerJobHandler {
+    job_service: Arc<dyn IJobService>,
+}
+
+impl TriggerJobHandler {
+    pub fn new(job_service: Arc<dyn IJobService>) -> Self {
+        Self { job_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<TriggerJobCommand, JobRun> for TriggerJobHandler {
+    async fn handle(&self, command: TriggerJobCommand) -> AppResult<JobRun> {
+        self.job_service.trigger_job(command).await
+    }
+}
@@ -0,0 +1,12 @@
+pub mod handlers;
+pub mod pause_job_command;
+pub mod resume_job_command;
+pub mod trigger_job_command;
+
+pub use pause_job_command::PauseJobCommand;
+pub use resume_job_command::ResumeJobCommand;
+pub use trigger_job_command::TriggerJobCommand;
+
+pub use handlers::PauseJobHandler;
+pub use handlers::ResumeJobHandler;
+pub use handlers::TriggerJobHandler;
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 暂停定时任务命令
+///
+/// 暂停后不再按计划执行，仍可手动触发
+///
+/// 参数：
+/// - name: 任务名称
+/// - paused_by: 操作者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PauseJobCommand {
+    pub name: String,
+    pub paused_by: Option<UserId>,
+}
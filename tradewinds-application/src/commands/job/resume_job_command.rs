@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 恢复定时任务命令
+///
+/// 从当前时间起重新计算下次执行时间
+///
+/// 参数：
+/// - name: 任务名称
+/// - resumed_by: 操作者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResumeJobCommand {
+    pub name: String,
+    pub resumed_by: Option<UserId>,
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 手动触发定时任务命令
+///
+/// 不受暂停状态限制，任务正在执行时拒绝
+///
+/// 参数：
+/// - name: 任务名称
+/// - triggered_by: 触发者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerJobCommand {
+    pub name: String,
+    pub triggered_by: Option<UserId>,
+}
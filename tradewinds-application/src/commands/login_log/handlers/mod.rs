@@ -0,0 +1,3 @@
+pub mod purge_login_logs_handler;
+
+pub use purge_login_logs_handler::PurgeLoginLogsHandler;
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::login_log_service::ILoginLogService,
+    commands::login_log::purge_login_logs_command::PurgeLoginLogsCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 清理过期登录日志命令处理器
+///
+/// 参数：
+/// - login_log_service: 登录日志服务
+///
+/// 返回：
+/// - 清理过期登录日志命令处理器
+pub struct PurgeLoginLogsHandler {
+    login_log_service: Arc<dyn ILoginLogService>,
+}
+
+impl PurgeLoginLogsHandler {
+    pub fn new(login_log_service: Arc<dyn ILoginLogService>) -> Self {
+        Self { login_log_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<PurgeLoginLogsCommand, u64> for PurgeLoginLogsHandler {
+    async fn handle(&self, command: PurgeLoginLogsCommand) -> AppResult<u64> {
+        self.login_log_service.purge_expired(command).await
+    }
+}
@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod purge_login_logs_command;
+
+pub use purge_login_logs_command::PurgeLoginLogsCommand;
+
+pub use handlers::PurgeLoginLogsHandler;
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 清理过期登录日志命令
+///
+/// 删除超过保留天数的登录日志
+///
+/// 参数：
+/// - triggered_by: 触发者ID（定时任务触发时为空）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurgeLoginLogsCommand {
+    pub triggered_by: Option<UserId>,
+}
@@ -1,9 +1,47 @@
+pub mod access_request;
+pub mod access_review;
 pub mod auth;
+pub mod department;
+pub mod feature_flag;
+pub mod group;
+pub mod job;
+pub mod login_log;
+pub mod outbox;
 pub mod permission;
+pub mod policy;
 pub mod role;
+pub mod tenant;
 pub mod user;
+pub mod webhook;
 pub mod system_setting;
 
+pub use access_request::SubmitAccessRequestCommand;
+pub use access_request::SubmitAccessRequestHandler;
+
+pub use access_request::ApproveAccessRequestCommand;
+pub use access_request::ApproveAccessRequestHandler;
+
+pub use access_request::RejectAccessRequestCommand;
+pub use access_request::RejectAccessRequestHandler;
+
+pub use access_request::CancelAccessRequestCommand;
+pub use access_request::CancelAccessRequestHandler;
+
+pub use access_request::ExpireAccessRequestsCommand;
+pub use access_request::ExpireAccessRequestsHandler;
+
+pub use access_request::SetRoleApproversCommand;
+pub use access_request::SetRoleApproversHandler;
+
+pub use access_review::LaunchAccessReviewCommand;
+pub use access_review::LaunchAccessReviewHandler;
+
+pub use access_review::DecideAccessReviewItemCommand;
+pub use access_review::DecideAccessReviewItemHandler;
+
+pub use access_review::CloseDueAccessReviewsCommand;
+pub use access_review::CloseDueAccessReviewsHandler;
+
 pub use auth::LoginCommand;
 pub use auth::LoginHandler;
 
@@ -31,6 +69,54 @@ pub use user::AssignRoleHandler;
 pub use user::RevokeRoleCommand;
 pub use user::RevokeRoleHandler;
 
+pub use department::CreateDepartmentCommand;
+pub use department::CreateDepartmentHandler;
+
+pub use department::DeleteDepartmentCommand;
+pub use department::DeleteDepartmentHandler;
+
+pub use department::UpdateDepartmentCommand;
+pub use department::UpdateDepartmentHandler;
+
+pub use feature_flag::CreateFeatureFlagCommand;
+pub use feature_flag::CreateFeatureFlagHandler;
+
+pub use feature_flag::UpdateFeatureFlagCommand;
+pub use feature_flag::UpdateFeatureFlagHandler;
+
+pub use feature_flag::DeleteFeatureFlagCommand;
+pub use feature_flag::DeleteFeatureFlagHandler;
+
+pub use group::CreateGroupCommand;
+pub use group::CreateGroupHandler;
+
+pub use group::DeleteGroupCommand;
+pub use group::DeleteGroupHandler;
+
+pub use group::UpdateGroupCommand;
+pub use group::UpdateGroupHandler;
+
+pub use group::AddGroupMembersCommand;
+pub use group::AddGroupMembersHandler;
+
+pub use group::RemoveGroupMemberCommand;
+pub use group::RemoveGroupMemberHandler;
+
+pub use job::TriggerJobCommand;
+pub use job::TriggerJobHandler;
+
+pub use job::PauseJobCommand;
+pub use job::PauseJobHandler;
+
+pub use job::ResumeJobCommand;
+pub use job::ResumeJobHandler;
+
+pub use login_log::PurgeLoginLogsCommand;
+pub use login_log::PurgeLoginLogsHandler;
+
+pub use outbox::RetryOutboxMessageCommand;
+pub use outbox::RetryOutboxMessageHandler;
+
 pub use permission::CreatePermissionCommand;
 pub use permission::CreatePermissionHandler;
 
@@ -40,5 +126,32 @@ pub use permission::DeletePermissionHandler;
 pub use permission::UpdatePermissionCommand;
 pub use permission::UpdatePermissionHandler;
 
+pub use policy::CreateAccessPolicyCommand;
+pub use policy::CreateAccessPolicyHandler;
+
+pub use policy::UpdateAccessPolicyCommand;
+pub use policy::UpdateAccessPolicyHandler;
+
+pub use policy::DeleteAccessPolicyCommand;
+pub use policy::DeleteAccessPolicyHandler;
+
+pub use tenant::CreateTenantCommand;
+pub use tenant::CreateTenantHandler;
+
+pub use tenant::UpdateTenantCommand;
+pub use tenant::UpdateTenantHandler;
+
 pub use system_setting::SetSystemSettingCommand;
-pub use system_setting::SetSystemSettingHandler;
\ No newline at end of file
+pub use system_setting::SetSystemSettingHandler;
+
+pub use webhook::CreateWebhookCommand;
+pub use webhook::CreateWebhookHandler;
+
+pub use webhook::UpdateWebhookCommand;
+pub use webhook::UpdateWebhookHandler;
+
+pub use webhook::DeleteWebhookCommand;
+pub use webhook::DeleteWebhookHandler;
+
+pub use webhook::RedeliverWebhookDeliveryCommand;
+pub use webhook::RedeliverWebhookDeliveryHandler;
\ No newline at end of file
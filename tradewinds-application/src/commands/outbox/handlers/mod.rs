@@ -0,0 +1,3 @@
+pub mod retry_outbox_message_handler;
+
+pub use retry_outbox_message_handler::RetryOutboxMessageHandler;
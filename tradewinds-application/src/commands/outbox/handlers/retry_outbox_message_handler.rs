@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::outbox_service::IOutboxService,
+    commands::outbox::retry_outbox_message_command::RetryOutboxMessageCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 重新投递发件箱消息命令处理器
+///
+/// 参数：
+/// - outbox_service: 发件箱服务
+///
+/// 返回：
+/// - 重新投递发件箱消息命令处理器
+pub struct RetryOutboxMessageHandler {
+    outbox_service: Arc<dyn IOutboxService>,
+}
+
+impl RetryOutboxMessageHandler {
+    pub fn new(outbox_service: Arc<dyn IOutboxService>) -> Self {
+        Self { outbox_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<RetryOutboxMessageCommand, ()> for RetryOutboxMessageHandler {
+    async fn handle(&self, command: RetryOutboxMessageCommand) -> AppResult<()> {
+        self.outbox_service.retry_message(command).await
+    }
+}
@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod retry_outbox_message_command;
+
+pub use retry_outbox_message_command::RetryOutboxMessageCommand;
+
+pub use handlers::RetryOutboxMessageHandler;
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::outbox::OutboxMessageId;
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 重新投递发件箱消息命令
+///
+/// 将死信或等待重试的消息立即放回待投递队列
+///
+/// 参数：
+/// - id: 消息ID
+/// - triggered_by: 操作者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryOutboxMessageCommand {
+    pub id: OutboxMessageId,
+    pub triggered_by: Option<UserId>,
+}
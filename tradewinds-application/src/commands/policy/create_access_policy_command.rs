@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    permission::PermissionCode,
+    policy::{
+        AccessPolicyName,
+        PolicyExpression,
+    },
+    role_permission::PermissionEffect,
+    user::UserId,
+};
+
+/// 创建访问策略命令
+///
+/// 参数：
+/// - name: 策略名称
+/// - description: 策略描述
+/// - action: 作用的操作（权限码，支持通配）
+/// - effect: 效果（允许/拒绝）
+/// - condition: 条件表达式
+/// - created_by: 创建者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAccessPolicyCommand {
+    pub name: AccessPolicyName,
+    pub description: Option<String>,
+    pub action: PermissionCode,
+    pub effect: PermissionEffect,
+    pub condition: PolicyExpression,
+    pub created_by: Option<UserId>,
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    policy::AccessPolicyId,
+    user::UserId,
+};
+
+/// 删除访问策略命令
+///
+/// 参数：
+/// - id: 策略ID
+/// - deleted_by: 删除者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteAccessPolicyCommand {
+    pub id: AccessPolicyId,
+    pub deleted_by: Option<UserId>,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::policy_service::IPolicyService,
+    commands::policy::create_access_policy_command::CreateAccessPolicyCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_error::AppResult;
+
+/// 创建访问策略命令处理器
+///
+/// 参数：
+/// - policy_service: 访问策略服务
+///
+/// 返回：
+/// - 创建访问策略命令处理器
+pub struct CreateAccessPolicyHandler {
+    policy_service: Arc<dyn IPolicyService>,
+}
+
+impl CreateAccessPolicyHandler {
+    pub fn new(policy_service: Arc<dyn IPolicyService>) -> Self {
+        Self { policy_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CreateAccessPolicyCommand, AccessPolicy> for CreateAccessPolicyHandler {
+    async fn handle(&self, command: CreateAccessPolicyCommand) -> AppResult<AccessPolicy> {
+        self.policy_service.create_policy(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::policy_service::IPolicyService,
+    commands::policy::delete_access_policy_command::DeleteAccessPolicyCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 删除访问策略命令处理器
+///
+/// 参数：
+/// - policy_service: 访问策略服务
+///
+/// 返回：
+/// - 删除访问策略命令处理器
+pub struct DeleteAccessPolicyHandler {
+    policy_service: Arc<dyn IPolicyService>,
+}
+
+impl DeleteAccessPolicyHandler {
+    pub fn new(policy_service: Arc<dyn IPolicyService>) -> Self {
+        Self { policy_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<DeleteAccessPolicyCommand, ()> for DeleteAccessPolicyHandler {
+    async fn handle(&self, command: DeleteAccessPolicyCommand) -> AppResult<()> {
+        self.policy_service.delete_policy(command).await
+    }
+}
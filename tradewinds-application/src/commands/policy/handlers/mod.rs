@@ -0,0 +1,7 @@
+pub mod create_access_policy_handler;
+pub mod delete_access_policy_handler;
+pub mod update_access_policy_handler;
+
+pub use create_access_policy_handler::CreateAccessPolicyHandler;
+pub use delete_access_policy_handler::DeleteAccessPolicyHandler;
+pub use update_access_policy_handler::UpdateAccessPolicyHandler;
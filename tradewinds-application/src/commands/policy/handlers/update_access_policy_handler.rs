@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::policy_service::IPolicyService,
+    commands::policy::update_access_policy_command::UpdateAccessPolicyCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 更新访问策略命令处理器
+///
+/// 参数：
+/// - policy_service: 访问策略服务
+///
+/// 返回：
+/// - 更新访问策略命令处理器
+pub struct UpdateAccessPolicyHandler {
+    policy_service: Arc<dyn IPolicyService>,
+}
+
+impl UpdateAccessPolicyHandler {
+    pub fn new(policy_service: Arc<dyn IPolicyService>) -> Self {
+        Self { policy_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<UpdateAccessPolicyCommand, ()> for UpdateAccessPolicyHandler {
+    async fn handle(&self, command: UpdateAccessPolicyCommand) -> AppResult<()> {
+        self.policy_service.update_policy(command).await
+    }
+}
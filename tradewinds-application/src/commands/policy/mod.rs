@@ -0,0 +1,12 @@
+pub mod create_access_policy_command;
+pub mod delete_access_policy_command;
+pub mod handlers;
+pub mod update_access_policy_command;
+
+pub use create_access_policy_command::CreateAccessPolicyCommand;
+pub use delete_access_policy_command::DeleteAccessPolicyCommand;
+pub use update_access_policy_command::UpdateAccessPolicyCommand;
+
+pub use handlers::CreateAccessPolicyHandler;
+pub use handlers::DeleteAccessPolicyHandler;
+pub use handlers::UpdateAccessPolicyHandler;
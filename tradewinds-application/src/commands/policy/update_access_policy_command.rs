@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    permission::PermissionCode,
+    policy::{
+        AccessPolicyId,
+        AccessPolicyName,
+        AccessPolicyStatus,
+        PolicyExpression,
+    },
+    role_permission::PermissionEffect,
+    user::UserId,
+};
+
+/// 更新访问策略命令
+///
+/// 参数：
+/// - id: 策略ID
+/// - name: 策略名称
+/// - description: 策略描述（`Some(None)` 表示清空）
+/// - action: 作用的操作
+/// - effect: 效果
+/// - condition: 条件表达式
+/// - status: 策略状态
+/// - updated_by: 更新者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateAccessPolicyCommand {
+    pub id: AccessPolicyId,
+    pub name: Option<AccessPolicyName>,
+    pub description: Option<Option<String>>,
+    pub action: Option<PermissionCode>,
+    pub effect: Option<PermissionEffect>,
+    pub condition: Option<PolicyExpression>,
+    pub status: Option<AccessPolicyStatus>,
+    pub updated_by: Option<UserId>,
+}
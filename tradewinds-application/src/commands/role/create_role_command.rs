@@ -2,7 +2,9 @@ use serde::{Deserialize, Serialize};
 
 #[rustfmt::skip]
 use tradewinds_domain::value_objects::{
+    department::DepartmentId,
     permission::PermissionId,
+    scope::DataScopeType,
     role::{RoleCode, RoleDescription, RoleName, RoleStatus},
     user::UserId,
 };
@@ -13,11 +15,19 @@ use tradewinds_domain::value_objects::{
 /// - name: 角色名称
 /// - description: 角色描述
 /// - permissions: 权限ID列表
-#[derive(Debug, Clone)]
+/// - denied_permissions: 显式拒绝的权限ID列表
+/// - data_scope: 数据范围，默认全部
+/// - data_scope_departments: 自定义数据范围的部门ID列表
+/// - created_by: 创建人
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateRoleCommand {
     pub name: RoleName,
     pub code: RoleCode,
     pub description: Option<RoleDescription>,
     pub permissions: Vec<PermissionId>,
+    pub denied_permissions: Vec<PermissionId>,
     pub status: Option<RoleStatus>, // 新增，支持指定角色状态
+    pub data_scope: Option<DataScopeType>,
+    pub data_scope_departments: Vec<DepartmentId>,
+    pub created_by: Option<UserId>,
 }
@@ -10,6 +10,8 @@ use tradewinds_domain::value_objects::{
     },
     user::UserId,
     permission::PermissionId,
+    department::DepartmentId,
+    scope::DataScopeType,
 };
 
 /// 更新角色命令
@@ -20,6 +22,10 @@ use tradewinds_domain::value_objects::{
 /// - description: 角色描述
 /// - status: 角色状态
 /// - updated_by: 更新者ID
+/// - permissions: 允许的权限ID列表
+/// - denied_permissions: 显式拒绝的权限ID列表
+/// - data_scope: 数据范围
+/// - data_scope_departments: 自定义数据范围的部门ID列表
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateRoleCommand {
     pub id: RoleId,
@@ -28,4 +34,7 @@ pub struct UpdateRoleCommand {
     pub status: Option<RoleStatus>,
     pub updated_by: Option<UserId>,
     pub permissions: Option<Vec<PermissionId>>,
+    pub denied_permissions: Option<Vec<PermissionId>>,
+    pub data_scope: Option<DataScopeType>,
+    pub data_scope_departments: Option<Vec<DepartmentId>>,
 }
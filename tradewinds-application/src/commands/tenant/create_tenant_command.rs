@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    auth::{
+        auth_password::Password,
+        auth_username::AuthUsername,
+    },
+    tenant::{
+        TenantCode,
+        TenantHost,
+        TenantName,
+    },
+    user::{
+        user_email::Email,
+        user_id::UserId,
+    },
+};
+
+/// 创建租户命令
+///
+/// 创建租户时同时初始化该租户的超级管理员角色与初始管理员账号。
+///
+/// 参数：
+/// - code: 租户编码
+/// - name: 租户名称
+/// - host: 绑定的访问域名
+/// - admin_username: 初始管理员用户名
+/// - admin_email: 初始管理员邮箱
+/// - admin_password: 初始管理员密码（明文）
+/// - created_by: 创建者ID（须为平台管理员）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateTenantCommand {
+    pub code: TenantCode,
+    pub name: TenantName,
+    pub host: Option<TenantHost>,
+    pub admin_username: AuthUsername,
+    pub admin_email: Email,
+    pub admin_password: Password,
+    pub created_by: UserId,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::tenant_service::ITenantService,
+    commands::tenant::create_tenant_command::CreateTenantCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_error::AppResult;
+
+/// 创建租户命令处理器
+///
+/// 参数：
+/// - tenant_service: 租户服务
+///
+/// 返回：
+/// - 创建租户命令处理器
+pub struct CreateTenantHandler {
+    tenant_service: Arc<dyn ITenantService>,
+}
+
+impl CreateTenantHandler {
+    pub fn new(tenant_service: Arc<dyn ITenantService>) -> Self {
+        Self { tenant_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CreateTenantCommand, Tenant> for CreateTenantHandler {
+    async fn handle(&self, command: CreateTenantCommand) -> AppResult<Tenant> {
+        self.tenant_service.create_tenant(command).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod create_tenant_handler;
+pub mod update_tenant_handler;
+
+pub use create_tenant_handler::CreateTenantHandler;
+pub use update_tenant_handler::UpdateTenantHandler;
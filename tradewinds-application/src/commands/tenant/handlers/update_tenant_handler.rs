@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::tenant_service::ITenantService,
+    commands::tenant::update_tenant_command::UpdateTenantCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 更新租户命令处理器
+///
+/// 参数：
+/// - tenant_service: 租户服务
+///
+/// 返回：
+/// - 更新租户命令处理器
+pub struct UpdateTenantHandler {
+    tenant_service: Arc<dyn ITenantService>,
+}
+
+impl UpdateTenantHandler {
+    pub fn new(tenant_service: Arc<dyn ITenantService>) -> Self {
+        Self { tenant_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<UpdateTenantCommand, ()> for UpdateTenantHandler {
+    async fn handle(&self, command: UpdateTenantCommand) -> AppResult<()> {
+        self.tenant_service.update_tenant(command).await
+    }
+}
@@ -0,0 +1,9 @@
+pub mod create_tenant_command;
+pub mod handlers;
+pub mod update_tenant_command;
+
+pub use create_tenant_command::CreateTenantCommand;
+pub use update_tenant_command::UpdateTenantCommand;
+
+pub use handlers::CreateTenantHandler;
+pub use handlers::UpdateTenantHandler;
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    tenant::{
+        TenantHost,
+        TenantId,
+        TenantName,
+        TenantStatus,
+    },
+    user::UserId,
+};
+
+/// 更新租户命令
+///
+/// 参数：
+/// - id: 租户ID
+/// - name: 租户名称
+/// - host: 绑定的访问域名（`Some(None)` 表示解除绑定）
+/// - status: 租户状态
+/// - updated_by: 更新者ID（须为平台管理员）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateTenantCommand {
+    pub id: TenantId,
+    pub name: Option<TenantName>,
+    pub host: Option<Option<TenantHost>>,
+    pub status: Option<TenantStatus>,
+    pub updated_by: UserId,
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[rustfmt::skip]
 use tradewinds_domain::value_objects::{
+    access_request::AccessRequestId,
     role::RoleId, 
     user::UserId,
 };
@@ -12,9 +13,11 @@ use tradewinds_domain::value_objects::{
 /// - user_id: 用户ID
 /// - role_id: 角色ID
 /// - assigned_by: 分配者ID
+/// - access_request_id: 经权限申请授予时对应的申请ID，申请到期时据此收回
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssignRoleCommand {
     pub user_id: UserId,
     pub role_id: RoleId,
     pub assigned_by: Option<UserId>,
+    pub access_request_id: Option<AccessRequestId>,
 }
@@ -14,6 +14,7 @@ use tradewinds_domain::value_objects::{
         user_id::UserId,
     },
     role::RoleId,
+    department::DepartmentId,
 };
 
 /// 创建用户命令
@@ -26,6 +27,7 @@ use tradewinds_domain::value_objects::{
 /// - phone: 手机号
 /// - avatar: 头像
 /// - role_ids: 角色ID列表
+/// - department_id: 所属部门ID
 /// - created_by: 创建者ID
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateUserCommand {
@@ -36,5 +38,6 @@ pub struct CreateUserCommand {
     pub phone: Option<Phone>,
     pub avatar: Option<Avatar>,
     pub role_ids: Option<Vec<RoleId>>,
+    pub department_id: Option<DepartmentId>,
     pub created_by: Option<UserId>,
 }
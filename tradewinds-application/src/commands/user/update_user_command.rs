@@ -9,7 +9,7 @@ use tradewinds_domain::value_objects::user::{
     user_status::UserStatus,
     user_email::Email,
 };
-use tradewinds_domain::value_objects::role::RoleId;
+use tradewinds_domain::value_objects::{department::DepartmentId, role::RoleId};
 
 /// 更新用户命令
 ///
@@ -21,6 +21,7 @@ use tradewinds_domain::value_objects::role::RoleId;
 /// - status: 状态
 /// - email: 邮箱
 /// - role_ids: 角色ID列表
+/// - department_id: 所属部门ID（`Some(None)` 表示移出部门）
 /// - updated_by: 更新者ID
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateUserCommand {
@@ -31,5 +32,6 @@ pub struct UpdateUserCommand {
     pub status: Option<UserStatus>,
     pub email: Option<Email>,
     pub role_ids: Option<Vec<RoleId>>,
+    pub department_id: Option<Option<DepartmentId>>,
     pub updated_by: Option<UserId>,
 }
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 创建 Webhook 端点命令
+///
+/// 参数：
+/// - name: 端点名称
+/// - url: 投递地址
+/// - secret: 签名密钥，为空时自动生成
+/// - event_types: 订阅的事件类型，`*` 表示全部
+/// - created_by: 创建者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateWebhookCommand {
+    pub name: String,
+    pub url: String,
+    pub secret: Option<String>,
+    pub event_types: Vec<String>,
+    pub created_by: Option<UserId>,
+}
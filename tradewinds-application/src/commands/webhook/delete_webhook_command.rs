@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    user::UserId,
+    webhook::WebhookId,
+};
+
+/// 删除 Webhook 端点命令
+///
+/// 参数：
+/// - id: 端点ID
+/// - deleted_by: 删除者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteWebhookCommand {
+    pub id: WebhookId,
+    pub deleted_by: Option<UserId>,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::webhook_service::IWebhookService,
+    commands::webhook::create_webhook_command::CreateWebhookCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::webhook::Webhook;
+use tradewinds_error::AppResult;
+
+/// 创建 Webhook 端点命令处理器
+///
+/// 参数：
+/// - webhook_service: Webhook 服务
+///
+/// 返回：
+/// - 创建 Webhook 端点命令处理器
+pub struct CreateWebhookHandler {
+    webhook_service: Arc<dyn IWebhookService>,
+}
+
+impl CreateWebhookHandler {
+    pub fn new(webhook_service: Arc<dyn IWebhookService>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<CreateWebhookCommand, Webhook> for CreateWebhookHandler {
+    async fn handle(&self, command: CreateWebhookCommand) -> AppResult<Webhook> {
+        self.webhook_service.create_webhook(command).await
+    }
+}
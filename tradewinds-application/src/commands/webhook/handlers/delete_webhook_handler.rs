@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::webhook_service::IWebhookService,
+    commands::webhook::delete_webhook_command::DeleteWebhookCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 删除 Webhook 端点命令处理器
+///
+/// 参数：
+/// - webhook_service: Webhook 服务
+///
+/// 返回：
+/// - 删除 Webhook 端点命令处理器
+pub struct DeleteWebhookHandler {
+    webhook_service: Arc<dyn IWebhookService>,
+}
+
+impl DeleteWebhookHandler {
+    pub fn new(webhook_service: Arc<dyn IWebhookService>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<DeleteWebhookCommand, ()> for DeleteWebhookHandler {
+    async fn handle(&self, command: DeleteWebhookCommand) -> AppResult<()> {
+        self.webhook_service.delete_webhook(command).await
+    }
+}
@@ -0,0 +1,9 @@
+pub mod create_webhook_handler;
+pub mod delete_webhook_handler;
+pub mod redeliver_webhook_delivery_handler;
+pub mod update_webhook_handler;
+
+pub use create_webhook_handler::CreateWebhookHandler;
+pub use delete_webhook_handler::DeleteWebhookHandler;
+pub use redeliver_webhook_delivery_handler::RedeliverWebhookDeliveryHandler;
+pub use update_webhook_handler::UpdateWebhookHandler;
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::webhook_service::IWebhookService,
+    commands::webhook::redeliver_webhook_delivery_command::RedeliverWebhookDeliveryCommand,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::webhook_delivery::WebhookDelivery;
+use tradewinds_error::AppResult;
+
+/// 重新投递 Webhook命令处理器
+///
+/// 参数：
+/// - webhook_service: Webhook 服务
+///
+/// 返回：
+/// - 重新投递 Webhook命令处理器
+pub struct RedeliverWebhookDeliveryHandler {
+    webhook_service: Arc<dyn IWebhookService>,
+}
+
+impl RedeliverWebhookDeliveryHandler {
+    pub fn new(webhook_service: Arc<dyn IWebhookService>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<RedeliverWebhookDeliveryCommand, WebhookDelivery> for RedeliverWebhookDeliveryHandler {
+    async fn handle(&self, command: RedeliverWebhookDeliveryCommand) -> AppResult<WebhookDelivery> {
+        self.webhook_service.redeliver(command).await
+    }
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    CommandHandler,
+    interfaces::webhook_service::IWebhookService,
+    commands::webhook::update_webhook_command::UpdateWebhookCommand,
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 更新 Webhook 端点命令处理器
+///
+/// 参数：
+/// - webhook_service: Webhook 服务
+///
+/// 返回：
+/// - 更新 Webhook 端点命令处理器
+pub struct UpdateWebhookHandler {
+    webhook_service: Arc<dyn IWebhookService>,
+}
+
+impl UpdateWebhookHandler {
+    pub fn new(webhook_service: Arc<dyn IWebhookService>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl CommandHandler<UpdateWebhookCommand, ()> for UpdateWebhookHandler {
+    async fn handle(&self, command: UpdateWebhookCommand) -> AppResult<()> {
+        self.webhook_service.update_webhook(command).await
+    }
+}
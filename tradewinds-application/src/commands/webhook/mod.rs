@@ -0,0 +1,15 @@
+pub mod create_webhook_command;
+pub mod delete_webhook_command;
+pub mod handlers;
+pub mod redeliver_webhook_delivery_command;
+pub mod update_webhook_command;
+
+pub use create_webhook_command::CreateWebhookCommand;
+pub use delete_webhook_command::DeleteWebhookCommand;
+pub use redeliver_webhook_delivery_command::RedeliverWebhookDeliveryCommand;
+pub use update_webhook_command::UpdateWebhookCommand;
+
+pub use handlers::CreateWebhookHandler;
+pub use handlers::DeleteWebhookHandler;
+pub use handlers::RedeliverWebhookDeliveryHandler;
+pub use handlers::UpdateWebhookHandler;
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    user::UserId,
+    webhook::WebhookDeliveryId,
+};
+
+/// 重新投递 Webhook 命令
+///
+/// 以原投递记录的请求体生成一条新的待投递记录
+///
+/// 参数：
+/// - id: 原投递记录ID
+/// - triggered_by: 操作者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedeliverWebhookDeliveryCommand {
+    pub id: WebhookDeliveryId,
+    pub triggered_by: Option<UserId>,
+}
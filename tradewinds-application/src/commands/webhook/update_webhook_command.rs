@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    user::UserId,
+    webhook::WebhookId,
+};
+
+/// 更新 Webhook 端点命令
+///
+/// 参数：
+/// - id: 端点ID
+/// - name: 端点名称
+/// - url: 投递地址
+/// - secret: 签名密钥
+/// - event_types: 订阅的事件类型
+/// - enabled: 是否启用，重新启用时清零连续失败次数
+/// - updated_by: 更新者ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateWebhookCommand {
+    pub id: WebhookId,
+    pub name: Option<String>,
+    pub url: Option<String>,
+    pub secret: Option<String>,
+    pub event_types: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+    pub updated_by: Option<UserId>,
+}
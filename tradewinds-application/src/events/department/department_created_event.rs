@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::services::Event;
+use tradewinds_error::AppResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepartmentCreatedEvent {
+    pub department_id: String,
+    pub name: String,
+    pub created_by: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl DepartmentCreatedEvent {
+    pub fn new(department_id: &str, name: &str, created_by: &str) -> Self {
+        Self {
+            department_id: department_id.to_string(),
+            name: name.to_string(),
+            created_by: created_by.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+#[async_trait]
+impl Event for DepartmentCreatedEvent {
+    fn event_type(&self) -> &'static str {
+        "department.created"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
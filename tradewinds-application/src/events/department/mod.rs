@@ -0,0 +1,7 @@
+pub mod department_created_event;
+pub mod department_deleted_event;
+pub mod department_updated_event;
+
+pub use department_created_event::DepartmentCreatedEvent;
+pub use department_deleted_event::DepartmentDeletedEvent;
+pub use department_updated_event::DepartmentUpdatedEvent;
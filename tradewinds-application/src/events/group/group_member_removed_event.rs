@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::services::Event;
+use tradewinds_error::AppResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMemberRemovedEvent {
+    pub group_id: String,
+    pub user_id: String,
+    pub removed_by: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl GroupMemberRemovedEvent {
+    pub fn new(group_id: &str, user_id: &str, removed_by: &str) -> Self {
+        Self {
+            group_id: group_id.to_string(),
+            user_id: user_id.to_string(),
+            removed_by: removed_by.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+#[async_trait]
+impl Event for GroupMemberRemovedEvent {
+    fn event_type(&self) -> &'static str {
+        "group.member_removed"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
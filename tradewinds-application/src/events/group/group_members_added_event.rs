@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::services::Event;
+use tradewinds_error::AppResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembersAddedEvent {
+    pub group_id: String,
+    pub user_ids: Vec<String>,
+    pub added_by: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl GroupMembersAddedEvent {
+    pub fn new(group_id: &str, user_ids: &[String], added_by: &str) -> Self {
+        Self {
+            group_id: group_id.to_string(),
+            user_ids: user_ids.to_vec(),
+            added_by: added_by.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+#[async_trait]
+impl Event for GroupMembersAddedEvent {
+    fn event_type(&self) -> &'static str {
+        "group.members_added"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
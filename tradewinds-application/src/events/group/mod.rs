@@ -0,0 +1,11 @@
+pub mod group_created_event;
+pub mod group_deleted_event;
+pub mod group_member_removed_event;
+pub mod group_members_added_event;
+pub mod group_updated_event;
+
+pub use group_created_event::GroupCreatedEvent;
+pub use group_deleted_event::GroupDeletedEvent;
+pub use group_member_removed_event::GroupMemberRemovedEvent;
+pub use group_members_added_event::GroupMembersAddedEvent;
+pub use group_updated_event::GroupUpdatedEvent;
@@ -1,9 +1,22 @@
 pub mod prelude;
 
 pub mod auth;
+pub mod department;
+pub mod group;
+pub mod permission;
+pub mod registry;
 pub mod role;
+pub mod subscribers;
+pub mod system_setting;
+pub mod tenant;
 pub mod user;
 
 pub use auth::*;
+pub use department::*;
+pub use group::*;
+pub use permission::*;
+pub use registry::event_registry;
 pub use role::*;
+pub use system_setting::*;
+pub use tenant::*;
 pub use user::*;
@@ -0,0 +1,7 @@
+pub mod permission_created_event;
+pub mod permission_deleted_event;
+pub mod permission_updated_event;
+
+pub use permission_created_event::PermissionCreatedEvent;
+pub use permission_deleted_event::PermissionDeletedEvent;
+pub use permission_updated_event::PermissionUpdatedEvent;
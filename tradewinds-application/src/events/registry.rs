@@ -0,0 +1,43 @@
+use super::*;
+use tradewinds_domain::events::AccessRequestEvent;
+use tradewinds_domain::services::EventRegistry;
+
+/// 已知事件类型的注册表，用于从发件箱等持久化的事件 JSON 还原事件
+pub fn event_registry() -> EventRegistry {
+    let mut registry = EventRegistry::new();
+    registry
+        .register::<UserLoggedInEvent>("user.logged_in")
+        .register::<UserLoggedOutEvent>("user.logged_out")
+        .register::<UserPasswordChangedEvent>("user.password_changed")
+        .register::<UserRegisteredEvent>("user.registered")
+        .register::<UserCreatedEvent>("user.created")
+        .register::<UserUpdatedEvent>("user.updated")
+        .register::<UserDeletedEvent>("user.deleted")
+        .register::<UserProfileUpdatedEvent>("user.profile_updated")
+        .register::<UserStatusChangedEvent>("user.status_changed")
+        .register::<UserRoleAssignedEvent>("user.role_assigned")
+        .register::<UserRoleRevokedEvent>("user.role_revoked")
+        .register::<RoleCreatedEvent>("role.created")
+        .register::<RoleUpdatedEvent>("role.updated")
+        .register::<RoleDeletedEvent>("role.deleted")
+        .register::<PermissionCreatedEvent>("permission.created")
+        .register::<PermissionUpdatedEvent>("permission.updated")
+        .register::<PermissionDeletedEvent>("permission.deleted")
+        .register::<DepartmentCreatedEvent>("department.created")
+        .register::<DepartmentUpdatedEvent>("department.updated")
+        .register::<DepartmentDeletedEvent>("department.deleted")
+        .register::<GroupCreatedEvent>("group.created")
+        .register::<GroupUpdatedEvent>("group.updated")
+        .register::<GroupDeletedEvent>("group.deleted")
+        .register::<GroupMembersAddedEvent>("group.members_added")
+        .register::<GroupMemberRemovedEvent>("group.member_removed")
+        .register::<TenantCreatedEvent>("tenant.created")
+        .register::<TenantUpdatedEvent>("tenant.updated")
+        .register::<SystemSettingChangedEvent>("system_setting.changed")
+        .register::<AccessRequestEvent>("access_request.submitted")
+        .register::<AccessRequestEvent>("access_request.approved")
+        .register::<AccessRequestEvent>("access_request.rejected")
+        .register::<AccessRequestEvent>("access_request.cancelled")
+        .register::<AccessRequestEvent>("access_request.expired");
+    registry
+}
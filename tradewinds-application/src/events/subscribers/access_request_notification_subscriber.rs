@@ -0,0 +1,93 @@
+use crate::interfaces::INotificationService;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tradewinds_domain::entities::user::User;
+use tradewinds_domain::events::AccessRequestEvent;
+use tradewinds_domain::repositories::{RoleApproverRepository, RoleRepository, UserRepository};
+use tradewinds_domain::services::EventHandler;
+use tradewinds_error::{AppError, AppResult};
+
+/// 权限申请通知订阅者：新申请通知审批人，审批结果与过期通知申请人
+pub struct AccessRequestNotificationSubscriber {
+    approver_repo: Arc<dyn RoleApproverRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    notifier: Arc<dyn INotificationService>,
+}
+
+impl AccessRequestNotificationSubscriber {
+    pub fn new(
+        approver_repo: Arc<dyn RoleApproverRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        notifier: Arc<dyn INotificationService>,
+    ) -> Self {
+        Self { approver_repo, user_repo, role_repo, notifier }
+    }
+
+    async fn requester(&self, event: &AccessRequestEvent) -> AppResult<Vec<User>> {
+        Ok(self.user_repo.find_by_id(event.requester_id()).await?.into_iter().collect())
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).map(|t| t.to_rfc3339()).unwrap_or_default()
+}
+
+#[async_trait]
+impl EventHandler<AccessRequestEvent> for AccessRequestNotificationSubscriber {
+    async fn handle(&self, event: &AccessRequestEvent) -> AppResult<()> {
+        let role = self
+            .role_repo
+            .find_by_id(event.role_id())
+            .await?
+            .ok_or_else(|| AppError::NotFound("Role not found".into()))?;
+        let role_name = role.name.value();
+        let (recipients, subject, body) = match event {
+            AccessRequestEvent::Submitted { requester_id, .. } => {
+                let requester = self.user_repo.find_by_id(requester_id).await?;
+                let requester = requester.as_ref().map(|u| u.username.value()).unwrap_or_default();
+                let approver_ids = self.approver_repo.find_approver_ids(event.role_id()).await?;
+                (
+                    self.user_repo.find_by_ids(&approver_ids).await?,
+                    "Access request awaiting approval".to_string(),
+                    format!("{} requested role {}. Request ID: {}", requester, role_name, event.request_id()),
+                )
+            }
+            AccessRequestEvent::Approved { grant_expires_at, .. } => (
+                self.requester(event).await?,
+                "Access request approved".to_string(),
+                format!(
+                    "Your request for role {} was approved. Access expires at {}.",
+                    role_name,
+                    format_time(*grant_expires_at)
+                ),
+            ),
+            AccessRequestEvent::Rejected { comment, .. } => (
+                self.requester(event).await?,
+                "Access request rejected".to_string(),
+                format!(
+                    "Your request for role {} was rejected.{}",
+                    role_name,
+                    comment.as_ref().map(|c| format!(" Reason: {}", c)).unwrap_or_default()
+                ),
+            ),
+            AccessRequestEvent::Expired { grant_ended: true, .. } => (
+                self.requester(event).await?,
+                "Access expired".to_string(),
+                format!("Your temporary access to role {} has expired and was revoked.", role_name),
+            ),
+            AccessRequestEvent::Expired { grant_ended: false, .. } => (
+                self.requester(event).await?,
+                "Access request expired".to_string(),
+                format!("Your request for role {} expired before it was approved.", role_name),
+            ),
+            AccessRequestEvent::Cancelled { .. } => return Ok(()),
+        };
+        for recipient in recipients.iter().filter(|u| !u.status.is_deleted()) {
+            self.notifier.notify(recipient, &subject, &body).await?;
+        }
+        Ok(())
+    }
+}
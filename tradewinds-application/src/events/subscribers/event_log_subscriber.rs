@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use tradewinds_domain::services::{Event, EventHandler};
+use tradewinds_error::AppResult;
+
+/// 事件日志订阅者，以结构化日志记录所有事件
+pub struct EventLogSubscriber;
+
+#[async_trait]
+impl EventHandler<dyn Event> for EventLogSubscriber {
+    async fn handle(&self, event: &dyn Event) -> AppResult<()> {
+        let payload = event.to_json()?;
+        tracing::info!(event = event.event_type(), "{}", payload);
+        Ok(())
+    }
+}
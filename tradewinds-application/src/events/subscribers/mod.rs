@@ -0,0 +1,7 @@
+pub mod access_request_notification_subscriber;
+pub mod event_log_subscriber;
+pub mod webhook_subscriber;
+
+pub use access_request_notification_subscriber::AccessRequestNotificationSubscriber;
+pub use event_log_subscriber::EventLogSubscriber;
+pub use webhook_subscriber::WebhookSubscriber;
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::webhook_delivery::WebhookDelivery;
+use tradewinds_domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use tradewinds_domain::services::{Event, EventHandler};
+use tradewinds_error::AppResult;
+
+/// Webhook 订阅者：为订阅了该事件类型的每个启用端点写入一条待投递记录，由投递任务发送
+pub struct WebhookSubscriber {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl WebhookSubscriber {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>, delivery_repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self { webhook_repo, delivery_repo }
+    }
+}
+
+#[async_trait]
+impl EventHandler<dyn Event> for WebhookSubscriber {
+    async fn handle(&self, event: &dyn Event) -> AppResult<()> {
+        let event_type = event.event_type();
+        let webhooks: Vec<_> = self
+            .webhook_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|webhook| webhook.subscribes_to(event_type))
+            .collect();
+        if webhooks.is_empty() {
+            return Ok(());
+        }
+        let data = event.to_json()?;
+        let tenant_id = current_tenant_id();
+        for webhook in &webhooks {
+            let delivery = WebhookDelivery::create(webhook.id.clone(), event_type, &data, &tenant_id)?;
+            self.delivery_repo.create(&delivery).await?;
+        }
+        Ok(())
+    }
+}
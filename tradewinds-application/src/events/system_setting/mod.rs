@@ -0,0 +1,3 @@
+pub mod system_setting_changed_event;
+
+pub use system_setting_changed_event::SystemSettingChangedEvent;
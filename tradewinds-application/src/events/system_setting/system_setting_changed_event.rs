@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::services::Event;
+use tradewinds_error::AppResult;
+
+/// 系统设置变更事件，不携带设置值，避免敏感设置经事件外泄
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemSettingChangedEvent {
+    pub key: String,
+    pub updated_by: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl SystemSettingChangedEvent {
+    pub fn new(key: &str, updated_by: &str) -> Self {
+        Self { key: key.to_string(), updated_by: updated_by.to_string(), occurred_at: Utc::now() }
+    }
+}
+
+#[async_trait]
+impl Event for SystemSettingChangedEvent {
+    fn event_type(&self) -> &'static str {
+        "system_setting.changed"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
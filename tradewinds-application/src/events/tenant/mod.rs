@@ -0,0 +1,5 @@
+pub mod tenant_created_event;
+pub mod tenant_updated_event;
+
+pub use tenant_created_event::TenantCreatedEvent;
+pub use tenant_updated_event::TenantUpdatedEvent;
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::services::Event;
+use tradewinds_error::AppResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantCreatedEvent {
+    pub tenant_id: String,
+    pub code: String,
+    pub name: String,
+    pub created_by: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TenantCreatedEvent {
+    pub fn new(tenant_id: &str, code: &str, name: &str, created_by: &str) -> Self {
+        Self {
+            tenant_id: tenant_id.to_string(),
+            code: code.to_string(),
+            name: name.to_string(),
+            created_by: created_by.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+#[async_trait]
+impl Event for TenantCreatedEvent {
+    fn event_type(&self) -> &'static str {
+        "tenant.created"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
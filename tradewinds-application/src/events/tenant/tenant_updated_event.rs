@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::services::Event;
+use tradewinds_error::AppResult;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantUpdatedEvent {
+    pub tenant_id: String,
+    pub name: String,
+    pub status: i32,
+    pub updated_by: String,
+    pub occurred_at: DateTime<Utc>,
+}
+
+impl TenantUpdatedEvent {
+    pub fn new(tenant_id: &str, name: &str, status: i32, updated_by: &str) -> Self {
+        Self {
+            tenant_id: tenant_id.to_string(),
+            name: name.to_string(),
+            status,
+            updated_by: updated_by.to_string(),
+            occurred_at: Utc::now(),
+        }
+    }
+}
+
+#[async_trait]
+impl Event for TenantUpdatedEvent {
+    fn event_type(&self) -> &'static str {
+        "tenant.updated"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
@@ -0,0 +1,35 @@
+#[rustfmt::skip]
+use crate::{
+    commands::access_request::*,
+    queries::access_request::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::{access_request::AccessRequest, user::User};
+use tradewinds_error::AppResult;
+
+/// 权限申请服务接口
+///
+/// 定义了角色权限申请与审批流程的基本操作。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `submit_request`: 提交权限申请
+/// - `approve_request`: 批准权限申请并为申请人分配角色
+/// - `reject_request`: 驳回权限申请
+/// - `cancel_request`: 申请人撤回待审批的申请
+/// - `expire_requests`: 处理到期申请，返回过期的申请数
+/// - `get_request_by_id`: 根据ID获取权限申请
+/// - `list_requests`: 分页列出权限申请
+/// - `get_role_approvers`: 获取角色审批人
+/// - `set_role_approvers`: 设置角色审批人
+#[async_trait::async_trait]
+pub trait IAccessRequestService: Send + Sync {
+    async fn submit_request(&self, cmd: SubmitAccessRequestCommand) -> AppResult<AccessRequest>;
+    async fn approve_request(&self, cmd: ApproveAccessRequestCommand) -> AppResult<()>;
+    async fn reject_request(&self, cmd: RejectAccessRequestCommand) -> AppResult<()>;
+    async fn cancel_request(&self, cmd: CancelAccessRequestCommand) -> AppResult<()>;
+    async fn expire_requests(&self, cmd: ExpireAccessRequestsCommand) -> AppResult<u64>;
+    async fn get_request_by_id(&self, query: GetAccessRequestByIdQuery) -> AppResult<AccessRequest>;
+    async fn list_requests(&self, query: ListAccessRequestsQuery) -> AppResult<PaginatedResult<AccessRequest>>;
+    async fn get_role_approvers(&self, query: GetRoleApproversQuery) -> AppResult<Vec<User>>;
+    async fn set_role_approvers(&self, cmd: SetRoleApproversCommand) -> AppResult<()>;
+}
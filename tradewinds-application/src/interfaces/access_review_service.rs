@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    commands::access_review::*,
+    queries::access_review::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_domain::entities::access_review::AccessReviewItem;
+use tradewinds_error::AppResult;
+
+/// 访问复核服务接口
+///
+/// 定义了特权角色定期复核（权限再认证）的基本操作。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `launch_review`: 发起复核活动，为范围内角色的每个直接持有人生成复核项
+/// - `decide_item`: 复核人确认或收回复核项
+/// - `close_due_reviews`: 处理到期活动，自动收回未决项，返回完成的活动数
+/// - `get_review_by_id`: 根据ID获取复核活动
+/// - `list_reviews`: 分页列出复核活动
+/// - `list_items`: 列出活动的复核项
+/// - `get_report`: 生成复核报告
+#[async_trait::async_trait]
+pub trait IAccessReviewService: Send + Sync {
+    async fn launch_review(&self, cmd: LaunchAccessReviewCommand) -> AppResult<AccessReviewAggregate>;
+    async fn decide_item(&self, cmd: DecideAccessReviewItemCommand) -> AppResult<()>;
+    async fn close_due_reviews(&self, cmd: CloseDueAccessReviewsCommand) -> AppResult<u64>;
+    async fn get_review_by_id(&self, query: GetAccessReviewByIdQuery) -> AppResult<AccessReviewAggregate>;
+    async fn list_reviews(&self, query: ListAccessReviewsQuery) -> AppResult<PaginatedResult<AccessReviewAggregate>>;
+    async fn list_items(&self, query: ListAccessReviewItemsQuery) -> AppResult<Vec<AccessReviewItem>>;
+    async fn get_report(&self, query: GetAccessReviewReportQuery) -> AppResult<AccessReviewReport>;
+}
@@ -0,0 +1,16 @@
+use crate::queries::audit_log::{AuditChainReport, VerifyAuditChainQuery};
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_error::AppResult;
+
+/// 审计哈希链服务接口
+///
+/// 定义了审计日志防篡改链的基本操作。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `append`: 将审计日志追加到当前租户的哈希链，按间隔生成签名检查点
+/// - `verify_chain`: 逐条校验哈希链与检查点，报告每个租户的第一处断点
+#[async_trait::async_trait]
+pub trait IAuditChainService: Send + Sync {
+    async fn append(&self, log: AuditLog) -> AppResult<AuditLog>;
+    async fn verify_chain(&self, query: VerifyAuditChainQuery) -> AppResult<Vec<AuditChainReport>>;
+}
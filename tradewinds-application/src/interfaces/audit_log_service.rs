@@ -0,0 +1,27 @@
+use serde_json::Value;
+
+#[rustfmt::skip]
+use crate::{
+    audit::AuditTarget,
+    queries::audit_log::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_error::AppResult;
+
+/// 审计日志服务接口
+///
+/// 定义了审计日志的记录与查询操作。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `snapshot`: 获取审计对象当前状态的快照（已脱敏），对象不存在时返回 `None`
+/// - `record`: 写入审计日志，补全操作人用户名
+/// - `list_logs`: 分页查询审计日志
+/// - `export_logs`: 导出符合条件的审计日志
+#[async_trait::async_trait]
+pub trait IAuditLogService: Send + Sync {
+    async fn snapshot(&self, target: &AuditTarget) -> AppResult<Option<Value>>;
+    async fn record(&self, log: AuditLog) -> AppResult<()>;
+    async fn list_logs(&self, query: ListAuditLogsQuery) -> AppResult<PaginatedResult<AuditLog>>;
+    async fn export_logs(&self, query: ExportAuditLogsQuery) -> AppResult<Vec<AuditLog>>;
+}
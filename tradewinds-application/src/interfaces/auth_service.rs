@@ -3,16 +3,17 @@ use crate::{
     commands::*,
     queries::*,
 };
-use crate::queries::auth::user_info::CurrentUserInfo;
+use crate::queries::auth::{dashboard_stats::DashboardStats, user_info::CurrentUserInfo};
+use tradewinds_common::PaginatedResult;
 use tradewinds_domain::{
-    entities::{permission::Permission, role::Role, user::User},
+    entities::{login_log::LoginLog, permission::Permission, role::Role, user::User},
     value_objects::auth::{auth_token::Token, auth_username::AuthUsername},
 };
 use tradewinds_error::AppResult;
 
 /// 认证服务接口
 ///
-/// 定义了认证服务的基本操作，包括用户注册、登录、修改密码、登出和获取当前用户。
+/// 定义了认证服务的基本操作，包括用户注册、登录、修改密码、登出、获取当前用户及其登录历史。
 /// 这些操作通过命令和查询来实现。
 ///
 /// 实现此接口的类型必须实现以下方法：
@@ -21,6 +22,8 @@ use tradewinds_error::AppResult;
 /// - `change_password`: 修改密码
 /// - `logout`: 登出用户
 /// - `get_current_user`: 获取当前用户
+/// - `get_login_history`: 获取当前用户的登录历史
+/// - `get_dashboard_stats`: 获取当前租户的仪表盘统计
 #[async_trait::async_trait]
 pub trait IAuthService: Send + Sync {
     async fn register(&self, cmd: RegisterCommand) -> AppResult<()>;
@@ -28,4 +31,6 @@ pub trait IAuthService: Send + Sync {
     async fn change_password(&self, cmd: ChangePasswordCommand) -> AppResult<()>;
     async fn logout(&self, cmd: LogoutCommand) -> AppResult<()>;
     async fn get_current_user(&self, query: GetCurrentUserQuery) -> AppResult<CurrentUserInfo>;
+    async fn get_login_history(&self, query: GetLoginHistoryQuery) -> AppResult<PaginatedResult<LoginLog>>;
+    async fn get_dashboard_stats(&self, query: GetDashboardStatsQuery) -> AppResult<DashboardStats>;
 }
@@ -0,0 +1,30 @@
+#[rustfmt::skip]
+use crate::{
+    commands::department::*,
+    queries::department::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_error::AppResult;
+
+/// 部门服务接口
+///
+/// 定义了部门服务的基本操作，包括创建、更新、删除、获取和列出部门。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `create_department`: 创建部门
+/// - `update_department`: 更新部门（含调整上级部门）
+/// - `delete_department`: 删除部门
+/// - `get_department_by_id`: 根据ID获取部门
+/// - `list_departments`: 分页列出部门
+/// - `list_all_departments`: 列出全部部门（用于构建部门树）
+#[async_trait::async_trait]
+pub trait IDepartmentService: Send + Sync {
+    async fn create_department(&self, cmd: CreateDepartmentCommand) -> AppResult<Department>;
+    async fn update_department(&self, cmd: UpdateDepartmentCommand) -> AppResult<()>;
+    async fn delete_department(&self, cmd: DeleteDepartmentCommand) -> AppResult<()>;
+    async fn get_department_by_id(&self, query: GetDepartmentByIdQuery) -> AppResult<Department>;
+    async fn list_departments(&self, query: ListDepartmentsQuery) -> AppResult<PaginatedResult<Department>>;
+    /// 查询所有未删除的部门（不分页）
+    async fn list_all_departments(&self) -> AppResult<Vec<Department>>;
+}
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    commands::feature_flag::*,
+    queries::feature_flag::*,
+};
+use std::collections::BTreeMap;
+use tradewinds_domain::entities::feature_flag::{FeatureFlag, FlagPrincipal};
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_error::AppResult;
+
+/// 功能开关服务接口
+///
+/// 定义了功能开关的管理与判定操作。开关为平台级数据，仅平台默认租户内可管理，判定在任意租户内进行。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `create_flag`: 创建开关
+/// - `update_flag`: 更新开关状态、灰度比例与白名单
+/// - `delete_flag`: 删除开关
+/// - `list_flags`: 查询全部开关
+/// - `principal`: 在当前租户内构造用户的判定对象
+/// - `is_enabled`: 判定开关对给定用户是否开启，未定义的开关视为关闭
+/// - `user_flags`: 判定全部开关对给定用户是否开启
+#[async_trait::async_trait]
+pub trait IFeatureFlagService: Send + Sync {
+    async fn create_flag(&self, cmd: CreateFeatureFlagCommand) -> AppResult<FeatureFlag>;
+    async fn update_flag(&self, cmd: UpdateFeatureFlagCommand) -> AppResult<FeatureFlag>;
+    async fn delete_flag(&self, cmd: DeleteFeatureFlagCommand) -> AppResult<()>;
+    async fn list_flags(&self, query: ListFeatureFlagsQuery) -> AppResult<Vec<FeatureFlag>>;
+    async fn principal(&self, user_id: &UserId) -> AppResult<FlagPrincipal>;
+    async fn is_enabled(&self, flag: &str, principal: &FlagPrincipal) -> AppResult<bool>;
+    async fn user_flags(&self, query: GetUserFeatureFlagsQuery) -> AppResult<BTreeMap<String, bool>>;
+}
@@ -0,0 +1,35 @@
+#[rustfmt::skip]
+use crate::{
+    commands::group::*,
+    queries::group::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_domain::entities::{group::Group, user::User};
+use tradewinds_error::AppResult;
+
+/// 用户组服务接口
+///
+/// 定义了用户组服务的基本操作，包括用户组的增删改查与成员管理。
+/// 用户组携带的角色会传递给组内全部成员。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `create_group`: 创建用户组
+/// - `update_group`: 更新用户组（含整体替换角色）
+/// - `delete_group`: 删除用户组
+/// - `add_group_members`: 添加用户组成员
+/// - `remove_group_member`: 移除用户组成员
+/// - `get_group_by_id`: 根据ID获取用户组（含成员与角色）
+/// - `list_groups`: 分页列出用户组
+/// - `list_group_members`: 列出用户组成员
+#[async_trait::async_trait]
+pub trait IGroupService: Send + Sync {
+    async fn create_group(&self, cmd: CreateGroupCommand) -> AppResult<Group>;
+    async fn update_group(&self, cmd: UpdateGroupCommand) -> AppResult<()>;
+    async fn delete_group(&self, cmd: DeleteGroupCommand) -> AppResult<()>;
+    async fn add_group_members(&self, cmd: AddGroupMembersCommand) -> AppResult<()>;
+    async fn remove_group_member(&self, cmd: RemoveGroupMemberCommand) -> AppResult<()>;
+    async fn get_group_by_id(&self, query: GetGroupByIdQuery) -> AppResult<GroupAggregate>;
+    async fn list_groups(&self, query: ListGroupsQuery) -> AppResult<PaginatedResult<Group>>;
+    async fn list_group_members(&self, query: ListGroupMembersQuery) -> AppResult<Vec<User>>;
+}
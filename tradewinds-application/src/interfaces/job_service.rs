@@ -0,0 +1,29 @@
+#[rustfmt::skip]
+use crate::{
+    commands::job::*,
+    queries::job::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_domain::entities::scheduled_job::ScheduledJob;
+use tradewinds_error::AppResult;
+
+/// 定时任务服务接口
+///
+/// 定义了定时任务的运维操作，任务由调度器登记并按 cron 表达式执行。
+/// 定时任务跨租户执行，仅平台默认租户内可管理。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `list_jobs`: 查询全部任务
+/// - `list_runs`: 分页查询执行记录
+/// - `trigger_job`: 立即执行任务
+/// - `pause_job`: 暂停按计划执行
+/// - `resume_job`: 恢复按计划执行
+#[async_trait::async_trait]
+pub trait IJobService: Send + Sync {
+    async fn list_jobs(&self, query: ListJobsQuery) -> AppResult<Vec<ScheduledJob>>;
+    async fn list_runs(&self, query: ListJobRunsQuery) -> AppResult<PaginatedResult<JobRun>>;
+    async fn trigger_job(&self, cmd: TriggerJobCommand) -> AppResult<JobRun>;
+    async fn pause_job(&self, cmd: PauseJobCommand) -> AppResult<()>;
+    async fn resume_job(&self, cmd: ResumeJobCommand) -> AppResult<()>;
+}
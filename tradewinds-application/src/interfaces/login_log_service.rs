@@ -0,0 +1,21 @@
+#[rustfmt::skip]
+use crate::{
+    commands::login_log::*,
+    queries::login_log::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::login_log::LoginLog;
+use tradewinds_error::AppResult;
+
+/// 登录日志服务接口
+///
+/// 定义了登录日志的查询与清理操作，登录日志由认证服务在登录时写入。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `list_logs`: 分页查询登录日志
+/// - `purge_expired`: 删除超过保留天数的登录日志，返回删除条数
+#[async_trait::async_trait]
+pub trait ILoginLogService: Send + Sync {
+    async fn list_logs(&self, query: ListLoginLogsQuery) -> AppResult<PaginatedResult<LoginLog>>;
+    async fn purge_expired(&self, cmd: PurgeLoginLogsCommand) -> AppResult<u64>;
+}
@@ -1,18 +1,60 @@
 /// 应用层接口
 ///
-/// 认证服务接口: 定义了认证服务的基本操作，包括用户注册、登录、修改密码、登出和获取当前用户。
+/// 权限申请服务接口: 定义了角色权限申请与审批流程的基本操作，包括提交、批准、驳回、撤回申请及维护角色审批人。
+/// 访问复核服务接口: 定义了特权角色定期复核的基本操作，包括发起复核活动、逐项确认或收回、到期自动收回及导出报告。
+/// 审计哈希链服务接口: 定义了审计日志防篡改哈希链的基本操作，包括追加记录、生成签名检查点及校验哈希链。
+/// 审计日志服务接口: 定义了审计日志的基本操作，包括对操作对象取快照、写入日志及分页查询和导出日志。
+/// 认证服务接口: 定义了认证服务的基本操作，包括用户注册、登录、修改密码、登出、获取当前用户及其登录历史。
+/// 定时任务服务接口: 定义了定时任务的运维操作，包括查询任务与执行记录、手动触发、暂停及恢复任务。
+/// 登录日志服务接口: 定义了登录日志的基本操作，包括分页查询和按保留天数清理登录日志。
+/// 发件箱服务接口: 定义了发件箱的运维操作，包括查询滞留消息、查看投递统计及重新投递消息。
 /// 用户服务接口: 定义了用户服务的基本操作，包括创建、更新、删除、分配角色和撤销角色。
 /// 角色服务接口: 定义了角色服务的基本操作，包括创建、更新、删除、分配权限和撤销权限。
 /// 权限服务接口: 定义了权限服务的基本操作，包括创建、更新、删除、获取和列出权限。
+/// 部门服务接口: 定义了部门服务的基本操作，包括创建、更新、删除、获取和列出部门。
+/// 功能开关服务接口: 定义了功能开关的管理与判定操作，包括创建、更新、删除、查询开关及按用户判定开关状态。
+/// 用户组服务接口: 定义了用户组服务的基本操作，包括创建、更新、删除用户组及管理组成员。
+/// 访问策略服务接口: 定义了访问策略（ABAC）的基本操作，包括创建、更新、删除、查询策略及试运行访问判定。
+/// 租户服务接口: 定义了平台级租户管理的基本操作，包括创建、更新、查询租户及解析请求所属租户。
+/// 通知服务接口: 定义了向用户发送站外通知的操作。
 /// 系统设置服务接口: 定义了系统设置服务的基本操作，包括获取和设置系统设置。
+/// Webhook 服务接口: 定义了 Webhook 端点的管理操作，包括创建、更新、删除端点，查询投递记录及重新投递。
+pub mod access_request_service;
+pub mod access_review_service;
+pub mod audit_chain_service;
+pub mod audit_log_service;
 pub mod auth_service;
+pub mod department_service;
+pub mod feature_flag_service;
+pub mod group_service;
+pub mod job_service;
+pub mod login_log_service;
+pub mod notification_service;
+pub mod outbox_service;
 pub mod permission_service;
+pub mod policy_service;
 pub mod role_service;
+pub mod tenant_service;
 pub mod user_service;
+pub mod webhook_service;
 pub mod system_setting_service;
 
+pub use access_request_service::IAccessRequestService;
+pub use access_review_service::IAccessReviewService;
+pub use audit_chain_service::IAuditChainService;
+pub use audit_log_service::IAuditLogService;
 pub use auth_service::IAuthService;
+pub use department_service::IDepartmentService;
+pub use feature_flag_service::IFeatureFlagService;
+pub use group_service::IGroupService;
+pub use job_service::IJobService;
+pub use login_log_service::ILoginLogService;
+pub use notification_service::INotificationService;
+pub use outbox_service::IOutboxService;
 pub use permission_service::IPermissionService;
+pub use policy_service::IPolicyService;
 pub use role_service::IRoleService;
+pub use tenant_service::ITenantService;
 pub use user_service::IUserService;
+pub use webhook_service::IWebhookService;
 pub use system_setting_service::ISystemSettingService;
\ No newline at end of file
@@ -0,0 +1,13 @@
+use tradewinds_domain::entities::user::User;
+use tradewinds_error::AppResult;
+
+/// 通知服务接口
+///
+/// 向用户发送站外通知（如邮件），具体渠道由基础设施层决定。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `notify`: 向用户发送一条通知
+#[async_trait::async_trait]
+pub trait INotificationService: Send + Sync {
+    async fn notify(&self, recipient: &User, subject: &str, body: &str) -> AppResult<()>;
+}
@@ -0,0 +1,23 @@
+#[rustfmt::skip]
+use crate::{
+    commands::outbox::*,
+    queries::outbox::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_error::AppResult;
+
+/// 发件箱服务接口
+///
+/// 定义了发件箱的运维操作，消息由聚合仓储写入、由中继任务投递。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `list_messages`: 分页查询发件箱消息，可只查询滞留消息
+/// - `get_stats`: 各状态消息数量与中继指标
+/// - `retry_message`: 将死信或等待重试的消息立即放回待投递队列
+#[async_trait::async_trait]
+pub trait IOutboxService: Send + Sync {
+    async fn list_messages(&self, query: ListOutboxMessagesQuery) -> AppResult<PaginatedResult<OutboxMessage>>;
+    async fn get_stats(&self, query: GetOutboxStatsQuery) -> AppResult<OutboxStats>;
+    async fn retry_message(&self, cmd: RetryOutboxMessageCommand) -> AppResult<()>;
+}
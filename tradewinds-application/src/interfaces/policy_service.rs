@@ -0,0 +1,30 @@
+#[rustfmt::skip]
+use crate::{
+    commands::policy::*,
+    queries::policy::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::policies::AccessEvaluation;
+use tradewinds_error::AppResult;
+
+/// 访问策略服务接口
+///
+/// 定义了租户内访问策略（ABAC）的管理操作及试运行访问判定。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `create_policy`: 创建访问策略
+/// - `update_policy`: 更新访问策略（含启用、禁用）
+/// - `delete_policy`: 删除访问策略
+/// - `get_policy_by_id`: 根据ID获取访问策略
+/// - `list_policies`: 分页列出访问策略
+/// - `evaluate`: 试运行访问判定，返回 RBAC 与 ABAC 的判定明细
+#[async_trait::async_trait]
+pub trait IPolicyService: Send + Sync {
+    async fn create_policy(&self, cmd: CreateAccessPolicyCommand) -> AppResult<AccessPolicy>;
+    async fn update_policy(&self, cmd: UpdateAccessPolicyCommand) -> AppResult<()>;
+    async fn delete_policy(&self, cmd: DeleteAccessPolicyCommand) -> AppResult<()>;
+    async fn get_policy_by_id(&self, query: GetAccessPolicyByIdQuery) -> AppResult<AccessPolicy>;
+    async fn list_policies(&self, query: ListAccessPoliciesQuery) -> AppResult<PaginatedResult<AccessPolicy>>;
+    async fn evaluate(&self, query: EvaluateAccessPolicyQuery) -> AppResult<AccessEvaluation>;
+}
@@ -1,12 +1,19 @@
 use crate::{
-    queries::system_setting::get_system_setting_query::GetSystemSettingQuery,
+    queries::system_setting::{GetSystemModeQuery, GetSystemSettingQuery, ListSystemSettingsQuery},
     commands::system_setting::set_system_setting_command::SetSystemSettingCommand,
 };
-use tradewinds_domain::entities::system_setting::SystemSetting;
+use tradewinds_domain::entities::system_setting::EffectiveSetting;
+use tradewinds_domain::value_objects::system_setting::SystemMode;
 use tradewinds_error::AppResult;
 
+/// 系统设置服务接口
+///
+/// 只能读写登记表中声明的设置，写入值按声明的类型与规则校验后规范化保存。
+/// 平台级设置只能在平台默认租户内修改。`system_mode` 在每个实例内短暂缓存，供每个请求读取。
 #[async_trait::async_trait]
 pub trait ISystemSettingService: Send + Sync {
-    async fn get_by_key(&self, query: GetSystemSettingQuery) -> AppResult<Option<SystemSetting>>;
+    async fn get_by_key(&self, query: GetSystemSettingQuery) -> AppResult<EffectiveSetting>;
+    async fn list(&self, query: ListSystemSettingsQuery) -> AppResult<Vec<EffectiveSetting>>;
     async fn set_value(&self, cmd: SetSystemSettingCommand) -> AppResult<()>;
+    async fn system_mode(&self, query: GetSystemModeQuery) -> AppResult<SystemMode>;
 }
@@ -0,0 +1,27 @@
+#[rustfmt::skip]
+use crate::{
+    commands::tenant::*,
+    queries::tenant::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_error::AppResult;
+
+/// 租户服务接口
+///
+/// 定义了平台级租户管理的基本操作；除租户解析外，均须由平台默认租户内的超级管理员调用。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `create_tenant`: 创建租户并初始化其超级管理员角色与管理员账号
+/// - `update_tenant`: 更新租户（名称、域名、状态）
+/// - `get_tenant_by_id`: 根据ID获取租户
+/// - `list_tenants`: 分页列出租户
+/// - `resolve_tenant`: 解析请求所属的启用租户
+#[async_trait::async_trait]
+pub trait ITenantService: Send + Sync {
+    async fn create_tenant(&self, cmd: CreateTenantCommand) -> AppResult<Tenant>;
+    async fn update_tenant(&self, cmd: UpdateTenantCommand) -> AppResult<()>;
+    async fn get_tenant_by_id(&self, query: GetTenantByIdQuery) -> AppResult<Tenant>;
+    async fn list_tenants(&self, query: ListTenantsQuery) -> AppResult<PaginatedResult<Tenant>>;
+    async fn resolve_tenant(&self, query: ResolveTenantQuery) -> AppResult<Tenant>;
+}
@@ -1,6 +1,8 @@
 use async_trait::async_trait;
 use tradewinds_common::PaginatedResult;
 use tradewinds_domain::entities::{permission::Permission, role::Role, user::User};
+use tradewinds_domain::policies::AccessExplanation;
+use tradewinds_domain::value_objects::RoleSource;
 use tradewinds_error::AppResult;
 
 use crate::commands::user::*;
@@ -24,8 +26,9 @@ use crate::queries::user::*;
 /// - `assign_role`: 为用户分配角色
 /// - `revoke_role`: 撤销用户角色
 /// - `get_user_by_id/username/email`: 根据不同条件查询用户
-/// - `get_user_roles`: 获取用户的角色列表
+/// - `get_user_roles`: 获取用户的有效角色列表及每个角色的来源
 /// - `get_user_permissions`: 获取用户的权限列表
+/// - `explain_user_access`: 说明用户为何拥有或缺少某个权限
 /// - `list_users`: 列出所有用户
 #[async_trait]
 pub trait IUserService: Send + Sync {
@@ -57,11 +60,14 @@ pub trait IUserService: Send + Sync {
     async fn get_user_by_email(&self, query: GetUserByEmailQuery) -> AppResult<User>;
 
     /// 获取用户的角色列表
-    async fn get_user_roles(&self, query: GetUserRolesQuery) -> AppResult<Vec<Role>>;
+    async fn get_user_roles(&self, query: GetUserRolesQuery) -> AppResult<Vec<(Role, Vec<RoleSource>)>>;
 
     /// 获取用户的权限列表
     async fn get_user_permissions(&self, query: GetUserPermissionsQuery) -> AppResult<Vec<Permission>>;
 
+    /// 说明用户对某个权限码的访问判定及证据链
+    async fn explain_user_access(&self, query: ExplainUserAccessQuery) -> AppResult<AccessExplanation>;
+
     /// 分页获取用户列表（包含角色信息）
     /// 返回：(用户信息, 角色列表) 的元组集合
     async fn list_users(&self, query: ListUsersQuery) -> AppResult<PaginatedResult<(User, Vec<Role>)>>;
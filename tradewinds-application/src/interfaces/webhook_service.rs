@@ -0,0 +1,30 @@
+#[rustfmt::skip]
+use crate::{
+    commands::webhook::*,
+    queries::webhook::*,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::webhook::Webhook;
+use tradewinds_domain::entities::webhook_delivery::WebhookDelivery;
+use tradewinds_error::AppResult;
+
+/// Webhook 服务接口
+///
+/// 定义了 Webhook 端点的管理操作，投递记录由事件订阅者写入、由投递任务发送。
+///
+/// 实现此接口的类型必须实现以下方法：
+/// - `create_webhook`: 创建端点，未指定密钥时自动生成
+/// - `update_webhook`: 更新端点，可停用或重新启用
+/// - `delete_webhook`: 删除端点，未完成的投递不再发送
+/// - `list_webhooks`: 查询当前租户的全部端点
+/// - `list_deliveries`: 分页查询投递记录
+/// - `redeliver`: 以原请求体重新投递
+#[async_trait::async_trait]
+pub trait IWebhookService: Send + Sync {
+    async fn create_webhook(&self, cmd: CreateWebhookCommand) -> AppResult<Webhook>;
+    async fn update_webhook(&self, cmd: UpdateWebhookCommand) -> AppResult<()>;
+    async fn delete_webhook(&self, cmd: DeleteWebhookCommand) -> AppResult<()>;
+    async fn list_webhooks(&self, query: ListWebhooksQuery) -> AppResult<Vec<Webhook>>;
+    async fn list_deliveries(&self, query: ListWebhookDeliveriesQuery) -> AppResult<PaginatedResult<WebhookDelivery>>;
+    async fn redeliver(&self, cmd: RedeliverWebhookDeliveryCommand) -> AppResult<WebhookDelivery>;
+}
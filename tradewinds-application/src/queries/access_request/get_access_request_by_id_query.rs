@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_request::AccessRequestId,
+    user::UserId,
+};
+
+/// 根据申请ID查询权限申请
+///
+/// 参数：
+/// - actor_id: 当前用户ID
+/// - id: 申请ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccessRequestByIdQuery {
+    pub actor_id: UserId,
+    pub id: AccessRequestId,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::role::RoleId;
+
+/// 查询角色审批人
+///
+/// 参数：
+/// - role_id: 角色ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetRoleApproversQuery {
+    pub role_id: RoleId,
+}
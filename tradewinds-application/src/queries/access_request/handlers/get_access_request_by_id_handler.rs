@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    queries::access_request::get_access_request_by_id_query::GetAccessRequestByIdQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::access_request::AccessRequest;
+use tradewinds_error::AppResult;
+
+/// 根据申请ID查询权限申请查询处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 根据申请ID查询权限申请查询处理器
+pub struct GetAccessRequestByIdHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl GetAccessRequestByIdHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetAccessRequestByIdQuery, AccessRequest> for GetAccessRequestByIdHandler {
+    async fn handle(&self, query: GetAccessRequestByIdQuery) -> AppResult<AccessRequest> {
+        self.access_request_service.get_request_by_id(query).await
+    }
+}
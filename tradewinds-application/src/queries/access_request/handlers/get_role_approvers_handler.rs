@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    queries::access_request::get_role_approvers_query::GetRoleApproversQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::user::User;
+use tradewinds_error::AppResult;
+
+/// 查询角色审批人查询处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 查询角色审批人查询处理器
+pub struct GetRoleApproversHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl GetRoleApproversHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetRoleApproversQuery, Vec<User>> for GetRoleApproversHandler {
+    async fn handle(&self, query: GetRoleApproversQuery) -> AppResult<Vec<User>> {
+        self.access_request_service.get_role_approvers(query).await
+    }
+}
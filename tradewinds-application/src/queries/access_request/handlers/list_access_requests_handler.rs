@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_request_service::IAccessRequestService,
+    queries::access_request::list_access_requests_query::ListAccessRequestsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::access_request::AccessRequest;
+use tradewinds_error::AppResult;
+
+/// 查询权限申请列表查询处理器
+///
+/// 参数：
+/// - access_request_service: 权限申请服务
+///
+/// 返回：
+/// - 查询权限申请列表查询处理器
+pub struct ListAccessRequestsHandler {
+    access_request_service: Arc<dyn IAccessRequestService>,
+}
+
+impl ListAccessRequestsHandler {
+    pub fn new(access_request_service: Arc<dyn IAccessRequestService>) -> Self {
+        Self { access_request_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListAccessRequestsQuery, PaginatedResult<AccessRequest>> for ListAccessRequestsHandler {
+    async fn handle(&self, query: ListAccessRequestsQuery) -> AppResult<PaginatedResult<AccessRequest>> {
+        self.access_request_service.list_requests(query).await
+    }
+}
@@ -0,0 +1,7 @@
+pub mod get_access_request_by_id_handler;
+pub mod get_role_approvers_handler;
+pub mod list_access_requests_handler;
+
+pub use get_access_request_by_id_handler::GetAccessRequestByIdHandler;
+pub use get_role_approvers_handler::GetRoleApproversHandler;
+pub use list_access_requests_handler::ListAccessRequestsHandler;
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_request::AccessRequestStatus,
+    user::UserId,
+};
+use tradewinds_error::AppError;
+
+/// 权限申请列表范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum AccessRequestScope {
+    /// 当前用户提交的申请
+    #[default]
+    Mine,
+    /// 当前用户担任审批人的角色收到的申请
+    Approvals,
+    /// 租户内全部申请
+    All,
+}
+
+impl FromStr for AccessRequestScope {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mine" => Ok(AccessRequestScope::Mine),
+            "approvals" => Ok(AccessRequestScope::Approvals),
+            "all" => Ok(AccessRequestScope::All),
+            _ => Err(AppError::Validation(format!("Invalid access request scope: {}", s))),
+        }
+    }
+}
+
+/// 查询权限申请列表查询
+///
+/// 参数：
+/// - actor_id: 当前用户ID
+/// - scope: 列表范围
+/// - status: 申请状态
+/// - page: 页码
+/// - page_size: 每页条数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAccessRequestsQuery {
+    pub actor_id: UserId,
+    pub scope: AccessRequestScope,
+    pub status: Option<AccessRequestStatus>,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+impl ListAccessRequestsQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
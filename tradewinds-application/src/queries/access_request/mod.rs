@@ -0,0 +1,10 @@
+pub mod get_access_request_by_id_query;
+pub mod get_role_approvers_query;
+pub mod handlers;
+pub mod list_access_requests_query;
+
+pub use get_access_request_by_id_query::GetAccessRequestByIdQuery;
+pub use get_role_approvers_query::GetRoleApproversQuery;
+pub use list_access_requests_query::{AccessRequestScope, ListAccessRequestsQuery};
+
+pub use handlers::*;
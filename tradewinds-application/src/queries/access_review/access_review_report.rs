@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use tradewinds_domain::entities::access_review::AccessReviewCampaign;
+use tradewinds_domain::value_objects::access_review::ReviewDecision;
+
+/// 访问复核报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessReviewReport {
+    pub campaign: AccessReviewCampaign,
+    pub rows: Vec<AccessReviewReportRow>,
+}
+
+/// 报告中的一行：一个复核项及相关用户、角色名称
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessReviewReportRow {
+    pub item_id: String,
+    pub user_id: String,
+    pub username: String,
+    pub role_id: String,
+    pub role_name: String,
+    pub reviewer_id: String,
+    pub reviewer_username: String,
+    pub decision: ReviewDecision,
+    pub comment: Option<String>,
+    pub decided_at: Option<i64>,
+}
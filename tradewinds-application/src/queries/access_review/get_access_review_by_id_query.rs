@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::access_review::AccessReviewId;
+
+/// 根据活动ID查询访问复核活动
+///
+/// 参数：
+/// - id: 活动ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccessReviewByIdQuery {
+    pub id: AccessReviewId,
+}
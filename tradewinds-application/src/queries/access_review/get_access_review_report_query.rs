@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::access_review::AccessReviewId;
+
+/// 查询访问复核报告查询
+///
+/// 参数：
+/// - id: 活动ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccessReviewReportQuery {
+    pub id: AccessReviewId,
+}
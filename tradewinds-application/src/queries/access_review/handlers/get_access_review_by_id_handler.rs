@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    queries::access_review::get_access_review_by_id_query::GetAccessReviewByIdQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_error::AppResult;
+
+/// 根据活动ID查询访问复核活动查询处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 根据活动ID查询访问复核活动查询处理器
+pub struct GetAccessReviewByIdHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl GetAccessReviewByIdHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetAccessReviewByIdQuery, AccessReviewAggregate> for GetAccessReviewByIdHandler {
+    async fn handle(&self, query: GetAccessReviewByIdQuery) -> AppResult<AccessReviewAggregate> {
+        self.access_review_service.get_review_by_id(query).await
+    }
+}
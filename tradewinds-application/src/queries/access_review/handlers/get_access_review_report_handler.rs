@@ -0,0 +1,35 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    queries::access_review::{
+        access_review_report::AccessReviewReport,
+        get_access_review_report_query::GetAccessReviewReportQuery,
+    },
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 查询访问复核报告查询处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 查询访问复核报告查询处理器
+pub struct GetAccessReviewReportHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl GetAccessReviewReportHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetAccessReviewReportQuery, AccessReviewReport> for GetAccessReviewReportHandler {
+    async fn handle(&self, query: GetAccessReviewReportQuery) -> AppResult<AccessReviewReport> {
+        self.access_review_service.get_report(query).await
+    }
+}
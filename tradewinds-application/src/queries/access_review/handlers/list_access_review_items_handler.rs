@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    queries::access_review::list_access_review_items_query::ListAccessReviewItemsQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::access_review::AccessReviewItem;
+use tradewinds_error::AppResult;
+
+/// 查询访问复核项查询处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 查询访问复核项查询处理器
+pub struct ListAccessReviewItemsHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl ListAccessReviewItemsHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListAccessReviewItemsQuery, Vec<AccessReviewItem>> for ListAccessReviewItemsHandler {
+    async fn handle(&self, query: ListAccessReviewItemsQuery) -> AppResult<Vec<AccessReviewItem>> {
+        self.access_review_service.list_items(query).await
+    }
+}
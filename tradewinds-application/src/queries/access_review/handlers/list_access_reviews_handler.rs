@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::access_review_service::IAccessReviewService,
+    queries::access_review::list_access_reviews_query::ListAccessReviewsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_error::AppResult;
+
+/// 查询访问复核活动列表查询处理器
+///
+/// 参数：
+/// - access_review_service: 访问复核服务
+///
+/// 返回：
+/// - 查询访问复核活动列表查询处理器
+pub struct ListAccessReviewsHandler {
+    access_review_service: Arc<dyn IAccessReviewService>,
+}
+
+impl ListAccessReviewsHandler {
+    pub fn new(access_review_service: Arc<dyn IAccessReviewService>) -> Self {
+        Self { access_review_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListAccessReviewsQuery, PaginatedResult<AccessReviewAggregate>> for ListAccessReviewsHandler {
+    async fn handle(&self, query: ListAccessReviewsQuery) -> AppResult<PaginatedResult<AccessReviewAggregate>> {
+        self.access_review_service.list_reviews(query).await
+    }
+}
@@ -0,0 +1,9 @@
+pub mod get_access_review_by_id_handler;
+pub mod get_access_review_report_handler;
+pub mod list_access_review_items_handler;
+pub mod list_access_reviews_handler;
+
+pub use get_access_review_by_id_handler::GetAccessReviewByIdHandler;
+pub use get_access_review_report_handler::GetAccessReviewReportHandler;
+pub use list_access_review_items_handler::ListAccessReviewItemsHandler;
+pub use list_access_reviews_handler::ListAccessReviewsHandler;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_review::{
+        AccessReviewId,
+        ReviewDecision,
+    },
+    user::UserId,
+};
+
+/// 查询访问复核项查询
+///
+/// 参数：
+/// - id: 活动ID
+/// - reviewer_id: 仅返回指派给该复核人的复核项
+/// - decision: 复核结论
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAccessReviewItemsQuery {
+    pub id: AccessReviewId,
+    pub reviewer_id: Option<UserId>,
+    pub decision: Option<ReviewDecision>,
+}
@@ -0,0 +1,56 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::value_objects::{
+    access_review::AccessReviewStatus,
+    user::UserId,
+};
+use tradewinds_error::AppError;
+
+/// 访问复核活动列表范围
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum AccessReviewScope {
+    /// 当前用户参与复核的活动
+    #[default]
+    Assigned,
+    /// 租户内全部活动
+    All,
+}
+
+impl FromStr for AccessReviewScope {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "assigned" => Ok(AccessReviewScope::Assigned),
+            "all" => Ok(AccessReviewScope::All),
+            _ => Err(AppError::Validation(format!("Invalid access review scope: {}", s))),
+        }
+    }
+}
+
+/// 查询访问复核活动列表查询
+///
+/// 参数：
+/// - actor_id: 当前用户ID
+/// - scope: 列表范围
+/// - status: 活动状态
+/// - page: 页码
+/// - page_size: 每页条数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAccessReviewsQuery {
+    pub actor_id: UserId,
+    pub scope: AccessReviewScope,
+    pub status: Option<AccessReviewStatus>,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+impl ListAccessReviewsQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
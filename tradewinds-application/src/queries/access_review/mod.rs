@@ -0,0 +1,14 @@
+pub mod access_review_report;
+pub mod get_access_review_by_id_query;
+pub mod get_access_review_report_query;
+pub mod handlers;
+pub mod list_access_review_items_query;
+pub mod list_access_reviews_query;
+
+pub use access_review_report::{AccessReviewReport, AccessReviewReportRow};
+pub use get_access_review_by_id_query::GetAccessReviewByIdQuery;
+pub use get_access_review_report_query::GetAccessReviewReportQuery;
+pub use list_access_review_items_query::ListAccessReviewItemsQuery;
+pub use list_access_reviews_query::{AccessReviewScope, ListAccessReviewsQuery};
+
+pub use handlers::*;
@@ -0,0 +1,14 @@
+use tradewinds_domain::services::audit::{AuditChainBreak, AuditChainSummary};
+
+/// 单个租户的哈希链校验结果：完整时为统计，否则为第一处断点
+#[derive(Debug, Clone)]
+pub struct AuditChainReport {
+    pub tenant_id: String,
+    pub result: Result<AuditChainSummary, AuditChainBreak>,
+}
+
+impl AuditChainReport {
+    pub fn is_intact(&self) -> bool {
+        self.result.is_ok()
+    }
+}
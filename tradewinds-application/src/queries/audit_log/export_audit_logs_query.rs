@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::repositories::AuditLogFilter;
+
+/// 导出审计日志查询
+///
+/// 参数：
+/// - filter: 查询条件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportAuditLogsQuery {
+    pub filter: AuditLogFilter,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::audit_log_service::IAuditLogService,
+    queries::audit_log::export_audit_logs_query::ExportAuditLogsQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_error::AppResult;
+
+/// 导出审计日志查询处理器
+///
+/// 参数：
+/// - audit_log_service: 审计日志服务
+///
+/// 返回：
+/// - 导出审计日志查询处理器
+pub struct ExportAuditLogsHandler {
+    audit_log_service: Arc<dyn IAuditLogService>,
+}
+
+impl ExportAuditLogsHandler {
+    pub fn new(audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self { audit_log_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ExportAuditLogsQuery, Vec<AuditLog>> for ExportAuditLogsHandler {
+    async fn handle(&self, query: ExportAuditLogsQuery) -> AppResult<Vec<AuditLog>> {
+        self.audit_log_service.export_logs(query).await
+    }
+}
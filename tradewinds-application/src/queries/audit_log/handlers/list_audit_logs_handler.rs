@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::audit_log_service::IAuditLogService,
+    queries::audit_log::list_audit_logs_query::ListAuditLogsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_error::AppResult;
+
+/// 查询审计日志列表查询处理器
+///
+/// 参数：
+/// - audit_log_service: 审计日志服务
+///
+/// 返回：
+/// - 查询审计日志列表查询处理器
+pub struct ListAuditLogsHandler {
+    audit_log_service: Arc<dyn IAuditLogService>,
+}
+
+impl ListAuditLogsHandler {
+    pub fn new(audit_log_service: Arc<dyn IAuditLogService>) -> Self {
+        Self { audit_log_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListAuditLogsQuery, PaginatedResult<AuditLog>> for ListAuditLogsHandler {
+    async fn handle(&self, query: ListAuditLogsQuery) -> AppResult<PaginatedResult<AuditLog>> {
+        self.audit_log_service.list_logs(query).await
+    }
+}
@@ -0,0 +1,7 @@
+pub mod export_audit_logs_handler;
+pub mod list_audit_logs_handler;
+pub mod verify_audit_chain_handler;
+
+pub use export_audit_logs_handler::ExportAuditLogsHandler;
+pub use list_audit_logs_handler::ListAuditLogsHandler;
+pub use verify_audit_chain_handler::VerifyAuditChainHandler;
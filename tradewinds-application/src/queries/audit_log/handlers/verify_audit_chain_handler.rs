@@ -0,0 +1,35 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::audit_chain_service::IAuditChainService,
+    queries::audit_log::{
+        audit_chain_report::AuditChainReport,
+        verify_audit_chain_query::VerifyAuditChainQuery,
+    },
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 校验审计日志哈希链查询处理器
+///
+/// 参数：
+/// - audit_chain_service: 审计哈希链服务
+///
+/// 返回：
+/// - 校验审计日志哈希链查询处理器
+pub struct VerifyAuditChainHandler {
+    audit_chain_service: Arc<dyn IAuditChainService>,
+}
+
+impl VerifyAuditChainHandler {
+    pub fn new(audit_chain_service: Arc<dyn IAuditChainService>) -> Self {
+        Self { audit_chain_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<VerifyAuditChainQuery, Vec<AuditChainReport>> for VerifyAuditChainHandler {
+    async fn handle(&self, query: VerifyAuditChainQuery) -> AppResult<Vec<AuditChainReport>> {
+        self.audit_chain_service.verify_chain(query).await
+    }
+}
@@ -0,0 +1,12 @@
+pub mod audit_chain_report;
+pub mod export_audit_logs_query;
+pub mod handlers;
+pub mod list_audit_logs_query;
+pub mod verify_audit_chain_query;
+
+pub use audit_chain_report::AuditChainReport;
+pub use export_audit_logs_query::ExportAuditLogsQuery;
+pub use list_audit_logs_query::ListAuditLogsQuery;
+pub use verify_audit_chain_query::VerifyAuditChainQuery;
+
+pub use handlers::*;
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// 校验审计日志哈希链查询
+///
+/// 参数：
+/// - tenant_id: 要校验的租户，为空时校验全部存在审计日志的租户
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VerifyAuditChainQuery {
+    pub tenant_id: Option<String>,
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// 仪表盘统计
+///
+/// 字段：
+/// - total_users: 未删除用户数
+/// - active_users: 最近 24 小时内登录过的用户数
+/// - total_roles: 未删除角色数
+/// - total_permissions: 未删除权限数
+/// - new_users_today / new_users_this_week / new_users_this_month: 本日、本周（周一起）、本月新增用户数，按 UTC 计
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DashboardStats {
+    pub total_users: u64,
+    pub active_users: u64,
+    pub total_roles: u64,
+    pub total_permissions: u64,
+    pub new_users_today: u64,
+    pub new_users_this_week: u64,
+    pub new_users_this_month: u64,
+}
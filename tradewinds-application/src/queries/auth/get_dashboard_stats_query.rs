@@ -0,0 +1,5 @@
+/// 获取仪表盘统计查询
+///
+/// 统计范围为当前租户
+#[derive(Debug, Clone)]
+pub struct GetDashboardStatsQuery;
@@ -0,0 +1,21 @@
+use tradewinds_domain::value_objects::auth::Token;
+
+/// 获取当前用户登录历史查询
+///
+/// 参数：
+/// - token: 令牌
+/// - page: 页码
+/// - page_size: 每页条数
+#[derive(Debug, Clone)]
+pub struct GetLoginHistoryQuery {
+    pub token: Token,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+impl GetLoginHistoryQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
@@ -0,0 +1,29 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::auth_service::IAuthService,
+    queries::auth::{dashboard_stats::DashboardStats, get_dashboard_stats_query::GetDashboardStatsQuery},
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 获取仪表盘统计查询处理器
+///
+/// 参数：
+/// - auth_service: 认证服务
+pub struct GetDashboardStatsHandler {
+    auth_service: Arc<dyn IAuthService>,
+}
+
+impl GetDashboardStatsHandler {
+    pub fn new(auth_service: Arc<dyn IAuthService>) -> Self {
+        Self { auth_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetDashboardStatsQuery, DashboardStats> for GetDashboardStatsHandler {
+    async fn handle(&self, query: GetDashboardStatsQuery) -> AppResult<DashboardStats> {
+        self.auth_service.get_dashboard_stats(query).await
+    }
+}
@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::auth_service::IAuthService,
+    queries::auth::get_login_history_query::GetLoginHistoryQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::login_log::LoginLog;
+use tradewinds_error::AppResult;
+
+/// 获取当前用户登录历史查询处理器
+///
+/// 参数：
+/// - auth_service: 认证服务
+///
+/// 返回：
+/// - 获取当前用户登录历史查询处理器
+pub struct GetLoginHistoryHandler {
+    auth_service: Arc<dyn IAuthService>,
+}
+
+impl GetLoginHistoryHandler {
+    pub fn new(auth_service: Arc<dyn IAuthService>) -> Self {
+        Self { auth_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetLoginHistoryQuery, PaginatedResult<LoginLog>> for GetLoginHistoryHandler {
+    async fn handle(&self, query: GetLoginHistoryQuery) -> AppResult<PaginatedResult<LoginLog>> {
+        self.auth_service.get_login_history(query).await
+    }
+}
@@ -1,5 +1,9 @@
 pub mod get_current_user_handler;
+pub mod get_dashboard_stats_handler;
+pub mod get_login_history_handler;
 pub mod get_user_menus_handler;
 
 pub use get_current_user_handler::GetCurrentUserHandler;
+pub use get_dashboard_stats_handler::GetDashboardStatsHandler;
+pub use get_login_history_handler::GetLoginHistoryHandler;
 pub use get_user_menus_handler::GetUserMenusHandler;
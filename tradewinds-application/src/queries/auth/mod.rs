@@ -1,14 +1,22 @@
 // 认证相关的查询将在这里实现
 
+pub mod dashboard_stats;
 pub mod get_current_user_query;
+pub mod get_dashboard_stats_query;
+pub mod get_login_history_query;
 pub mod get_user_menus_query;
 pub mod handlers;
 pub mod menu_info;
 pub mod user_info;
 
+pub use dashboard_stats::DashboardStats;
 pub use get_current_user_query::GetCurrentUserQuery;
+pub use get_dashboard_stats_query::GetDashboardStatsQuery;
+pub use get_login_history_query::GetLoginHistoryQuery;
 pub use get_user_menus_query::GetUserMenusQuery;
 pub use handlers::get_current_user_handler::GetCurrentUserHandler;
+pub use handlers::get_dashboard_stats_handler::GetDashboardStatsHandler;
+pub use handlers::get_login_history_handler::GetLoginHistoryHandler;
 pub use handlers::get_user_menus_handler::GetUserMenusHandler;
 pub use handlers::*;
 pub use menu_info::MenuInfo;
@@ -20,8 +20,11 @@ pub struct UserInfo {
     pub phone: Option<String>,
     pub avatar: Option<String>,
     pub status: String,
+    pub department_id: Option<String>,
     pub created_at: i64,
     pub updated_at: i64,
+    pub last_login_at: Option<i64>,
+    pub last_login_ip: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,6 +34,8 @@ pub struct RoleInfo {
     pub name: String,
     pub description: Option<String>,
     pub status: i32,
+    pub data_scope: i32,
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -47,6 +52,7 @@ pub struct PermissionInfo {
     pub icon: Option<String>,
     pub sort: i32,
     pub status: String,
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -61,8 +67,11 @@ impl From<User> for UserInfo {
             phone: user.phone.map(|v| v.to_string()),
             avatar: user.avatar.map(|v| v.to_string()),
             status: user.status.to_string(),
+            department_id: user.department_id.map(|v| v.to_string()),
             created_at: user.created_at,
             updated_at: user.updated_at,
+            last_login_at: user.last_login_at,
+            last_login_ip: user.last_login_ip,
         }
     }
 }
@@ -75,6 +84,8 @@ impl From<Role> for RoleInfo {
             name: role.name.to_string(),
             description: role.description.map(|v| v.to_string()),
             status: role.status.value(),
+            data_scope: role.data_scope.value(),
+            built_in: role.built_in,
             created_at: role.created_at,
             updated_at: role.updated_at,
         }
@@ -94,6 +105,7 @@ impl From<Permission> for PermissionInfo {
             icon: p.icon.map(|v| v.to_string()),
             sort: p.sort.value(),
             status: p.status.to_string(),
+            built_in: p.built_in,
             created_at: p.created_at,
             updated_at: p.updated_at,
         }
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::department::DepartmentId;
+
+/// 根据部门ID查询部门
+///
+/// 参数：
+/// - department_id: 部门ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDepartmentByIdQuery {
+    pub department_id: DepartmentId,
+}
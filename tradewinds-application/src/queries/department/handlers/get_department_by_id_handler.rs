@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::department_service::IDepartmentService,
+    queries::department::get_department_by_id_query::GetDepartmentByIdQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_error::AppResult;
+
+/// 根据部门ID查询部门查询处理器
+///
+/// 参数：
+/// - department_service: 部门服务
+///
+/// 返回：
+/// - 根据部门ID查询部门查询处理器
+pub struct GetDepartmentByIdHandler {
+    department_service: Arc<dyn IDepartmentService>,
+}
+
+impl GetDepartmentByIdHandler {
+    pub fn new(department_service: Arc<dyn IDepartmentService>) -> Self {
+        Self { department_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetDepartmentByIdQuery, Department> for GetDepartmentByIdHandler {
+    async fn handle(&self, query: GetDepartmentByIdQuery) -> AppResult<Department> {
+        self.department_service.get_department_by_id(query).await
+    }
+}
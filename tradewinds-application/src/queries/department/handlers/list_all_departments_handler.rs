@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::department_service::IDepartmentService,
+    queries::department::list_all_departments_query::ListAllDepartmentsQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_error::AppResult;
+
+/// 查询全部部门查询处理器
+///
+/// 参数：
+/// - department_service: 部门服务
+///
+/// 返回：
+/// - 查询全部部门查询处理器
+pub struct ListAllDepartmentsHandler {
+    department_service: Arc<dyn IDepartmentService>,
+}
+
+impl ListAllDepartmentsHandler {
+    pub fn new(department_service: Arc<dyn IDepartmentService>) -> Self {
+        Self { department_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListAllDepartmentsQuery, Vec<Department>> for ListAllDepartmentsHandler {
+    async fn handle(&self, _query: ListAllDepartmentsQuery) -> AppResult<Vec<Department>> {
+        self.department_service.list_all_departments().await
+    }
+}
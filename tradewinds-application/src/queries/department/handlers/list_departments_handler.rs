@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::department_service::IDepartmentService,
+    queries::department::list_departments_query::ListDepartmentsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_error::AppResult;
+
+/// 查询部门列表查询处理器
+///
+/// 参数：
+/// - department_service: 部门服务
+///
+/// 返回：
+/// - 查询部门列表查询处理器
+pub struct ListDepartmentsHandler {
+    department_service: Arc<dyn IDepartmentService>,
+}
+
+impl ListDepartmentsHandler {
+    pub fn new(department_service: Arc<dyn IDepartmentService>) -> Self {
+        Self { department_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListDepartmentsQuery, PaginatedResult<Department>> for ListDepartmentsHandler {
+    async fn handle(&self, query: ListDepartmentsQuery) -> AppResult<PaginatedResult<Department>> {
+        self.department_service.list_departments(query).await
+    }
+}
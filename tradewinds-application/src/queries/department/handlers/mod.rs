@@ -0,0 +1,7 @@
+pub mod get_department_by_id_handler;
+pub mod list_all_departments_handler;
+pub mod list_departments_handler;
+
+pub use get_department_by_id_handler::GetDepartmentByIdHandler;
+pub use list_all_departments_handler::ListAllDepartmentsHandler;
+pub use list_departments_handler::ListDepartmentsHandler;
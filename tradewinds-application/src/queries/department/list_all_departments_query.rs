@@ -0,0 +1,5 @@
+use serde::{Deserialize, Serialize};
+
+/// 查询全部部门（用于构建部门树）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAllDepartmentsQuery;
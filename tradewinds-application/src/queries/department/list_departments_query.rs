@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use tradewinds_domain::value_objects::department::{DepartmentId, DepartmentName, DepartmentStatus};
+
+/// 查询部门列表查询
+///
+/// 参数：
+/// - page: 页码
+/// - page_size: 每页条数
+/// - name: 部门名称（模糊匹配）
+/// - parent_id: 上级部门ID
+/// - status: 部门状态
+/// - show_deleted: 是否包含已删除部门
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListDepartmentsQuery {
+    pub page: u64,
+    pub page_size: u64,
+    pub name: Option<DepartmentName>,
+    pub parent_id: Option<DepartmentId>,
+    pub status: Option<DepartmentStatus>,
+    pub show_deleted: Option<bool>,
+}
+
+impl ListDepartmentsQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
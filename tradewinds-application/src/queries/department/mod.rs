@@ -0,0 +1,10 @@
+pub mod get_department_by_id_query;
+pub mod handlers;
+pub mod list_all_departments_query;
+pub mod list_departments_query;
+
+pub use get_department_by_id_query::GetDepartmentByIdQuery;
+pub use list_all_departments_query::ListAllDepartmentsQuery;
+pub use list_departments_query::ListDepartmentsQuery;
+
+pub use handlers::*;
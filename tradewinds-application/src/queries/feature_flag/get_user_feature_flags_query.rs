@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::user::UserId;
+
+/// 查询用户的功能开关状态查询
+///
+/// 在当前租户范围内判定全部开关对该用户是否开启
+///
+/// 参数：
+/// - user_id: 用户ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetUserFeatureFlagsQuery {
+    pub user_id: UserId,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::feature_flag_service::IFeatureFlagService,
+    queries::feature_flag::get_user_feature_flags_query::GetUserFeatureFlagsQuery,
+};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 查询用户的功能开关状态查询处理器
+///
+/// 参数：
+/// - feature_flag_service: 功能开关服务
+///
+/// 返回：
+/// - 查询用户的功能开关状态查询处理器
+pub struct GetUserFeatureFlagsHandler {
+    feature_flag_service: Arc<dyn IFeatureFlagService>,
+}
+
+impl GetUserFeatureFlagsHandler {
+    pub fn new(feature_flag_service: Arc<dyn IFeatureFlagService>) -> Self {
+        Self { feature_flag_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetUserFeatureFlagsQuery, BTreeMap<String, bool>> for GetUserFeatureFlagsHandler {
+    async fn handle(&self, query: GetUserFeatureFlagsQuery) -> AppResult<BTreeMap<String, bool>> {
+        self.feature_flag_service.user_flags(query).await
+    }
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::feature_flag_service::IFeatureFlagService,
+    queries::feature_flag::list_feature_flags_query::ListFeatureFlagsQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_error::AppResult;
+
+/// 查询功能开关列表查询处理器
+///
+/// 参数：
+/// - feature_flag_service: 功能开关服务
+///
+/// 返回：
+/// - 查询功能开关列表查询处理器
+pub struct ListFeatureFlagsHandler {
+    feature_flag_service: Arc<dyn IFeatureFlagService>,
+}
+
+impl ListFeatureFlagsHandler {
+    pub fn new(feature_flag_service: Arc<dyn IFeatureFlagService>) -> Self {
+        Self { feature_flag_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListFeatureFlagsQuery, Vec<FeatureFlag>> for ListFeatureFlagsHandler {
+    async fn handle(&self, query: ListFeatureFlagsQuery) -> AppResult<Vec<FeatureFlag>> {
+        self.feature_flag_service.list_flags(query).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod get_user_feature_flags_handler;
+pub mod list_feature_flags_handler;
+
+pub use get_user_feature_flags_handler::GetUserFeatureFlagsHandler;
+pub use list_feature_flags_handler::ListFeatureFlagsHandler;
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// 查询功能开关列表查询
+///
+/// 返回全部开关及其灰度比例、白名单
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListFeatureFlagsQuery;
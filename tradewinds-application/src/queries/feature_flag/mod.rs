@@ -0,0 +1,8 @@
+pub mod get_user_feature_flags_query;
+pub mod handlers;
+pub mod list_feature_flags_query;
+
+pub use get_user_feature_flags_query::GetUserFeatureFlagsQuery;
+pub use list_feature_flags_query::ListFeatureFlagsQuery;
+
+pub use handlers::*;
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::group::GroupId;
+
+/// 根据用户组ID查询用户组（含成员与角色）
+///
+/// 参数：
+/// - group_id: 用户组ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetGroupByIdQuery {
+    pub group_id: GroupId,
+}
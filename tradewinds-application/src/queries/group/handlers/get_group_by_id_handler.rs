@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::group_service::IGroupService,
+    queries::group::get_group_by_id_query::GetGroupByIdQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_error::AppResult;
+
+/// 根据用户组ID查询用户组查询处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 根据用户组ID查询用户组查询处理器
+pub struct GetGroupByIdHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl GetGroupByIdHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetGroupByIdQuery, GroupAggregate> for GetGroupByIdHandler {
+    async fn handle(&self, query: GetGroupByIdQuery) -> AppResult<GroupAggregate> {
+        self.group_service.get_group_by_id(query).await
+    }
+}
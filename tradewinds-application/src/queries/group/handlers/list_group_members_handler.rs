@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::group_service::IGroupService,
+    queries::group::list_group_members_query::ListGroupMembersQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::user::User;
+use tradewinds_error::AppResult;
+
+/// 查询用户组成员查询处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 查询用户组成员查询处理器
+pub struct ListGroupMembersHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl ListGroupMembersHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListGroupMembersQuery, Vec<User>> for ListGroupMembersHandler {
+    async fn handle(&self, query: ListGroupMembersQuery) -> AppResult<Vec<User>> {
+        self.group_service.list_group_members(query).await
+    }
+}
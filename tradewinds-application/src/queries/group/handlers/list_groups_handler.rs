@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::group_service::IGroupService,
+    queries::group::list_groups_query::ListGroupsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::group::Group;
+use tradewinds_error::AppResult;
+
+/// 查询用户组列表查询处理器
+///
+/// 参数：
+/// - group_service: 用户组服务
+///
+/// 返回：
+/// - 查询用户组列表查询处理器
+pub struct ListGroupsHandler {
+    group_service: Arc<dyn IGroupService>,
+}
+
+impl ListGroupsHandler {
+    pub fn new(group_service: Arc<dyn IGroupService>) -> Self {
+        Self { group_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListGroupsQuery, PaginatedResult<Group>> for ListGroupsHandler {
+    async fn handle(&self, query: ListGroupsQuery) -> AppResult<PaginatedResult<Group>> {
+        self.group_service.list_groups(query).await
+    }
+}
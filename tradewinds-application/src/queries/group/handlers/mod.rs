@@ -0,0 +1,7 @@
+pub mod get_group_by_id_handler;
+pub mod list_group_members_handler;
+pub mod list_groups_handler;
+
+pub use get_group_by_id_handler::GetGroupByIdHandler;
+pub use list_group_members_handler::ListGroupMembersHandler;
+pub use list_groups_handler::ListGroupsHandler;
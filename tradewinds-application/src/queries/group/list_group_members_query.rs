@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::group::GroupId;
+
+/// 查询用户组成员
+///
+/// 参数：
+/// - group_id: 用户组ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListGroupMembersQuery {
+    pub group_id: GroupId,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use tradewinds_domain::value_objects::group::{GroupName, GroupStatus};
+
+/// 查询用户组列表查询
+///
+/// 参数：
+/// - page: 页码
+/// - page_size: 每页条数
+/// - name: 用户组名称（模糊匹配）
+/// - status: 用户组状态
+/// - show_deleted: 是否包含已删除用户组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListGroupsQuery {
+    pub page: u64,
+    pub page_size: u64,
+    pub name: Option<GroupName>,
+    pub status: Option<GroupStatus>,
+    pub show_deleted: Option<bool>,
+}
+
+impl ListGroupsQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
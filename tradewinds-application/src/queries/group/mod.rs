@@ -0,0 +1,10 @@
+pub mod get_group_by_id_query;
+pub mod handlers;
+pub mod list_group_members_query;
+pub mod list_groups_query;
+
+pub use get_group_by_id_query::GetGroupByIdQuery;
+pub use list_group_members_query::ListGroupMembersQuery;
+pub use list_groups_query::ListGroupsQuery;
+
+pub use handlers::*;
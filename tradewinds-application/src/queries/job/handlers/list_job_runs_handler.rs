@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::job_service::IJobService,
+    queries::job::list_job_runs_query::ListJobRunsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_error::AppResult;
+
+/// 查询定时任务执行记录列表查询处理器
+///
+/// 参数：
+/// - job_service: 定时任务服务
+///
+/// 返回：
+/// - 查询定时任务执行记录列表查询处理器
+pub struct ListJobRunsHandler {
+    job_service: Arc<dyn IJobService>,
+}
+
+impl ListJobRunsHandler {
+    pub fn new(job_service: Arc<dyn IJobService>) -> Self {
+        Self { job_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListJobRunsQuery, PaginatedResult<JobRun>> for ListJobRunsHandler {
+    async fn handle(&self, query: ListJobRunsQuery) -> AppResult<PaginatedResult<JobRun>> {
+        self.job_service.list_runs(query).await
+    }
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::job_service::IJobService,
+    queries::job::list_jobs_query::ListJobsQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::scheduled_job::ScheduledJob;
+use tradewinds_error::AppResult;
+
+/// 查询定时任务列表查询处理器
+///
+/// 参数：
+/// - job_service: 定时任务服务
+///
+/// 返回：
+/// - 查询定时任务列表查询处理器
+pub struct ListJobsHandler {
+    job_service: Arc<dyn IJobService>,
+}
+
+impl ListJobsHandler {
+    pub fn new(job_service: Arc<dyn IJobService>) -> Self {
+        Self { job_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListJobsQuery, Vec<ScheduledJob>> for ListJobsHandler {
+    async fn handle(&self, query: ListJobsQuery) -> AppResult<Vec<ScheduledJob>> {
+        self.job_service.list_jobs(query).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod list_job_runs_handler;
+pub mod list_jobs_handler;
+
+pub use list_job_runs_handler::ListJobRunsHandler;
+pub use list_jobs_handler::ListJobsHandler;
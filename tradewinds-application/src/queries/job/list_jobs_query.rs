@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// 查询定时任务列表查询
+///
+/// 返回全部已登记的任务及其暂停状态、下次执行时间
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListJobsQuery;
@@ -0,0 +1,8 @@
+pub mod handlers;
+pub mod list_job_runs_query;
+pub mod list_jobs_query;
+
+pub use list_job_runs_query::ListJobRunsQuery;
+pub use list_jobs_query::ListJobsQuery;
+
+pub use handlers::*;
@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::login_log_service::ILoginLogService,
+    queries::login_log::list_login_logs_query::ListLoginLogsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::login_log::LoginLog;
+use tradewinds_error::AppResult;
+
+/// 查询登录日志列表查询处理器
+///
+/// 参数：
+/// - login_log_service: 登录日志服务
+///
+/// 返回：
+/// - 查询登录日志列表查询处理器
+pub struct ListLoginLogsHandler {
+    login_log_service: Arc<dyn ILoginLogService>,
+}
+
+impl ListLoginLogsHandler {
+    pub fn new(login_log_service: Arc<dyn ILoginLogService>) -> Self {
+        Self { login_log_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListLoginLogsQuery, PaginatedResult<LoginLog>> for ListLoginLogsHandler {
+    async fn handle(&self, query: ListLoginLogsQuery) -> AppResult<PaginatedResult<LoginLog>> {
+        self.login_log_service.list_logs(query).await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod list_login_logs_handler;
+
+pub use list_login_logs_handler::ListLoginLogsHandler;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::repositories::LoginLogFilter;
+
+/// 查询登录日志列表查询
+///
+/// 参数：
+/// - filter: 查询条件
+/// - page: 页码
+/// - page_size: 每页条数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListLoginLogsQuery {
+    pub filter: LoginLogFilter,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+impl ListLoginLogsQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
@@ -0,0 +1,6 @@
+pub mod handlers;
+pub mod list_login_logs_query;
+
+pub use list_login_logs_query::ListLoginLogsQuery;
+
+pub use handlers::*;
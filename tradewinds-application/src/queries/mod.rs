@@ -1,11 +1,35 @@
+pub mod access_request;
+pub mod access_review;
+pub mod audit_log;
 pub mod auth;
+pub mod department;
+pub mod feature_flag;
+pub mod group;
+pub mod job;
+pub mod login_log;
+pub mod outbox;
 pub mod permission;
+pub mod policy;
 pub mod role;
+pub mod tenant;
 pub mod user;
+pub mod webhook;
 pub mod system_setting;
 
+pub use access_request::*;
+pub use access_review::*;
+pub use audit_log::*;
 pub use auth::*;
+pub use department::*;
+pub use feature_flag::*;
+pub use group::*;
+pub use job::*;
+pub use login_log::*;
+pub use outbox::*;
 pub use permission::*;
+pub use policy::*;
 pub use role::*;
+pub use tenant::*;
 pub use user::*;
+pub use webhook::*;
 pub use system_setting::*;
\ No newline at end of file
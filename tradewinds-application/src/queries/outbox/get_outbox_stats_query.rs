@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// 查询发件箱统计查询
+///
+/// 消息数量按当前租户统计，中继指标为进程级累计值
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetOutboxStatsQuery;
@@ -0,0 +1,32 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::outbox_service::IOutboxService,
+    queries::outbox::{get_outbox_stats_query::GetOutboxStatsQuery, outbox_stats::OutboxStats},
+};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 查询发件箱统计查询处理器
+///
+/// 参数：
+/// - outbox_service: 发件箱服务
+///
+/// 返回：
+/// - 查询发件箱统计查询处理器
+pub struct GetOutboxStatsHandler {
+    outbox_service: Arc<dyn IOutboxService>,
+}
+
+impl GetOutboxStatsHandler {
+    pub fn new(outbox_service: Arc<dyn IOutboxService>) -> Self {
+        Self { outbox_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetOutboxStatsQuery, OutboxStats> for GetOutboxStatsHandler {
+    async fn handle(&self, query: GetOutboxStatsQuery) -> AppResult<OutboxStats> {
+        self.outbox_service.get_stats(query).await
+    }
+}
@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::outbox_service::IOutboxService,
+    queries::outbox::list_outbox_messages_query::ListOutboxMessagesQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_error::AppResult;
+
+/// 查询发件箱消息列表查询处理器
+///
+/// 参数：
+/// - outbox_service: 发件箱服务
+///
+/// 返回：
+/// - 查询发件箱消息列表查询处理器
+pub struct ListOutboxMessagesHandler {
+    outbox_service: Arc<dyn IOutboxService>,
+}
+
+impl ListOutboxMessagesHandler {
+    pub fn new(outbox_service: Arc<dyn IOutboxService>) -> Self {
+        Self { outbox_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListOutboxMessagesQuery, PaginatedResult<OutboxMessage>> for ListOutboxMessagesHandler {
+    async fn handle(&self, query: ListOutboxMessagesQuery) -> AppResult<PaginatedResult<OutboxMessage>> {
+        self.outbox_service.list_messages(query).await
+    }
+}
@@ -0,0 +1,5 @@
+pub mod get_outbox_stats_handler;
+pub mod list_outbox_messages_handler;
+
+pub use get_outbox_stats_handler::GetOutboxStatsHandler;
+pub use list_outbox_messages_handler::ListOutboxMessagesHandler;
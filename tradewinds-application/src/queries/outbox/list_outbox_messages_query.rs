@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::repositories::OutboxFilter;
+
+/// 查询发件箱消息列表查询
+///
+/// 参数：
+/// - filter: 查询条件
+/// - stuck: 是否只查询滞留消息（死信及超过滞留阈值仍未投递的消息）
+/// - page: 页码
+/// - page_size: 每页条数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListOutboxMessagesQuery {
+    pub filter: OutboxFilter,
+    pub stuck: bool,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+impl ListOutboxMessagesQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
@@ -0,0 +1,10 @@
+pub mod get_outbox_stats_query;
+pub mod handlers;
+pub mod list_outbox_messages_query;
+pub mod outbox_stats;
+
+pub use get_outbox_stats_query::GetOutboxStatsQuery;
+pub use list_outbox_messages_query::ListOutboxMessagesQuery;
+pub use outbox_stats::OutboxStats;
+
+pub use handlers::*;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use crate::services::outbox_relay::OutboxMetricsSnapshot;
+
+/// 发件箱统计
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutboxStats {
+    pub pending: u64,
+    pub published: u64,
+    pub dead: u64,
+    /// 最早一条待投递消息的写入时间
+    pub oldest_pending_at: Option<i64>,
+    /// 中继累计指标
+    pub relay: OutboxMetricsSnapshot,
+}
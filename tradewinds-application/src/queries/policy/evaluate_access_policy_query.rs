@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+#[rustfmt::skip]
+use tradewinds_domain::{
+    policies::Attributes,
+    value_objects::{
+        permission::PermissionCode,
+        user::UserId,
+    },
+};
+
+/// 试运行访问判定查询
+///
+/// 按实际的 RBAC 授权与当前启用的访问策略判定，不产生任何副作用
+///
+/// 参数：
+/// - actor_id: 查询者ID，未指定主体时以查询者为主体
+/// - subject_id: 主体用户ID
+/// - action: 操作（权限码）
+/// - resource: 资源属性
+/// - environment: 环境属性，覆盖按当前请求生成的同名属性
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvaluateAccessPolicyQuery {
+    pub actor_id: UserId,
+    pub subject_id: Option<UserId>,
+    pub action: PermissionCode,
+    pub resource: Attributes,
+    pub environment: Attributes,
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::policy::AccessPolicyId;
+
+/// 根据策略ID查询访问策略
+///
+/// 参数：
+/// - id: 策略ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetAccessPolicyByIdQuery {
+    pub id: AccessPolicyId,
+}
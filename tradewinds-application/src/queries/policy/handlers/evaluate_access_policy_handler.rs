@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::policy_service::IPolicyService,
+    queries::policy::evaluate_access_policy_query::EvaluateAccessPolicyQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::policies::AccessEvaluation;
+use tradewinds_error::AppResult;
+
+/// 试运行访问判定查询处理器
+///
+/// 参数：
+/// - policy_service: 访问策略服务
+///
+/// 返回：
+/// - 试运行访问判定查询处理器
+pub struct EvaluateAccessPolicyHandler {
+    policy_service: Arc<dyn IPolicyService>,
+}
+
+impl EvaluateAccessPolicyHandler {
+    pub fn new(policy_service: Arc<dyn IPolicyService>) -> Self {
+        Self { policy_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<EvaluateAccessPolicyQuery, AccessEvaluation> for EvaluateAccessPolicyHandler {
+    async fn handle(&self, query: EvaluateAccessPolicyQuery) -> AppResult<AccessEvaluation> {
+        self.policy_service.evaluate(query).await
+    }
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::policy_service::IPolicyService,
+    queries::policy::get_access_policy_by_id_query::GetAccessPolicyByIdQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_error::AppResult;
+
+/// 根据策略ID查询访问策略查询处理器
+///
+/// 参数：
+/// - policy_service: 访问策略服务
+///
+/// 返回：
+/// - 根据策略ID查询访问策略查询处理器
+pub struct GetAccessPolicyByIdHandler {
+    policy_service: Arc<dyn IPolicyService>,
+}
+
+impl GetAccessPolicyByIdHandler {
+    pub fn new(policy_service: Arc<dyn IPolicyService>) -> Self {
+        Self { policy_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetAccessPolicyByIdQuery, AccessPolicy> for GetAccessPolicyByIdHandler {
+    async fn handle(&self, query: GetAccessPolicyByIdQuery) -> AppResult<AccessPolicy> {
+        self.policy_service.get_policy_by_id(query).await
+    }
+}
@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::policy_service::IPolicyService,
+    queries::policy::list_access_policies_query::ListAccessPoliciesQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_error::AppResult;
+
+/// 查询访问策略列表查询处理器
+///
+/// 参数：
+/// - policy_service: 访问策略服务
+///
+/// 返回：
+/// - 查询访问策略列表查询处理器
+pub struct ListAccessPoliciesHandler {
+    policy_service: Arc<dyn IPolicyService>,
+}
+
+impl ListAccessPoliciesHandler {
+    pub fn new(policy_service: Arc<dyn IPolicyService>) -> Self {
+        Self { policy_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListAccessPoliciesQuery, PaginatedResult<AccessPolicy>> for ListAccessPoliciesHandler {
+    async fn handle(&self, query: ListAccessPoliciesQuery) -> AppResult<PaginatedResult<AccessPolicy>> {
+        self.policy_service.list_policies(query).await
+    }
+}
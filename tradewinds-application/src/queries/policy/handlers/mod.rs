@@ -0,0 +1,7 @@
+pub mod evaluate_access_policy_handler;
+pub mod get_access_policy_by_id_handler;
+pub mod list_access_policies_handler;
+
+pub use evaluate_access_policy_handler::EvaluateAccessPolicyHandler;
+pub use get_access_policy_by_id_handler::GetAccessPolicyByIdHandler;
+pub use list_access_policies_handler::ListAccessPoliciesHandler;
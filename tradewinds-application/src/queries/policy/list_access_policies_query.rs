@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+use tradewinds_domain::value_objects::policy::AccessPolicyStatus;
+
+/// 查询访问策略列表查询
+///
+/// 参数：
+/// - page: 页码
+/// - page_size: 每页条数
+/// - keyword: 策略名称或操作（模糊匹配）
+/// - status: 策略状态（不传时排除已删除策略）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListAccessPoliciesQuery {
+    pub page: u64,
+    pub page_size: u64,
+    pub keyword: Option<String>,
+    pub status: Option<AccessPolicyStatus>,
+}
+
+impl ListAccessPoliciesQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
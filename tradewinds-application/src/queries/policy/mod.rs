@@ -0,0 +1,10 @@
+pub mod evaluate_access_policy_query;
+pub mod get_access_policy_by_id_query;
+pub mod handlers;
+pub mod list_access_policies_query;
+
+pub use evaluate_access_policy_query::EvaluateAccessPolicyQuery;
+pub use get_access_policy_by_id_query::GetAccessPolicyByIdQuery;
+pub use list_access_policies_query::ListAccessPoliciesQuery;
+
+pub use handlers::*;
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// 获取维护模式与只读模式当前状态查询
+///
+/// 两种模式为平台级设置，结果与当前租户无关
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GetSystemModeQuery;
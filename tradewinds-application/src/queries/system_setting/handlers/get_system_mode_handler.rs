@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::system_setting_service::ISystemSettingService,
+    queries::system_setting::get_system_mode_query::GetSystemModeQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::value_objects::system_setting::SystemMode;
+use tradewinds_error::AppResult;
+
+/// 获取维护模式与只读模式当前状态查询处理器
+///
+/// 参数：
+/// - system_setting_service: 系统设置服务
+///
+/// 返回：
+/// - 获取维护模式与只读模式当前状态查询处理器
+pub struct GetSystemModeHandler {
+    system_setting_service: Arc<dyn ISystemSettingService>,
+}
+
+impl GetSystemModeHandler {
+    pub fn new(system_setting_service: Arc<dyn ISystemSettingService>) -> Self {
+        Self { system_setting_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetSystemModeQuery, SystemMode> for GetSystemModeHandler {
+    async fn handle(&self, query: GetSystemModeQuery) -> AppResult<SystemMode> {
+        self.system_setting_service.system_mode(query).await
+    }
+}
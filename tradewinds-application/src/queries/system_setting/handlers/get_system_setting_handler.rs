@@ -5,8 +5,8 @@ use crate::{
     queries::system_setting::get_system_setting_query::GetSystemSettingQuery,
 };
 use std::sync::Arc;
-use tradewinds_domain::entities::system_setting::SystemSetting;
-use tradewinds_error::{AppError, AppResult};
+use tradewinds_domain::entities::system_setting::EffectiveSetting;
+use tradewinds_error::AppResult;
 
 /// 根据系统设置键获取系统设置查询处理器
 ///
@@ -26,11 +26,8 @@ impl GetSystemSettingHandler {
 }
 
 #[async_trait::async_trait]
-impl QueryHandler<GetSystemSettingQuery, SystemSetting> for GetSystemSettingHandler {
-    async fn handle(&self, query: GetSystemSettingQuery) -> AppResult<SystemSetting> {
-        match self.system_setting_service.get_by_key(query).await? {
-            Some(setting) => Ok(setting),
-            None => Err(AppError::NotFound("Setting not found".to_string())),
-        }
+impl QueryHandler<GetSystemSettingQuery, EffectiveSetting> for GetSystemSettingHandler {
+    async fn handle(&self, query: GetSystemSettingQuery) -> AppResult<EffectiveSetting> {
+        self.system_setting_service.get_by_key(query).await
     }
 }
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::system_setting_service::ISystemSettingService,
+    queries::system_setting::list_system_settings_query::ListSystemSettingsQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::system_setting::EffectiveSetting;
+use tradewinds_error::AppResult;
+
+/// 查询系统设置列表查询处理器
+///
+/// 参数：
+/// - system_setting_service: 系统设置服务
+///
+/// 返回：
+/// - 查询系统设置列表查询处理器
+pub struct ListSystemSettingsHandler {
+    system_setting_service: Arc<dyn ISystemSettingService>,
+}
+
+impl ListSystemSettingsHandler {
+    pub fn new(system_setting_service: Arc<dyn ISystemSettingService>) -> Self {
+        Self { system_setting_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListSystemSettingsQuery, Vec<EffectiveSetting>> for ListSystemSettingsHandler {
+    async fn handle(&self, query: ListSystemSettingsQuery) -> AppResult<Vec<EffectiveSetting>> {
+        self.system_setting_service.list(query).await
+    }
+}
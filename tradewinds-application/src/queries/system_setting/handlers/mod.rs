@@ -1,3 +1,7 @@
+pub mod get_system_mode_handler;
 pub mod get_system_setting_handler;
+pub mod list_system_settings_handler;
 
-pub use get_system_setting_handler::GetSystemSettingHandler;
\ No newline at end of file
+pub use get_system_mode_handler::GetSystemModeHandler;
+pub use get_system_setting_handler::GetSystemSettingHandler;
+pub use list_system_settings_handler::ListSystemSettingsHandler;
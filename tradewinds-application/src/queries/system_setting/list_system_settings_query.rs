@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use tradewinds_domain::value_objects::system_setting::SettingCategory;
+
+/// 查询系统设置列表查询
+///
+/// 返回登记表中的全部设置及当前租户的生效值
+///
+/// 参数：
+/// - category: 按分类过滤
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListSystemSettingsQuery {
+    pub category: Option<SettingCategory>,
+}
@@ -1,5 +1,9 @@
+pub mod get_system_mode_query;
 pub mod get_system_setting_query;
 pub mod handlers;
+pub mod list_system_settings_query;
 
+pub use get_system_mode_query::GetSystemModeQuery;
 pub use get_system_setting_query::GetSystemSettingQuery;
-pub use handlers::GetSystemSettingHandler;
\ No newline at end of file
+pub use handlers::{GetSystemModeHandler, GetSystemSettingHandler, ListSystemSettingsHandler};
+pub use list_system_settings_query::ListSystemSettingsQuery;
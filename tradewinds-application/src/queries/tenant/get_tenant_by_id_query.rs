@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::{tenant::TenantId, user::UserId};
+
+/// 根据租户ID查询租户
+///
+/// 参数：
+/// - tenant_id: 租户ID
+/// - actor_id: 查询者ID（须为平台管理员）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetTenantByIdQuery {
+    pub tenant_id: TenantId,
+    pub actor_id: UserId,
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::tenant_service::ITenantService,
+    queries::tenant::get_tenant_by_id_query::GetTenantByIdQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_error::AppResult;
+
+/// 根据租户ID查询租户查询处理器
+///
+/// 参数：
+/// - tenant_service: 租户服务
+///
+/// 返回：
+/// - 根据租户ID查询租户查询处理器
+pub struct GetTenantByIdHandler {
+    tenant_service: Arc<dyn ITenantService>,
+}
+
+impl GetTenantByIdHandler {
+    pub fn new(tenant_service: Arc<dyn ITenantService>) -> Self {
+        Self { tenant_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<GetTenantByIdQuery, Tenant> for GetTenantByIdHandler {
+    async fn handle(&self, query: GetTenantByIdQuery) -> AppResult<Tenant> {
+        self.tenant_service.get_tenant_by_id(query).await
+    }
+}
@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::tenant_service::ITenantService,
+    queries::tenant::list_tenants_query::ListTenantsQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_error::AppResult;
+
+/// 查询租户列表查询处理器
+///
+/// 参数：
+/// - tenant_service: 租户服务
+///
+/// 返回：
+/// - 查询租户列表查询处理器
+pub struct ListTenantsHandler {
+    tenant_service: Arc<dyn ITenantService>,
+}
+
+impl ListTenantsHandler {
+    pub fn new(tenant_service: Arc<dyn ITenantService>) -> Self {
+        Self { tenant_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListTenantsQuery, PaginatedResult<Tenant>> for ListTenantsHandler {
+    async fn handle(&self, query: ListTenantsQuery) -> AppResult<PaginatedResult<Tenant>> {
+        self.tenant_service.list_tenants(query).await
+    }
+}
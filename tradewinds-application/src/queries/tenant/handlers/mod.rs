@@ -0,0 +1,7 @@
+pub mod get_tenant_by_id_handler;
+pub mod list_tenants_handler;
+pub mod resolve_tenant_handler;
+
+pub use get_tenant_by_id_handler::GetTenantByIdHandler;
+pub use list_tenants_handler::ListTenantsHandler;
+pub use resolve_tenant_handler::ResolveTenantHandler;
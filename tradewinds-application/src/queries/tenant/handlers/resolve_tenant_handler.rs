@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::tenant_service::ITenantService,
+    queries::tenant::resolve_tenant_query::ResolveTenantQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_error::AppResult;
+
+/// 解析请求所属租户查询处理器
+///
+/// 参数：
+/// - tenant_service: 租户服务
+///
+/// 返回：
+/// - 解析请求所属租户查询处理器
+pub struct ResolveTenantHandler {
+    tenant_service: Arc<dyn ITenantService>,
+}
+
+impl ResolveTenantHandler {
+    pub fn new(tenant_service: Arc<dyn ITenantService>) -> Self {
+        Self { tenant_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ResolveTenantQuery, Tenant> for ResolveTenantHandler {
+    async fn handle(&self, query: ResolveTenantQuery) -> AppResult<Tenant> {
+        self.tenant_service.resolve_tenant(query).await
+    }
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use tradewinds_domain::value_objects::{tenant::TenantStatus, user::UserId};
+
+/// 查询租户列表查询
+///
+/// 参数：
+/// - page: 页码
+/// - page_size: 每页条数
+/// - keyword: 租户编码或名称（模糊匹配）
+/// - status: 租户状态（不传时排除已删除租户）
+/// - actor_id: 查询者ID（须为平台管理员）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListTenantsQuery {
+    pub page: u64,
+    pub page_size: u64,
+    pub keyword: Option<String>,
+    pub status: Option<TenantStatus>,
+    pub actor_id: UserId,
+}
+
+impl ListTenantsQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
@@ -0,0 +1,10 @@
+pub mod get_tenant_by_id_query;
+pub mod handlers;
+pub mod list_tenants_query;
+pub mod resolve_tenant_query;
+
+pub use get_tenant_by_id_query::GetTenantByIdQuery;
+pub use list_tenants_query::ListTenantsQuery;
+pub use resolve_tenant_query::ResolveTenantQuery;
+
+pub use handlers::*;
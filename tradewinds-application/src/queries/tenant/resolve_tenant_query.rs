@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::tenant::{TenantCode, TenantHost, TenantId};
+
+/// 解析请求所属租户
+///
+/// 按 id（令牌声明）> code（请求头）> host（访问域名）的优先级解析；
+/// 都未提供或域名未绑定任何租户时归属平台默认租户。
+///
+/// 参数：
+/// - id: 令牌中的租户ID
+/// - code: 请求头中的租户编码
+/// - host: 请求的访问域名
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResolveTenantQuery {
+    pub id: Option<TenantId>,
+    pub code: Option<TenantCode>,
+    pub host: Option<TenantHost>,
+}
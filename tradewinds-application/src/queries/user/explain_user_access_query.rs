@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::value_objects::{permission::PermissionCode, user::UserId};
+
+/// 访问说明查询：用户为何拥有（或缺少）某个权限
+///
+/// 参数：
+/// - user_id: 用户ID
+/// - code: 权限码
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExplainUserAccessQuery {
+    pub user_id: UserId,
+    pub code: PermissionCode,
+}
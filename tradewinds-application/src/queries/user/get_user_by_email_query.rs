@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use tradewinds_domain::value_objects::user::UserId;
 use tradewinds_domain::value_objects::user::user_email::Email;
 
 /// 根据邮箱获取用户查询
 ///
 /// 参数：
 /// - user_email: 邮箱
+/// - principal_id: 当前主体ID，目标用户须在其数据范围内；为空表示系统内部调用，不做限制
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetUserByEmailQuery {
     pub user_email: Email,
+    pub principal_id: Option<UserId>,
 }
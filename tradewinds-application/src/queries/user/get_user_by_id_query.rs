@@ -6,7 +6,9 @@ use tradewinds_domain::value_objects::user::UserId;
 ///
 /// 参数：
 /// - user_id: 用户ID
+/// - principal_id: 当前主体ID，目标用户须在其数据范围内；为空表示系统内部调用，不做限制
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetUserByIdQuery {
     pub user_id: UserId,
+    pub principal_id: Option<UserId>,
 }
@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use tradewinds_domain::value_objects::auth::auth_username::AuthUsername;
+use tradewinds_domain::value_objects::user::UserId;
 
 /// 根据用户名获取用户查询
 ///
 /// 参数：
 /// - username: 用户名
+/// - principal_id: 当前主体ID，目标用户须在其数据范围内；为空表示系统内部调用，不做限制
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GetUserByUsernameQuery {
     pub username: AuthUsername,
+    pub principal_id: Option<UserId>,
 }
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::user_service::IUserService,
+    queries::user::explain_user_access_query::ExplainUserAccessQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::policies::AccessExplanation;
+use tradewinds_error::AppResult;
+
+/// 访问说明查询处理器
+///
+/// 参数：
+/// - user_service: 用户服务
+///
+/// 返回：
+/// - 访问说明查询处理器
+pub struct ExplainUserAccessHandler {
+    user_service: Arc<dyn IUserService>,
+}
+
+impl ExplainUserAccessHandler {
+    pub fn new(user_service: Arc<dyn IUserService>) -> Self {
+        Self { user_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ExplainUserAccessQuery, AccessExplanation> for ExplainUserAccessHandler {
+    async fn handle(&self, query: ExplainUserAccessQuery) -> AppResult<AccessExplanation> {
+        self.user_service.explain_user_access(query).await
+    }
+}
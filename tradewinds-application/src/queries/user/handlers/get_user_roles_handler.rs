@@ -6,6 +6,7 @@ use crate::{
 };
 use std::sync::Arc;
 use tradewinds_domain::entities::role::Role;
+use tradewinds_domain::value_objects::RoleSource;
 use tradewinds_domain::value_objects::user::UserId;
 use tradewinds_error::AppResult;
 
@@ -27,8 +28,8 @@ impl GetUserRolesHandler {
 }
 
 #[async_trait::async_trait]
-impl QueryHandler<GetUserRolesQuery, Vec<Role>> for GetUserRolesHandler {
-    async fn handle(&self, query: GetUserRolesQuery) -> AppResult<Vec<Role>> {
+impl QueryHandler<GetUserRolesQuery, Vec<(Role, Vec<RoleSource>)>> for GetUserRolesHandler {
+    async fn handle(&self, query: GetUserRolesQuery) -> AppResult<Vec<(Role, Vec<RoleSource>)>> {
         self.user_service.get_user_roles(query).await
     }
 }
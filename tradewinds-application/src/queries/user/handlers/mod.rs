@@ -1,3 +1,4 @@
+pub mod explain_user_access_handler;
 pub mod get_user_by_email_handler;
 pub mod get_user_by_id_handler;
 pub mod get_user_by_username_handler;
@@ -5,6 +6,7 @@ pub mod get_user_permissions_handler;
 pub mod get_user_roles_handler;
 pub mod list_users_handler;
 
+pub use explain_user_access_handler::ExplainUserAccessHandler;
 pub use get_user_by_email_handler::GetUserByEmailHandler;
 pub use get_user_by_id_handler::GetUserByIdHandler;
 pub use get_user_by_username_handler::GetUserByUsernameHandler;
@@ -1,10 +1,14 @@
 use serde::{Deserialize, Serialize};
 
+use tradewinds_domain::value_objects::{DepartmentId, UserId};
+
 /// 查询用户列表查询
 ///
 /// 参数：
 /// - page: 页码
 /// - page_size: 每页条数
+/// - department_id: 所属部门ID，包含其全部子部门
+/// - principal_id: 当前主体ID，用于按其角色的数据范围过滤；为空表示系统内部调用，不做限制
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ListUsersQuery {
     pub page: u64,
@@ -14,6 +18,8 @@ pub struct ListUsersQuery {
     pub status: Option<i32>,
     pub email: Option<String>,
     pub show_deleted: Option<bool>,
+    pub department_id: Option<DepartmentId>,
+    pub principal_id: Option<UserId>,
 }
 
 impl ListUsersQuery {
@@ -1,5 +1,6 @@
 pub mod handlers;
 
+pub mod explain_user_access_query;
 pub mod get_user_by_email_query;
 pub mod get_user_by_id_query;
 pub mod get_user_by_username_query;
@@ -7,6 +8,7 @@ pub mod get_user_permissions_query;
 pub mod get_user_roles_query;
 pub mod list_users_query;
 
+pub use explain_user_access_query::ExplainUserAccessQuery;
 pub use get_user_by_email_query::GetUserByEmailQuery;
 pub use get_user_by_id_query::GetUserByIdQuery;
 pub use get_user_by_username_query::GetUserByUsernameQuery;
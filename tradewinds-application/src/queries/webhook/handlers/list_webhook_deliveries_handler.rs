@@ -0,0 +1,34 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::webhook_service::IWebhookService,
+    queries::webhook::list_webhook_deliveries_query::ListWebhookDeliveriesQuery,
+};
+use std::sync::Arc;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::webhook_delivery::WebhookDelivery;
+use tradewinds_error::AppResult;
+
+/// 查询 Webhook 投递记录列表查询处理器
+///
+/// 参数：
+/// - webhook_service: Webhook 服务
+///
+/// 返回：
+/// - 查询 Webhook 投递记录列表查询处理器
+pub struct ListWebhookDeliveriesHandler {
+    webhook_service: Arc<dyn IWebhookService>,
+}
+
+impl ListWebhookDeliveriesHandler {
+    pub fn new(webhook_service: Arc<dyn IWebhookService>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListWebhookDeliveriesQuery, PaginatedResult<WebhookDelivery>> for ListWebhookDeliveriesHandler {
+    async fn handle(&self, query: ListWebhookDeliveriesQuery) -> AppResult<PaginatedResult<WebhookDelivery>> {
+        self.webhook_service.list_deliveries(query).await
+    }
+}
@@ -0,0 +1,33 @@
+#[rustfmt::skip]
+use crate::{
+    QueryHandler,
+    interfaces::webhook_service::IWebhookService,
+    queries::webhook::list_webhooks_query::ListWebhooksQuery,
+};
+use std::sync::Arc;
+use tradewinds_domain::entities::webhook::Webhook;
+use tradewinds_error::AppResult;
+
+/// 查询 Webhook 端点列表查询处理器
+///
+/// 参数：
+/// - webhook_service: Webhook 服务
+///
+/// 返回：
+/// - 查询 Webhook 端点列表查询处理器
+pub struct ListWebhooksHandler {
+    webhook_service: Arc<dyn IWebhookService>,
+}
+
+impl ListWebhooksHandler {
+    pub fn new(webhook_service: Arc<dyn IWebhookService>) -> Self {
+        Self { webhook_service }
+    }
+}
+
+#[async_trait::async_trait]
+impl QueryHandler<ListWebhooksQuery, Vec<Webhook>> for ListWebhooksHandler {
+    async fn handle(&self, query: ListWebhooksQuery) -> AppResult<Vec<Webhook>> {
+        self.webhook_service.list_webhooks(query).await
+    }
+}
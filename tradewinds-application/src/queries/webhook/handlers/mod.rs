@@ -0,0 +1,5 @@
+pub mod list_webhook_deliveries_handler;
+pub mod list_webhooks_handler;
+
+pub use list_webhook_deliveries_handler::ListWebhookDeliveriesHandler;
+pub use list_webhooks_handler::ListWebhooksHandler;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+
+use tradewinds_domain::repositories::WebhookDeliveryFilter;
+
+/// 查询 Webhook 投递记录列表查询
+///
+/// 参数：
+/// - filter: 查询条件
+/// - page: 页码
+/// - page_size: 每页条数
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListWebhookDeliveriesQuery {
+    pub filter: WebhookDeliveryFilter,
+    pub page: u64,
+    pub page_size: u64,
+}
+
+impl ListWebhookDeliveriesQuery {
+    pub fn pagination(&self) -> (u64, u64) {
+        let offset = self.page.saturating_sub(1) * self.page_size;
+        (self.page_size, offset)
+    }
+}
@@ -0,0 +1,7 @@
+use serde::{Deserialize, Serialize};
+
+/// 查询 Webhook 端点列表查询
+///
+/// 返回当前租户的全部端点
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ListWebhooksQuery;
@@ -0,0 +1,8 @@
+pub mod handlers;
+pub mod list_webhook_deliveries_query;
+pub mod list_webhooks_query;
+
+pub use list_webhook_deliveries_query::ListWebhookDeliveriesQuery;
+pub use list_webhooks_query::ListWebhooksQuery;
+
+pub use handlers::*;
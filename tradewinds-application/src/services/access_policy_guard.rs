@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use chrono::{Datelike, Local, Timelike};
+use tradewinds_common::request_context::current_request_context;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::user::User;
+use tradewinds_domain::policies::{AbacDecision, AbacEvaluation, AbacPolicy, AccessContext, Attributes};
+use tradewinds_domain::repositories::{AccessPolicyRepository, RoleRepository, UserRepository, UserRoleRepository};
+use tradewinds_domain::value_objects::{PermissionCode, RoleAssignment, UserId};
+use tradewinds_error::{AppError, AppResult};
+
+/// 受访问策略约束的操作
+pub(crate) const ACTION_USER_UPDATE: &str = "user:update";
+pub(crate) const ACTION_USER_DELETE: &str = "user:delete";
+pub(crate) const ACTION_USER_RESET_PASSWORD: &str = "user:password:reset";
+pub(crate) const ACTION_USER_ASSIGN_ROLE: &str = "user:role:assign";
+pub(crate) const ACTION_USER_REVOKE_ROLE: &str = "user:role:revoke";
+
+/// 经过访问策略校验的全部操作
+pub(crate) const ENFORCED_ACTIONS: [&str; 5] = [
+    ACTION_USER_UPDATE,
+    ACTION_USER_DELETE,
+    ACTION_USER_RESET_PASSWORD,
+    ACTION_USER_ASSIGN_ROLE,
+    ACTION_USER_REVOKE_ROLE,
+];
+
+/// 访问策略（ABAC）校验
+///
+/// 在其余业务校验通过后、变更落库前调用，
+/// 以操作者为主体、目标对象为资源、当前请求为环境执行 AbacPolicy。
+/// 操作者取自命令中的 `*_by`，由处理器从认证中间件写入请求上下文的操作人填充；
+/// 未指定操作者（系统内部调用）时不做校验。
+///
+/// 目前只有 `UserService` 的用户更新、删除、重置密码与角色分配、撤销（`ACTION_USER_*`）经过此校验，
+/// 其余服务的变更不受访问策略约束；创建或修改策略时由 [`AccessPolicyGuard::ensure_enforced`]
+/// 拒绝不覆盖其中任何操作的 `action`，避免配置了永远不会生效的策略。
+#[derive(Clone)]
+pub(crate) struct AccessPolicyGuard {
+    policy_repo: Arc<dyn AccessPolicyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+}
+
+impl AccessPolicyGuard {
+    pub(crate) fn new(
+        policy_repo: Arc<dyn AccessPolicyRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+    ) -> Self {
+        Self { policy_repo, user_repo, role_repo, user_role_repo }
+    }
+
+    /// 校验操作者能否对资源执行操作，被策略拒绝时返回 Forbidden
+    pub(crate) async fn authorize(
+        &self,
+        actor_id: Option<&UserId>,
+        action: &str,
+        resource: Attributes,
+    ) -> AppResult<()> {
+        let Some(actor_id) = actor_id else {
+            return Ok(());
+        };
+        let action = PermissionCode::new(action)?;
+        let policies = self.policy_repo.find_active().await?;
+        if !policies.iter().any(|p| p.applies_to(&action)) {
+            return Ok(());
+        }
+
+        let actor = self
+            .user_repo
+            .find_by_id(actor_id)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized(format!("Principal not found: {}", actor_id)))?;
+        let ctx = self.context(&actor, action, resource, Attributes::new()).await?;
+        let evaluation = AbacPolicy::evaluate(&policies, &ctx);
+        if evaluation.is_allowed() {
+            return Ok(());
+        }
+        Err(AppError::Forbidden(format!("Access denied by policy: {}", Self::deny_reason(&evaluation))))
+    }
+
+    /// 策略的操作模式须覆盖至少一个经过校验的操作
+    ///
+    /// 通配模式只在其覆盖的受校验操作上生效
+    pub(crate) fn ensure_enforced(action: &PermissionCode) -> AppResult<()> {
+        for enforced in ENFORCED_ACTIONS {
+            if action.matches(&PermissionCode::new(enforced)?) {
+                return Ok(());
+            }
+        }
+        Err(AppError::Validation(format!(
+            "Access policy action {} is not enforced, supported actions: {}",
+            action,
+            ENFORCED_ACTIONS.join(", ")
+        )))
+    }
+
+    /// 构造访问上下文，`environment` 覆盖按当前请求生成的同名环境属性
+    pub(crate) async fn context(
+        &self,
+        subject: &User,
+        action: PermissionCode,
+        resource: Attributes,
+        environment: Attributes,
+    ) -> AppResult<AccessContext> {
+        let mut ctx = AccessContext::new(action);
+        ctx.subject = self.subject_attributes(subject).await?;
+        ctx.resource = resource;
+        ctx.environment = Self::environment_attributes();
+        ctx.environment.extend(environment);
+        Ok(ctx)
+    }
+
+    /// 按当前启用的策略求值
+    pub(crate) async fn evaluate(&self, ctx: &AccessContext) -> AppResult<AbacEvaluation> {
+        let policies = self.policy_repo.find_active().await?;
+        Ok(AbacPolicy::evaluate(&policies, ctx))
+    }
+
+    /// 用户作为资源时的属性
+    pub(crate) fn user_attributes(user: &User) -> Attributes {
+        let mut attributes = Attributes::new();
+        attributes.insert("id".into(), user.id.value().into());
+        attributes.insert("username".into(), user.username.value().into());
+        attributes.insert("email".into(), user.email.value().into());
+        attributes.insert("status".into(), i64::from(user.status.value()).into());
+        attributes.insert("department_id".into(), user.department_id.as_ref().map(|d| d.value()).into());
+        attributes.insert("created_by".into(), user.created_by.as_ref().map(|u| u.value()).into());
+        attributes
+    }
+
+    /// 主体属性：用户属性、租户以及有效启用角色的编码
+    async fn subject_attributes(&self, user: &User) -> AppResult<Attributes> {
+        let assignments = self.user_role_repo.find_assignments_by_user_id(&user.id).await?;
+        let role_ids = RoleAssignment::effective_role_ids(&assignments);
+        let roles = if role_ids.is_empty() { Vec::new() } else { self.role_repo.find_by_ids(&role_ids).await? };
+        let role_codes: Vec<String> =
+            roles.iter().filter(|role| role.is_active()).map(|role| role.code.value().to_string()).collect();
+
+        let mut attributes = Self::user_attributes(user);
+        attributes.insert("tenant_id".into(), current_tenant_id().into());
+        attributes.insert("roles".into(), role_codes.into());
+        Ok(attributes)
+    }
+
+    /// 环境属性：服务器本地时间与客户端地址
+    ///
+    /// weekday 取 1-7，对应周一至周日
+    fn environment_attributes() -> Attributes {
+        let now = Local::now();
+        let request = current_request_context();
+        let mut attributes = Attributes::new();
+        attributes.insert("time".into(), now.to_rfc3339().into());
+        attributes.insert("date".into(), now.format("%Y-%m-%d").to_string().into());
+        attributes.insert("hour".into(), now.hour().into());
+        attributes.insert("minute".into(), now.minute().into());
+        attributes.insert("weekday".into(), now.weekday().number_from_monday().into());
+        attributes.insert("ip".into(), request.client_ip.into());
+        attributes.insert("user_agent".into(), request.user_agent.into());
+        attributes
+    }
+
+    fn deny_reason(evaluation: &AbacEvaluation) -> String {
+        let denied: Vec<&str> =
+            evaluation.outcomes.iter().filter(|o| o.effect.is_deny() && o.matched).map(|o| o.name.value()).collect();
+        match evaluation.decision {
+            AbacDecision::Deny if !denied.is_empty() => denied.join(", "),
+            _ => "no allow policy matched".to_string(),
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use crate::commands::access_request::{
+    ApproveAccessRequestCommand, CancelAccessRequestCommand, ExpireAccessRequestsCommand, RejectAccessRequestCommand,
+    SetRoleApproversCommand, SubmitAccessRequestCommand,
+};
+use crate::commands::user::{AssignRoleCommand, RevokeRoleCommand};
+use crate::interfaces::{IAccessRequestService, IUserService};
+use crate::queries::access_request::{
+    AccessRequestScope, GetAccessRequestByIdQuery, GetRoleApproversQuery, ListAccessRequestsQuery,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::AccessRequestAggregate;
+use tradewinds_domain::entities::{access_request::AccessRequest, role::Role, user::User};
+use tradewinds_domain::policies::SUPER_ADMIN_ROLE_CODE;
+use tradewinds_domain::repositories::{
+    AccessRequestRepository, RoleApproverRepository, RoleRepository, UserRepository, UserRoleRepository,
+};
+use tradewinds_domain::value_objects::role::RoleCode;
+use tradewinds_domain::value_objects::{AccessRequestId, RoleAssignment, RoleId, UserId};
+
+use chrono::Utc;
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 权限申请服务
+///
+/// 批准申请时先落库审批结论，再经由用户服务的分配角色流程授权，职责分离、访问策略等校验同样生效，
+/// 授权被拒绝时申请恢复为待审批；授权到期时经由撤销角色流程收回，仅收回由该申请授予的角色。
+/// 申请事件随状态变更在同一事务内写入发件箱。
+/// 申请详情仅申请人、该角色的审批人与超级管理员可见，租户内全部申请仅超级管理员可查。
+#[derive(Clone)]
+pub struct AccessRequestService {
+    request_repo: Arc<dyn AccessRequestRepository>,
+    approver_repo: Arc<dyn RoleApproverRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    user_service: Arc<dyn IUserService>,
+}
+
+impl AccessRequestService {
+    pub fn new(
+        request_repo: Arc<dyn AccessRequestRepository>,
+        approver_repo: Arc<dyn RoleApproverRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+        user_service: Arc<dyn IUserService>,
+    ) -> Self {
+        Self { request_repo, approver_repo, user_repo, role_repo, user_role_repo, user_service }
+    }
+
+    async fn find_aggregate(&self, id: &AccessRequestId) -> AppResult<AccessRequestAggregate> {
+        self.request_repo
+            .find_by_id(id)
+            .await?
+            .map(AccessRequestAggregate::from_existing)
+            .ok_or_else(|| AppError::NotFound("Access request not found".into()))
+    }
+
+    async fn find_role(&self, role_id: &RoleId) -> AppResult<Role> {
+        self.role_repo.find_by_id(role_id).await?.ok_or_else(|| AppError::NotFound("Role not found".into()))
+    }
+
+    /// 审批人必须是该角色配置的审批人之一
+    async fn ensure_approver(&self, role_id: &RoleId, approver_id: &UserId) -> AppResult<()> {
+        let approvers = self.approver_repo.find_approver_ids(role_id).await?;
+        if !approvers.contains(approver_id) {
+            return Err(AppError::Forbidden("Only approvers of the role can decide this access request".into()));
+        }
+        Ok(())
+    }
+
+    /// 是否为超级管理员：有效角色（含经用户组获得的角色）中包含启用的超级管理员角色
+    async fn is_super_admin(&self, actor_id: &UserId) -> AppResult<bool> {
+        let Some(super_admin) = self.role_repo.find_by_code(&RoleCode::new(SUPER_ADMIN_ROLE_CODE.into())?).await?
+        else {
+            return Ok(false);
+        };
+        if !super_admin.is_active() {
+            return Ok(false);
+        }
+        let assignments = self.user_role_repo.find_assignments_by_user_id(actor_id).await?;
+        Ok(RoleAssignment::effective_role_ids(&assignments).contains(&super_admin.id))
+    }
+
+    /// 处理单个到期申请，授权到期时先收回由该申请授予的角色
+    ///
+    /// 申请人在申请之前或之后被直接分配了同一角色时，该角色不随申请到期而收回
+    async fn expire_one(&self, request: AccessRequest, now: i64) -> AppResult<()> {
+        let mut aggregate = AccessRequestAggregate::from_existing(request);
+        if aggregate.request.status.is_approved() {
+            let request = &aggregate.request;
+            let granted_by_request =
+                self.user_role_repo.find_by_user_id(&request.requester_id).await?.iter().any(|user_role| {
+                    user_role.role_id == request.role_id && user_role.access_request_id.as_ref() == Some(&request.id)
+                });
+            if granted_by_request {
+                self.user_service
+                    .revoke_role(RevokeRoleCommand {
+                        user_id: request.requester_id.clone(),
+                        role_id: request.role_id.clone(),
+                        revoked_by: None,
+                    })
+                    .await?;
+            }
+        }
+        aggregate.expire(now)?;
+        self.request_repo.save(&aggregate).await
+    }
+}
+
+#[async_trait::async_trait]
+impl IAccessRequestService for AccessRequestService {
+    async fn submit_request(&self, cmd: SubmitAccessRequestCommand) -> AppResult<AccessRequest> {
+        let role = self.find_role(&cmd.role_id).await?;
+        if !role.is_active() {
+            return Err(AppError::Validation(format!("Role {} is not active", role.name)));
+        }
+        if self.approver_repo.find_approver_ids(&cmd.role_id).await?.is_empty() {
+            return Err(AppError::Validation(format!("Role {} has no approvers configured", role.name)));
+        }
+        if self.user_role_repo.exists(&cmd.requester_id, &cmd.role_id).await? {
+            return Err(AppError::Conflict(format!("User already has role {}", role.name)));
+        }
+        if self.request_repo.find_pending(&cmd.requester_id, &cmd.role_id).await?.is_some() {
+            return Err(AppError::Conflict(format!("A pending request for role {} already exists", role.name)));
+        }
+
+        let aggregate = AccessRequestAggregate::submit(cmd.requester_id, cmd.role_id, cmd.justification, cmd.duration);
+        self.request_repo.create(&aggregate).await?;
+        Ok(aggregate.request)
+    }
+
+    async fn approve_request(&self, cmd: ApproveAccessRequestCommand) -> AppResult<()> {
+        let mut aggregate = self.find_aggregate(&cmd.id).await?;
+        self.ensure_approver(&aggregate.request.role_id, &cmd.approver_id).await?;
+        let pending = aggregate.request.clone();
+        aggregate.approve(cmd.approver_id.clone(), cmd.comment)?;
+
+        // 先落库审批结论再授权，避免角色已授予而申请仍处于待审批；授权成功后才写入批准事件
+        self.request_repo.save(&AccessRequestAggregate::from_existing(aggregate.request.clone())).await?;
+        let granted = self
+            .user_service
+            .assign_role(AssignRoleCommand {
+                user_id: aggregate.request.requester_id.clone(),
+                role_id: aggregate.request.role_id.clone(),
+                assigned_by: Some(cmd.approver_id),
+                access_request_id: Some(aggregate.request.id.clone()),
+            })
+            .await;
+        if let Err(e) = granted {
+            // 授权被职责分离、访问策略等校验拒绝时撤销审批结论，申请恢复为待审批
+            let restored = AccessRequestAggregate::from_existing(pending);
+            if let Err(restore_err) = self.request_repo.save(&restored).await {
+                tracing::error!("Failed to restore access request {} to pending: {}", restored.request.id, restore_err);
+            }
+            return Err(e);
+        }
+        self.request_repo.save(&aggregate).await
+    }
+
+    async fn reject_request(&self, cmd: RejectAccessRequestCommand) -> AppResult<()> {
+        let mut aggregate = self.find_aggregate(&cmd.id).await?;
+        self.ensure_approver(&aggregate.request.role_id, &cmd.approver_id).await?;
+        aggregate.reject(cmd.approver_id, cmd.comment)?;
+        self.request_repo.save(&aggregate).await
+    }
+
+    async fn cancel_request(&self, cmd: CancelAccessRequestCommand) -> AppResult<()> {
+        let mut aggregate = self.find_aggregate(&cmd.id).await?;
+        aggregate.cancel(&cmd.requester_id)?;
+        self.request_repo.save(&aggregate).await
+    }
+
+    async fn expire_requests(&self, _cmd: ExpireAccessRequestsCommand) -> AppResult<u64> {
+        let now = Utc::now().timestamp();
+        let mut expired = 0;
+        for request in self.request_repo.find_due_for_expiry(now).await? {
+            let id = request.id.clone();
+            // 单个申请失败（如收回角色被安全校验拒绝）不影响其余申请，下次处理时重试
+            match self.expire_one(request, now).await {
+                Ok(()) => expired += 1,
+                Err(e) => tracing::warn!("Failed to expire access request {}: {}", id, e),
+            }
+        }
+        Ok(expired)
+    }
+
+    async fn get_request_by_id(&self, query: GetAccessRequestByIdQuery) -> AppResult<AccessRequest> {
+        let request = self
+            .request_repo
+            .find_by_id(&query.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Access request not found".into()))?;
+        if request.requester_id != query.actor_id
+            && !self.approver_repo.find_approver_ids(&request.role_id).await?.contains(&query.actor_id)
+            && !self.is_super_admin(&query.actor_id).await?
+        {
+            return Err(AppError::Forbidden(
+                "Only the requester, approvers of the role or administrators can view this access request".into(),
+            ));
+        }
+        Ok(request)
+    }
+
+    async fn list_requests(&self, query: ListAccessRequestsQuery) -> AppResult<PaginatedResult<AccessRequest>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) = match query.scope {
+            AccessRequestScope::Mine => {
+                self.request_repo.search(Some(&query.actor_id), None, query.status, limit, offset).await?
+            }
+            AccessRequestScope::Approvals => {
+                let role_ids = self.approver_repo.find_role_ids_by_approver(&query.actor_id).await?;
+                self.request_repo.search(None, Some(&role_ids), query.status, limit, offset).await?
+            }
+            AccessRequestScope::All => {
+                if !self.is_super_admin(&query.actor_id).await? {
+                    return Err(AppError::Forbidden("Only administrators can list all access requests".into()));
+                }
+                self.request_repo.search(None, None, query.status, limit, offset).await?
+            }
+        };
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn get_role_approvers(&self, query: GetRoleApproversQuery) -> AppResult<Vec<User>> {
+        self.find_role(&query.role_id).await?;
+        let approver_ids = self.approver_repo.find_approver_ids(&query.role_id).await?;
+        if approver_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.user_repo.find_by_ids(&approver_ids).await
+    }
+
+    async fn set_role_approvers(&self, cmd: SetRoleApproversCommand) -> AppResult<()> {
+        self.find_role(&cmd.role_id).await?;
+        let mut approver_ids: Vec<UserId> = Vec::new();
+        for id in cmd.approver_ids {
+            if !approver_ids.contains(&id) {
+                approver_ids.push(id);
+            }
+        }
+        if !approver_ids.is_empty() {
+            let users = self.user_repo.find_by_ids(&approver_ids).await?;
+            for id in &approver_ids {
+                if !users.iter().any(|u| &u.id == id && !u.status.is_deleted()) {
+                    return Err(AppError::NotFound(format!("User not found: {}", id)));
+                }
+            }
+        }
+        self.approver_repo.replace(&cmd.role_id, &approver_ids).await
+    }
+}
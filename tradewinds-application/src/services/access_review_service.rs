@@ -0,0 +1,247 @@
+use crate::commands::access_review::{
+    CloseDueAccessReviewsCommand, DecideAccessReviewItemCommand, LaunchAccessReviewCommand,
+};
+use crate::commands::user::RevokeRoleCommand;
+use crate::interfaces::{IAccessReviewService, INotificationService, IUserService};
+use crate::queries::access_review::{
+    AccessReviewReport, AccessReviewReportRow, AccessReviewScope, GetAccessReviewByIdQuery, GetAccessReviewReportQuery,
+    ListAccessReviewItemsQuery, ListAccessReviewsQuery,
+};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::{AccessReviewAggregate, ReviewScope};
+use tradewinds_domain::entities::access_review::AccessReviewItem;
+use tradewinds_domain::repositories::{
+    AccessReviewRepository, RoleApproverRepository, RoleRepository, UserRepository, UserRoleRepository,
+};
+use tradewinds_domain::value_objects::{AccessReviewId, UserId};
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 访问复核服务
+///
+/// 复核范围为所选角色的直接持有人（经由用户组获得的角色随用户组管理，不在此复核）；
+/// 每个组合优先由角色审批人复核，未配置审批人时由兜底复核人复核。
+/// 收回决定与到期自动收回均经由用户服务的撤销角色流程执行，管理员保护等校验同样生效。
+#[derive(Clone)]
+pub struct AccessReviewService {
+    review_repo: Arc<dyn AccessReviewRepository>,
+    approver_repo: Arc<dyn RoleApproverRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    user_service: Arc<dyn IUserService>,
+    notifier: Arc<dyn INotificationService>,
+}
+
+impl AccessReviewService {
+    pub fn new(
+        review_repo: Arc<dyn AccessReviewRepository>,
+        approver_repo: Arc<dyn RoleApproverRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+        user_service: Arc<dyn IUserService>,
+        notifier: Arc<dyn INotificationService>,
+    ) -> Self {
+        Self { review_repo, approver_repo, user_repo, role_repo, user_role_repo, user_service, notifier }
+    }
+
+    async fn find_aggregate(&self, id: &AccessReviewId) -> AppResult<AccessReviewAggregate> {
+        self.review_repo.find_by_id(id).await?.ok_or_else(|| AppError::NotFound("Access review not found".into()))
+    }
+
+    /// 经由撤销角色流程收回角色，用户已不再直接持有该角色时跳过
+    async fn revoke(&self, item: &AccessReviewItem, revoked_by: Option<UserId>) -> AppResult<()> {
+        if !self.user_role_repo.exists(&item.user_id, &item.role_id).await? {
+            return Ok(());
+        }
+        self.user_service
+            .revoke_role(RevokeRoleCommand { user_id: item.user_id.clone(), role_id: item.role_id.clone(), revoked_by })
+            .await
+    }
+
+    /// 处理单个到期活动：逐项自动收回，全部处理后完成活动
+    ///
+    /// 单项收回失败（如被管理员保护拒绝）时保留为未决，活动保持进行中，下次处理时重试
+    async fn close_one(&self, mut aggregate: AccessReviewAggregate, now: i64) -> AppResult<bool> {
+        let pending: Vec<AccessReviewItem> = aggregate.pending_items().cloned().collect();
+        for item in pending {
+            match self.revoke(&item, None).await {
+                Ok(()) => aggregate.auto_revoke(&item.id, now)?,
+                Err(e) => tracing::warn!(
+                    "Failed to auto-revoke role {} from user {} in access review {}: {}",
+                    item.role_id,
+                    item.user_id,
+                    aggregate.campaign.id,
+                    e
+                ),
+            }
+        }
+        let completed = aggregate.pending_items().next().is_none();
+        if completed {
+            aggregate.complete(now)?;
+        }
+        self.review_repo.save(&aggregate).await?;
+        Ok(completed)
+    }
+
+    /// 通知各复核人待复核的数量，通知失败不影响活动发起
+    async fn notify_reviewers(&self, aggregate: &AccessReviewAggregate) {
+        let mut counts: HashMap<&UserId, usize> = HashMap::new();
+        for item in &aggregate.items {
+            *counts.entry(&item.reviewer_id).or_default() += 1;
+        }
+        let reviewer_ids: Vec<UserId> = counts.keys().map(|id| (*id).clone()).collect();
+        let reviewers = match self.user_repo.find_by_ids(&reviewer_ids).await {
+            Ok(reviewers) => reviewers,
+            Err(e) => {
+                tracing::warn!("Failed to load reviewers for access review {}: {}", aggregate.campaign.id, e);
+                return;
+            }
+        };
+        let campaign = &aggregate.campaign;
+        for reviewer in reviewers.iter().filter(|u| !u.status.is_deleted()) {
+            let count = counts.get(&reviewer.id).copied().unwrap_or_default();
+            let body = format!(
+                "You have {} role assignment(s) to review in \"{}\" before {}. Review ID: {}",
+                count,
+                campaign.name,
+                format_time(campaign.deadline),
+                campaign.id
+            );
+            if let Err(e) = self.notifier.notify(reviewer, "Access review assigned", &body).await {
+                tracing::warn!("Failed to notify reviewer {} for access review {}: {}", reviewer.id, campaign.id, e);
+            }
+        }
+    }
+}
+
+fn format_time(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).map(|t| t.to_rfc3339()).unwrap_or_default()
+}
+
+#[async_trait::async_trait]
+impl IAccessReviewService for AccessReviewService {
+    async fn launch_review(&self, cmd: LaunchAccessReviewCommand) -> AppResult<AccessReviewAggregate> {
+        let fallback_reviewer = cmd.reviewer_id.unwrap_or_else(|| cmd.created_by.clone());
+        if self.user_repo.find_by_id(&fallback_reviewer).await?.is_none_or(|u| u.status.is_deleted()) {
+            return Err(AppError::NotFound(format!("User not found: {}", fallback_reviewer)));
+        }
+
+        let mut scopes = Vec::new();
+        for role_id in cmd.role_ids {
+            if !self.role_repo.exists_by_id(&role_id).await? {
+                return Err(AppError::NotFound(format!("Role not found: {}", role_id)));
+            }
+            let holders =
+                self.user_role_repo.find_users_by_role_id(&role_id).await?.into_iter().map(|u| u.id).collect();
+            let mut reviewers = self.approver_repo.find_approver_ids(&role_id).await?;
+            reviewers.push(fallback_reviewer.clone());
+            scopes.push(ReviewScope { role_id, holders, reviewers });
+        }
+
+        let aggregate = AccessReviewAggregate::launch(cmd.name, cmd.created_by, cmd.deadline, scopes)?;
+        self.review_repo.create(&aggregate).await?;
+        self.notify_reviewers(&aggregate).await;
+        Ok(aggregate)
+    }
+
+    async fn decide_item(&self, cmd: DecideAccessReviewItemCommand) -> AppResult<()> {
+        let mut aggregate = self.find_aggregate(&cmd.review_id).await?;
+        let item = aggregate.decide(&cmd.item_id, &cmd.reviewer_id, cmd.decision, cmd.comment)?.clone();
+        if item.decision.is_revoked() {
+            self.revoke(&item, Some(cmd.reviewer_id)).await?;
+        }
+        self.review_repo.save(&aggregate).await
+    }
+
+    async fn close_due_reviews(&self, _cmd: CloseDueAccessReviewsCommand) -> AppResult<u64> {
+        let now = Utc::now().timestamp();
+        let mut completed = 0;
+        for aggregate in self.review_repo.find_due(now).await? {
+            let id = aggregate.campaign.id.clone();
+            match self.close_one(aggregate, now).await {
+                Ok(true) => completed += 1,
+                Ok(false) => {}
+                Err(e) => tracing::warn!("Failed to close access review {}: {}", id, e),
+            }
+        }
+        Ok(completed)
+    }
+
+    async fn get_review_by_id(&self, query: GetAccessReviewByIdQuery) -> AppResult<AccessReviewAggregate> {
+        self.find_aggregate(&query.id).await
+    }
+
+    async fn list_reviews(&self, query: ListAccessReviewsQuery) -> AppResult<PaginatedResult<AccessReviewAggregate>> {
+        let (limit, offset) = query.pagination();
+        let reviewer_id = match query.scope {
+            AccessReviewScope::Assigned => Some(&query.actor_id),
+            AccessReviewScope::All => None,
+        };
+        let (items, total) = self.review_repo.search(reviewer_id, query.status, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn list_items(&self, query: ListAccessReviewItemsQuery) -> AppResult<Vec<AccessReviewItem>> {
+        let aggregate = self.find_aggregate(&query.id).await?;
+        Ok(aggregate
+            .items
+            .into_iter()
+            .filter(|i| query.reviewer_id.as_ref().is_none_or(|r| &i.reviewer_id == r))
+            .filter(|i| query.decision.is_none_or(|d| i.decision == d))
+            .collect())
+    }
+
+    async fn get_report(&self, query: GetAccessReviewReportQuery) -> AppResult<AccessReviewReport> {
+        let aggregate = self.find_aggregate(&query.id).await?;
+
+        let mut user_ids: Vec<UserId> = Vec::new();
+        for item in &aggregate.items {
+            for id in [&item.user_id, &item.reviewer_id] {
+                if !user_ids.contains(id) {
+                    user_ids.push(id.clone());
+                }
+            }
+        }
+        let usernames: HashMap<UserId, String> = if user_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.user_repo
+                .find_by_ids(&user_ids)
+                .await?
+                .into_iter()
+                .map(|u| (u.id, u.username.value().to_string()))
+                .collect()
+        };
+        let role_names: HashMap<String, String> = self
+            .role_repo
+            .find_by_ids(&aggregate.campaign.role_ids)
+            .await?
+            .into_iter()
+            .map(|r| (r.id.value().to_string(), r.name.value().to_string()))
+            .collect();
+
+        // 已删除的用户或角色在报告中保留ID，名称留空
+        let rows = aggregate
+            .items
+            .into_iter()
+            .map(|item| AccessReviewReportRow {
+                item_id: item.id.value().to_string(),
+                username: usernames.get(&item.user_id).cloned().unwrap_or_default(),
+                user_id: item.user_id.value().to_string(),
+                role_name: role_names.get(item.role_id.value()).cloned().unwrap_or_default(),
+                role_id: item.role_id.value().to_string(),
+                reviewer_username: usernames.get(&item.reviewer_id).cloned().unwrap_or_default(),
+                reviewer_id: item.reviewer_id.value().to_string(),
+                decision: item.decision,
+                comment: item.comment,
+                decided_at: item.decided_at,
+            })
+            .collect();
+        Ok(AccessReviewReport { campaign: aggregate.campaign, rows })
+    }
+}
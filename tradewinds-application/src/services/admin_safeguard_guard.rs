@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use tradewinds_domain::policies::{AdminSafeguardPolicy, SUPER_ADMIN_ROLE_CODE};
+use tradewinds_domain::repositories::{RoleRepository, UserRepository, UserRoleRepository};
+use tradewinds_domain::value_objects::role::RoleCode;
+use tradewinds_domain::value_objects::{RoleId, UserId};
+use tradewinds_error::AppResult;
+
+/// 最后管理员保护
+///
+/// 在删除用户、禁用用户、撤销角色以及用户或用户组角色变更落库前调用，
+/// 以变更后的结果执行 AdminSafeguardPolicy。
+#[derive(Clone)]
+pub(crate) struct AdminSafeguardGuard {
+    role_repo: Arc<dyn RoleRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+}
+
+impl AdminSafeguardGuard {
+    pub(crate) fn new(
+        role_repo: Arc<dyn RoleRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+    ) -> Self {
+        Self { role_repo, user_repo, user_role_repo }
+    }
+
+    /// 校验一批用户变更后仍保留启用的超级管理员
+    ///
+    /// `changes` 中每一项为用户及其变更后的全部有效角色，`None` 表示用户被删除或禁用
+    pub(crate) async fn check(&self, changes: &[(UserId, Option<Vec<RoleId>>)]) -> AppResult<()> {
+        let Some(super_admin) = self.role_repo.find_by_code(&RoleCode::new(SUPER_ADMIN_ROLE_CODE.into())?).await?
+        else {
+            return Ok(());
+        };
+
+        let losing: Vec<UserId> = changes
+            .iter()
+            .filter(|(_, roles)| !roles.as_ref().is_some_and(|roles| roles.contains(&super_admin.id)))
+            .map(|(user_id, _)| user_id.clone())
+            .collect();
+        if losing.is_empty() {
+            return Ok(());
+        }
+
+        let holder_ids = self.user_role_repo.find_holder_ids_by_role_id(&super_admin.id).await?;
+        let active_holders: Vec<UserId> = self
+            .user_repo
+            .find_by_ids(&holder_ids)
+            .await?
+            .into_iter()
+            .filter(|user| user.status.is_active())
+            .map(|user| user.id)
+            .collect();
+        AdminSafeguardPolicy::check_remaining_super_admins(&active_holders, &losing)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::interfaces::IAuditChainService;
+use crate::queries::audit_log::{AuditChainReport, VerifyAuditChainQuery};
+use tradewinds_common::tenant::{current_tenant_id, with_tenant};
+use tradewinds_domain::entities::{audit_checkpoint::AuditCheckpoint, audit_log::AuditLog};
+use tradewinds_domain::repositories::AuditLogRepository;
+use tradewinds_domain::services::audit::{AuditChainBreak, AuditChainSummary, AuditChainVerifier, AuditSigner};
+
+use chrono::Utc;
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 校验时每批读取的记录数
+const VERIFY_BATCH_SIZE: u64 = 500;
+
+/// 审计哈希链服务
+///
+/// 每追加 `checkpoint_interval` 条记录对链尾签名一次；为 0 时不生成检查点。
+/// 最近一个检查点之后的记录只受哈希链保护，能发现单条修改，但无法发现整段重新计算。
+#[derive(Clone)]
+pub struct AuditChainService {
+    audit_log_repo: Arc<dyn AuditLogRepository>,
+    signer: Arc<dyn AuditSigner>,
+    checkpoint_interval: u64,
+}
+
+impl AuditChainService {
+    pub fn new(
+        audit_log_repo: Arc<dyn AuditLogRepository>,
+        signer: Arc<dyn AuditSigner>,
+        checkpoint_interval: u64,
+    ) -> Self {
+        Self { audit_log_repo, signer, checkpoint_interval }
+    }
+
+    /// 校验当前租户的哈希链
+    async fn verify_tenant(&self, tenant_id: &str) -> AppResult<Result<AuditChainSummary, AuditChainBreak>> {
+        let checkpoints = self.audit_log_repo.find_checkpoints().await?;
+        let mut verifier = AuditChainVerifier::new(tenant_id, checkpoints, self.signer.as_ref());
+        let mut after_seq = 0;
+        loop {
+            let batch = self.audit_log_repo.find_chain(after_seq, VERIFY_BATCH_SIZE).await?;
+            let Some(last) = batch.last() else {
+                break;
+            };
+            after_seq = last.seq;
+            for log in &batch {
+                if let Err(broken) = verifier.check(log) {
+                    return Ok(Err(broken));
+                }
+            }
+        }
+        Ok(verifier.finish())
+    }
+}
+
+#[async_trait::async_trait]
+impl IAuditChainService for AuditChainService {
+    async fn append(&self, log: AuditLog) -> AppResult<AuditLog> {
+        let log = self.audit_log_repo.append(log).await?;
+        if self.checkpoint_interval > 0 && log.seq % self.checkpoint_interval == 0 {
+            let payload = AuditCheckpoint::payload(&current_tenant_id(), log.seq, &log.hash);
+            let checkpoint = AuditCheckpoint {
+                seq: log.seq,
+                hash: log.hash.clone(),
+                signature: self.signer.sign(&payload),
+                created_at: Utc::now().timestamp(),
+            };
+            self.audit_log_repo.create_checkpoint(&checkpoint).await?;
+        }
+        Ok(log)
+    }
+
+    async fn verify_chain(&self, query: VerifyAuditChainQuery) -> AppResult<Vec<AuditChainReport>> {
+        let tenant_ids = match query.tenant_id {
+            Some(tenant_id) => vec![tenant_id],
+            None => self.audit_log_repo.find_tenant_ids().await?,
+        };
+        let mut reports = Vec::with_capacity(tenant_ids.len());
+        for tenant_id in tenant_ids {
+            let result = with_tenant(tenant_id.clone(), self.verify_tenant(&tenant_id)).await?;
+            reports.push(AuditChainReport { tenant_id, result });
+        }
+        Ok(reports)
+    }
+}
@@ -0,0 +1,300 @@
+use crate::audit::{AuditTarget, AuditTargetType};
+use crate::interfaces::{IAuditChainService, IAuditLogService};
+use crate::queries::audit_log::{ExportAuditLogsQuery, ListAuditLogsQuery};
+use crate::services::settings::Settings;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::audit_log::AuditLog;
+use tradewinds_domain::repositories::{
+    AccessPolicyRepository, AccessRequestRepository, AccessReviewRepository, AuditLogRepository, DepartmentRepository,
+    FeatureFlagRepository, GroupAggregateRepository, OutboxRepository, PermissionRepository, RoleRepository,
+    ScheduledJobRepository, SystemSettingRepository, TenantRepository, UserRepository, UserRoleRepository,
+    WebhookRepository,
+};
+use tradewinds_domain::value_objects::{
+    AccessPolicyId, AccessRequestId, AccessReviewId, DepartmentId, GroupId, OutboxMessageId, PermissionId, RoleId,
+    TenantId, UserId, WebhookId, system_setting::SettingRegistry,
+};
+
+use serde_json::{Value, json};
+use std::str::FromStr;
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 快照中不参与比较的字段（每次修改都会变化）
+const VOLATILE_FIELDS: [&str; 2] = ["created_at", "updated_at"];
+
+/// 审计日志服务
+///
+/// 日志经由审计哈希链服务写入。对象快照只包含业务字段：用户快照不含密码，敏感系统设置的值以占位值代替。
+#[derive(Clone)]
+pub struct AuditLogService {
+    audit_log_repo: Arc<dyn AuditLogRepository>,
+    audit_chain_service: Arc<dyn IAuditChainService>,
+    user_repo: Arc<dyn UserRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    group_agg_repo: Arc<dyn GroupAggregateRepository>,
+    department_repo: Arc<dyn DepartmentRepository>,
+    tenant_repo: Arc<dyn TenantRepository>,
+    access_policy_repo: Arc<dyn AccessPolicyRepository>,
+    access_request_repo: Arc<dyn AccessRequestRepository>,
+    access_review_repo: Arc<dyn AccessReviewRepository>,
+    feature_flag_repo: Arc<dyn FeatureFlagRepository>,
+    webhook_repo: Arc<dyn WebhookRepository>,
+    job_repo: Arc<dyn ScheduledJobRepository>,
+    outbox_repo: Arc<dyn OutboxRepository>,
+    settings: Settings,
+}
+
+/// 对操作对象取快照所用的仓储
+pub struct AuditSnapshotRepos {
+    pub user_repo: Arc<dyn UserRepository>,
+    pub user_role_repo: Arc<dyn UserRoleRepository>,
+    pub role_repo: Arc<dyn RoleRepository>,
+    pub permission_repo: Arc<dyn PermissionRepository>,
+    pub system_setting_repo: Arc<dyn SystemSettingRepository>,
+    pub group_agg_repo: Arc<dyn GroupAggregateRepository>,
+    pub department_repo: Arc<dyn DepartmentRepository>,
+    pub tenant_repo: Arc<dyn TenantRepository>,
+    pub access_policy_repo: Arc<dyn AccessPolicyRepository>,
+    pub access_request_repo: Arc<dyn AccessRequestRepository>,
+    pub access_review_repo: Arc<dyn AccessReviewRepository>,
+    pub feature_flag_repo: Arc<dyn FeatureFlagRepository>,
+    pub webhook_repo: Arc<dyn WebhookRepository>,
+    pub job_repo: Arc<dyn ScheduledJobRepository>,
+    pub outbox_repo: Arc<dyn OutboxRepository>,
+}
+
+impl AuditLogService {
+    pub fn new(
+        audit_log_repo: Arc<dyn AuditLogRepository>,
+        audit_chain_service: Arc<dyn IAuditChainService>,
+        repos: AuditSnapshotRepos,
+    ) -> Self {
+        let AuditSnapshotRepos {
+            user_repo,
+            user_role_repo,
+            role_repo,
+            permission_repo,
+            system_setting_repo,
+            group_agg_repo,
+            department_repo,
+            tenant_repo,
+            access_policy_repo,
+            access_request_repo,
+            access_review_repo,
+            feature_flag_repo,
+            webhook_repo,
+            job_repo,
+            outbox_repo,
+        } = repos;
+        Self {
+            audit_log_repo,
+            audit_chain_service,
+            user_repo,
+            user_role_repo,
+            role_repo,
+            permission_repo,
+            group_agg_repo,
+            department_repo,
+            tenant_repo,
+            access_policy_repo,
+            access_request_repo,
+            access_review_repo,
+            feature_flag_repo,
+            webhook_repo,
+            job_repo,
+            outbox_repo,
+            settings: Settings::new(system_setting_repo),
+        }
+    }
+
+    async fn user_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = UserId::from_str(id)?;
+        let Some(user) = self.user_repo.find_by_id(&id).await? else {
+            return Ok(None);
+        };
+        let mut role_ids: Vec<String> =
+            self.user_role_repo.find_by_user_id(&id).await?.into_iter().map(|ur| ur.role_id.to_string()).collect();
+        role_ids.sort();
+        Ok(Some(json!({
+            "username": user.username,
+            "email": user.email,
+            "real_name": user.real_name,
+            "phone": user.phone,
+            "avatar": user.avatar,
+            "status": user.status,
+            "department_id": user.department_id,
+            "role_ids": role_ids,
+        })))
+    }
+
+    async fn role_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = RoleId::from_str(id)?;
+        let Some((role, permission_ids)) = self.role_repo.find_with_permissions(&id).await? else {
+            return Ok(None);
+        };
+        let mut permission_ids: Vec<String> = permission_ids.iter().map(|id| id.value().to_string()).collect();
+        permission_ids.sort();
+        let mut snapshot = to_snapshot(&role)?;
+        snapshot.insert("permission_ids".to_string(), json!(permission_ids));
+        Ok(Some(Value::Object(snapshot)))
+    }
+
+    async fn permission_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = PermissionId::from_str(id)?;
+        let Some(permission) = self.permission_repo.find_by_id(&id).await? else {
+            return Ok(None);
+        };
+        Ok(Some(Value::Object(to_snapshot(&permission)?)))
+    }
+
+    async fn system_setting_snapshot(&self, key: &str) -> AppResult<Option<Value>> {
+        if SettingRegistry::find(key).is_none() {
+            return Ok(None);
+        }
+        let setting = self.settings.effective(key).await?;
+        Ok(Some(json!({ "value": setting.display_value(), "description": setting.definition.description })))
+    }
+
+    async fn group_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = GroupId::from_str(id)?;
+        let Some(aggregate) = self.group_agg_repo.find_by_id(&id).await? else {
+            return Ok(None);
+        };
+        let mut member_ids: Vec<String> = aggregate.members.iter().map(|id| id.to_string()).collect();
+        member_ids.sort();
+        let mut role_ids: Vec<String> = aggregate.roles.iter().map(|id| id.to_string()).collect();
+        role_ids.sort();
+        let mut snapshot = to_snapshot(&aggregate.group)?;
+        snapshot.insert("member_ids".to_string(), json!(member_ids));
+        snapshot.insert("role_ids".to_string(), json!(role_ids));
+        Ok(Some(Value::Object(snapshot)))
+    }
+
+    async fn department_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = DepartmentId::from_str(id)?;
+        entity_snapshot(self.department_repo.find_by_id(&id).await?)
+    }
+
+    async fn tenant_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = TenantId::from_str(id)?;
+        entity_snapshot(self.tenant_repo.find_by_id(&id).await?)
+    }
+
+    async fn access_policy_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = AccessPolicyId::from_str(id)?;
+        entity_snapshot(self.access_policy_repo.find_by_id(&id).await?)
+    }
+
+    async fn access_request_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = AccessRequestId::from_str(id)?;
+        entity_snapshot(self.access_request_repo.find_by_id(&id).await?)
+    }
+
+    /// 复核活动快照，复核项只记录结论，以便比较出逐项确认或收回
+    async fn access_review_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = AccessReviewId::from_str(id)?;
+        let Some(aggregate) = self.access_review_repo.find_by_id(&id).await? else {
+            return Ok(None);
+        };
+        let decisions: serde_json::Map<String, Value> = aggregate
+            .items
+            .iter()
+            .map(|item| (item.id.to_string(), json!({ "decision": item.decision, "comment": item.comment })))
+            .collect();
+        let mut snapshot = to_snapshot(&aggregate.campaign)?;
+        snapshot.insert("decisions".to_string(), Value::Object(decisions));
+        Ok(Some(Value::Object(snapshot)))
+    }
+
+    async fn feature_flag_snapshot(&self, key: &str) -> AppResult<Option<Value>> {
+        entity_snapshot(self.feature_flag_repo.find_by_key(key).await?)
+    }
+
+    async fn webhook_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = WebhookId::from_str(id)?;
+        entity_snapshot(self.webhook_repo.find_by_id(&id).await?)
+    }
+
+    /// 任务快照只含可由管理员变更的状态，执行时间由调度器维护
+    async fn job_snapshot(&self, name: &str) -> AppResult<Option<Value>> {
+        let Some(job) = self.job_repo.find_by_name(name).await? else {
+            return Ok(None);
+        };
+        Ok(Some(json!({ "cron": job.cron, "paused": job.paused })))
+    }
+
+    async fn outbox_message_snapshot(&self, id: &str) -> AppResult<Option<Value>> {
+        let id = OutboxMessageId::from_str(id)?;
+        let Some(message) = self.outbox_repo.find_by_id(&id).await? else {
+            return Ok(None);
+        };
+        Ok(Some(json!({ "status": message.status, "attempts": message.attempts, "last_error": message.last_error })))
+    }
+}
+
+/// 实体快照，实体不存在时为空
+fn entity_snapshot<T: serde::Serialize>(entity: Option<T>) -> AppResult<Option<Value>> {
+    entity.map(|entity| to_snapshot(&entity).map(Value::Object)).transpose()
+}
+
+/// 将实体序列化为快照，去掉时间戳字段并脱敏
+fn to_snapshot<T: serde::Serialize>(entity: &T) -> AppResult<serde_json::Map<String, Value>> {
+    let value = serde_json::to_value(entity).map_err(|e| AppError::Internal(e.to_string()))?;
+    let Value::Object(mut map) = AuditLog::redact(value) else {
+        return Err(AppError::Internal("Audit snapshot must be a JSON object".into()));
+    };
+    for field in VOLATILE_FIELDS {
+        map.remove(field);
+    }
+    Ok(map)
+}
+
+#[async_trait::async_trait]
+impl IAuditLogService for AuditLogService {
+    async fn snapshot(&self, target: &AuditTarget) -> AppResult<Option<Value>> {
+        let Some(id) = target.target_id.as_deref() else {
+            return Ok(None);
+        };
+        match target.target_type {
+            AuditTargetType::User => self.user_snapshot(id).await,
+            AuditTargetType::Role => self.role_snapshot(id).await,
+            AuditTargetType::Permission => self.permission_snapshot(id).await,
+            AuditTargetType::SystemSetting => self.system_setting_snapshot(id).await,
+            AuditTargetType::Group => self.group_snapshot(id).await,
+            AuditTargetType::Department => self.department_snapshot(id).await,
+            AuditTargetType::Tenant => self.tenant_snapshot(id).await,
+            AuditTargetType::AccessPolicy => self.access_policy_snapshot(id).await,
+            AuditTargetType::AccessRequest => self.access_request_snapshot(id).await,
+            AuditTargetType::AccessReview => self.access_review_snapshot(id).await,
+            AuditTargetType::FeatureFlag => self.feature_flag_snapshot(id).await,
+            AuditTargetType::Webhook => self.webhook_snapshot(id).await,
+            AuditTargetType::Job => self.job_snapshot(id).await,
+            AuditTargetType::OutboxMessage => self.outbox_message_snapshot(id).await,
+            // 投递记录与登录日志只追加不修改，无需比较
+            AuditTargetType::WebhookDelivery | AuditTargetType::LoginLog => Ok(None),
+        }
+    }
+
+    async fn record(&self, mut log: AuditLog) -> AppResult<()> {
+        if let (None, Some(actor_id)) = (&log.actor_name, &log.actor_id) {
+            log.actor_name = self.user_repo.find_by_id(actor_id).await?.map(|user| user.username.to_string());
+        }
+        self.audit_chain_service.append(log).await?;
+        Ok(())
+    }
+
+    async fn list_logs(&self, query: ListAuditLogsQuery) -> AppResult<PaginatedResult<AuditLog>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) = self.audit_log_repo.search(&query.filter, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn export_logs(&self, query: ExportAuditLogsQuery) -> AppResult<Vec<AuditLog>> {
+        let max_rows = self.settings.audit_export_max_rows().await?;
+        let (items, _) = self.audit_log_repo.search(&query.filter, max_rows, 0).await?;
+        Ok(items)
+    }
+}
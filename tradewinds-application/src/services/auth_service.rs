@@ -1,13 +1,25 @@
 use crate::{
-    commands::auth::*, interfaces::auth_service::IAuthService, queries::auth::user_info::CurrentUserInfo,
+    commands::auth::*,
+    events::{UserLoggedInEvent, UserLoggedOutEvent, UserPasswordChangedEvent, UserRegisteredEvent},
+    interfaces::auth_service::IAuthService,
+    queries::auth::user_info::CurrentUserInfo,
     queries::auth::*,
 };
+use chrono::{Datelike, Duration, NaiveTime, Utc};
 use std::sync::Arc;
+use tradewinds_common::{PaginatedResult, request_context::current_request_context};
 use tradewinds_domain::{
-    aggregates::user_aggregate::UserAggregate,
-    repositories::{PermissionRepository, RoleRepository, UserAggregateRepository, UserRepository, UserRoleRepository},
+    aggregates::user_aggregate::{USER_AGGREGATE_TYPE, UserAggregate},
+    entities::{login_log::LoginLog, outbox_message::OutboxMessage, user::User},
+    policies::PermissionPolicy,
+    repositories::{
+        LoginLogFilter, LoginLogRepository, OutboxRepository, PermissionRepository, RoleRepository,
+        UserAggregateRepository, UserRepository, UserRoleRepository, UserSearchFilter,
+    },
     services::auth::{PasswordService, TokenService},
+    value_objects::RoleAssignment,
     value_objects::auth::{auth_password::Password, auth_token::Token},
+    value_objects::login_log::{LoginFailureReason, LoginMethod},
 };
 use tradewinds_error::{AppError, AppResult};
 
@@ -18,21 +30,83 @@ pub struct AuthService {
     permission_repo: Arc<dyn PermissionRepository>,
     user_role_repo: Arc<dyn UserRoleRepository>,
     user_agg_repo: Arc<dyn UserAggregateRepository>,
+    login_log_repo: Arc<dyn LoginLogRepository>,
     token_service: Arc<dyn TokenService>,
     password_service: Arc<dyn PasswordService>,
+    outbox_repo: Arc<dyn OutboxRepository>,
+}
+
+/// 认证服务的依赖
+pub struct AuthServiceDeps {
+    pub user_repo: Arc<dyn UserRepository>,
+    pub role_repo: Arc<dyn RoleRepository>,
+    pub permission_repo: Arc<dyn PermissionRepository>,
+    pub user_role_repo: Arc<dyn UserRoleRepository>,
+    pub user_agg_repo: Arc<dyn UserAggregateRepository>,
+    pub login_log_repo: Arc<dyn LoginLogRepository>,
+    pub token_service: Arc<dyn TokenService>,
+    pub password_service: Arc<dyn PasswordService>,
+    pub outbox_repo: Arc<dyn OutboxRepository>,
 }
 
 impl AuthService {
-    pub fn new(
-        user_repo: Arc<dyn UserRepository>,
-        role_repo: Arc<dyn RoleRepository>,
-        permission_repo: Arc<dyn PermissionRepository>,
-        user_role_repo: Arc<dyn UserRoleRepository>,
-        user_agg_repo: Arc<dyn UserAggregateRepository>,
-        token_service: Arc<dyn TokenService>,
-        password_service: Arc<dyn PasswordService>,
-    ) -> Self {
-        Self { user_repo, role_repo, permission_repo, user_role_repo, user_agg_repo, token_service, password_service }
+    pub fn new(deps: AuthServiceDeps) -> Self {
+        let AuthServiceDeps {
+            user_repo,
+            role_repo,
+            permission_repo,
+            user_role_repo,
+            user_agg_repo,
+            login_log_repo,
+            token_service,
+            password_service,
+            outbox_repo,
+        } = deps;
+        Self {
+            user_repo,
+            role_repo,
+            permission_repo,
+            user_role_repo,
+            user_agg_repo,
+            login_log_repo,
+            token_service,
+            password_service,
+            outbox_repo,
+        }
+    }
+
+    /// 记录失败的登录尝试；登录日志写入失败不影响登录结果
+    async fn record_failure(&self, username: &str, user: Option<&User>, reason: LoginFailureReason) {
+        let context = current_request_context();
+        let log =
+            LoginLog::failed(username, user, LoginMethod::Password, reason, context.client_ip, context.user_agent);
+        if let Err(e) = self.login_log_repo.create(&log).await {
+            tracing::warn!("Failed to record login attempt of {}: {}", username, e);
+        }
+    }
+
+    /// 记录成功登录：写入登录日志、更新用户最近登录信息并发出登录事件
+    async fn record_success(&self, mut user: User) {
+        let context = current_request_context();
+        let log = LoginLog::succeeded(&user, LoginMethod::Password, context.client_ip.clone(), context.user_agent);
+        if let Err(e) = self.login_log_repo.create(&log).await {
+            tracing::warn!("Failed to record login of {}: {}", user.id, e);
+        }
+        user.record_login(context.client_ip.clone());
+        let event = UserLoggedInEvent::new(user.id.value(), user.username.value(), context.client_ip.as_deref());
+        let result = match OutboxMessage::from_event(USER_AGGREGATE_TYPE, user.id.value(), &event) {
+            Ok(message) => self.user_repo.update_last_login(&user, &[message]).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to update last login of {}: {}", user.id, e);
+        }
+    }
+
+    /// 按条件统计未删除用户数
+    async fn count_users(&self, filter: UserSearchFilter) -> AppResult<u64> {
+        let (_, total) = self.user_repo.search(&filter, 1, 0).await?;
+        Ok(total)
     }
 }
 
@@ -55,7 +129,7 @@ impl IAuthService for AuthService {
         let hashed_password = Password::new(hashed_password)?;
 
         // 创建用户聚合
-        let user_agg = UserAggregate::create(
+        let mut user_agg = UserAggregate::create(
             cmd.username,
             cmd.email,
             hashed_password,
@@ -64,6 +138,10 @@ impl IAuthService for AuthService {
             None, // No avatar on registration
         )?;
 
+        let user = &user_agg.user;
+        let event = UserRegisteredEvent::new(user.id.value(), user.username.value(), user.email.value());
+        user_agg.record_event(&event)?;
+
         // 用户聚合落库
         self.user_agg_repo.create(&user_agg).await
     }
@@ -74,22 +152,30 @@ impl IAuthService for AuthService {
         let invalid_cred = || AppError::Validation("Invalid username or password".into());
 
         // 查询用户
-        let user = self.user_repo.find_by_username(&cmd.username).await?.ok_or_else(&invalid_cred)?;
+        let Some(user) = self.user_repo.find_by_username(&cmd.username).await? else {
+            self.record_failure(cmd.username.value(), None, LoginFailureReason::UserNotFound).await;
+            return Err(invalid_cred());
+        };
 
         // 新增：检查用户状态
         if !user.status.is_active() {
+            self.record_failure(cmd.username.value(), Some(&user), LoginFailureReason::UserInactive).await;
             return Err(AppError::Validation("用户未启用或已被禁用/删除，无法登录".into()));
         }
 
         // 验证密码
         let valid = self.password_service.verify(&user.password.value(), cmd.password.value()).await.unwrap_or(false);
         if !valid {
+            self.record_failure(cmd.username.value(), Some(&user), LoginFailureReason::InvalidPassword).await;
             return Err(invalid_cred());
         }
 
         // 生成令牌
         let token = self.token_service.generate(&user.id).await?;
 
+        // 记录登录
+        self.record_success(user).await;
+
         Ok(token)
     }
 
@@ -101,8 +187,14 @@ impl IAuthService for AuthService {
         // 拉黑令牌
         self.token_service.revoke(&cmd.token).await?;
 
-        // 可选：记录登出事件
-        // self.event_bus.publish(UserLoggedOutEvent { user_id: token_data.user_id }).await?;
+        // 登出事件写入发件箱，令牌已拉黑，写入失败仅记录告警
+        let username =
+            self.user_repo.find_by_id(&token_data.user_id).await.ok().flatten().map(|u| u.username.value().to_string());
+        let event = UserLoggedOutEvent::new(token_data.user_id.value(), username.as_deref().unwrap_or_default());
+        let message = OutboxMessage::from_event(USER_AGGREGATE_TYPE, token_data.user_id.value(), &event)?;
+        if let Err(e) = self.outbox_repo.append(&[message]).await {
+            tracing::warn!("Failed to record logout of {}: {}", token_data.user_id, e);
+        }
 
         Ok(())
     }
@@ -129,12 +221,11 @@ impl IAuthService for AuthService {
         let new_hashed_password = self.password_service.hash(cmd.new_password.value()).await?;
         user_agg.user.password = Password::new(new_hashed_password)?;
 
+        user_agg.record_event(&UserPasswordChangedEvent::new(user_agg.user.id.value()))?;
+
         // 保存用户聚合
         self.user_agg_repo.save(&user_agg).await?;
 
-        // 可选：记录密码修改事件
-        // self.event_bus.publish(UserPasswordChangedEvent { user_id: user_agg.user.id.clone() }).await?;
-
         Ok(())
     }
 
@@ -150,13 +241,15 @@ impl IAuthService for AuthService {
             .await?
             .ok_or_else(|| AppError::NotFound(format!("User not found: {}", claims.user_id)))?;
 
-        // 查询角色
-        let roles = self.user_role_repo.find_by_user_id(&user.id).await?;
-        let role_ids = roles.iter().map(|r| r.role_id.clone()).collect::<Vec<_>>();
+        // 查询角色：直接分配的角色加上所在用户组携带的角色
+        let assignments = self.user_role_repo.find_assignments_by_user_id(&user.id).await?;
+        let role_ids = RoleAssignment::effective_role_ids(&assignments);
         let roles = self.role_repo.find_by_ids(&role_ids).await?;
 
-        // 查询权限
-        let permissions = self.permission_repo.find_by_user_id(&user.id).await?;
+        // 查询权限：按权限策略展开通配，剔除被拒绝、未启用角色授予、未启用或上级菜单未启用的权限
+        let grants = self.role_repo.find_permission_grants(&role_ids).await?;
+        let catalog = self.permission_repo.find_all().await?;
+        let permissions = PermissionPolicy::effective_permissions(&catalog, &roles, &grants);
 
         Ok(CurrentUserInfo {
             user: user.into(),
@@ -164,4 +257,46 @@ impl IAuthService for AuthService {
             permissions: permissions.into_iter().map(Into::into).collect(),
         })
     }
+
+    /// 获取当前用户的登录历史
+    async fn get_login_history(&self, query: GetLoginHistoryQuery) -> AppResult<PaginatedResult<LoginLog>> {
+        // 验证令牌
+        let claims = self.token_service.validate(&query.token).await?;
+
+        let (limit, offset) = query.pagination();
+        let filter = LoginLogFilter { user_id: Some(claims.user_id), ..Default::default() };
+        let (items, total) = self.login_log_repo.search(&filter, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn get_dashboard_stats(&self, _query: GetDashboardStatsQuery) -> AppResult<DashboardStats> {
+        let now = Utc::now();
+        let today = now.date_naive();
+        let day_start = today.and_time(NaiveTime::MIN).and_utc().timestamp();
+        let week_start = day_start - Duration::days(today.weekday().num_days_from_monday() as i64).num_seconds();
+        let month_start = today.with_day(1).unwrap_or(today).and_time(NaiveTime::MIN).and_utc().timestamp();
+
+        let (_, total_roles) = self.role_repo.search(None, None, None, None, 1, 0).await?;
+        let (_, total_permissions) = self.permission_repo.search(None, None, None, None, None, 1, 0).await?;
+        Ok(DashboardStats {
+            total_users: self.count_users(UserSearchFilter::default()).await?,
+            active_users: self
+                .count_users(UserSearchFilter {
+                    last_login_since: Some((now - Duration::hours(24)).timestamp()),
+                    ..Default::default()
+                })
+                .await?,
+            total_roles,
+            total_permissions,
+            new_users_today: self
+                .count_users(UserSearchFilter { created_since: Some(day_start), ..Default::default() })
+                .await?,
+            new_users_this_week: self
+                .count_users(UserSearchFilter { created_since: Some(week_start), ..Default::default() })
+                .await?,
+            new_users_this_month: self
+                .count_users(UserSearchFilter { created_since: Some(month_start), ..Default::default() })
+                .await?,
+        })
+    }
 }
@@ -0,0 +1,153 @@
+use crate::commands::department::{CreateDepartmentCommand, DeleteDepartmentCommand, UpdateDepartmentCommand};
+use crate::events::{DepartmentCreatedEvent, DepartmentDeletedEvent, DepartmentUpdatedEvent};
+use crate::interfaces::IDepartmentService;
+use crate::queries::department::{GetDepartmentByIdQuery, ListDepartmentsQuery};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::department_aggregate::DepartmentAggregate;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_domain::repositories::{DepartmentAggregateRepository, DepartmentRepository, UserRepository};
+use tradewinds_domain::value_objects::{DepartmentId, DepartmentName, UserId};
+
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct DepartmentService {
+    department_repo: Arc<dyn DepartmentRepository>,
+    department_agg_repo: Arc<dyn DepartmentAggregateRepository>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl DepartmentService {
+    pub fn new(
+        department_repo: Arc<dyn DepartmentRepository>,
+        department_agg_repo: Arc<dyn DepartmentAggregateRepository>,
+        user_repo: Arc<dyn UserRepository>,
+    ) -> Self {
+        Self { department_repo, department_agg_repo, user_repo }
+    }
+
+    async fn find_parent(&self, parent_id: &DepartmentId) -> AppResult<Department> {
+        self.department_repo
+            .find_by_id(parent_id)
+            .await?
+            .filter(|d| !d.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound(format!("Parent department {} not found", parent_id)))
+    }
+
+    /// 负责人必须是存在且未删除的用户
+    async fn ensure_leader_exists(&self, leader_id: &UserId) -> AppResult<()> {
+        match self.user_repo.find_by_id(leader_id).await? {
+            Some(user) if !user.is_deleted() => Ok(()),
+            _ => Err(AppError::NotFound(format!("Leader user {} not found", leader_id))),
+        }
+    }
+
+    /// 同一上级部门下名称不可重复
+    async fn ensure_name_available(
+        &self,
+        parent_id: Option<&DepartmentId>,
+        name: &DepartmentName,
+        current: Option<&DepartmentId>,
+    ) -> AppResult<()> {
+        if let Some(existing) = self.department_repo.find_by_name(parent_id, name).await?
+            && Some(&existing.id) != current
+        {
+            return Err(AppError::Conflict(format!("Department name already exists: {}", name)));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl IDepartmentService for DepartmentService {
+    async fn create_department(&self, cmd: CreateDepartmentCommand) -> AppResult<Department> {
+        let parent = match &cmd.parent_id {
+            Some(parent_id) => Some(self.find_parent(parent_id).await?),
+            None => None,
+        };
+        if let Some(leader_id) = &cmd.leader_id {
+            self.ensure_leader_exists(leader_id).await?;
+        }
+        self.ensure_name_available(cmd.parent_id.as_ref(), &cmd.name, None).await?;
+
+        let mut department_agg = DepartmentAggregate::create(cmd.name, parent.as_ref(), cmd.sort, cmd.leader_id)?;
+        let department = &department_agg.department;
+        let created_by = cmd.created_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = DepartmentCreatedEvent::new(department.id.value(), department.name.value(), created_by);
+        department_agg.record_event(&event)?;
+        self.department_agg_repo.create(&department_agg).await?;
+
+        Ok(department_agg.department)
+    }
+
+    async fn update_department(&self, cmd: UpdateDepartmentCommand) -> AppResult<()> {
+        let mut department_agg = self
+            .department_agg_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .filter(|agg| !agg.department.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("Department not found".into()))?;
+
+        if let Some(Some(leader_id)) = &cmd.leader_id {
+            self.ensure_leader_exists(leader_id).await?;
+        }
+
+        // 调整上级部门：需排除自身及后代，防止形成环
+        if let Some(parent_id) = &cmd.parent_id {
+            let parent = match parent_id {
+                Some(parent_id) => Some(self.find_parent(parent_id).await?),
+                None => None,
+            };
+            let descendants = self.department_repo.find_descendant_ids(&cmd.id).await?;
+            department_agg.move_to(parent.as_ref(), &descendants)?;
+        }
+
+        let name = cmd.name.as_ref().unwrap_or(&department_agg.department.name);
+        self.ensure_name_available(department_agg.department.parent_id.as_ref(), name, Some(&cmd.id)).await?;
+
+        department_agg.update(cmd.name, cmd.sort, cmd.leader_id, cmd.status)?;
+
+        let department = &department_agg.department;
+        let updated_by = cmd.updated_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = DepartmentUpdatedEvent::new(department.id.value(), department.name.value(), updated_by);
+        department_agg.record_event(&event)?;
+        self.department_agg_repo.save(&department_agg).await
+    }
+
+    async fn delete_department(&self, cmd: DeleteDepartmentCommand) -> AppResult<()> {
+        let mut department_agg = self
+            .department_agg_repo
+            .find_by_id(&cmd.department_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Department not found".into()))?;
+
+        let has_children = self.department_repo.count_children(&cmd.department_id).await? > 0;
+        let has_members = self.user_repo.count_by_departments(std::slice::from_ref(&cmd.department_id)).await? > 0;
+        department_agg.delete(has_children, has_members)?;
+
+        let deleted_by = cmd.deleted_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        department_agg.record_event(&DepartmentDeletedEvent::new(cmd.department_id.value(), deleted_by))?;
+        self.department_agg_repo.save(&department_agg).await
+    }
+
+    async fn get_department_by_id(&self, query: GetDepartmentByIdQuery) -> AppResult<Department> {
+        self.department_repo
+            .find_by_id(&query.department_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Department not found".into()))
+    }
+
+    async fn list_departments(&self, query: ListDepartmentsQuery) -> AppResult<PaginatedResult<Department>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) = self
+            .department_repo
+            .search(query.name.as_ref(), query.parent_id.as_ref(), query.status, query.show_deleted, limit, offset)
+            .await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn list_all_departments(&self) -> AppResult<Vec<Department>> {
+        self.department_repo.find_all().await
+    }
+}
@@ -0,0 +1,105 @@
+use crate::commands::feature_flag::{CreateFeatureFlagCommand, DeleteFeatureFlagCommand, UpdateFeatureFlagCommand};
+use crate::interfaces::IFeatureFlagService;
+use crate::queries::feature_flag::{GetUserFeatureFlagsQuery, ListFeatureFlagsQuery};
+use tradewinds_common::tenant::{current_tenant_id, is_platform_tenant};
+use tradewinds_domain::entities::feature_flag::{FeatureFlag, FlagPrincipal};
+use tradewinds_domain::repositories::{FeatureFlagRepository, RoleRepository, UserRoleRepository};
+use tradewinds_domain::value_objects::{RoleAssignment, UserId};
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct FeatureFlagService {
+    flag_repo: Arc<dyn FeatureFlagRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+}
+
+impl FeatureFlagService {
+    pub fn new(
+        flag_repo: Arc<dyn FeatureFlagRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+    ) -> Self {
+        Self { flag_repo, user_role_repo, role_repo }
+    }
+
+    /// 功能开关对全部租户生效，只能在平台默认租户内管理
+    fn ensure_platform() -> AppResult<()> {
+        if !is_platform_tenant() {
+            return Err(AppError::Forbidden("Feature flags can only be managed from the platform tenant".into()));
+        }
+        Ok(())
+    }
+
+    async fn find_flag(&self, key: &str) -> AppResult<FeatureFlag> {
+        self.flag_repo
+            .find_by_key(key)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Feature flag {} not found", key)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IFeatureFlagService for FeatureFlagService {
+    async fn create_flag(&self, cmd: CreateFeatureFlagCommand) -> AppResult<FeatureFlag> {
+        Self::ensure_platform()?;
+        let flag = FeatureFlag::create(
+            cmd.key,
+            cmd.description,
+            cmd.enabled,
+            cmd.rollout_percentage,
+            cmd.allowed_roles,
+            cmd.allowed_tenants,
+        )?;
+        if self.flag_repo.find_by_key(&flag.key).await?.is_some() {
+            return Err(AppError::Conflict(format!("Feature flag {} already exists", flag.key)));
+        }
+        self.flag_repo.create(&flag).await?;
+        Ok(flag)
+    }
+
+    async fn update_flag(&self, cmd: UpdateFeatureFlagCommand) -> AppResult<FeatureFlag> {
+        Self::ensure_platform()?;
+        let mut flag = self.find_flag(&cmd.key).await?;
+        flag.update(cmd.description, cmd.enabled, cmd.rollout_percentage, cmd.allowed_roles, cmd.allowed_tenants)?;
+        self.flag_repo.update(&flag).await?;
+        Ok(flag)
+    }
+
+    async fn delete_flag(&self, cmd: DeleteFeatureFlagCommand) -> AppResult<()> {
+        Self::ensure_platform()?;
+        let flag = self.find_flag(&cmd.key).await?;
+        self.flag_repo.delete(&flag.key).await
+    }
+
+    async fn list_flags(&self, _query: ListFeatureFlagsQuery) -> AppResult<Vec<FeatureFlag>> {
+        Self::ensure_platform()?;
+        self.flag_repo.find_all().await
+    }
+
+    /// 角色白名单只对平台租户生效（见 `FeatureFlag::is_enabled_for`），停用的角色不计入
+    async fn principal(&self, user_id: &UserId) -> AppResult<FlagPrincipal> {
+        let assignments = self.user_role_repo.find_assignments_by_user_id(user_id).await?;
+        let role_ids = RoleAssignment::effective_role_ids(&assignments);
+        let roles = self.role_repo.find_by_ids(&role_ids).await?;
+        Ok(FlagPrincipal {
+            user_id: user_id.clone(),
+            role_codes: roles.into_iter().filter(|role| role.is_active()).map(|role| role.code.to_string()).collect(),
+            tenant_id: current_tenant_id(),
+        })
+    }
+
+    async fn is_enabled(&self, flag: &str, principal: &FlagPrincipal) -> AppResult<bool> {
+        let flag = self.flag_repo.find_by_key(flag).await?;
+        Ok(flag.is_some_and(|flag| flag.is_enabled_for(principal)))
+    }
+
+    async fn user_flags(&self, query: GetUserFeatureFlagsQuery) -> AppResult<BTreeMap<String, bool>> {
+        let principal = self.principal(&query.user_id).await?;
+        let flags = self.flag_repo.find_all().await?;
+        Ok(flags.into_iter().map(|flag| (flag.key.clone(), flag.is_enabled_for(&principal))).collect())
+    }
+}
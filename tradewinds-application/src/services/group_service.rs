@@ -0,0 +1,220 @@
+use crate::commands::group::{
+    AddGroupMembersCommand, CreateGroupCommand, DeleteGroupCommand, RemoveGroupMemberCommand, UpdateGroupCommand,
+};
+use crate::events::{
+    GroupCreatedEvent, GroupDeletedEvent, GroupMemberRemovedEvent, GroupMembersAddedEvent, GroupUpdatedEvent,
+};
+use crate::interfaces::IGroupService;
+use crate::queries::group::{GetGroupByIdQuery, ListGroupMembersQuery, ListGroupsQuery};
+use crate::services::admin_safeguard_guard::AdminSafeguardGuard;
+use crate::services::separation_of_duty_guard::SeparationOfDutyGuard;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_domain::entities::{group::Group, user::User};
+use tradewinds_domain::repositories::{
+    GroupAggregateRepository, GroupRepository, RoleRepository, SodRuleRepository, UserRepository, UserRoleRepository,
+};
+use tradewinds_domain::value_objects::{GroupId, GroupName, RoleId, UserId};
+
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct GroupService {
+    group_repo: Arc<dyn GroupRepository>,
+    group_agg_repo: Arc<dyn GroupAggregateRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    sod_guard: SeparationOfDutyGuard,
+    admin_guard: AdminSafeguardGuard,
+}
+
+impl GroupService {
+    pub fn new(
+        group_repo: Arc<dyn GroupRepository>,
+        group_agg_repo: Arc<dyn GroupAggregateRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+        sod_rule_repo: Arc<dyn SodRuleRepository>,
+    ) -> Self {
+        let sod_guard = SeparationOfDutyGuard::new(sod_rule_repo, user_role_repo.clone());
+        let admin_guard = AdminSafeguardGuard::new(role_repo.clone(), user_repo.clone(), user_role_repo);
+        Self { group_repo, group_agg_repo, user_repo, role_repo, sod_guard, admin_guard }
+    }
+
+    /// 用户组启用时，成员经由该组获得的角色须满足职责分离规则
+    async fn ensure_separation_of_duties(&self, group_agg: &GroupAggregate, members: &[UserId]) -> AppResult<()> {
+        if !group_agg.group.is_active() || members.is_empty() {
+            return Ok(());
+        }
+        let mut changes = Vec::with_capacity(members.len());
+        for user_id in members {
+            let effective = self.sod_guard.effective_with_group(user_id, &group_agg.group.id, &group_agg.roles).await?;
+            changes.push((user_id.clone(), effective));
+        }
+        self.sod_guard.check(&changes).await
+    }
+
+    /// 成员经由该组获得的角色变化后（含组被禁用、删除或成员被移出），须保留启用的超级管理员
+    async fn ensure_admin_remains(&self, group_agg: &GroupAggregate, members: &[UserId]) -> AppResult<()> {
+        if members.is_empty() {
+            return Ok(());
+        }
+        let group_roles: &[RoleId] = if group_agg.group.is_active() { &group_agg.roles } else { &[] };
+        let mut changes = Vec::with_capacity(members.len());
+        for user_id in members {
+            let retains_group = group_agg.has_member(user_id);
+            let roles = if retains_group { group_roles } else { &[] };
+            let effective = self.sod_guard.effective_with_group(user_id, &group_agg.group.id, roles).await?;
+            changes.push((user_id.clone(), Some(effective)));
+        }
+        self.admin_guard.check(&changes).await
+    }
+
+    async fn find_group(&self, group_id: &GroupId) -> AppResult<GroupAggregate> {
+        self.group_agg_repo
+            .find_by_id(group_id)
+            .await?
+            .filter(|agg| !agg.group.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("Group not found".into()))
+    }
+
+    /// 用户组名称在租户内唯一
+    async fn ensure_name_available(&self, name: &GroupName, current: Option<&GroupId>) -> AppResult<()> {
+        if let Some(existing) = self.group_repo.find_by_name(name).await?
+            && Some(&existing.id) != current
+        {
+            return Err(AppError::Conflict(format!("Group name already exists: {}", name)));
+        }
+        Ok(())
+    }
+
+    /// 用户组携带的角色必须全部存在
+    async fn ensure_roles_exist(&self, role_ids: &[RoleId]) -> AppResult<()> {
+        if role_ids.is_empty() {
+            return Ok(());
+        }
+        let mut unique = role_ids.to_vec();
+        unique.sort_by(|a, b| a.value().cmp(b.value()));
+        unique.dedup();
+        let existing = self.role_repo.find_by_ids(&unique).await?;
+        if existing.len() != unique.len() {
+            return Err(AppError::Validation("Some roles do not exist".into()));
+        }
+        Ok(())
+    }
+
+    /// 加入用户组的用户必须存在且未删除
+    async fn ensure_users_exist(&self, user_ids: &[UserId]) -> AppResult<()> {
+        let users = self.user_repo.find_by_ids(user_ids).await?;
+        for user_id in user_ids {
+            if !users.iter().any(|u| &u.id == user_id && !u.is_deleted()) {
+                return Err(AppError::NotFound(format!("User {} not found", user_id)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl IGroupService for GroupService {
+    async fn create_group(&self, cmd: CreateGroupCommand) -> AppResult<Group> {
+        self.ensure_name_available(&cmd.name, None).await?;
+        self.ensure_roles_exist(&cmd.role_ids).await?;
+
+        let mut group_agg = GroupAggregate::create(cmd.name, cmd.description, cmd.role_ids)?;
+        let group = &group_agg.group;
+        let created_by = cmd.created_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = GroupCreatedEvent::new(group.id.value(), group.name.value(), created_by);
+        group_agg.record_event(&event)?;
+        self.group_agg_repo.create(&group_agg).await?;
+
+        Ok(group_agg.group)
+    }
+
+    async fn update_group(&self, cmd: UpdateGroupCommand) -> AppResult<()> {
+        let mut group_agg = self.find_group(&cmd.id).await?;
+
+        if let Some(name) = &cmd.name {
+            self.ensure_name_available(name, Some(&cmd.id)).await?;
+        }
+        if let Some(role_ids) = &cmd.role_ids {
+            self.ensure_roles_exist(role_ids).await?;
+        }
+
+        // 角色或状态变化会改变成员的有效角色
+        let affects_members = cmd.role_ids.is_some() || cmd.status.is_some();
+        group_agg.update(cmd.name, cmd.description, cmd.status, cmd.role_ids)?;
+        if affects_members {
+            self.ensure_separation_of_duties(&group_agg, &group_agg.members).await?;
+            self.ensure_admin_remains(&group_agg, &group_agg.members).await?;
+        }
+
+        let group = &group_agg.group;
+        let updated_by = cmd.updated_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = GroupUpdatedEvent::new(group.id.value(), group.name.value(), updated_by);
+        group_agg.record_event(&event)?;
+        self.group_agg_repo.save(&group_agg).await
+    }
+
+    async fn delete_group(&self, cmd: DeleteGroupCommand) -> AppResult<()> {
+        let mut group_agg = self.find_group(&cmd.group_id).await?;
+        group_agg.delete()?;
+        self.ensure_admin_remains(&group_agg, &group_agg.members).await?;
+
+        let deleted_by = cmd.deleted_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        group_agg.record_event(&GroupDeletedEvent::new(cmd.group_id.value(), deleted_by))?;
+        self.group_agg_repo.save(&group_agg).await
+    }
+
+    async fn add_group_members(&self, cmd: AddGroupMembersCommand) -> AppResult<()> {
+        if cmd.user_ids.is_empty() {
+            return Err(AppError::Validation("User ids cannot be empty".into()));
+        }
+        let mut group_agg = self.find_group(&cmd.group_id).await?;
+        self.ensure_users_exist(&cmd.user_ids).await?;
+
+        group_agg.add_members(cmd.user_ids.clone())?;
+        self.ensure_separation_of_duties(&group_agg, &cmd.user_ids).await?;
+
+        let user_ids: Vec<String> = cmd.user_ids.iter().map(|id| id.value().to_string()).collect();
+        let added_by = cmd.added_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        group_agg.record_event(&GroupMembersAddedEvent::new(cmd.group_id.value(), &user_ids, added_by))?;
+        self.group_agg_repo.save(&group_agg).await
+    }
+
+    async fn remove_group_member(&self, cmd: RemoveGroupMemberCommand) -> AppResult<()> {
+        let mut group_agg = self.find_group(&cmd.group_id).await?;
+        group_agg.remove_member(&cmd.user_id)?;
+        self.ensure_admin_remains(&group_agg, std::slice::from_ref(&cmd.user_id)).await?;
+
+        let removed_by = cmd.removed_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = GroupMemberRemovedEvent::new(cmd.group_id.value(), cmd.user_id.value(), removed_by);
+        group_agg.record_event(&event)?;
+        self.group_agg_repo.save(&group_agg).await
+    }
+
+    async fn get_group_by_id(&self, query: GetGroupByIdQuery) -> AppResult<GroupAggregate> {
+        self.group_agg_repo
+            .find_by_id(&query.group_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Group not found".into()))
+    }
+
+    async fn list_groups(&self, query: ListGroupsQuery) -> AppResult<PaginatedResult<Group>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) =
+            self.group_repo.search(query.name.as_ref(), query.status, query.show_deleted, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn list_group_members(&self, query: ListGroupMembersQuery) -> AppResult<Vec<User>> {
+        let group_agg = self.find_group(&query.group_id).await?;
+        if group_agg.members.is_empty() {
+            return Ok(vec![]);
+        }
+        let users = self.user_repo.find_by_ids(&group_agg.members).await?;
+        Ok(users.into_iter().filter(|u| !u.is_deleted()).collect())
+    }
+}
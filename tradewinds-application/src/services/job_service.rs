@@ -0,0 +1,75 @@
+use crate::commands::job::{PauseJobCommand, ResumeJobCommand, TriggerJobCommand};
+use crate::interfaces::IJobService;
+use crate::queries::job::{ListJobRunsQuery, ListJobsQuery};
+use tradewinds_common::PaginatedResult;
+use tradewinds_common::tenant::is_platform_tenant;
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_domain::entities::scheduled_job::ScheduledJob;
+use tradewinds_domain::repositories::{JobRunRepository, ScheduledJobRepository};
+use tradewinds_domain::services::JobRunner;
+
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct JobService {
+    job_repo: Arc<dyn ScheduledJobRepository>,
+    run_repo: Arc<dyn JobRunRepository>,
+    runner: Arc<dyn JobRunner>,
+}
+
+impl JobService {
+    pub fn new(
+        job_repo: Arc<dyn ScheduledJobRepository>,
+        run_repo: Arc<dyn JobRunRepository>,
+        runner: Arc<dyn JobRunner>,
+    ) -> Self {
+        Self { job_repo, run_repo, runner }
+    }
+
+    /// 定时任务跨租户执行，只能在平台默认租户内管理
+    fn ensure_platform() -> AppResult<()> {
+        if !is_platform_tenant() {
+            return Err(AppError::Forbidden("Scheduled jobs can only be managed from the platform tenant".into()));
+        }
+        Ok(())
+    }
+
+    async fn find_job(&self, name: &str) -> AppResult<ScheduledJob> {
+        self.job_repo.find_by_name(name).await?.ok_or_else(|| AppError::NotFound(format!("Job {} not found", name)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IJobService for JobService {
+    async fn list_jobs(&self, _query: ListJobsQuery) -> AppResult<Vec<ScheduledJob>> {
+        Self::ensure_platform()?;
+        self.job_repo.find_all().await
+    }
+
+    async fn list_runs(&self, query: ListJobRunsQuery) -> AppResult<PaginatedResult<JobRun>> {
+        Self::ensure_platform()?;
+        let (limit, offset) = query.pagination();
+        let (items, total) = self.run_repo.search(&query.filter, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn trigger_job(&self, cmd: TriggerJobCommand) -> AppResult<JobRun> {
+        Self::ensure_platform()?;
+        self.runner.run_now(&cmd.name, cmd.triggered_by).await
+    }
+
+    async fn pause_job(&self, cmd: PauseJobCommand) -> AppResult<()> {
+        Self::ensure_platform()?;
+        let mut job = self.find_job(&cmd.name).await?;
+        job.pause()?;
+        self.job_repo.save(&job).await
+    }
+
+    async fn resume_job(&self, cmd: ResumeJobCommand) -> AppResult<()> {
+        Self::ensure_platform()?;
+        let mut job = self.find_job(&cmd.name).await?;
+        job.resume()?;
+        self.job_repo.save(&job).await
+    }
+}
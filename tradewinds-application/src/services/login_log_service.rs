@@ -0,0 +1,39 @@
+use crate::commands::login_log::PurgeLoginLogsCommand;
+use crate::interfaces::ILoginLogService;
+use crate::queries::login_log::ListLoginLogsQuery;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::login_log::LoginLog;
+use tradewinds_domain::repositories::LoginLogRepository;
+
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tradewinds_error::AppResult;
+
+/// 登录日志服务
+///
+/// 登录日志保留 `retention_days` 天，清理只作用于当前租户。
+#[derive(Clone)]
+pub struct LoginLogService {
+    login_log_repo: Arc<dyn LoginLogRepository>,
+    retention_days: i64,
+}
+
+impl LoginLogService {
+    pub fn new(login_log_repo: Arc<dyn LoginLogRepository>, retention_days: i64) -> Self {
+        Self { login_log_repo, retention_days }
+    }
+}
+
+#[async_trait::async_trait]
+impl ILoginLogService for LoginLogService {
+    async fn list_logs(&self, query: ListLoginLogsQuery) -> AppResult<PaginatedResult<LoginLog>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) = self.login_log_repo.search(&query.filter, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn purge_expired(&self, _cmd: PurgeLoginLogsCommand) -> AppResult<u64> {
+        let before = (Utc::now() - Duration::days(self.retention_days)).timestamp();
+        self.login_log_repo.delete_before(before).await
+    }
+}
@@ -1,6 +1,25 @@
 //! 应用层服务
+pub(crate) mod access_policy_guard;
+pub mod access_request_service;
+pub mod access_review_service;
+pub(crate) mod admin_safeguard_guard;
+pub mod audit_chain_service;
+pub mod audit_log_service;
 pub mod auth_service;
+pub mod department_service;
+pub mod feature_flag_service;
+pub mod group_service;
+pub mod job_service;
+pub mod login_log_service;
+pub mod outbox_relay;
+pub mod outbox_service;
 pub mod permission_service;
+pub mod policy_service;
 pub mod role_service;
+pub(crate) mod separation_of_duty_guard;
+pub mod settings;
 pub mod system_setting_service;
+pub mod tenant_service;
 pub mod user_service;
+pub mod webhook_dispatcher;
+pub mod webhook_service;
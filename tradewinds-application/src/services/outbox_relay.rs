@@ -0,0 +1,151 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_domain::entities::outbox_message::{OutboxMessage, OutboxRetryPolicy};
+use tradewinds_domain::repositories::OutboxRepository;
+use tradewinds_domain::services::{EventBus, EventRegistry};
+use tradewinds_error::AppResult;
+
+/// 发件箱中继的累计指标（进程内，重启后清零）
+#[derive(Debug, Default)]
+pub struct OutboxMetrics {
+    published: AtomicU64,
+    failed: AtomicU64,
+    dead_lettered: AtomicU64,
+    last_run_at: AtomicI64,
+}
+
+/// 发件箱中继指标快照
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct OutboxMetricsSnapshot {
+    /// 投递成功次数
+    pub published_total: u64,
+    /// 投递失败次数，含之后重试成功的
+    pub failed_total: u64,
+    /// 转为死信的消息数
+    pub dead_lettered_total: u64,
+    /// 最近一次轮询时间，尚未运行时为空
+    pub last_run_at: Option<i64>,
+}
+
+impl OutboxMetrics {
+    pub fn snapshot(&self) -> OutboxMetricsSnapshot {
+        let last_run_at = self.last_run_at.load(Ordering::Relaxed);
+        OutboxMetricsSnapshot {
+            published_total: self.published.load(Ordering::Relaxed),
+            failed_total: self.failed.load(Ordering::Relaxed),
+            dead_lettered_total: self.dead_lettered.load(Ordering::Relaxed),
+            last_run_at: (last_run_at > 0).then_some(last_run_at),
+        }
+    }
+}
+
+/// 发件箱中继
+///
+/// 轮询各租户待投递的发件箱消息，还原为事件后发布到事件总线。
+/// 消息先以租约认领再投递，多个实例同时运行中继时同一消息不会被并发投递；
+/// 消息在发布成功后才标记为已投递，进程在两步之间退出时租约到期后会重复投递，即至少一次语义，订阅者需幂等。
+/// 同一聚合的消息按写入顺序投递：每次只认领各聚合最早一条待投递消息，它等待重试时该聚合的后续消息随之等待，
+/// 其他聚合不受影响；转为死信的消息不再阻塞后续消息。
+pub struct OutboxRelay {
+    outbox_repo: Arc<dyn OutboxRepository>,
+    registry: EventRegistry,
+    event_bus: Arc<dyn EventBus>,
+    retry_policy: OutboxRetryPolicy,
+    batch_size: u64,
+    lease_secs: i64,
+    metrics: Arc<OutboxMetrics>,
+}
+
+impl OutboxRelay {
+    pub fn new(
+        outbox_repo: Arc<dyn OutboxRepository>,
+        registry: EventRegistry,
+        event_bus: Arc<dyn EventBus>,
+        retry_policy: OutboxRetryPolicy,
+        batch_size: u64,
+        lease_secs: i64,
+        metrics: Arc<OutboxMetrics>,
+    ) -> Self {
+        Self { outbox_repo, registry, event_bus, retry_policy, batch_size, lease_secs, metrics }
+    }
+
+    /// 投递一轮，返回投递成功的消息数；单个租户失败不影响其他租户
+    pub async fn relay_once(&self) -> AppResult<u64> {
+        let mut published = 0;
+        for tenant_id in self.outbox_repo.find_tenant_ids_with_pending().await? {
+            match with_tenant(tenant_id.clone(), self.relay_tenant()).await {
+                Ok(count) => published += count,
+                Err(e) => tracing::warn!("Failed to relay outbox messages of tenant {}: {}", tenant_id, e),
+            }
+        }
+        self.metrics.last_run_at.store(Utc::now().timestamp(), Ordering::Relaxed);
+        Ok(published)
+    }
+
+    /// 投递当前租户的一批消息，最多 `batch_size` 条
+    ///
+    /// 聚合队首投递成功后其后续消息成为新的队首，因此认领到消息时继续下一轮认领
+    async fn relay_tenant(&self) -> AppResult<u64> {
+        let mut published = 0;
+        let mut claimed_total = 0;
+        while claimed_total < self.batch_size {
+            let now = Utc::now().timestamp();
+            let claimed = self.outbox_repo.claim_due(now, self.lease_secs, self.batch_size - claimed_total).await?;
+            if claimed.is_empty() {
+                break;
+            }
+            claimed_total += claimed.len() as u64;
+            for mut message in claimed {
+                if self.relay_message(&mut message).await {
+                    published += 1;
+                }
+                self.outbox_repo.update(&message).await?;
+            }
+        }
+        Ok(published)
+    }
+
+    /// 投递一条已认领的消息并记录结果，返回是否投递成功
+    async fn relay_message(&self, message: &mut OutboxMessage) -> bool {
+        let result = self.deliver(message).await;
+        let now = Utc::now().timestamp();
+        match result {
+            Ok(()) => {
+                message.mark_published(now);
+                self.metrics.published.fetch_add(1, Ordering::Relaxed);
+                true
+            }
+            Err(e) => {
+                message.mark_failed(&e.to_string(), now, &self.retry_policy);
+                self.metrics.failed.fetch_add(1, Ordering::Relaxed);
+                if message.status.is_dead() {
+                    self.metrics.dead_lettered.fetch_add(1, Ordering::Relaxed);
+                    tracing::error!(
+                        "Outbox message {} ({}) dead-lettered after {} attempts: {}",
+                        message.id,
+                        message.event_type,
+                        message.attempts,
+                        e
+                    );
+                } else {
+                    tracing::warn!(
+                        "Failed to deliver outbox message {} ({}), attempt {}: {}",
+                        message.id,
+                        message.event_type,
+                        message.attempts,
+                        e
+                    );
+                }
+                false
+            }
+        }
+    }
+
+    async fn deliver(&self, message: &OutboxMessage) -> AppResult<()> {
+        let event = self.registry.decode(&message.event_type, &message.payload)?;
+        self.event_bus.publish(event).await
+    }
+}
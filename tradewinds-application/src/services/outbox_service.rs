@@ -0,0 +1,67 @@
+use crate::commands::outbox::RetryOutboxMessageCommand;
+use crate::interfaces::IOutboxService;
+use crate::queries::outbox::{GetOutboxStatsQuery, ListOutboxMessagesQuery, OutboxStats};
+use crate::services::outbox_relay::OutboxMetrics;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_domain::repositories::OutboxRepository;
+use tradewinds_domain::value_objects::outbox::OutboxStatus;
+
+use chrono::Utc;
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 发件箱服务
+///
+/// 写入超过 `stuck_after_secs` 秒仍未投递的消息与死信一起视为滞留消息。
+#[derive(Clone)]
+pub struct OutboxService {
+    outbox_repo: Arc<dyn OutboxRepository>,
+    metrics: Arc<OutboxMetrics>,
+    stuck_after_secs: i64,
+}
+
+impl OutboxService {
+    pub fn new(outbox_repo: Arc<dyn OutboxRepository>, metrics: Arc<OutboxMetrics>, stuck_after_secs: i64) -> Self {
+        Self { outbox_repo, metrics, stuck_after_secs }
+    }
+}
+
+#[async_trait::async_trait]
+impl IOutboxService for OutboxService {
+    async fn list_messages(&self, query: ListOutboxMessagesQuery) -> AppResult<PaginatedResult<OutboxMessage>> {
+        let (limit, offset) = query.pagination();
+        let mut filter = query.filter;
+        if query.stuck {
+            filter.stuck_before = Some(Utc::now().timestamp() - self.stuck_after_secs);
+        }
+        let (items, total) = self.outbox_repo.search(&filter, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn get_stats(&self, _query: GetOutboxStatsQuery) -> AppResult<OutboxStats> {
+        let mut stats = OutboxStats {
+            oldest_pending_at: self.outbox_repo.oldest_pending_at().await?,
+            relay: self.metrics.snapshot(),
+            ..Default::default()
+        };
+        for (status, count) in self.outbox_repo.count_by_status().await? {
+            match status {
+                OutboxStatus::Pending => stats.pending = count,
+                OutboxStatus::Published => stats.published = count,
+                OutboxStatus::Dead => stats.dead = count,
+            }
+        }
+        Ok(stats)
+    }
+
+    async fn retry_message(&self, cmd: RetryOutboxMessageCommand) -> AppResult<()> {
+        let mut message = self
+            .outbox_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Outbox message not found".into()))?;
+        message.requeue(Utc::now().timestamp())?;
+        self.outbox_repo.update(&message).await
+    }
+}
@@ -1,4 +1,5 @@
 use crate::commands::permission::{CreatePermissionCommand, DeletePermissionCommand, UpdatePermissionCommand};
+use crate::events::{PermissionCreatedEvent, PermissionDeletedEvent, PermissionUpdatedEvent};
 use crate::interfaces::IPermissionService;
 use crate::queries::{
     get_permission_by_id_query::GetPermissionByIdQuery, get_permission_by_name_query::GetPermissionByNameQuery,
@@ -73,7 +74,7 @@ impl IPermissionService for PermissionService {
             }
         }
 
-        let permission_agg = PermissionAggregate::create(
+        let mut permission_agg = PermissionAggregate::create(
             cmd.name,
             cmd.code,
             cmd.type_,
@@ -84,6 +85,10 @@ impl IPermissionService for PermissionService {
             cmd.sort,
         )?;
 
+        let permission = &permission_agg.permission;
+        let created_by = cmd.created_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = PermissionCreatedEvent::new(permission.id.value(), permission.name.value(), created_by);
+        permission_agg.record_event(&event)?;
         self.permission_agg_repo.create(&permission_agg).await?;
 
         Ok(permission_agg.permission)
@@ -115,12 +120,24 @@ impl IPermissionService for PermissionService {
             cmd.status,
         )?;
 
+        let permission = &permission_agg.permission;
+        let updated_by = cmd.updated_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = PermissionUpdatedEvent::new(permission.id.value(), permission.name.value(), updated_by);
+        permission_agg.record_event(&event)?;
         self.permission_agg_repo.save(&permission_agg).await?;
 
         Ok(())
     }
 
     async fn delete_permission(&self, cmd: DeletePermissionCommand) -> AppResult<()> {
+        let mut permission_agg = self
+            .permission_agg_repo
+            .find_by_id(&cmd.permission_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Permission not found".to_string()))?;
+        // 由聚合校验是否允许删除（内置权限不可删除）
+        permission_agg.delete()?;
+
         // 检查是否有子权限
         let (list, _total) = self.permission_repo.search(None, None, None, None, None, 100, 0).await?;
         let children_vec: Vec<_> =
@@ -129,8 +146,9 @@ impl IPermissionService for PermissionService {
             return Err(AppError::Validation("Cannot delete permission with children".into()));
         }
 
-        self.permission_agg_repo.delete_by_id(&cmd.permission_id).await?;
-        Ok(())
+        let deleted_by = cmd.deleted_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        permission_agg.record_event(&PermissionDeletedEvent::new(cmd.permission_id.value(), deleted_by))?;
+        self.permission_agg_repo.delete(&permission_agg).await
     }
 
     async fn get_permission_by_id(&self, query: GetPermissionByIdQuery) -> AppResult<Permission> {
@@ -0,0 +1,126 @@
+use crate::commands::policy::{CreateAccessPolicyCommand, DeleteAccessPolicyCommand, UpdateAccessPolicyCommand};
+use crate::interfaces::IPolicyService;
+use crate::queries::policy::{EvaluateAccessPolicyQuery, GetAccessPolicyByIdQuery, ListAccessPoliciesQuery};
+use crate::services::access_policy_guard::AccessPolicyGuard;
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::policies::{AccessEvaluation, AccessExplanationPolicy};
+use tradewinds_domain::repositories::{
+    AccessPolicyRepository, PermissionRepository, RoleRepository, UserRepository, UserRoleRepository,
+};
+use tradewinds_domain::value_objects::{AccessPolicyId, AccessPolicyName, AccessPolicyStatus, RoleAssignment};
+
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct PolicyService {
+    policy_repo: Arc<dyn AccessPolicyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    policy_guard: AccessPolicyGuard,
+}
+
+impl PolicyService {
+    pub fn new(
+        policy_repo: Arc<dyn AccessPolicyRepository>,
+        user_repo: Arc<dyn UserRepository>,
+        role_repo: Arc<dyn RoleRepository>,
+        permission_repo: Arc<dyn PermissionRepository>,
+        user_role_repo: Arc<dyn UserRoleRepository>,
+    ) -> Self {
+        let policy_guard =
+            AccessPolicyGuard::new(policy_repo.clone(), user_repo.clone(), role_repo.clone(), user_role_repo.clone());
+        Self { policy_repo, user_repo, role_repo, permission_repo, user_role_repo, policy_guard }
+    }
+
+    async fn find_policy(&self, id: &AccessPolicyId) -> AppResult<AccessPolicy> {
+        self.policy_repo
+            .find_by_id(id)
+            .await?
+            .filter(|policy| !policy.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("Access policy not found".into()))
+    }
+
+    /// 策略名称在租户内唯一
+    async fn ensure_name_available(&self, name: &AccessPolicyName, current: Option<&AccessPolicyId>) -> AppResult<()> {
+        if let Some(existing) = self.policy_repo.find_by_name(name).await?
+            && Some(&existing.id) != current
+        {
+            return Err(AppError::Conflict(format!("Access policy name already exists: {}", name)));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl IPolicyService for PolicyService {
+    async fn create_policy(&self, cmd: CreateAccessPolicyCommand) -> AppResult<AccessPolicy> {
+        self.ensure_name_available(&cmd.name, None).await?;
+        AccessPolicyGuard::ensure_enforced(&cmd.action)?;
+        let policy = AccessPolicy::create(cmd.name, cmd.description, cmd.action, cmd.effect, cmd.condition);
+        self.policy_repo.create(&policy).await?;
+        Ok(policy)
+    }
+
+    async fn update_policy(&self, cmd: UpdateAccessPolicyCommand) -> AppResult<()> {
+        let mut policy = self.find_policy(&cmd.id).await?;
+        if let Some(name) = &cmd.name {
+            self.ensure_name_available(name, Some(&cmd.id)).await?;
+        }
+        if let Some(action) = &cmd.action {
+            AccessPolicyGuard::ensure_enforced(action)?;
+        }
+        policy.update(cmd.name, cmd.description, cmd.action, cmd.effect, cmd.condition, cmd.status)?;
+        self.policy_repo.save(&policy).await
+    }
+
+    async fn delete_policy(&self, cmd: DeleteAccessPolicyCommand) -> AppResult<()> {
+        let mut policy = self.find_policy(&cmd.id).await?;
+        policy.update(None, None, None, None, None, Some(AccessPolicyStatus::Deleted))?;
+        self.policy_repo.save(&policy).await
+    }
+
+    async fn get_policy_by_id(&self, query: GetAccessPolicyByIdQuery) -> AppResult<AccessPolicy> {
+        self.find_policy(&query.id).await
+    }
+
+    async fn list_policies(&self, query: ListAccessPoliciesQuery) -> AppResult<PaginatedResult<AccessPolicy>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) = self.policy_repo.search(query.keyword.as_deref(), query.status, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn evaluate(&self, query: EvaluateAccessPolicyQuery) -> AppResult<AccessEvaluation> {
+        let subject_id = query.subject_id.as_ref().unwrap_or(&query.actor_id);
+        let subject = self
+            .user_repo
+            .find_by_id(subject_id)
+            .await?
+            .filter(|u| !u.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+
+        // RBAC：与访问说明相同的判定
+        let assignments = self.user_role_repo.find_assignments_by_user_id(&subject.id).await?;
+        let role_ids = RoleAssignment::effective_role_ids(&assignments);
+        let roles = if role_ids.is_empty() { Vec::new() } else { self.role_repo.find_by_ids(&role_ids).await? };
+        let roles: Vec<_> = roles
+            .into_iter()
+            .map(|role| {
+                let sources = RoleAssignment::sources_of(&assignments, &role.id).into_iter().cloned().collect();
+                (role, sources)
+            })
+            .collect();
+        let grants = self.role_repo.find_permission_grants(&role_ids).await?;
+        let catalog = self.permission_repo.find_all().await?;
+        let rbac = AccessExplanationPolicy::explain(&subject, &query.action, &roles, &grants, &catalog);
+
+        // ABAC：当前启用的策略
+        let ctx = self.policy_guard.context(&subject, query.action, query.resource, query.environment).await?;
+        let abac = self.policy_guard.evaluate(&ctx).await?;
+
+        Ok(AccessEvaluation::new(ctx, rbac, abac))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::commands::role::{
     AssignPermissionCommand, CreateRoleCommand, DeleteRoleCommand, RevokePermissionCommand, UpdateRoleCommand,
 };
+use crate::events::{RoleCreatedEvent, RoleDeletedEvent, RoleUpdatedEvent};
 use crate::interfaces::IRoleService;
 use crate::queries::role::{GetRoleByIdQuery, GetRoleByNameQuery, GetRolePermissionsQuery, ListRolesQuery};
 use std::sync::Arc;
@@ -12,6 +13,7 @@ use tradewinds_domain::{
     value_objects::role::RoleCode,
     value_objects::role::RoleName,
     value_objects::role::RoleStatus,
+    value_objects::user::UserId,
 };
 use tradewinds_error::{AppError, AppResult};
 
@@ -25,6 +27,14 @@ impl RoleService {
     pub fn new(role_repo: Arc<dyn RoleRepository>, role_agg_repo: Arc<dyn RoleAggregateRepository>) -> Self {
         Self { role_repo, role_agg_repo }
     }
+
+    /// 记录角色更新事件，随聚合保存写入发件箱
+    fn record_updated(role_agg: &mut RoleAggregate, updated_by: Option<&UserId>) -> AppResult<()> {
+        let role = &role_agg.role;
+        let updated_by = updated_by.map(|id| id.value()).unwrap_or_default();
+        let event = RoleUpdatedEvent::new(role.id.value(), role.name.value(), updated_by);
+        role_agg.record_event(&event)
+    }
 }
 
 #[async_trait::async_trait]
@@ -54,8 +64,20 @@ impl IRoleService for RoleService {
 
         let code = cmd.code.clone();
         let status = cmd.status.unwrap_or_default();
-        let role_agg = RoleAggregate::create(cmd.name, code, cmd.description, Some(cmd.permissions), status)?;
-
+        let mut role_agg = RoleAggregate::create(
+            cmd.name,
+            code,
+            cmd.description,
+            Some(cmd.permissions),
+            Some(cmd.denied_permissions),
+            status,
+        )?;
+        role_agg.set_data_scope(cmd.data_scope.unwrap_or_default(), Some(cmd.data_scope_departments))?;
+
+        let role = &role_agg.role;
+        let created_by = cmd.created_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = RoleCreatedEvent::new(role.id.value(), role.name.value(), created_by);
+        role_agg.record_event(&event)?;
         self.role_agg_repo.create(&role_agg).await?;
 
         Ok(role_agg.role)
@@ -68,16 +90,30 @@ impl IRoleService for RoleService {
             .await?
             .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
 
-        role_agg.update(cmd.name, cmd.description, cmd.status, cmd.permissions)?;
+        role_agg.update(cmd.name, cmd.description, cmd.status, cmd.permissions, cmd.denied_permissions)?;
+        if cmd.data_scope.is_some() || cmd.data_scope_departments.is_some() {
+            let data_scope = cmd.data_scope.unwrap_or(role_agg.role.data_scope);
+            role_agg.set_data_scope(data_scope, cmd.data_scope_departments)?;
+        }
 
+        Self::record_updated(&mut role_agg, cmd.updated_by.as_ref())?;
         self.role_agg_repo.save(&role_agg).await?;
 
         Ok(())
     }
 
     async fn delete_role(&self, cmd: DeleteRoleCommand) -> AppResult<()> {
-        self.role_agg_repo.delete_by_id(&cmd.id).await?;
-        Ok(())
+        let mut role_agg = self
+            .role_agg_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+        // 由聚合校验是否允许删除（内置角色不可删除）
+        role_agg.delete()?;
+
+        let deleted_by = cmd.deleted_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        role_agg.record_event(&RoleDeletedEvent::new(cmd.id.value(), deleted_by))?;
+        self.role_agg_repo.delete(&role_agg).await
     }
 
     async fn assign_permission(&self, cmd: AssignPermissionCommand) -> AppResult<()> {
@@ -91,6 +127,7 @@ impl IRoleService for RoleService {
             role_agg.assign_permission(&permission_id)?;
         }
 
+        Self::record_updated(&mut role_agg, Some(&cmd.assigned_by))?;
         self.role_agg_repo.save(&role_agg).await?;
 
         Ok(())
@@ -103,8 +140,9 @@ impl IRoleService for RoleService {
             .await?
             .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
 
-        role_agg.revoke_permission(&cmd.permission_id);
+        role_agg.revoke_permission(&cmd.permission_id)?;
 
+        Self::record_updated(&mut role_agg, cmd.revoked_by.as_ref())?;
         self.role_agg_repo.save(&role_agg).await?;
 
         Ok(())
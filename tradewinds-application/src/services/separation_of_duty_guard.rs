@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use tradewinds_domain::policies::SeparationOfDutyPolicy;
+use tradewinds_domain::repositories::{SodRuleRepository, UserRoleRepository};
+use tradewinds_domain::value_objects::{GroupId, RoleAssignment, RoleId, RoleSource, UserId};
+use tradewinds_error::AppResult;
+
+/// 职责分离校验
+///
+/// 在角色分配、用户更新与用户组变更落库前调用，
+/// 以变更后的有效角色为准执行 SeparationOfDutyPolicy。
+#[derive(Clone)]
+pub(crate) struct SeparationOfDutyGuard {
+    sod_rule_repo: Arc<dyn SodRuleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+}
+
+impl SeparationOfDutyGuard {
+    pub(crate) fn new(sod_rule_repo: Arc<dyn SodRuleRepository>, user_role_repo: Arc<dyn UserRoleRepository>) -> Self {
+        Self { sod_rule_repo, user_role_repo }
+    }
+
+    /// 校验一批用户变更后的有效角色
+    ///
+    /// `changes` 中每一项为用户及其变更后的全部有效角色
+    pub(crate) async fn check(&self, changes: &[(UserId, Vec<RoleId>)]) -> AppResult<()> {
+        let rules = self.sod_rule_repo.find_enabled().await?;
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        // 新增持有人按角色归集，便于统一校验人数上限
+        let mut gained: HashMap<&RoleId, HashSet<&UserId>> = HashMap::new();
+        for (user_id, role_ids) in changes {
+            SeparationOfDutyPolicy::check_user_roles(&rules, role_ids)?;
+
+            let current = self.user_role_repo.find_assignments_by_user_id(user_id).await?;
+            let current = RoleAssignment::effective_role_ids(&current);
+            for role_id in role_ids.iter().filter(|r| !current.contains(r)) {
+                gained.entry(role_id).or_default().insert(user_id);
+            }
+        }
+
+        for (role_id, users) in gained {
+            let mut holders: HashSet<UserId> =
+                self.user_role_repo.find_holder_ids_by_role_id(role_id).await?.into_iter().collect();
+            holders.extend(users.into_iter().cloned());
+            SeparationOfDutyPolicy::check_role_holders(&rules, role_id, holders.len())?;
+        }
+        Ok(())
+    }
+
+    /// 以新的直接角色替换后，用户的有效角色（保留用户组携带的角色）
+    pub(crate) async fn effective_with_direct(&self, user_id: &UserId, direct: &[RoleId]) -> AppResult<Vec<RoleId>> {
+        let mut assignments: Vec<RoleAssignment> = self
+            .user_role_repo
+            .find_assignments_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter(|a| !a.source.is_direct())
+            .collect();
+        assignments.extend(direct.iter().cloned().map(RoleAssignment::direct));
+        Ok(RoleAssignment::effective_role_ids(&assignments))
+    }
+
+    /// 用户组携带的角色替换后，组成员的有效角色
+    pub(crate) async fn effective_with_group(
+        &self,
+        user_id: &UserId,
+        group_id: &GroupId,
+        group_roles: &[RoleId],
+    ) -> AppResult<Vec<RoleId>> {
+        let mut assignments: Vec<RoleAssignment> = self
+            .user_role_repo
+            .find_assignments_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .filter(|a| !matches!(&a.source, RoleSource::Group { group_id: g, .. } if g == group_id))
+            .collect();
+        assignments.extend(group_roles.iter().cloned().map(RoleAssignment::direct));
+        Ok(RoleAssignment::effective_role_ids(&assignments))
+    }
+}
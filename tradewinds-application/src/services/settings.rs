@@ -0,0 +1,87 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde_json::Value;
+use tradewinds_common::tenant::{DEFAULT_TENANT_ID, with_tenant};
+use tradewinds_domain::entities::system_setting::EffectiveSetting;
+use tradewinds_domain::repositories::SystemSettingRepository;
+use tradewinds_domain::value_objects::system_setting::{
+    AUDIT_EXPORT_MAX_ROWS, DEFAULT_PASSWORD, MAINTENANCE_MESSAGE, MAINTENANCE_MODE, READ_ONLY_MODE, SettingRegistry,
+    SystemMode, SystemSettingKey, parse_bool, parse_duration,
+};
+use tradewinds_error::{AppError, AppResult};
+
+/// 类型化的系统设置读取
+///
+/// 按登记表解析当前租户的生效值：租户未修改时取平台默认租户的值，均未修改或保存的值不合法时取声明的默认值。
+/// 平台级设置始终取平台默认租户的值。
+#[derive(Clone)]
+pub struct Settings {
+    system_setting_repo: Arc<dyn SystemSettingRepository>,
+}
+
+impl Settings {
+    pub fn new(system_setting_repo: Arc<dyn SystemSettingRepository>) -> Self {
+        Self { system_setting_repo }
+    }
+
+    /// 用户重置密码后的初始密码
+    pub async fn default_password(&self) -> AppResult<String> {
+        self.string(DEFAULT_PASSWORD).await
+    }
+
+    /// 审计日志单次导出的最大条数
+    pub async fn audit_export_max_rows(&self) -> AppResult<u64> {
+        let rows = self.int(AUDIT_EXPORT_MAX_ROWS).await?;
+        u64::try_from(rows).map_err(|_| mismatch(AUDIT_EXPORT_MAX_ROWS))
+    }
+
+    /// 维护模式与只读模式的当前状态
+    pub async fn system_mode(&self) -> AppResult<SystemMode> {
+        Ok(SystemMode {
+            maintenance: self.bool(MAINTENANCE_MODE).await?,
+            message: self.string(MAINTENANCE_MESSAGE).await?,
+            read_only: self.bool(READ_ONLY_MODE).await?,
+        })
+    }
+
+    pub async fn effective(&self, key: &str) -> AppResult<EffectiveSetting> {
+        let definition = SettingRegistry::require(key)?;
+        let stored_key = SystemSettingKey::new(key.to_string()).map_err(AppError::Validation)?;
+        let stored = if definition.platform {
+            with_tenant(DEFAULT_TENANT_ID, self.system_setting_repo.get_by_key(&stored_key)).await?
+        } else {
+            self.system_setting_repo.get_by_key(&stored_key).await?
+        };
+        let setting = EffectiveSetting::resolve(definition, stored.as_ref())?;
+        if stored.is_some() && !setting.customized {
+            tracing::warn!("Stored value of setting {} is invalid, using the default", key);
+        }
+        Ok(setting)
+    }
+
+    pub async fn bool(&self, key: &str) -> AppResult<bool> {
+        parse_bool(self.effective(key).await?.value.value()).ok_or_else(|| mismatch(key))
+    }
+
+    pub async fn int(&self, key: &str) -> AppResult<i64> {
+        self.effective(key).await?.value.value().parse().map_err(|_| mismatch(key))
+    }
+
+    pub async fn duration(&self, key: &str) -> AppResult<Duration> {
+        parse_duration(self.effective(key).await?.value.value()).ok_or_else(|| mismatch(key))
+    }
+
+    pub async fn string(&self, key: &str) -> AppResult<String> {
+        Ok(self.effective(key).await?.value.value().to_string())
+    }
+
+    pub async fn json(&self, key: &str) -> AppResult<Value> {
+        serde_json::from_str(self.effective(key).await?.value.value()).map_err(|_| mismatch(key))
+    }
+}
+
+/// 读取方式与声明的类型不一致，属于代码错误
+fn mismatch(key: &str) -> AppError {
+    AppError::Internal(format!("Setting {} does not have the requested type", key))
+}
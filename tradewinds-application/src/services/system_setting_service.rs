@@ -1,31 +1,106 @@
 use crate::{
     commands::system_setting::set_system_setting_command::SetSystemSettingCommand,
+    events::SystemSettingChangedEvent,
     interfaces::system_setting_service::ISystemSettingService,
-    queries::system_setting::get_system_setting_query::GetSystemSettingQuery,
+    queries::system_setting::{GetSystemModeQuery, GetSystemSettingQuery, ListSystemSettingsQuery},
+    services::settings::Settings,
 };
-use std::sync::Arc;
-use tradewinds_domain::entities::system_setting::SystemSetting;
+use std::collections::HashMap;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+use tradewinds_common::tenant::is_platform_tenant;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_domain::entities::system_setting::{EffectiveSetting, SYSTEM_SETTING_AGGREGATE_TYPE};
 use tradewinds_domain::repositories::system_setting_repository::SystemSettingRepository;
-use tradewinds_error::AppResult;
+use tradewinds_domain::value_objects::system_setting::{SettingRegistry, SystemMode};
+use tradewinds_error::{AppError, AppResult};
+
+/// 维护模式与只读模式在实例内的缓存时长，其他实例切换后最迟在此时长内生效
+const SYSTEM_MODE_REFRESH: Duration = Duration::from_secs(2);
 
 #[derive(Clone)]
 pub struct SystemSettingService {
     system_setting_repo: Arc<dyn SystemSettingRepository>,
+    settings: Settings,
+    system_mode: Arc<RwLock<Option<(Instant, SystemMode)>>>,
 }
 
 impl SystemSettingService {
     pub fn new(system_setting_repo: Arc<dyn SystemSettingRepository>) -> Self {
-        Self { system_setting_repo }
+        Self {
+            settings: Settings::new(system_setting_repo.clone()),
+            system_setting_repo,
+            system_mode: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    fn cached_system_mode(&self, fresh_only: bool) -> Option<SystemMode> {
+        let cached = self.system_mode.read().unwrap_or_else(PoisonError::into_inner);
+        cached
+            .as_ref()
+            .filter(|(loaded_at, _)| !fresh_only || loaded_at.elapsed() < SYSTEM_MODE_REFRESH)
+            .map(|(_, mode)| mode.clone())
     }
 }
 
 #[async_trait::async_trait]
 impl ISystemSettingService for SystemSettingService {
-    async fn get_by_key(&self, query: GetSystemSettingQuery) -> AppResult<Option<SystemSetting>> {
-        self.system_setting_repo.get_by_key(&query.key).await
+    async fn get_by_key(&self, query: GetSystemSettingQuery) -> AppResult<EffectiveSetting> {
+        self.settings.effective(query.key.value()).await
+    }
+
+    async fn list(&self, query: ListSystemSettingsQuery) -> AppResult<Vec<EffectiveSetting>> {
+        let stored: HashMap<String, _> = self
+            .system_setting_repo
+            .find_all()
+            .await?
+            .into_iter()
+            .map(|setting| (setting.key.value().to_string(), setting))
+            .collect();
+        SettingRegistry::all()
+            .iter()
+            .filter(|definition| query.category.is_none_or(|category| definition.category == category))
+            .map(|definition| EffectiveSetting::resolve(definition, stored.get(definition.key)))
+            .collect()
     }
 
     async fn set_value(&self, cmd: SetSystemSettingCommand) -> AppResult<()> {
-        self.system_setting_repo.set_value(&cmd.key, &cmd.value).await
+        let definition = SettingRegistry::require(cmd.key.value())?;
+        if definition.platform && !is_platform_tenant() {
+            return Err(AppError::Forbidden(format!(
+                "Setting {} applies to all tenants and can only be changed from the platform tenant",
+                definition.key
+            )));
+        }
+        let value = definition.validate(cmd.value.value())?;
+        let updated_by = cmd.updated_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = SystemSettingChangedEvent::new(cmd.key.value(), updated_by);
+        let message = OutboxMessage::from_event(SYSTEM_SETTING_AGGREGATE_TYPE, cmd.key.value(), &event)?;
+        self.system_setting_repo.set_value(&cmd.key, &value, &[message]).await?;
+        if definition.platform {
+            *self.system_mode.write().unwrap_or_else(PoisonError::into_inner) = None;
+        }
+        Ok(())
+    }
+
+    /// 读取失败时沿用上次读取的状态，避免数据库短暂不可用时所有请求都失败
+    async fn system_mode(&self, _query: GetSystemModeQuery) -> AppResult<SystemMode> {
+        if let Some(mode) = self.cached_system_mode(true) {
+            return Ok(mode);
+        }
+        match self.settings.system_mode().await {
+            Ok(mode) => {
+                *self.system_mode.write().unwrap_or_else(PoisonError::into_inner) =
+                    Some((Instant::now(), mode.clone()));
+                Ok(mode)
+            }
+            Err(e) => match self.cached_system_mode(false) {
+                Some(mode) => {
+                    tracing::warn!("Failed to refresh system mode, keeping the last known state: {}", e);
+                    Ok(mode)
+                }
+                None => Err(e),
+            },
+        }
     }
 }
@@ -0,0 +1,231 @@
+use crate::commands::tenant::{CreateTenantCommand, UpdateTenantCommand};
+use crate::events::{TenantCreatedEvent, TenantUpdatedEvent};
+use crate::interfaces::ITenantService;
+use crate::queries::tenant::{GetTenantByIdQuery, ListTenantsQuery, ResolveTenantQuery};
+use tradewinds_common::PaginatedResult;
+use tradewinds_common::tenant::{DEFAULT_TENANT_ID, current_tenant_id, with_tenant};
+use tradewinds_domain::aggregates::{
+    permission_aggregate::PermissionAggregate, role_aggregate::RoleAggregate, user_aggregate::UserAggregate,
+};
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_domain::entities::tenant::{TENANT_AGGREGATE_TYPE, Tenant};
+use tradewinds_domain::policies::SUPER_ADMIN_ROLE_CODE;
+use tradewinds_domain::repositories::{
+    PermissionAggregateRepository, RoleAggregateRepository, RoleRepository, TenantRepository, UserAggregateRepository,
+    UserRepository, UserRoleRepository,
+};
+use tradewinds_domain::services::auth::PasswordService;
+use tradewinds_domain::value_objects::permission::{PermissionCode, PermissionName, PermissionSort, PermissionType};
+use tradewinds_domain::value_objects::role::{RoleCode, RoleName};
+use tradewinds_domain::value_objects::{Password, RoleAssignment, RoleStatus, TenantHost, TenantId, UserId};
+
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct TenantService {
+    tenant_repo: Arc<dyn TenantRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    user_agg_repo: Arc<dyn UserAggregateRepository>,
+    role_agg_repo: Arc<dyn RoleAggregateRepository>,
+    permission_agg_repo: Arc<dyn PermissionAggregateRepository>,
+    password_service: Arc<dyn PasswordService>,
+}
+
+/// 租户服务的依赖
+pub struct TenantServiceDeps {
+    pub tenant_repo: Arc<dyn TenantRepository>,
+    pub user_repo: Arc<dyn UserRepository>,
+    pub role_repo: Arc<dyn RoleRepository>,
+    pub user_role_repo: Arc<dyn UserRoleRepository>,
+    pub user_agg_repo: Arc<dyn UserAggregateRepository>,
+    pub role_agg_repo: Arc<dyn RoleAggregateRepository>,
+    pub permission_agg_repo: Arc<dyn PermissionAggregateRepository>,
+    pub password_service: Arc<dyn PasswordService>,
+}
+
+impl TenantService {
+    pub fn new(deps: TenantServiceDeps) -> Self {
+        let TenantServiceDeps {
+            tenant_repo,
+            user_repo,
+            role_repo,
+            user_role_repo,
+            user_agg_repo,
+            role_agg_repo,
+            permission_agg_repo,
+            password_service,
+        } = deps;
+        Self {
+            tenant_repo,
+            user_repo,
+            role_repo,
+            user_role_repo,
+            user_agg_repo,
+            role_agg_repo,
+            permission_agg_repo,
+            password_service,
+        }
+    }
+
+    /// 平台管理员：平台默认租户内、启用且持有超级管理员角色（含经由用户组）的用户
+    async fn ensure_platform_admin(&self, actor_id: &UserId) -> AppResult<()> {
+        let denied = || AppError::Forbidden("Only platform administrators can manage tenants".into());
+        if current_tenant_id() != DEFAULT_TENANT_ID {
+            return Err(denied());
+        }
+        let actor = self.user_repo.find_by_id(actor_id).await?.filter(|u| u.status.is_active()).ok_or_else(denied)?;
+        let super_admin = self
+            .role_repo
+            .find_by_code(&RoleCode::new(SUPER_ADMIN_ROLE_CODE.into())?)
+            .await?
+            .filter(|role| role.is_active())
+            .ok_or_else(denied)?;
+        let assignments = self.user_role_repo.find_assignments_by_user_id(&actor.id).await?;
+        if !RoleAssignment::effective_role_ids(&assignments).contains(&super_admin.id) {
+            return Err(denied());
+        }
+        Ok(())
+    }
+
+    async fn find_tenant(&self, id: &TenantId) -> AppResult<Tenant> {
+        self.tenant_repo
+            .find_by_id(id)
+            .await?
+            .filter(|tenant| !tenant.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("Tenant not found".into()))
+    }
+
+    /// 域名只能绑定到一个租户
+    async fn ensure_host_available(&self, host: &TenantHost, current: Option<&TenantId>) -> AppResult<()> {
+        if let Some(existing) = self.tenant_repo.find_by_host(host).await?
+            && Some(&existing.id) != current
+        {
+            return Err(AppError::Conflict(format!("Tenant host already bound: {}", host)));
+        }
+        Ok(())
+    }
+
+    /// 在新租户内初始化内置的全部接口权限、超级管理员角色与初始管理员
+    async fn provision(&self, cmd: &CreateTenantCommand) -> AppResult<()> {
+        let mut permission_agg = PermissionAggregate::create(
+            PermissionName::new("全部接口")?,
+            Some(PermissionCode::new(PermissionCode::WILDCARD)?),
+            PermissionType::Api,
+            None,
+            None,
+            None,
+            None,
+            PermissionSort::new(0)?,
+        )?;
+        permission_agg.permission.built_in = true;
+        self.permission_agg_repo.create(&permission_agg).await?;
+
+        let mut role_agg = RoleAggregate::create(
+            RoleName::new("超级管理员")?,
+            RoleCode::new(SUPER_ADMIN_ROLE_CODE.into())?,
+            None,
+            Some(vec![permission_agg.permission.id.clone()]),
+            None,
+            RoleStatus::Active,
+        )?;
+        role_agg.role.built_in = true;
+        self.role_agg_repo.create(&role_agg).await?;
+
+        let hashed_password = self.password_service.hash(cmd.admin_password.as_ref()).await?;
+        let mut user_agg = UserAggregate::create_with_roles(
+            cmd.admin_username.clone(),
+            cmd.admin_email.clone(),
+            Password::new(hashed_password)?,
+            None,
+            None,
+            None,
+            vec![role_agg.role.id.clone()],
+        )?;
+        user_agg.user.created_by = Some(cmd.created_by.clone());
+        self.user_agg_repo.create(&user_agg).await
+    }
+}
+
+#[async_trait::async_trait]
+impl ITenantService for TenantService {
+    async fn create_tenant(&self, cmd: CreateTenantCommand) -> AppResult<Tenant> {
+        self.ensure_platform_admin(&cmd.created_by).await?;
+        if self.tenant_repo.find_by_code(&cmd.code).await?.is_some() {
+            return Err(AppError::Conflict(format!("Tenant code already exists: {}", cmd.code)));
+        }
+        if let Some(host) = &cmd.host {
+            self.ensure_host_available(host, None).await?;
+        }
+
+        // 先初始化租户数据再登记租户，初始化失败时租户不可被解析
+        let tenant = Tenant::create(cmd.code.clone(), cmd.name.clone(), cmd.host.clone());
+        with_tenant(tenant.id.value(), self.provision(&cmd)).await?;
+
+        let event = TenantCreatedEvent::new(
+            tenant.id.value(),
+            tenant.code.value(),
+            tenant.name.value(),
+            cmd.created_by.value(),
+        );
+        let message = OutboxMessage::from_event(TENANT_AGGREGATE_TYPE, tenant.id.value(), &event)?;
+        self.tenant_repo.create(&tenant, &[message]).await?;
+        Ok(tenant)
+    }
+
+    async fn update_tenant(&self, cmd: UpdateTenantCommand) -> AppResult<()> {
+        self.ensure_platform_admin(&cmd.updated_by).await?;
+        let mut tenant = self.find_tenant(&cmd.id).await?;
+        if let Some(Some(host)) = &cmd.host {
+            self.ensure_host_available(host, Some(&cmd.id)).await?;
+        }
+        tenant.update(cmd.name, cmd.host, cmd.status)?;
+
+        let event = TenantUpdatedEvent::new(
+            tenant.id.value(),
+            tenant.name.value(),
+            tenant.status.value(),
+            cmd.updated_by.value(),
+        );
+        let message = OutboxMessage::from_event(TENANT_AGGREGATE_TYPE, tenant.id.value(), &event)?;
+        self.tenant_repo.save(&tenant, &[message]).await
+    }
+
+    async fn get_tenant_by_id(&self, query: GetTenantByIdQuery) -> AppResult<Tenant> {
+        self.ensure_platform_admin(&query.actor_id).await?;
+        self.find_tenant(&query.tenant_id).await
+    }
+
+    async fn list_tenants(&self, query: ListTenantsQuery) -> AppResult<PaginatedResult<Tenant>> {
+        self.ensure_platform_admin(&query.actor_id).await?;
+        let (limit, offset) = query.pagination();
+        let (items, total) = self.tenant_repo.search(query.keyword.as_deref(), query.status, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn resolve_tenant(&self, query: ResolveTenantQuery) -> AppResult<Tenant> {
+        let tenant = if let Some(id) = &query.id {
+            self.tenant_repo.find_by_id(id).await?
+        } else if let Some(code) = &query.code {
+            self.tenant_repo.find_by_code(code).await?
+        } else {
+            let by_host = match &query.host {
+                Some(host) => self.tenant_repo.find_by_host(host).await?,
+                None => None,
+            };
+            match by_host {
+                Some(tenant) => Some(tenant),
+                None => self.tenant_repo.find_by_id(&TenantId::new(DEFAULT_TENANT_ID.to_string())?).await?,
+            }
+        };
+        let tenant = tenant
+            .filter(|tenant| !tenant.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("Tenant not found".into()))?;
+        if !tenant.is_active() {
+            return Err(AppError::Forbidden(format!("Tenant is disabled: {}", tenant.code)));
+        }
+        Ok(tenant)
+    }
+}
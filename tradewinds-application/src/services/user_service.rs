@@ -8,14 +8,17 @@ use crate::{
 use tradewinds_domain::{
     aggregates::user_aggregate::UserAggregate,
     entities::{
+        department::Department,
         permission::Permission, 
         role::Role, 
         user::User
     },
+    policies::{AccessExplanation, AccessExplanationPolicy, DataScopePolicy, PermissionPolicy},
     repositories::{
-        RoleRepository, SystemSettingRepository, UserAggregateRepository, UserRepository, UserRoleRepository
+        AccessPolicyRepository, DepartmentRepository, PermissionRepository, RoleRepository, SodRuleRepository, SystemSettingRepository, UserAggregateRepository,
+        UserRepository, UserRoleRepository, UserSearchFilter
     },
-    services::auth::PasswordService,
+    services::{auth::PasswordService, Event},
     value_objects::auth::auth_password::Password,
     value_objects::user::user_id::UserId,
     value_objects::user::Email,
@@ -23,13 +26,25 @@ use tradewinds_domain::{
     value_objects::role_permission::role_permission_id::RolePermissionId,
     value_objects::auth::auth_username::AuthUsername,
     value_objects::user::UserStatus,
-    value_objects::system_setting::{SystemSettingKey, SystemSettingValue},
+    value_objects::scope::DataScope,
+    value_objects::department::DepartmentId,
 };
 
+use crate::events::{
+    UserCreatedEvent, UserDeletedEvent, UserPasswordChangedEvent, UserProfileUpdatedEvent, UserRoleAssignedEvent,
+    UserRoleRevokedEvent, UserStatusChangedEvent, UserUpdatedEvent,
+};
 use crate::queries::system_setting::get_system_setting_query::GetSystemSettingQuery;
+use crate::services::access_policy_guard::{
+    ACTION_USER_ASSIGN_ROLE, ACTION_USER_DELETE, ACTION_USER_RESET_PASSWORD, ACTION_USER_REVOKE_ROLE,
+    ACTION_USER_UPDATE, AccessPolicyGuard,
+};
+use crate::services::admin_safeguard_guard::AdminSafeguardGuard;
+use crate::services::separation_of_duty_guard::SeparationOfDutyGuard;
+use crate::services::settings::Settings;
 use std::sync::Arc;
 use tradewinds_common::PaginatedResult;
-use tradewinds_domain::value_objects::RoleId;
+use tradewinds_domain::value_objects::{RoleAssignment, RoleId, RoleSource};
 use tradewinds_error::{AppError, AppResult};
 
 #[derive(Clone)]
@@ -37,21 +52,119 @@ pub struct UserService {
     user_agg_repo: Arc<dyn UserAggregateRepository>,
     user_repo: Arc<dyn UserRepository>,
     role_repo: Arc<dyn RoleRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    department_repo: Arc<dyn DepartmentRepository>,
     user_role_repo: Arc<dyn UserRoleRepository>,
     password_service: Arc<dyn PasswordService>,
-    system_setting_repo: Arc<dyn SystemSettingRepository>,
+    settings: Settings,
+    sod_guard: SeparationOfDutyGuard,
+    admin_guard: AdminSafeguardGuard,
+    policy_guard: AccessPolicyGuard,
+}
+
+/// 用户服务的依赖
+pub struct UserServiceDeps {
+    pub user_agg_repo: Arc<dyn UserAggregateRepository>,
+    pub user_repo: Arc<dyn UserRepository>,
+    pub role_repo: Arc<dyn RoleRepository>,
+    pub permission_repo: Arc<dyn PermissionRepository>,
+    pub department_repo: Arc<dyn DepartmentRepository>,
+    pub user_role_repo: Arc<dyn UserRoleRepository>,
+    pub password_service: Arc<dyn PasswordService>,
+    pub system_setting_repo: Arc<dyn SystemSettingRepository>,
+    pub sod_rule_repo: Arc<dyn SodRuleRepository>,
+    pub access_policy_repo: Arc<dyn AccessPolicyRepository>,
 }
 
 impl UserService {
-    pub fn new(
-        user_agg_repo: Arc<dyn UserAggregateRepository>,
-        user_repo: Arc<dyn UserRepository>,
-        role_repo: Arc<dyn RoleRepository>,
-        user_role_repo: Arc<dyn UserRoleRepository>,
-        password_service: Arc<dyn PasswordService>,
-        system_setting_repo: Arc<dyn SystemSettingRepository>,
-    ) -> Self {
-        Self { user_agg_repo, user_repo, role_repo, user_role_repo, password_service, system_setting_repo }
+    pub fn new(deps: UserServiceDeps) -> Self {
+        let UserServiceDeps {
+            user_agg_repo,
+            user_repo,
+            role_repo,
+            permission_repo,
+            department_repo,
+            user_role_repo,
+            password_service,
+            system_setting_repo,
+            sod_rule_repo,
+            access_policy_repo,
+        } = deps;
+        let sod_guard = SeparationOfDutyGuard::new(sod_rule_repo, user_role_repo.clone());
+        let admin_guard = AdminSafeguardGuard::new(role_repo.clone(), user_repo.clone(), user_role_repo.clone());
+        let policy_guard =
+            AccessPolicyGuard::new(access_policy_repo, user_repo.clone(), role_repo.clone(), user_role_repo.clone());
+        Self {
+            user_agg_repo,
+            user_repo,
+            role_repo,
+            permission_repo,
+            department_repo,
+            user_role_repo,
+            password_service,
+            settings: Settings::new(system_setting_repo),
+            sod_guard,
+            admin_guard,
+            policy_guard,
+        }
+    }
+
+    /// 角色名称，仅用于事件内容，查询失败时为空
+    async fn role_name(&self, role_id: &RoleId) -> String {
+        match self.role_repo.find_by_id(role_id).await {
+            Ok(Some(role)) => role.name.value().to_string(),
+            _ => String::new(),
+        }
+    }
+
+    /// 查找用户要加入的部门，部门必须存在且未删除
+    async fn find_department(&self, department_id: &DepartmentId) -> AppResult<Department> {
+        self.department_repo
+            .find_by_id(department_id)
+            .await?
+            .filter(|d| !d.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound(format!("Department {} not found", department_id)))
+    }
+
+    /// 根据主体的角色解析数据范围
+    ///
+    /// 作用于用户列表及按ID/用户名/邮箱查询；用户角色、权限等按用户ID的关联查询仅受 RBAC 权限控制
+    async fn resolve_data_scope(&self, principal_id: Option<&UserId>) -> AppResult<DataScope> {
+        let Some(principal_id) = principal_id else {
+            return Ok(DataScope::All);
+        };
+        let principal = self
+            .user_repo
+            .find_by_id(principal_id)
+            .await?
+            .ok_or_else(|| AppError::Unauthorized(format!("Principal not found: {}", principal_id)))?;
+
+        let role_ids = self.effective_role_ids(principal_id).await?;
+        let roles = if role_ids.is_empty() { Vec::new() } else { self.role_repo.find_by_ids(&role_ids).await? };
+        let custom_role_ids = DataScopePolicy::custom_role_ids(&roles);
+        let custom_departments = if custom_role_ids.is_empty() {
+            Vec::new()
+        } else {
+            self.role_repo.find_data_scope_departments(&custom_role_ids).await?
+        };
+
+        Ok(DataScopePolicy::resolve(&principal, &roles, &custom_departments))
+    }
+
+    /// 按主体的数据范围过滤单个用户，范围外的用户视同不存在
+    async fn visible_user(&self, principal_id: Option<&UserId>, user: Option<User>) -> AppResult<User> {
+        let user = user.ok_or_else(|| AppError::NotFound("User not found".into()))?;
+        let data_scope = self.resolve_data_scope(principal_id).await?;
+        if !self.user_repo.is_visible(&data_scope, &user.id).await? {
+            return Err(AppError::NotFound("User not found".into()));
+        }
+        Ok(user)
+    }
+
+    /// 用户的有效角色：直接分配的角色加上所在用户组携带的角色
+    async fn effective_role_ids(&self, user_id: &UserId) -> AppResult<Vec<RoleId>> {
+        let assignments = self.user_role_repo.find_assignments_by_user_id(user_id).await?;
+        Ok(RoleAssignment::effective_role_ids(&assignments))
     }
 }
 
@@ -59,23 +172,22 @@ impl UserService {
 impl IUserService for UserService {
     async fn list_users(&self, query: ListUsersQuery) -> AppResult<PaginatedResult<(User, Vec<Role>)>> {
         let (limit, offset) = query.pagination();
-        let username_obj = query.username.as_ref().map(|s| AuthUsername::new(s.clone())).transpose()?;
-        let username_ref = username_obj.as_ref();
-        let phone_ref = query.phone.as_deref();
-        let email_obj = query.email.as_ref().map(|s| Email::new(s.clone())).transpose()?;
-        let email_ref = email_obj.as_ref();
-        let status_obj = query.status.map(UserStatus::from_i32).transpose()?;
-        let (users, total) = self
-            .user_repo
-            .search(username_ref, phone_ref, email_ref, status_obj, query.show_deleted, limit, offset)
-            .await?;
+        let filter = UserSearchFilter {
+            data_scope: self.resolve_data_scope(query.principal_id.as_ref()).await?,
+            department_tree: query.department_id.clone(),
+            username: query.username.as_ref().map(|s| AuthUsername::new(s.clone())).transpose()?,
+            phone: query.phone.clone(),
+            email: query.email.as_ref().map(|s| Email::new(s.clone())).transpose()?,
+            status: query.status.map(UserStatus::from_i32).transpose()?,
+            show_deleted: query.show_deleted,
+            ..Default::default()
+        };
+        let (users, total) = self.user_repo.search(&filter, limit, offset).await?;
 
         // 为每个用户查询角色信息
         let mut users_with_roles = Vec::new();
         for user in users {
-            let user_roles = self.user_role_repo.find_by_user_id(&user.id).await?;
-            let role_ids: Vec<_> = user_roles.iter().map(|ur| ur.role_id.clone()).collect();
-
+            let role_ids = self.effective_role_ids(&user.id).await?;
             let roles = if !role_ids.is_empty() { self.role_repo.find_by_ids(&role_ids).await? } else { Vec::new() };
 
             users_with_roles.push((user, roles));
@@ -108,7 +220,7 @@ impl IUserService for UserService {
             Vec::new()
         };
 
-        let user_agg = if role_ids.is_empty() {
+        let mut user_agg = if role_ids.is_empty() {
             UserAggregate::create(cmd.username, cmd.email, hashed_password, cmd.real_name, cmd.phone, cmd.avatar)?
         } else {
             UserAggregate::create_with_roles(
@@ -122,6 +234,20 @@ impl IUserService for UserService {
             )?
         };
 
+        if let Some(department_id) = &cmd.department_id {
+            let department = self.find_department(department_id).await?;
+            user_agg.assign_department(Some(&department))?;
+        }
+        user_agg.user.created_by = cmd.created_by;
+        if !user_agg.roles.is_empty() {
+            self.sod_guard.check(&[(user_agg.user.id.clone(), user_agg.roles.clone())]).await?;
+        }
+
+        let user = &user_agg.user;
+        let created_by = user.created_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let event = UserCreatedEvent::new(user.id.value(), user.username.value(), user.email.value(), created_by);
+        user_agg.record_event(&event)?;
+
         self.user_agg_repo.create(&user_agg).await?;
 
         Ok(user_agg.user)
@@ -130,8 +256,53 @@ impl IUserService for UserService {
     async fn update_user(&self, cmd: UpdateUserCommand) -> AppResult<()> {
         let mut user_agg =
             self.user_agg_repo.find_by_id(&cmd.id).await?.ok_or_else(|| AppError::NotFound("User not found".into()))?;
+        let resource = AccessPolicyGuard::user_attributes(&user_agg.user);
 
+        let roles_changed = cmd.role_ids.is_some();
+        let status_changed = cmd.status.is_some();
+        let profile_changed = cmd.real_name.is_some() || cmd.phone.is_some();
         user_agg.update(cmd.real_name, cmd.phone, cmd.avatar, cmd.status, cmd.email, cmd.role_ids)?;
+        if roles_changed || status_changed {
+            let effective = self.sod_guard.effective_with_direct(&cmd.id, &user_agg.roles).await?;
+            if roles_changed {
+                self.sod_guard.check(&[(cmd.id.clone(), effective.clone())]).await?;
+            }
+            let remaining = user_agg.user.status.is_active().then_some(effective);
+            self.admin_guard.check(&[(cmd.id.clone(), remaining)]).await?;
+        }
+        if let Some(department_id) = &cmd.department_id {
+            let department = match department_id {
+                Some(department_id) => Some(self.find_department(department_id).await?),
+                None => None,
+            };
+            user_agg.assign_department(department.as_ref())?;
+        }
+        self.policy_guard.authorize(cmd.updated_by.as_ref(), ACTION_USER_UPDATE, resource).await?;
+
+        let user = &user_agg.user;
+        let updated_by = cmd.updated_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        let mut events: Vec<Box<dyn Event>> =
+            vec![Box::new(UserUpdatedEvent::new(user.id.value(), user.username.value(), updated_by))];
+        if profile_changed {
+            events.push(Box::new(UserProfileUpdatedEvent::new(
+                user.id.value(),
+                user.username.value(),
+                user.real_name.as_ref().map(|n| n.value()),
+                user.phone.as_ref().map(|p| p.value()),
+            )));
+        }
+        if status_changed {
+            let status = user.status.to_string();
+            events.push(Box::new(UserStatusChangedEvent::new(
+                user.id.value(),
+                user.username.value(),
+                &status,
+                updated_by,
+            )));
+        }
+        for event in &events {
+            user_agg.record_event(event.as_ref())?;
+        }
 
         self.user_agg_repo.save(&user_agg).await?;
 
@@ -139,26 +310,31 @@ impl IUserService for UserService {
     }
 
     async fn delete_user(&self, cmd: DeleteUserCommand) -> AppResult<()> {
-        self.user_agg_repo.delete_by_id(&cmd.id).await?;
-        Ok(())
+        self.admin_guard.check(&[(cmd.id.clone(), None)]).await?;
+        let Some(mut user_agg) = self.user_agg_repo.find_by_id(&cmd.id).await? else {
+            return Ok(());
+        };
+        let resource = AccessPolicyGuard::user_attributes(&user_agg.user);
+        self.policy_guard.authorize(cmd.deleted_by.as_ref(), ACTION_USER_DELETE, resource).await?;
+
+        let deleted_by = cmd.deleted_by.as_ref().map(|id| id.value()).unwrap_or_default();
+        user_agg.record_event(&UserDeletedEvent::new(cmd.id.value(), deleted_by))?;
+        self.user_agg_repo.delete(&user_agg).await
     }
 
     async fn reset_password(&self, cmd: ResetPasswordCommand) -> AppResult<()> {
         let mut user_agg =
             self.user_agg_repo.find_by_id(&cmd.id).await?.ok_or_else(|| AppError::NotFound("User not found".into()))?;
+        let resource = AccessPolicyGuard::user_attributes(&user_agg.user);
+        self.policy_guard.authorize(cmd.reset_by.as_ref(), ACTION_USER_RESET_PASSWORD, resource).await?;
 
-        let default_password_vo = self
-            .system_setting_repo
-            .get_by_key(&SystemSettingKey::new("default_password".to_string()).unwrap())
-            .await?
-            .map(|setting| setting.value)
-            .filter(|v| !v.value().trim().is_empty())
-            .unwrap_or_else(|| SystemSettingValue::new("123456".to_string()).unwrap());
+        let default_password = self.settings.default_password().await?;
 
         // 先 hash 明文密码
-        let hashed_password = self.password_service.hash(default_password_vo.value()).await?;
+        let hashed_password = self.password_service.hash(&default_password).await?;
         let password = Password::new(hashed_password)?;
         user_agg.reset_password(password);
+        user_agg.record_event(&UserPasswordChangedEvent::new(cmd.id.value()))?;
         self.user_agg_repo.save(&user_agg).await?;
 
         Ok(())
@@ -170,7 +346,25 @@ impl IUserService for UserService {
             .await?
             .ok_or_else(|| AppError::NotFound("User not found".into()))?;
 
-        user_agg.assign_role(&cmd.role_id)?;
+        match &cmd.access_request_id {
+            Some(request_id) => user_agg.assign_role_by_request(&cmd.role_id, request_id)?,
+            None => user_agg.assign_role(&cmd.role_id)?,
+        }
+        let effective = self.sod_guard.effective_with_direct(&cmd.user_id, &user_agg.roles).await?;
+        self.sod_guard.check(&[(cmd.user_id.clone(), effective)]).await?;
+        let mut resource = AccessPolicyGuard::user_attributes(&user_agg.user);
+        resource.insert("role_id".into(), cmd.role_id.value().into());
+        self.policy_guard.authorize(cmd.assigned_by.as_ref(), ACTION_USER_ASSIGN_ROLE, resource).await?;
+
+        let role_name = self.role_name(&cmd.role_id).await;
+        let event = UserRoleAssignedEvent::new(
+            cmd.user_id.value(),
+            user_agg.user.username.value(),
+            cmd.role_id.value(),
+            &role_name,
+            cmd.assigned_by.as_ref().map(|id| id.value()).unwrap_or_default(),
+        );
+        user_agg.record_event(&event)?;
 
         self.user_agg_repo.save(&user_agg).await?;
         Ok(())
@@ -184,57 +378,93 @@ impl IUserService for UserService {
             .ok_or_else(|| AppError::NotFound("User not found".into()))?;
 
         user_agg.revoke_role(&cmd.role_id)?;
+        let effective = self.sod_guard.effective_with_direct(&cmd.user_id, &user_agg.roles).await?;
+        self.admin_guard.check(&[(cmd.user_id.clone(), Some(effective))]).await?;
+        let mut resource = AccessPolicyGuard::user_attributes(&user_agg.user);
+        resource.insert("role_id".into(), cmd.role_id.value().into());
+        self.policy_guard.authorize(cmd.revoked_by.as_ref(), ACTION_USER_REVOKE_ROLE, resource).await?;
+
+        let role_name = self.role_name(&cmd.role_id).await;
+        let event = UserRoleRevokedEvent::new(
+            cmd.user_id.value(),
+            user_agg.user.username.value(),
+            cmd.role_id.value(),
+            &role_name,
+            cmd.revoked_by.as_ref().map(|id| id.value()).unwrap_or_default(),
+        );
+        user_agg.record_event(&event)?;
 
         self.user_agg_repo.save(&user_agg).await?;
         Ok(())
     }
 
     async fn get_user_by_id(&self, query: GetUserByIdQuery) -> AppResult<User> {
-        self.user_repo.find_by_id(&query.user_id).await?.ok_or_else(|| AppError::NotFound("User not found".into()))
+        let user = self.user_repo.find_by_id(&query.user_id).await?;
+        self.visible_user(query.principal_id.as_ref(), user).await
     }
 
     async fn get_user_by_username(&self, query: GetUserByUsernameQuery) -> AppResult<User> {
-        self.user_repo
-            .find_by_username(&query.username)
-            .await?
-            .ok_or_else(|| AppError::NotFound("User not found".into()))
+        let user = self.user_repo.find_by_username(&query.username).await?;
+        self.visible_user(query.principal_id.as_ref(), user).await
     }
 
     async fn get_user_by_email(&self, query: GetUserByEmailQuery) -> AppResult<User> {
-        self.user_repo
-            .find_by_email(&query.user_email)
-            .await?
-            .ok_or_else(|| AppError::NotFound("User not found".into()))
+        let user = self.user_repo.find_by_email(&query.user_email).await?;
+        self.visible_user(query.principal_id.as_ref(), user).await
     }
 
-    async fn get_user_roles(&self, query: GetUserRolesQuery) -> AppResult<Vec<Role>> {
-        let user_roles = self.user_role_repo.find_by_user_id(&query.user_id).await?;
-
-        let role_ids: Vec<_> = user_roles.iter().map(|ur| ur.role_id.clone()).collect();
+    async fn get_user_roles(&self, query: GetUserRolesQuery) -> AppResult<Vec<(Role, Vec<RoleSource>)>> {
+        let assignments = self.user_role_repo.find_assignments_by_user_id(&query.user_id).await?;
 
+        let role_ids = RoleAssignment::effective_role_ids(&assignments);
         if role_ids.is_empty() {
             return Ok(vec![]);
         }
         let roles = self.role_repo.find_by_ids(&role_ids).await?;
 
-        Ok(roles)
+        // 附上每个角色的来源（直接分配或经由哪个用户组）
+        Ok(roles
+            .into_iter()
+            .map(|role| {
+                let sources = RoleAssignment::sources_of(&assignments, &role.id).into_iter().cloned().collect();
+                (role, sources)
+            })
+            .collect())
     }
 
     async fn get_user_permissions(&self, query: GetUserPermissionsQuery) -> AppResult<Vec<Permission>> {
-        // 1. 获取用户所有角色
-        let roles = self.user_role_repo.find_by_user_id(&query.user_id).await?;
-        if roles.is_empty() {
+        // 1. 获取用户所有有效角色（含用户组携带的角色）
+        let role_ids = self.effective_role_ids(&query.user_id).await?;
+        // 2. 没有角色则没有权限
+        if role_ids.is_empty() {
             return Ok(Vec::new());
         }
-        // 2. 提取所有角色ID
-        let role_ids: Vec<RoleId> = roles.iter().map(|r| RoleId::new(r.role_id.value().to_string()).unwrap()).collect();
-        // 3. 查询这些角色拥有的所有权限
-        let mut all_permissions = self.role_repo.find_permissions_by_ids(&role_ids).await?;
+        // 3. 查询角色本身与这些角色上的全部授权（允许与拒绝）
+        let roles = self.role_repo.find_by_ids(&role_ids).await?;
+        let grants = self.role_repo.find_permission_grants(&role_ids).await?;
 
-        // 4. 去重
+        // 4. 按权限策略展开通配，剔除被拒绝、未启用角色授予、未启用或上级菜单未启用的权限（与访问说明一致）
+        let catalog = self.permission_repo.find_all().await?;
+        let mut all_permissions = PermissionPolicy::effective_permissions(&catalog, &roles, &grants);
         all_permissions.sort_by_key(|p| p.id.clone());
-        all_permissions.dedup_by_key(|a| a.id.clone());
 
         Ok(all_permissions)
     }
+
+    async fn explain_user_access(&self, query: ExplainUserAccessQuery) -> AppResult<AccessExplanation> {
+        let user = self
+            .user_repo
+            .find_by_id(&query.user_id)
+            .await?
+            .filter(|u| !u.status.is_deleted())
+            .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+
+        // 有效角色及来源、这些角色上的全部授权，以及含上级菜单的权限全集
+        let roles = self.get_user_roles(GetUserRolesQuery { user_id: query.user_id.clone() }).await?;
+        let role_ids: Vec<RoleId> = roles.iter().map(|(role, _)| role.id.clone()).collect();
+        let grants = self.role_repo.find_permission_grants(&role_ids).await?;
+        let catalog = self.permission_repo.find_all().await?;
+
+        Ok(AccessExplanationPolicy::explain(&user, &query.code, &roles, &grants, &catalog))
+    }
 }
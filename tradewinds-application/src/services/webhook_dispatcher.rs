@@ -0,0 +1,155 @@
+use chrono::Utc;
+use futures::future::join_all;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_domain::entities::webhook::Webhook;
+use tradewinds_domain::entities::webhook_delivery::{WebhookDelivery, WebhookRetryPolicy};
+use tradewinds_domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use tradewinds_domain::services::WebhookSender;
+use tradewinds_domain::value_objects::webhook::WebhookId;
+use tradewinds_error::AppResult;
+
+/// Webhook 投递任务的调优参数
+#[derive(Debug, Clone)]
+pub struct WebhookDispatchOptions {
+    pub retry_policy: WebhookRetryPolicy,
+    /// 每轮每个租户认领的最大记录数
+    pub batch_size: u64,
+    /// 端点连续失败达到该次数后自动停用
+    pub disable_after_failures: u32,
+    /// 认领租约时长（秒）
+    pub lease_secs: i64,
+    /// 单次请求超时
+    pub send_timeout: Duration,
+}
+
+/// Webhook 投递任务
+///
+/// 轮询各租户到期的投递记录，以租约认领后发送，多个实例同时运行时同一记录不会被并发发送。
+/// 2xx 响应视为成功，其余响应与连接失败按重试策略退避重试。
+/// 不同端点的记录并发发送，同一端点按写入顺序依次发送；端点本轮发送失败，或剩余时间不足一次请求超时
+/// （继续发送可能在租约到期后与其他实例重复投递）时，该端点其余的记录释放到下一轮，慢端点不会拖住其他端点。
+/// 端点连续失败达到 `disable_after_failures` 次后自动停用，停用或已删除端点的待投递记录不再发送，
+/// 可在端点重新启用后人工重新投递。
+pub struct WebhookDispatcher {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+    sender: Arc<dyn WebhookSender>,
+    options: WebhookDispatchOptions,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        webhook_repo: Arc<dyn WebhookRepository>,
+        delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+        sender: Arc<dyn WebhookSender>,
+        options: WebhookDispatchOptions,
+    ) -> Self {
+        Self { webhook_repo, delivery_repo, sender, options }
+    }
+
+    /// 投递一轮，返回投递成功的记录数；单个租户失败不影响其他租户
+    pub async fn dispatch_once(&self) -> AppResult<u64> {
+        let mut delivered = 0;
+        for tenant_id in self.delivery_repo.find_tenant_ids_with_due(Utc::now().timestamp()).await? {
+            match with_tenant(tenant_id.clone(), self.dispatch_tenant()).await {
+                Ok(count) => delivered += count,
+                Err(e) => tracing::warn!("Failed to dispatch webhooks of tenant {}: {}", tenant_id, e),
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// 认领当前租户的一批记录，按端点分组并发发送
+    async fn dispatch_tenant(&self) -> AppResult<u64> {
+        let now = Utc::now().timestamp();
+        let mut endpoints: Vec<(WebhookId, Vec<WebhookDelivery>)> = Vec::new();
+        for delivery in self.delivery_repo.claim_due(now, self.options.lease_secs, self.options.batch_size).await? {
+            match endpoints.iter_mut().find(|(webhook_id, _)| *webhook_id == delivery.webhook_id) {
+                Some((_, deliveries)) => deliveries.push(delivery),
+                None => endpoints.push((delivery.webhook_id.clone(), vec![delivery])),
+            }
+        }
+        let results = join_all(
+            endpoints.into_iter().map(|(webhook_id, deliveries)| self.dispatch_endpoint(webhook_id, deliveries)),
+        )
+        .await;
+        let mut delivered = 0;
+        for result in results {
+            delivered += result?;
+        }
+        Ok(delivered)
+    }
+
+    /// 依次发送同一端点的记录，返回投递成功的记录数
+    async fn dispatch_endpoint(&self, webhook_id: WebhookId, deliveries: Vec<WebhookDelivery>) -> AppResult<u64> {
+        let started = Instant::now();
+        let budget = Duration::from_secs(self.options.lease_secs.max(0) as u64).saturating_sub(self.options.send_timeout);
+        let mut webhook = self.webhook_repo.find_by_id(&webhook_id).await?;
+        let mut delivered = 0;
+        let mut failed = false;
+        for mut delivery in deliveries {
+            let now = Utc::now().timestamp();
+            match webhook.as_mut() {
+                None => delivery.abandon("Webhook has been deleted"),
+                Some(webhook) if !webhook.status.is_active() => delivery.abandon("Webhook is disabled"),
+                Some(_) if failed || started.elapsed() >= budget => delivery.release(now),
+                Some(webhook) => {
+                    if self.deliver(webhook, &mut delivery, now).await? {
+                        delivered += 1;
+                    } else {
+                        failed = true;
+                    }
+                }
+            }
+            self.delivery_repo.update(&delivery).await?;
+        }
+        Ok(delivered)
+    }
+
+    /// 发送一条记录并更新端点的连续失败次数，返回是否投递成功
+    async fn deliver(&self, webhook: &mut Webhook, delivery: &mut WebhookDelivery, now: i64) -> AppResult<bool> {
+        let (response_status, error) = match self.sender.send(webhook, delivery).await {
+            Ok(status) if (200..300).contains(&status) => {
+                delivery.mark_succeeded(status, now);
+                if webhook.record_success(now) {
+                    self.webhook_repo.update(webhook).await?;
+                }
+                return Ok(true);
+            }
+            Ok(status) => (Some(status), format!("Endpoint responded with HTTP {}", status)),
+            Err(e) => (None, e.to_string()),
+        };
+        delivery.mark_failed(response_status, &error, now, &self.options.retry_policy);
+        if delivery.status.is_failed() {
+            tracing::error!(
+                "Webhook delivery {} ({}) to {} failed after {} attempts: {}",
+                delivery.id,
+                delivery.event_type,
+                webhook.url,
+                delivery.attempts,
+                error
+            );
+        } else {
+            tracing::warn!(
+                "Failed to deliver webhook {} ({}) to {}, attempt {}: {}",
+                delivery.id,
+                delivery.event_type,
+                webhook.url,
+                delivery.attempts,
+                error
+            );
+        }
+        if webhook.record_failure(now, self.options.disable_after_failures) {
+            tracing::error!(
+                "Webhook {} ({}) disabled after {} consecutive failures",
+                webhook.id,
+                webhook.url,
+                webhook.consecutive_failures
+            );
+        }
+        self.webhook_repo.update(webhook).await?;
+        Ok(false)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::commands::webhook::{
+    CreateWebhookCommand, DeleteWebhookCommand, RedeliverWebhookDeliveryCommand, UpdateWebhookCommand,
+};
+use crate::interfaces::IWebhookService;
+use crate::queries::webhook::{ListWebhookDeliveriesQuery, ListWebhooksQuery};
+use tradewinds_common::PaginatedResult;
+use tradewinds_domain::entities::webhook::Webhook;
+use tradewinds_domain::entities::webhook_delivery::WebhookDelivery;
+use tradewinds_domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use tradewinds_domain::value_objects::webhook::WebhookId;
+
+use chrono::Utc;
+use std::sync::Arc;
+use tradewinds_error::{AppError, AppResult};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct WebhookService {
+    webhook_repo: Arc<dyn WebhookRepository>,
+    delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+impl WebhookService {
+    pub fn new(webhook_repo: Arc<dyn WebhookRepository>, delivery_repo: Arc<dyn WebhookDeliveryRepository>) -> Self {
+        Self { webhook_repo, delivery_repo }
+    }
+
+    async fn find_webhook(&self, id: &WebhookId) -> AppResult<Webhook> {
+        self.webhook_repo.find_by_id(id).await?.ok_or_else(|| AppError::NotFound("Webhook not found".into()))
+    }
+}
+
+#[async_trait::async_trait]
+impl IWebhookService for WebhookService {
+    async fn create_webhook(&self, cmd: CreateWebhookCommand) -> AppResult<Webhook> {
+        let secret = cmd.secret.unwrap_or_else(|| format!("whsec_{}", Uuid::new_v4().simple()));
+        let webhook = Webhook::create(cmd.name, cmd.url, secret, cmd.event_types)?;
+        self.webhook_repo.create(&webhook).await?;
+        Ok(webhook)
+    }
+
+    async fn update_webhook(&self, cmd: UpdateWebhookCommand) -> AppResult<()> {
+        let mut webhook = self.find_webhook(&cmd.id).await?;
+        webhook.update(cmd.name, cmd.url, cmd.secret, cmd.event_types, cmd.enabled)?;
+        self.webhook_repo.update(&webhook).await
+    }
+
+    async fn delete_webhook(&self, cmd: DeleteWebhookCommand) -> AppResult<()> {
+        let webhook = self.find_webhook(&cmd.id).await?;
+        self.webhook_repo.delete(&webhook.id).await
+    }
+
+    async fn list_webhooks(&self, _query: ListWebhooksQuery) -> AppResult<Vec<Webhook>> {
+        self.webhook_repo.find_all().await
+    }
+
+    async fn list_deliveries(&self, query: ListWebhookDeliveriesQuery) -> AppResult<PaginatedResult<WebhookDelivery>> {
+        let (limit, offset) = query.pagination();
+        let (items, total) = self.delivery_repo.search(&query.filter, limit, offset).await?;
+        Ok(PaginatedResult { items, total })
+    }
+
+    async fn redeliver(&self, cmd: RedeliverWebhookDeliveryCommand) -> AppResult<WebhookDelivery> {
+        let delivery = self
+            .delivery_repo
+            .find_by_id(&cmd.id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Webhook delivery not found".into()))?;
+        self.find_webhook(&delivery.webhook_id).await?;
+        let redelivery = delivery.redeliver(Utc::now().timestamp())?;
+        self.delivery_repo.create(&redelivery).await?;
+        Ok(redelivery)
+    }
+}
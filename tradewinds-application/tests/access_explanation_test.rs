@@ -0,0 +1,75 @@
+//! 访问说明与有效权限一致性测试
+//!
+//! 对每个用户的每个权限码，访问说明判定可访问当且仅当该权限出现在用户的有效权限列表中，
+//! 覆盖未启用角色、未启用的通配权限、未启用或已删除的上级菜单以及经由用户组的拒绝
+
+mod common;
+
+use common::{Store, user_service};
+use tradewinds_application::interfaces::user_service::IUserService;
+use tradewinds_application::queries::user::explain_user_access_query::ExplainUserAccessQuery;
+use tradewinds_application::queries::user::get_user_permissions_query::GetUserPermissionsQuery;
+use tradewinds_domain::value_objects::permission::{PermissionCode, PermissionStatus};
+use tradewinds_domain::value_objects::role_permission::PermissionEffect;
+use tradewinds_domain::value_objects::{RoleStatus, UserId};
+
+#[tokio::test]
+async fn explanation_agrees_with_user_permissions() {
+    let store = Store::new();
+    let system = store.add_permission("system", None, PermissionStatus::Active);
+    let users = store.add_permission("system:user", Some(&system), PermissionStatus::Active);
+    let list = store.add_permission("system:user:list", Some(&users), PermissionStatus::Active);
+    let delete = store.add_permission("system:user:delete", Some(&users), PermissionStatus::Active);
+    let all_users = store.add_permission("system:user:*", Some(&users), PermissionStatus::Inactive);
+    let orders = store.add_permission("orders", None, PermissionStatus::Inactive);
+    let order_list = store.add_permission("orders:list", Some(&orders), PermissionStatus::Active);
+    let archive = store.add_permission("archive", None, PermissionStatus::Deleted);
+    let archive_list = store.add_permission("archive:list", Some(&archive), PermissionStatus::Active);
+
+    let admin = store.add_role("admin", RoleStatus::Active);
+    for permission in [&system, &users, &list, &all_users, &order_list, &archive_list] {
+        store.grant_permission(&admin, permission, PermissionEffect::Allow);
+    }
+    let legacy = store.add_role("legacy", RoleStatus::Inactive);
+    store.grant_permission(&legacy, &delete, PermissionEffect::Allow);
+    store.grant_permission(&legacy, &list, PermissionEffect::Deny);
+    let locked = store.add_role("locked", RoleStatus::Active);
+    store.grant_permission(&locked, &list, PermissionEffect::Deny);
+
+    let alice = store.add_user("alice");
+    store.grant(&alice, &admin);
+    let bob = store.add_user("bob");
+    store.grant(&bob, &admin);
+    store.grant(&bob, &legacy);
+    let carol = store.add_user("carol");
+    store.grant(&carol, &legacy);
+    let dave = store.add_user("dave");
+    store.grant(&dave, &admin);
+    store.add_group("locked", vec![locked], vec![dave.clone()]);
+
+    let service = user_service(&store);
+    let catalog = store.permissions.lock().unwrap().clone();
+    let mut allowed_somewhere = 0;
+    for user_id in [alice, bob, carol, dave] {
+        let permissions =
+            service.get_user_permissions(GetUserPermissionsQuery { user_id: user_id.clone() }).await.unwrap();
+        for permission in &catalog {
+            let code = permission.code.clone().unwrap();
+            let explanation = explain(&service, &user_id, &code).await;
+            let listed = permissions.iter().any(|p| p.id == permission.id);
+            assert_eq!(explanation, listed, "{} for {}", code, user_id);
+            allowed_somewhere += usize::from(listed);
+        }
+    }
+    // alice、bob 可访问 system、system:user、system:user:list；dave 的 list 被用户组角色拒绝
+    assert_eq!(allowed_somewhere, 8);
+}
+
+/// 访问说明是否判定可访问
+async fn explain(service: &impl IUserService, user_id: &UserId, code: &PermissionCode) -> bool {
+    service
+        .explain_user_access(ExplainUserAccessQuery { user_id: user_id.clone(), code: code.clone() })
+        .await
+        .unwrap()
+        .allowed
+}
@@ -0,0 +1,72 @@
+//! 访问策略测试
+//!
+//! 覆盖创建或修改策略时拒绝不经过访问策略校验的操作，以及已接受的策略在对应操作上生效
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{Store, policy_service, user_service};
+use tradewinds_application::commands::policy::{CreateAccessPolicyCommand, UpdateAccessPolicyCommand};
+use tradewinds_application::commands::user::delete_user_command::DeleteUserCommand;
+use tradewinds_application::interfaces::policy_service::IPolicyService;
+use tradewinds_application::interfaces::user_service::IUserService;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::value_objects::permission::PermissionCode;
+use tradewinds_domain::value_objects::policy::{AccessPolicyName, PolicyExpression};
+use tradewinds_domain::value_objects::role_permission::PermissionEffect;
+use tradewinds_error::{AppError, AppResult};
+
+async fn create(store: &Arc<Store>, name: &str, action: &str) -> AppResult<AccessPolicy> {
+    policy_service(store)
+        .create_policy(CreateAccessPolicyCommand {
+            name: AccessPolicyName::new(name).unwrap(),
+            description: None,
+            action: PermissionCode::new(action).unwrap(),
+            effect: PermissionEffect::Deny,
+            condition: PolicyExpression::new("true").unwrap(),
+            created_by: None,
+        })
+        .await
+}
+
+#[tokio::test]
+async fn policies_for_unenforced_actions_are_rejected() {
+    let store = Store::new();
+
+    for action in ["role:create", "system:tenant:*", "user:create"] {
+        let result = create(&store, action, action).await;
+        assert!(matches!(result, Err(AppError::Validation(_))), "{}: {:?}", action, result);
+    }
+    for action in ["user:role:assign", "user:*", "*"] {
+        create(&store, action, action).await.unwrap();
+    }
+
+    let policy = create(&store, "deny-delete", "user:delete").await.unwrap();
+    let result = policy_service(&store)
+        .update_policy(UpdateAccessPolicyCommand {
+            id: policy.id,
+            name: None,
+            description: None,
+            action: Some(PermissionCode::new("department:delete").unwrap()),
+            effect: None,
+            condition: None,
+            status: None,
+            updated_by: None,
+        })
+        .await;
+    assert!(matches!(result, Err(AppError::Validation(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn accepted_policy_is_enforced() {
+    let store = Store::new();
+    let alice = store.add_user("alice");
+    let bob = store.add_user("bob");
+    create(&store, "deny-delete", "user:delete").await.unwrap();
+
+    let result = user_service(&store).delete_user(DeleteUserCommand { id: alice.clone(), deleted_by: Some(bob) }).await;
+
+    assert!(matches!(result, Err(AppError::Forbidden(_))), "{:?}", result);
+    assert!(!store.user(&alice).is_deleted());
+}
@@ -0,0 +1,163 @@
+//! 权限申请测试
+//!
+//! 覆盖批准时授权被拒绝后申请恢复为待审批且不写入批准事件、到期时只收回由该申请授予的角色，
+//! 以及申请详情与全部申请列表的可见范围
+
+mod common;
+
+use std::sync::Arc;
+
+use common::{Store, access_request_service, user_service};
+use tradewinds_application::commands::access_request::{
+    ApproveAccessRequestCommand, ExpireAccessRequestsCommand, SubmitAccessRequestCommand,
+};
+use tradewinds_application::commands::user::assign_role_command::AssignRoleCommand;
+use tradewinds_application::interfaces::access_request_service::IAccessRequestService;
+use tradewinds_application::interfaces::user_service::IUserService;
+use tradewinds_application::queries::access_request::{
+    AccessRequestScope, GetAccessRequestByIdQuery, ListAccessRequestsQuery,
+};
+use tradewinds_domain::policies::SUPER_ADMIN_ROLE_CODE;
+use tradewinds_domain::value_objects::{
+    AccessDuration, AccessJustification, AccessRequestId, AccessRequestStatus, RoleId, RoleStatus, SodRuleType, UserId,
+};
+use tradewinds_error::AppError;
+
+/// 申请人 alice 申请 auditor 角色，bob 为该角色的审批人
+struct Fixture {
+    alice: UserId,
+    bob: UserId,
+    auditor: RoleId,
+}
+
+fn fixture(store: &Store) -> Fixture {
+    let alice = store.add_user("alice");
+    let bob = store.add_user("bob");
+    let auditor = store.add_role("auditor", RoleStatus::Active);
+    store.add_approver(&auditor, &bob);
+    Fixture { alice, bob, auditor }
+}
+
+async fn submit(store: &Arc<Store>, f: &Fixture) -> AccessRequestId {
+    access_request_service(store)
+        .submit_request(SubmitAccessRequestCommand {
+            requester_id: f.alice.clone(),
+            role_id: f.auditor.clone(),
+            justification: AccessJustification::new("quarterly audit").unwrap(),
+            duration: AccessDuration::from_days(7).unwrap(),
+        })
+        .await
+        .unwrap()
+        .id
+}
+
+async fn approve(store: &Arc<Store>, f: &Fixture, id: &AccessRequestId) -> Result<(), AppError> {
+    access_request_service(store)
+        .approve_request(ApproveAccessRequestCommand { id: id.clone(), approver_id: f.bob.clone(), comment: None })
+        .await
+}
+
+/// 将授权到期时间改到过去后处理到期申请
+async fn expire_grant(store: &Arc<Store>, id: &AccessRequestId) -> u64 {
+    store.update_access_request(id, |r| r.grant_expires_at = Some(0));
+    access_request_service(store).expire_requests(ExpireAccessRequestsCommand { triggered_by: None }).await.unwrap()
+}
+
+#[tokio::test]
+async fn approve_restores_pending_when_grant_is_rejected() {
+    let store = Store::new();
+    let f = fixture(&store);
+    let id = submit(&store, &f).await;
+    let requester = store.add_role("payment_requester", RoleStatus::Active);
+    store.add_sod_rule(SodRuleType::MutuallyExclusive, vec![requester.clone(), f.auditor.clone()], 1);
+    store.grant(&f.alice, &requester);
+
+    let result = approve(&store, &f, &id).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert_eq!(store.access_request(&id).status, AccessRequestStatus::Pending);
+    assert!(!store.has_role(&f.alice, &f.auditor));
+    assert_eq!(store.outbox_event_types(), vec!["access_request.submitted"]);
+}
+
+#[tokio::test]
+async fn approve_writes_event_to_outbox_after_grant() {
+    let store = Store::new();
+    let f = fixture(&store);
+    let id = submit(&store, &f).await;
+
+    approve(&store, &f, &id).await.unwrap();
+
+    let event_types = store.outbox_event_types();
+    assert_eq!(event_types.first().map(String::as_str), Some("access_request.submitted"));
+    assert_eq!(event_types.last().map(String::as_str), Some("access_request.approved"));
+    assert_eq!(event_types.iter().filter(|t| t.as_str() == "access_request.approved").count(), 1);
+}
+
+#[tokio::test]
+async fn expiry_revokes_role_granted_by_the_request() {
+    let store = Store::new();
+    let f = fixture(&store);
+    let id = submit(&store, &f).await;
+    approve(&store, &f, &id).await.unwrap();
+    assert_eq!(store.access_request(&id).status, AccessRequestStatus::Approved);
+    assert!(store.has_role(&f.alice, &f.auditor));
+
+    assert_eq!(expire_grant(&store, &id).await, 1);
+
+    assert_eq!(store.access_request(&id).status, AccessRequestStatus::Expired);
+    assert!(!store.has_role(&f.alice, &f.auditor));
+}
+
+#[tokio::test]
+async fn expiry_keeps_role_assigned_directly_after_approval() {
+    let store = Store::new();
+    let f = fixture(&store);
+    let id = submit(&store, &f).await;
+    approve(&store, &f, &id).await.unwrap();
+    // 批准后管理员又直接分配了同一角色，角色来源不再是该申请
+    user_service(&store)
+        .assign_role(AssignRoleCommand {
+            user_id: f.alice.clone(),
+            role_id: f.auditor.clone(),
+            assigned_by: None,
+            access_request_id: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(expire_grant(&store, &id).await, 1);
+
+    assert_eq!(store.access_request(&id).status, AccessRequestStatus::Expired);
+    assert!(store.has_role(&f.alice, &f.auditor));
+}
+
+#[tokio::test]
+async fn request_details_are_limited_to_requester_approvers_and_admins() {
+    let store = Store::new();
+    let f = fixture(&store);
+    let id = submit(&store, &f).await;
+    let carol = store.add_user("carol");
+    let root = store.add_user("root");
+    let super_admin = store.add_role(SUPER_ADMIN_ROLE_CODE, RoleStatus::Active);
+    store.grant(&root, &super_admin);
+    let service = access_request_service(&store);
+
+    for actor in [&f.alice, &f.bob, &root] {
+        let query = GetAccessRequestByIdQuery { actor_id: actor.clone(), id: id.clone() };
+        assert_eq!(service.get_request_by_id(query).await.unwrap().id, id);
+    }
+    let result = service.get_request_by_id(GetAccessRequestByIdQuery { actor_id: carol.clone(), id }).await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))), "{:?}", result);
+
+    let list_all = |actor_id: &UserId| ListAccessRequestsQuery {
+        actor_id: actor_id.clone(),
+        scope: AccessRequestScope::All,
+        status: None,
+        page: 1,
+        page_size: 10,
+    };
+    assert_eq!(service.list_requests(list_all(&root)).await.unwrap().total, 1);
+    let result = service.list_requests(list_all(&f.bob)).await;
+    assert!(matches!(result, Err(AppError::Forbidden(_))));
+}
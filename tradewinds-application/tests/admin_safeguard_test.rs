@@ -0,0 +1,170 @@
+//! 最后管理员保护测试
+//!
+//! 覆盖删除用户、禁用用户、撤销或替换角色、移出用户组与删除用户组在会移除最后一个启用的超级管理员时被拒绝且不落库，
+//! 以及仍有其他启用的超级管理员时正常生效
+
+mod common;
+
+use common::{Store, group_service, user_service};
+use tradewinds_application::commands::group::delete_group_command::DeleteGroupCommand;
+use tradewinds_application::commands::group::remove_group_member_command::RemoveGroupMemberCommand;
+use tradewinds_application::commands::user::delete_user_command::DeleteUserCommand;
+use tradewinds_application::commands::user::revoke_role_command::RevokeRoleCommand;
+use tradewinds_application::commands::user::update_user_command::UpdateUserCommand;
+use tradewinds_application::interfaces::group_service::IGroupService;
+use tradewinds_application::interfaces::user_service::IUserService;
+use tradewinds_domain::policies::SUPER_ADMIN_ROLE_CODE;
+use tradewinds_domain::value_objects::{RoleId, RoleStatus, UserId, UserStatus};
+use tradewinds_error::AppError;
+
+/// 唯一的超级管理员 root，直接持有超级管理员角色
+fn single_admin() -> (std::sync::Arc<Store>, RoleId, UserId) {
+    let store = Store::new();
+    let super_admin = store.add_role(SUPER_ADMIN_ROLE_CODE, RoleStatus::Active);
+    let root = store.add_user("root");
+    store.grant(&root, &super_admin);
+    (store, super_admin, root)
+}
+
+fn update(id: &UserId, status: Option<UserStatus>, role_ids: Option<Vec<RoleId>>) -> UpdateUserCommand {
+    UpdateUserCommand {
+        id: id.clone(),
+        real_name: None,
+        phone: None,
+        avatar: None,
+        status,
+        email: None,
+        role_ids,
+        department_id: None,
+        updated_by: None,
+    }
+}
+
+#[tokio::test]
+async fn delete_user_rejects_last_super_admin() {
+    let (store, _, root) = single_admin();
+
+    let result = user_service(&store).delete_user(DeleteUserCommand { id: root.clone(), deleted_by: None }).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(store.user(&root).status.is_active());
+}
+
+#[tokio::test]
+async fn delete_user_ignores_inactive_super_admins() {
+    let (store, super_admin, root) = single_admin();
+    let backup = store.add_user("backup");
+    store.grant(&backup, &super_admin);
+    store.users.lock().unwrap().iter_mut().filter(|u| u.id == backup).for_each(|u| u.status = UserStatus::Inactive);
+
+    let result = user_service(&store).delete_user(DeleteUserCommand { id: root, deleted_by: None }).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn delete_user_allows_super_admin_when_another_remains() {
+    let (store, super_admin, root) = single_admin();
+    let backup = store.add_user("backup");
+    store.grant(&backup, &super_admin);
+
+    user_service(&store).delete_user(DeleteUserCommand { id: root.clone(), deleted_by: None }).await.unwrap();
+
+    assert_eq!(store.user(&root).status, UserStatus::Deleted);
+}
+
+#[tokio::test]
+async fn update_user_rejects_disabling_last_super_admin() {
+    let (store, _, root) = single_admin();
+
+    let result = user_service(&store).update_user(update(&root, Some(UserStatus::Inactive), None)).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(store.user(&root).status.is_active());
+}
+
+#[tokio::test]
+async fn update_user_rejects_replacing_roles_of_last_super_admin() {
+    let (store, super_admin, root) = single_admin();
+    let auditor = store.add_role("auditor", RoleStatus::Active);
+
+    let result = user_service(&store).update_user(update(&root, None, Some(vec![auditor]))).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert_eq!(*store.user_roles.lock().unwrap(), vec![(root, super_admin)]);
+}
+
+#[tokio::test]
+async fn revoke_role_rejects_last_super_admin() {
+    let (store, super_admin, root) = single_admin();
+
+    let result = user_service(&store)
+        .revoke_role(RevokeRoleCommand { user_id: root.clone(), role_id: super_admin.clone(), revoked_by: None })
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert_eq!(*store.user_roles.lock().unwrap(), vec![(root, super_admin)]);
+}
+
+#[tokio::test]
+async fn revoke_role_allows_super_admin_held_through_group() {
+    let (store, super_admin, root) = single_admin();
+    store.add_group("admins", vec![super_admin.clone()], vec![root.clone()]);
+
+    user_service(&store)
+        .revoke_role(RevokeRoleCommand { user_id: root, role_id: super_admin, revoked_by: None })
+        .await
+        .unwrap();
+
+    assert!(store.user_roles.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn remove_group_member_rejects_last_super_admin() {
+    let store = Store::new();
+    let super_admin = store.add_role(SUPER_ADMIN_ROLE_CODE, RoleStatus::Active);
+    let root = store.add_user("root");
+    let admins = store.add_group("admins", vec![super_admin], vec![root.clone()]);
+
+    let result = group_service(&store)
+        .remove_group_member(RemoveGroupMemberCommand {
+            group_id: admins.clone(),
+            user_id: root.clone(),
+            removed_by: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(store.group(&admins).has_member(&root));
+}
+
+#[tokio::test]
+async fn remove_group_member_allows_member_with_direct_super_admin() {
+    let (store, super_admin, root) = single_admin();
+    let admins = store.add_group("admins", vec![super_admin], vec![root.clone()]);
+
+    group_service(&store)
+        .remove_group_member(RemoveGroupMemberCommand {
+            group_id: admins.clone(),
+            user_id: root.clone(),
+            removed_by: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(!store.group(&admins).has_member(&root));
+}
+
+#[tokio::test]
+async fn delete_group_rejects_removing_last_super_admin() {
+    let store = Store::new();
+    let super_admin = store.add_role(SUPER_ADMIN_ROLE_CODE, RoleStatus::Active);
+    let root = store.add_user("root");
+    let admins = store.add_group("admins", vec![super_admin], vec![root]);
+
+    let result =
+        group_service(&store).delete_group(DeleteGroupCommand { group_id: admins.clone(), deleted_by: None }).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(store.group(&admins).group.is_active());
+}
@@ -0,0 +1,779 @@
+//! 应用服务测试共用的内存仓储
+//!
+//! `Store` 以内存中的用户、角色、角色分配、用户组、职责分离规则、权限与授权、访问策略、
+//! 权限申请与角色审批人实现各仓储接口，不区分租户，聚合保存时记录的事件追加到内存发件箱；
+//! 有效角色与持有人的计算与数据库实现一致：直接分配的角色加上所在启用用户组携带的角色。
+//! 测试用不到的方法直接 panic，一旦被调用即暴露出测试遗漏的依赖。
+
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tradewinds_application::services::access_request_service::AccessRequestService;
+use tradewinds_application::services::group_service::GroupService;
+use tradewinds_application::services::policy_service::PolicyService;
+use tradewinds_application::services::user_service::{UserService, UserServiceDeps};
+use tradewinds_domain::aggregates::access_request_aggregate::AccessRequestAggregate;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_domain::aggregates::user_aggregate::UserAggregate;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::entities::access_request::AccessRequest;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_domain::entities::system_setting::SystemSetting;
+use tradewinds_domain::entities::{
+    group::Group, permission::Permission, role::Role, sod_rule::SodRule, user::User, user_role::UserRole,
+};
+use tradewinds_domain::policies::PermissionGrant;
+use tradewinds_domain::repositories::{
+    AccessPolicyRepository, AccessRequestRepository, DepartmentRepository, GroupAggregateRepository, GroupRepository,
+    PermissionRepository, RoleApproverRepository, RoleRepository, SodRuleRepository, SystemSettingRepository,
+    UserAggregateRepository, UserRepository, UserRoleRepository, UserSearchFilter,
+};
+use tradewinds_domain::services::auth::PasswordService;
+use tradewinds_domain::value_objects::auth::{AuthUsername, Password};
+use tradewinds_domain::value_objects::permission::{
+    PermissionCode, PermissionId, PermissionName, PermissionSort, PermissionStatus, PermissionType,
+};
+use tradewinds_domain::value_objects::policy::{AccessPolicyId, AccessPolicyName, AccessPolicyStatus};
+use tradewinds_domain::value_objects::role::RoleCode;
+use tradewinds_domain::value_objects::role_permission::PermissionEffect;
+use tradewinds_domain::value_objects::sod::{SodRuleId, SodRuleName, SodRuleType};
+use tradewinds_domain::value_objects::system_setting::{SystemSettingKey, SystemSettingValue};
+use tradewinds_domain::value_objects::user::{Email, UserStatus};
+use tradewinds_domain::value_objects::{
+    AccessRequestId, AccessRequestStatus, DataScope, DepartmentId, DepartmentName, DepartmentStatus, GroupId,
+    GroupName, GroupStatus, RoleAssignment, RoleId, RoleName, RoleStatus, UserId, UserRoleId,
+};
+use tradewinds_error::AppResult;
+
+#[derive(Default)]
+pub struct Store {
+    pub users: Mutex<Vec<User>>,
+    pub roles: Mutex<Vec<Role>>,
+    /// 直接分配的角色
+    pub user_roles: Mutex<Vec<(UserId, RoleId)>>,
+    /// 经权限申请授予的角色对应的申请
+    pub request_grants: Mutex<Vec<(UserId, RoleId, AccessRequestId)>>,
+    pub groups: Mutex<Vec<GroupAggregate>>,
+    pub sod_rules: Mutex<Vec<SodRule>>,
+    /// 权限全集，含已删除的权限
+    pub permissions: Mutex<Vec<Permission>>,
+    pub grants: Mutex<Vec<PermissionGrant>>,
+    /// 访问策略，默认没有任何策略
+    pub policies: Mutex<Vec<AccessPolicy>>,
+    pub access_requests: Mutex<Vec<AccessRequest>>,
+    /// 角色审批人
+    pub approvers: Mutex<Vec<(RoleId, UserId)>>,
+    /// 随聚合写入的发件箱消息
+    pub outbox: Mutex<Vec<OutboxMessage>>,
+}
+
+impl Store {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// 新增启用的用户
+    pub fn add_user(&self, username: &str) -> UserId {
+        let user = User::create(
+            AuthUsername::new(username.to_string()).unwrap(),
+            Email::new(format!("{}@example.com", username)).unwrap(),
+            Password::new("hashed".to_string()).unwrap(),
+            None,
+            None,
+            None,
+        );
+        let id = user.id.clone();
+        self.users.lock().unwrap().push(user);
+        id
+    }
+
+    /// 新增指定编码与状态的角色
+    pub fn add_role(&self, code: &str, status: RoleStatus) -> RoleId {
+        let role = Role::create(
+            RoleId::new_v4(),
+            RoleCode::new(code.to_string()).unwrap(),
+            RoleName::new(code).unwrap(),
+            None,
+            status,
+            0,
+            0,
+        );
+        let id = role.id.clone();
+        self.roles.lock().unwrap().push(role);
+        id
+    }
+
+    /// 直接为用户分配角色
+    pub fn grant(&self, user_id: &UserId, role_id: &RoleId) {
+        self.user_roles.lock().unwrap().push((user_id.clone(), role_id.clone()));
+    }
+
+    /// 新增启用的用户组
+    pub fn add_group(&self, name: &str, role_ids: Vec<RoleId>, members: Vec<UserId>) -> GroupId {
+        let mut group = GroupAggregate::create(GroupName::new(name).unwrap(), None, role_ids).unwrap();
+        if !members.is_empty() {
+            group.add_members(members).unwrap();
+        }
+        let id = group.group.id.clone();
+        self.groups.lock().unwrap().push(group);
+        id
+    }
+
+    /// 新增启用的职责分离规则
+    pub fn add_sod_rule(&self, rule_type: SodRuleType, role_ids: Vec<RoleId>, max_count: u32) {
+        self.sod_rules.lock().unwrap().push(SodRule {
+            id: SodRuleId::new_v4(),
+            name: SodRuleName::new("payments").unwrap(),
+            rule_type,
+            role_ids,
+            max_count,
+            enabled: true,
+            created_at: 0,
+            updated_at: 0,
+        });
+    }
+
+    /// 新增菜单权限
+    pub fn add_permission(
+        &self,
+        code: &str,
+        parent_id: Option<&PermissionId>,
+        status: PermissionStatus,
+    ) -> PermissionId {
+        let mut permission = Permission::create(
+            PermissionName::new(code).unwrap(),
+            Some(PermissionCode::new(code).unwrap()),
+            PermissionType::Menu,
+            parent_id.cloned(),
+            None,
+            None,
+            None,
+            PermissionSort::new(0).unwrap(),
+        )
+        .unwrap();
+        permission.status = status;
+        let id = permission.id.clone();
+        self.permissions.lock().unwrap().push(permission);
+        id
+    }
+
+    /// 在角色上授予（或拒绝）权限
+    pub fn grant_permission(&self, role_id: &RoleId, permission_id: &PermissionId, effect: PermissionEffect) {
+        let permission = self.permissions.lock().unwrap().iter().find(|p| &p.id == permission_id).cloned().unwrap();
+        self.grants.lock().unwrap().push(PermissionGrant::new(
+            role_id.clone(),
+            permission.id,
+            permission.code,
+            effect,
+            permission.status,
+        ));
+    }
+
+    /// 为角色配置审批人
+    pub fn add_approver(&self, role_id: &RoleId, user_id: &UserId) {
+        self.approvers.lock().unwrap().push((role_id.clone(), user_id.clone()));
+    }
+
+    pub fn access_request(&self, id: &AccessRequestId) -> AccessRequest {
+        self.access_requests.lock().unwrap().iter().find(|r| &r.id == id).cloned().unwrap()
+    }
+
+    /// 改写已保存的权限申请，用于构造到期等场景
+    pub fn update_access_request(&self, id: &AccessRequestId, f: impl FnOnce(&mut AccessRequest)) {
+        f(self.access_requests.lock().unwrap().iter_mut().find(|r| &r.id == id).unwrap());
+    }
+
+    pub fn has_role(&self, user_id: &UserId, role_id: &RoleId) -> bool {
+        self.direct_role_ids(user_id).contains(role_id)
+    }
+
+    pub fn user(&self, id: &UserId) -> User {
+        self.users.lock().unwrap().iter().find(|u| &u.id == id).cloned().unwrap()
+    }
+
+    pub fn group(&self, id: &GroupId) -> GroupAggregate {
+        self.groups.lock().unwrap().iter().find(|g| &g.group.id == id).cloned().unwrap()
+    }
+
+    fn direct_role_ids(&self, user_id: &UserId) -> Vec<RoleId> {
+        self.user_roles.lock().unwrap().iter().filter(|(u, _)| u == user_id).map(|(_, r)| r.clone()).collect()
+    }
+
+    /// 已写入发件箱的事件类型，按写入顺序
+    pub fn outbox_event_types(&self) -> Vec<String> {
+        self.outbox.lock().unwrap().iter().map(|m| m.event_type.clone()).collect()
+    }
+
+    fn append_outbox(&self, messages: &[OutboxMessage]) {
+        self.outbox.lock().unwrap().extend_from_slice(messages);
+    }
+
+    /// 存入的用户组不带待写入的事件
+    fn stored_group(aggregate: &GroupAggregate) -> GroupAggregate {
+        GroupAggregate::from_existing(aggregate.group.clone(), aggregate.members.clone(), aggregate.roles.clone())
+    }
+
+    fn active_groups(&self) -> Vec<GroupAggregate> {
+        self.groups.lock().unwrap().iter().filter(|g| g.group.is_active()).cloned().collect()
+    }
+}
+
+pub fn user_service(store: &Arc<Store>) -> UserService {
+    UserService::new(UserServiceDeps {
+        user_agg_repo: store.clone(),
+        user_repo: store.clone(),
+        role_repo: store.clone(),
+        permission_repo: store.clone(),
+        department_repo: store.clone(),
+        user_role_repo: store.clone(),
+        password_service: store.clone(),
+        system_setting_repo: store.clone(),
+        sod_rule_repo: store.clone(),
+        access_policy_repo: store.clone(),
+    })
+}
+
+pub fn access_request_service(store: &Arc<Store>) -> AccessRequestService {
+    AccessRequestService::new(
+        store.clone(),
+        store.clone(),
+        store.clone(),
+        store.clone(),
+        store.clone(),
+        Arc::new(user_service(store)),
+    )
+}
+
+pub fn policy_service(store: &Arc<Store>) -> PolicyService {
+    PolicyService::new(store.clone(), store.clone(), store.clone(), store.clone(), store.clone())
+}
+
+pub fn group_service(store: &Arc<Store>) -> GroupService {
+    GroupService::new(store.clone(), store.clone(), store.clone(), store.clone(), store.clone(), store.clone())
+}
+
+#[async_trait]
+impl UserRepository for Store {
+    async fn find_by_id(&self, id: &UserId) -> AppResult<Option<User>> {
+        Ok(self.users.lock().unwrap().iter().find(|u| &u.id == id).cloned())
+    }
+
+    async fn find_by_email(&self, email: &Email) -> AppResult<Option<User>> {
+        Ok(self.users.lock().unwrap().iter().find(|u| &u.email == email).cloned())
+    }
+
+    async fn find_by_username(&self, username: &AuthUsername) -> AppResult<Option<User>> {
+        Ok(self.users.lock().unwrap().iter().find(|u| &u.username == username).cloned())
+    }
+
+    async fn find_by_ids(&self, ids: &[UserId]) -> AppResult<Vec<User>> {
+        Ok(self.users.lock().unwrap().iter().filter(|u| ids.contains(&u.id)).cloned().collect())
+    }
+
+    async fn update_last_login(&self, _user: &User, _events: &[OutboxMessage]) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn exists_by_username(&self, _username: &AuthUsername) -> AppResult<bool> {
+        unimplemented!()
+    }
+
+    async fn exists_by_email(&self, _email: &Email) -> AppResult<bool> {
+        unimplemented!()
+    }
+
+    async fn count(&self) -> AppResult<u64> {
+        unimplemented!()
+    }
+
+    async fn count_by_departments(&self, _department_ids: &[DepartmentId]) -> AppResult<u64> {
+        unimplemented!()
+    }
+
+    async fn is_visible(&self, _data_scope: &DataScope, _id: &UserId) -> AppResult<bool> {
+        unimplemented!()
+    }
+
+    async fn search(&self, _filter: &UserSearchFilter, _limit: u64, _offset: u64) -> AppResult<(Vec<User>, u64)> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl UserAggregateRepository for Store {
+    async fn find_by_id(&self, user_id: &UserId) -> AppResult<Option<UserAggregate>> {
+        let user = self.users.lock().unwrap().iter().find(|u| &u.id == user_id && !u.is_deleted()).cloned();
+        let request_grants: HashMap<RoleId, AccessRequestId> = self
+            .request_grants
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(u, _, _)| u == user_id)
+            .map(|(_, r, q)| (r.clone(), q.clone()))
+            .collect();
+        Ok(user.map(|user| {
+            UserAggregate::from_existing(user, self.direct_role_ids(user_id)).with_request_grants(request_grants)
+        }))
+    }
+
+    async fn save(&self, aggregate: &UserAggregate) -> AppResult<()> {
+        let user_id = &aggregate.user.id;
+        for user in self.users.lock().unwrap().iter_mut().filter(|u| &u.id == user_id) {
+            *user = aggregate.user.clone();
+        }
+        let mut user_roles = self.user_roles.lock().unwrap();
+        user_roles.retain(|(u, _)| u != user_id);
+        user_roles.extend(aggregate.roles.iter().map(|role_id| (user_id.clone(), role_id.clone())));
+        let mut request_grants = self.request_grants.lock().unwrap();
+        request_grants.retain(|(u, _, _)| u != user_id);
+        request_grants.extend(aggregate.request_grants.iter().map(|(r, q)| (user_id.clone(), r.clone(), q.clone())));
+        self.append_outbox(aggregate.events());
+        Ok(())
+    }
+
+    async fn create(&self, aggregate: &UserAggregate) -> AppResult<()> {
+        self.users.lock().unwrap().push(aggregate.user.clone());
+        for role_id in &aggregate.roles {
+            self.grant(&aggregate.user.id, role_id);
+        }
+        self.append_outbox(aggregate.events());
+        Ok(())
+    }
+
+    async fn delete(&self, aggregate: &UserAggregate) -> AppResult<()> {
+        let user_id = &aggregate.user.id;
+        for user in self.users.lock().unwrap().iter_mut().filter(|u| &u.id == user_id) {
+            user.status = UserStatus::Deleted;
+        }
+        self.user_roles.lock().unwrap().retain(|(u, _)| u != user_id);
+        self.request_grants.lock().unwrap().retain(|(u, _, _)| u != user_id);
+        self.append_outbox(aggregate.events());
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl RoleRepository for Store {
+    async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<Role>> {
+        Ok(self.roles.lock().unwrap().iter().find(|r| &r.id == id).cloned())
+    }
+
+    async fn find_by_name(&self, name: &RoleName) -> AppResult<Option<Role>> {
+        Ok(self.roles.lock().unwrap().iter().find(|r| &r.name == name).cloned())
+    }
+
+    async fn find_by_code(&self, code: &RoleCode) -> AppResult<Option<Role>> {
+        Ok(self.roles.lock().unwrap().iter().find(|r| &r.code == code).cloned())
+    }
+
+    async fn find_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Role>> {
+        Ok(self.roles.lock().unwrap().iter().filter(|r| ids.contains(&r.id)).cloned().collect())
+    }
+
+    async fn exists_by_id(&self, id: &RoleId) -> AppResult<bool> {
+        Ok(self.roles.lock().unwrap().iter().any(|r| &r.id == id))
+    }
+
+    async fn find_with_permissions(&self, _id: &RoleId) -> AppResult<Option<(Role, Vec<PermissionId>)>> {
+        unimplemented!()
+    }
+
+    async fn find_permissions(&self, _id: &RoleId) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_permissions_by_ids(&self, _ids: &[RoleId]) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_permission_grants(&self, ids: &[RoleId]) -> AppResult<Vec<PermissionGrant>> {
+        Ok(self.grants.lock().unwrap().iter().filter(|g| ids.contains(&g.role_id)).cloned().collect())
+    }
+
+    async fn find_data_scope_departments(&self, _ids: &[RoleId]) -> AppResult<Vec<DepartmentId>> {
+        unimplemented!()
+    }
+
+    async fn search(
+        &self,
+        _name: Option<&RoleName>,
+        _code: Option<&str>,
+        _status: Option<i32>,
+        _show_deleted: Option<bool>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Role>, u64)> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl UserRoleRepository for Store {
+    async fn create(&self, user_role: &UserRole) -> AppResult<()> {
+        self.grant(&user_role.user_id, &user_role.role_id);
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId, role_id: &RoleId) -> AppResult<()> {
+        self.user_roles.lock().unwrap().retain(|(u, r)| !(u == user_id && r == role_id));
+        self.request_grants.lock().unwrap().retain(|(u, r, _)| !(u == user_id && r == role_id));
+        Ok(())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<UserRole>> {
+        let request_grants = self.request_grants.lock().unwrap();
+        Ok(self
+            .direct_role_ids(user_id)
+            .into_iter()
+            .map(|role_id| UserRole {
+                id: UserRoleId::new_v4(),
+                user_id: user_id.clone(),
+                access_request_id: request_grants
+                    .iter()
+                    .find(|(u, r, _)| u == user_id && r == &role_id)
+                    .map(|(_, _, q)| q.clone()),
+                role_id,
+                created_at: 0,
+                updated_at: 0,
+            })
+            .collect())
+    }
+
+    async fn find_assignments_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<RoleAssignment>> {
+        let mut assignments: Vec<RoleAssignment> =
+            self.direct_role_ids(user_id).into_iter().map(RoleAssignment::direct).collect();
+        for group in self.active_groups().into_iter().filter(|g| g.has_member(user_id)) {
+            for role_id in &group.roles {
+                assignments.push(RoleAssignment::via_group(
+                    role_id.clone(),
+                    group.group.id.clone(),
+                    group.group.name.clone(),
+                ));
+            }
+        }
+        Ok(assignments)
+    }
+
+    async fn find_holder_ids_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<UserId>> {
+        let mut holders: Vec<UserId> =
+            self.user_roles.lock().unwrap().iter().filter(|(_, r)| r == role_id).map(|(u, _)| u.clone()).collect();
+        for group in self.active_groups().into_iter().filter(|g| g.roles.contains(role_id)) {
+            for user_id in group.members {
+                if !holders.contains(&user_id) {
+                    holders.push(user_id);
+                }
+            }
+        }
+        Ok(holders)
+    }
+
+    async fn find_users_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<User>> {
+        let holders: Vec<UserId> =
+            self.user_roles.lock().unwrap().iter().filter(|(_, r)| r == role_id).map(|(u, _)| u.clone()).collect();
+        Ok(self.users.lock().unwrap().iter().filter(|u| holders.contains(&u.id) && !u.is_deleted()).cloned().collect())
+    }
+
+    async fn exists(&self, user_id: &UserId, role_id: &RoleId) -> AppResult<bool> {
+        Ok(self.direct_role_ids(user_id).contains(role_id))
+    }
+}
+
+#[async_trait]
+impl GroupRepository for Store {
+    async fn find_by_id(&self, id: &GroupId) -> AppResult<Option<Group>> {
+        Ok(self.groups.lock().unwrap().iter().find(|g| &g.group.id == id).map(|g| g.group.clone()))
+    }
+
+    async fn find_by_name(&self, name: &GroupName) -> AppResult<Option<Group>> {
+        let groups = self.groups.lock().unwrap();
+        Ok(groups.iter().find(|g| &g.group.name == name && !g.group.status.is_deleted()).map(|g| g.group.clone()))
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<Group>> {
+        let groups = self.groups.lock().unwrap();
+        Ok(groups
+            .iter()
+            .filter(|g| g.has_member(user_id) && !g.group.status.is_deleted())
+            .map(|g| g.group.clone())
+            .collect())
+    }
+
+    async fn search(
+        &self,
+        _name: Option<&GroupName>,
+        _status: Option<GroupStatus>,
+        _show_deleted: Option<bool>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Group>, u64)> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl GroupAggregateRepository for Store {
+    async fn create(&self, aggregate: &GroupAggregate) -> AppResult<()> {
+        self.groups.lock().unwrap().push(Self::stored_group(aggregate));
+        self.append_outbox(aggregate.events());
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &GroupAggregate) -> AppResult<()> {
+        for group in self.groups.lock().unwrap().iter_mut().filter(|g| g.group.id == aggregate.group.id) {
+            *group = Self::stored_group(aggregate);
+        }
+        self.append_outbox(aggregate.events());
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &GroupId) -> AppResult<Option<GroupAggregate>> {
+        Ok(self.groups.lock().unwrap().iter().find(|g| &g.group.id == id).cloned())
+    }
+}
+
+#[async_trait]
+impl SodRuleRepository for Store {
+    async fn find_enabled(&self) -> AppResult<Vec<SodRule>> {
+        Ok(self.sod_rules.lock().unwrap().iter().filter(|r| r.enabled).cloned().collect())
+    }
+}
+
+#[async_trait]
+impl PermissionRepository for Store {
+    async fn find_by_id(&self, _id: &PermissionId) -> AppResult<Option<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_by_name(&self, _name: &PermissionName) -> AppResult<Option<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_by_code(&self, _code: &PermissionCode) -> AppResult<Option<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_by_ids(&self, _ids: &[PermissionId]) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_by_user_id(&self, _user_id: &UserId) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn search(
+        &self,
+        _name: Option<&PermissionName>,
+        _code: Option<&PermissionCode>,
+        _permission_type: Option<&PermissionType>,
+        _status: Option<PermissionStatus>,
+        _show_deleted: Option<bool>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Permission>, u64)> {
+        unimplemented!()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Permission>> {
+        Ok(self.permissions.lock().unwrap().clone())
+    }
+}
+
+#[async_trait]
+impl DepartmentRepository for Store {
+    async fn find_by_id(&self, _id: &DepartmentId) -> AppResult<Option<Department>> {
+        unimplemented!()
+    }
+
+    async fn find_by_ids(&self, _ids: &[DepartmentId]) -> AppResult<Vec<Department>> {
+        unimplemented!()
+    }
+
+    async fn find_by_name(
+        &self,
+        _parent_id: Option<&DepartmentId>,
+        _name: &DepartmentName,
+    ) -> AppResult<Option<Department>> {
+        unimplemented!()
+    }
+
+    async fn find_descendant_ids(&self, _id: &DepartmentId) -> AppResult<Vec<DepartmentId>> {
+        unimplemented!()
+    }
+
+    async fn count_children(&self, _id: &DepartmentId) -> AppResult<u64> {
+        unimplemented!()
+    }
+
+    async fn search(
+        &self,
+        _name: Option<&DepartmentName>,
+        _parent_id: Option<&DepartmentId>,
+        _status: Option<DepartmentStatus>,
+        _show_deleted: Option<bool>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Department>, u64)> {
+        unimplemented!()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Department>> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl SystemSettingRepository for Store {
+    async fn get_by_key(&self, _key: &SystemSettingKey) -> AppResult<Option<SystemSetting>> {
+        unimplemented!()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<SystemSetting>> {
+        unimplemented!()
+    }
+
+    async fn set_value(
+        &self,
+        _key: &SystemSettingKey,
+        _value: &SystemSettingValue,
+        _events: &[OutboxMessage],
+    ) -> AppResult<()> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl AccessPolicyRepository for Store {
+    async fn create(&self, policy: &AccessPolicy) -> AppResult<()> {
+        self.policies.lock().unwrap().push(policy.clone());
+        Ok(())
+    }
+
+    async fn save(&self, policy: &AccessPolicy) -> AppResult<()> {
+        for stored in self.policies.lock().unwrap().iter_mut().filter(|p| p.id == policy.id) {
+            *stored = policy.clone();
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &AccessPolicyId) -> AppResult<Option<AccessPolicy>> {
+        Ok(self.policies.lock().unwrap().iter().find(|p| &p.id == id).cloned())
+    }
+
+    async fn find_by_name(&self, name: &AccessPolicyName) -> AppResult<Option<AccessPolicy>> {
+        Ok(self.policies.lock().unwrap().iter().find(|p| &p.name == name && !p.status.is_deleted()).cloned())
+    }
+
+    async fn find_active(&self) -> AppResult<Vec<AccessPolicy>> {
+        Ok(self.policies.lock().unwrap().iter().filter(|p| p.is_active()).cloned().collect())
+    }
+
+    async fn search(
+        &self,
+        _keyword: Option<&str>,
+        _status: Option<AccessPolicyStatus>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<AccessPolicy>, u64)> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl PasswordService for Store {
+    async fn hash(&self, raw: &str) -> AppResult<String> {
+        Ok(format!("hashed:{}", raw))
+    }
+
+    async fn verify(&self, hashed: &str, raw: &str) -> AppResult<bool> {
+        Ok(hashed == format!("hashed:{}", raw))
+    }
+
+    async fn validate_password_strength(&self, _password: &str) -> AppResult<()> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AccessRequestRepository for Store {
+    async fn create(&self, aggregate: &AccessRequestAggregate) -> AppResult<()> {
+        self.access_requests.lock().unwrap().push(aggregate.request.clone());
+        self.append_outbox(&aggregate.outbox_messages()?);
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &AccessRequestAggregate) -> AppResult<()> {
+        let request = &aggregate.request;
+        for stored in self.access_requests.lock().unwrap().iter_mut().filter(|r| r.id == request.id) {
+            *stored = request.clone();
+        }
+        self.append_outbox(&aggregate.outbox_messages()?);
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &AccessRequestId) -> AppResult<Option<AccessRequest>> {
+        Ok(self.access_requests.lock().unwrap().iter().find(|r| &r.id == id).cloned())
+    }
+
+    async fn find_pending(&self, requester_id: &UserId, role_id: &RoleId) -> AppResult<Option<AccessRequest>> {
+        Ok(self
+            .access_requests
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|r| {
+                &r.requester_id == requester_id && &r.role_id == role_id && r.status == AccessRequestStatus::Pending
+            })
+            .cloned())
+    }
+
+    async fn find_due_for_expiry(&self, now: i64) -> AppResult<Vec<AccessRequest>> {
+        Ok(self
+            .access_requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| AccessRequestAggregate::from_existing((*r).clone()).is_due_for_expiry(now))
+            .cloned()
+            .collect())
+    }
+
+    async fn search(
+        &self,
+        requester_id: Option<&UserId>,
+        role_ids: Option<&[RoleId]>,
+        status: Option<AccessRequestStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessRequest>, u64)> {
+        let matched: Vec<AccessRequest> = self
+            .access_requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| requester_id.is_none_or(|id| &r.requester_id == id))
+            .filter(|r| role_ids.is_none_or(|ids| ids.contains(&r.role_id)))
+            .filter(|r| status.is_none_or(|status| r.status == status))
+            .cloned()
+            .collect();
+        let total = matched.len() as u64;
+        Ok((matched.into_iter().skip(offset as usize).take(limit as usize).collect(), total))
+    }
+}
+
+#[async_trait]
+impl RoleApproverRepository for Store {
+    async fn find_approver_ids(&self, role_id: &RoleId) -> AppResult<Vec<UserId>> {
+        Ok(self.approvers.lock().unwrap().iter().filter(|(r, _)| r == role_id).map(|(_, u)| u.clone()).collect())
+    }
+
+    async fn find_role_ids_by_approver(&self, user_id: &UserId) -> AppResult<Vec<RoleId>> {
+        Ok(self.approvers.lock().unwrap().iter().filter(|(_, u)| u == user_id).map(|(r, _)| r.clone()).collect())
+    }
+
+    async fn replace(&self, _role_id: &RoleId, _approver_ids: &[UserId]) -> AppResult<()> {
+        unimplemented!()
+    }
+}
@@ -0,0 +1,94 @@
+//! 功能开关服务测试
+//!
+//! 覆盖角色白名单只匹配平台租户的启用角色
+
+mod common;
+
+use async_trait::async_trait;
+use common::Store;
+use std::sync::{Arc, Mutex};
+use tradewinds_application::interfaces::feature_flag_service::IFeatureFlagService;
+use tradewinds_application::queries::feature_flag::GetUserFeatureFlagsQuery;
+use tradewinds_application::services::feature_flag_service::FeatureFlagService;
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_domain::repositories::FeatureFlagRepository;
+use tradewinds_domain::value_objects::{RoleStatus, UserId};
+use tradewinds_error::AppResult;
+
+#[derive(Default)]
+struct InMemoryFeatureFlagRepository {
+    flags: Mutex<Vec<FeatureFlag>>,
+}
+
+#[async_trait]
+impl FeatureFlagRepository for InMemoryFeatureFlagRepository {
+    async fn create(&self, flag: &FeatureFlag) -> AppResult<()> {
+        self.flags.lock().unwrap().push(flag.clone());
+        Ok(())
+    }
+
+    async fn update(&self, flag: &FeatureFlag) -> AppResult<()> {
+        for existing in self.flags.lock().unwrap().iter_mut().filter(|f| f.key == flag.key) {
+            *existing = flag.clone();
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        self.flags.lock().unwrap().retain(|f| f.key != key);
+        Ok(())
+    }
+
+    async fn find_by_key(&self, key: &str) -> AppResult<Option<FeatureFlag>> {
+        Ok(self.flags.lock().unwrap().iter().find(|f| f.key == key).cloned())
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<FeatureFlag>> {
+        Ok(self.flags.lock().unwrap().clone())
+    }
+}
+
+/// 灰度为 0、只对白名单角色 `beta` 开启的开关
+fn service(store: &Arc<Store>) -> FeatureFlagService {
+    let flag =
+        FeatureFlag::create("new_dashboard".into(), String::new(), true, 0, vec!["beta".into()], vec![]).unwrap();
+    let flags = InMemoryFeatureFlagRepository { flags: Mutex::new(vec![flag]) };
+    FeatureFlagService::new(Arc::new(flags), store.clone(), store.clone())
+}
+
+async fn dashboard_enabled(service: &FeatureFlagService, tenant_id: &str, user_id: &UserId) -> bool {
+    let query = GetUserFeatureFlagsQuery { user_id: user_id.clone() };
+    let flags = with_tenant(tenant_id, service.user_flags(query)).await.unwrap();
+    flags["new_dashboard"]
+}
+
+#[tokio::test]
+async fn allow_listed_role_enables_flag_in_platform_tenant() {
+    let store = Store::new();
+    let user_id = UserId::new_v4();
+    store.grant(&user_id, &store.add_role("beta", RoleStatus::Active));
+    let service = service(&store);
+
+    assert!(dashboard_enabled(&service, "default", &user_id).await);
+}
+
+#[tokio::test]
+async fn same_role_code_in_another_tenant_does_not_enable_flag() {
+    let store = Store::new();
+    let user_id = UserId::new_v4();
+    store.grant(&user_id, &store.add_role("beta", RoleStatus::Active));
+    let service = service(&store);
+
+    assert!(!dashboard_enabled(&service, "acme", &user_id).await);
+}
+
+#[tokio::test]
+async fn inactive_role_does_not_enable_flag() {
+    let store = Store::new();
+    let user_id = UserId::new_v4();
+    store.grant(&user_id, &store.add_role("beta", RoleStatus::Inactive));
+    let service = service(&store);
+
+    assert!(!dashboard_enabled(&service, "default", &user_id).await);
+}
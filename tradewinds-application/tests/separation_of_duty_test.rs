@@ -0,0 +1,199 @@
+//! 职责分离校验测试
+//!
+//! 覆盖直接分配角色、更新用户角色与加入用户组三条路径在违反互斥规则时被拒绝且不落库，
+//! 以及未违反规则时正常生效
+
+mod common;
+
+use common::{Store, group_service, user_service};
+use tradewinds_application::commands::group::add_group_members_command::AddGroupMembersCommand;
+use tradewinds_application::commands::group::update_group_command::UpdateGroupCommand;
+use tradewinds_application::commands::user::assign_role_command::AssignRoleCommand;
+use tradewinds_application::commands::user::update_user_command::UpdateUserCommand;
+use tradewinds_application::interfaces::group_service::IGroupService;
+use tradewinds_application::interfaces::user_service::IUserService;
+use tradewinds_domain::value_objects::{RoleId, RoleStatus, SodRuleType, UserId};
+use tradewinds_error::AppError;
+
+/// 付款申请与付款审批互斥
+fn payments(store: &Store) -> (RoleId, RoleId) {
+    let requester = store.add_role("payment_requester", RoleStatus::Active);
+    let approver = store.add_role("payment_approver", RoleStatus::Active);
+    store.add_sod_rule(SodRuleType::MutuallyExclusive, vec![requester.clone(), approver.clone()], 1);
+    (requester, approver)
+}
+
+fn update_roles(id: &UserId, role_ids: Vec<RoleId>) -> UpdateUserCommand {
+    UpdateUserCommand {
+        id: id.clone(),
+        real_name: None,
+        phone: None,
+        avatar: None,
+        status: None,
+        email: None,
+        role_ids: Some(role_ids),
+        department_id: None,
+        updated_by: None,
+    }
+}
+
+#[tokio::test]
+async fn assign_role_rejects_mutually_exclusive_role() {
+    let store = Store::new();
+    let (requester, approver) = payments(&store);
+    let alice = store.add_user("alice");
+    store.grant(&alice, &requester);
+
+    let result = user_service(&store)
+        .assign_role(AssignRoleCommand {
+            user_id: alice.clone(),
+            role_id: approver.clone(),
+            assigned_by: None,
+            access_request_id: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(!store.user_roles.lock().unwrap().contains(&(alice, approver)));
+}
+
+#[tokio::test]
+async fn assign_role_rejects_role_conflicting_with_group_role() {
+    let store = Store::new();
+    let (requester, approver) = payments(&store);
+    let alice = store.add_user("alice");
+    store.add_group("requesters", vec![requester], vec![alice.clone()]);
+
+    let result = user_service(&store)
+        .assign_role(AssignRoleCommand {
+            user_id: alice,
+            role_id: approver,
+            assigned_by: None,
+            access_request_id: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn assign_role_allows_role_outside_rules() {
+    let store = Store::new();
+    let (requester, _) = payments(&store);
+    let auditor = store.add_role("auditor", RoleStatus::Active);
+    let alice = store.add_user("alice");
+    store.grant(&alice, &requester);
+
+    user_service(&store)
+        .assign_role(AssignRoleCommand {
+            user_id: alice.clone(),
+            role_id: auditor.clone(),
+            assigned_by: None,
+            access_request_id: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(store.user_roles.lock().unwrap().contains(&(alice, auditor)));
+}
+
+#[tokio::test]
+async fn update_user_rejects_mutually_exclusive_roles() {
+    let store = Store::new();
+    let (requester, approver) = payments(&store);
+    let alice = store.add_user("alice");
+    store.grant(&alice, &requester);
+
+    let result = user_service(&store).update_user(update_roles(&alice, vec![requester.clone(), approver])).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert_eq!(*store.user_roles.lock().unwrap(), vec![(alice, requester)]);
+}
+
+#[tokio::test]
+async fn update_user_rejects_role_exceeding_max_users() {
+    let store = Store::new();
+    let treasurer = store.add_role("treasurer", RoleStatus::Active);
+    store.add_sod_rule(SodRuleType::MaxUsersPerRole, vec![treasurer.clone()], 1);
+    let alice = store.add_user("alice");
+    let bob = store.add_user("bob");
+    store.grant(&alice, &treasurer);
+
+    let result = user_service(&store).update_user(update_roles(&bob, vec![treasurer])).await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+}
+
+#[tokio::test]
+async fn update_user_allows_swapping_exclusive_roles() {
+    let store = Store::new();
+    let (requester, approver) = payments(&store);
+    let alice = store.add_user("alice");
+    store.grant(&alice, &requester);
+
+    user_service(&store).update_user(update_roles(&alice, vec![approver.clone()])).await.unwrap();
+
+    assert_eq!(*store.user_roles.lock().unwrap(), vec![(alice, approver)]);
+}
+
+#[tokio::test]
+async fn add_group_members_rejects_member_holding_exclusive_role() {
+    let store = Store::new();
+    let (requester, approver) = payments(&store);
+    let alice = store.add_user("alice");
+    store.grant(&alice, &requester);
+    let approvers = store.add_group("approvers", vec![approver], Vec::new());
+
+    let result = group_service(&store)
+        .add_group_members(AddGroupMembersCommand {
+            group_id: approvers.clone(),
+            user_ids: vec![alice.clone()],
+            added_by: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(!store.group(&approvers).has_member(&alice));
+}
+
+#[tokio::test]
+async fn add_group_members_allows_member_without_conflict() {
+    let store = Store::new();
+    let (_, approver) = payments(&store);
+    let bob = store.add_user("bob");
+    let approvers = store.add_group("approvers", vec![approver], Vec::new());
+
+    group_service(&store)
+        .add_group_members(AddGroupMembersCommand {
+            group_id: approvers.clone(),
+            user_ids: vec![bob.clone()],
+            added_by: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(store.group(&approvers).has_member(&bob));
+}
+
+#[tokio::test]
+async fn update_group_rejects_role_conflicting_with_member_role() {
+    let store = Store::new();
+    let (requester, approver) = payments(&store);
+    let alice = store.add_user("alice");
+    store.grant(&alice, &requester);
+    let finance = store.add_group("finance", Vec::new(), vec![alice]);
+
+    let result = group_service(&store)
+        .update_group(UpdateGroupCommand {
+            id: finance.clone(),
+            name: None,
+            description: None,
+            status: None,
+            role_ids: Some(vec![approver]),
+            updated_by: None,
+        })
+        .await;
+
+    assert!(matches!(result, Err(AppError::Conflict(_))), "{:?}", result);
+    assert!(store.group(&finance).roles.is_empty());
+}
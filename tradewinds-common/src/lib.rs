@@ -24,5 +24,7 @@ impl<T> ApiResponse<T> {
 }
 
 pub mod debug;
+pub mod request_context;
+pub mod tenant;
 pub mod utils;
 pub use utils::get_current_user_token;
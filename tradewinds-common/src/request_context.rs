@@ -0,0 +1,34 @@
+//! 当前请求的客户端信息
+//!
+//! 请求上下文中间件解析出客户端地址、User-Agent 与请求ID 后，以 `with_request_context` 包裹后续处理，
+//! 认证中间件在其中补充当前操作人；应用层在同一任务内通过 `current_request_context` 读取，
+//! 用于访问策略的环境属性、审计日志等。
+//! 不在请求范围内时（如命令行工具、后台任务）返回空上下文。
+
+use std::future::Future;
+
+/// 请求上下文
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    /// 客户端IP
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+    /// 请求ID，取自 `X-Request-Id` 请求头或自动生成
+    pub request_id: Option<String>,
+    /// 已认证的操作人用户ID
+    pub actor_id: Option<String>,
+}
+
+tokio::task_local! {
+    static CURRENT_REQUEST: RequestContext;
+}
+
+/// 在指定请求上下文内执行
+pub async fn with_request_context<F: Future>(context: RequestContext, f: F) -> F::Output {
+    CURRENT_REQUEST.scope(context, f).await
+}
+
+/// 当前请求上下文
+pub fn current_request_context() -> RequestContext {
+    CURRENT_REQUEST.try_with(|context| context.clone()).unwrap_or_default()
+}
@@ -0,0 +1,29 @@
+//! 当前请求的租户上下文
+//!
+//! 租户中间件解析出租户后，以 `with_tenant` 包裹后续处理；
+//! 仓储在同一任务内通过 `current_tenant_id` 读取租户并自动限定查询范围。
+//! 不在任何租户范围内时（如命令行工具、迁移）使用平台默认租户。
+
+use std::future::Future;
+
+/// 平台默认租户ID，已有数据迁移后归属该租户
+pub const DEFAULT_TENANT_ID: &str = "default";
+
+tokio::task_local! {
+    static CURRENT_TENANT: String;
+}
+
+/// 在指定租户范围内执行
+pub async fn with_tenant<F: Future>(tenant_id: impl Into<String>, f: F) -> F::Output {
+    CURRENT_TENANT.scope(tenant_id.into(), f).await
+}
+
+/// 当前租户ID
+pub fn current_tenant_id() -> String {
+    CURRENT_TENANT.try_with(|tenant_id| tenant_id.clone()).unwrap_or_else(|_| DEFAULT_TENANT_ID.to_string())
+}
+
+/// 当前是否为平台默认租户
+pub fn is_platform_tenant() -> bool {
+    current_tenant_id() == DEFAULT_TENANT_ID
+}
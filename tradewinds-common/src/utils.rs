@@ -19,3 +19,36 @@ where
     let opt = Option::<String>::deserialize(deserializer)?;
     Ok(opt.and_then(|s| if s.trim().is_empty() { None } else { Some(s) }))
 }
+
+/// 通用：嵌套 Option 的反序列化，用于区分“未传”与“清空”
+///
+/// 字段缺省为 `None`（不修改，需配合 `#[serde(default)]`），`null` 或 `"null"` 为 `Some(None)`（清空）
+pub fn null_string_as_none_nested<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let opt = Option::<Option<String>>::deserialize(deserializer)?;
+    match opt {
+        None => Ok(Some(None)), // 兼容 null
+        Some(Some(ref s)) if s == "null" => Ok(Some(None)),
+        _ => Ok(opt),
+    }
+}
+
+/// 通用：拼接一行 CSV（RFC 4180），以 `\r\n` 结尾
+///
+/// 含逗号、引号或换行的字段加引号转义；以 `=`、`+`、`-`、`@` 开头的字段前置单引号，
+/// 避免导出文件在电子表格中被当作公式执行
+pub fn csv_row<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut row = fields
+        .iter()
+        .map(|field| {
+            let field = field.as_ref();
+            let field = if field.starts_with(['=', '+', '-', '@']) { format!("'{}", field) } else { field.to_string() };
+            if field.contains([',', '"', '\r', '\n']) { format!("\"{}\"", field.replace('"', "\"\"")) } else { field }
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    row.push_str("\r\n");
+    row
+}
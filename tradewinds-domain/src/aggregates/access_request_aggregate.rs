@@ -0,0 +1,265 @@
+use crate::entities::{access_request::AccessRequest, outbox_message::OutboxMessage};
+use crate::events::AccessRequestEvent;
+use crate::value_objects::{
+    access_request::{AccessDuration, AccessJustification, AccessRequestId, AccessRequestStatus},
+    role::RoleId,
+    user::user_id::UserId,
+};
+use chrono::Utc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 发件箱消息中的聚合类型
+pub const ACCESS_REQUEST_AGGREGATE_TYPE: &str = "access_request";
+
+/// 权限申请聚合
+///
+/// 状态流转：
+/// - 待审批 -> 已批准 / 已驳回 / 已撤回
+/// - 待审批超过截止时间 -> 已过期
+/// - 已批准且授权到期 -> 已过期（由应用层收回角色）
+///
+/// 每次状态变化记录一条领域事件，由仓储在保存申请的同一事务内写入发件箱
+#[derive(Debug, Clone)]
+pub struct AccessRequestAggregate {
+    pub request: AccessRequest,
+    events: Vec<AccessRequestEvent>,
+}
+
+impl AccessRequestAggregate {
+    /// 待审批申请的有效期（秒）
+    pub const PENDING_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+    /// 提交权限申请
+    pub fn submit(
+        requester_id: UserId,
+        role_id: RoleId,
+        justification: AccessJustification,
+        duration: AccessDuration,
+    ) -> Self {
+        let now = Utc::now().timestamp();
+        let request = AccessRequest {
+            id: AccessRequestId::new_v4(),
+            requester_id,
+            role_id,
+            justification,
+            duration,
+            status: AccessRequestStatus::Pending,
+            decided_by: None,
+            decision_comment: None,
+            decided_at: None,
+            expires_at: now + Self::PENDING_TTL_SECS,
+            grant_expires_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let event = AccessRequestEvent::Submitted {
+            request_id: request.id.clone(),
+            requester_id: request.requester_id.clone(),
+            role_id: request.role_id.clone(),
+            occurred_at: now,
+        };
+        Self { request, events: vec![event] }
+    }
+
+    /// 从已有数据重建聚合（用于从数据库加载）
+    pub fn from_existing(request: AccessRequest) -> Self {
+        Self { request, events: Vec::new() }
+    }
+
+    /// 批准申请，授权自批准时刻起按申请时长生效
+    pub fn approve(&mut self, approver_id: UserId, comment: Option<String>) -> AppResult<()> {
+        let now = Utc::now().timestamp();
+        self.ensure_decidable(&approver_id, now)?;
+        let grant_expires_at = now + self.request.duration.as_secs();
+        self.request.status = AccessRequestStatus::Approved;
+        self.request.grant_expires_at = Some(grant_expires_at);
+        self.decide(approver_id.clone(), comment, now);
+        self.events.push(AccessRequestEvent::Approved {
+            request_id: self.request.id.clone(),
+            requester_id: self.request.requester_id.clone(),
+            role_id: self.request.role_id.clone(),
+            approver_id,
+            grant_expires_at,
+            occurred_at: now,
+        });
+        Ok(())
+    }
+
+    /// 驳回申请
+    pub fn reject(&mut self, approver_id: UserId, comment: Option<String>) -> AppResult<()> {
+        let now = Utc::now().timestamp();
+        self.ensure_decidable(&approver_id, now)?;
+        self.request.status = AccessRequestStatus::Rejected;
+        self.decide(approver_id.clone(), comment.clone(), now);
+        self.events.push(AccessRequestEvent::Rejected {
+            request_id: self.request.id.clone(),
+            requester_id: self.request.requester_id.clone(),
+            role_id: self.request.role_id.clone(),
+            approver_id,
+            comment,
+            occurred_at: now,
+        });
+        Ok(())
+    }
+
+    /// 申请人撤回待审批的申请
+    pub fn cancel(&mut self, actor_id: &UserId) -> AppResult<()> {
+        if actor_id != &self.request.requester_id {
+            return Err(AppError::Forbidden("Only the requester can cancel an access request".into()));
+        }
+        self.ensure_pending()?;
+        let now = Utc::now().timestamp();
+        self.request.status = AccessRequestStatus::Cancelled;
+        self.request.updated_at = now;
+        self.events.push(AccessRequestEvent::Cancelled {
+            request_id: self.request.id.clone(),
+            requester_id: self.request.requester_id.clone(),
+            role_id: self.request.role_id.clone(),
+            occurred_at: now,
+        });
+        Ok(())
+    }
+
+    /// 是否应在 `now` 时过期：待审批超时，或已批准的授权到期
+    pub fn is_due_for_expiry(&self, now: i64) -> bool {
+        match self.request.status {
+            AccessRequestStatus::Pending => self.request.expires_at <= now,
+            AccessRequestStatus::Approved => self.request.grant_expires_at.is_some_and(|at| at <= now),
+            _ => false,
+        }
+    }
+
+    /// 置为已过期
+    pub fn expire(&mut self, now: i64) -> AppResult<()> {
+        if !self.is_due_for_expiry(now) {
+            return Err(AppError::Validation("Access request is not due for expiry".into()));
+        }
+        let grant_ended = self.request.status.is_approved();
+        self.request.status = AccessRequestStatus::Expired;
+        self.request.updated_at = now;
+        self.events.push(AccessRequestEvent::Expired {
+            request_id: self.request.id.clone(),
+            requester_id: self.request.requester_id.clone(),
+            role_id: self.request.role_id.clone(),
+            grant_ended,
+            occurred_at: now,
+        });
+        Ok(())
+    }
+
+    /// 尚未写入发件箱的领域事件
+    pub fn events(&self) -> &[AccessRequestEvent] {
+        &self.events
+    }
+
+    /// 取出并清空尚未写入发件箱的领域事件
+    pub fn take_events(&mut self) -> Vec<AccessRequestEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// 待写入发件箱的事件消息
+    pub fn outbox_messages(&self) -> AppResult<Vec<OutboxMessage>> {
+        self.events
+            .iter()
+            .map(|event| OutboxMessage::from_event(ACCESS_REQUEST_AGGREGATE_TYPE, self.request.id.value(), event))
+            .collect()
+    }
+
+    fn ensure_pending(&self) -> AppResult<()> {
+        if !self.request.status.is_pending() {
+            return Err(AppError::Validation(format!(
+                "Access request is no longer pending (status {})",
+                self.request.status
+            )));
+        }
+        Ok(())
+    }
+
+    /// 审批前校验：申请待审批且未超时，审批人不能是申请人
+    fn ensure_decidable(&self, approver_id: &UserId, now: i64) -> AppResult<()> {
+        self.ensure_pending()?;
+        if self.request.expires_at <= now {
+            return Err(AppError::Validation("Access request has expired".into()));
+        }
+        if approver_id == &self.request.requester_id {
+            return Err(AppError::Forbidden("Requesters cannot decide their own access request".into()));
+        }
+        Ok(())
+    }
+
+    fn decide(&mut self, approver_id: UserId, comment: Option<String>, now: i64) {
+        self.request.decided_by = Some(approver_id);
+        self.request.decision_comment = comment;
+        self.request.decided_at = Some(now);
+        self.request.updated_at = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id.to_string()).unwrap()
+    }
+
+    fn submit() -> AccessRequestAggregate {
+        AccessRequestAggregate::submit(
+            user("alice"),
+            RoleId::new("auditor".to_string()).unwrap(),
+            AccessJustification::new("季度审计").unwrap(),
+            AccessDuration::from_days(3).unwrap(),
+        )
+    }
+
+    #[test]
+    fn approval_grants_for_the_requested_duration() {
+        let mut aggregate = submit();
+        assert!(matches!(aggregate.take_events()[..], [AccessRequestEvent::Submitted { .. }]));
+
+        aggregate.approve(user("bob"), Some("ok".into())).unwrap();
+        let request = &aggregate.request;
+        assert_eq!(request.status, AccessRequestStatus::Approved);
+        assert_eq!(request.decided_by, Some(user("bob")));
+        assert_eq!(request.grant_expires_at, Some(request.decided_at.unwrap() + 3 * 24 * 60 * 60));
+        assert!(matches!(aggregate.events(), [AccessRequestEvent::Approved { .. }]));
+
+        // 已审批的申请不能再次审批或撤回
+        assert!(aggregate.reject(user("bob"), None).is_err());
+        assert!(aggregate.cancel(&user("alice")).is_err());
+    }
+
+    #[test]
+    fn requester_cannot_decide_but_can_cancel() {
+        let mut aggregate = submit();
+        assert!(matches!(aggregate.approve(user("alice"), None), Err(AppError::Forbidden(_))));
+        assert!(matches!(aggregate.cancel(&user("bob")), Err(AppError::Forbidden(_))));
+
+        aggregate.cancel(&user("alice")).unwrap();
+        assert_eq!(aggregate.request.status, AccessRequestStatus::Cancelled);
+        assert!(aggregate.request.status.is_final());
+        assert!(matches!(aggregate.events().last(), Some(AccessRequestEvent::Cancelled { .. })));
+    }
+
+    #[test]
+    fn pending_requests_and_grants_expire() {
+        let mut pending = submit();
+        let deadline = pending.request.expires_at;
+        assert!(!pending.is_due_for_expiry(deadline - 1));
+        assert!(pending.expire(deadline - 1).is_err());
+        pending.expire(deadline).unwrap();
+        assert!(matches!(pending.events().last(), Some(AccessRequestEvent::Expired { grant_ended: false, .. })));
+
+        let mut stale = submit();
+        stale.request.expires_at = 0;
+        assert!(stale.approve(user("bob"), None).is_err());
+
+        let mut granted = submit();
+        granted.approve(user("bob"), None).unwrap();
+        let grant_end = granted.request.grant_expires_at.unwrap();
+        assert!(!granted.is_due_for_expiry(grant_end - 1));
+        granted.expire(grant_end).unwrap();
+        assert_eq!(granted.request.status, AccessRequestStatus::Expired);
+        assert!(matches!(granted.events().last(), Some(AccessRequestEvent::Expired { grant_ended: true, .. })));
+    }
+}
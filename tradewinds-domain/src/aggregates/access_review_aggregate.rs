@@ -0,0 +1,260 @@
+use crate::entities::access_review::{AccessReviewCampaign, AccessReviewItem};
+use crate::value_objects::{
+    access_review::{AccessReviewId, AccessReviewItemId, AccessReviewName, AccessReviewStatus, ReviewDecision},
+    role::RoleId,
+    user::user_id::UserId,
+};
+use chrono::Utc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 复核范围中的一个角色
+#[derive(Debug, Clone)]
+pub struct ReviewScope {
+    pub role_id: RoleId,
+    /// 直接持有该角色的用户
+    pub holders: Vec<UserId>,
+    /// 候选复核人，按优先级排列
+    pub reviewers: Vec<UserId>,
+}
+
+/// 访问复核活动聚合
+///
+/// 发起时为范围内每个（用户，角色）组合生成复核项，并指派一名不是该用户本人的复核人；
+/// 截止前复核人逐项确认或收回，截止后未决项转为自动收回，全部处理后活动完成。
+/// 角色的实际收回由应用层经撤销角色流程完成。
+#[derive(Debug, Clone)]
+pub struct AccessReviewAggregate {
+    pub campaign: AccessReviewCampaign,
+    pub items: Vec<AccessReviewItem>,
+}
+
+impl AccessReviewAggregate {
+    /// 发起复核活动
+    pub fn launch(
+        name: AccessReviewName,
+        created_by: UserId,
+        deadline: i64,
+        scopes: Vec<ReviewScope>,
+    ) -> AppResult<Self> {
+        let now = Utc::now().timestamp();
+        if deadline <= now {
+            return Err(AppError::Validation("Review deadline must be in the future".into()));
+        }
+        if scopes.is_empty() {
+            return Err(AppError::Validation("At least one role is required".into()));
+        }
+
+        let mut role_ids: Vec<RoleId> = Vec::new();
+        let mut items: Vec<AccessReviewItem> = Vec::new();
+        for scope in scopes {
+            if role_ids.contains(&scope.role_id) {
+                continue;
+            }
+            for user_id in scope.holders {
+                if items.iter().any(|i| i.user_id == user_id && i.role_id == scope.role_id) {
+                    continue;
+                }
+                // 复核人不能复核自己的权限
+                let reviewer_id = scope.reviewers.iter().find(|r| **r != user_id).cloned().ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "No independent reviewer available for user {} on role {}",
+                        user_id, scope.role_id
+                    ))
+                })?;
+                items.push(AccessReviewItem {
+                    id: AccessReviewItemId::new_v4(),
+                    user_id,
+                    role_id: scope.role_id.clone(),
+                    reviewer_id,
+                    decision: ReviewDecision::Pending,
+                    comment: None,
+                    decided_at: None,
+                });
+            }
+            role_ids.push(scope.role_id);
+        }
+
+        let campaign = AccessReviewCampaign {
+            id: AccessReviewId::new_v4(),
+            name,
+            role_ids,
+            deadline,
+            status: AccessReviewStatus::Active,
+            created_by,
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+        };
+        Ok(Self { campaign, items })
+    }
+
+    /// 从已有数据重建聚合（用于从数据库加载）
+    pub fn from_existing(campaign: AccessReviewCampaign, items: Vec<AccessReviewItem>) -> Self {
+        Self { campaign, items }
+    }
+
+    /// 复核人对复核项给出结论，仅可确认或收回
+    pub fn decide(
+        &mut self,
+        item_id: &AccessReviewItemId,
+        reviewer_id: &UserId,
+        decision: ReviewDecision,
+        comment: Option<String>,
+    ) -> AppResult<&AccessReviewItem> {
+        let now = Utc::now().timestamp();
+        if !self.campaign.status.is_active() {
+            return Err(AppError::Validation("Access review is already completed".into()));
+        }
+        if self.campaign.deadline <= now {
+            return Err(AppError::Validation("Access review deadline has passed".into()));
+        }
+        if !matches!(decision, ReviewDecision::Confirmed | ReviewDecision::Revoked) {
+            return Err(AppError::Validation("Decision must be confirm or revoke".into()));
+        }
+        let item = self.find_item_mut(item_id)?;
+        if &item.reviewer_id != reviewer_id {
+            return Err(AppError::Forbidden("Only the assigned reviewer can decide this item".into()));
+        }
+        if !item.decision.is_pending() {
+            return Err(AppError::Validation("Review item has already been decided".into()));
+        }
+        item.decision = decision;
+        item.comment = comment;
+        item.decided_at = Some(now);
+        self.campaign.updated_at = now;
+        Ok(self.find_item(item_id).expect("item exists"))
+    }
+
+    /// 是否已到截止时间且尚未完成
+    pub fn is_due(&self, now: i64) -> bool {
+        self.campaign.status.is_active() && self.campaign.deadline <= now
+    }
+
+    /// 尚未给出结论的复核项
+    pub fn pending_items(&self) -> impl Iterator<Item = &AccessReviewItem> {
+        self.items.iter().filter(|i| i.decision.is_pending())
+    }
+
+    /// 截止后将未决项记为自动收回
+    pub fn auto_revoke(&mut self, item_id: &AccessReviewItemId, now: i64) -> AppResult<()> {
+        if !self.is_due(now) {
+            return Err(AppError::Validation("Access review is not due".into()));
+        }
+        let item = self.find_item_mut(item_id)?;
+        if !item.decision.is_pending() {
+            return Err(AppError::Validation("Review item has already been decided".into()));
+        }
+        item.decision = ReviewDecision::AutoRevoked;
+        item.decided_at = Some(now);
+        self.campaign.updated_at = now;
+        Ok(())
+    }
+
+    /// 所有复核项处理完毕后完成活动
+    pub fn complete(&mut self, now: i64) -> AppResult<()> {
+        if !self.is_due(now) {
+            return Err(AppError::Validation("Access review is not due".into()));
+        }
+        if self.pending_items().next().is_some() {
+            return Err(AppError::Validation("Access review still has undecided items".into()));
+        }
+        self.campaign.status = AccessReviewStatus::Completed;
+        self.campaign.completed_at = Some(now);
+        self.campaign.updated_at = now;
+        Ok(())
+    }
+
+    pub fn find_item(&self, item_id: &AccessReviewItemId) -> Option<&AccessReviewItem> {
+        self.items.iter().find(|i| &i.id == item_id)
+    }
+
+    fn find_item_mut(&mut self, item_id: &AccessReviewItemId) -> AppResult<&mut AccessReviewItem> {
+        self.items
+            .iter_mut()
+            .find(|i| &i.id == item_id)
+            .ok_or_else(|| AppError::NotFound("Review item not found".into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id.to_string()).unwrap()
+    }
+
+    fn role(id: &str) -> RoleId {
+        RoleId::new(id.to_string()).unwrap()
+    }
+
+    fn launch() -> AccessReviewAggregate {
+        AccessReviewAggregate::launch(
+            AccessReviewName::new("2026 Q4 特权角色复核").unwrap(),
+            user("admin"),
+            Utc::now().timestamp() + 3600,
+            vec![ReviewScope {
+                role_id: role("dba"),
+                holders: vec![user("alice"), user("bob"), user("alice")],
+                reviewers: vec![user("bob"), user("admin")],
+            }],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn launch_assigns_an_independent_reviewer_per_pair() {
+        let aggregate = launch();
+        assert_eq!(aggregate.campaign.role_ids, vec![role("dba")]);
+        assert_eq!(aggregate.items.len(), 2);
+        let alice = aggregate.items.iter().find(|i| i.user_id == user("alice")).unwrap();
+        let bob = aggregate.items.iter().find(|i| i.user_id == user("bob")).unwrap();
+        assert_eq!(alice.reviewer_id, user("bob"));
+        assert_eq!(bob.reviewer_id, user("admin"));
+
+        let self_only = AccessReviewAggregate::launch(
+            AccessReviewName::new("q").unwrap(),
+            user("admin"),
+            Utc::now().timestamp() + 3600,
+            vec![ReviewScope { role_id: role("dba"), holders: vec![user("admin")], reviewers: vec![user("admin")] }],
+        );
+        assert!(matches!(self_only, Err(AppError::Validation(_))));
+    }
+
+    #[test]
+    fn only_the_assigned_reviewer_decides_once() {
+        let mut aggregate = launch();
+        let item_id = aggregate.items[0].id.clone();
+        let reviewer = aggregate.items[0].reviewer_id.clone();
+
+        let result = aggregate.decide(&item_id, &user("mallory"), ReviewDecision::Confirmed, None);
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        let result = aggregate.decide(&item_id, &reviewer, ReviewDecision::AutoRevoked, None);
+        assert!(matches!(result, Err(AppError::Validation(_))));
+
+        let item = aggregate.decide(&item_id, &reviewer, ReviewDecision::Revoked, Some("离职".into())).unwrap();
+        assert!(item.decision.is_revoked());
+        assert!(aggregate.decide(&item_id, &reviewer, ReviewDecision::Confirmed, None).is_err());
+    }
+
+    #[test]
+    fn undecided_items_are_auto_revoked_at_deadline() {
+        let mut aggregate = launch();
+        let deadline = aggregate.campaign.deadline;
+        let decided = aggregate.items[0].clone();
+        aggregate.decide(&decided.id, &decided.reviewer_id, ReviewDecision::Confirmed, None).unwrap();
+        assert!(aggregate.complete(deadline).is_err());
+
+        assert!(!aggregate.is_due(deadline - 1));
+        let pending: Vec<AccessReviewItemId> = aggregate.pending_items().map(|i| i.id.clone()).collect();
+        assert_eq!(pending.len(), 1);
+        assert!(aggregate.auto_revoke(&pending[0], deadline - 1).is_err());
+        aggregate.auto_revoke(&pending[0], deadline).unwrap();
+        assert!(aggregate.auto_revoke(&decided.id, deadline).is_err());
+
+        aggregate.complete(deadline).unwrap();
+        assert_eq!(aggregate.campaign.status, AccessReviewStatus::Completed);
+        assert_eq!(aggregate.find_item(&pending[0]).unwrap().decision, ReviewDecision::AutoRevoked);
+        assert!(!aggregate.is_due(deadline));
+    }
+}
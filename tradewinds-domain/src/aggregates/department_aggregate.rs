@@ -0,0 +1,190 @@
+use crate::entities::{department::Department, outbox_message::OutboxMessage};
+use crate::services::Event;
+use crate::value_objects::{
+    department::{DepartmentId, DepartmentName, DepartmentSort, DepartmentStatus},
+    user::user_id::UserId,
+};
+use chrono::Utc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 发件箱消息中的聚合类型
+pub const DEPARTMENT_AGGREGATE_TYPE: &str = "department";
+
+/// 部门聚合
+#[derive(Debug, Clone)]
+pub struct DepartmentAggregate {
+    pub department: Department,
+    /// 待随本次变更一并写入发件箱的事件
+    events: Vec<OutboxMessage>,
+}
+
+impl DepartmentAggregate {
+    /// 创建新部门，上级部门必须处于启用状态
+    pub fn create(
+        name: DepartmentName,
+        parent: Option<&Department>,
+        sort: DepartmentSort,
+        leader_id: Option<UserId>,
+    ) -> AppResult<Self> {
+        if let Some(parent) = parent {
+            Self::ensure_parent_usable(parent)?;
+        }
+        let department = Department::create(name, parent.map(|p| p.id.clone()), sort, leader_id);
+        Ok(Self { department, events: Vec::new() })
+    }
+
+    /// 从已有数据重建部门聚合（用于从数据库加载）
+    pub fn from_existing(department: Department) -> Self {
+        Self { department, events: Vec::new() }
+    }
+
+    /// 更新部门资料
+    ///
+    /// `leader_id` 为 `Some(None)` 时清空负责人
+    pub fn update(
+        &mut self,
+        name: Option<DepartmentName>,
+        sort: Option<DepartmentSort>,
+        leader_id: Option<Option<UserId>>,
+        status: Option<DepartmentStatus>,
+    ) -> AppResult<()> {
+        if status.is_some_and(|s| s.is_deleted()) {
+            return Err(AppError::Validation("Use delete to remove a department".into()));
+        }
+        if let Some(name) = name {
+            self.department.name = name;
+        }
+        if let Some(sort) = sort {
+            self.department.sort = sort;
+        }
+        if let Some(leader_id) = leader_id {
+            self.department.leader_id = leader_id;
+        }
+        if let Some(status) = status {
+            self.department.status = status;
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// 调整上级部门
+    ///
+    /// `descendants` 为当前部门的全部后代，用于防止形成环
+    pub fn move_to(&mut self, parent: Option<&Department>, descendants: &[DepartmentId]) -> AppResult<()> {
+        if let Some(parent) = parent {
+            if parent.id == self.department.id || descendants.contains(&parent.id) {
+                return Err(AppError::Validation(
+                    "A department cannot be moved under itself or one of its descendants".into(),
+                ));
+            }
+            Self::ensure_parent_usable(parent)?;
+        }
+        let parent_id = parent.map(|p| p.id.clone());
+        if parent_id != self.department.parent_id {
+            self.department.parent_id = parent_id;
+            self.touch();
+        }
+        Ok(())
+    }
+
+    /// 删除部门（逻辑删除），存在子部门或成员时拒绝
+    pub fn delete(&mut self, has_children: bool, has_members: bool) -> AppResult<()> {
+        if self.department.status.is_deleted() {
+            return Err(AppError::Validation("Department already deleted".into()));
+        }
+        if has_children {
+            return Err(AppError::Conflict("Cannot delete department with child departments".into()));
+        }
+        if has_members {
+            return Err(AppError::Conflict("Cannot delete department that still has users".into()));
+        }
+        self.department.status = DepartmentStatus::Deleted;
+        self.touch();
+        Ok(())
+    }
+
+    fn ensure_parent_usable(parent: &Department) -> AppResult<()> {
+        if !parent.is_active() {
+            return Err(AppError::Validation(format!("Parent department {} is not active", parent.id)));
+        }
+        Ok(())
+    }
+
+    /// 记录事件，由仓储在保存聚合的同一事务内写入发件箱
+    pub fn record_event(&mut self, event: &dyn Event) -> AppResult<()> {
+        let message = OutboxMessage::from_event(DEPARTMENT_AGGREGATE_TYPE, self.department.id.value(), event)?;
+        self.events.push(message);
+        Ok(())
+    }
+
+    /// 已记录、待写入发件箱的事件
+    pub fn events(&self) -> &[OutboxMessage] {
+        &self.events
+    }
+
+    fn touch(&mut self) {
+        self.department.updated_at = Utc::now().timestamp();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn department(name: &str, parent: Option<&Department>) -> Department {
+        DepartmentAggregate::create(DepartmentName::new(name).unwrap(), parent, DepartmentSort::default(), None)
+            .unwrap()
+            .department
+    }
+
+    #[test]
+    fn descendants_are_collected_across_levels() {
+        let head = department("总部", None);
+        let east = department("华东", Some(&head));
+        let shanghai = department("上海", Some(&east));
+        let west = department("华西", Some(&head));
+        let all = vec![head.clone(), east.clone(), shanghai.clone(), west.clone()];
+
+        assert_eq!(Department::descendants_of(&all, &head.id), vec![east.id.clone(), west.id, shanghai.id.clone()]);
+        assert_eq!(Department::descendants_of(&all, &east.id), vec![shanghai.id]);
+    }
+
+    #[test]
+    fn cannot_move_under_itself_or_descendant() {
+        let head = department("总部", None);
+        let east = department("华东", Some(&head));
+        let shanghai = department("上海", Some(&east));
+        let all = vec![head.clone(), east.clone(), shanghai.clone()];
+        let descendants = Department::descendants_of(&all, &east.id);
+
+        let mut aggregate = DepartmentAggregate::from_existing(east.clone());
+        assert!(aggregate.move_to(Some(&east), &descendants).is_err());
+        assert!(aggregate.move_to(Some(&shanghai), &descendants).is_err());
+        assert!(aggregate.move_to(None, &descendants).is_ok());
+        assert_eq!(aggregate.department.parent_id, None);
+    }
+
+    #[test]
+    fn cannot_attach_to_inactive_parent() {
+        let mut head = department("总部", None);
+        head.status = DepartmentStatus::Inactive;
+
+        let result = DepartmentAggregate::create(
+            DepartmentName::new("华东").unwrap(),
+            Some(&head),
+            DepartmentSort::default(),
+            None,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn delete_rejects_children_and_members() {
+        let mut aggregate = DepartmentAggregate::from_existing(department("总部", None));
+
+        assert!(matches!(aggregate.delete(true, false), Err(AppError::Conflict(_))));
+        assert!(matches!(aggregate.delete(false, true), Err(AppError::Conflict(_))));
+        assert!(aggregate.delete(false, false).is_ok());
+        assert!(aggregate.department.status.is_deleted());
+    }
+}
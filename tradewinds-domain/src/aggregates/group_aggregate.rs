@@ -0,0 +1,195 @@
+use crate::entities::{group::Group, outbox_message::OutboxMessage};
+use crate::services::Event;
+use crate::value_objects::{
+    group::{GroupDescription, GroupName, GroupStatus},
+    role::RoleId,
+    user::user_id::UserId,
+};
+use chrono::Utc;
+use tradewinds_error::{AppError, AppResult};
+
+/// 发件箱消息中的聚合类型
+pub const GROUP_AGGREGATE_TYPE: &str = "group";
+
+/// 用户组聚合
+///
+/// 用户组携带一组角色，成员通过所在用户组继承这些角色
+#[derive(Debug, Clone)]
+pub struct GroupAggregate {
+    pub group: Group,
+    pub members: Vec<UserId>,
+    pub roles: Vec<RoleId>,
+    /// 待随本次变更一并写入发件箱的事件
+    events: Vec<OutboxMessage>,
+}
+
+impl GroupAggregate {
+    /// 创建新用户组
+    pub fn create(name: GroupName, description: Option<GroupDescription>, role_ids: Vec<RoleId>) -> AppResult<Self> {
+        let mut aggregate = Self {
+            group: Group::create(name, description),
+            members: Vec::new(),
+            roles: Vec::new(),
+            events: Vec::new(),
+        };
+        aggregate.set_roles(role_ids);
+        Ok(aggregate)
+    }
+
+    /// 从已有数据重建用户组聚合（用于从数据库加载）
+    pub fn from_existing(group: Group, members: Vec<UserId>, roles: Vec<RoleId>) -> Self {
+        Self { group, members, roles, events: Vec::new() }
+    }
+
+    /// 更新用户组资料与角色
+    pub fn update(
+        &mut self,
+        name: Option<GroupName>,
+        description: Option<GroupDescription>,
+        status: Option<GroupStatus>,
+        role_ids: Option<Vec<RoleId>>,
+    ) -> AppResult<()> {
+        self.ensure_not_deleted()?;
+        if status.is_some_and(|s| s.is_deleted()) {
+            return Err(AppError::Validation("Use delete to remove a group".into()));
+        }
+        if let Some(name) = name {
+            self.group.name = name;
+        }
+        if let Some(description) = description {
+            self.group.description = Some(description);
+        }
+        if let Some(status) = status {
+            self.group.status = status;
+        }
+        if let Some(role_ids) = role_ids {
+            self.set_roles(role_ids);
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// 添加成员，已是成员的用户忽略
+    pub fn add_members(&mut self, user_ids: Vec<UserId>) -> AppResult<()> {
+        self.ensure_not_deleted()?;
+        for user_id in user_ids {
+            if !self.members.contains(&user_id) {
+                self.members.push(user_id);
+            }
+        }
+        self.touch();
+        Ok(())
+    }
+
+    /// 移除成员
+    pub fn remove_member(&mut self, user_id: &UserId) -> AppResult<()> {
+        self.ensure_not_deleted()?;
+        let before = self.members.len();
+        self.members.retain(|m| m != user_id);
+        if self.members.len() == before {
+            return Err(AppError::NotFound(format!("User {} is not a member of this group", user_id)));
+        }
+        self.touch();
+        Ok(())
+    }
+
+    pub fn has_member(&self, user_id: &UserId) -> bool {
+        self.members.contains(user_id)
+    }
+
+    /// 删除用户组（逻辑删除），成员不再继承其角色
+    pub fn delete(&mut self) -> AppResult<()> {
+        self.ensure_not_deleted()?;
+        self.group.status = GroupStatus::Deleted;
+        self.touch();
+        Ok(())
+    }
+
+    fn set_roles(&mut self, role_ids: Vec<RoleId>) {
+        self.roles.clear();
+        for role_id in role_ids {
+            if !self.roles.contains(&role_id) {
+                self.roles.push(role_id);
+            }
+        }
+    }
+
+    fn ensure_not_deleted(&self) -> AppResult<()> {
+        if self.group.status.is_deleted() {
+            return Err(AppError::Validation("Group already deleted".into()));
+        }
+        Ok(())
+    }
+
+    /// 记录事件，由仓储在保存聚合的同一事务内写入发件箱
+    pub fn record_event(&mut self, event: &dyn Event) -> AppResult<()> {
+        let message = OutboxMessage::from_event(GROUP_AGGREGATE_TYPE, self.group.id.value(), event)?;
+        self.events.push(message);
+        Ok(())
+    }
+
+    /// 已记录、待写入发件箱的事件
+    pub fn events(&self) -> &[OutboxMessage] {
+        &self.events
+    }
+
+    fn touch(&mut self) {
+        self.group.updated_at = Utc::now().timestamp();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::{
+        group::GroupId,
+        role::{RoleAssignment, RoleSource},
+    };
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id.to_string()).unwrap()
+    }
+
+    fn role(id: &str) -> RoleId {
+        RoleId::new(id.to_string()).unwrap()
+    }
+
+    #[test]
+    fn membership_is_idempotent_and_removal_requires_membership() {
+        let mut group = GroupAggregate::create(GroupName::new("运维").unwrap(), None, vec![role("r1"), role("r1")])
+            .unwrap();
+        assert_eq!(group.roles, vec![role("r1")]);
+
+        group.add_members(vec![user("u1"), user("u2"), user("u1")]).unwrap();
+        assert_eq!(group.members, vec![user("u1"), user("u2")]);
+
+        group.remove_member(&user("u1")).unwrap();
+        assert!(!group.has_member(&user("u1")));
+        assert!(matches!(group.remove_member(&user("u1")), Err(AppError::NotFound(_))));
+    }
+
+    #[test]
+    fn deleted_group_rejects_changes() {
+        let mut group = GroupAggregate::create(GroupName::new("运维").unwrap(), None, vec![]).unwrap();
+        group.delete().unwrap();
+
+        assert!(group.add_members(vec![user("u1")]).is_err());
+        assert!(group.update(None, None, Some(GroupStatus::Active), None).is_err());
+        assert!(group.delete().is_err());
+    }
+
+    #[test]
+    fn effective_roles_merge_direct_and_group_roles() {
+        let ops = GroupId::new("g1".to_string()).unwrap();
+        let assignments = vec![
+            RoleAssignment::direct(role("r1")),
+            RoleAssignment::via_group(role("r1"), ops.clone(), GroupName::new("运维").unwrap()),
+            RoleAssignment::via_group(role("r2"), ops, GroupName::new("运维").unwrap()),
+        ];
+
+        assert_eq!(RoleAssignment::effective_role_ids(&assignments), vec![role("r1"), role("r2")]);
+        let sources = RoleAssignment::sources_of(&assignments, &role("r1"));
+        assert_eq!(sources.len(), 2);
+        assert_eq!(sources[0], &RoleSource::Direct);
+    }
+}
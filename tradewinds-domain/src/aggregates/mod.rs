@@ -1,7 +1,15 @@
+pub mod access_request_aggregate;
+pub mod access_review_aggregate;
+pub mod department_aggregate;
+pub mod group_aggregate;
 pub mod permission_aggregate;
 pub mod role_aggregate;
 pub mod user_aggregate;
 
+pub use access_request_aggregate::AccessRequestAggregate;
+pub use access_review_aggregate::{AccessReviewAggregate, ReviewScope};
+pub use department_aggregate::DepartmentAggregate;
+pub use group_aggregate::GroupAggregate;
 pub use permission_aggregate::PermissionAggregate;
 pub use role_aggregate::RoleAggregate;
 pub use user_aggregate::UserAggregate;
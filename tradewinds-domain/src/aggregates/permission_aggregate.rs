@@ -1,4 +1,5 @@
-use crate::entities::permission::Permission;
+use crate::entities::{outbox_message::OutboxMessage, permission::Permission};
+use crate::services::Event;
 use crate::value_objects::permission::{
     PermissionCode, PermissionComponent, PermissionIcon, PermissionId, PermissionName, PermissionPath, PermissionSort,
     PermissionStatus, PermissionType,
@@ -6,10 +7,15 @@ use crate::value_objects::permission::{
 use chrono::Utc;
 use tradewinds_error::AppResult;
 
+/// 发件箱消息中的聚合类型
+pub const PERMISSION_AGGREGATE_TYPE: &str = "permission";
+
 /// 权限聚合
 #[derive(Debug, Clone)]
 pub struct PermissionAggregate {
     pub permission: Permission,
+    /// 待随本次变更一并写入发件箱的事件
+    events: Vec<OutboxMessage>,
 }
 
 impl PermissionAggregate {
@@ -25,7 +31,12 @@ impl PermissionAggregate {
         sort: PermissionSort,
     ) -> AppResult<Self> {
         let permission = Permission::create(name, code, type_, parent_id, path, component, icon, sort)?;
-        Ok(Self { permission })
+        Ok(Self { permission, events: Vec::new() })
+    }
+
+    /// 从已有数据重建权限聚合（用于从数据库加载）
+    pub fn from_existing(permission: Permission) -> Self {
+        Self { permission, events: Vec::new() }
     }
 
     /// 更新权限
@@ -41,6 +52,7 @@ impl PermissionAggregate {
         sort: Option<PermissionSort>,
         status: Option<PermissionStatus>,
     ) -> AppResult<()> {
+        self.ensure_mutable()?;
         let parent_id_to_pass = match parent_id {
             None => self.permission.parent_id.clone(), // 不修改
             Some(None) => None,                        // 清空
@@ -52,6 +64,7 @@ impl PermissionAggregate {
 
     /// 删除权限
     pub fn delete(&mut self) -> AppResult<()> {
+        self.ensure_mutable()?;
         if self.permission.status.is_deleted() {
             return Err(tradewinds_error::AppError::Validation("Permission already deleted".into()));
         }
@@ -60,6 +73,29 @@ impl PermissionAggregate {
         Ok(())
     }
 
+    /// 内置权限不可修改或删除
+    fn ensure_mutable(&self) -> AppResult<()> {
+        if self.permission.built_in {
+            return Err(tradewinds_error::AppError::Forbidden(format!(
+                "Built-in permission {} cannot be modified",
+                self.permission.name
+            )));
+        }
+        Ok(())
+    }
+
+    /// 记录事件，由仓储在保存聚合的同一事务内写入发件箱
+    pub fn record_event(&mut self, event: &dyn Event) -> AppResult<()> {
+        let message = OutboxMessage::from_event(PERMISSION_AGGREGATE_TYPE, self.permission.id.value(), event)?;
+        self.events.push(message);
+        Ok(())
+    }
+
+    /// 已记录、待写入发件箱的事件
+    pub fn events(&self) -> &[OutboxMessage] {
+        &self.events
+    }
+
     /// 内部更新时间戳
     fn touch(&mut self) {
         self.permission.updated_at = Utc::now().timestamp();
@@ -1,17 +1,29 @@
 // Test comment
-use crate::entities::role::Role;
+use crate::entities::{outbox_message::OutboxMessage, role::Role};
+use crate::services::Event;
 use crate::value_objects::{
+    department::DepartmentId,
     permission::PermissionId,
     role::{RoleCode, RoleDescription, RoleId, RoleName, RoleStatus},
+    scope::DataScopeType,
 };
 use chrono::Utc;
 use tradewinds_error::{AppError, AppResult};
 
+/// 发件箱消息中的聚合类型
+pub const ROLE_AGGREGATE_TYPE: &str = "role";
+
 /// 角色聚合
 #[derive(Debug, Clone)]
 pub struct RoleAggregate {
     pub role: Role,
     pub permissions: Vec<PermissionId>,
+    /// 显式拒绝的权限，优先于任何角色的允许
+    pub denied_permissions: Vec<PermissionId>,
+    /// 自定义数据范围的部门集合，仅在数据范围为 Custom 时有效
+    pub data_scope_departments: Vec<DepartmentId>,
+    /// 待随本次变更一并写入发件箱的事件
+    events: Vec<OutboxMessage>,
 }
 
 impl RoleAggregate {
@@ -21,17 +33,26 @@ impl RoleAggregate {
         code: RoleCode,
         description: Option<RoleDescription>,
         permissions: Option<Vec<PermissionId>>,
+        denied_permissions: Option<Vec<PermissionId>>,
         status: RoleStatus,
     ) -> AppResult<Self> {
         let now = Utc::now().timestamp();
         let id = RoleId::new_v4();
         let role = Role::create(id, code, name, description, status, now, now);
-        Ok(Self { role, permissions: permissions.unwrap_or_default() })
+        let permissions = permissions.unwrap_or_default();
+        let denied_permissions = denied_permissions.unwrap_or_default();
+        Self::ensure_disjoint(&permissions, &denied_permissions)?;
+        Ok(Self { role, permissions, denied_permissions, data_scope_departments: Vec::new(), events: Vec::new() })
     }
 
     /// 从已有数据重建角色聚合（用于从数据库加载）
-    pub fn from_existing(role: Role, permissions: Vec<PermissionId>) -> Self {
-        Self { role, permissions }
+    pub fn from_existing(
+        role: Role,
+        permissions: Vec<PermissionId>,
+        denied_permissions: Vec<PermissionId>,
+        data_scope_departments: Vec<DepartmentId>,
+    ) -> Self {
+        Self { role, permissions, denied_permissions, data_scope_departments, events: Vec::new() }
     }
 
     /// 更新角色
@@ -41,10 +62,40 @@ impl RoleAggregate {
         description: Option<RoleDescription>,
         status: Option<RoleStatus>,
         permissions: Option<Vec<PermissionId>>,
+        denied_permissions: Option<Vec<PermissionId>>,
     ) -> AppResult<()> {
+        self.ensure_mutable()?;
+        let perms = permissions.unwrap_or_else(|| self.permissions.clone());
+        let denied = denied_permissions.unwrap_or_else(|| self.denied_permissions.clone());
+        Self::ensure_disjoint(&perms, &denied)?;
         self.role.update_profile(name, description, status)?;
-        if let Some(perms) = permissions {
+        if perms != self.permissions || denied != self.denied_permissions {
             self.permissions = perms;
+            self.denied_permissions = denied;
+            self.touch();
+        }
+        Ok(())
+    }
+
+    /// 设置数据范围
+    ///
+    /// 仅 Custom 范围保留部门集合，其余范围会清空部门集合
+    pub fn set_data_scope(&mut self, data_scope: DataScopeType, departments: Option<Vec<DepartmentId>>) -> AppResult<()> {
+        self.ensure_mutable()?;
+        let departments = if data_scope.is_custom() {
+            let mut departments = departments.unwrap_or_else(|| self.data_scope_departments.clone());
+            departments.sort();
+            departments.dedup();
+            if departments.is_empty() {
+                return Err(AppError::Validation("Custom data scope requires at least one department".into()));
+            }
+            departments
+        } else {
+            Vec::new()
+        };
+        if data_scope != self.role.data_scope || departments != self.data_scope_departments {
+            self.role.set_data_scope(data_scope);
+            self.data_scope_departments = departments;
             self.touch();
         }
         Ok(())
@@ -52,6 +103,7 @@ impl RoleAggregate {
 
     /// 删除角色
     pub fn delete(&mut self) -> AppResult<()> {
+        self.ensure_mutable()?;
         if self.role.status == RoleStatus::Deleted {
             return Err(AppError::Validation("Role already deleted".into()));
         }
@@ -62,7 +114,13 @@ impl RoleAggregate {
     }
 
     /// 分配权限（避免重复）
+    ///
+    /// 若该权限此前被显式拒绝，则改为允许；内置角色只允许追加权限，不能借此撤销拒绝
     pub fn assign_permission(&mut self, permission_id: &PermissionId) -> AppResult<()> {
+        if self.denied_permissions.contains(permission_id) {
+            self.ensure_mutable()?;
+            self.denied_permissions.retain(|p| p != permission_id);
+        }
         if !self.permissions.contains(permission_id) {
             self.permissions.push(permission_id.clone());
             self.touch();
@@ -70,10 +128,57 @@ impl RoleAggregate {
         Ok(())
     }
 
-    /// 移除权限
-    pub fn revoke_permission(&mut self, permission_id: &PermissionId) {
+    /// 显式拒绝权限（避免重复）
+    ///
+    /// 若该权限此前被允许，则改为拒绝
+    pub fn deny_permission(&mut self, permission_id: &PermissionId) -> AppResult<()> {
+        self.ensure_mutable()?;
         self.permissions.retain(|p| p != permission_id);
+        if !self.denied_permissions.contains(permission_id) {
+            self.denied_permissions.push(permission_id.clone());
+            self.touch();
+        }
+        Ok(())
+    }
+
+    /// 移除权限（同时移除允许与拒绝）
+    pub fn revoke_permission(&mut self, permission_id: &PermissionId) -> AppResult<()> {
+        self.ensure_mutable()?;
+        self.permissions.retain(|p| p != permission_id);
+        self.denied_permissions.retain(|p| p != permission_id);
         self.touch();
+        Ok(())
+    }
+
+    /// 内置角色只允许追加权限，其余修改一律拒绝
+    fn ensure_mutable(&self) -> AppResult<()> {
+        if self.role.built_in {
+            return Err(AppError::Forbidden(format!("Built-in role {} cannot be modified", self.role.code)));
+        }
+        Ok(())
+    }
+
+    /// 同一权限不能在同一角色上既允许又拒绝
+    fn ensure_disjoint(permissions: &[PermissionId], denied_permissions: &[PermissionId]) -> AppResult<()> {
+        if let Some(conflict) = permissions.iter().find(|p| denied_permissions.contains(p)) {
+            return Err(AppError::Validation(format!(
+                "Permission {} cannot be both allowed and denied on the same role",
+                conflict
+            )));
+        }
+        Ok(())
+    }
+
+    /// 记录事件，由仓储在保存聚合的同一事务内写入发件箱
+    pub fn record_event(&mut self, event: &dyn Event) -> AppResult<()> {
+        let message = OutboxMessage::from_event(ROLE_AGGREGATE_TYPE, self.role.id.value(), event)?;
+        self.events.push(message);
+        Ok(())
+    }
+
+    /// 已记录、待写入发件箱的事件
+    pub fn events(&self) -> &[OutboxMessage] {
+        &self.events
     }
 
     /// 内部更新时间戳
@@ -81,3 +186,51 @@ impl RoleAggregate {
         self.role.updated_at = Utc::now().timestamp();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role(built_in: bool, denied: &PermissionId) -> RoleAggregate {
+        let mut role = RoleAggregate::create(
+            RoleName::new("auditor").unwrap(),
+            RoleCode::new("auditor".to_string()).unwrap(),
+            None,
+            None,
+            Some(vec![denied.clone()]),
+            RoleStatus::Active,
+        )
+        .unwrap();
+        role.role.built_in = built_in;
+        role
+    }
+
+    fn permission(id: &str) -> PermissionId {
+        PermissionId::new(id.to_string()).unwrap()
+    }
+
+    #[test]
+    fn assigning_a_denied_permission_replaces_the_deny() {
+        let delete = permission("p-delete");
+        let mut role = role(false, &delete);
+
+        role.assign_permission(&delete).unwrap();
+
+        assert_eq!(role.permissions, vec![delete]);
+        assert!(role.denied_permissions.is_empty());
+    }
+
+    #[test]
+    fn built_in_role_gains_permissions_but_keeps_denies() {
+        let delete = permission("p-delete");
+        let list = permission("p-list");
+        let mut role = role(true, &delete);
+
+        role.assign_permission(&list).unwrap();
+        let result = role.assign_permission(&delete);
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))), "{:?}", result);
+        assert_eq!(role.permissions, vec![list]);
+        assert_eq!(role.denied_permissions, vec![delete]);
+    }
+}
@@ -1,5 +1,7 @@
-use crate::entities::user::User;
+use crate::entities::{department::Department, outbox_message::OutboxMessage, user::User};
+use crate::services::Event;
 use crate::value_objects::{
+    access_request::AccessRequestId,
     auth::{auth_password::Password, auth_username::AuthUsername},
     role::RoleId,
     user::{
@@ -7,15 +9,34 @@ use crate::value_objects::{
     },
 };
 use chrono::Utc;
+use std::collections::HashMap;
 use tradewinds_error::{AppError, AppResult};
 
+/// 发件箱消息中的聚合类型
+pub const USER_AGGREGATE_TYPE: &str = "user";
+
 #[derive(Debug)]
 pub struct UserAggregate {
     pub user: User,
     pub roles: Vec<RoleId>,
+    /// 经权限申请授予的角色及其申请，申请到期时只收回由该申请授予的角色
+    pub request_grants: HashMap<RoleId, AccessRequestId>,
+    /// 待随本次变更一并写入发件箱的事件
+    events: Vec<OutboxMessage>,
 }
 
 impl UserAggregate {
+    /// 由已持久化的用户与角色重建聚合
+    pub fn from_existing(user: User, roles: Vec<RoleId>) -> Self {
+        Self { user, roles, request_grants: HashMap::new(), events: Vec::new() }
+    }
+
+    /// 附带经权限申请授予的角色
+    pub fn with_request_grants(mut self, request_grants: HashMap<RoleId, AccessRequestId>) -> Self {
+        self.request_grants = request_grants;
+        self
+    }
+
     /// 创建新用户（注册逻辑）
     pub fn create(
         username: AuthUsername,
@@ -27,7 +48,7 @@ impl UserAggregate {
     ) -> AppResult<Self> {
         // 调用 User 的 create 方法创建用户
         let user = User::create(username, email, password, real_name, phone, avatar);
-        Ok(Self { user, roles: Vec::new() })
+        Ok(Self { user, roles: Vec::new(), request_grants: HashMap::new(), events: Vec::new() })
     }
 
     /// 创建新用户并分配角色
@@ -42,7 +63,7 @@ impl UserAggregate {
     ) -> AppResult<Self> {
         // 调用 User 的 create 方法创建用户
         let user = User::create(username, email, password, real_name, phone, avatar);
-        Ok(Self { user, roles: role_ids })
+        Ok(Self { user, roles: role_ids, request_grants: HashMap::new(), events: Vec::new() })
     }
 
     /// 更新用户资料
@@ -59,11 +80,24 @@ impl UserAggregate {
         self.user.update_profile(real_name, phone, avatar, new_status, email);
         if let Some(role_ids) = role_ids {
             self.roles = role_ids;
+            self.request_grants.retain(|role_id, _| self.roles.contains(role_id));
         }
         self.touch();
         Ok(())
     }
 
+    /// 调整用户所属部门，`None` 表示移出部门，目标部门必须处于启用状态
+    pub fn assign_department(&mut self, department: Option<&Department>) -> AppResult<()> {
+        if let Some(department) = department
+            && !department.is_active()
+        {
+            return Err(AppError::Validation(format!("Department {} is not active", department.id)));
+        }
+        self.user.department_id = department.map(|d| d.id.clone());
+        self.touch();
+        Ok(())
+    }
+
     /// 删除用户
     pub fn delete(&mut self) -> AppResult<()> {
         if self.user.status == UserStatus::Deleted {
@@ -82,17 +116,38 @@ impl UserAggregate {
     }
 
     /// 分配角色（避免重复）
+    ///
+    /// 直接分配的角色不再随权限申请到期而收回
     pub fn assign_role(&mut self, role_id: &RoleId) -> AppResult<()> {
-        if !self.roles.contains(&role_id) {
+        let granted_by_request = self.request_grants.remove(role_id).is_some();
+        if !self.roles.contains(role_id) {
             self.roles.push(role_id.clone());
             self.touch();
+        } else if granted_by_request {
+            self.touch();
         }
         Ok(())
     }
 
+    /// 经权限申请分配角色，已持有的角色保持原有来源不变
+    pub fn assign_role_by_request(&mut self, role_id: &RoleId, request_id: &AccessRequestId) -> AppResult<()> {
+        if !self.roles.contains(role_id) {
+            self.roles.push(role_id.clone());
+            self.request_grants.insert(role_id.clone(), request_id.clone());
+            self.touch();
+        }
+        Ok(())
+    }
+
+    /// 该角色是否由指定的权限申请授予
+    pub fn is_granted_by_request(&self, role_id: &RoleId, request_id: &AccessRequestId) -> bool {
+        self.request_grants.get(role_id) == Some(request_id)
+    }
+
     /// 移除角色
     pub fn revoke_role(&mut self, role_id: &RoleId) -> AppResult<()> {
         self.roles.retain(|r| r != role_id);
+        self.request_grants.remove(role_id);
         self.touch();
         Ok(())
     }
@@ -113,8 +168,78 @@ impl UserAggregate {
         self.touch();
     }
 
+    /// 记录事件，由仓储在保存聚合的同一事务内写入发件箱
+    pub fn record_event(&mut self, event: &dyn Event) -> AppResult<()> {
+        let message = OutboxMessage::from_event(USER_AGGREGATE_TYPE, self.user.id.value(), event)?;
+        self.events.push(message);
+        Ok(())
+    }
+
+    /// 已记录、待写入发件箱的事件
+    pub fn events(&self) -> &[OutboxMessage] {
+        &self.events
+    }
+
     /// 内部更新时间戳
     fn touch(&mut self) {
         self.user.updated_at = Utc::now().timestamp();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn role(id: &str) -> RoleId {
+        RoleId::new(id.to_string()).unwrap()
+    }
+
+    fn request(id: &str) -> AccessRequestId {
+        AccessRequestId::new(id.to_string()).unwrap()
+    }
+
+    fn aggregate() -> UserAggregate {
+        UserAggregate::create(
+            AuthUsername::new("alice".to_string()).unwrap(),
+            Email::new("alice@example.com".to_string()).unwrap(),
+            Password::new("hashed-password".to_string()).unwrap(),
+            None,
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn request_grant_is_tracked_until_revoked() {
+        let mut user = aggregate();
+        user.assign_role_by_request(&role("r1"), &request("q1")).unwrap();
+
+        assert_eq!(user.roles, vec![role("r1")]);
+        assert!(user.is_granted_by_request(&role("r1"), &request("q1")));
+        assert!(!user.is_granted_by_request(&role("r1"), &request("q2")));
+
+        user.revoke_role(&role("r1")).unwrap();
+        assert!(user.request_grants.is_empty());
+    }
+
+    #[test]
+    fn request_does_not_claim_a_role_already_held() {
+        let mut user = aggregate();
+        user.assign_role(&role("r1")).unwrap();
+        user.assign_role_by_request(&role("r1"), &request("q1")).unwrap();
+
+        assert_eq!(user.roles, vec![role("r1")]);
+        assert!(!user.is_granted_by_request(&role("r1"), &request("q1")));
+    }
+
+    #[test]
+    fn direct_assignment_takes_over_request_grant() {
+        let mut user = aggregate();
+        user.assign_role_by_request(&role("r1"), &request("q1")).unwrap();
+        user.assign_role(&role("r1")).unwrap();
+
+        assert_eq!(user.roles, vec![role("r1")]);
+        assert!(!user.is_granted_by_request(&role("r1"), &request("q1")));
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::value_objects::permission::PermissionCode;
+use crate::value_objects::policy::{AccessPolicyId, AccessPolicyName, AccessPolicyStatus, PolicyExpression};
+use crate::value_objects::role_permission::PermissionEffect;
+
+// 访问策略实体（ABAC）
+//
+/// 策略作用于与 `action` 模式匹配的操作（支持权限码通配），
+/// 在 RBAC 校验通过后按 `condition` 对主体、资源与环境属性求值，由 AbacPolicy 统一合并结果。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessPolicy {
+    pub id: AccessPolicyId,
+    pub name: AccessPolicyName,
+    pub description: Option<String>,
+    pub action: PermissionCode,
+    pub effect: PermissionEffect,
+    pub condition: PolicyExpression,
+    pub status: AccessPolicyStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl AccessPolicy {
+    pub fn create(
+        name: AccessPolicyName,
+        description: Option<String>,
+        action: PermissionCode,
+        effect: PermissionEffect,
+        condition: PolicyExpression,
+    ) -> Self {
+        let now = Utc::now().timestamp();
+        Self {
+            id: AccessPolicyId::new_v4(),
+            name,
+            description,
+            action,
+            effect,
+            condition,
+            status: AccessPolicyStatus::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 更新策略
+    ///
+    /// `description` 为 `Some(None)` 时清空描述
+    pub fn update(
+        &mut self,
+        name: Option<AccessPolicyName>,
+        description: Option<Option<String>>,
+        action: Option<PermissionCode>,
+        effect: Option<PermissionEffect>,
+        condition: Option<PolicyExpression>,
+        status: Option<AccessPolicyStatus>,
+    ) -> AppResult<()> {
+        if self.status.is_deleted() {
+            return Err(AppError::Validation("Access policy already deleted".into()));
+        }
+        if let Some(name) = name {
+            self.name = name;
+        }
+        if let Some(description) = description {
+            self.description = description;
+        }
+        if let Some(action) = action {
+            self.action = action;
+        }
+        if let Some(effect) = effect {
+            self.effect = effect;
+        }
+        if let Some(condition) = condition {
+            self.condition = condition;
+        }
+        if let Some(status) = status {
+            self.status = status;
+        }
+        self.updated_at = Utc::now().timestamp();
+        Ok(())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+
+    /// 启用且操作模式覆盖目标操作
+    pub fn applies_to(&self, action: &PermissionCode) -> bool {
+        self.is_active() && self.action.matches(action)
+    }
+}
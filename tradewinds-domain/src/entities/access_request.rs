@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::access_request::{AccessDuration, AccessJustification, AccessRequestId, AccessRequestStatus};
+use crate::value_objects::{role::RoleId, user::UserId};
+
+// 权限申请实体
+//
+/// 用户申请在一段时间内持有某个角色，由该角色的审批人批准或驳回；
+/// 状态流转由 AccessRequestAggregate 负责。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessRequest {
+    pub id: AccessRequestId,
+    pub requester_id: UserId,
+    pub role_id: RoleId,
+    pub justification: AccessJustification,
+    pub duration: AccessDuration,
+    pub status: AccessRequestStatus,
+    /// 审批人（批准或驳回者）
+    pub decided_by: Option<UserId>,
+    pub decision_comment: Option<String>,
+    pub decided_at: Option<i64>,
+    /// 待审批截止时间，超时未处理的申请自动过期
+    pub expires_at: i64,
+    /// 批准后的授权到期时间
+    pub grant_expires_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::access_review::{
+    AccessReviewId, AccessReviewItemId, AccessReviewName, AccessReviewStatus, ReviewDecision,
+};
+use crate::value_objects::{role::RoleId, user::UserId};
+
+// 访问复核活动实体
+//
+/// 管理员针对选定角色发起的定期复核（如季度权限再认证），
+/// 活动内的复核项由 AccessReviewAggregate 统一维护。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessReviewCampaign {
+    pub id: AccessReviewId,
+    pub name: AccessReviewName,
+    /// 复核范围内的角色
+    pub role_ids: Vec<RoleId>,
+    /// 截止时间，到期后未决项被自动收回
+    pub deadline: i64,
+    pub status: AccessReviewStatus,
+    pub created_by: UserId,
+    pub completed_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// 访问复核项：一个（用户，角色）组合及其复核结论
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessReviewItem {
+    pub id: AccessReviewItemId,
+    pub user_id: UserId,
+    pub role_id: RoleId,
+    pub reviewer_id: UserId,
+    pub decision: ReviewDecision,
+    pub comment: Option<String>,
+    pub decided_at: Option<i64>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+// 审计检查点
+//
+/// 定期对哈希链的链尾签名。签名密钥不在数据库中，
+/// 即使重新计算整条链的哈希也无法伪造检查点，从而发现对已签名部分的改写。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditCheckpoint {
+    /// 检查点覆盖到的记录序号
+    pub seq: u64,
+    /// 该记录的哈希
+    pub hash: String,
+    pub signature: String,
+    pub created_at: i64,
+}
+
+impl AuditCheckpoint {
+    /// 签名内容，包含租户ID，检查点不能在租户间挪用
+    pub fn payload(tenant_id: &str, seq: u64, hash: &str) -> String {
+        format!("audit-checkpoint:{}:{}:{}", tenant_id, seq, hash)
+    }
+}
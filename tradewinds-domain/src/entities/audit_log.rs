@@ -0,0 +1,223 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use sha2::{Digest, Sha256};
+
+use crate::value_objects::audit::{AuditLogId, AuditOutcome};
+use crate::value_objects::user::UserId;
+
+/// 脱敏后替换敏感字段的占位值
+pub const REDACTED: &str = "***";
+
+/// 哈希链首条记录的前一哈希
+pub const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 字段名包含以下片段时视为敏感字段
+const SENSITIVE_KEYS: [&str; 3] = ["password", "secret", "token"];
+
+// 审计日志实体
+//
+/// 记录一次管理操作：操作人、操作对象、变更前后差异、请求来源及结果。
+/// 写入后不再修改：同一租户内的记录按序号组成哈希链，每条记录的哈希覆盖自身内容与前一条记录的哈希，
+/// 任何修改、删除或插入都会使链在该处断开。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditLog {
+    pub id: AuditLogId,
+    pub actor_id: Option<UserId>,
+    /// 操作时的操作人用户名
+    pub actor_name: Option<String>,
+    /// 操作名称，如 `user.create`
+    pub action: String,
+    /// 操作对象类型，如 `user`
+    pub target_type: String,
+    pub target_id: Option<String>,
+    /// 脱敏后的命令参数
+    pub params: Option<Value>,
+    /// 变更的字段：`{字段: {"before": 旧值, "after": 新值}}`
+    pub changes: Option<Value>,
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    pub outcome: AuditOutcome,
+    pub error_message: Option<String>,
+    pub created_at: i64,
+    /// 租户内的链序号，从 1 开始，写入时分配
+    pub seq: u64,
+    /// 前一条记录的哈希，首条记录为 `GENESIS_HASH`
+    pub prev_hash: String,
+    /// 本条记录的哈希
+    pub hash: String,
+}
+
+impl AuditLog {
+    /// 接在链尾之后：分配序号、记录前一哈希并计算本条哈希
+    ///
+    /// `previous` 为当前链尾的序号与哈希，链为空时为 `None`
+    pub fn link(&mut self, previous: Option<(u64, &str)>) {
+        let (seq, prev_hash) = previous.unwrap_or((0, GENESIS_HASH));
+        self.seq = seq + 1;
+        self.prev_hash = prev_hash.to_string();
+        self.hash = self.compute_hash();
+    }
+
+    /// 按当前内容计算记录哈希：SHA-256(前一哈希 + 规范化内容)，十六进制小写
+    pub fn compute_hash(&self) -> String {
+        // 以 JSON 数组表示内容，字段边界无歧义；参数与变更使用存储时的文本形式
+        let content = json!([
+            self.seq,
+            self.id.value(),
+            self.actor_id.as_ref().map(|id| id.value()),
+            self.actor_name,
+            self.action,
+            self.target_type,
+            self.target_id,
+            self.params.as_ref().map(Value::to_string),
+            self.changes.as_ref().map(Value::to_string),
+            self.client_ip,
+            self.user_agent,
+            self.request_id,
+            self.outcome.value(),
+            self.error_message,
+            self.created_at,
+        ]);
+        let mut hasher = Sha256::new();
+        hasher.update(self.prev_hash.as_bytes());
+        hasher.update(content.to_string().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// 比较对象变更前后的快照，返回发生变化的顶层字段
+    ///
+    /// 创建时 `before` 为空、删除时 `after` 为空，此时全部字段视为变化；没有变化时返回 `None`
+    pub fn diff(before: Option<&Value>, after: Option<&Value>) -> Option<Value> {
+        let empty = Map::new();
+        let as_object = |v: Option<&Value>| v.and_then(Value::as_object).unwrap_or(&empty).clone();
+        if before.is_none() && after.is_none() {
+            return None;
+        }
+        let (before, after) = (as_object(before), as_object(after));
+
+        let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+        keys.sort();
+        keys.dedup();
+        let changes: Map<String, Value> = keys
+            .into_iter()
+            .filter_map(|key| {
+                let (old, new) = (before.get(key).unwrap_or(&Value::Null), after.get(key).unwrap_or(&Value::Null));
+                (old != new).then(|| (key.clone(), json!({ "before": old, "after": new })))
+            })
+            .collect();
+        (!changes.is_empty()).then_some(Value::Object(changes))
+    }
+
+    /// 字段名（或系统设置键）是否属于敏感信息
+    pub fn is_sensitive_field(name: &str) -> bool {
+        let name = name.to_lowercase();
+        SENSITIVE_KEYS.iter().any(|s| name.contains(s))
+    }
+
+    /// 将敏感字段（密码、密钥、令牌等）替换为占位值，递归处理嵌套对象与数组
+    pub fn redact(value: Value) -> Value {
+        match value {
+            Value::Object(map) => Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| {
+                        if Self::is_sensitive_field(&key) && !value.is_null() {
+                            (key, Value::String(REDACTED.to_string()))
+                        } else {
+                            (key, Self::redact(value))
+                        }
+                    })
+                    .collect(),
+            ),
+            Value::Array(items) => Value::Array(items.into_iter().map(Self::redact).collect()),
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_log(action: &str) -> AuditLog {
+        AuditLog {
+            id: AuditLogId::new_v4(),
+            actor_id: Some(UserId::new_v4()),
+            actor_name: Some("admin".into()),
+            action: action.into(),
+            target_type: "user".into(),
+            target_id: Some("u-1".into()),
+            params: Some(json!({ "username": "alice" })),
+            changes: None,
+            client_ip: Some("10.0.0.1".into()),
+            user_agent: None,
+            request_id: Some("req-1".into()),
+            outcome: AuditOutcome::Success,
+            error_message: None,
+            created_at: 1_700_000_000,
+            seq: 0,
+            prev_hash: String::new(),
+            hash: String::new(),
+        }
+    }
+
+    #[test]
+    fn link_chains_hashes_and_detects_edits() {
+        let mut first = sample_log("user.create");
+        first.link(None);
+        assert_eq!((first.seq, first.prev_hash.as_str()), (1, GENESIS_HASH));
+        assert_eq!(first.hash, first.compute_hash());
+
+        let mut second = sample_log("user.update");
+        second.link(Some((first.seq, &first.hash)));
+        assert_eq!((second.seq, second.prev_hash.as_str()), (2, first.hash.as_str()));
+        assert_ne!(second.hash, first.hash);
+
+        let mut edited = first.clone();
+        edited.action = "user.delete".into();
+        assert_ne!(edited.compute_hash(), first.hash);
+    }
+
+    #[test]
+    fn diff_reports_only_changed_fields() {
+        let before = json!({ "name": "运维", "status": 0, "permissions": ["a"] });
+        let after = json!({ "name": "运维", "status": 1, "permissions": ["a", "b"], "description": "ops" });
+        let changes = AuditLog::diff(Some(&before), Some(&after)).unwrap();
+        assert_eq!(
+            changes,
+            json!({
+                "description": { "before": null, "after": "ops" },
+                "permissions": { "before": ["a"], "after": ["a", "b"] },
+                "status": { "before": 0, "after": 1 },
+            })
+        );
+        assert_eq!(AuditLog::diff(Some(&before), Some(&before)), None);
+        assert_eq!(AuditLog::diff(None, None), None);
+    }
+
+    #[test]
+    fn diff_of_created_or_deleted_object_covers_all_fields() {
+        let snapshot = json!({ "code": "ops" });
+        assert_eq!(AuditLog::diff(None, Some(&snapshot)), Some(json!({ "code": { "before": null, "after": "ops" } })));
+        assert_eq!(AuditLog::diff(Some(&snapshot), None), Some(json!({ "code": { "before": "ops", "after": null } })));
+    }
+
+    #[test]
+    fn redact_masks_sensitive_fields_recursively() {
+        let params = json!({
+            "username": "alice",
+            "password": "P@ssw0rd",
+            "settings": [{ "smtpSecret": "s", "host": "smtp" }],
+            "refresh_token": null,
+        });
+        assert_eq!(
+            AuditLog::redact(params),
+            json!({
+                "username": "alice",
+                "password": REDACTED,
+                "settings": [{ "smtpSecret": REDACTED, "host": "smtp" }],
+                "refresh_token": null,
+            })
+        );
+    }
+}
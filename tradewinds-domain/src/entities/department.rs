@@ -0,0 +1,70 @@
+use std::collections::{HashMap, VecDeque};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::{
+    department::{DepartmentId, DepartmentName, DepartmentSort, DepartmentStatus},
+    user::user_id::UserId,
+};
+
+/// 部门实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Department {
+    pub id: DepartmentId,
+    pub name: DepartmentName,
+    pub parent_id: Option<DepartmentId>,
+    pub sort: DepartmentSort,
+    pub leader_id: Option<UserId>,
+    pub status: DepartmentStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Department {
+    pub fn create(
+        name: DepartmentName,
+        parent_id: Option<DepartmentId>,
+        sort: DepartmentSort,
+        leader_id: Option<UserId>,
+    ) -> Self {
+        let now = Utc::now().timestamp();
+        Self {
+            id: DepartmentId::new_v4(),
+            name,
+            parent_id,
+            sort,
+            leader_id,
+            status: DepartmentStatus::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+
+    /// 在部门列表中收集 `root` 的全部后代部门ID（不含自身），按层级顺序返回
+    pub fn descendants_of(departments: &[Department], root: &DepartmentId) -> Vec<DepartmentId> {
+        let mut children: HashMap<&DepartmentId, Vec<&DepartmentId>> = HashMap::new();
+        for department in departments {
+            if let Some(parent_id) = &department.parent_id {
+                children.entry(parent_id).or_default().push(&department.id);
+            }
+        }
+
+        let mut descendants = Vec::new();
+        let mut queue = VecDeque::from([root]);
+        while let Some(current) = queue.pop_front() {
+            for child in children.get(current).into_iter().flatten() {
+                // 防御脏数据中的环
+                if *child != root && !descendants.contains(*child) {
+                    descendants.push((*child).clone());
+                    queue.push_back(child);
+                }
+            }
+        }
+        descendants
+    }
+}
@@ -0,0 +1,248 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::entities::tenant::PLATFORM_TENANT_ID;
+use crate::value_objects::UserId;
+
+/// 灰度比例的上限
+pub const MAX_ROLLOUT_PERCENTAGE: u8 = 100;
+
+/// 功能开关
+///
+/// 开关为平台级数据，按键标识。关闭时对所有人关闭；开启后，角色或租户命中白名单的用户直接开启，
+/// 角色编码由各租户自行定义，角色白名单只对平台租户的用户生效，其他租户按租户白名单投放；
+/// 其余用户按 `UserId` 哈希分桶，落在灰度比例内的开启。分桶结果与开关键相关，不同开关的灰度人群相互独立，
+/// 同一开关调高比例时已开启的用户保持开启。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeatureFlag {
+    pub key: String,
+    pub description: String,
+    pub enabled: bool,
+    /// 灰度比例，0-100
+    pub rollout_percentage: u8,
+    /// 白名单角色编码，仅匹配平台租户的角色
+    pub allowed_roles: Vec<String>,
+    /// 白名单租户ID
+    pub allowed_tenants: Vec<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+/// 功能开关的判定对象
+#[derive(Debug, Clone)]
+pub struct FlagPrincipal {
+    pub user_id: UserId,
+    /// 生效的启用角色编码，含用户组携带的角色
+    pub role_codes: Vec<String>,
+    pub tenant_id: String,
+}
+
+impl FeatureFlag {
+    pub fn create(
+        key: String,
+        description: String,
+        enabled: bool,
+        rollout_percentage: u8,
+        allowed_roles: Vec<String>,
+        allowed_tenants: Vec<String>,
+    ) -> AppResult<Self> {
+        let now = Utc::now().timestamp();
+        Ok(Self {
+            key: validate_key(key)?,
+            description: validate_description(description)?,
+            enabled,
+            rollout_percentage: validate_rollout_percentage(rollout_percentage)?,
+            allowed_roles: normalize_list(allowed_roles),
+            allowed_tenants: normalize_list(allowed_tenants),
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 更新开关，未提供的字段保持不变
+    pub fn update(
+        &mut self,
+        description: Option<String>,
+        enabled: Option<bool>,
+        rollout_percentage: Option<u8>,
+        allowed_roles: Option<Vec<String>>,
+        allowed_tenants: Option<Vec<String>>,
+    ) -> AppResult<()> {
+        if let Some(description) = description {
+            self.description = validate_description(description)?;
+        }
+        if let Some(enabled) = enabled {
+            self.enabled = enabled;
+        }
+        if let Some(rollout_percentage) = rollout_percentage {
+            self.rollout_percentage = validate_rollout_percentage(rollout_percentage)?;
+        }
+        if let Some(allowed_roles) = allowed_roles {
+            self.allowed_roles = normalize_list(allowed_roles);
+        }
+        if let Some(allowed_tenants) = allowed_tenants {
+            self.allowed_tenants = normalize_list(allowed_tenants);
+        }
+        self.updated_at = Utc::now().timestamp();
+        Ok(())
+    }
+
+    /// 对给定用户是否开启
+    pub fn is_enabled_for(&self, principal: &FlagPrincipal) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        let role_allowed = principal.tenant_id == PLATFORM_TENANT_ID
+            && principal.role_codes.iter().any(|code| self.allowed_roles.contains(code));
+        if role_allowed || self.allowed_tenants.contains(&principal.tenant_id) {
+            return true;
+        }
+        self.rollout_bucket(&principal.user_id) < self.rollout_percentage
+    }
+
+    /// 用户在该开关下的分桶，0-99
+    pub fn rollout_bucket(&self, user_id: &UserId) -> u8 {
+        let mut hasher = Sha256::new();
+        hasher.update(self.key.as_bytes());
+        hasher.update(b":");
+        hasher.update(user_id.value().as_bytes());
+        let digest = hasher.finalize();
+        let value = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+        (value % 100) as u8
+    }
+}
+
+fn validate_key(key: String) -> AppResult<String> {
+    let key = key.trim().to_string();
+    if key.is_empty()
+        || key.len() > 64
+        || !key.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '.' | '-'))
+    {
+        return Err(AppError::Validation(format!(
+            "Invalid feature flag key: {} (use 1-64 lowercase letters, digits, '_', '.' or '-')",
+            key
+        )));
+    }
+    Ok(key)
+}
+
+fn validate_description(description: String) -> AppResult<String> {
+    let description = description.trim().to_string();
+    if description.chars().count() > 255 {
+        return Err(AppError::Validation("Feature flag description must be at most 255 characters".into()));
+    }
+    Ok(description)
+}
+
+fn validate_rollout_percentage(rollout_percentage: u8) -> AppResult<u8> {
+    if rollout_percentage > MAX_ROLLOUT_PERCENTAGE {
+        return Err(AppError::Validation(format!(
+            "Rollout percentage must be between 0 and {}",
+            MAX_ROLLOUT_PERCENTAGE
+        )));
+    }
+    Ok(rollout_percentage)
+}
+
+/// 去掉空白项与重复项，保持原有顺序
+fn normalize_list(items: Vec<String>) -> Vec<String> {
+    let mut normalized: Vec<String> = Vec::with_capacity(items.len());
+    for item in items {
+        let item = item.trim().to_string();
+        if !item.is_empty() && !normalized.contains(&item) {
+            normalized.push(item);
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flag(rollout_percentage: u8) -> FeatureFlag {
+        FeatureFlag::create("new_dashboard".into(), "新版仪表盘".into(), true, rollout_percentage, vec![], vec![])
+            .unwrap()
+    }
+
+    fn principal(user_id: &str) -> FlagPrincipal {
+        FlagPrincipal {
+            user_id: UserId::new(user_id.to_string()).unwrap(),
+            role_codes: vec!["user".into()],
+            tenant_id: "default".into(),
+        }
+    }
+
+    fn principals() -> Vec<FlagPrincipal> {
+        (0..200).map(|i| principal(&format!("00000000-0000-0000-0000-{:012}", i))).collect()
+    }
+
+    #[test]
+    fn validates_key_and_percentage() {
+        assert!(FeatureFlag::create("New Dashboard".into(), String::new(), true, 0, vec![], vec![]).is_err());
+        assert!(FeatureFlag::create("new_dashboard".into(), String::new(), true, 101, vec![], vec![]).is_err());
+        let flag = FeatureFlag::create(
+            " checkout.v2 ".into(),
+            String::new(),
+            true,
+            50,
+            vec![" admin ".into(), "admin".into(), "".into()],
+            vec![],
+        )
+        .unwrap();
+        assert_eq!(flag.key, "checkout.v2");
+        assert_eq!(flag.allowed_roles, vec!["admin".to_string()]);
+    }
+
+    #[test]
+    fn rollout_boundaries() {
+        assert!(principals().iter().all(|p| flag(100).is_enabled_for(p)));
+        assert!(principals().iter().all(|p| !flag(0).is_enabled_for(p)));
+
+        let mut disabled = flag(100);
+        disabled.update(None, Some(false), None, None, None).unwrap();
+        assert!(principals().iter().all(|p| !disabled.is_enabled_for(p)));
+    }
+
+    #[test]
+    fn rollout_is_stable_and_monotonic() {
+        let half = flag(50);
+        let enabled = principals().iter().filter(|p| half.is_enabled_for(p)).count();
+        assert!((50..150).contains(&enabled), "{} of 200 users enabled at 50%", enabled);
+
+        let wider = flag(80);
+        for p in principals() {
+            assert_eq!(half.rollout_bucket(&p.user_id), flag(50).rollout_bucket(&p.user_id));
+            if half.is_enabled_for(&p) {
+                assert!(wider.is_enabled_for(&p));
+            }
+        }
+    }
+
+    #[test]
+    fn allow_lists_bypass_rollout() {
+        let mut flag = flag(0);
+        let user = principal("00000000-0000-0000-0000-000000000001");
+        assert!(!flag.is_enabled_for(&user));
+
+        flag.update(None, None, None, Some(vec!["user".into()]), None).unwrap();
+        assert!(flag.is_enabled_for(&user));
+
+        flag.update(None, None, None, Some(vec![]), Some(vec!["default".into()])).unwrap();
+        assert!(flag.is_enabled_for(&user));
+    }
+
+    #[test]
+    fn role_allow_list_only_matches_platform_tenant_roles() {
+        let mut flag = flag(0);
+        flag.update(None, None, None, Some(vec!["user".into()]), None).unwrap();
+        let tenant_user =
+            FlagPrincipal { tenant_id: "acme".into(), ..principal("00000000-0000-0000-0000-000000000001") };
+        assert!(!flag.is_enabled_for(&tenant_user));
+
+        flag.update(None, None, None, None, Some(vec!["acme".into()])).unwrap();
+        assert!(flag.is_enabled_for(&tenant_user));
+    }
+}
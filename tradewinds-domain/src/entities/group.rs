@@ -0,0 +1,33 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::group::{GroupDescription, GroupId, GroupName, GroupStatus};
+
+/// 用户组实体
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    pub id: GroupId,
+    pub name: GroupName,
+    pub description: Option<GroupDescription>,
+    pub status: GroupStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Group {
+    pub fn create(name: GroupName, description: Option<GroupDescription>) -> Self {
+        let now = Utc::now().timestamp();
+        Self {
+            id: GroupId::new_v4(),
+            name,
+            description,
+            status: GroupStatus::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+}
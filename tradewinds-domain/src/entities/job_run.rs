@@ -0,0 +1,91 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::job::{JobRunId, JobRunStatus, JobTrigger};
+use crate::value_objects::user::UserId;
+
+/// 错误信息的最大保留长度
+const MAX_ERROR_LEN: usize = 1000;
+
+// 定时任务执行记录
+//
+/// 每次按计划或手动执行生成一条记录，开始时为执行中，结束后记录耗时、处理条数或失败原因。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobRun {
+    pub id: JobRunId,
+    pub job_name: String,
+    pub trigger: JobTrigger,
+    pub status: JobRunStatus,
+    /// 手动触发者
+    pub triggered_by: Option<UserId>,
+    /// 执行所在的实例
+    pub instance: String,
+    pub started_at: i64,
+    pub finished_at: Option<i64>,
+    pub duration_ms: Option<u64>,
+    /// 处理的记录数
+    pub affected: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl JobRun {
+    pub fn start(job_name: &str, trigger: JobTrigger, triggered_by: Option<UserId>, instance: &str) -> Self {
+        Self {
+            id: JobRunId::new_v4(),
+            job_name: job_name.to_string(),
+            trigger,
+            status: JobRunStatus::Running,
+            triggered_by,
+            instance: instance.to_string(),
+            started_at: Utc::now().timestamp(),
+            finished_at: None,
+            duration_ms: None,
+            affected: None,
+            error: None,
+        }
+    }
+
+    /// 执行成功
+    pub fn succeed(&mut self, affected: u64, duration_ms: u64) {
+        self.status = JobRunStatus::Succeeded;
+        self.affected = Some(affected);
+        self.finish(duration_ms);
+    }
+
+    /// 执行失败
+    pub fn fail(&mut self, error: &str, duration_ms: u64) {
+        self.status = JobRunStatus::Failed;
+        self.error = Some(error.chars().take(MAX_ERROR_LEN).collect());
+        self.finish(duration_ms);
+    }
+
+    fn finish(&mut self, duration_ms: u64) {
+        self.finished_at = Some(Utc::now().timestamp());
+        self.duration_ms = Some(duration_ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn successful_run_records_affected_rows() {
+        let mut run = JobRun::start("token_blacklist_cleanup", JobTrigger::Schedule, None, "node-1");
+        assert!(run.status.is_running());
+        run.succeed(42, 15);
+        assert_eq!(run.status, JobRunStatus::Succeeded);
+        assert_eq!(run.affected, Some(42));
+        assert_eq!(run.duration_ms, Some(15));
+        assert!(run.finished_at.is_some());
+    }
+
+    #[test]
+    fn failed_run_truncates_error() {
+        let mut run = JobRun::start("token_blacklist_cleanup", JobTrigger::Manual, None, "node-1");
+        run.fail(&"x".repeat(MAX_ERROR_LEN * 2), 3);
+        assert_eq!(run.status, JobRunStatus::Failed);
+        assert_eq!(run.error.as_ref().map(|e| e.len()), Some(MAX_ERROR_LEN));
+        assert!(run.affected.is_none());
+    }
+}
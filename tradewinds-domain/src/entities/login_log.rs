@@ -0,0 +1,100 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::user::User;
+use crate::value_objects::login_log::{LoginFailureReason, LoginLogId, LoginMethod};
+use crate::value_objects::user::UserId;
+
+// 登录日志实体
+//
+/// 记录每一次登录尝试，无论成功与否。用户名为尝试时提交的用户名，
+/// 用户不存在时 `user_id` 为空。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoginLog {
+    pub id: LoginLogId,
+    pub user_id: Option<UserId>,
+    pub username: String,
+    pub method: LoginMethod,
+    pub success: bool,
+    /// 失败原因，成功时为空
+    pub failure_reason: Option<LoginFailureReason>,
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub created_at: i64,
+}
+
+impl LoginLog {
+    /// 登录成功
+    pub fn succeeded(user: &User, method: LoginMethod, client_ip: Option<String>, user_agent: Option<String>) -> Self {
+        Self {
+            id: LoginLogId::new_v4(),
+            user_id: Some(user.id.clone()),
+            username: user.username.value().to_string(),
+            method,
+            success: true,
+            failure_reason: None,
+            client_ip,
+            user_agent,
+            created_at: Utc::now().timestamp(),
+        }
+    }
+
+    /// 登录失败，`user` 为按用户名查到的用户
+    pub fn failed(
+        username: &str,
+        user: Option<&User>,
+        method: LoginMethod,
+        reason: LoginFailureReason,
+        client_ip: Option<String>,
+        user_agent: Option<String>,
+    ) -> Self {
+        Self {
+            id: LoginLogId::new_v4(),
+            user_id: user.map(|u| u.id.clone()),
+            username: username.to_string(),
+            method,
+            success: false,
+            failure_reason: Some(reason),
+            client_ip,
+            user_agent,
+            created_at: Utc::now().timestamp(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::{AuthUsername, Email, Password};
+
+    fn user() -> User {
+        User::create(
+            AuthUsername::new("alice".to_string()).unwrap(),
+            Email::new("alice@example.com".to_string()).unwrap(),
+            Password::new("hashed-password".to_string()).unwrap(),
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn succeeded_records_user_without_reason() {
+        let user = user();
+        let log = LoginLog::succeeded(&user, LoginMethod::Password, Some("10.0.0.1".to_string()), None);
+        assert!(log.success);
+        assert_eq!(log.user_id, Some(user.id.clone()));
+        assert_eq!(log.username, "alice");
+        assert_eq!(log.failure_reason, None);
+    }
+
+    #[test]
+    fn failed_keeps_submitted_username_for_unknown_user() {
+        let log =
+            LoginLog::failed("mallory", None, LoginMethod::Password, LoginFailureReason::UserNotFound, None, None);
+        assert!(!log.success);
+        assert_eq!(log.user_id, None);
+        assert_eq!(log.username, "mallory");
+        assert_eq!(log.failure_reason, Some(LoginFailureReason::UserNotFound));
+    }
+}
@@ -1,12 +1,44 @@
+pub mod access_policy;
+pub mod access_request;
+pub mod access_review;
+pub mod audit_checkpoint;
+pub mod audit_log;
+pub mod department;
+pub mod feature_flag;
+pub mod group;
+pub mod job_run;
+pub mod login_log;
+pub mod outbox_message;
 pub mod permission;
 pub mod role;
 pub mod role_permission;
+pub mod scheduled_job;
+pub mod sod_rule;
 pub mod system_setting;
+pub mod tenant;
 pub mod user;
 pub mod user_role;
+pub mod webhook;
+pub mod webhook_delivery;
 
+pub use access_policy::AccessPolicy;
+pub use access_request::AccessRequest;
+pub use access_review::{AccessReviewCampaign, AccessReviewItem};
+pub use audit_checkpoint::AuditCheckpoint;
+pub use audit_log::AuditLog;
+pub use department::Department;
+pub use feature_flag::{FeatureFlag, FlagPrincipal};
+pub use group::Group;
+pub use job_run::JobRun;
+pub use login_log::LoginLog;
+pub use outbox_message::{OutboxMessage, OutboxRetryPolicy};
 pub use permission::Permission;
 pub use role::Role;
 pub use role_permission::RolePermission;
+pub use scheduled_job::ScheduledJob;
+pub use sod_rule::SodRule;
+pub use tenant::Tenant;
 pub use user::User;
 pub use user_role::UserRole;
+pub use webhook::Webhook;
+pub use webhook_delivery::{WebhookDelivery, WebhookRetryPolicy};
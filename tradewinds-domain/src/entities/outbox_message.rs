@@ -0,0 +1,189 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::services::Event;
+use crate::value_objects::outbox::{OutboxMessageId, OutboxStatus};
+use tradewinds_error::{AppError, AppResult};
+
+/// 错误信息的最大保留长度
+const MAX_ERROR_LEN: usize = 1000;
+
+// 发件箱消息
+//
+/// 与聚合变更在同一事务内写入，由中继任务投递到事件总线。
+/// 同一聚合的消息按写入顺序投递，投递失败按指数退避重试，超过最大次数后转为死信。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutboxMessage {
+    pub id: OutboxMessageId,
+    /// 聚合类型，如 user
+    pub aggregate_type: String,
+    pub aggregate_id: String,
+    pub event_type: String,
+    /// 事件 JSON
+    pub payload: String,
+    pub status: OutboxStatus,
+    /// 已尝试投递的次数
+    pub attempts: u32,
+    /// 下次可投递的时间
+    pub next_attempt_at: i64,
+    /// 最近一次投递失败的原因
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub published_at: Option<i64>,
+}
+
+/// 发件箱重试策略
+#[derive(Debug, Clone, Copy)]
+pub struct OutboxRetryPolicy {
+    /// 首次重试的等待秒数，之后每次翻倍
+    pub base_delay_secs: i64,
+    /// 单次等待的上限秒数
+    pub max_delay_secs: i64,
+    /// 最大尝试次数，达到后转为死信
+    pub max_attempts: u32,
+}
+
+impl OutboxRetryPolicy {
+    /// 第 `attempts` 次失败后的等待秒数
+    pub fn delay_secs(&self, attempts: u32) -> i64 {
+        let exponent = attempts.saturating_sub(1).min(32);
+        self.base_delay_secs.saturating_mul(1_i64 << exponent).min(self.max_delay_secs)
+    }
+}
+
+impl OutboxMessage {
+    /// 由聚合产生的事件创建待投递消息
+    pub fn from_event(aggregate_type: &str, aggregate_id: &str, event: &dyn Event) -> AppResult<Self> {
+        let now = Utc::now().timestamp();
+        Ok(Self {
+            id: OutboxMessageId::new_v4(),
+            aggregate_type: aggregate_type.to_string(),
+            aggregate_id: aggregate_id.to_string(),
+            event_type: event.event_type().to_string(),
+            payload: event.to_json()?,
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            created_at: now,
+            published_at: None,
+        })
+    }
+
+    /// 是否到了可投递的时间
+    pub fn is_due(&self, now: i64) -> bool {
+        self.status.is_pending() && self.next_attempt_at <= now
+    }
+
+    /// 认领投递：租约期内其他中继实例不会再认领该消息，投递结果写回前进程退出时租约到期后重新投递
+    pub fn lease(&mut self, now: i64, lease_secs: i64) {
+        self.next_attempt_at = now + lease_secs;
+    }
+
+    /// 投递成功
+    pub fn mark_published(&mut self, now: i64) {
+        self.attempts += 1;
+        self.status = OutboxStatus::Published;
+        self.published_at = Some(now);
+        self.last_error = None;
+    }
+
+    /// 投递失败：按重试策略安排下次投递，达到最大次数后转为死信
+    pub fn mark_failed(&mut self, error: &str, now: i64, policy: &OutboxRetryPolicy) {
+        self.attempts += 1;
+        self.last_error = Some(error.chars().take(MAX_ERROR_LEN).collect());
+        if self.attempts >= policy.max_attempts {
+            self.status = OutboxStatus::Dead;
+        } else {
+            self.next_attempt_at = now + policy.delay_secs(self.attempts);
+        }
+    }
+
+    /// 人工重新投递：死信或等待重试的消息立即回到待投递状态并重新计数
+    pub fn requeue(&mut self, now: i64) -> AppResult<()> {
+        if self.status == OutboxStatus::Published {
+            return Err(AppError::Validation("Outbox message has already been published".into()));
+        }
+        self.status = OutboxStatus::Pending;
+        self.attempts = 0;
+        self.next_attempt_at = now;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message() -> OutboxMessage {
+        OutboxMessage {
+            id: OutboxMessageId::new_v4(),
+            aggregate_type: "user".to_string(),
+            aggregate_id: "u1".to_string(),
+            event_type: "user.updated".to_string(),
+            payload: "{}".to_string(),
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            next_attempt_at: 100,
+            last_error: None,
+            created_at: 100,
+            published_at: None,
+        }
+    }
+
+    fn policy() -> OutboxRetryPolicy {
+        OutboxRetryPolicy { base_delay_secs: 2, max_delay_secs: 10, max_attempts: 3 }
+    }
+
+    #[test]
+    fn retry_delay_grows_exponentially_up_to_the_cap() {
+        let policy = policy();
+        assert_eq!(policy.delay_secs(1), 2);
+        assert_eq!(policy.delay_secs(2), 4);
+        assert_eq!(policy.delay_secs(3), 8);
+        assert_eq!(policy.delay_secs(4), 10);
+        assert_eq!(policy.delay_secs(100), 10);
+    }
+
+    #[test]
+    fn failed_delivery_is_retried_and_then_dead_lettered() {
+        let mut message = message();
+        message.mark_failed("broker unavailable", 100, &policy());
+        assert!(message.status.is_pending());
+        assert_eq!(message.next_attempt_at, 102);
+        assert!(!message.is_due(101));
+        assert!(message.is_due(102));
+
+        message.mark_failed("broker unavailable", 102, &policy());
+        message.mark_failed("broker unavailable", 106, &policy());
+        assert!(message.status.is_dead());
+        assert!(!message.is_due(i64::MAX));
+        assert_eq!(message.last_error.as_deref(), Some("broker unavailable"));
+    }
+
+    #[test]
+    fn leased_message_is_not_due_until_the_lease_expires() {
+        let mut message = message();
+        message.lease(100, 30);
+        assert!(!message.is_due(129));
+        assert!(message.is_due(130));
+
+        // 投递失败后按重试策略重新安排，不再等待租约
+        message.mark_failed("broker unavailable", 101, &policy());
+        assert_eq!(message.next_attempt_at, 103);
+    }
+
+    #[test]
+    fn dead_message_can_be_requeued_but_published_cannot() {
+        let mut message = message();
+        for _ in 0..3 {
+            message.mark_failed("broker unavailable", 100, &policy());
+        }
+        message.requeue(200).unwrap();
+        assert!(message.is_due(200));
+        assert_eq!(message.attempts, 0);
+
+        message.mark_published(200);
+        assert!(message.requeue(300).is_err());
+    }
+}
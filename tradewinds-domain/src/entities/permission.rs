@@ -19,6 +19,8 @@ pub struct Permission {
     pub icon: Option<PermissionIcon>,
     pub sort: PermissionSort,
     pub status: PermissionStatus,
+    /// 内置权限不可修改或删除
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -49,6 +51,7 @@ impl Permission {
             icon,
             sort,
             status,
+            built_in: false,
             created_at: now,
             updated_at: now,
         };
@@ -1,4 +1,5 @@
 use crate::value_objects::role::RoleCode;
+use crate::value_objects::scope::DataScopeType;
 use crate::value_objects::{RoleDescription, RoleId, RoleName, RoleStatus};
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
@@ -12,6 +13,9 @@ pub struct Role {
     pub name: RoleName,
     pub description: Option<RoleDescription>,
     pub status: RoleStatus,
+    pub data_scope: DataScopeType,
+    /// 内置角色（如超级管理员）不可修改、禁用或删除
+    pub built_in: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -26,7 +30,17 @@ impl Role {
         created_at: i64,
         updated_at: i64,
     ) -> Self {
-        Role { id, code, name, description, status, created_at, updated_at }
+        Role {
+            id,
+            code,
+            name,
+            description,
+            status,
+            data_scope: DataScopeType::default(),
+            built_in: false,
+            created_at,
+            updated_at,
+        }
     }
 
     pub fn update_profile(
@@ -54,6 +68,10 @@ impl Role {
         self.status = status;
     }
 
+    pub fn set_data_scope(&mut self, data_scope: DataScopeType) {
+        self.data_scope = data_scope;
+    }
+
     pub fn is_active(&self) -> bool {
         self.status.is_active()
     }
@@ -1,6 +1,7 @@
 use crate::value_objects::{
-    permission::permission_id::PermissionId, role::role_id::RoleId,
-    role_permission::role_permission_id::RolePermissionId,
+    permission::permission_id::PermissionId,
+    role::role_id::RoleId,
+    role_permission::{PermissionEffect, role_permission_id::RolePermissionId},
 };
 use chrono::Utc;
 
@@ -9,14 +10,20 @@ pub struct RolePermission {
     pub id: RolePermissionId,
     pub role_id: RoleId,
     pub permission_id: PermissionId,
+    pub effect: PermissionEffect,
     pub created_at: i64,
     pub updated_at: i64,
 }
 
 impl RolePermission {
     pub fn new(role_id: RoleId, permission_id: PermissionId) -> Self {
+        Self::with_effect(role_id, permission_id, PermissionEffect::Allow)
+    }
+
+    /// 创建指定授权效果（允许/拒绝）的角色权限关联
+    pub fn with_effect(role_id: RoleId, permission_id: PermissionId, effect: PermissionEffect) -> Self {
         let now = Utc::now().timestamp();
-        Self { id: RolePermissionId::new_v4(), role_id, permission_id, created_at: now, updated_at: now }
+        Self { id: RolePermissionId::new_v4(), role_id, permission_id, effect, created_at: now, updated_at: now }
     }
 
     pub fn role_id(&self) -> &RoleId {
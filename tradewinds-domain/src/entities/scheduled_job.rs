@@ -0,0 +1,133 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::value_objects::job::JobRunStatus;
+
+// 定时任务
+//
+/// 任务由调度器在启动时登记，名称、说明与 cron 表达式以代码中的登记为准；
+/// 暂停状态与下次执行时间持久化，供多个实例共享。`next_run_at` 为空时由调度器按 cron 表达式重新计算。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledJob {
+    pub name: String,
+    pub description: String,
+    /// cron 表达式：秒 分 时 日 月 周，按 UTC 解析
+    pub cron: String,
+    pub paused: bool,
+    /// 下次执行时间
+    pub next_run_at: Option<i64>,
+    /// 最近一次执行的开始时间
+    pub last_run_at: Option<i64>,
+    /// 最近一次执行的状态
+    pub last_status: Option<JobRunStatus>,
+    pub updated_at: i64,
+}
+
+impl ScheduledJob {
+    pub fn register(name: String, description: String, cron: String) -> AppResult<Self> {
+        if name.is_empty()
+            || name.len() > 64
+            || !name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(AppError::Validation(format!("Invalid job name: {}", name)));
+        }
+        if cron.trim().is_empty() {
+            return Err(AppError::Validation(format!("Cron expression of job {} is required", name)));
+        }
+        Ok(Self {
+            name,
+            description,
+            cron: cron.trim().to_string(),
+            paused: false,
+            next_run_at: None,
+            last_run_at: None,
+            last_status: None,
+            updated_at: Utc::now().timestamp(),
+        })
+    }
+
+    /// 以新的登记信息更新任务，cron 表达式变化时重新计算下次执行时间，返回是否有变化
+    pub fn redefine(&mut self, description: &str, cron: &str) -> bool {
+        let cron = cron.trim();
+        if self.description == description && self.cron == cron {
+            return false;
+        }
+        if self.cron != cron {
+            self.cron = cron.to_string();
+            self.next_run_at = None;
+        }
+        self.description = description.to_string();
+        self.updated_at = Utc::now().timestamp();
+        true
+    }
+
+    /// 暂停后不再按计划执行，仍可手动触发
+    pub fn pause(&mut self) -> AppResult<()> {
+        if self.paused {
+            return Err(AppError::Conflict(format!("Job {} is already paused", self.name)));
+        }
+        self.paused = true;
+        self.updated_at = Utc::now().timestamp();
+        Ok(())
+    }
+
+    /// 恢复后从当前时间起重新计算下次执行时间，暂停期间错过的执行不再补跑
+    pub fn resume(&mut self) -> AppResult<()> {
+        if !self.paused {
+            return Err(AppError::Conflict(format!("Job {} is not paused", self.name)));
+        }
+        self.paused = false;
+        self.next_run_at = None;
+        self.updated_at = Utc::now().timestamp();
+        Ok(())
+    }
+
+    /// 是否到了按计划执行的时间
+    pub fn is_due(&self, now: i64) -> bool {
+        !self.paused && self.next_run_at.is_some_and(|next_run_at| next_run_at <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job() -> ScheduledJob {
+        ScheduledJob::register("login_log_retention".into(), "清理登录日志".into(), "0 30 3 * * *".into()).unwrap()
+    }
+
+    #[test]
+    fn validates_name_and_cron() {
+        assert!(ScheduledJob::register("Login-Logs".into(), String::new(), "0 * * * * *".into()).is_err());
+        assert!(ScheduledJob::register("login_logs".into(), String::new(), " ".into()).is_err());
+        assert!(job().next_run_at.is_none());
+    }
+
+    #[test]
+    fn pause_and_resume_control_due_runs() {
+        let mut job = job();
+        job.next_run_at = Some(100);
+        assert!(job.is_due(100));
+        assert!(!job.is_due(99));
+
+        job.pause().unwrap();
+        assert!(!job.is_due(100));
+        assert!(job.pause().is_err());
+
+        job.resume().unwrap();
+        assert!(job.next_run_at.is_none());
+        assert!(job.resume().is_err());
+    }
+
+    #[test]
+    fn changed_cron_resets_next_run() {
+        let mut job = job();
+        job.next_run_at = Some(100);
+        assert!(!job.redefine("清理登录日志", "0 30 3 * * *"));
+        assert!(job.redefine("清理过期登录日志", "0 30 3 * * *"));
+        assert_eq!(job.next_run_at, Some(100));
+        assert!(job.redefine("清理过期登录日志", "0 0 4 * * *"));
+        assert!(job.next_run_at.is_none());
+    }
+}
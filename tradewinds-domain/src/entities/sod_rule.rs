@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::role::RoleId;
+use crate::value_objects::sod::{SodRuleId, SodRuleName, SodRuleType};
+
+// 职责分离（SoD）规则实体
+//
+/// 规则以数据形式维护，由 SeparationOfDutyPolicy 统一校验
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SodRule {
+    pub id: SodRuleId,
+    pub name: SodRuleName,
+    pub rule_type: SodRuleType,
+    pub role_ids: Vec<RoleId>,
+    pub max_count: u32,
+    pub enabled: bool,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl SodRule {
+    /// 规则是否约束该角色；MaxRolesPerUser 规则约束全部角色
+    pub fn covers(&self, role_id: &RoleId) -> bool {
+        matches!(self.rule_type, SodRuleType::MaxRolesPerUser) || self.role_ids.contains(role_id)
+    }
+}
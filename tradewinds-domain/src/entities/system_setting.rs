@@ -1,7 +1,12 @@
-use crate::value_objects::system_setting::{SystemSettingId, SystemSettingKey, SystemSettingValue};
+use crate::value_objects::system_setting::{SettingDefinition, SystemSettingId, SystemSettingKey, SystemSettingValue};
 use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use tradewinds_error::AppResult;
 
-#[derive(Debug, Clone)]
+/// 发件箱消息中的聚合类型，聚合ID为设置键
+pub const SYSTEM_SETTING_AGGREGATE_TYPE: &str = "system_setting";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SystemSetting {
     pub id: SystemSettingId,
     pub key: SystemSettingKey,
@@ -9,3 +14,68 @@ pub struct SystemSetting {
     pub description: Option<String>,
     pub updated_at: NaiveDateTime,
 }
+
+/// 登记表中的设置及其当前生效的值
+#[derive(Debug, Clone)]
+pub struct EffectiveSetting {
+    pub definition: &'static SettingDefinition,
+    /// 规范化后的生效值
+    pub value: SystemSettingValue,
+    /// 生效值是否来自数据库，否则为声明的默认值
+    pub customized: bool,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+impl EffectiveSetting {
+    /// 保存的值不符合当前声明（如声明变更前写入的旧值）时回退到默认值
+    pub fn resolve(definition: &'static SettingDefinition, stored: Option<&SystemSetting>) -> AppResult<Self> {
+        if let Some(setting) = stored
+            && let Ok(value) = definition.validate(setting.value.value())
+        {
+            return Ok(Self { definition, value, customized: true, updated_at: Some(setting.updated_at) });
+        }
+        let value = definition.validate(definition.default_value)?;
+        Ok(Self { definition, value, customized: false, updated_at: None })
+    }
+
+    /// 对外展示的值，敏感设置以占位值代替
+    pub fn display_value(&self) -> &str {
+        self.definition.display_value(self.value.value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::system_setting::{AUDIT_EXPORT_MAX_ROWS, SettingRegistry};
+
+    fn stored(value: &str) -> SystemSetting {
+        SystemSetting {
+            id: SystemSettingId::new("1".to_string()).unwrap(),
+            key: SystemSettingKey::new(AUDIT_EXPORT_MAX_ROWS.to_string()).unwrap(),
+            value: SystemSettingValue::new(value.to_string()).unwrap(),
+            description: None,
+            updated_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    #[test]
+    fn resolve_prefers_valid_stored_value() {
+        let definition = SettingRegistry::require(AUDIT_EXPORT_MAX_ROWS).unwrap();
+        let setting = EffectiveSetting::resolve(definition, Some(&stored(" 500 "))).unwrap();
+        assert_eq!(setting.value.value(), "500");
+        assert!(setting.customized);
+    }
+
+    #[test]
+    fn resolve_falls_back_to_default() {
+        let definition = SettingRegistry::require(AUDIT_EXPORT_MAX_ROWS).unwrap();
+        let missing = EffectiveSetting::resolve(definition, None).unwrap();
+        assert_eq!(missing.value.value(), definition.default_value);
+        assert!(!missing.customized);
+
+        let invalid = EffectiveSetting::resolve(definition, Some(&stored("lots"))).unwrap();
+        assert_eq!(invalid.value.value(), definition.default_value);
+        assert!(!invalid.customized);
+    }
+}
@@ -0,0 +1,115 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::value_objects::tenant::{TenantCode, TenantHost, TenantId, TenantName, TenantStatus};
+
+/// 平台默认租户ID（与 tradewinds_common::tenant::DEFAULT_TENANT_ID 一致）
+pub const PLATFORM_TENANT_ID: &str = "default";
+
+/// 发件箱消息中的聚合类型
+pub const TENANT_AGGREGATE_TYPE: &str = "tenant";
+
+// 租户实体
+//
+/// 每个租户拥有独立的用户、角色、权限与系统参数；
+/// 平台默认租户承载平台管理员，不可禁用或删除。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Tenant {
+    pub id: TenantId,
+    pub code: TenantCode,
+    pub name: TenantName,
+    pub host: Option<TenantHost>,
+    pub status: TenantStatus,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Tenant {
+    pub fn create(code: TenantCode, name: TenantName, host: Option<TenantHost>) -> Self {
+        let now = Utc::now().timestamp();
+        Self {
+            id: TenantId::new_v4(),
+            code,
+            name,
+            host,
+            status: TenantStatus::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 更新租户名称、域名与状态
+    ///
+    /// `host` 为 `Some(None)` 时解除域名绑定
+    pub fn update(
+        &mut self,
+        name: Option<TenantName>,
+        host: Option<Option<TenantHost>>,
+        status: Option<TenantStatus>,
+    ) -> AppResult<()> {
+        if self.status.is_deleted() {
+            return Err(AppError::Validation("Tenant already deleted".into()));
+        }
+        if let Some(status) = status
+            && !status.is_active()
+            && self.is_platform()
+        {
+            return Err(AppError::Forbidden("The platform tenant cannot be disabled or deleted".into()));
+        }
+        if let Some(name) = name {
+            self.name = name;
+        }
+        if let Some(host) = host {
+            self.host = host;
+        }
+        if let Some(status) = status {
+            self.status = status;
+        }
+        self.updated_at = Utc::now().timestamp();
+        Ok(())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status.is_active()
+    }
+
+    pub fn is_platform(&self) -> bool {
+        self.id.value() == PLATFORM_TENANT_ID
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tenant(id: &str) -> Tenant {
+        let mut tenant = Tenant::create(TenantCode::new("acme").unwrap(), TenantName::new("Acme").unwrap(), None);
+        tenant.id = TenantId::new(id.to_string()).unwrap();
+        tenant
+    }
+
+    #[test]
+    fn platform_tenant_cannot_be_disabled() {
+        let mut platform = tenant(PLATFORM_TENANT_ID);
+        let result = platform.update(None, None, Some(TenantStatus::Inactive));
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        assert!(platform.is_active());
+    }
+
+    #[test]
+    fn deleted_tenant_rejects_changes() {
+        let mut acme = tenant("t-acme");
+        acme.update(None, Some(Some(TenantHost::new("Acme.Example.com:8080").unwrap())), Some(TenantStatus::Deleted))
+            .unwrap();
+        assert_eq!(acme.host.as_ref().map(|h| h.value()), Some("acme.example.com"));
+        assert!(acme.update(Some(TenantName::new("Acme 2").unwrap()), None, None).is_err());
+    }
+
+    #[test]
+    fn tenant_code_is_restricted() {
+        assert!(TenantCode::new("acme-01").is_ok());
+        assert!(TenantCode::new("Acme").is_err());
+        assert!(TenantCode::new("a").is_err());
+    }
+}
@@ -2,6 +2,7 @@ use chrono::Utc;
 
 use crate::value_objects::{
     auth::{auth_password::Password, auth_username::AuthUsername},
+    department::DepartmentId,
     user::{
         user_avatar::Avatar, user_email::Email, user_id::UserId, user_phone::Phone, user_real_name::RealName,
         user_status::UserStatus,
@@ -18,8 +19,13 @@ pub struct User {
     pub phone: Option<Phone>,
     pub avatar: Option<Avatar>,
     pub status: UserStatus,
+    pub department_id: Option<DepartmentId>,
+    pub created_by: Option<UserId>,
     pub created_at: i64,
     pub updated_at: i64,
+    /// 最近一次成功登录的时间与来源IP
+    pub last_login_at: Option<i64>,
+    pub last_login_ip: Option<String>,
 }
 
 impl User {
@@ -41,8 +47,12 @@ impl User {
             phone,
             avatar,
             status: UserStatus::Active,
+            department_id: None,
+            created_by: None,
             created_at: now,
             updated_at: now,
+            last_login_at: None,
+            last_login_ip: None,
         }
     }
 
@@ -82,4 +92,10 @@ impl User {
         self.password = new_password;
         self.updated_at = Utc::now().timestamp();
     }
+
+    /// 记录一次成功登录，不视为资料变更，不更新 `updated_at`
+    pub fn record_login(&mut self, client_ip: Option<String>) {
+        self.last_login_at = Some(Utc::now().timestamp());
+        self.last_login_ip = client_ip;
+    }
 }
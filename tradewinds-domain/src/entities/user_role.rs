@@ -1,4 +1,6 @@
-use crate::value_objects::{role::role_id::RoleId, user::user_id::UserId, user_role::user_role_id::UserRoleId};
+use crate::value_objects::{
+    access_request::AccessRequestId, role::role_id::RoleId, user::user_id::UserId, user_role::user_role_id::UserRoleId,
+};
 use chrono::Utc;
 
 /// 用户-角色关联实体
@@ -8,6 +10,8 @@ pub struct UserRole {
     pub id: UserRoleId,
     pub user_id: UserId,
     pub role_id: RoleId,
+    /// 经权限申请授予时对应的申请，直接分配时为空
+    pub access_request_id: Option<AccessRequestId>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -15,7 +19,7 @@ pub struct UserRole {
 impl UserRole {
     pub fn new(user_id: UserId, role_id: RoleId) -> Self {
         let now = Utc::now().timestamp();
-        Self { id: UserRoleId::new_v4(), user_id, role_id, created_at: now, updated_at: now }
+        Self { id: UserRoleId::new_v4(), user_id, role_id, access_request_id: None, created_at: now, updated_at: now }
     }
 
     pub fn user_id(&self) -> &UserId {
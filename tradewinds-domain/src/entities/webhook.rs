@@ -0,0 +1,327 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::value_objects::webhook::{WebhookId, WebhookStatus};
+
+/// 可订阅的事件类型，`*` 表示全部
+pub const WEBHOOK_EVENT_TYPES: &[&str] = &[
+    "user.created",
+    "user.registered",
+    "user.updated",
+    "user.profile_updated",
+    "user.status_changed",
+    "user.deleted",
+    "user.role_assigned",
+    "user.role_revoked",
+    "role.created",
+    "role.updated",
+    "role.deleted",
+    "permission.created",
+    "permission.updated",
+    "permission.deleted",
+    "department.created",
+    "department.updated",
+    "department.deleted",
+    "group.created",
+    "group.updated",
+    "group.deleted",
+    "group.members_added",
+    "group.member_removed",
+    "tenant.created",
+    "tenant.updated",
+    "system_setting.changed",
+];
+
+/// 订阅全部事件类型
+pub const WEBHOOK_ALL_EVENTS: &str = "*";
+
+/// 签名密钥的最小长度
+const MIN_SECRET_LEN: usize = 16;
+
+// Webhook 端点
+//
+/// 订阅的事件发生后向 `url` 投递签名的 JSON 请求，连续投递失败达到阈值后自动停用，
+/// 重新启用后清零失败计数。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Webhook {
+    pub id: WebhookId,
+    pub name: String,
+    pub url: String,
+    /// HMAC-SHA256 签名密钥
+    pub secret: String,
+    /// 订阅的事件类型
+    pub event_types: Vec<String>,
+    pub status: WebhookStatus,
+    /// 连续投递失败次数，投递成功后清零
+    pub consecutive_failures: u32,
+    /// 自动停用的原因
+    pub disabled_reason: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl Webhook {
+    pub fn create(name: String, url: String, secret: String, event_types: Vec<String>) -> AppResult<Self> {
+        let now = Utc::now().timestamp();
+        Ok(Self {
+            id: WebhookId::new_v4(),
+            name: validate_name(name)?,
+            url: validate_url(url)?,
+            secret: validate_secret(secret)?,
+            event_types: validate_event_types(event_types)?,
+            status: WebhookStatus::Active,
+            consecutive_failures: 0,
+            disabled_reason: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 更新端点，`enabled` 为 `Some(true)` 时重新启用并清零失败计数
+    pub fn update(
+        &mut self,
+        name: Option<String>,
+        url: Option<String>,
+        secret: Option<String>,
+        event_types: Option<Vec<String>>,
+        enabled: Option<bool>,
+    ) -> AppResult<()> {
+        if let Some(name) = name {
+            self.name = validate_name(name)?;
+        }
+        if let Some(url) = url {
+            self.url = validate_url(url)?;
+        }
+        if let Some(secret) = secret {
+            self.secret = validate_secret(secret)?;
+        }
+        if let Some(event_types) = event_types {
+            self.event_types = validate_event_types(event_types)?;
+        }
+        match enabled {
+            Some(true) => {
+                self.status = WebhookStatus::Active;
+                self.consecutive_failures = 0;
+                self.disabled_reason = None;
+            }
+            Some(false) => self.status = WebhookStatus::Disabled,
+            None => {}
+        }
+        self.updated_at = Utc::now().timestamp();
+        Ok(())
+    }
+
+    /// 是否向该端点投递给定类型的事件
+    pub fn subscribes_to(&self, event_type: &str) -> bool {
+        self.status.is_active()
+            && self.event_types.iter().any(|subscribed| subscribed == WEBHOOK_ALL_EVENTS || subscribed == event_type)
+    }
+
+    /// 投递成功，返回状态是否有变化
+    pub fn record_success(&mut self, now: i64) -> bool {
+        if self.consecutive_failures == 0 {
+            return false;
+        }
+        self.consecutive_failures = 0;
+        self.updated_at = now;
+        true
+    }
+
+    /// 投递失败，连续失败达到 `max_consecutive_failures` 次时自动停用，返回是否因此停用
+    pub fn record_failure(&mut self, now: i64, max_consecutive_failures: u32) -> bool {
+        self.consecutive_failures += 1;
+        self.updated_at = now;
+        if self.status.is_active() && self.consecutive_failures >= max_consecutive_failures {
+            self.status = WebhookStatus::Disabled;
+            self.disabled_reason =
+                Some(format!("Disabled after {} consecutive failed deliveries", self.consecutive_failures));
+            return true;
+        }
+        false
+    }
+}
+
+fn validate_name(name: String) -> AppResult<String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > 100 {
+        return Err(AppError::Validation("Webhook name must be 1-100 characters".into()));
+    }
+    Ok(name)
+}
+
+/// 是否为允许投递的公网地址
+///
+/// 回环、私有网段、链路本地（含云厂商元数据服务 169.254.169.254）、运营商级 NAT、未指定、广播与组播地址
+/// 可能指向内网服务，不能作为 Webhook 目标。IPv4 映射的 IPv6 地址按其 IPv4 地址判断。
+pub fn is_public_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_ipv4(ip),
+            None => {
+                !(ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_multicast()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local())
+            }
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+    let shared = first == 100 && (64..128).contains(&second);
+    !(first == 0
+        || shared
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation())
+}
+
+/// 取 url 中的主机名，IPv6 地址去掉方括号
+fn url_host(rest: &str) -> &str {
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority.rsplit('@').next().unwrap_or_default();
+    match host_port.strip_prefix('[') {
+        Some(ipv6) => ipv6.split(']').next().unwrap_or_default(),
+        None => host_port.split(':').next().unwrap_or_default(),
+    }
+}
+
+/// 校验 url 格式，并拒绝 localhost 与非公网的 IP 地址；域名解析到的地址由发送方在连接前校验
+fn validate_url(url: String) -> AppResult<String> {
+    let url = url.trim().to_string();
+    let rest = url.strip_prefix("https://").or_else(|| url.strip_prefix("http://"));
+    let host = match rest {
+        Some(rest) if !url.contains(char::is_whitespace) => url_host(rest),
+        _ => "",
+    };
+    if host.is_empty() {
+        return Err(AppError::Validation("Webhook url must be an absolute http(s) url".into()));
+    }
+    let host = host.to_ascii_lowercase();
+    let local_name = host == "localhost" || host.ends_with(".localhost");
+    if local_name || host.parse::<IpAddr>().is_ok_and(|ip| !is_public_address(ip)) {
+        return Err(AppError::Validation("Webhook url must not point to a local or private network address".into()));
+    }
+    Ok(url)
+}
+
+fn validate_secret(secret: String) -> AppResult<String> {
+    if secret.chars().count() < MIN_SECRET_LEN {
+        return Err(AppError::Validation(format!("Webhook secret must be at least {} characters", MIN_SECRET_LEN)));
+    }
+    Ok(secret)
+}
+
+fn validate_event_types(event_types: Vec<String>) -> AppResult<Vec<String>> {
+    if event_types.is_empty() {
+        return Err(AppError::Validation("Webhook must subscribe to at least one event type".into()));
+    }
+    let mut validated: Vec<String> = Vec::with_capacity(event_types.len());
+    for event_type in event_types {
+        let event_type = event_type.trim().to_string();
+        if event_type != WEBHOOK_ALL_EVENTS && !WEBHOOK_EVENT_TYPES.contains(&event_type.as_str()) {
+            return Err(AppError::Validation(format!("Unsupported webhook event type: {}", event_type)));
+        }
+        if !validated.contains(&event_type) {
+            validated.push(event_type);
+        }
+    }
+    Ok(validated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn webhook(event_types: &[&str]) -> Webhook {
+        Webhook::create(
+            "HR".to_string(),
+            "https://hr.example.com/hooks".to_string(),
+            "0123456789abcdef".to_string(),
+            event_types.iter().map(|t| t.to_string()).collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn validates_url_secret_and_event_types() {
+        let create = |url: &str, secret: &str, event_type: &str| {
+            Webhook::create("HR".into(), url.into(), secret.into(), vec![event_type.into()])
+        };
+        assert!(create("https://hr.example.com/hooks", "0123456789abcdef", "user.created").is_ok());
+        assert!(create("ftp://hr.example.com", "0123456789abcdef", "user.created").is_err());
+        assert!(create("https:///hooks", "0123456789abcdef", "user.created").is_err());
+        assert!(create("https://hr.example.com/hooks", "short", "user.created").is_err());
+        assert!(create("https://hr.example.com/hooks", "0123456789abcdef", "user.logged_in").is_err());
+    }
+
+    #[test]
+    fn rejects_urls_pointing_at_local_or_private_addresses() {
+        let create = |url: &str| {
+            Webhook::create("HR".into(), url.into(), "0123456789abcdef".into(), vec!["user.created".into()])
+        };
+        assert!(create("https://203.0.113.7.nip.io/hooks").is_ok());
+        assert!(create("https://8.8.8.8:8443/hooks").is_ok());
+        assert!(create("http://[2001:4860:4860::8888]/hooks").is_ok());
+        assert!(create("http://localhost:8080/hooks").is_err());
+        assert!(create("http://api.LOCALHOST/hooks").is_err());
+        assert!(create("http://127.0.0.1/hooks").is_err());
+        assert!(create("http://10.0.0.5/hooks").is_err());
+        assert!(create("http://172.16.0.1/hooks").is_err());
+        assert!(create("http://192.168.1.1/hooks").is_err());
+        assert!(create("http://169.254.169.254/latest/meta-data").is_err());
+        assert!(create("http://0.0.0.0/hooks").is_err());
+        assert!(create("http://user@127.0.0.1/hooks").is_err());
+        assert!(create("http://[::1]:8080/hooks").is_err());
+        assert!(create("http://[::ffff:10.0.0.1]/hooks").is_err());
+        assert!(create("http://[fd00::1]/hooks").is_err());
+        assert!(create("http://[fe80::1]/hooks").is_err());
+    }
+
+    #[test]
+    fn only_public_addresses_are_deliverable() {
+        let public = |ip: &str| is_public_address(ip.parse().unwrap());
+        assert!(public("93.184.216.34"));
+        assert!(public("2606:2800:220:1::1"));
+        assert!(!public("100.64.0.1"));
+        assert!(!public("255.255.255.255"));
+        assert!(!public("224.0.0.1"));
+        assert!(!public("::"));
+    }
+
+    #[test]
+    fn matches_subscribed_event_types_only_while_active() {
+        let mut hook = webhook(&["user.created", "user.deleted"]);
+        assert!(hook.subscribes_to("user.created"));
+        assert!(!hook.subscribes_to("role.updated"));
+        assert!(webhook(&["*"]).subscribes_to("role.updated"));
+
+        hook.update(None, None, None, None, Some(false)).unwrap();
+        assert!(!hook.subscribes_to("user.created"));
+    }
+
+    #[test]
+    fn disables_after_consecutive_failures_and_reenable_resets() {
+        let mut hook = webhook(&["user.created"]);
+        assert!(!hook.record_failure(100, 3));
+        assert!(hook.record_success(101));
+        assert!(!hook.record_failure(102, 3));
+        assert!(!hook.record_failure(103, 3));
+        assert!(hook.record_failure(104, 3));
+        assert!(!hook.status.is_active());
+        assert!(hook.disabled_reason.is_some());
+
+        hook.update(None, None, None, None, Some(true)).unwrap();
+        assert!(hook.status.is_active());
+        assert_eq!(hook.consecutive_failures, 0);
+        assert!(hook.disabled_reason.is_none());
+    }
+}
@@ -0,0 +1,213 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::webhook::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookId};
+use tradewinds_error::{AppError, AppResult};
+
+/// 错误信息的最大保留长度
+const MAX_ERROR_LEN: usize = 1000;
+
+// Webhook 投递记录
+//
+/// 每个订阅的事件对每个端点生成一条投递记录，由投递任务发送；失败按指数退避重试，
+/// 超过最大次数后标记为失败。人工重新投递会基于原记录生成一条新记录，原记录保留作为投递日志。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookDelivery {
+    pub id: WebhookDeliveryId,
+    pub webhook_id: WebhookId,
+    pub event_type: String,
+    /// 请求体 JSON，包含事件 ID、类型、时间、租户与事件数据
+    pub payload: String,
+    pub status: WebhookDeliveryStatus,
+    /// 已尝试投递的次数
+    pub attempts: u32,
+    /// 下次可投递的时间
+    pub next_attempt_at: i64,
+    /// 最近一次响应的 HTTP 状态码
+    pub response_status: Option<u16>,
+    /// 最近一次投递失败的原因
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub delivered_at: Option<i64>,
+    /// 重新投递时指向原投递记录
+    pub redelivery_of: Option<WebhookDeliveryId>,
+}
+
+/// Webhook 重试策略
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookRetryPolicy {
+    /// 首次重试的等待秒数，之后每次翻倍
+    pub base_delay_secs: i64,
+    /// 单次等待的上限秒数
+    pub max_delay_secs: i64,
+    /// 最大尝试次数，达到后标记为失败
+    pub max_attempts: u32,
+}
+
+impl WebhookRetryPolicy {
+    /// 第 `attempts` 次失败后的等待秒数
+    pub fn delay_secs(&self, attempts: u32) -> i64 {
+        let exponent = attempts.saturating_sub(1).min(32);
+        self.base_delay_secs.saturating_mul(1_i64 << exponent).min(self.max_delay_secs)
+    }
+}
+
+impl WebhookDelivery {
+    /// 为端点创建一条待投递记录
+    pub fn create(webhook_id: WebhookId, event_type: &str, data: &str, tenant_id: &str) -> AppResult<Self> {
+        let now = Utc::now();
+        let id = WebhookDeliveryId::new_v4();
+        let data: serde_json::Value = serde_json::from_str(data)?;
+        let payload = serde_json::json!({
+            "id": id.value(),
+            "type": event_type,
+            "createdAt": now.to_rfc3339(),
+            "tenantId": tenant_id,
+            "data": data,
+        });
+        Ok(Self {
+            id,
+            webhook_id,
+            event_type: event_type.to_string(),
+            payload: payload.to_string(),
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now.timestamp(),
+            response_status: None,
+            last_error: None,
+            created_at: now.timestamp(),
+            delivered_at: None,
+            redelivery_of: None,
+        })
+    }
+
+    /// 是否到了可投递的时间
+    pub fn is_due(&self, now: i64) -> bool {
+        self.status.is_pending() && self.next_attempt_at <= now
+    }
+
+    /// 认领投递：租约期内其他投递任务实例不会再认领该记录
+    pub fn lease(&mut self, now: i64, lease_secs: i64) {
+        self.next_attempt_at = now + lease_secs;
+    }
+
+    /// 释放已认领但本轮未发送的记录，下一轮重新认领，不计入尝试次数
+    pub fn release(&mut self, now: i64) {
+        self.next_attempt_at = now;
+    }
+
+    /// 投递成功
+    pub fn mark_succeeded(&mut self, response_status: u16, now: i64) {
+        self.attempts += 1;
+        self.status = WebhookDeliveryStatus::Succeeded;
+        self.response_status = Some(response_status);
+        self.delivered_at = Some(now);
+        self.last_error = None;
+    }
+
+    /// 投递失败：按重试策略安排下次投递，达到最大次数后标记为失败
+    pub fn mark_failed(&mut self, response_status: Option<u16>, error: &str, now: i64, policy: &WebhookRetryPolicy) {
+        self.attempts += 1;
+        self.response_status = response_status;
+        self.last_error = Some(error.chars().take(MAX_ERROR_LEN).collect());
+        if self.attempts >= policy.max_attempts {
+            self.status = WebhookDeliveryStatus::Failed;
+        } else {
+            self.next_attempt_at = now + policy.delay_secs(self.attempts);
+        }
+    }
+
+    /// 放弃投递，如端点已停用或删除
+    pub fn abandon(&mut self, reason: &str) {
+        self.status = WebhookDeliveryStatus::Failed;
+        self.last_error = Some(reason.chars().take(MAX_ERROR_LEN).collect());
+    }
+
+    /// 人工重新投递：以相同请求体生成一条新的待投递记录，等待重试中的记录不能重新投递
+    pub fn redeliver(&self, now: i64) -> AppResult<Self> {
+        if self.status.is_pending() {
+            return Err(AppError::Validation("Webhook delivery is still pending".into()));
+        }
+        Ok(Self {
+            id: WebhookDeliveryId::new_v4(),
+            webhook_id: self.webhook_id.clone(),
+            event_type: self.event_type.clone(),
+            payload: self.payload.clone(),
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            response_status: None,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+            redelivery_of: Some(self.id.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn delivery() -> WebhookDelivery {
+        let mut delivery =
+            WebhookDelivery::create(WebhookId::new_v4(), "user.created", r#"{"user_id":"u1"}"#, "default").unwrap();
+        delivery.next_attempt_at = 100;
+        delivery
+    }
+
+    fn policy() -> WebhookRetryPolicy {
+        WebhookRetryPolicy { base_delay_secs: 10, max_delay_secs: 60, max_attempts: 3 }
+    }
+
+    #[test]
+    fn payload_wraps_event_data_in_envelope() {
+        let delivery = delivery();
+        let payload: serde_json::Value = serde_json::from_str(&delivery.payload).unwrap();
+        assert_eq!(payload["id"], delivery.id.value());
+        assert_eq!(payload["type"], "user.created");
+        assert_eq!(payload["tenantId"], "default");
+        assert_eq!(payload["data"]["user_id"], "u1");
+    }
+
+    #[test]
+    fn failed_delivery_backs_off_and_then_fails() {
+        let mut delivery = delivery();
+        delivery.mark_failed(Some(503), "Service Unavailable", 100, &policy());
+        assert!(delivery.status.is_pending());
+        assert_eq!(delivery.next_attempt_at, 110);
+        assert!(!delivery.is_due(109));
+        assert_eq!(delivery.response_status, Some(503));
+
+        delivery.mark_failed(None, "connection refused", 110, &policy());
+        assert_eq!(delivery.next_attempt_at, 130);
+        delivery.mark_failed(None, "connection refused", 130, &policy());
+        assert!(delivery.status.is_failed());
+        assert!(!delivery.is_due(i64::MAX));
+    }
+
+    #[test]
+    fn leased_delivery_waits_for_the_lease_and_release_does_not_count_an_attempt() {
+        let mut delivery = delivery();
+        delivery.lease(100, 60);
+        assert!(!delivery.is_due(159));
+        assert!(delivery.is_due(160));
+
+        delivery.release(120);
+        assert!(delivery.is_due(120));
+        assert_eq!(delivery.attempts, 0);
+    }
+
+    #[test]
+    fn redelivery_copies_payload_into_new_pending_delivery() {
+        let mut delivery = delivery();
+        assert!(delivery.redeliver(100).is_err());
+
+        delivery.abandon("Webhook is disabled");
+        let redelivery = delivery.redeliver(200).unwrap();
+        assert_ne!(redelivery.id, delivery.id);
+        assert_eq!(redelivery.payload, delivery.payload);
+        assert_eq!(redelivery.redelivery_of.as_ref(), Some(&delivery.id));
+        assert!(redelivery.is_due(200));
+    }
+}
@@ -0,0 +1,97 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::services::event_bus::Event;
+use crate::value_objects::{access_request::AccessRequestId, role::RoleId, user::UserId};
+use tradewinds_error::AppResult;
+
+/// 权限申请领域事件，由 AccessRequestAggregate 在状态变化时产生
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AccessRequestEvent {
+    Submitted {
+        request_id: AccessRequestId,
+        requester_id: UserId,
+        role_id: RoleId,
+        occurred_at: i64,
+    },
+    Approved {
+        request_id: AccessRequestId,
+        requester_id: UserId,
+        role_id: RoleId,
+        approver_id: UserId,
+        grant_expires_at: i64,
+        occurred_at: i64,
+    },
+    Rejected {
+        request_id: AccessRequestId,
+        requester_id: UserId,
+        role_id: RoleId,
+        approver_id: UserId,
+        comment: Option<String>,
+        occurred_at: i64,
+    },
+    Cancelled {
+        request_id: AccessRequestId,
+        requester_id: UserId,
+        role_id: RoleId,
+        occurred_at: i64,
+    },
+    /// `grant_ended` 为 true 表示已批准的授权到期，否则为待审批超时
+    Expired {
+        request_id: AccessRequestId,
+        requester_id: UserId,
+        role_id: RoleId,
+        grant_ended: bool,
+        occurred_at: i64,
+    },
+}
+
+impl AccessRequestEvent {
+    pub fn request_id(&self) -> &AccessRequestId {
+        match self {
+            Self::Submitted { request_id, .. }
+            | Self::Approved { request_id, .. }
+            | Self::Rejected { request_id, .. }
+            | Self::Cancelled { request_id, .. }
+            | Self::Expired { request_id, .. } => request_id,
+        }
+    }
+
+    pub fn requester_id(&self) -> &UserId {
+        match self {
+            Self::Submitted { requester_id, .. }
+            | Self::Approved { requester_id, .. }
+            | Self::Rejected { requester_id, .. }
+            | Self::Cancelled { requester_id, .. }
+            | Self::Expired { requester_id, .. } => requester_id,
+        }
+    }
+
+    pub fn role_id(&self) -> &RoleId {
+        match self {
+            Self::Submitted { role_id, .. }
+            | Self::Approved { role_id, .. }
+            | Self::Rejected { role_id, .. }
+            | Self::Cancelled { role_id, .. }
+            | Self::Expired { role_id, .. } => role_id,
+        }
+    }
+}
+
+#[async_trait]
+impl Event for AccessRequestEvent {
+    fn event_type(&self) -> &'static str {
+        match self {
+            Self::Submitted { .. } => "access_request.submitted",
+            Self::Approved { .. } => "access_request.approved",
+            Self::Rejected { .. } => "access_request.rejected",
+            Self::Cancelled { .. } => "access_request.cancelled",
+            Self::Expired { .. } => "access_request.expired",
+        }
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        serde_json::to_string(self).map_err(|e| e.into())
+    }
+}
@@ -1 +1,3 @@
+pub mod access_request_event;
 
+pub use access_request_event::AccessRequestEvent;
@@ -0,0 +1,717 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::value_objects::permission::PermissionCode;
+
+/// 属性值
+///
+/// 与 JSON 值一一对应，便于从请求体或数据库直接构造
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(untagged)]
+pub enum AttributeValue {
+    #[default]
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    List(Vec<AttributeValue>),
+}
+
+impl AttributeValue {
+    pub fn is_null(&self) -> bool {
+        matches!(self, AttributeValue::Null)
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            AttributeValue::Null => "null",
+            AttributeValue::Bool(_) => "bool",
+            AttributeValue::Number(_) => "number",
+            AttributeValue::String(_) => "string",
+            AttributeValue::List(_) => "list",
+        }
+    }
+}
+
+impl From<bool> for AttributeValue {
+    fn from(value: bool) -> Self {
+        AttributeValue::Bool(value)
+    }
+}
+
+impl From<i64> for AttributeValue {
+    fn from(value: i64) -> Self {
+        AttributeValue::Number(value as f64)
+    }
+}
+
+impl From<u32> for AttributeValue {
+    fn from(value: u32) -> Self {
+        AttributeValue::Number(value as f64)
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        AttributeValue::Number(value)
+    }
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        AttributeValue::String(value.to_string())
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        AttributeValue::String(value)
+    }
+}
+
+impl<T: Into<AttributeValue>> From<Vec<T>> for AttributeValue {
+    fn from(value: Vec<T>) -> Self {
+        AttributeValue::List(value.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<AttributeValue>> From<Option<T>> for AttributeValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(AttributeValue::Null)
+    }
+}
+
+impl fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AttributeValue::Null => write!(f, "null"),
+            AttributeValue::Bool(b) => write!(f, "{}", b),
+            AttributeValue::Number(n) => write!(f, "{}", n),
+            AttributeValue::String(s) => write!(f, "{:?}", s),
+            AttributeValue::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/// 属性集合
+pub type Attributes = BTreeMap<String, AttributeValue>;
+
+/// 访问上下文：主体、资源、操作与环境属性
+///
+/// 表达式中分别以 `subject.*`、`resource.*`、`action`、`env.*` 引用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccessContext {
+    pub subject: Attributes,
+    pub resource: Attributes,
+    pub action: PermissionCode,
+    pub environment: Attributes,
+}
+
+impl AccessContext {
+    pub fn new(action: PermissionCode) -> Self {
+        Self { subject: Attributes::new(), resource: Attributes::new(), action, environment: Attributes::new() }
+    }
+
+    fn lookup(&self, path: &[String]) -> AttributeValue {
+        let (root, rest) = match path.split_first() {
+            Some(split) => split,
+            None => return AttributeValue::Null,
+        };
+        let attributes = match root.as_str() {
+            "action" => return AttributeValue::String(self.action.value().to_string()),
+            "subject" => &self.subject,
+            "resource" => &self.resource,
+            _ => &self.environment,
+        };
+        attributes.get(&rest.join(".")).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Num(f64),
+    Compare(CompareOp),
+    And,
+    Or,
+    Not,
+    In,
+    True,
+    False,
+    Null,
+    Dot,
+    Comma,
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    IpIn,
+    Contains,
+    StartsWith,
+    EndsWith,
+    Exists,
+}
+
+impl Function {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "ip_in" => Some(Function::IpIn),
+            "contains" => Some(Function::Contains),
+            "starts_with" => Some(Function::StartsWith),
+            "ends_with" => Some(Function::EndsWith),
+            "exists" => Some(Function::Exists),
+            _ => None,
+        }
+    }
+
+    fn arity(&self) -> usize {
+        match self {
+            Function::Exists => 1,
+            _ => 2,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Literal(AttributeValue),
+    Path(Vec<String>),
+    List(Vec<Expr>),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(CompareOp, Box<Expr>, Box<Expr>),
+    In(Box<Expr>, Box<Expr>),
+    Call(Function, Vec<Expr>),
+}
+
+/// 属性表达式
+///
+/// 语法（优先级由低到高）：
+/// - `a or b`、`a || b`
+/// - `a and b`、`a && b`
+/// - `not a`、`!a`
+/// - 比较：`==`、`!=`、`<`、`<=`、`>`、`>=`、`in`、`not in`
+/// - 字面量：`true`、`false`、`null`、数字、`'字符串'`、`[列表]`
+/// - 属性：`subject.*`、`resource.*`、`env.*`、`action`
+/// - 函数：`ip_in(ip, cidr)`、`contains(a, b)`、`starts_with(s, p)`、`ends_with(s, p)`、`exists(x)`
+///
+/// 缺失的属性取值为 `null`；类型不匹配在求值时报错。
+/// 括号、列表、函数参数、`not` 与 `and`/`or` 链合计嵌套不超过 [`MAX_DEPTH`] 层
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression(Expr);
+
+impl Expression {
+    pub fn parse(source: &str) -> AppResult<Self> {
+        let tokens = tokenize(source).map_err(|(pos, msg)| invalid(pos, &msg))?;
+        let mut parser = Parser { tokens, pos: 0, end: source.len(), depth: 0 };
+        let expr = parser.parse_or().map_err(|(pos, msg)| invalid(pos, &msg))?;
+        if let Some((_, pos)) = parser.tokens.get(parser.pos) {
+            return Err(invalid(*pos, "unexpected trailing input"));
+        }
+        Ok(Self(expr))
+    }
+
+    /// 求值，结果必须为布尔值
+    pub fn evaluate(&self, ctx: &AccessContext) -> AppResult<bool> {
+        match eval(&self.0, ctx).map_err(AppError::Validation)? {
+            AttributeValue::Bool(b) => Ok(b),
+            other => Err(AppError::Validation(format!(
+                "Policy condition must evaluate to a bool, got {}",
+                other.type_name()
+            ))),
+        }
+    }
+}
+
+fn invalid(pos: usize, msg: &str) -> AppError {
+    AppError::Validation(format!("Invalid policy expression at {}: {}", pos, msg))
+}
+
+type ParseResult<T> = Result<T, (usize, String)>;
+
+/// 表达式的最大嵌套深度，避免过深的表达式在解析与求值时耗尽栈空间
+pub const MAX_DEPTH: usize = 64;
+
+fn tokenize(source: &str) -> ParseResult<Vec<(Token, usize)>> {
+    let chars: Vec<(usize, char)> = source.char_indices().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        let next = chars.get(i + 1).map(|(_, c)| *c);
+        let (token, width) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (Token::LParen, 1),
+            ')' => (Token::RParen, 1),
+            '[' => (Token::LBracket, 1),
+            ']' => (Token::RBracket, 1),
+            ',' => (Token::Comma, 1),
+            '.' => (Token::Dot, 1),
+            '=' if next == Some('=') => (Token::Compare(CompareOp::Eq), 2),
+            '!' if next == Some('=') => (Token::Compare(CompareOp::Ne), 2),
+            '!' => (Token::Not, 1),
+            '<' if next == Some('=') => (Token::Compare(CompareOp::Le), 2),
+            '<' => (Token::Compare(CompareOp::Lt), 1),
+            '>' if next == Some('=') => (Token::Compare(CompareOp::Ge), 2),
+            '>' => (Token::Compare(CompareOp::Gt), 1),
+            '&' if next == Some('&') => (Token::And, 2),
+            '|' if next == Some('|') => (Token::Or, 2),
+            '\'' | '"' => {
+                let mut value = String::new();
+                let mut j = i + 1;
+                loop {
+                    match chars.get(j) {
+                        None => return Err((pos, "unterminated string".into())),
+                        Some((_, '\\')) => match chars.get(j + 1) {
+                            Some((_, escaped)) => {
+                                value.push(*escaped);
+                                j += 2;
+                            }
+                            None => return Err((pos, "unterminated string".into())),
+                        },
+                        Some((_, ch)) if *ch == c => break,
+                        Some((_, ch)) => {
+                            value.push(*ch);
+                            j += 1;
+                        }
+                    }
+                }
+                (Token::Str(value), j + 1 - i)
+            }
+            c if c.is_ascii_digit() || (c == '-' && next.is_some_and(|n| n.is_ascii_digit())) => {
+                let mut j = i + 1;
+                while chars.get(j).is_some_and(|(_, ch)| ch.is_ascii_digit() || *ch == '.') {
+                    j += 1;
+                }
+                let text: String = chars[i..j].iter().map(|(_, ch)| ch).collect();
+                let number = text.parse::<f64>().map_err(|_| (pos, format!("invalid number '{}'", text)))?;
+                (Token::Num(number), j - i)
+            }
+            c if c.is_ascii_alphabetic() || c == '_' => {
+                let mut j = i + 1;
+                while chars.get(j).is_some_and(|(_, ch)| ch.is_ascii_alphanumeric() || *ch == '_') {
+                    j += 1;
+                }
+                let word: String = chars[i..j].iter().map(|(_, ch)| ch).collect();
+                let token = match word.as_str() {
+                    "and" => Token::And,
+                    "or" => Token::Or,
+                    "not" => Token::Not,
+                    "in" => Token::In,
+                    "true" => Token::True,
+                    "false" => Token::False,
+                    "null" => Token::Null,
+                    _ => Token::Ident(word),
+                };
+                (token, j - i)
+            }
+            other => return Err((pos, format!("unexpected character '{}'", other))),
+        };
+        tokens.push((token, pos));
+        i += width;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    end: usize,
+    /// 当前嵌套深度
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn offset(&self) -> usize {
+        self.tokens.get(self.pos).map(|(_, p)| *p).unwrap_or(self.end)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    /// 进入一层嵌套，超过最大深度时报错
+    fn descend(&mut self) -> ParseResult<()> {
+        if self.depth >= MAX_DEPTH {
+            return Err((self.offset(), format!("expression nested deeper than {} levels", MAX_DEPTH)));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// 在下一层嵌套中解析
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> ParseResult<T>) -> ParseResult<T> {
+        self.descend()?;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn expect(&mut self, expected: Token, what: &str) -> ParseResult<()> {
+        if self.peek() == Some(&expected) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err((self.offset(), format!("expected {}", what)))
+        }
+    }
+
+    /// `or` 链左结合，每多一个操作数语法树加深一层
+    fn parse_or(&mut self) -> ParseResult<Expr> {
+        let depth = self.depth;
+        let mut left = self.parse_and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.descend()?;
+            self.pos += 1;
+            left = Expr::Or(Box::new(left), Box::new(self.parse_and()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn parse_and(&mut self) -> ParseResult<Expr> {
+        let depth = self.depth;
+        let mut left = self.parse_not()?;
+        while self.peek() == Some(&Token::And) {
+            self.descend()?;
+            self.pos += 1;
+            left = Expr::And(Box::new(left), Box::new(self.parse_not()?));
+        }
+        self.depth = depth;
+        Ok(left)
+    }
+
+    fn parse_not(&mut self) -> ParseResult<Expr> {
+        if self.peek() == Some(&Token::Not) {
+            self.pos += 1;
+            return self.nested(|p| Ok(Expr::Not(Box::new(p.parse_not()?))));
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> ParseResult<Expr> {
+        let left = self.parse_primary()?;
+        match self.peek() {
+            Some(Token::Compare(op)) => {
+                let op = *op;
+                self.pos += 1;
+                Ok(Expr::Compare(op, Box::new(left), Box::new(self.parse_primary()?)))
+            }
+            Some(Token::In) => {
+                self.pos += 1;
+                Ok(Expr::In(Box::new(left), Box::new(self.parse_primary()?)))
+            }
+            Some(Token::Not) if self.tokens.get(self.pos + 1).map(|(t, _)| t) == Some(&Token::In) => {
+                self.pos += 2;
+                Ok(Expr::Not(Box::new(Expr::In(Box::new(left), Box::new(self.parse_primary()?)))))
+            }
+            _ => Ok(left),
+        }
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<Expr> {
+        let offset = self.offset();
+        match self.advance() {
+            Some(Token::True) => Ok(Expr::Literal(AttributeValue::Bool(true))),
+            Some(Token::False) => Ok(Expr::Literal(AttributeValue::Bool(false))),
+            Some(Token::Null) => Ok(Expr::Literal(AttributeValue::Null)),
+            Some(Token::Num(n)) => Ok(Expr::Literal(AttributeValue::Number(n))),
+            Some(Token::Str(s)) => Ok(Expr::Literal(AttributeValue::String(s))),
+            Some(Token::LParen) => {
+                let expr = self.nested(Self::parse_or)?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(expr)
+            }
+            Some(Token::LBracket) => {
+                let mut items = Vec::new();
+                if self.peek() != Some(&Token::RBracket) {
+                    loop {
+                        items.push(self.nested(Self::parse_primary)?);
+                        if self.peek() != Some(&Token::Comma) {
+                            break;
+                        }
+                        self.pos += 1;
+                    }
+                }
+                self.expect(Token::RBracket, "']'")?;
+                Ok(Expr::List(items))
+            }
+            Some(Token::Ident(name)) if self.peek() == Some(&Token::LParen) => {
+                let function =
+                    Function::parse(&name).ok_or_else(|| (offset, format!("unknown function '{}'", name)))?;
+                self.pos += 1;
+                let mut args = Vec::new();
+                if self.peek() != Some(&Token::RParen) {
+                    loop {
+                        args.push(self.nested(Self::parse_or)?);
+                        if self.peek() != Some(&Token::Comma) {
+                            break;
+                        }
+                        self.pos += 1;
+                    }
+                }
+                self.expect(Token::RParen, "')'")?;
+                if args.len() != function.arity() {
+                    return Err((offset, format!("function '{}' expects {} argument(s)", name, function.arity())));
+                }
+                Ok(Expr::Call(function, args))
+            }
+            Some(Token::Ident(root)) => self.parse_path(root, offset),
+            Some(_) => Err((offset, "unexpected token".into())),
+            None => Err((offset, "unexpected end of expression".into())),
+        }
+    }
+
+    fn parse_path(&mut self, root: String, offset: usize) -> ParseResult<Expr> {
+        let root = match root.as_str() {
+            "action" => return Ok(Expr::Path(vec![root])),
+            "subject" | "resource" | "env" => root,
+            "environment" => "env".to_string(),
+            _ => return Err((offset, format!("unknown attribute '{}'", root))),
+        };
+        let mut path = vec![root];
+        while self.peek() == Some(&Token::Dot) {
+            self.pos += 1;
+            match self.advance() {
+                Some(Token::Ident(segment)) => path.push(segment),
+                _ => return Err((self.offset(), "expected attribute name after '.'".into())),
+            }
+        }
+        if path.len() < 2 {
+            return Err((offset, format!("attribute '{}' requires a name, e.g. {}.id", path[0], path[0])));
+        }
+        Ok(Expr::Path(path))
+    }
+}
+
+type EvalResult = Result<AttributeValue, String>;
+
+fn eval(expr: &Expr, ctx: &AccessContext) -> EvalResult {
+    match expr {
+        Expr::Literal(value) => Ok(value.clone()),
+        Expr::Path(path) => Ok(ctx.lookup(path)),
+        Expr::List(items) => Ok(AttributeValue::List(items.iter().map(|e| eval(e, ctx)).collect::<Result<_, _>>()?)),
+        Expr::Not(inner) => Ok(AttributeValue::Bool(!eval_bool(inner, ctx)?)),
+        Expr::And(left, right) => Ok(AttributeValue::Bool(eval_bool(left, ctx)? && eval_bool(right, ctx)?)),
+        Expr::Or(left, right) => Ok(AttributeValue::Bool(eval_bool(left, ctx)? || eval_bool(right, ctx)?)),
+        Expr::Compare(op, left, right) => compare(*op, &eval(left, ctx)?, &eval(right, ctx)?).map(AttributeValue::Bool),
+        Expr::In(item, collection) => contains(&eval(collection, ctx)?, &eval(item, ctx)?).map(AttributeValue::Bool),
+        Expr::Call(function, args) => {
+            let args = args.iter().map(|e| eval(e, ctx)).collect::<Result<Vec<_>, _>>()?;
+            call(*function, &args).map(AttributeValue::Bool)
+        }
+    }
+}
+
+fn eval_bool(expr: &Expr, ctx: &AccessContext) -> Result<bool, String> {
+    match eval(expr, ctx)? {
+        AttributeValue::Bool(b) => Ok(b),
+        other => Err(format!("expected bool, got {}", other.type_name())),
+    }
+}
+
+fn compare(op: CompareOp, left: &AttributeValue, right: &AttributeValue) -> Result<bool, String> {
+    match op {
+        CompareOp::Eq => return Ok(left == right),
+        CompareOp::Ne => return Ok(left != right),
+        _ => {}
+    }
+    let ordering = match (left, right) {
+        (AttributeValue::Number(a), AttributeValue::Number(b)) => a.partial_cmp(b),
+        (AttributeValue::String(a), AttributeValue::String(b)) => Some(a.cmp(b)),
+        _ => None,
+    }
+    .ok_or_else(|| format!("cannot order {} and {}", left.type_name(), right.type_name()))?;
+    Ok(match op {
+        CompareOp::Lt => ordering.is_lt(),
+        CompareOp::Le => ordering.is_le(),
+        CompareOp::Gt => ordering.is_gt(),
+        _ => ordering.is_ge(),
+    })
+}
+
+fn contains(collection: &AttributeValue, item: &AttributeValue) -> Result<bool, String> {
+    match (collection, item) {
+        (AttributeValue::List(items), _) => Ok(items.contains(item)),
+        (AttributeValue::String(s), AttributeValue::String(sub)) => Ok(s.contains(sub.as_str())),
+        (AttributeValue::Null, _) => Ok(false),
+        _ => Err(format!("cannot search {} in {}", item.type_name(), collection.type_name())),
+    }
+}
+
+fn call(function: Function, args: &[AttributeValue]) -> Result<bool, String> {
+    match function {
+        Function::Exists => Ok(!args[0].is_null()),
+        Function::Contains => contains(&args[0], &args[1]),
+        Function::StartsWith | Function::EndsWith => match (&args[0], &args[1]) {
+            (AttributeValue::String(s), AttributeValue::String(p)) => {
+                Ok(if function == Function::StartsWith { s.starts_with(p.as_str()) } else { s.ends_with(p.as_str()) })
+            }
+            (AttributeValue::Null, _) => Ok(false),
+            (a, b) => Err(format!("expected strings, got {} and {}", a.type_name(), b.type_name())),
+        },
+        Function::IpIn => {
+            let ip = match &args[0] {
+                AttributeValue::Null => return Ok(false),
+                AttributeValue::String(s) => s.parse::<IpAddr>().map_err(|_| format!("invalid ip address '{}'", s))?,
+                other => return Err(format!("expected ip string, got {}", other.type_name())),
+            };
+            let networks = match &args[1] {
+                AttributeValue::List(items) => items.clone(),
+                other => vec![other.clone()],
+            };
+            for network in &networks {
+                match network {
+                    AttributeValue::String(cidr) => {
+                        if ip_in_cidr(ip, cidr)? {
+                            return Ok(true);
+                        }
+                    }
+                    other => return Err(format!("expected cidr string, got {}", other.type_name())),
+                }
+            }
+            Ok(false)
+        }
+    }
+}
+
+/// 判断 IP 是否位于网段内，网段可以是 `10.0.0.0/8` 或单个地址
+fn ip_in_cidr(ip: IpAddr, cidr: &str) -> Result<bool, String> {
+    let invalid = || format!("invalid cidr '{}'", cidr);
+    let (network, prefix) = match cidr.split_once('/') {
+        Some((network, prefix)) => (network, Some(prefix.parse::<u32>().map_err(|_| invalid())?)),
+        None => (cidr, None),
+    };
+    let network = network.trim().parse::<IpAddr>().map_err(|_| invalid())?;
+    match (ip.to_canonical(), network.to_canonical()) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let prefix = prefix.unwrap_or(32);
+            if prefix > 32 {
+                return Err(invalid());
+            }
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            Ok(u32::from(ip) & mask == u32::from(network) & mask)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let prefix = prefix.unwrap_or(128);
+            if prefix > 128 {
+                return Err(invalid());
+            }
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            Ok(u128::from(ip) & mask == u128::from(network) & mask)
+        }
+        _ => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ctx() -> AccessContext {
+        let mut ctx = AccessContext::new(PermissionCode::new("system:user:update").unwrap());
+        ctx.subject.insert("department_id".into(), "d-sales".into());
+        ctx.subject.insert("roles".into(), vec!["manager", "auditor"].into());
+        ctx.resource.insert("department_id".into(), "d-sales".into());
+        ctx.environment.insert("hour".into(), 10i64.into());
+        ctx.environment.insert("weekday".into(), 3i64.into());
+        ctx.environment.insert("ip".into(), "10.1.2.3".into());
+        ctx
+    }
+
+    fn check(source: &str) -> AppResult<bool> {
+        Expression::parse(source)?.evaluate(&ctx())
+    }
+
+    #[test]
+    fn business_hours_from_office_network() {
+        let rule =
+            "env.hour >= 9 and env.hour < 18 and env.weekday <= 5 and ip_in(env.ip, ['10.0.0.0/8', '192.168.1.0/24'])";
+        assert!(check(rule).unwrap());
+        assert!(!check("ip_in(env.ip, '192.168.0.0/16')").unwrap());
+        assert!(check("ip_in('::ffff:10.1.2.3', '10.1.0.0/16')").unwrap());
+    }
+
+    #[test]
+    fn same_department_and_membership() {
+        assert!(check("subject.department_id == resource.department_id").unwrap());
+        assert!(check("'manager' in subject.roles && action == 'system:user:update'").unwrap());
+        assert!(check("'admin' not in subject.roles").unwrap());
+        assert!(!check("exists(resource.owner_id)").unwrap());
+    }
+
+    #[test]
+    fn precedence_of_not_and_or() {
+        assert!(check("false or true and true").unwrap());
+        assert!(!check("not true or false").unwrap());
+        assert!(check("!(env.hour > 12)").unwrap());
+    }
+
+    #[test]
+    fn invalid_expressions_are_rejected() {
+        assert!(Expression::parse("subject.id ==").is_err());
+        assert!(Expression::parse("user.id == 1").is_err());
+        assert!(Expression::parse("foo(1)").is_err());
+        assert!(Expression::parse("(true").is_err());
+        assert!(Expression::parse("'open").is_err());
+    }
+
+    #[test]
+    fn nesting_depth_is_limited() {
+        let within = format!("{}true{}", "(".repeat(MAX_DEPTH - 1), ")".repeat(MAX_DEPTH - 1));
+        assert!(check(&within).unwrap());
+
+        let parens = format!("{}true{}", "(".repeat(MAX_DEPTH + 1), ")".repeat(MAX_DEPTH + 1));
+        assert!(Expression::parse(&parens).is_err());
+        assert!(Expression::parse(&format!("{}true", "not ".repeat(MAX_DEPTH + 1))).is_err());
+        assert!(Expression::parse(&format!("{}true", "!".repeat(100_000))).is_err());
+        assert!(Expression::parse(&format!("{}1{}", "[".repeat(100_000), "]".repeat(100_000))).is_err());
+        assert!(Expression::parse(&format!("exists({}1{})", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH))).is_err());
+        assert!(Expression::parse(&vec!["true"; MAX_DEPTH + 2].join(" and ")).is_err());
+        assert!(Expression::parse(&vec!["false"; 100_000].join(" or ")).is_err());
+    }
+
+    #[test]
+    fn type_errors_surface_at_evaluation() {
+        assert!(check("resource.level > 3").is_err());
+        assert!(check("env.hour").is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::entities::access_policy::AccessPolicy;
+use crate::policies::abac_expression::AccessContext;
+use crate::policies::access_explanation_policy::AccessExplanation;
+use crate::value_objects::{
+    policy::{AccessPolicyId, AccessPolicyName},
+    role_permission::PermissionEffect,
+};
+
+/// ABAC 判定结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbacDecision {
+    /// 没有策略作用于该操作，维持 RBAC 结论
+    NotApplicable,
+    Permit,
+    Deny,
+}
+
+/// 单条策略的求值结果
+#[derive(Debug, Clone)]
+pub struct PolicyOutcome {
+    pub policy_id: AccessPolicyId,
+    pub name: AccessPolicyName,
+    pub effect: PermissionEffect,
+    /// 条件是否成立；求值出错时按不利于访问的一方计
+    pub matched: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AbacEvaluation {
+    pub decision: AbacDecision,
+    /// 作用于该操作的策略的求值明细
+    pub outcomes: Vec<PolicyOutcome>,
+}
+
+impl AbacEvaluation {
+    pub fn is_allowed(&self) -> bool {
+        self.decision != AbacDecision::Deny
+    }
+}
+
+/// RBAC 与 ABAC 的合并判定，ABAC 在 RBAC 通过后生效
+#[derive(Debug, Clone)]
+pub struct AccessEvaluation {
+    pub context: AccessContext,
+    pub rbac: AccessExplanation,
+    pub abac: AbacEvaluation,
+    pub allowed: bool,
+}
+
+impl AccessEvaluation {
+    pub fn new(context: AccessContext, rbac: AccessExplanation, abac: AbacEvaluation) -> Self {
+        let allowed = rbac.allowed && abac.is_allowed();
+        Self { context, rbac, abac, allowed }
+    }
+}
+
+/// 属性访问控制策略
+///
+/// 规则：
+/// - 仅启用且操作模式覆盖目标操作的策略参与判定
+/// - 任一拒绝策略条件成立即拒绝（拒绝优先）
+/// - 存在允许策略时，至少一条条件成立才允许
+/// - 没有策略作用于该操作时不作限制
+/// - 条件求值出错时失败即关闭：拒绝策略视为成立，允许策略视为不成立
+pub struct AbacPolicy;
+
+impl AbacPolicy {
+    pub fn evaluate(policies: &[AccessPolicy], ctx: &AccessContext) -> AbacEvaluation {
+        let outcomes: Vec<PolicyOutcome> = policies
+            .iter()
+            .filter(|p| p.applies_to(&ctx.action))
+            .map(|p| {
+                let result = p.condition.compile().and_then(|expr| expr.evaluate(ctx));
+                let (matched, error) = match result {
+                    Ok(matched) => (matched, None),
+                    Err(e) => (p.effect.is_deny(), Some(e.to_string())),
+                };
+                PolicyOutcome { policy_id: p.id.clone(), name: p.name.clone(), effect: p.effect, matched, error }
+            })
+            .collect();
+
+        let decision = if outcomes.iter().any(|o| o.effect.is_deny() && o.matched) {
+            AbacDecision::Deny
+        } else if outcomes.iter().any(|o| o.effect.is_allow()) {
+            if outcomes.iter().any(|o| o.effect.is_allow() && o.matched) {
+                AbacDecision::Permit
+            } else {
+                AbacDecision::Deny
+            }
+        } else {
+            AbacDecision::NotApplicable
+        };
+        AbacEvaluation { decision, outcomes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::{
+        permission::PermissionCode,
+        policy::{AccessPolicyStatus, PolicyExpression},
+    };
+
+    fn policy(name: &str, action: &str, effect: PermissionEffect, condition: &str) -> AccessPolicy {
+        AccessPolicy::create(
+            AccessPolicyName::new(name).unwrap(),
+            None,
+            PermissionCode::new(action).unwrap(),
+            effect,
+            PolicyExpression::new(condition).unwrap(),
+        )
+    }
+
+    fn ctx(action: &str, hour: i64, ip: &str) -> AccessContext {
+        let mut ctx = AccessContext::new(PermissionCode::new(action).unwrap());
+        ctx.environment.insert("hour".into(), hour.into());
+        ctx.environment.insert("ip".into(), ip.into());
+        ctx
+    }
+
+    #[test]
+    fn allow_policies_constrain_matching_actions() {
+        let policies = vec![policy(
+            "business-hours",
+            "order:approve",
+            PermissionEffect::Allow,
+            "env.hour >= 9 and env.hour < 18 and ip_in(env.ip, '10.0.0.0/8')",
+        )];
+
+        assert_eq!(
+            AbacPolicy::evaluate(&policies, &ctx("order:approve", 10, "10.0.0.5")).decision,
+            AbacDecision::Permit
+        );
+        assert_eq!(AbacPolicy::evaluate(&policies, &ctx("order:approve", 20, "10.0.0.5")).decision, AbacDecision::Deny);
+        let other = AbacPolicy::evaluate(&policies, &ctx("order:list", 20, "8.8.8.8"));
+        assert_eq!(other.decision, AbacDecision::NotApplicable);
+        assert!(other.outcomes.is_empty());
+    }
+
+    #[test]
+    fn deny_overrides_allow() {
+        let policies = vec![
+            policy("all", "*", PermissionEffect::Allow, "true"),
+            policy("no-external", "order:*", PermissionEffect::Deny, "not ip_in(env.ip, '10.0.0.0/8')"),
+        ];
+
+        let evaluation = AbacPolicy::evaluate(&policies, &ctx("order:approve", 10, "8.8.8.8"));
+        assert_eq!(evaluation.decision, AbacDecision::Deny);
+        assert_eq!(evaluation.outcomes.len(), 2);
+        assert!(!evaluation.is_allowed());
+    }
+
+    #[test]
+    fn evaluation_errors_fail_closed() {
+        let deny = vec![policy("level", "order:approve", PermissionEffect::Deny, "resource.level > 3")];
+        let evaluation = AbacPolicy::evaluate(&deny, &ctx("order:approve", 10, "10.0.0.1"));
+        assert_eq!(evaluation.decision, AbacDecision::Deny);
+        assert!(evaluation.outcomes[0].error.is_some());
+
+        let allow = vec![policy("level", "order:approve", PermissionEffect::Allow, "resource.level <= 3")];
+        assert_eq!(AbacPolicy::evaluate(&allow, &ctx("order:approve", 10, "10.0.0.1")).decision, AbacDecision::Deny);
+    }
+
+    #[test]
+    fn inactive_policies_are_ignored() {
+        let mut disabled = policy("closed", "order:approve", PermissionEffect::Deny, "true");
+        disabled.update(None, None, None, None, None, Some(AccessPolicyStatus::Inactive)).unwrap();
+        assert_eq!(
+            AbacPolicy::evaluate(&[disabled], &ctx("order:approve", 10, "10.0.0.1")).decision,
+            AbacDecision::NotApplicable
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+
+use crate::entities::{permission::Permission, role::Role, user::User};
+use crate::policies::{PermissionDecision, PermissionGrant, PermissionPolicy};
+use crate::value_objects::{
+    permission::{PermissionCode, PermissionId},
+    role::{RoleId, RoleSource},
+};
+
+/// 命中目标权限的一条授权证据
+#[derive(Debug, Clone)]
+pub struct GrantEvidence {
+    pub grant: PermissionGrant,
+    pub role: Role,
+    /// 用户获得该角色的来源（直接分配或经由用户组）
+    pub sources: Vec<RoleSource>,
+}
+
+/// 阻止访问的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccessBlocker {
+    /// 用户本身未启用
+    UserInactive,
+    /// 没有任何启用角色经由启用的权限授予该权限
+    NotGranted,
+    /// 被某个角色显式拒绝
+    DeniedByRole { role_id: RoleId, permission_id: PermissionId },
+    /// 授予该权限的角色未启用
+    RoleInactive { role_id: RoleId },
+    /// 权限本身或授予它的（通配）权限未启用
+    PermissionInactive { permission_id: PermissionId },
+    /// 上级菜单未启用
+    ParentInactive { permission_id: PermissionId },
+}
+
+/// 访问判定说明
+#[derive(Debug, Clone)]
+pub struct AccessExplanation {
+    pub code: PermissionCode,
+    /// 仅按启用角色上、启用权限的授权判定的结果
+    pub decision: PermissionDecision,
+    /// 最终是否可访问
+    pub allowed: bool,
+    /// 权限码对应的权限（仅按通配授权判定的接口权限可能不存在）
+    pub permission: Option<Permission>,
+    /// 上级菜单链，由近及远
+    pub ancestors: Vec<Permission>,
+    /// 命中该权限的全部授权（含未启用角色或未启用权限上的授权）
+    pub grants: Vec<GrantEvidence>,
+    pub blockers: Vec<AccessBlocker>,
+}
+
+/// 访问说明策略
+///
+/// 规则：
+/// - 授权是否参与判定、权限及上级菜单是否可达均由 PermissionPolicy 判定，与有效权限列表一致
+/// - 未启用角色上或未启用权限上的允许授权作为阻止原因列出
+/// - 用户未启用时不可访问
+pub struct AccessExplanationPolicy;
+
+impl AccessExplanationPolicy {
+    /// 说明用户对某个权限码的访问判定
+    ///
+    /// `roles` 为用户的有效角色及来源，`grants` 为这些角色上的全部授权，`catalog` 为权限全集
+    pub fn explain(
+        user: &User,
+        required: &PermissionCode,
+        roles: &[(Role, Vec<RoleSource>)],
+        grants: &[PermissionGrant],
+        catalog: &[Permission],
+    ) -> AccessExplanation {
+        let permission = catalog.iter().find(|p| !p.status.is_deleted() && p.code.as_ref() == Some(required)).cloned();
+        let ancestors = permission.as_ref().map(|p| PermissionPolicy::ancestors(p, catalog)).unwrap_or_default();
+
+        let roles_by_id: HashMap<&RoleId, &(Role, Vec<RoleSource>)> = roles.iter().map(|r| (&r.0.id, r)).collect();
+        let evidence: Vec<GrantEvidence> = grants
+            .iter()
+            .filter(|g| match &permission {
+                Some(p) => g.covers(p),
+                None => g.covers_code(required),
+            })
+            .filter_map(|g| {
+                roles_by_id.get(&g.role_id).map(|(role, sources)| GrantEvidence {
+                    grant: g.clone(),
+                    role: role.clone(),
+                    sources: sources.clone(),
+                })
+            })
+            .collect();
+
+        let participates = |e: &GrantEvidence| PermissionPolicy::participates(&e.grant, &e.role);
+        let active_grants: Vec<PermissionGrant> =
+            evidence.iter().filter(|e| participates(e)).map(|e| e.grant.clone()).collect();
+        let decision = match &permission {
+            Some(p) => PermissionPolicy::evaluate_permission(&active_grants, p),
+            None => PermissionPolicy::evaluate(&active_grants, required),
+        };
+
+        let mut blockers = Vec::new();
+        if !user.status.is_active() {
+            blockers.push(AccessBlocker::UserInactive);
+        }
+        match decision {
+            PermissionDecision::Allowed => {}
+            PermissionDecision::Denied => {
+                for e in evidence.iter().filter(|e| participates(e) && e.grant.effect.is_deny()) {
+                    blockers.push(AccessBlocker::DeniedByRole {
+                        role_id: e.grant.role_id.clone(),
+                        permission_id: e.grant.permission_id.clone(),
+                    });
+                }
+            }
+            PermissionDecision::NotGranted => {
+                for e in evidence.iter().filter(|e| !participates(e) && !e.grant.effect.is_deny()) {
+                    let blocker = if e.role.is_active() {
+                        AccessBlocker::PermissionInactive { permission_id: e.grant.permission_id.clone() }
+                    } else {
+                        AccessBlocker::RoleInactive { role_id: e.role.id.clone() }
+                    };
+                    if !blockers.contains(&blocker) {
+                        blockers.push(blocker);
+                    }
+                }
+                if !blockers.iter().any(|b| *b != AccessBlocker::UserInactive) {
+                    blockers.push(AccessBlocker::NotGranted);
+                }
+            }
+        }
+        if let Some(p) = &permission
+            && !p.status.is_active()
+        {
+            let blocker = AccessBlocker::PermissionInactive { permission_id: p.id.clone() };
+            if !blockers.contains(&blocker) {
+                blockers.push(blocker);
+            }
+        }
+        for parent in ancestors.iter().filter(|p| !p.status.is_active()) {
+            blockers.push(AccessBlocker::ParentInactive { permission_id: parent.id.clone() });
+        }
+
+        let reachable = permission.as_ref().is_none_or(|p| PermissionPolicy::is_reachable(p, catalog));
+        AccessExplanation {
+            code: required.clone(),
+            decision,
+            allowed: user.status.is_active() && decision.is_allowed() && reachable,
+            permission,
+            ancestors,
+            grants: evidence,
+            blockers,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::{
+        RoleStatus,
+        auth::{auth_password::Password, auth_username::AuthUsername},
+        permission::{PermissionName, PermissionSort, PermissionStatus, PermissionType},
+        role::{RoleCode, RoleName},
+        role_permission::PermissionEffect,
+        user::{Email, UserStatus},
+    };
+
+    fn user(status: UserStatus) -> User {
+        let mut user = User::create(
+            AuthUsername::new("alice".to_string()).unwrap(),
+            Email::new("alice@example.com".to_string()).unwrap(),
+            Password::new("hashed".to_string()).unwrap(),
+            None,
+            None,
+            None,
+        );
+        user.status = status;
+        user
+    }
+
+    fn permission(id: &str, code: &str, parent: Option<&str>, status: PermissionStatus) -> Permission {
+        let mut p = Permission::create(
+            PermissionName::new(id).unwrap(),
+            Some(PermissionCode::new(code).unwrap()),
+            PermissionType::Menu,
+            parent.map(|p| PermissionId::new(p.to_string()).unwrap()),
+            None,
+            None,
+            None,
+            PermissionSort::new(0).unwrap(),
+        )
+        .unwrap();
+        p.id = PermissionId::new(id.to_string()).unwrap();
+        p.status = status;
+        p
+    }
+
+    fn role(id: &str, status: RoleStatus) -> (Role, Vec<RoleSource>) {
+        let role = Role::create(
+            RoleId::new(id.to_string()).unwrap(),
+            RoleCode::new(id.to_string()).unwrap(),
+            RoleName::new(id.to_string()).unwrap(),
+            None,
+            status,
+            0,
+            0,
+        );
+        (role, vec![RoleSource::Direct])
+    }
+
+    fn grant(role: &str, p: &Permission, effect: PermissionEffect) -> PermissionGrant {
+        PermissionGrant::new(RoleId::new(role.to_string()).unwrap(), p.id.clone(), p.code.clone(), effect, p.status)
+    }
+
+    fn code(s: &str) -> PermissionCode {
+        PermissionCode::new(s).unwrap()
+    }
+
+    #[test]
+    fn allowed_with_evidence_from_granting_role() {
+        let system = permission("p-system", "system", None, PermissionStatus::Active);
+        let orders = permission("p-orders", "system:orders", Some("p-system"), PermissionStatus::Active);
+        let grants = vec![grant("sales", &orders, PermissionEffect::Allow)];
+        let roles = vec![role("sales", RoleStatus::Active)];
+
+        let explanation = AccessExplanationPolicy::explain(
+            &user(UserStatus::Active),
+            &code("system:orders"),
+            &roles,
+            &grants,
+            &[system, orders],
+        );
+
+        assert!(explanation.allowed);
+        assert_eq!(explanation.decision, PermissionDecision::Allowed);
+        assert_eq!(explanation.grants.len(), 1);
+        assert_eq!(explanation.ancestors.len(), 1);
+    }
+
+    #[test]
+    fn disabled_parent_menu_and_deny_are_reported() {
+        let system = permission("p-system", "system", None, PermissionStatus::Inactive);
+        let orders = permission("p-orders", "system:orders", Some("p-system"), PermissionStatus::Active);
+        let grants =
+            vec![grant("sales", &orders, PermissionEffect::Allow), grant("auditor", &orders, PermissionEffect::Deny)];
+        let roles = vec![role("sales", RoleStatus::Active), role("auditor", RoleStatus::Active)];
+
+        let explanation = AccessExplanationPolicy::explain(
+            &user(UserStatus::Active),
+            &code("system:orders"),
+            &roles,
+            &grants,
+            &[system, orders.clone()],
+        );
+
+        assert!(!explanation.allowed);
+        assert_eq!(explanation.decision, PermissionDecision::Denied);
+        assert_eq!(
+            explanation.blockers,
+            vec![
+                AccessBlocker::DeniedByRole {
+                    role_id: RoleId::new("auditor".to_string()).unwrap(),
+                    permission_id: orders.id.clone()
+                },
+                AccessBlocker::ParentInactive { permission_id: PermissionId::new("p-system".to_string()).unwrap() },
+            ]
+        );
+    }
+
+    #[test]
+    fn allow_only_from_inactive_role_is_not_granted() {
+        let orders = permission("p-orders", "system:orders", None, PermissionStatus::Active);
+        let grants = vec![grant("sales", &orders, PermissionEffect::Allow)];
+        let roles = vec![role("sales", RoleStatus::Inactive)];
+
+        let explanation = AccessExplanationPolicy::explain(
+            &user(UserStatus::Inactive),
+            &code("system:orders"),
+            &roles,
+            &grants,
+            &[orders],
+        );
+
+        assert_eq!(explanation.decision, PermissionDecision::NotGranted);
+        assert_eq!(
+            explanation.blockers,
+            vec![
+                AccessBlocker::UserInactive,
+                AccessBlocker::RoleInactive { role_id: RoleId::new("sales".to_string()).unwrap() },
+            ]
+        );
+    }
+
+    #[test]
+    fn allow_through_disabled_wildcard_permission_is_reported() {
+        let all_orders = permission("p-all-orders", "system:orders:*", None, PermissionStatus::Inactive);
+        let list = permission("p-list", "system:orders:list", None, PermissionStatus::Active);
+        let grants = vec![grant("sales", &all_orders, PermissionEffect::Allow)];
+        let roles = vec![role("sales", RoleStatus::Active)];
+
+        let explanation = AccessExplanationPolicy::explain(
+            &user(UserStatus::Active),
+            &code("system:orders:list"),
+            &roles,
+            &grants,
+            &[all_orders, list],
+        );
+
+        assert!(!explanation.allowed);
+        assert_eq!(explanation.decision, PermissionDecision::NotGranted);
+        assert_eq!(explanation.grants.len(), 1);
+        assert_eq!(
+            explanation.blockers,
+            vec![AccessBlocker::PermissionInactive {
+                permission_id: PermissionId::new("p-all-orders".to_string()).unwrap()
+            }]
+        );
+    }
+
+    /// 有效权限列表中的权限恰好是访问说明判定为可访问的权限
+    #[test]
+    fn explanation_agrees_with_effective_permissions() {
+        let system = permission("p-system", "system", None, PermissionStatus::Active);
+        let users = permission("p-users", "system:user", Some("p-system"), PermissionStatus::Active);
+        let list = permission("p-list", "system:user:list", Some("p-users"), PermissionStatus::Active);
+        let delete = permission("p-delete", "system:user:delete", Some("p-users"), PermissionStatus::Active);
+        let all_users = permission("p-all-users", "system:user:*", Some("p-users"), PermissionStatus::Inactive);
+        let orders = permission("p-orders", "orders", None, PermissionStatus::Inactive);
+        let order_list = permission("p-order-list", "orders:list", Some("p-orders"), PermissionStatus::Active);
+        let archive = permission("p-archive", "archive", None, PermissionStatus::Deleted);
+        let archive_list = permission("p-archive-list", "archive:list", Some("p-archive"), PermissionStatus::Active);
+        let audit = permission("p-audit", "audit", None, PermissionStatus::Active);
+        let catalog = vec![system, users, list, delete, all_users, orders, order_list, archive, archive_list, audit];
+        let by_id = |id: &str| catalog.iter().find(|p| p.id.value() == id).unwrap();
+
+        let grants = vec![
+            grant("admin", by_id("p-system"), PermissionEffect::Allow),
+            grant("admin", by_id("p-users"), PermissionEffect::Allow),
+            grant("admin", by_id("p-all-users"), PermissionEffect::Allow),
+            grant("admin", by_id("p-list"), PermissionEffect::Allow),
+            grant("admin", by_id("p-order-list"), PermissionEffect::Allow),
+            grant("admin", by_id("p-archive-list"), PermissionEffect::Allow),
+            grant("auditor", by_id("p-audit"), PermissionEffect::Allow),
+            grant("locked", by_id("p-list"), PermissionEffect::Deny),
+            grant("restricted", by_id("p-users"), PermissionEffect::Deny),
+        ];
+        let role_sets = [
+            vec![role("admin", RoleStatus::Active)],
+            vec![role("admin", RoleStatus::Active), role("auditor", RoleStatus::Inactive)],
+            vec![role("admin", RoleStatus::Active), role("locked", RoleStatus::Active)],
+            vec![role("admin", RoleStatus::Active), role("locked", RoleStatus::Inactive)],
+            vec![role("admin", RoleStatus::Inactive), role("auditor", RoleStatus::Active)],
+            vec![role("admin", RoleStatus::Active), role("restricted", RoleStatus::Active)],
+        ];
+
+        for roles in &role_sets {
+            let plain_roles: Vec<Role> = roles.iter().map(|(role, _)| role.clone()).collect();
+            let effective = PermissionPolicy::effective_permissions(&catalog, &plain_roles, &grants);
+            for p in &catalog {
+                let code = p.code.clone().unwrap();
+                let explanation =
+                    AccessExplanationPolicy::explain(&user(UserStatus::Active), &code, roles, &grants, &catalog);
+                let listed = effective.iter().any(|e| e.id == p.id);
+                assert_eq!(explanation.allowed, listed, "{} with roles {:?}", code, plain_roles);
+                assert_eq!(explanation.allowed, explanation.blockers.is_empty(), "{}", code);
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::value_objects::user::user_id::UserId;
+use tradewinds_error::{AppError, AppResult};
+
+/// 超级管理员角色编码
+pub const SUPER_ADMIN_ROLE_CODE: &str = "super_admin";
+
+/// 最后管理员保护策略
+///
+/// 规则：
+/// - 系统中已有启用状态的超级管理员时，任何操作都不能使其数量降为零
+/// - 删除用户、禁用用户、撤销角色、调整用户或用户组角色均按变更后的结果判断
+/// - 当前本就没有启用的超级管理员时不做限制（避免锁死修复路径）
+pub struct AdminSafeguardPolicy;
+
+impl AdminSafeguardPolicy {
+    /// 校验变更后是否仍保留至少一名启用的超级管理员
+    ///
+    /// `active_holders` 为变更前启用的超级管理员，`losing` 为本次变更后不再是启用超级管理员的用户
+    pub fn check_remaining_super_admins(active_holders: &[UserId], losing: &[UserId]) -> AppResult<()> {
+        if active_holders.is_empty() {
+            return Ok(());
+        }
+        if active_holders.iter().all(|holder| losing.contains(holder)) {
+            return Err(AppError::Conflict(
+                "Operation would leave no active super administrator; assign the role to another active user first"
+                    .into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(id: &str) -> UserId {
+        UserId::new(id.to_string()).unwrap()
+    }
+
+    #[test]
+    fn rejects_removing_the_last_active_super_admin() {
+        let holders = vec![user("admin")];
+        let result = AdminSafeguardPolicy::check_remaining_super_admins(&holders, &[user("admin")]);
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+    }
+
+    #[test]
+    fn allows_changes_while_another_super_admin_remains() {
+        let holders = vec![user("admin"), user("backup")];
+        assert!(AdminSafeguardPolicy::check_remaining_super_admins(&holders, &[user("admin")]).is_ok());
+        assert!(AdminSafeguardPolicy::check_remaining_super_admins(&holders, &[user("other")]).is_ok());
+        assert!(
+            AdminSafeguardPolicy::check_remaining_super_admins(&holders, &[user("admin"), user("backup")]).is_err()
+        );
+    }
+
+    #[test]
+    fn no_restriction_without_active_super_admins() {
+        assert!(AdminSafeguardPolicy::check_remaining_super_admins(&[], &[user("admin")]).is_ok());
+    }
+}
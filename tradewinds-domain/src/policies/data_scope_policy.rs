@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+use crate::entities::{role::Role, user::User};
+use crate::value_objects::{
+    department::DepartmentId,
+    role::RoleId,
+    scope::{DataScope, DataScopeType},
+};
+
+/// 数据范围策略
+///
+/// 规则：
+/// - 仅启用状态的角色参与计算
+/// - 任一角色为 All 即不做限制
+/// - 其余角色的范围取并集，且主体始终可见本人数据
+pub struct DataScopePolicy;
+
+impl DataScopePolicy {
+    /// 解析主体的数据范围
+    ///
+    /// `custom_departments` 为这些角色上 Custom 范围配置的部门集合
+    pub fn resolve(principal: &User, roles: &[Role], custom_departments: &[DepartmentId]) -> DataScope {
+        let active_roles: Vec<&Role> = roles.iter().filter(|r| r.is_active()).collect();
+        if active_roles.iter().any(|r| r.data_scope.is_all()) {
+            return DataScope::All;
+        }
+
+        let mut departments = BTreeSet::new();
+        let mut department_trees = BTreeSet::new();
+        for role in active_roles {
+            match role.data_scope {
+                DataScopeType::Custom => departments.extend(custom_departments.iter().cloned()),
+                DataScopeType::Department => departments.extend(principal.department_id.clone()),
+                DataScopeType::DepartmentAndChildren => department_trees.extend(principal.department_id.clone()),
+                DataScopeType::SelfOnly | DataScopeType::All => {}
+            }
+        }
+
+        DataScope::Restricted { departments, department_trees, owner: Some(principal.id.clone()) }
+    }
+
+    /// 需要加载 Custom 部门配置的角色：仅启用且范围为 Custom 的角色
+    pub fn custom_role_ids(roles: &[Role]) -> Vec<RoleId> {
+        roles.iter().filter(|r| r.is_active() && r.data_scope == DataScopeType::Custom).map(|r| r.id.clone()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::{
+        AuthUsername, Email, Password,
+        role::{RoleCode, RoleName, RoleStatus},
+    };
+
+    fn principal(department: Option<&str>) -> User {
+        let mut user = User::create(
+            AuthUsername::new("manager".to_string()).unwrap(),
+            Email::new("manager@example.com".to_string()).unwrap(),
+            Password::new("hashed-password".to_string()).unwrap(),
+            None,
+            None,
+            None,
+        );
+        user.department_id = department.map(|d| DepartmentId::new(d.to_string()).unwrap());
+        user
+    }
+
+    fn role(code: &str, data_scope: DataScopeType, status: RoleStatus) -> Role {
+        let mut role = Role::create(
+            RoleId::new_v4(),
+            RoleCode::new(code.to_string()).unwrap(),
+            RoleName::new(code).unwrap(),
+            None,
+            status,
+            0,
+            0,
+        );
+        role.set_data_scope(data_scope);
+        role
+    }
+
+    fn dept(id: &str) -> DepartmentId {
+        DepartmentId::new(id.to_string()).unwrap()
+    }
+
+    #[test]
+    fn any_all_scope_is_unrestricted() {
+        let user = principal(Some("d-east"));
+        let roles = vec![
+            role("self", DataScopeType::SelfOnly, RoleStatus::Active),
+            role("admin", DataScopeType::All, RoleStatus::Active),
+        ];
+
+        assert_eq!(DataScopePolicy::resolve(&user, &roles, &[]), DataScope::All);
+    }
+
+    #[test]
+    fn inactive_roles_are_ignored() {
+        let user = principal(None);
+        let roles = vec![role("admin", DataScopeType::All, RoleStatus::Inactive)];
+
+        assert_eq!(DataScopePolicy::resolve(&user, &roles, &[]), DataScope::owner(user.id.clone()));
+    }
+
+    #[test]
+    fn restricted_scopes_are_merged() {
+        let user = principal(Some("d-east"));
+        let roles = vec![
+            role("regional", DataScopeType::DepartmentAndChildren, RoleStatus::Active),
+            role("auditor", DataScopeType::Custom, RoleStatus::Active),
+        ];
+
+        let scope = DataScopePolicy::resolve(&user, &roles, &[dept("d-west"), dept("d-north")]);
+
+        assert_eq!(
+            scope,
+            DataScope::Restricted {
+                departments: [dept("d-west"), dept("d-north")].into_iter().collect(),
+                department_trees: [dept("d-east")].into_iter().collect(),
+                owner: Some(user.id.clone()),
+            }
+        );
+    }
+
+    #[test]
+    fn department_scope_without_department_falls_back_to_self() {
+        let user = principal(None);
+        let roles = vec![role("staff", DataScopeType::Department, RoleStatus::Active)];
+
+        assert_eq!(DataScopePolicy::resolve(&user, &roles, &[]), DataScope::owner(user.id.clone()));
+    }
+
+    #[test]
+    fn only_active_custom_roles_load_custom_departments() {
+        let auditor = role("auditor", DataScopeType::Custom, RoleStatus::Active);
+        let roles = vec![
+            auditor.clone(),
+            role("retired", DataScopeType::Custom, RoleStatus::Inactive),
+            role("regional", DataScopeType::DepartmentAndChildren, RoleStatus::Active),
+        ];
+
+        assert_eq!(DataScopePolicy::custom_role_ids(&roles), vec![auditor.id]);
+    }
+}
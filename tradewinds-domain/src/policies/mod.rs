@@ -1 +1,15 @@
+pub mod abac_expression;
+pub mod abac_policy;
+pub mod access_explanation_policy;
+pub mod admin_safeguard_policy;
+pub mod data_scope_policy;
+pub mod permission_policy;
+pub mod separation_of_duty_policy;
 
+pub use abac_expression::{AccessContext, AttributeValue, Attributes, Expression};
+pub use abac_policy::{AbacDecision, AbacEvaluation, AbacPolicy, AccessEvaluation, PolicyOutcome};
+pub use access_explanation_policy::{AccessBlocker, AccessExplanation, AccessExplanationPolicy, GrantEvidence};
+pub use admin_safeguard_policy::{AdminSafeguardPolicy, SUPER_ADMIN_ROLE_CODE};
+pub use data_scope_policy::DataScopePolicy;
+pub use permission_policy::{PermissionDecision, PermissionGrant, PermissionPolicy};
+pub use separation_of_duty_policy::SeparationOfDutyPolicy;
@@ -0,0 +1,332 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::entities::{permission::Permission, role::Role};
+use crate::value_objects::{
+    permission::{PermissionCode, PermissionId, PermissionStatus},
+    role::RoleId,
+    role_permission::PermissionEffect,
+};
+
+/// 角色上的一条授权记录（允许或拒绝）
+///
+/// 授权通过权限ID精确命中，或通过权限码（可为通配码）按模式命中。
+/// 授权所在权限的状态随授权一并返回，未启用权限上的授权不参与判定，但仍可用于访问说明。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionGrant {
+    pub role_id: RoleId,
+    pub permission_id: PermissionId,
+    pub code: Option<PermissionCode>,
+    pub effect: PermissionEffect,
+    pub permission_status: PermissionStatus,
+}
+
+impl PermissionGrant {
+    pub fn new(
+        role_id: RoleId,
+        permission_id: PermissionId,
+        code: Option<PermissionCode>,
+        effect: PermissionEffect,
+        permission_status: PermissionStatus,
+    ) -> Self {
+        Self { role_id, permission_id, code, effect, permission_status }
+    }
+
+    /// 授权所在的权限是否启用
+    pub fn is_active(&self) -> bool {
+        self.permission_status.is_active()
+    }
+
+    /// 该授权是否覆盖给定权限
+    pub fn covers(&self, permission: &Permission) -> bool {
+        if self.permission_id == permission.id {
+            return true;
+        }
+        match (&self.code, &permission.code) {
+            (Some(pattern), Some(code)) => pattern.matches(code),
+            _ => false,
+        }
+    }
+
+    /// 该授权是否覆盖给定权限码
+    pub fn covers_code(&self, code: &PermissionCode) -> bool {
+        self.code.as_ref().is_some_and(|pattern| pattern.matches(code))
+    }
+}
+
+/// 权限判定结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionDecision {
+    /// 至少一条允许且无拒绝
+    Allowed,
+    /// 存在显式拒绝（无论是否有允许）
+    Denied,
+    /// 没有任何授权命中
+    NotGranted,
+}
+
+impl PermissionDecision {
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, PermissionDecision::Allowed)
+    }
+}
+
+/// 权限策略
+///
+/// 规则：
+/// - 通配权限码（如 `system:user:*`）覆盖该前缀下的所有权限码
+/// - 拒绝优先：任一角色的拒绝都会覆盖其他角色的允许
+/// - 未启用角色上的授权、未启用或已删除权限上的授权（允许与拒绝）都不计入
+/// - 未命中任何授权即视为未授权
+/// - 权限本身或任一上级菜单未启用时，即使被授予也不生效
+///
+/// 有效权限列表与访问说明（AccessExplanationPolicy）都经由这里的规则判定，二者结论一致。
+pub struct PermissionPolicy;
+
+impl PermissionPolicy {
+    /// 判定一组授权对某个权限码的结果
+    pub fn evaluate(grants: &[PermissionGrant], required: &PermissionCode) -> PermissionDecision {
+        Self::decide(grants.iter().filter(|g| g.covers_code(required)))
+    }
+
+    /// 判定一组授权对某个权限的结果（按ID精确匹配或按权限码模式匹配）
+    pub fn evaluate_permission(grants: &[PermissionGrant], permission: &Permission) -> PermissionDecision {
+        Self::decide(grants.iter().filter(|g| g.covers(permission)))
+    }
+
+    /// 从权限全集中计算有效权限：通配展开后剔除被拒绝、未启用或上级菜单未启用的权限
+    ///
+    /// `catalog` 须为含已删除记录的权限全集，以便判断上级菜单状态；`roles` 为授权所属的角色
+    pub fn effective_permissions(
+        catalog: &[Permission],
+        roles: &[Role],
+        grants: &[PermissionGrant],
+    ) -> Vec<Permission> {
+        let grants = Self::active_grants(roles, grants);
+        let mut seen = HashSet::new();
+        catalog
+            .iter()
+            .filter(|p| Self::evaluate_permission(&grants, p).is_allowed() && Self::is_reachable(p, catalog))
+            .filter(|p| seen.insert(p.id.clone()))
+            .cloned()
+            .collect()
+    }
+
+    /// 授权是否参与判定：所属角色与授权所在的权限均已启用
+    pub fn participates(grant: &PermissionGrant, role: &Role) -> bool {
+        grant.role_id == role.id && role.is_active() && grant.is_active()
+    }
+
+    /// 参与判定的授权，所属角色不在 `roles` 中的授权不计入
+    pub fn active_grants(roles: &[Role], grants: &[PermissionGrant]) -> Vec<PermissionGrant> {
+        grants.iter().filter(|g| roles.iter().any(|r| Self::participates(g, r))).cloned().collect()
+    }
+
+    /// 权限本身及全部上级菜单均已启用
+    pub fn is_reachable(permission: &Permission, catalog: &[Permission]) -> bool {
+        permission.status.is_active() && Self::ancestors(permission, catalog).iter().all(|p| p.status.is_active())
+    }
+
+    /// 沿 parent_id 向上收集上级菜单，由近及远；遇到环或缺失的上级时停止
+    pub fn ancestors(permission: &Permission, catalog: &[Permission]) -> Vec<Permission> {
+        let by_id: HashMap<&PermissionId, &Permission> = catalog.iter().map(|p| (&p.id, p)).collect();
+        let mut visited = HashSet::from([&permission.id]);
+        let mut chain = Vec::new();
+        let mut next = permission.parent_id.as_ref();
+        while let Some(parent_id) = next {
+            let Some(parent) = by_id.get(parent_id) else { break };
+            if !visited.insert(&parent.id) {
+                break;
+            }
+            chain.push((*parent).clone());
+            next = parent.parent_id.as_ref();
+        }
+        chain
+    }
+
+    fn decide<'a>(matched: impl Iterator<Item = &'a PermissionGrant>) -> PermissionDecision {
+        let mut decision = PermissionDecision::NotGranted;
+        for grant in matched {
+            if grant.effect.is_deny() {
+                return PermissionDecision::Denied;
+            }
+            decision = PermissionDecision::Allowed;
+        }
+        decision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::permission::{PermissionName, PermissionSort, PermissionType};
+    use crate::value_objects::role::{RoleCode, RoleName, RoleStatus};
+
+    fn permission(id: &str, code: Option<&str>) -> Permission {
+        let mut p = Permission::create(
+            PermissionName::new(id).unwrap(),
+            code.map(|c| PermissionCode::new(c).unwrap()),
+            PermissionType::Api,
+            None,
+            None,
+            None,
+            None,
+            PermissionSort::new(0).unwrap(),
+        )
+        .unwrap();
+        p.id = PermissionId::new(id.to_string()).unwrap();
+        p
+    }
+
+    fn grant(role: &str, p: &Permission, effect: PermissionEffect) -> PermissionGrant {
+        PermissionGrant::new(RoleId::new(role.to_string()).unwrap(), p.id.clone(), p.code.clone(), effect, p.status)
+    }
+
+    fn role(id: &str, status: RoleStatus) -> Role {
+        Role::create(
+            RoleId::new(id.to_string()).unwrap(),
+            RoleCode::new(id.to_string()).unwrap(),
+            RoleName::new(id.to_string()).unwrap(),
+            None,
+            status,
+            0,
+            0,
+        )
+    }
+
+    fn roles(ids: &[&str]) -> Vec<Role> {
+        ids.iter().map(|id| role(id, RoleStatus::Active)).collect()
+    }
+
+    fn code(s: &str) -> PermissionCode {
+        PermissionCode::new(s).unwrap()
+    }
+
+    #[test]
+    fn no_grants_means_not_granted() {
+        assert_eq!(PermissionPolicy::evaluate(&[], &code("system:user:list")), PermissionDecision::NotGranted);
+    }
+
+    #[test]
+    fn wildcard_allow_grants_descendants() {
+        let all_users = permission("p-users", Some("system:user:*"));
+        let grants = vec![grant("admin", &all_users, PermissionEffect::Allow)];
+
+        assert_eq!(PermissionPolicy::evaluate(&grants, &code("system:user:list")), PermissionDecision::Allowed);
+        assert_eq!(PermissionPolicy::evaluate(&grants, &code("system:user:role:assign")), PermissionDecision::Allowed);
+        assert_eq!(PermissionPolicy::evaluate(&grants, &code("system:role:list")), PermissionDecision::NotGranted);
+    }
+
+    #[test]
+    fn deny_from_another_role_overrides_allow() {
+        let all_users = permission("p-users", Some("system:user:*"));
+        let delete_user = permission("p-delete", Some("system:user:delete"));
+        let grants = vec![
+            grant("admin", &all_users, PermissionEffect::Allow),
+            grant("auditor", &delete_user, PermissionEffect::Deny),
+        ];
+
+        assert_eq!(PermissionPolicy::evaluate(&grants, &code("system:user:list")), PermissionDecision::Allowed);
+        assert_eq!(PermissionPolicy::evaluate(&grants, &code("system:user:delete")), PermissionDecision::Denied);
+    }
+
+    #[test]
+    fn wildcard_deny_overrides_concrete_allow() {
+        let list = permission("p-list", Some("system:user:list"));
+        let all_users = permission("p-users", Some("system:user:*"));
+        let grants =
+            vec![grant("admin", &list, PermissionEffect::Allow), grant("locked", &all_users, PermissionEffect::Deny)];
+
+        assert_eq!(PermissionPolicy::evaluate(&grants, &code("system:user:list")), PermissionDecision::Denied);
+    }
+
+    #[test]
+    fn effective_permissions_expand_wildcards_and_drop_denied() {
+        let all_users = permission("p-users", Some("system:user:*"));
+        let list = permission("p-list", Some("system:user:list"));
+        let delete = permission("p-delete", Some("system:user:delete"));
+        let roles = permission("p-roles", Some("system:role:list"));
+        let menu = permission("p-menu", None);
+        let grants = vec![
+            grant("admin", &all_users, PermissionEffect::Allow),
+            grant("admin", &menu, PermissionEffect::Allow),
+            grant("auditor", &delete, PermissionEffect::Deny),
+        ];
+
+        let catalog = vec![all_users, list, delete, roles, menu];
+        let effective = PermissionPolicy::effective_permissions(&catalog, &self::roles(&["admin", "auditor"]), &grants);
+        let ids: Vec<&str> = effective.iter().map(|p| p.id.value()).collect();
+
+        assert_eq!(ids, vec!["p-users", "p-list", "p-menu"]);
+    }
+
+    #[test]
+    fn grants_on_inactive_permissions_are_ignored() {
+        let mut all_users = permission("p-users", Some("system:user:*"));
+        all_users.status = PermissionStatus::Inactive;
+        let list_users = permission("p-user-list", Some("system:user:list"));
+        let delete_user = permission("p-user-delete", Some("system:user:delete"));
+        let mut all_roles = permission("p-roles", Some("system:role:*"));
+        all_roles.status = PermissionStatus::Inactive;
+        let list_roles = permission("p-role-list", Some("system:role:list"));
+        let grants = vec![
+            grant("admin", &all_users, PermissionEffect::Allow),
+            grant("admin", &list_users, PermissionEffect::Allow),
+            grant("admin", &list_roles, PermissionEffect::Allow),
+            grant("auditor", &all_roles, PermissionEffect::Deny),
+        ];
+
+        let catalog = vec![all_users, list_users, delete_user, all_roles, list_roles];
+        let effective = PermissionPolicy::effective_permissions(&catalog, &roles(&["admin", "auditor"]), &grants);
+        let ids: Vec<&str> = effective.iter().map(|p| p.id.value()).collect();
+
+        // 禁用的通配允许不再覆盖 delete，禁用的通配拒绝也不再覆盖 role:list
+        assert_eq!(ids, vec!["p-user-list", "p-role-list"]);
+    }
+
+    #[test]
+    fn grants_on_inactive_roles_are_ignored() {
+        let list = permission("p-list", Some("system:user:list"));
+        let delete = permission("p-delete", Some("system:user:delete"));
+        let grants = vec![
+            grant("admin", &list, PermissionEffect::Allow),
+            grant("admin", &delete, PermissionEffect::Allow),
+            grant("locked", &list, PermissionEffect::Deny),
+            grant("former", &delete, PermissionEffect::Allow),
+        ];
+        let roles = vec![role("admin", RoleStatus::Active), role("locked", RoleStatus::Inactive)];
+
+        let effective = PermissionPolicy::effective_permissions(&[list, delete.clone()], &roles, &grants);
+
+        // 未启用角色上的拒绝不再生效；不在角色列表中的授权不计入
+        let ids: Vec<&str> = effective.iter().map(|p| p.id.value()).collect();
+        assert_eq!(ids, vec!["p-list", "p-delete"]);
+        let without_admin = PermissionPolicy::effective_permissions(&[delete], &roles[1..], &grants);
+        assert!(without_admin.is_empty());
+    }
+
+    #[test]
+    fn permissions_under_inactive_or_deleted_menus_are_not_effective() {
+        let mut system = permission("p-system", Some("system"));
+        system.status = PermissionStatus::Deleted;
+        let mut users = permission("p-users", Some("system:user"));
+        users.parent_id = Some(system.id.clone());
+        let mut list = permission("p-list", Some("system:user:list"));
+        list.parent_id = Some(users.id.clone());
+        let mut orders = permission("p-orders", Some("orders"));
+        orders.status = PermissionStatus::Inactive;
+        let mut order_list = permission("p-order-list", Some("orders:list"));
+        order_list.parent_id = Some(orders.id.clone());
+        let report = permission("p-report", Some("report"));
+        let grants: Vec<PermissionGrant> = [&users, &list, &order_list, &report]
+            .into_iter()
+            .map(|p| grant("admin", p, PermissionEffect::Allow))
+            .collect();
+
+        let catalog = vec![system, users, list, orders, order_list, report];
+        let effective = PermissionPolicy::effective_permissions(&catalog, &roles(&["admin"]), &grants);
+
+        let ids: Vec<&str> = effective.iter().map(|p| p.id.value()).collect();
+        assert_eq!(ids, vec!["p-report"]);
+    }
+}
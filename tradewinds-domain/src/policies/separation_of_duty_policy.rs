@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use crate::entities::sod_rule::SodRule;
+use crate::value_objects::{role::RoleId, sod::SodRuleType};
+use tradewinds_error::{AppError, AppResult};
+
+/// 职责分离策略
+///
+/// 规则：
+/// - 仅启用的规则参与校验
+/// - 互斥规则：同一用户持有规则角色集合中的角色数不得超过 `max_count`
+/// - 每人角色上限：同一用户的有效角色数不得超过 `max_count`
+/// - 每角色人数上限：规则中每个角色的有效持有人数不得超过 `max_count`
+///
+/// 校验对象均为有效角色（直接分配与用户组携带的角色合并去重）。
+pub struct SeparationOfDutyPolicy;
+
+impl SeparationOfDutyPolicy {
+    /// 校验单个用户的有效角色集合
+    pub fn check_user_roles(rules: &[SodRule], role_ids: &[RoleId]) -> AppResult<()> {
+        let held: HashSet<&RoleId> = role_ids.iter().collect();
+        for rule in rules.iter().filter(|r| r.enabled) {
+            let count = match rule.rule_type {
+                SodRuleType::MutuallyExclusive => rule.role_ids.iter().filter(|r| held.contains(r)).count(),
+                SodRuleType::MaxRolesPerUser => held.len(),
+                SodRuleType::MaxUsersPerRole => continue,
+            };
+            if count > rule.max_count as usize {
+                return Err(AppError::Conflict(format!(
+                    "Separation of duties rule '{}' violated: a user may hold at most {} of its roles, got {}",
+                    rule.name, rule.max_count, count
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// 校验角色的有效持有人数
+    pub fn check_role_holders(rules: &[SodRule], role_id: &RoleId, holders: usize) -> AppResult<()> {
+        for rule in rules.iter().filter(|r| r.enabled && r.rule_type == SodRuleType::MaxUsersPerRole) {
+            if rule.covers(role_id) && holders > rule.max_count as usize {
+                return Err(AppError::Conflict(format!(
+                    "Separation of duties rule '{}' violated: role {} may be held by at most {} users, got {}",
+                    rule.name, role_id, rule.max_count, holders
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::sod::{SodRuleId, SodRuleName};
+
+    fn rule(rule_type: SodRuleType, role_ids: &[&RoleId], max_count: u32) -> SodRule {
+        SodRule {
+            id: SodRuleId::new_v4(),
+            name: SodRuleName::new("payments").unwrap(),
+            rule_type,
+            role_ids: role_ids.iter().map(|r| (*r).clone()).collect(),
+            max_count,
+            enabled: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn mutually_exclusive_roles_cannot_be_combined() {
+        let (creator, approver, viewer) = (RoleId::new_v4(), RoleId::new_v4(), RoleId::new_v4());
+        let rules = vec![rule(SodRuleType::MutuallyExclusive, &[&creator, &approver], 1)];
+
+        assert!(SeparationOfDutyPolicy::check_user_roles(&rules, &[creator.clone(), viewer]).is_ok());
+        let err = SeparationOfDutyPolicy::check_user_roles(&rules, &[creator, approver]).unwrap_err();
+        assert!(matches!(err, AppError::Conflict(msg) if msg.contains("payments")));
+    }
+
+    #[test]
+    fn disabled_rules_are_ignored() {
+        let (creator, approver) = (RoleId::new_v4(), RoleId::new_v4());
+        let mut exclusive = rule(SodRuleType::MutuallyExclusive, &[&creator, &approver], 1);
+        exclusive.enabled = false;
+
+        assert!(SeparationOfDutyPolicy::check_user_roles(&[exclusive], &[creator, approver]).is_ok());
+    }
+
+    #[test]
+    fn max_roles_per_user_counts_distinct_roles() {
+        let (a, b) = (RoleId::new_v4(), RoleId::new_v4());
+        let rules = vec![rule(SodRuleType::MaxRolesPerUser, &[], 1)];
+
+        assert!(SeparationOfDutyPolicy::check_user_roles(&rules, &[a.clone(), a.clone()]).is_ok());
+        assert!(SeparationOfDutyPolicy::check_user_roles(&rules, &[a, b]).is_err());
+    }
+
+    #[test]
+    fn max_users_per_role_only_applies_to_listed_roles() {
+        let (admin, staff) = (RoleId::new_v4(), RoleId::new_v4());
+        let rules = vec![rule(SodRuleType::MaxUsersPerRole, &[&admin], 2)];
+
+        assert!(SeparationOfDutyPolicy::check_role_holders(&rules, &admin, 2).is_ok());
+        assert!(SeparationOfDutyPolicy::check_role_holders(&rules, &admin, 3).is_err());
+        assert!(SeparationOfDutyPolicy::check_role_holders(&rules, &staff, 10).is_ok());
+    }
+}
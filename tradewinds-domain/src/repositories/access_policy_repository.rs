@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::entities::access_policy::AccessPolicy;
+use crate::value_objects::policy::{AccessPolicyId, AccessPolicyName, AccessPolicyStatus};
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait AccessPolicyRepository: Send + Sync {
+    async fn create(&self, policy: &AccessPolicy) -> AppResult<()>;
+
+    async fn save(&self, policy: &AccessPolicy) -> AppResult<()>;
+
+    async fn find_by_id(&self, id: &AccessPolicyId) -> AppResult<Option<AccessPolicy>>;
+
+    async fn find_by_name(&self, name: &AccessPolicyName) -> AppResult<Option<AccessPolicy>>;
+
+    /// 查询全部启用的访问策略
+    async fn find_active(&self) -> AppResult<Vec<AccessPolicy>>;
+
+    /// 分页查询，未指定状态时排除已删除策略
+    async fn search(
+        &self,
+        keyword: Option<&str>,
+        status: Option<AccessPolicyStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessPolicy>, u64)>;
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::aggregates::access_request_aggregate::AccessRequestAggregate;
+use crate::entities::access_request::AccessRequest;
+use crate::value_objects::access_request::{AccessRequestId, AccessRequestStatus};
+use crate::value_objects::{role::RoleId, user::UserId};
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait AccessRequestRepository: Send + Sync {
+    /// 保存申请，聚合记录的事件在同一事务内写入发件箱
+    async fn create(&self, aggregate: &AccessRequestAggregate) -> AppResult<()>;
+
+    async fn save(&self, aggregate: &AccessRequestAggregate) -> AppResult<()>;
+
+    async fn find_by_id(&self, id: &AccessRequestId) -> AppResult<Option<AccessRequest>>;
+
+    /// 查询用户对某角色尚在审批中的申请
+    async fn find_pending(&self, requester_id: &UserId, role_id: &RoleId) -> AppResult<Option<AccessRequest>>;
+
+    /// 查询在 `now` 时应过期的申请：待审批超时，或已批准的授权到期
+    async fn find_due_for_expiry(&self, now: i64) -> AppResult<Vec<AccessRequest>>;
+
+    /// 分页查询，`role_ids` 为空切片时不返回任何申请
+    async fn search(
+        &self,
+        requester_id: Option<&UserId>,
+        role_ids: Option<&[RoleId]>,
+        status: Option<AccessRequestStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessRequest>, u64)>;
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::aggregates::access_review_aggregate::AccessReviewAggregate;
+use crate::value_objects::access_review::{AccessReviewId, AccessReviewStatus};
+use crate::value_objects::user::UserId;
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait AccessReviewRepository: Send + Sync {
+    async fn create(&self, aggregate: &AccessReviewAggregate) -> AppResult<()>;
+
+    /// 保存活动状态与各复核项结论
+    async fn save(&self, aggregate: &AccessReviewAggregate) -> AppResult<()>;
+
+    async fn find_by_id(&self, id: &AccessReviewId) -> AppResult<Option<AccessReviewAggregate>>;
+
+    /// 查询在 `now` 时已到截止时间但尚未完成的活动
+    async fn find_due(&self, now: i64) -> AppResult<Vec<AccessReviewAggregate>>;
+
+    /// 分页查询活动，指定 `reviewer_id` 时仅返回其参与复核的活动
+    async fn search(
+        &self,
+        reviewer_id: Option<&UserId>,
+        status: Option<AccessReviewStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessReviewAggregate>, u64)>;
+}
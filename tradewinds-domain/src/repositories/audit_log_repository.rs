@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::{audit_checkpoint::AuditCheckpoint, audit_log::AuditLog};
+use crate::value_objects::{audit::AuditOutcome, user::UserId};
+use tradewinds_error::AppResult;
+
+/// 审计日志查询条件，各条件为空时不限制
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditLogFilter {
+    pub actor_id: Option<UserId>,
+    /// 操作名称前缀，如 `user.` 匹配全部用户操作
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub outcome: Option<AuditOutcome>,
+    /// 起始时间（含）
+    pub from: Option<i64>,
+    /// 截止时间（不含）
+    pub to: Option<i64>,
+}
+
+#[async_trait]
+pub trait AuditLogRepository: Send + Sync {
+    /// 追加到当前租户哈希链的链尾，返回分配了序号与哈希的记录
+    async fn append(&self, log: AuditLog) -> AppResult<AuditLog>;
+
+    /// 按时间倒序分页查询
+    async fn search(&self, filter: &AuditLogFilter, limit: u64, offset: u64) -> AppResult<(Vec<AuditLog>, u64)>;
+
+    /// 按序号升序读取序号大于 `after_seq` 的记录，用于逐段校验哈希链
+    async fn find_chain(&self, after_seq: u64, limit: u64) -> AppResult<Vec<AuditLog>>;
+
+    async fn create_checkpoint(&self, checkpoint: &AuditCheckpoint) -> AppResult<()>;
+
+    async fn find_checkpoints(&self) -> AppResult<Vec<AuditCheckpoint>>;
+
+    /// 存在审计日志的全部租户（不限于当前租户）
+    async fn find_tenant_ids(&self) -> AppResult<Vec<String>>;
+}
@@ -0,0 +1,11 @@
+use crate::aggregates::department_aggregate::DepartmentAggregate;
+use crate::value_objects::department::DepartmentId;
+use async_trait::async_trait;
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait DepartmentAggregateRepository: Send + Sync {
+    async fn create(&self, aggregate: &DepartmentAggregate) -> AppResult<()>;
+    async fn save(&self, aggregate: &DepartmentAggregate) -> AppResult<()>;
+    async fn find_by_id(&self, id: &DepartmentId) -> AppResult<Option<DepartmentAggregate>>;
+}
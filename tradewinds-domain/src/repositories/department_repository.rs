@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use tradewinds_error::AppResult;
+
+use crate::entities::department::Department;
+use crate::value_objects::department::{DepartmentId, DepartmentName, DepartmentStatus};
+
+#[async_trait]
+pub trait DepartmentRepository: Send + Sync {
+    async fn find_by_id(&self, id: &DepartmentId) -> AppResult<Option<Department>>;
+    async fn find_by_ids(&self, ids: &[DepartmentId]) -> AppResult<Vec<Department>>;
+    /// 在同一上级部门下按名称查找未删除的部门
+    async fn find_by_name(
+        &self,
+        parent_id: Option<&DepartmentId>,
+        name: &DepartmentName,
+    ) -> AppResult<Option<Department>>;
+    /// 查询部门的全部后代部门ID（不含自身，不含已删除部门）
+    async fn find_descendant_ids(&self, id: &DepartmentId) -> AppResult<Vec<DepartmentId>>;
+    async fn count_children(&self, id: &DepartmentId) -> AppResult<u64>;
+    async fn search(
+        &self,
+        name: Option<&DepartmentName>,
+        parent_id: Option<&DepartmentId>,
+        status: Option<DepartmentStatus>,
+        show_deleted: Option<bool>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Department>, u64)>;
+    /// 查询所有未删除的部门
+    async fn find_all(&self) -> AppResult<Vec<Department>>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::entities::feature_flag::FeatureFlag;
+use tradewinds_error::AppResult;
+
+/// 功能开关仓储
+///
+/// 功能开关为平台级数据，不受当前租户范围限制
+#[async_trait]
+pub trait FeatureFlagRepository: Send + Sync {
+    async fn create(&self, flag: &FeatureFlag) -> AppResult<()>;
+
+    async fn update(&self, flag: &FeatureFlag) -> AppResult<()>;
+
+    async fn delete(&self, key: &str) -> AppResult<()>;
+
+    async fn find_by_key(&self, key: &str) -> AppResult<Option<FeatureFlag>>;
+
+    /// 按键排序的全部开关
+    async fn find_all(&self) -> AppResult<Vec<FeatureFlag>>;
+}
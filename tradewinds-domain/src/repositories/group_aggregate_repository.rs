@@ -0,0 +1,11 @@
+use crate::aggregates::group_aggregate::GroupAggregate;
+use crate::value_objects::group::GroupId;
+use async_trait::async_trait;
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait GroupAggregateRepository: Send + Sync {
+    async fn create(&self, aggregate: &GroupAggregate) -> AppResult<()>;
+    async fn save(&self, aggregate: &GroupAggregate) -> AppResult<()>;
+    async fn find_by_id(&self, id: &GroupId) -> AppResult<Option<GroupAggregate>>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use tradewinds_error::AppResult;
+
+use crate::entities::group::Group;
+use crate::value_objects::group::{GroupId, GroupName, GroupStatus};
+use crate::value_objects::user::UserId;
+
+#[async_trait]
+pub trait GroupRepository: Send + Sync {
+    async fn find_by_id(&self, id: &GroupId) -> AppResult<Option<Group>>;
+    /// 按名称查找未删除的用户组
+    async fn find_by_name(&self, name: &GroupName) -> AppResult<Option<Group>>;
+    /// 查询用户所在的全部未删除用户组
+    async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<Group>>;
+    async fn search(
+        &self,
+        name: Option<&GroupName>,
+        status: Option<GroupStatus>,
+        show_deleted: Option<bool>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Group>, u64)>;
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::job_run::JobRun;
+use crate::value_objects::job::JobRunStatus;
+use tradewinds_error::AppResult;
+
+/// 执行记录查询条件，各条件为空时不限制
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobRunFilter {
+    pub job_name: Option<String>,
+    pub status: Option<JobRunStatus>,
+}
+
+/// 定时任务执行记录仓储，平台级数据
+#[async_trait]
+pub trait JobRunRepository: Send + Sync {
+    async fn create(&self, run: &JobRun) -> AppResult<()>;
+
+    /// 更新执行结果
+    async fn update(&self, run: &JobRun) -> AppResult<()>;
+
+    /// 按开始时间倒序分页查询
+    async fn search(&self, filter: &JobRunFilter, limit: u64, offset: u64) -> AppResult<(Vec<JobRun>, u64)>;
+
+    /// 删除开始时间早于 `before` 的记录，返回删除条数
+    async fn delete_before(&self, before: i64) -> AppResult<u64>;
+}
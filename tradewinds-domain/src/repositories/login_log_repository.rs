@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::login_log::LoginLog;
+use crate::value_objects::user::UserId;
+use tradewinds_error::AppResult;
+
+/// 登录日志查询条件，各条件为空时不限制
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LoginLogFilter {
+    pub user_id: Option<UserId>,
+    /// 登录时提交的用户名，精确匹配
+    pub username: Option<String>,
+    pub success: Option<bool>,
+    pub client_ip: Option<String>,
+    /// 起始时间（含）
+    pub from: Option<i64>,
+    /// 截止时间（不含）
+    pub to: Option<i64>,
+}
+
+#[async_trait]
+pub trait LoginLogRepository: Send + Sync {
+    async fn create(&self, log: &LoginLog) -> AppResult<()>;
+
+    /// 按时间倒序分页查询
+    async fn search(&self, filter: &LoginLogFilter, limit: u64, offset: u64) -> AppResult<(Vec<LoginLog>, u64)>;
+
+    /// 删除早于给定时间的记录，返回删除条数
+    async fn delete_before(&self, before: i64) -> AppResult<u64>;
+}
@@ -1,21 +1,57 @@
+pub mod access_policy_repository;
+pub mod access_request_repository;
+pub mod access_review_repository;
+pub mod audit_log_repository;
+pub mod department_aggregate_repository;
+pub mod department_repository;
+pub mod feature_flag_repository;
+pub mod group_aggregate_repository;
+pub mod group_repository;
+pub mod job_run_repository;
+pub mod login_log_repository;
+pub mod outbox_repository;
 pub mod permission_aggregate_repository;
 pub mod permission_repository;
 pub mod role_aggregate_repository;
+pub mod role_approver_repository;
 pub mod role_permission_repository;
 pub mod role_repository;
+pub mod scheduled_job_repository;
+pub mod sod_rule_repository;
 pub mod system_setting_repository;
+pub mod tenant_repository;
 pub mod token_blacklist_repository;
 pub mod user_aggregate_repository;
 pub mod user_repository;
 pub mod user_role_repository;
+pub mod webhook_delivery_repository;
+pub mod webhook_repository;
 
+pub use access_policy_repository::AccessPolicyRepository;
+pub use access_request_repository::AccessRequestRepository;
+pub use access_review_repository::AccessReviewRepository;
+pub use audit_log_repository::{AuditLogFilter, AuditLogRepository};
+pub use department_aggregate_repository::DepartmentAggregateRepository;
+pub use department_repository::DepartmentRepository;
+pub use feature_flag_repository::FeatureFlagRepository;
+pub use group_aggregate_repository::GroupAggregateRepository;
+pub use group_repository::GroupRepository;
+pub use job_run_repository::{JobRunFilter, JobRunRepository};
+pub use login_log_repository::{LoginLogFilter, LoginLogRepository};
+pub use outbox_repository::{OutboxFilter, OutboxRepository};
 pub use permission_aggregate_repository::PermissionAggregateRepository;
 pub use permission_repository::PermissionRepository;
 pub use role_aggregate_repository::RoleAggregateRepository;
+pub use role_approver_repository::RoleApproverRepository;
 pub use role_permission_repository::RolePermissionRepository;
 pub use role_repository::RoleRepository;
+pub use scheduled_job_repository::ScheduledJobRepository;
+pub use sod_rule_repository::SodRuleRepository;
 pub use system_setting_repository::SystemSettingRepository;
+pub use tenant_repository::TenantRepository;
 pub use token_blacklist_repository::TokenBlacklistRepository;
 pub use user_aggregate_repository::UserAggregateRepository;
-pub use user_repository::UserRepository;
+pub use user_repository::{UserRepository, UserSearchFilter};
 pub use user_role_repository::UserRoleRepository;
+pub use webhook_delivery_repository::{WebhookDeliveryFilter, WebhookDeliveryRepository};
+pub use webhook_repository::WebhookRepository;
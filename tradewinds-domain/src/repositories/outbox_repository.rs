@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::outbox_message::OutboxMessage;
+use crate::value_objects::outbox::{OutboxMessageId, OutboxStatus};
+use tradewinds_error::AppResult;
+
+/// 发件箱消息查询条件，各条件为空时不限制
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutboxFilter {
+    pub status: Option<OutboxStatus>,
+    pub event_type: Option<String>,
+    pub aggregate_id: Option<String>,
+    /// 仅查询滞留消息：死信，以及早于该时间写入仍未投递的消息
+    pub stuck_before: Option<i64>,
+}
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    /// 有待投递消息的租户
+    async fn find_tenant_ids_with_pending(&self) -> AppResult<Vec<String>>;
+
+    /// 认领当前租户到期的待投递消息，按写入顺序排列
+    ///
+    /// 每个聚合只取最早一条待投递消息，前一条未投递成功时不会越过它认领后续消息；
+    /// 认领以条件更新完成并设置 `lease_secs` 秒的租约，多个中继实例并发认领时每条消息只归属一个实例
+    async fn claim_due(&self, now: i64, lease_secs: i64, limit: u64) -> AppResult<Vec<OutboxMessage>>;
+
+    /// 写入不伴随聚合变更的事件消息（如登出）
+    async fn append(&self, messages: &[OutboxMessage]) -> AppResult<()>;
+
+    async fn find_by_id(&self, id: &OutboxMessageId) -> AppResult<Option<OutboxMessage>>;
+
+    /// 更新投递状态
+    async fn update(&self, message: &OutboxMessage) -> AppResult<()>;
+
+    /// 按写入时间倒序分页查询
+    async fn search(&self, filter: &OutboxFilter, limit: u64, offset: u64) -> AppResult<(Vec<OutboxMessage>, u64)>;
+
+    /// 各状态的消息数量
+    async fn count_by_status(&self) -> AppResult<Vec<(OutboxStatus, u64)>>;
+
+    /// 最早一条待投递消息的写入时间
+    async fn oldest_pending_at(&self) -> AppResult<Option<i64>>;
+}
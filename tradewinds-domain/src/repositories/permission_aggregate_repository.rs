@@ -8,5 +8,5 @@ pub trait PermissionAggregateRepository: Send + Sync {
     async fn create(&self, aggregate: &PermissionAggregate) -> AppResult<()>;
     async fn save(&self, aggregate: &PermissionAggregate) -> AppResult<()>;
     async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<PermissionAggregate>>;
-    async fn delete_by_id(&self, id: &PermissionId) -> AppResult<()>;
+    async fn delete(&self, aggregate: &PermissionAggregate) -> AppResult<()>;
 }
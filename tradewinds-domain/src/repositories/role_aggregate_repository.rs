@@ -8,5 +8,5 @@ pub trait RoleAggregateRepository: Send + Sync {
     async fn create(&self, aggregate: &RoleAggregate) -> AppResult<()>;
     async fn save(&self, aggregate: &RoleAggregate) -> AppResult<()>;
     async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<RoleAggregate>>;
-    async fn delete_by_id(&self, id: &RoleId) -> AppResult<()>;
+    async fn delete(&self, aggregate: &RoleAggregate) -> AppResult<()>;
 }
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::value_objects::{role::RoleId, user::UserId};
+use tradewinds_error::AppResult;
+
+/// 角色审批人仓储，审批人负责处理该角色的权限申请
+#[async_trait]
+pub trait RoleApproverRepository: Send + Sync {
+    async fn find_approver_ids(&self, role_id: &RoleId) -> AppResult<Vec<UserId>>;
+
+    /// 查询用户担任审批人的角色
+    async fn find_role_ids_by_approver(&self, user_id: &UserId) -> AppResult<Vec<RoleId>>;
+
+    /// 整体替换角色的审批人
+    async fn replace(&self, role_id: &RoleId, approver_ids: &[UserId]) -> AppResult<()>;
+}
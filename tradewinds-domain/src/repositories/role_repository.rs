@@ -2,20 +2,27 @@ use async_trait::async_trait;
 
 use crate::entities::permission::Permission;
 use crate::entities::role::Role;
+use crate::policies::PermissionGrant;
+use crate::value_objects::department::DepartmentId;
 use crate::value_objects::permission::PermissionId;
-use crate::value_objects::role::{RoleId, RoleName};
+use crate::value_objects::role::{RoleCode, RoleId, RoleName};
 use tradewinds_error::AppResult;
 
 #[async_trait]
 pub trait RoleRepository: Send + Sync {
     async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<Role>>;
     async fn find_by_name(&self, name: &RoleName) -> AppResult<Option<Role>>;
+    async fn find_by_code(&self, code: &RoleCode) -> AppResult<Option<Role>>;
     async fn find_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Role>>;
     async fn exists_by_id(&self, id: &RoleId) -> AppResult<bool>;
 
     async fn find_with_permissions(&self, id: &RoleId) -> AppResult<Option<(Role, Vec<PermissionId>)>>;
     async fn find_permissions(&self, id: &RoleId) -> AppResult<Vec<Permission>>;
     async fn find_permissions_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Permission>>;
+    /// 查询角色上启用权限的全部授权（含允许与拒绝），用于通配与拒绝优先的权限计算；禁用或已删除的权限不返回
+    async fn find_permission_grants(&self, ids: &[RoleId]) -> AppResult<Vec<PermissionGrant>>;
+    /// 查询角色上自定义数据范围的部门集合（并集）
+    async fn find_data_scope_departments(&self, ids: &[RoleId]) -> AppResult<Vec<DepartmentId>>;
     async fn search(
         &self,
         name: Option<&RoleName>,
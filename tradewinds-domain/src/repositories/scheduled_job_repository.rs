@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+use crate::entities::job_run::JobRun;
+use crate::entities::scheduled_job::ScheduledJob;
+use tradewinds_error::AppResult;
+
+/// 定时任务仓储
+///
+/// 定时任务为平台级数据，不受当前租户范围限制
+#[async_trait]
+pub trait ScheduledJobRepository: Send + Sync {
+    /// 按名称新增或整体更新
+    async fn save(&self, job: &ScheduledJob) -> AppResult<()>;
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<ScheduledJob>>;
+
+    /// 按名称排序的全部任务
+    async fn find_all(&self) -> AppResult<Vec<ScheduledJob>>;
+
+    /// 只更新下次执行时间，不覆盖并发修改的暂停状态
+    async fn set_next_run_at(&self, name: &str, next_run_at: Option<i64>) -> AppResult<()>;
+
+    /// 只更新最近一次执行的时间与状态
+    async fn record_run(&self, run: &JobRun) -> AppResult<()>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::entities::sod_rule::SodRule;
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait SodRuleRepository: Send + Sync {
+    /// 查询全部启用的职责分离规则
+    async fn find_enabled(&self) -> AppResult<Vec<SodRule>>;
+}
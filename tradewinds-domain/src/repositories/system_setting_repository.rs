@@ -1,4 +1,4 @@
-use crate::entities::system_setting::SystemSetting;
+use crate::entities::{outbox_message::OutboxMessage, system_setting::SystemSetting};
 use crate::value_objects::system_setting::{SystemSettingKey, SystemSettingValue};
 use async_trait::async_trait;
 use tradewinds_error::AppResult;
@@ -6,5 +6,13 @@ use tradewinds_error::AppResult;
 #[async_trait]
 pub trait SystemSettingRepository: Send + Sync {
     async fn get_by_key(&self, key: &SystemSettingKey) -> AppResult<Option<SystemSetting>>;
-    async fn set_value(&self, key: &SystemSettingKey, value: &SystemSettingValue) -> AppResult<()>;
+    /// 当前租户保存过的全部设置，租户未单独设置的取平台默认租户的值
+    async fn find_all(&self) -> AppResult<Vec<SystemSetting>>;
+    /// 保存设置值，`events` 在同一事务内写入发件箱
+    async fn set_value(
+        &self,
+        key: &SystemSettingKey,
+        value: &SystemSettingValue,
+        events: &[OutboxMessage],
+    ) -> AppResult<()>;
 }
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use tradewinds_error::AppResult;
+
+use crate::entities::{outbox_message::OutboxMessage, tenant::Tenant};
+use crate::value_objects::tenant::{TenantCode, TenantHost, TenantId, TenantStatus};
+
+/// 租户仓储
+///
+/// 租户表本身是平台级数据，不受当前租户范围限制；`events` 与租户在同一事务内写入发件箱
+#[async_trait]
+pub trait TenantRepository: Send + Sync {
+    async fn create(&self, tenant: &Tenant, events: &[OutboxMessage]) -> AppResult<()>;
+    async fn save(&self, tenant: &Tenant, events: &[OutboxMessage]) -> AppResult<()>;
+    async fn find_by_id(&self, id: &TenantId) -> AppResult<Option<Tenant>>;
+    async fn find_by_code(&self, code: &TenantCode) -> AppResult<Option<Tenant>>;
+    async fn find_by_host(&self, host: &TenantHost) -> AppResult<Option<Tenant>>;
+    /// 全部未删除租户的ID，含已停用租户
+    async fn find_all_ids(&self) -> AppResult<Vec<TenantId>>;
+    async fn search(
+        &self,
+        keyword: Option<&str>,
+        status: Option<TenantStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Tenant>, u64)>;
+}
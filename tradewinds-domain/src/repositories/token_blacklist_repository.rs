@@ -6,5 +6,6 @@ use tradewinds_error::AppResult;
 pub trait TokenBlacklistRepository: Send + Sync {
     async fn add(&self, token: &Token, user_id: &UserId, expires_at: i64) -> AppResult<()>;
     async fn is_blacklisted(&self, token: &Token) -> AppResult<bool>;
-    async fn cleanup(&self) -> AppResult<()>;
+    /// 删除已过期的记录，返回删除条数
+    async fn cleanup(&self) -> AppResult<u64>;
 }
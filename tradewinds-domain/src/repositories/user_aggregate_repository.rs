@@ -6,7 +6,10 @@ use tradewinds_error::AppResult;
 #[async_trait]
 pub trait UserAggregateRepository: Send + Sync {
     async fn find_by_id(&self, user_id: &UserId) -> AppResult<Option<UserAggregate>>;
+    /// 保存聚合，聚合记录的事件在同一事务内写入发件箱
     async fn save(&self, aggregate: &UserAggregate) -> AppResult<()>;
+    /// 创建聚合，聚合记录的事件在同一事务内写入发件箱
     async fn create(&self, aggregate: &UserAggregate) -> AppResult<()>;
-    async fn delete_by_id(&self, id: &UserId) -> AppResult<()>;
+    /// 删除聚合，聚合记录的事件在同一事务内写入发件箱
+    async fn delete(&self, aggregate: &UserAggregate) -> AppResult<()>;
 }
@@ -1,12 +1,36 @@
 use async_trait::async_trait;
 
-use crate::entities::user::User;
+use crate::entities::{outbox_message::OutboxMessage, user::User};
 use crate::value_objects::{
     auth::auth_username::AuthUsername,
+    department::DepartmentId,
+    scope::DataScope,
     user::{user_email::Email, user_id::UserId, user_status::UserStatus},
 };
 use tradewinds_error::AppResult;
 
+/// 用户查询条件，各条件为空时不限制
+#[derive(Debug, Clone, Default)]
+pub struct UserSearchFilter {
+    /// 当前主体的数据范围
+    pub data_scope: DataScope,
+    /// 部门筛选，包含其子部门
+    pub department_tree: Option<DepartmentId>,
+    /// 用户名，模糊匹配
+    pub username: Option<AuthUsername>,
+    /// 手机号，模糊匹配
+    pub phone: Option<String>,
+    /// 邮箱，模糊匹配
+    pub email: Option<Email>,
+    pub status: Option<UserStatus>,
+    /// 未指定状态时是否包含已删除用户
+    pub show_deleted: Option<bool>,
+    /// 创建时间不早于该时间戳（秒）
+    pub created_since: Option<i64>,
+    /// 最近登录时间不早于该时间戳（秒）
+    pub last_login_since: Option<i64>,
+}
+
 #[async_trait]
 pub trait UserRepository: Send + Sync {
     async fn find_by_id(&self, id: &UserId) -> AppResult<Option<User>>;
@@ -14,19 +38,19 @@ pub trait UserRepository: Send + Sync {
     async fn find_by_username(&self, username: &AuthUsername) -> AppResult<Option<User>>;
     async fn find_by_ids(&self, ids: &[UserId]) -> AppResult<Vec<User>>;
 
+    /// 仅更新最近登录时间与IP，`events` 在同一事务内写入发件箱
+    async fn update_last_login(&self, user: &User, events: &[OutboxMessage]) -> AppResult<()>;
+
     async fn exists_by_username(&self, username: &AuthUsername) -> AppResult<bool>;
     async fn exists_by_email(&self, email: &Email) -> AppResult<bool>;
 
     async fn count(&self) -> AppResult<u64>;
+    /// 统计归属于给定部门的未删除用户数
+    async fn count_by_departments(&self, department_ids: &[DepartmentId]) -> AppResult<u64>;
+
+    /// 用户是否落在数据范围内，规则与 `search` 的数据范围过滤一致
+    async fn is_visible(&self, data_scope: &DataScope, id: &UserId) -> AppResult<bool>;
 
-    async fn search(
-        &self,
-        username: Option<&AuthUsername>,
-        phone: Option<&str>,
-        email: Option<&Email>,
-        status: Option<UserStatus>,
-        show_deleted: Option<bool>,
-        limit: u64,
-        offset: u64,
-    ) -> AppResult<(Vec<User>, u64)>;
+    /// 按条件分页查询用户
+    async fn search(&self, filter: &UserSearchFilter, limit: u64, offset: u64) -> AppResult<(Vec<User>, u64)>;
 }
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
 use crate::entities::{user::User, user_role::UserRole};
-use crate::value_objects::role::RoleId;
+use crate::value_objects::role::{RoleAssignment, RoleId};
 use crate::value_objects::user::UserId;
 use tradewinds_error::AppResult;
 
@@ -10,6 +10,11 @@ pub trait UserRoleRepository: Send + Sync {
     async fn create(&self, user_role: &UserRole) -> AppResult<()>;
     async fn delete(&self, user_id: &UserId, role_id: &RoleId) -> AppResult<()>;
     async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<UserRole>>;
+    /// 查询用户的全部角色分配：直接角色，以及所在启用用户组的角色
+    async fn find_assignments_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<RoleAssignment>>;
+    /// 查询有效持有该角色的用户：直接分配，或经由启用用户组获得
+    async fn find_holder_ids_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<UserId>>;
+    /// 查询直接分配该角色的用户（不含经由用户组获得者），已删除的用户除外
     async fn find_users_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<User>>;
     async fn exists(&self, user_id: &UserId, role_id: &RoleId) -> AppResult<bool>;
 }
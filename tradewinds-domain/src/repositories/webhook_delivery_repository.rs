@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::entities::webhook_delivery::WebhookDelivery;
+use crate::value_objects::webhook::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookId};
+use tradewinds_error::AppResult;
+
+/// 投递记录查询条件，各条件为空时不限制
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WebhookDeliveryFilter {
+    pub webhook_id: Option<WebhookId>,
+    pub status: Option<WebhookDeliveryStatus>,
+    pub event_type: Option<String>,
+}
+
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn create(&self, delivery: &WebhookDelivery) -> AppResult<()>;
+
+    /// 更新投递状态
+    async fn update(&self, delivery: &WebhookDelivery) -> AppResult<()>;
+
+    async fn find_by_id(&self, id: &WebhookDeliveryId) -> AppResult<Option<WebhookDelivery>>;
+
+    /// 有到期待投递记录的租户
+    async fn find_tenant_ids_with_due(&self, now: i64) -> AppResult<Vec<String>>;
+
+    /// 认领当前租户到期待投递的记录，按写入顺序排列
+    ///
+    /// 认领以条件更新完成并设置 `lease_secs` 秒的租约，多个投递任务实例并发认领时每条记录只归属一个实例
+    async fn claim_due(&self, now: i64, lease_secs: i64, limit: u64) -> AppResult<Vec<WebhookDelivery>>;
+
+    /// 按写入时间倒序分页查询
+    async fn search(
+        &self,
+        filter: &WebhookDeliveryFilter,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<WebhookDelivery>, u64)>;
+}
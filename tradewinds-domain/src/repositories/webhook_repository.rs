@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::entities::webhook::Webhook;
+use crate::value_objects::webhook::WebhookId;
+use tradewinds_error::AppResult;
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create(&self, webhook: &Webhook) -> AppResult<()>;
+
+    async fn update(&self, webhook: &Webhook) -> AppResult<()>;
+
+    async fn delete(&self, id: &WebhookId) -> AppResult<()>;
+
+    async fn find_by_id(&self, id: &WebhookId) -> AppResult<Option<Webhook>>;
+
+    /// 当前租户的全部端点，按创建时间排列
+    async fn find_all(&self) -> AppResult<Vec<Webhook>>;
+}
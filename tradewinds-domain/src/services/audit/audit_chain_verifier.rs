@@ -0,0 +1,238 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::entities::{audit_checkpoint::AuditCheckpoint, audit_log::AuditLog};
+use crate::services::audit::AuditSigner;
+
+/// 哈希链断开的原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuditChainBreakReason {
+    /// 序号不连续：记录被删除或插入
+    SequenceGap { expected: u64 },
+    /// 前一哈希与链上前一条记录不符
+    PrevHashMismatch,
+    /// 记录内容与哈希不符：记录被修改
+    HashMismatch,
+    /// 检查点签名无效
+    InvalidCheckpointSignature,
+    /// 检查点与该序号的记录哈希不符：链被重新计算过
+    CheckpointMismatch,
+    /// 检查点超出链尾：末尾记录被删除
+    MissingTail,
+}
+
+impl fmt::Display for AuditChainBreakReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuditChainBreakReason::SequenceGap { expected } => write!(f, "sequence gap, expected seq {}", expected),
+            AuditChainBreakReason::PrevHashMismatch => write!(f, "previous hash does not match the chain"),
+            AuditChainBreakReason::HashMismatch => write!(f, "record content does not match its hash"),
+            AuditChainBreakReason::InvalidCheckpointSignature => write!(f, "checkpoint signature is invalid"),
+            AuditChainBreakReason::CheckpointMismatch => write!(f, "record hash does not match the signed checkpoint"),
+            AuditChainBreakReason::MissingTail => write!(f, "records covered by a signed checkpoint are missing"),
+        }
+    }
+}
+
+/// 哈希链的第一处断点
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditChainBreak {
+    /// 断点处的序号
+    pub seq: u64,
+    /// 断点处的记录ID，记录缺失时为空
+    pub log_id: Option<String>,
+    pub reason: AuditChainBreakReason,
+}
+
+/// 校验通过时的统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditChainSummary {
+    pub records: u64,
+    pub checkpoints: u64,
+    /// 链尾序号，链为空时为 0
+    pub head_seq: u64,
+}
+
+/// 审计哈希链校验器
+///
+/// 按序号依次传入同一租户的全部记录：校验序号连续、前一哈希衔接、内容与哈希一致，
+/// 并在检查点序号处校验签名及哈希；全部传入后调用 `finish` 检查末尾是否被截断。
+pub struct AuditChainVerifier<'a> {
+    tenant_id: String,
+    signer: &'a dyn AuditSigner,
+    checkpoints: VecDeque<AuditCheckpoint>,
+    head: Option<(u64, String)>,
+    summary: AuditChainSummary,
+}
+
+impl<'a> AuditChainVerifier<'a> {
+    pub fn new(
+        tenant_id: impl Into<String>,
+        mut checkpoints: Vec<AuditCheckpoint>,
+        signer: &'a dyn AuditSigner,
+    ) -> Self {
+        checkpoints.sort_by_key(|c| c.seq);
+        Self {
+            tenant_id: tenant_id.into(),
+            signer,
+            checkpoints: checkpoints.into(),
+            head: None,
+            summary: AuditChainSummary::default(),
+        }
+    }
+
+    /// 当前链尾的序号与哈希
+    pub fn head(&self) -> Option<(u64, &str)> {
+        self.head.as_ref().map(|(seq, hash)| (*seq, hash.as_str()))
+    }
+
+    pub fn check(&mut self, log: &AuditLog) -> Result<(), AuditChainBreak> {
+        let mut probe = log.clone();
+        probe.link(self.head());
+        let broken = |reason| AuditChainBreak { seq: log.seq, log_id: Some(log.id.to_string()), reason };
+        if log.seq != probe.seq {
+            return Err(AuditChainBreak {
+                seq: probe.seq,
+                log_id: None,
+                reason: AuditChainBreakReason::SequenceGap { expected: probe.seq },
+            });
+        }
+        if log.prev_hash != probe.prev_hash {
+            return Err(broken(AuditChainBreakReason::PrevHashMismatch));
+        }
+        if log.hash != probe.hash {
+            return Err(broken(AuditChainBreakReason::HashMismatch));
+        }
+        if let Some(checkpoint) = self.checkpoints.front().filter(|c| c.seq == log.seq) {
+            let payload = AuditCheckpoint::payload(&self.tenant_id, checkpoint.seq, &checkpoint.hash);
+            if !self.signer.verify(&payload, &checkpoint.signature) {
+                return Err(broken(AuditChainBreakReason::InvalidCheckpointSignature));
+            }
+            if checkpoint.hash != log.hash {
+                return Err(broken(AuditChainBreakReason::CheckpointMismatch));
+            }
+            self.checkpoints.pop_front();
+            self.summary.checkpoints += 1;
+        }
+        self.head = Some((log.seq, log.hash.clone()));
+        self.summary.records += 1;
+        self.summary.head_seq = log.seq;
+        Ok(())
+    }
+
+    /// 全部记录传入后调用：仍有未匹配的检查点说明链尾记录缺失，断点为缺失的第一条
+    pub fn finish(self) -> Result<AuditChainSummary, AuditChainBreak> {
+        if self.checkpoints.is_empty() {
+            return Ok(self.summary);
+        }
+        Err(AuditChainBreak {
+            seq: self.summary.head_seq + 1,
+            log_id: None,
+            reason: AuditChainBreakReason::MissingTail,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_objects::{AuditLogId, AuditOutcome};
+
+    /// 以明文拼接代替签名，仅用于测试
+    struct PlainSigner;
+
+    impl AuditSigner for PlainSigner {
+        fn sign(&self, payload: &str) -> String {
+            format!("signed:{}", payload)
+        }
+
+        fn verify(&self, payload: &str, signature: &str) -> bool {
+            self.sign(payload) == signature
+        }
+    }
+
+    fn chain(len: usize) -> Vec<AuditLog> {
+        let mut logs: Vec<AuditLog> = Vec::new();
+        for i in 0..len {
+            let mut log = AuditLog {
+                id: AuditLogId::new_v4(),
+                actor_id: None,
+                actor_name: None,
+                action: format!("role.update.{}", i),
+                target_type: "role".into(),
+                target_id: None,
+                params: None,
+                changes: None,
+                client_ip: None,
+                user_agent: None,
+                request_id: None,
+                outcome: AuditOutcome::Success,
+                error_message: None,
+                created_at: 1_700_000_000 + i as i64,
+                seq: 0,
+                prev_hash: String::new(),
+                hash: String::new(),
+            };
+            log.link(logs.last().map(|prev| (prev.seq, prev.hash.as_str())));
+            logs.push(log);
+        }
+        logs
+    }
+
+    fn checkpoint(log: &AuditLog) -> AuditCheckpoint {
+        let payload = AuditCheckpoint::payload("default", log.seq, &log.hash);
+        AuditCheckpoint { seq: log.seq, hash: log.hash.clone(), signature: PlainSigner.sign(&payload), created_at: 0 }
+    }
+
+    fn verify(logs: &[AuditLog], checkpoints: Vec<AuditCheckpoint>) -> Result<AuditChainSummary, AuditChainBreak> {
+        let mut verifier = AuditChainVerifier::new("default", checkpoints, &PlainSigner);
+        for log in logs {
+            verifier.check(log)?;
+        }
+        verifier.finish()
+    }
+
+    #[test]
+    fn intact_chain_passes_with_checkpoints() {
+        let logs = chain(5);
+        let summary = verify(&logs, vec![checkpoint(&logs[1]), checkpoint(&logs[3])]).unwrap();
+        assert_eq!(summary, AuditChainSummary { records: 5, checkpoints: 2, head_seq: 5 });
+    }
+
+    #[test]
+    fn edited_or_deleted_records_break_the_chain() {
+        let mut logs = chain(4);
+        logs[2].action = "role.delete".into();
+        let broken = verify(&logs, vec![]).unwrap_err();
+        assert_eq!((broken.seq, broken.reason), (3, AuditChainBreakReason::HashMismatch));
+
+        let mut logs = chain(4);
+        logs.remove(1);
+        let broken = verify(&logs, vec![]).unwrap_err();
+        assert_eq!((broken.seq, broken.reason), (2, AuditChainBreakReason::SequenceGap { expected: 2 }));
+    }
+
+    #[test]
+    fn rewritten_chain_and_truncated_tail_are_caught_by_checkpoints() {
+        let logs = chain(4);
+        let checkpoints = vec![checkpoint(&logs[2])];
+
+        // 修改第 2 条后重新计算其后全部哈希，链本身自洽，但与已签名的检查点不符
+        let mut rewritten = logs.clone();
+        rewritten[1].action = "role.delete".into();
+        for i in 1..rewritten.len() {
+            let previous = (rewritten[i - 1].seq, rewritten[i - 1].hash.clone());
+            rewritten[i].link(Some((previous.0, &previous.1)));
+        }
+        let broken = verify(&rewritten, checkpoints.clone()).unwrap_err();
+        assert_eq!((broken.seq, broken.reason), (3, AuditChainBreakReason::CheckpointMismatch));
+
+        let broken = verify(&logs[..2], checkpoints.clone()).unwrap_err();
+        assert_eq!((broken.seq, broken.reason), (3, AuditChainBreakReason::MissingTail));
+
+        let mut forged = checkpoints;
+        forged[0].signature = "forged".into();
+        let broken = verify(&logs, forged).unwrap_err();
+        assert_eq!(broken.reason, AuditChainBreakReason::InvalidCheckpointSignature);
+    }
+}
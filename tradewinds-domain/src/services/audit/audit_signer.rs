@@ -0,0 +1,8 @@
+/// 审计检查点签名服务 trait
+pub trait AuditSigner: Send + Sync {
+    /// 对内容签名
+    fn sign(&self, payload: &str) -> String;
+
+    /// 校验签名是否与内容匹配
+    fn verify(&self, payload: &str, signature: &str) -> bool;
+}
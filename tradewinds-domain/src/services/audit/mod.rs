@@ -0,0 +1,5 @@
+pub mod audit_chain_verifier;
+pub mod audit_signer;
+
+pub use audit_chain_verifier::{AuditChainBreak, AuditChainBreakReason, AuditChainSummary, AuditChainVerifier};
+pub use audit_signer::AuditSigner;
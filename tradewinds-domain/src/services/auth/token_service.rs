@@ -1,5 +1,6 @@
 //! 令牌服务，生成和验证令牌
 use crate::value_objects::Token;
+use crate::value_objects::tenant::TenantId;
 use crate::value_objects::user::UserId;
 use tradewinds_error::AppResult;
 
@@ -14,4 +15,6 @@ pub trait TokenService: Send + Sync + 'static {
     async fn validate(&self, token: &Token) -> AppResult<TokenClaims>;
     async fn revoke(&self, token: &Token) -> AppResult<()>;
     async fn get_user_id_from_token(&self, token: &Token) -> AppResult<UserId>;
+    /// 令牌签发时所属的租户，旧令牌可能不含租户
+    async fn get_tenant_id_from_token(&self, token: &Token) -> AppResult<Option<TenantId>>;
 }
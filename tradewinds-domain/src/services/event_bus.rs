@@ -1,8 +1,29 @@
 use async_trait::async_trait;
+use std::any::Any;
+use std::sync::Arc;
 use tradewinds_error::AppResult;
 
+/// 领域事件
+///
+/// 以 `Any` 为父 trait，事件总线据此把 `dyn Event` 还原为具体事件类型分发给订阅者
 #[async_trait]
-pub trait Event: Send + Sync {
+pub trait Event: Any + Send + Sync {
     fn event_type(&self) -> &'static str;
     fn to_json(&self) -> AppResult<String>;
 }
+
+/// 事件订阅者，按具体事件类型接收事件
+///
+/// 以 `dyn Event` 为类型参数时接收所有事件
+#[async_trait]
+pub trait EventHandler<E: Event + ?Sized>: Send + Sync {
+    async fn handle(&self, event: &E) -> AppResult<()>;
+}
+
+/// 事件总线
+///
+/// 应用服务在业务变更提交成功后发布事件；订阅者的失败不影响发布方
+#[async_trait]
+pub trait EventBus: Send + Sync {
+    async fn publish(&self, event: Arc<dyn Event>) -> AppResult<()>;
+}
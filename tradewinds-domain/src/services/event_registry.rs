@@ -0,0 +1,57 @@
+use serde::de::DeserializeOwned;
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::services::Event;
+use tradewinds_error::{AppError, AppResult};
+
+type Decoder = fn(&str) -> AppResult<Arc<dyn Event>>;
+
+fn decode<E: Event + DeserializeOwned>(payload: &str) -> AppResult<Arc<dyn Event>> {
+    let event: E = serde_json::from_str(payload)?;
+    Ok(Arc::new(event))
+}
+
+/// 事件类型注册表
+///
+/// 记录事件类型名称到具体事件类型的映射，用于把发件箱、消息队列中的事件 JSON 还原为事件
+#[derive(Clone, Default)]
+pub struct EventRegistry {
+    decoders: HashMap<&'static str, Decoder>,
+    event_types: HashMap<TypeId, &'static str>,
+}
+
+impl EventRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 注册事件类型，`event_type` 与事件的 `event_type()` 一致
+    pub fn register<E: Event + DeserializeOwned>(&mut self, event_type: &'static str) -> &mut Self {
+        self.decoders.insert(event_type, decode::<E>);
+        self.event_types.insert(TypeId::of::<E>(), event_type);
+        self
+    }
+
+    /// 事件类型的注册名称
+    pub fn event_type_of<E: Event>(&self) -> Option<&'static str> {
+        self.event_types.get(&TypeId::of::<E>()).copied()
+    }
+
+    /// 所有已注册的事件类型名称
+    pub fn event_types(&self) -> Vec<&'static str> {
+        let mut event_types: Vec<_> = self.decoders.keys().copied().collect();
+        event_types.sort_unstable();
+        event_types
+    }
+
+    /// 还原事件
+    pub fn decode(&self, event_type: &str, payload: &str) -> AppResult<Arc<dyn Event>> {
+        let decoder = self
+            .decoders
+            .get(event_type)
+            .ok_or_else(|| AppError::Validation(format!("Unknown event type: {}", event_type)))?;
+        decoder(payload)
+    }
+}
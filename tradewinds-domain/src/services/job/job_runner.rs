@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::entities::JobRun;
+use crate::value_objects::user::UserId;
+use tradewinds_error::AppResult;
+
+/// 定时任务执行服务 trait
+#[async_trait]
+pub trait JobRunner: Send + Sync {
+    /// 立即在后台执行已登记的任务，返回执行中的记录；任务正在其他位置执行时返回冲突错误
+    async fn run_now(&self, name: &str, triggered_by: Option<UserId>) -> AppResult<JobRun>;
+}
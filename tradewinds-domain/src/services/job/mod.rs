@@ -0,0 +1,3 @@
+pub mod job_runner;
+
+pub use job_runner::JobRunner;
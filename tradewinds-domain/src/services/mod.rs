@@ -1,15 +1,23 @@
+pub mod audit;
 pub mod auth;
 pub mod event_bus;
+pub mod event_registry;
+pub mod job;
 pub mod permission;
 pub mod role;
 pub mod role_permission;
 pub mod user;
 pub mod user_role;
+pub mod webhook;
 
+pub use audit::AuditSigner;
 pub use auth::{PasswordService, TokenService};
-pub use event_bus::Event;
+pub use event_bus::{Event, EventBus, EventHandler};
+pub use event_registry::EventRegistry;
+pub use job::JobRunner;
 pub use permission::PermissionService;
 pub use role::RoleService;
 pub use role_permission::RolePermissionService;
 pub use user::UserService;
 pub use user_role::UserRoleService;
+pub use webhook::WebhookSender;
@@ -0,0 +1,3 @@
+pub mod webhook_sender;
+
+pub use webhook_sender::WebhookSender;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::entities::{Webhook, WebhookDelivery};
+use tradewinds_error::AppResult;
+
+/// Webhook 发送服务 trait
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// 以端点密钥签名并发送投递记录的请求体，返回响应的 HTTP 状态码；连接失败或超时返回错误
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> AppResult<u16>;
+}
@@ -0,0 +1,34 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 申请的授权时长（天），批准后自批准时刻起计算
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct AccessDuration(u32);
+
+impl AccessDuration {
+    pub const MAX_DAYS: u32 = 365;
+
+    pub fn from_days(days: u32) -> AppResult<Self> {
+        if days == 0 || days > Self::MAX_DAYS {
+            return Err(AppError::Validation(format!("Duration must be 1-{} days", Self::MAX_DAYS)));
+        }
+        Ok(Self(days))
+    }
+
+    pub fn days(&self) -> u32 {
+        self.0
+    }
+
+    pub fn as_secs(&self) -> i64 {
+        i64::from(self.0) * 24 * 60 * 60
+    }
+}
+
+impl fmt::Display for AccessDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,39 @@
+use std::{fmt, str::FromStr};
+
+use derive_more::Deref;
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 权限申请理由
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
+pub struct AccessJustification(String);
+
+impl AccessJustification {
+    pub fn new<S: Into<String>>(value: S) -> AppResult<Self> {
+        let value = value.into().trim().to_string();
+        let len = value.chars().count();
+        if len == 0 || len > 500 {
+            return Err(AppError::Validation("Justification must be 1-500 characters".into()));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for AccessJustification {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for AccessJustification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 权限申请状态
+///
+/// 仅待审批的申请可以被批准、驳回或撤回；
+/// 待审批超时或已批准的授权到期后转为已过期
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum AccessRequestStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    Cancelled,
+    Expired,
+}
+
+impl AccessRequestStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(AccessRequestStatus::Pending),
+            1 => Ok(AccessRequestStatus::Approved),
+            2 => Ok(AccessRequestStatus::Rejected),
+            3 => Ok(AccessRequestStatus::Cancelled),
+            4 => Ok(AccessRequestStatus::Expired),
+            _ => Err(AppError::Validation("Access request status can only be 0, 1, 2, 3, 4".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            AccessRequestStatus::Pending => 0,
+            AccessRequestStatus::Approved => 1,
+            AccessRequestStatus::Rejected => 2,
+            AccessRequestStatus::Cancelled => 3,
+            AccessRequestStatus::Expired => 4,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, AccessRequestStatus::Pending)
+    }
+
+    pub fn is_approved(&self) -> bool {
+        matches!(self, AccessRequestStatus::Approved)
+    }
+
+    /// 已结束的申请不再发生状态变化
+    pub fn is_final(&self) -> bool {
+        matches!(self, AccessRequestStatus::Rejected | AccessRequestStatus::Cancelled | AccessRequestStatus::Expired)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for AccessRequestStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(AccessRequestStatus::Pending),
+            "approved" => Ok(AccessRequestStatus::Approved),
+            "rejected" => Ok(AccessRequestStatus::Rejected),
+            "cancelled" => Ok(AccessRequestStatus::Cancelled),
+            "expired" => Ok(AccessRequestStatus::Expired),
+            _ => Err(AppError::Validation(format!("Invalid access request status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for AccessRequestStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod access_duration;
+pub mod access_justification;
+pub mod access_request_id;
+pub mod access_request_status;
+
+pub use access_duration::AccessDuration;
+pub use access_justification::AccessJustification;
+pub use access_request_id::AccessRequestId;
+pub use access_request_status::AccessRequestStatus;
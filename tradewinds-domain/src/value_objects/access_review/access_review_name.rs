@@ -0,0 +1,39 @@
+use std::{fmt, str::FromStr};
+
+use derive_more::Deref;
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 访问复核活动名称
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
+pub struct AccessReviewName(String);
+
+impl AccessReviewName {
+    pub fn new<S: Into<String>>(value: S) -> AppResult<Self> {
+        let value = value.into().trim().to_string();
+        let len = value.chars().count();
+        if len == 0 || len > 100 {
+            return Err(AppError::Validation("Campaign name must be 1-100 characters".into()));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for AccessReviewName {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for AccessReviewName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,59 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 访问复核活动状态
+///
+/// 进行中的活动可由复核人逐项确认或收回；
+/// 截止时间到达后未决项被自动收回，活动转为已完成
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum AccessReviewStatus {
+    #[default]
+    Active,
+    Completed,
+}
+
+impl AccessReviewStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(AccessReviewStatus::Active),
+            1 => Ok(AccessReviewStatus::Completed),
+            _ => Err(AppError::Validation("Access review status can only be 0, 1".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            AccessReviewStatus::Active => 0,
+            AccessReviewStatus::Completed => 1,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, AccessReviewStatus::Active)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for AccessReviewStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(AccessReviewStatus::Active),
+            "completed" => Ok(AccessReviewStatus::Completed),
+            _ => Err(AppError::Validation(format!("Invalid access review status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for AccessReviewStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
@@ -0,0 +1,11 @@
+pub mod access_review_id;
+pub mod access_review_item_id;
+pub mod access_review_name;
+pub mod access_review_status;
+pub mod review_decision;
+
+pub use access_review_id::AccessReviewId;
+pub use access_review_item_id::AccessReviewItemId;
+pub use access_review_name::AccessReviewName;
+pub use access_review_status::AccessReviewStatus;
+pub use review_decision::ReviewDecision;
@@ -0,0 +1,82 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 复核项结论
+///
+/// 复核人对每个（用户，角色）组合确认保留或收回；
+/// 截止时仍未决的组合记为自动收回
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum ReviewDecision {
+    #[default]
+    Pending,
+    Confirmed,
+    Revoked,
+    AutoRevoked,
+}
+
+impl ReviewDecision {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(ReviewDecision::Pending),
+            1 => Ok(ReviewDecision::Confirmed),
+            2 => Ok(ReviewDecision::Revoked),
+            3 => Ok(ReviewDecision::AutoRevoked),
+            _ => Err(AppError::Validation("Review decision can only be 0, 1, 2, 3".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            ReviewDecision::Pending => 0,
+            ReviewDecision::Confirmed => 1,
+            ReviewDecision::Revoked => 2,
+            ReviewDecision::AutoRevoked => 3,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ReviewDecision::Pending)
+    }
+
+    /// 角色是否被收回（人工或自动）
+    pub fn is_revoked(&self) -> bool {
+        matches!(self, ReviewDecision::Revoked | ReviewDecision::AutoRevoked)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 报表中使用的名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReviewDecision::Pending => "pending",
+            ReviewDecision::Confirmed => "confirmed",
+            ReviewDecision::Revoked => "revoked",
+            ReviewDecision::AutoRevoked => "auto_revoked",
+        }
+    }
+}
+
+impl FromStr for ReviewDecision {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(ReviewDecision::Pending),
+            "confirmed" | "confirm" => Ok(ReviewDecision::Confirmed),
+            "revoked" | "revoke" => Ok(ReviewDecision::Revoked),
+            "auto_revoked" => Ok(ReviewDecision::AutoRevoked),
+            _ => Err(AppError::Validation(format!("Invalid review decision: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for ReviewDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
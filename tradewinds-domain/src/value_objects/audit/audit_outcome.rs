@@ -0,0 +1,64 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 审计日志记录的操作结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum AuditOutcome {
+    #[default]
+    Success,
+    Failure,
+}
+
+impl AuditOutcome {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(AuditOutcome::Success),
+            1 => Ok(AuditOutcome::Failure),
+            _ => Err(AppError::Validation("Audit outcome can only be 0, 1".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            AuditOutcome::Success => 0,
+            AuditOutcome::Failure => 1,
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, AuditOutcome::Success)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 报表中使用的名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditOutcome::Success => "success",
+            AuditOutcome::Failure => "failure",
+        }
+    }
+}
+
+impl FromStr for AuditOutcome {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "success" => Ok(AuditOutcome::Success),
+            "failure" => Ok(AuditOutcome::Failure),
+            _ => Err(AppError::Validation(format!("Invalid audit outcome: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for AuditOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
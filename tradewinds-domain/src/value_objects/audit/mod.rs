@@ -0,0 +1,5 @@
+pub mod audit_log_id;
+pub mod audit_outcome;
+
+pub use audit_log_id::AuditLogId;
+pub use audit_outcome::AuditOutcome;
@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum DepartmentStatus {
+    #[default]
+    Active,
+    Inactive,
+    Deleted,
+}
+
+impl DepartmentStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(DepartmentStatus::Active),
+            1 => Ok(DepartmentStatus::Inactive),
+            2 => Ok(DepartmentStatus::Deleted),
+            _ => Err(AppError::Validation("Department status can only be 0, 1, 2".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            DepartmentStatus::Active => 0,
+            DepartmentStatus::Inactive => 1,
+            DepartmentStatus::Deleted => 2,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, DepartmentStatus::Active)
+    }
+
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, DepartmentStatus::Inactive)
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, DepartmentStatus::Deleted)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for DepartmentStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(DepartmentStatus::Active),
+            "inactive" => Ok(DepartmentStatus::Inactive),
+            "deleted" => Ok(DepartmentStatus::Deleted),
+            _ => Err(AppError::Validation(format!("Invalid department status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for DepartmentStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod department_id;
+pub mod department_name;
+pub mod department_sort;
+pub mod department_status;
+
+pub use department_id::DepartmentId;
+pub use department_name::DepartmentName;
+pub use department_sort::DepartmentSort;
+pub use department_status::DepartmentStatus;
@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum GroupStatus {
+    #[default]
+    Active,
+    Inactive,
+    Deleted,
+}
+
+impl GroupStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(GroupStatus::Active),
+            1 => Ok(GroupStatus::Inactive),
+            2 => Ok(GroupStatus::Deleted),
+            _ => Err(AppError::Validation("Group status can only be 0, 1, 2".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            GroupStatus::Active => 0,
+            GroupStatus::Inactive => 1,
+            GroupStatus::Deleted => 2,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, GroupStatus::Active)
+    }
+
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, GroupStatus::Inactive)
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, GroupStatus::Deleted)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for GroupStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(GroupStatus::Active),
+            "inactive" => Ok(GroupStatus::Inactive),
+            "deleted" => Ok(GroupStatus::Deleted),
+            _ => Err(AppError::Validation(format!("Invalid group status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for GroupStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
@@ -0,0 +1,9 @@
+pub mod group_description;
+pub mod group_id;
+pub mod group_name;
+pub mod group_status;
+
+pub use group_description::GroupDescription;
+pub use group_id::GroupId;
+pub use group_name::GroupName;
+pub use group_status::GroupStatus;
@@ -0,0 +1,63 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 定时任务执行状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum JobRunStatus {
+    /// 执行中
+    #[default]
+    Running = 0,
+    /// 执行成功
+    Succeeded = 1,
+    /// 执行失败，或实例退出导致执行中断
+    Failed = 2,
+}
+
+impl JobRunStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(JobRunStatus::Running),
+            1 => Ok(JobRunStatus::Succeeded),
+            2 => Ok(JobRunStatus::Failed),
+            _ => Err(AppError::Validation("Job run status can only be 0, 1 or 2".to_string())),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobRunStatus::Running => "running",
+            JobRunStatus::Succeeded => "succeeded",
+            JobRunStatus::Failed => "failed",
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        matches!(self, JobRunStatus::Running)
+    }
+}
+
+impl FromStr for JobRunStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "running" => Ok(JobRunStatus::Running),
+            "succeeded" => Ok(JobRunStatus::Succeeded),
+            "failed" => Ok(JobRunStatus::Failed),
+            _ => Err(AppError::Validation(format!("Invalid job run status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for JobRunStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
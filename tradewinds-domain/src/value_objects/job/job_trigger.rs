@@ -0,0 +1,54 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 定时任务的触发方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum JobTrigger {
+    /// 按 cron 表达式到期触发
+    #[default]
+    Schedule = 0,
+    /// 管理员手动触发
+    Manual = 1,
+}
+
+impl JobTrigger {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(JobTrigger::Schedule),
+            1 => Ok(JobTrigger::Manual),
+            _ => Err(AppError::Validation("Job trigger can only be 0 or 1".to_string())),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobTrigger::Schedule => "schedule",
+            JobTrigger::Manual => "manual",
+        }
+    }
+}
+
+impl FromStr for JobTrigger {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "schedule" => Ok(JobTrigger::Schedule),
+            "manual" => Ok(JobTrigger::Manual),
+            _ => Err(AppError::Validation(format!("Invalid job trigger: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for JobTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
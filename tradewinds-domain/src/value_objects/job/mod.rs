@@ -0,0 +1,7 @@
+pub mod job_run_id;
+pub mod job_run_status;
+pub mod job_trigger;
+
+pub use job_run_id::JobRunId;
+pub use job_run_status::JobRunStatus;
+pub use job_trigger::JobTrigger;
@@ -0,0 +1,47 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::AppError;
+
+/// 登录失败原因
+///
+/// 仅记录在登录日志中供管理员排查，返回给客户端的错误不区分用户不存在与密码错误。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LoginFailureReason {
+    /// 用户不存在
+    UserNotFound,
+    /// 用户未启用、已禁用或已删除
+    UserInactive,
+    /// 密码错误
+    InvalidPassword,
+}
+
+impl LoginFailureReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginFailureReason::UserNotFound => "user_not_found",
+            LoginFailureReason::UserInactive => "user_inactive",
+            LoginFailureReason::InvalidPassword => "invalid_password",
+        }
+    }
+}
+
+impl FromStr for LoginFailureReason {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "user_not_found" => Ok(LoginFailureReason::UserNotFound),
+            "user_inactive" => Ok(LoginFailureReason::UserInactive),
+            "invalid_password" => Ok(LoginFailureReason::InvalidPassword),
+            _ => Err(AppError::Validation(format!("Invalid login failure reason: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for LoginFailureReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,50 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 登录方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum LoginMethod {
+    /// 用户名密码
+    #[default]
+    Password,
+}
+
+impl LoginMethod {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(LoginMethod::Password),
+            _ => Err(AppError::Validation("Login method can only be 0".to_string())),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    /// 报表中使用的名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginMethod::Password => "password",
+        }
+    }
+}
+
+impl FromStr for LoginMethod {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "password" => Ok(LoginMethod::Password),
+            _ => Err(AppError::Validation(format!("Invalid login method: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for LoginMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,7 @@
+pub mod login_failure_reason;
+pub mod login_log_id;
+pub mod login_method;
+
+pub use login_failure_reason::LoginFailureReason;
+pub use login_log_id::LoginLogId;
+pub use login_method::LoginMethod;
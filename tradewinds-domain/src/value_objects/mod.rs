@@ -1,20 +1,46 @@
+pub mod access_request;
+pub mod access_review;
+pub mod audit;
 pub mod auth;
+pub mod department;
+pub mod group;
+pub mod job;
+pub mod login_log;
+pub mod outbox;
 pub mod permission;
+pub mod policy;
 pub mod role;
 pub mod role_permission;
+pub mod scope;
+pub mod sod;
 pub mod system_setting;
+pub mod tenant;
 pub mod user;
 pub mod user_role;
+pub mod webhook;
 
+pub use access_request::{AccessDuration, AccessJustification, AccessRequestId, AccessRequestStatus};
+pub use access_review::{AccessReviewId, AccessReviewItemId, AccessReviewName, AccessReviewStatus, ReviewDecision};
+pub use audit::{AuditLogId, AuditOutcome};
 pub use auth::{auth_password::Password, auth_token::Token, auth_username::AuthUsername};
+pub use department::{DepartmentId, DepartmentName, DepartmentSort, DepartmentStatus};
+pub use group::{GroupDescription, GroupId, GroupName, GroupStatus};
+pub use job::{JobRunId, JobRunStatus, JobTrigger};
+pub use login_log::{LoginFailureReason, LoginLogId, LoginMethod};
+pub use outbox::{OutboxMessageId, OutboxStatus};
 pub use permission::{
     PermissionCode, PermissionComponent, PermissionIcon, PermissionId, PermissionName, PermissionPath, PermissionSort,
     PermissionStatus, PermissionType,
 };
-pub use role::{RoleDescription, RoleId, RoleName, RoleStatus};
-pub use role_permission::RolePermissionId;
+pub use policy::{AccessPolicyId, AccessPolicyName, AccessPolicyStatus, PolicyExpression};
+pub use role::{RoleAssignment, RoleDescription, RoleId, RoleName, RoleSource, RoleStatus};
+pub use role_permission::{PermissionEffect, RolePermissionId};
+pub use scope::{DataScope, DataScopeType};
+pub use sod::{SodRuleId, SodRuleName, SodRuleType};
+pub use tenant::{TenantCode, TenantHost, TenantId, TenantName, TenantStatus};
 pub use user::{
     user_avatar::Avatar, user_email::Email, user_id::UserId, user_phone::Phone, user_real_name::RealName,
     user_status::UserStatus,
 };
 pub use user_role::UserRoleId;
+pub use webhook::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookId, WebhookStatus};
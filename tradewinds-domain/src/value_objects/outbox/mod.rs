@@ -0,0 +1,5 @@
+pub mod outbox_message_id;
+pub mod outbox_status;
+
+pub use outbox_message_id::OutboxMessageId;
+pub use outbox_status::OutboxStatus;
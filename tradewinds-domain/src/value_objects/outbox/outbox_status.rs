@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 发件箱消息状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum OutboxStatus {
+    /// 待投递，含等待重试
+    #[default]
+    Pending = 0,
+    /// 已投递
+    Published = 1,
+    /// 超过最大重试次数，等待人工处理
+    Dead = 2,
+}
+
+impl OutboxStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(OutboxStatus::Pending),
+            1 => Ok(OutboxStatus::Published),
+            2 => Ok(OutboxStatus::Dead),
+            _ => Err(AppError::Validation("Outbox status can only be 0, 1 or 2".to_string())),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OutboxStatus::Pending => "pending",
+            OutboxStatus::Published => "published",
+            OutboxStatus::Dead => "dead",
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, OutboxStatus::Pending)
+    }
+
+    pub fn is_dead(&self) -> bool {
+        matches!(self, OutboxStatus::Dead)
+    }
+}
+
+impl FromStr for OutboxStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(OutboxStatus::Pending),
+            "published" => Ok(OutboxStatus::Published),
+            "dead" => Ok(OutboxStatus::Dead),
+            _ => Err(AppError::Validation(format!("Invalid outbox status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for OutboxStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
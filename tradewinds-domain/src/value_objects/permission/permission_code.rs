@@ -3,21 +3,61 @@ use serde::{Deserialize, Serialize};
 use std::{fmt, str::FromStr};
 use tradewinds_error::{AppError, AppResult};
 
+/// 权限码
+///
+/// 权限码按 `:` 分段组成层级，例如 `system:user:list`。
+/// 最后一段为 `*` 时表示通配，匹配该前缀下的所有后代权限码：
+/// - `system:user:*` 匹配 `system:user:list`、`system:user:role:assign`，但不匹配 `system:user` 本身
+/// - `*` 匹配所有权限码
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
 pub struct PermissionCode(String);
 
 impl PermissionCode {
+    pub const SEPARATOR: char = ':';
+    pub const WILDCARD: &'static str = "*";
+
     pub fn new<S: Into<String>>(value: S) -> AppResult<Self> {
         let value = value.into();
         if value.trim().is_empty() {
             return Err(AppError::Validation("Permission code cannot be empty".into()));
         }
+        let segments: Vec<&str> = value.split(Self::SEPARATOR).collect();
+        if segments.iter().any(|s| s.trim().is_empty()) {
+            return Err(AppError::Validation(format!("Permission code has an empty segment: {}", value)));
+        }
+        let last = segments.len() - 1;
+        for (i, segment) in segments.iter().enumerate() {
+            if segment.contains('*') && (i != last || *segment != Self::WILDCARD) {
+                return Err(AppError::Validation(format!(
+                    "Wildcard '*' is only allowed as the last segment of a permission code: {}",
+                    value
+                )));
+            }
+        }
         Ok(Self(value))
     }
 
     pub fn value(&self) -> &str {
         &self.0
     }
+
+    /// 是否为通配权限码
+    pub fn is_wildcard(&self) -> bool {
+        self.0 == Self::WILDCARD || self.0.ends_with(":*")
+    }
+
+    /// 以当前权限码为模式，判断是否覆盖目标权限码
+    pub fn matches(&self, code: &PermissionCode) -> bool {
+        if !self.is_wildcard() {
+            return self.0 == code.0;
+        }
+        if self.0 == Self::WILDCARD {
+            return true;
+        }
+        // 保留末尾的分隔符，避免 `system:user:*` 误匹配 `system:username`
+        let prefix = &self.0[..self.0.len() - Self::WILDCARD.len()];
+        code.0.len() > prefix.len() && code.0.starts_with(prefix)
+    }
 }
 
 impl FromStr for PermissionCode {
@@ -33,3 +73,51 @@ impl fmt::Display for PermissionCode {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(s: &str) -> PermissionCode {
+        PermissionCode::new(s).unwrap()
+    }
+
+    #[test]
+    fn rejects_misplaced_wildcards() {
+        assert!(PermissionCode::new("system:*:list").is_err());
+        assert!(PermissionCode::new("system:user*").is_err());
+        assert!(PermissionCode::new("system::list").is_err());
+        assert!(PermissionCode::new("system:user:").is_err());
+        assert!(PermissionCode::new("system:user:*").is_ok());
+        assert!(PermissionCode::new("*").is_ok());
+    }
+
+    #[test]
+    fn concrete_code_matches_only_itself() {
+        let pattern = code("system:user:list");
+        assert!(!pattern.is_wildcard());
+        assert!(pattern.matches(&code("system:user:list")));
+        assert!(!pattern.matches(&code("system:user:create")));
+        assert!(!pattern.matches(&code("system:user")));
+    }
+
+    #[test]
+    fn wildcard_matches_descendants_only() {
+        let pattern = code("system:user:*");
+        assert!(pattern.is_wildcard());
+        assert!(pattern.matches(&code("system:user:list")));
+        assert!(pattern.matches(&code("system:user:role:assign")));
+        assert!(pattern.matches(&code("system:user:*")));
+        assert!(!pattern.matches(&code("system:user")));
+        assert!(!pattern.matches(&code("system:username")));
+        assert!(!pattern.matches(&code("system:role:list")));
+    }
+
+    #[test]
+    fn root_wildcard_matches_everything() {
+        let pattern = code("*");
+        assert!(pattern.is_wildcard());
+        assert!(pattern.matches(&code("system")));
+        assert!(pattern.matches(&code("system:user:list")));
+    }
+}
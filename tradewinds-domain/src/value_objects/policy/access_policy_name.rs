@@ -0,0 +1,38 @@
+use std::{fmt, str::FromStr};
+
+use derive_more::Deref;
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
+pub struct AccessPolicyName(String);
+
+impl AccessPolicyName {
+    pub fn new<S: Into<String>>(value: S) -> AppResult<Self> {
+        let value = value.into();
+        let len = value.trim().chars().count();
+        if len == 0 || len > 50 {
+            return Err(AppError::Validation("Access policy name must be 1-50 characters".into()));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for AccessPolicyName {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for AccessPolicyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
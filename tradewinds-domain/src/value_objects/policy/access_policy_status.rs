@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum AccessPolicyStatus {
+    #[default]
+    Active,
+    Inactive,
+    Deleted,
+}
+
+impl AccessPolicyStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(AccessPolicyStatus::Active),
+            1 => Ok(AccessPolicyStatus::Inactive),
+            2 => Ok(AccessPolicyStatus::Deleted),
+            _ => Err(AppError::Validation("Access policy status can only be 0, 1, 2".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            AccessPolicyStatus::Active => 0,
+            AccessPolicyStatus::Inactive => 1,
+            AccessPolicyStatus::Deleted => 2,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, AccessPolicyStatus::Active)
+    }
+
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, AccessPolicyStatus::Inactive)
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, AccessPolicyStatus::Deleted)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for AccessPolicyStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(AccessPolicyStatus::Active),
+            "inactive" => Ok(AccessPolicyStatus::Inactive),
+            "deleted" => Ok(AccessPolicyStatus::Deleted),
+            _ => Err(AppError::Validation(format!("Invalid access policy status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for AccessPolicyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
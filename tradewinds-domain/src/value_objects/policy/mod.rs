@@ -0,0 +1,9 @@
+pub mod access_policy_id;
+pub mod access_policy_name;
+pub mod access_policy_status;
+pub mod policy_expression;
+
+pub use access_policy_id::AccessPolicyId;
+pub use access_policy_name::AccessPolicyName;
+pub use access_policy_status::AccessPolicyStatus;
+pub use policy_expression::PolicyExpression;
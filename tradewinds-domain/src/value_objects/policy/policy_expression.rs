@@ -0,0 +1,48 @@
+use std::{fmt, str::FromStr};
+
+use derive_more::Deref;
+use serde::{Deserialize, Serialize};
+
+use crate::policies::abac_expression::Expression;
+use tradewinds_error::{AppError, AppResult};
+
+/// 访问策略条件表达式
+///
+/// 创建时即完成语法校验，保证入库的表达式均可解析
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
+pub struct PolicyExpression(String);
+
+impl PolicyExpression {
+    pub const MAX_LENGTH: usize = 2000;
+
+    pub fn new<S: Into<String>>(value: S) -> AppResult<Self> {
+        let value = value.into().trim().to_string();
+        if value.is_empty() || value.chars().count() > Self::MAX_LENGTH {
+            return Err(AppError::Validation(format!("Policy condition must be 1-{} characters", Self::MAX_LENGTH)));
+        }
+        Expression::parse(&value)?;
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    pub fn compile(&self) -> AppResult<Expression> {
+        Expression::parse(&self.0)
+    }
+}
+
+impl FromStr for PolicyExpression {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for PolicyExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -1,11 +1,13 @@
 pub mod role_description;
 pub mod role_id;
 pub mod role_name;
+pub mod role_source;
 pub mod role_status;
 pub mod role_code;
 
 pub use role_description::RoleDescription;
 pub use role_id::RoleId;
 pub use role_name::RoleName;
+pub use role_source::{RoleAssignment, RoleSource};
 pub use role_status::RoleStatus;
 pub use role_code::RoleCode;
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::{
+    group::{GroupId, GroupName},
+    role::RoleId,
+};
+
+/// 角色来源：直接分配给用户，或经由用户组继承
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoleSource {
+    Direct,
+    Group { group_id: GroupId, group_name: GroupName },
+}
+
+impl RoleSource {
+    pub fn is_direct(&self) -> bool {
+        matches!(self, RoleSource::Direct)
+    }
+}
+
+/// 用户持有的一条角色分配及其来源
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoleAssignment {
+    pub role_id: RoleId,
+    pub source: RoleSource,
+}
+
+impl RoleAssignment {
+    pub fn direct(role_id: RoleId) -> Self {
+        Self { role_id, source: RoleSource::Direct }
+    }
+
+    pub fn via_group(role_id: RoleId, group_id: GroupId, group_name: GroupName) -> Self {
+        Self { role_id, source: RoleSource::Group { group_id, group_name } }
+    }
+
+    /// 有效角色 = 直接角色 ∪ 所在用户组的角色，去重并保持首次出现的顺序
+    pub fn effective_role_ids(assignments: &[RoleAssignment]) -> Vec<RoleId> {
+        let mut role_ids: Vec<RoleId> = Vec::new();
+        for assignment in assignments {
+            if !role_ids.contains(&assignment.role_id) {
+                role_ids.push(assignment.role_id.clone());
+            }
+        }
+        role_ids
+    }
+
+    /// 某个角色的全部来源
+    pub fn sources_of<'a>(assignments: &'a [RoleAssignment], role_id: &RoleId) -> Vec<&'a RoleSource> {
+        assignments.iter().filter(|a| &a.role_id == role_id).map(|a| &a.source).collect()
+    }
+}
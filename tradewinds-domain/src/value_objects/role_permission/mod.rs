@@ -1,3 +1,5 @@
+pub mod permission_effect;
 pub mod role_permission_id;
 
+pub use permission_effect::PermissionEffect;
 pub use role_permission_id::RolePermissionId;
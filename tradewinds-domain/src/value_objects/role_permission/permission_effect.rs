@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 角色授权效果
+///
+/// - Allow: 允许
+/// - Deny: 显式拒绝，优先于任何角色的允许
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum PermissionEffect {
+    #[default]
+    Allow,
+    Deny,
+}
+
+impl PermissionEffect {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(PermissionEffect::Allow),
+            1 => Ok(PermissionEffect::Deny),
+            _ => Err(AppError::Validation("Permission effect can only be 0, 1".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            PermissionEffect::Allow => 0,
+            PermissionEffect::Deny => 1,
+        }
+    }
+
+    pub fn is_allow(&self) -> bool {
+        matches!(self, PermissionEffect::Allow)
+    }
+
+    pub fn is_deny(&self) -> bool {
+        matches!(self, PermissionEffect::Deny)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for PermissionEffect {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "allow" => Ok(PermissionEffect::Allow),
+            "deny" => Ok(PermissionEffect::Deny),
+            _ => Err(AppError::Validation(format!("Invalid permission effect: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for PermissionEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            PermissionEffect::Allow => "allow",
+            PermissionEffect::Deny => "deny",
+        };
+        write!(f, "{}", s)
+    }
+}
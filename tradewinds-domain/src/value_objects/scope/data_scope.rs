@@ -0,0 +1,37 @@
+use std::collections::BTreeSet;
+
+use crate::value_objects::{department::DepartmentId, user::user_id::UserId};
+
+/// 数据范围（行级过滤条件）
+///
+/// 由当前主体的角色解析得到，作为仓储 `search` 的额外过滤条件：
+/// - All: 不做限制
+/// - Restricted: 命中任一条件即可见
+///   - departments: 所属部门在集合内
+///   - department_trees: 所属部门为集合内部门或其子部门
+///   - owner: 数据本身属于该用户，或由该用户创建
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DataScope {
+    #[default]
+    All,
+    Restricted {
+        departments: BTreeSet<DepartmentId>,
+        department_trees: BTreeSet<DepartmentId>,
+        owner: Option<UserId>,
+    },
+}
+
+impl DataScope {
+    /// 仅本人可见
+    pub fn owner(user_id: UserId) -> Self {
+        DataScope::Restricted {
+            departments: BTreeSet::new(),
+            department_trees: BTreeSet::new(),
+            owner: Some(user_id),
+        }
+    }
+
+    pub fn is_all(&self) -> bool {
+        matches!(self, DataScope::All)
+    }
+}
@@ -0,0 +1,78 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 角色数据范围类型
+///
+/// - All: 全部数据
+/// - Custom: 自定义部门集合
+/// - Department: 本部门
+/// - DepartmentAndChildren: 本部门及子部门
+/// - SelfOnly: 仅本人
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum DataScopeType {
+    #[default]
+    All,
+    Custom,
+    Department,
+    DepartmentAndChildren,
+    SelfOnly,
+}
+
+impl DataScopeType {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(DataScopeType::All),
+            1 => Ok(DataScopeType::Custom),
+            2 => Ok(DataScopeType::Department),
+            3 => Ok(DataScopeType::DepartmentAndChildren),
+            4 => Ok(DataScopeType::SelfOnly),
+            _ => Err(AppError::Validation("Data scope can only be 0, 1, 2, 3, 4".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            DataScopeType::All => 0,
+            DataScopeType::Custom => 1,
+            DataScopeType::Department => 2,
+            DataScopeType::DepartmentAndChildren => 3,
+            DataScopeType::SelfOnly => 4,
+        }
+    }
+
+    pub fn is_all(&self) -> bool {
+        matches!(self, DataScopeType::All)
+    }
+
+    pub fn is_custom(&self) -> bool {
+        matches!(self, DataScopeType::Custom)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for DataScopeType {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(DataScopeType::All),
+            "custom" => Ok(DataScopeType::Custom),
+            "department" => Ok(DataScopeType::Department),
+            "department_and_children" => Ok(DataScopeType::DepartmentAndChildren),
+            "self" => Ok(DataScopeType::SelfOnly),
+            _ => Err(AppError::Validation(format!("Invalid data scope: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for DataScopeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
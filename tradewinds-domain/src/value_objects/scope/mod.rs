@@ -0,0 +1,5 @@
+pub mod data_scope;
+pub mod data_scope_type;
+
+pub use data_scope::DataScope;
+pub use data_scope_type::DataScopeType;
@@ -0,0 +1,7 @@
+pub mod sod_rule_id;
+pub mod sod_rule_name;
+pub mod sod_rule_type;
+
+pub use sod_rule_id::SodRuleId;
+pub use sod_rule_name::SodRuleName;
+pub use sod_rule_type::SodRuleType;
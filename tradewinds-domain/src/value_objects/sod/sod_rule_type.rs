@@ -0,0 +1,60 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 职责分离规则类型
+///
+/// - MutuallyExclusive: 同一用户最多持有规则角色集合中的 `max_count` 个角色
+/// - MaxUsersPerRole: 规则中每个角色最多由 `max_count` 个用户持有
+/// - MaxRolesPerUser: 同一用户最多持有 `max_count` 个角色（不限定角色集合）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum SodRuleType {
+    #[default]
+    MutuallyExclusive,
+    MaxUsersPerRole,
+    MaxRolesPerUser,
+}
+
+impl SodRuleType {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(SodRuleType::MutuallyExclusive),
+            1 => Ok(SodRuleType::MaxUsersPerRole),
+            2 => Ok(SodRuleType::MaxRolesPerUser),
+            _ => Err(AppError::Validation("SoD rule type can only be 0, 1, 2".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            SodRuleType::MutuallyExclusive => 0,
+            SodRuleType::MaxUsersPerRole => 1,
+            SodRuleType::MaxRolesPerUser => 2,
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for SodRuleType {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "mutually_exclusive" => Ok(SodRuleType::MutuallyExclusive),
+            "max_users_per_role" => Ok(SodRuleType::MaxUsersPerRole),
+            "max_roles_per_user" => Ok(SodRuleType::MaxRolesPerUser),
+            _ => Err(AppError::Validation(format!("Invalid SoD rule type: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for SodRuleType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
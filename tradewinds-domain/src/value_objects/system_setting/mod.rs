@@ -1,7 +1,17 @@
+mod setting_definition;
+mod setting_registry;
 mod system_setting_id;
 mod system_setting_key;
 mod system_setting_value;
+mod system_mode;
 
+pub use setting_definition::{
+    SettingCategory, SettingDefinition, SettingRule, SettingType, parse_bool, parse_duration,
+};
+pub use setting_registry::{
+    AUDIT_EXPORT_MAX_ROWS, DEFAULT_PASSWORD, MAINTENANCE_MESSAGE, MAINTENANCE_MODE, READ_ONLY_MODE, SettingRegistry,
+};
+pub use system_mode::{MAINTENANCE_BYPASS_PERMISSION, SystemMode};
 pub use system_setting_id::SystemSettingId;
 pub use system_setting_key::SystemSettingKey;
 pub use system_setting_value::SystemSettingValue;
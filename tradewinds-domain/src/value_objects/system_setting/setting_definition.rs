@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use tradewinds_error::{AppError, AppResult};
+
+use super::SystemSettingValue;
+use crate::entities::audit_log::REDACTED;
+
+/// 系统设置的值类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingType {
+    Bool,
+    Int,
+    /// 时长，可写作秒数或带 s/m/h/d 单位，保存为秒数
+    Duration,
+    String,
+    Json,
+}
+
+impl SettingType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingType::Bool => "bool",
+            SettingType::Int => "int",
+            SettingType::Duration => "duration",
+            SettingType::String => "string",
+            SettingType::Json => "json",
+        }
+    }
+
+    /// 按类型解析原始值并规范化：布尔值统一为 true/false，时长统一为秒数，JSON 去掉多余空白
+    pub fn normalize(&self, raw: &str) -> Result<String, String> {
+        let trimmed = raw.trim();
+        match self {
+            SettingType::Bool => parse_bool(trimmed)
+                .map(|value| value.to_string())
+                .ok_or_else(|| format!("Expected a boolean, got {}", raw)),
+            SettingType::Int => trimmed
+                .parse::<i64>()
+                .map(|value| value.to_string())
+                .map_err(|_| format!("Expected an integer, got {}", raw)),
+            SettingType::Duration => parse_duration(trimmed)
+                .map(|value| value.as_secs().to_string())
+                .ok_or_else(|| format!("Expected a duration such as 30s, 15m, 2h or 7d, got {}", raw)),
+            SettingType::String => Ok(raw.to_string()),
+            SettingType::Json => serde_json::from_str::<serde_json::Value>(trimmed)
+                .map(|value| value.to_string())
+                .map_err(|e| format!("Expected JSON: {}", e)),
+        }
+    }
+}
+
+impl fmt::Display for SettingType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+pub fn parse_bool(raw: &str) -> Option<bool> {
+    match raw.to_lowercase().as_str() {
+        "true" | "1" | "yes" | "on" => Some(true),
+        "false" | "0" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
+
+/// 解析时长，纯数字按秒计
+pub fn parse_duration(raw: &str) -> Option<Duration> {
+    let (number, unit) = match raw.find(|c: char| !c.is_ascii_digit()) {
+        Some(index) => raw.split_at(index),
+        None => (raw, "s"),
+    };
+    let number: u64 = number.parse().ok()?;
+    let seconds = match unit {
+        "s" => number,
+        "m" => number.checked_mul(60)?,
+        "h" => number.checked_mul(3600)?,
+        "d" => number.checked_mul(86_400)?,
+        _ => return None,
+    };
+    Some(Duration::from_secs(seconds))
+}
+
+/// 系统设置分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SettingCategory {
+    /// 账号与密码
+    Account,
+    /// 安全
+    Security,
+    /// 审计
+    Audit,
+    /// 系统运行
+    System,
+}
+
+impl SettingCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SettingCategory::Account => "account",
+            SettingCategory::Security => "security",
+            SettingCategory::Audit => "audit",
+            SettingCategory::System => "system",
+        }
+    }
+}
+
+impl FromStr for SettingCategory {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "account" => Ok(SettingCategory::Account),
+            "security" => Ok(SettingCategory::Security),
+            "audit" => Ok(SettingCategory::Audit),
+            "system" => Ok(SettingCategory::System),
+            _ => Err(AppError::Validation(format!("Invalid setting category: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for SettingCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// 规范化之后的取值校验规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingRule {
+    Any,
+    /// 整数或时长（秒）的取值范围，含两端
+    Range {
+        min: i64,
+        max: i64,
+    },
+    /// 字符串长度（按字符计）范围，含两端
+    Length {
+        min: usize,
+        max: usize,
+    },
+    /// 只能取列出的值之一
+    OneOf(&'static [&'static str]),
+}
+
+impl SettingRule {
+    fn check(&self, value: &str) -> Result<(), String> {
+        match *self {
+            SettingRule::Any => Ok(()),
+            SettingRule::Range { min, max } => match value.parse::<i64>() {
+                Ok(number) if (min..=max).contains(&number) => Ok(()),
+                _ => Err(format!("Value must be between {} and {}", min, max)),
+            },
+            SettingRule::Length { min, max } => {
+                if (min..=max).contains(&value.chars().count()) {
+                    Ok(())
+                } else {
+                    Err(format!("Value length must be between {} and {}", min, max))
+                }
+            }
+            SettingRule::OneOf(options) => {
+                if options.contains(&value) {
+                    Ok(())
+                } else {
+                    Err(format!("Value must be one of: {}", options.join(", ")))
+                }
+            }
+        }
+    }
+}
+
+/// 代码中声明的系统设置
+///
+/// 数据库中只保存被修改过的值，未修改的设置取声明的默认值。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingDefinition {
+    pub key: &'static str,
+    pub setting_type: SettingType,
+    pub default_value: &'static str,
+    pub rule: SettingRule,
+    pub category: SettingCategory,
+    /// 敏感设置在列表与审计中不展示实际值
+    pub sensitive: bool,
+    /// 平台级设置对全部租户生效，只能在平台默认租户内修改
+    pub platform: bool,
+    pub description: &'static str,
+}
+
+impl SettingDefinition {
+    /// 按声明的类型与规则校验写入值，返回规范化后的值
+    pub fn validate(&self, raw: &str) -> AppResult<SystemSettingValue> {
+        let value = self
+            .setting_type
+            .normalize(raw)
+            .and_then(|value| self.rule.check(&value).map(|_| value))
+            .map_err(|e| AppError::Validation(format!("Invalid value for setting {}: {}", self.key, e)))?;
+        SystemSettingValue::new(value).map_err(AppError::Validation)
+    }
+
+    /// 对外展示的值，敏感设置以占位值代替
+    pub fn display_value<'a>(&self, value: &'a str) -> &'a str {
+        if self.sensitive { REDACTED } else { value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(setting_type: SettingType, rule: SettingRule) -> SettingDefinition {
+        SettingDefinition {
+            key: "test_setting",
+            setting_type,
+            default_value: "",
+            rule,
+            category: SettingCategory::System,
+            sensitive: false,
+            platform: false,
+            description: "",
+        }
+    }
+
+    #[test]
+    fn normalizes_booleans_and_durations() {
+        assert_eq!(SettingType::Bool.normalize(" Yes ").unwrap(), "true");
+        assert_eq!(SettingType::Bool.normalize("0").unwrap(), "false");
+        assert!(SettingType::Bool.normalize("maybe").is_err());
+        assert_eq!(SettingType::Duration.normalize("15m").unwrap(), "900");
+        assert_eq!(SettingType::Duration.normalize("120").unwrap(), "120");
+        assert!(SettingType::Duration.normalize("2w").is_err());
+        assert!(SettingType::Duration.normalize("-5s").is_err());
+    }
+
+    #[test]
+    fn normalizes_json() {
+        assert_eq!(SettingType::Json.normalize("{ \"a\": [1, 2] }").unwrap(), "{\"a\":[1,2]}");
+        assert!(SettingType::Json.normalize("{a: 1}").is_err());
+    }
+
+    #[test]
+    fn validate_applies_rule_after_normalizing() {
+        let timeout = definition(SettingType::Duration, SettingRule::Range { min: 60, max: 3600 });
+        assert_eq!(timeout.validate("30m").unwrap().value(), "1800");
+        assert!(timeout.validate("2h").is_err());
+
+        let mode = definition(SettingType::String, SettingRule::OneOf(&["strict", "lenient"]));
+        assert!(mode.validate("strict").is_ok());
+        assert!(mode.validate("other").is_err());
+
+        let name = definition(SettingType::String, SettingRule::Length { min: 2, max: 3 });
+        assert!(name.validate("密码").is_ok());
+        assert!(name.validate("a").is_err());
+    }
+
+    #[test]
+    fn sensitive_values_are_masked() {
+        let mut secret = definition(SettingType::String, SettingRule::Any);
+        assert_eq!(secret.display_value("abc"), "abc");
+        secret.sensitive = true;
+        assert_eq!(secret.display_value("abc"), REDACTED);
+    }
+}
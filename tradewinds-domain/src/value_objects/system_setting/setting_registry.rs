@@ -0,0 +1,119 @@
+use tradewinds_error::{AppError, AppResult};
+
+use super::setting_definition::{SettingCategory, SettingDefinition, SettingRule, SettingType};
+
+/// 用户重置密码后的初始密码
+pub const DEFAULT_PASSWORD: &str = "default_password";
+/// 审计日志单次导出的最大条数
+pub const AUDIT_EXPORT_MAX_ROWS: &str = "audit_export_max_rows";
+/// 维护模式开关
+pub const MAINTENANCE_MODE: &str = "maintenance_mode";
+/// 维护模式提示
+pub const MAINTENANCE_MESSAGE: &str = "maintenance_message";
+/// 只读模式开关
+///
+/// 登录接口不受只读模式限制，登录时仍会写入登录日志并更新用户最近登录信息；
+/// 这两项写入失败只记录警告、不影响登录。维护模式同样放行登录，如需完全停止写库须在数据库层面设为只读。
+pub const READ_ONLY_MODE: &str = "read_only_mode";
+
+const DEFINITIONS: &[SettingDefinition] = &[
+    SettingDefinition {
+        key: DEFAULT_PASSWORD,
+        setting_type: SettingType::String,
+        default_value: "123456",
+        rule: SettingRule::Length { min: 6, max: 64 },
+        category: SettingCategory::Account,
+        sensitive: true,
+        platform: false,
+        description: "用户重置密码默认值",
+    },
+    SettingDefinition {
+        key: AUDIT_EXPORT_MAX_ROWS,
+        setting_type: SettingType::Int,
+        default_value: "10000",
+        rule: SettingRule::Range { min: 1, max: 100_000 },
+        category: SettingCategory::Audit,
+        sensitive: false,
+        platform: false,
+        description: "审计日志单次导出的最大条数",
+    },
+    SettingDefinition {
+        key: MAINTENANCE_MODE,
+        setting_type: SettingType::Bool,
+        default_value: "false",
+        rule: SettingRule::Any,
+        category: SettingCategory::System,
+        sensitive: false,
+        platform: true,
+        description: "维护模式，开启后除持有豁免权限的用户外所有请求返回 503",
+    },
+    SettingDefinition {
+        key: MAINTENANCE_MESSAGE,
+        setting_type: SettingType::String,
+        default_value: "系统维护中，请稍后再试",
+        rule: SettingRule::Length { min: 1, max: 255 },
+        category: SettingCategory::System,
+        sensitive: false,
+        platform: true,
+        description: "维护模式下返回给用户的提示",
+    },
+    SettingDefinition {
+        key: READ_ONLY_MODE,
+        setting_type: SettingType::Bool,
+        default_value: "false",
+        rule: SettingRule::Any,
+        category: SettingCategory::System,
+        sensitive: false,
+        platform: true,
+        description: "只读模式，开启后除持有豁免权限的用户外拒绝所有非 GET 请求；登录仍会写入登录日志与最近登录信息",
+    },
+];
+
+/// 系统设置登记表
+///
+/// 可读写的设置都在此声明，未声明的键不能写入。
+pub struct SettingRegistry;
+
+impl SettingRegistry {
+    /// 全部设置，按声明顺序
+    pub fn all() -> &'static [SettingDefinition] {
+        DEFINITIONS
+    }
+
+    pub fn find(key: &str) -> Option<&'static SettingDefinition> {
+        DEFINITIONS.iter().find(|definition| definition.key == key)
+    }
+
+    pub fn require(key: &str) -> AppResult<&'static SettingDefinition> {
+        Self::find(key).ok_or_else(|| AppError::NotFound(format!("Unknown setting: {}", key)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn keys_are_unique() {
+        let keys: HashSet<&str> = SettingRegistry::all().iter().map(|definition| definition.key).collect();
+        assert_eq!(keys.len(), SettingRegistry::all().len());
+    }
+
+    #[test]
+    fn mode_switches_are_platform_level_and_off_by_default() {
+        for key in [MAINTENANCE_MODE, MAINTENANCE_MESSAGE, READ_ONLY_MODE] {
+            assert!(SettingRegistry::require(key).unwrap().platform, "{} must be platform level", key);
+        }
+        assert_eq!(SettingRegistry::require(MAINTENANCE_MODE).unwrap().default_value, "false");
+        assert_eq!(SettingRegistry::require(READ_ONLY_MODE).unwrap().default_value, "false");
+    }
+
+    #[test]
+    fn defaults_pass_their_own_validation() {
+        for definition in SettingRegistry::all() {
+            let value = definition.validate(definition.default_value).unwrap();
+            assert_eq!(value.value(), definition.default_value, "default of {} is not normalized", definition.key);
+        }
+    }
+}
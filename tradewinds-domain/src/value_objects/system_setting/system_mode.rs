@@ -0,0 +1,65 @@
+use tradewinds_error::{AppError, AppResult};
+
+/// 维护模式与只读模式下仍放行的权限
+pub const MAINTENANCE_BYPASS_PERMISSION: &str = "maintenance:bypass";
+
+/// 维护模式与只读模式的当前状态
+///
+/// 维护模式拒绝全部请求；只读模式只拒绝写请求，读请求照常处理。两者同时开启时按维护模式处理。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SystemMode {
+    pub maintenance: bool,
+    /// 维护模式下返回给用户的提示
+    pub message: String,
+    pub read_only: bool,
+}
+
+impl SystemMode {
+    /// 当前模式是否允许该请求，`is_read` 表示请求不修改数据
+    pub fn check(&self, is_read: bool) -> AppResult<()> {
+        if self.maintenance {
+            return Err(AppError::Maintenance(self.message.clone()));
+        }
+        if self.read_only && !is_read {
+            return Err(AppError::ReadOnly("The system is in read-only mode, only GET requests are accepted".into()));
+        }
+        Ok(())
+    }
+
+    /// 是否有任一模式开启
+    pub fn is_restricted(&self) -> bool {
+        self.maintenance || self.read_only
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mode(maintenance: bool, read_only: bool) -> SystemMode {
+        SystemMode { maintenance, message: "维护中".into(), read_only }
+    }
+
+    #[test]
+    fn normal_mode_allows_everything() {
+        assert!(!mode(false, false).is_restricted());
+        assert!(mode(false, false).check(true).is_ok());
+        assert!(mode(false, false).check(false).is_ok());
+    }
+
+    #[test]
+    fn read_only_rejects_writes() {
+        assert!(mode(false, true).check(true).is_ok());
+        assert!(matches!(mode(false, true).check(false), Err(AppError::ReadOnly(_))));
+    }
+
+    #[test]
+    fn maintenance_rejects_everything_with_message() {
+        for is_read in [true, false] {
+            match mode(true, true).check(is_read) {
+                Err(AppError::Maintenance(message)) => assert_eq!(message, "维护中"),
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+pub mod tenant_code;
+pub mod tenant_host;
+pub mod tenant_id;
+pub mod tenant_name;
+pub mod tenant_status;
+
+pub use tenant_code::TenantCode;
+pub use tenant_host::TenantHost;
+pub use tenant_id::TenantId;
+pub use tenant_name::TenantName;
+pub use tenant_status::TenantStatus;
@@ -0,0 +1,44 @@
+use std::{fmt, str::FromStr};
+
+use derive_more::Deref;
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// 租户绑定的访问域名，请求的 Host 与之匹配时识别为该租户
+///
+/// 统一保存为小写且不含端口
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Deref)]
+pub struct TenantHost(String);
+
+impl TenantHost {
+    pub fn new<S: Into<String>>(value: S) -> AppResult<Self> {
+        let value = value.into();
+        let host = value.trim().split(':').next().unwrap_or_default().to_ascii_lowercase();
+        if host.is_empty() || host.len() > 255 {
+            return Err(AppError::Validation("Tenant host must be 1-255 characters".into()));
+        }
+        if !host.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.') {
+            return Err(AppError::Validation(format!("Invalid tenant host: {}", value)));
+        }
+        Ok(Self(host))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for TenantHost {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl fmt::Display for TenantHost {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
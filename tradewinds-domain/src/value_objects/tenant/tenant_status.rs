@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum TenantStatus {
+    #[default]
+    Active,
+    Inactive,
+    Deleted,
+}
+
+impl TenantStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(TenantStatus::Active),
+            1 => Ok(TenantStatus::Inactive),
+            2 => Ok(TenantStatus::Deleted),
+            _ => Err(AppError::Validation("Tenant status can only be 0, 1, 2".to_string())),
+        }
+    }
+
+    pub fn to_i32(&self) -> i32 {
+        match self {
+            TenantStatus::Active => 0,
+            TenantStatus::Inactive => 1,
+            TenantStatus::Deleted => 2,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, TenantStatus::Active)
+    }
+
+    pub fn is_inactive(&self) -> bool {
+        matches!(self, TenantStatus::Inactive)
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        matches!(self, TenantStatus::Deleted)
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+}
+
+impl FromStr for TenantStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(TenantStatus::Active),
+            "inactive" => Ok(TenantStatus::Inactive),
+            "deleted" => Ok(TenantStatus::Deleted),
+            _ => Err(AppError::Validation(format!("Invalid tenant status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for TenantStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_i32())
+    }
+}
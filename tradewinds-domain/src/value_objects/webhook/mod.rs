@@ -0,0 +1,9 @@
+pub mod webhook_delivery_id;
+pub mod webhook_delivery_status;
+pub mod webhook_id;
+pub mod webhook_status;
+
+pub use webhook_delivery_id::WebhookDeliveryId;
+pub use webhook_delivery_status::WebhookDeliveryStatus;
+pub use webhook_id::WebhookId;
+pub use webhook_status::WebhookStatus;
@@ -0,0 +1,67 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// Webhook 投递状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum WebhookDeliveryStatus {
+    /// 待投递，含等待重试
+    #[default]
+    Pending = 0,
+    /// 投递成功
+    Succeeded = 1,
+    /// 超过最大重试次数或端点已停用、删除，不再自动重试
+    Failed = 2,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(WebhookDeliveryStatus::Pending),
+            1 => Ok(WebhookDeliveryStatus::Succeeded),
+            2 => Ok(WebhookDeliveryStatus::Failed),
+            _ => Err(AppError::Validation("Webhook delivery status can only be 0, 1 or 2".to_string())),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookDeliveryStatus::Pending => "pending",
+            WebhookDeliveryStatus::Succeeded => "succeeded",
+            WebhookDeliveryStatus::Failed => "failed",
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, WebhookDeliveryStatus::Pending)
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, WebhookDeliveryStatus::Failed)
+    }
+}
+
+impl FromStr for WebhookDeliveryStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pending" => Ok(WebhookDeliveryStatus::Pending),
+            "succeeded" => Ok(WebhookDeliveryStatus::Succeeded),
+            "failed" => Ok(WebhookDeliveryStatus::Failed),
+            _ => Err(AppError::Validation(format!("Invalid webhook delivery status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
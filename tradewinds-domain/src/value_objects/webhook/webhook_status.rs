@@ -0,0 +1,58 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use tradewinds_error::{AppError, AppResult};
+
+/// Webhook 状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum WebhookStatus {
+    /// 启用，接收事件
+    #[default]
+    Active = 0,
+    /// 停用，手动停用或连续投递失败后自动停用
+    Disabled = 1,
+}
+
+impl WebhookStatus {
+    pub fn from_i32(value: i32) -> AppResult<Self> {
+        match value {
+            0 => Ok(WebhookStatus::Active),
+            1 => Ok(WebhookStatus::Disabled),
+            _ => Err(AppError::Validation("Webhook status can only be 0 or 1".to_string())),
+        }
+    }
+
+    pub fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookStatus::Active => "active",
+            WebhookStatus::Disabled => "disabled",
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        matches!(self, WebhookStatus::Active)
+    }
+}
+
+impl FromStr for WebhookStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "active" => Ok(WebhookStatus::Active),
+            "disabled" => Ok(WebhookStatus::Disabled),
+            _ => Err(AppError::Validation(format!("Invalid webhook status: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for WebhookStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -44,6 +44,12 @@ pub enum AppError {
     
     #[error("Database error: {0}")]
     DatabaseError(String),
+
+    #[error("Maintenance: {0}")]
+    Maintenance(String),
+
+    #[error("Read-only: {0}")]
+    ReadOnly(String),
 }
 
 impl From<String> for AppError {
@@ -142,17 +148,28 @@ impl IntoResponse for AppError {
                 error!("Database error: {}", msg);
                 (StatusCode::INTERNAL_SERVER_ERROR, msg.clone())
             }
+            AppError::Maintenance(msg) => {
+                warn!("Rejected during maintenance: {}", msg);
+                (StatusCode::SERVICE_UNAVAILABLE, msg.clone())
+            }
+            AppError::ReadOnly(msg) => {
+                warn!("Rejected in read-only mode: {}", msg);
+                (StatusCode::LOCKED, msg.clone())
+            }
         };
 
         // 记录完整的错误信息用于调试
         error!("Error response: status={}, error={:?}", status.as_u16(), self);
 
-        let error_type = match status {
-            StatusCode::UNAUTHORIZED => "auth_error",
-            StatusCode::FORBIDDEN => "forbidden",
-            StatusCode::NOT_FOUND => "not_found",
-            StatusCode::BAD_REQUEST => "validation_error",
-            StatusCode::CONFLICT => "conflict",
+        // 维护模式返回 503 表示暂不可用；只读模式是有意拒绝写入，返回 423 而非 503，避免被负载均衡判为实例故障
+        let error_type = match (&self, status) {
+            (AppError::Maintenance(_), _) => "maintenance",
+            (AppError::ReadOnly(_), _) => "read_only",
+            (_, StatusCode::UNAUTHORIZED) => "auth_error",
+            (_, StatusCode::FORBIDDEN) => "forbidden",
+            (_, StatusCode::NOT_FOUND) => "not_found",
+            (_, StatusCode::BAD_REQUEST) => "validation_error",
+            (_, StatusCode::CONFLICT) => "conflict",
             _ => "system_error",
         };
 
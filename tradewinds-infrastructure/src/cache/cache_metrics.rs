@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// 缓存的累计指标（进程内，重启后清零）
+#[derive(Debug, Default)]
+pub struct CacheMetrics {
+    local_hits: AtomicU64,
+    redis_hits: AtomicU64,
+    misses: AtomicU64,
+    loads: AtomicU64,
+    errors: AtomicU64,
+    invalidations: AtomicU64,
+}
+
+/// 缓存指标快照
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct CacheMetricsSnapshot {
+    /// 进程内缓存命中次数
+    pub local_hits_total: u64,
+    /// 进程内缓存未命中、Redis 命中次数
+    pub redis_hits_total: u64,
+    /// 两层均未命中次数
+    pub misses_total: u64,
+    /// `get_or_set` 执行回源的次数
+    pub loads_total: u64,
+    /// Redis 读写失败次数
+    pub errors_total: u64,
+    /// 收到其他实例失效通知的次数
+    pub invalidations_total: u64,
+    /// 进程内缓存当前条目数
+    pub local_entries: usize,
+}
+
+impl CacheMetrics {
+    pub(crate) fn local_hit(&self) {
+        self.local_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn redis_hit(&self) {
+        self.redis_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn load(&self) {
+        self.loads.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn invalidation(&self) {
+        self.invalidations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self, local_entries: usize) -> CacheMetricsSnapshot {
+        CacheMetricsSnapshot {
+            local_hits_total: self.local_hits.load(Ordering::Relaxed),
+            redis_hits_total: self.redis_hits.load(Ordering::Relaxed),
+            misses_total: self.misses.load(Ordering::Relaxed),
+            loads_total: self.loads.load(Ordering::Relaxed),
+            errors_total: self.errors.load(Ordering::Relaxed),
+            invalidations_total: self.invalidations.load(Ordering::Relaxed),
+            local_entries,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+struct Entry {
+    value: String,
+    expires_at: Instant,
+    /// 最近一次使用的序号，对应 `order` 中的键
+    used: u64,
+}
+
+#[derive(Default)]
+struct Inner {
+    entries: HashMap<String, Entry>,
+    /// 按最近使用顺序排列的键，最小的序号最久未使用
+    order: BTreeMap<u64, String>,
+    clock: u64,
+}
+
+impl Inner {
+    fn touch(&mut self, key: &str) -> Option<String> {
+        self.clock += 1;
+        let clock = self.clock;
+        let entry = self.entries.get_mut(key)?;
+        self.order.remove(&entry.used);
+        entry.used = clock;
+        self.order.insert(clock, key.to_string());
+        Some(entry.value.clone())
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.used);
+        }
+    }
+}
+
+/// 进程内缓存层
+///
+/// 按条目数量上限淘汰最久未使用的条目，每个条目另有过期时间，过期后在读取时移除。
+/// 保存序列化后的值，与 Redis 中的内容一致。
+pub struct LocalCache {
+    inner: Mutex<Inner>,
+    capacity: usize,
+    max_ttl: Duration,
+}
+
+impl LocalCache {
+    pub fn new(capacity: usize, max_ttl: Duration) -> Self {
+        Self { inner: Mutex::new(Inner::default()), capacity: capacity.max(1), max_ttl }
+    }
+
+    pub fn get(&self, key: &str) -> Option<String> {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        let expired = inner.entries.get(key)?.expires_at <= Instant::now();
+        if expired {
+            inner.remove(key);
+            return None;
+        }
+        inner.touch(key)
+    }
+
+    /// 写入条目，保留时间取 `ttl` 与本层上限中较短的
+    pub fn insert(&self, key: &str, value: String, ttl: Duration) {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        inner.remove(key);
+        inner.clock += 1;
+        let used = inner.clock;
+        let expires_at = Instant::now() + ttl.min(self.max_ttl);
+        inner.entries.insert(key.to_string(), Entry { value, expires_at, used });
+        inner.order.insert(used, key.to_string());
+        while inner.entries.len() > self.capacity {
+            let Some((_, oldest)) = inner.order.pop_first() else {
+                break;
+            };
+            inner.entries.remove(&oldest);
+        }
+    }
+
+    pub fn remove(&self, key: &str) {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner).remove(key);
+    }
+
+    pub fn clear(&self) {
+        let mut inner = self.inner.lock().unwrap_or_else(PoisonError::into_inner);
+        inner.entries.clear();
+        inner.order.clear();
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner).entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
@@ -1,4 +1,11 @@
 // 缓存模块
-// 用于处理系统缓存逻辑
+// 用于处理系统缓存逻辑：Redis 缓存、可选的进程内缓存层、命中指标与仓储缓存装饰器
 
+pub mod cache_metrics;
+pub mod local_cache;
 pub mod redis_cache;
+pub mod repositories;
+
+pub use cache_metrics::{CacheMetrics, CacheMetricsSnapshot};
+pub use local_cache::LocalCache;
+pub use redis_cache::{Cache, CacheExt, CacheLoader, RedisCache};
@@ -1,61 +1,543 @@
 use async_trait::async_trait;
-use redis::{Client, Commands, Connection};
-use serde::{Serialize, de::DeserializeOwned};
-
+use futures::StreamExt;
+use redis::aio::{ConnectionManager, ConnectionManagerConfig};
+use redis::{AsyncCommands, Client, Script};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, OnceCell};
 use tradewinds_error::{AppError, AppResult};
+use uuid::Uuid;
+
+use crate::cache::cache_metrics::{CacheMetrics, CacheMetricsSnapshot};
+use crate::cache::local_cache::LocalCache;
+use crate::config::CacheConfig;
+
+/// 回源加载，返回序列化后的值
+pub type CacheLoader<'a> = Pin<Box<dyn Future<Output = AppResult<String>> + Send + 'a>>;
+
+/// 仍由持有者持有时释放回源锁
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
 
+/// 建立连接与等待响应的超时时间
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(2);
+/// 失效通知订阅中断后重新订阅前的等待时间
+const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+/// 其他实例回源期间轮询缓存的间隔
+const LOAD_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// 缓存
+///
+/// 值以字符串保存，类型化的读写见 [`CacheExt`]。
 #[async_trait]
 pub trait Cache: Send + Sync {
-    async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T, ttl_seconds: u64) -> AppResult<()>;
-    async fn get<T: DeserializeOwned + Send>(&self, key: &str) -> AppResult<Option<T>>;
-    async fn delete(&self, key: &str) -> AppResult<()>;
+    async fn get_raw(&self, key: &str) -> AppResult<Option<String>>;
+
+    /// 批量读取，结果与 `keys` 一一对应
+    async fn mget_raw(&self, keys: &[String]) -> AppResult<Vec<Option<String>>>;
+
+    async fn set_raw(&self, key: &str, value: String, ttl: Duration) -> AppResult<()>;
+
+    /// 批量写入，全部条目使用同一过期时间
+    async fn mset_raw(&self, entries: Vec<(String, String)>, ttl: Duration) -> AppResult<()>;
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        self.delete_many(&[key.to_string()]).await
+    }
+
+    async fn delete_many(&self, keys: &[String]) -> AppResult<()>;
+
+    /// 读取缓存，未命中时调用 `loader` 回源并写入，同一时刻每个键只有一个回源
+    ///
+    /// 缓存不可用时直接回源，不返回缓存错误。
+    async fn get_or_set_raw<'a>(
+        &'a self,
+        key: &'a str,
+        ttl: Duration,
+        loader: &'a (dyn Fn() -> CacheLoader<'a> + Send + Sync),
+    ) -> AppResult<String>;
+
+    fn metrics(&self) -> CacheMetricsSnapshot;
+}
+
+/// 类型化的缓存读写，值以 JSON 序列化保存
+///
+/// 无法反序列化的缓存值（如升级后结构变化）视为未命中。
+#[async_trait]
+pub trait CacheExt: Cache {
+    async fn get<T: DeserializeOwned + Send>(&self, key: &str) -> AppResult<Option<T>> {
+        Ok(self.get_raw(key).await?.and_then(|value| decode(key, &value)))
+    }
+
+    async fn mget<T: DeserializeOwned + Send>(&self, keys: &[String]) -> AppResult<Vec<Option<T>>> {
+        let values = self.mget_raw(keys).await?;
+        Ok(keys.iter().zip(values).map(|(key, value)| value.and_then(|value| decode(key, &value))).collect())
+    }
+
+    async fn set<T: Serialize + Sync>(&self, key: &str, value: &T, ttl: Duration) -> AppResult<()> {
+        self.set_raw(key, encode(value)?, ttl).await
+    }
+
+    async fn mset<T: Serialize + Sync>(&self, entries: &[(String, T)], ttl: Duration) -> AppResult<()> {
+        let entries =
+            entries.iter().map(|(key, value)| Ok((key.clone(), encode(value)?))).collect::<AppResult<Vec<_>>>()?;
+        self.mset_raw(entries, ttl).await
+    }
+
+    async fn get_or_set<T, F, Fut>(&self, key: &str, ttl: Duration, loader: F) -> AppResult<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = AppResult<T>> + Send,
+    {
+        let load = || -> CacheLoader<'_> {
+            let loading = loader();
+            Box::pin(async move { encode(&loading.await?) })
+        };
+        let value = self.get_or_set_raw(key, ttl, &load).await?;
+        if let Some(value) = decode(key, &value) {
+            return Ok(value);
+        }
+        // 缓存中的值无法反序列化，删除后重新回源
+        if let Err(e) = self.delete(key).await {
+            tracing::warn!("Failed to delete invalid cache entry {}: {}", key, e);
+        }
+        let value = loader().await?;
+        if let Err(e) = self.set(key, &value, ttl).await {
+            tracing::warn!("Failed to cache {}: {}", key, e);
+        }
+        Ok(value)
+    }
 }
 
+impl<C: Cache + ?Sized> CacheExt for C {}
+
+fn encode<T: Serialize + ?Sized>(value: &T) -> AppResult<String> {
+    serde_json::to_string(value).map_err(|e| AppError::System(format!("Serialization error: {}", e)))
+}
+
+fn decode<T: DeserializeOwned>(key: &str, value: &str) -> Option<T> {
+    serde_json::from_str(value)
+        .inspect_err(|e| tracing::warn!("Ignoring cache entry {} that cannot be deserialized: {}", key, e))
+        .ok()
+}
+
+/// 跨实例失效通知
+#[derive(Serialize, Deserialize)]
+struct Invalidation {
+    /// 发出通知的实例，实例忽略自己发出的通知
+    origin: String,
+    keys: Vec<String>,
+}
+
+/// Redis 缓存
+///
+/// 通过自动重连的多路复用连接访问 Redis，首次使用时建立连接。
+/// 可选的进程内缓存层位于 Redis 之前：写入与删除时先更新 Redis，再通过发布订阅通知其他实例移除本地副本。
+/// 进程内缓存层只在失效通知订阅正常时使用，订阅中断期间直接读取 Redis，重新订阅后清空本地副本；
+/// 通知与读取并发时本地副本最多滞后 `local_ttl_secs`。
+///
+/// `get_or_set` 在进程内按键合并并发回源；跨实例由 Redis 回源锁协调，
+/// 未取得锁的实例轮询等待持有者写入，超过 `load_lock_ttl_ms` 仍未写入时自行回源。
 pub struct RedisCache {
     client: Client,
+    connection: OnceCell<ConnectionManager>,
+    key_prefix: String,
+    local: Option<Arc<LocalCache>>,
+    /// 失效通知订阅是否正常
+    subscribed: Arc<AtomicBool>,
+    channel: String,
+    origin: String,
+    load_lock_ttl: Duration,
+    /// 进行中的回源，按键合并
+    loads: StdMutex<HashMap<String, Arc<Mutex<()>>>>,
+    metrics: Arc<CacheMetrics>,
 }
 
 impl RedisCache {
-    pub fn new(redis_url: &str) -> AppResult<Self> {
-        let client = Client::open(redis_url).map_err(|e| AppError::System(format!("Redis connection error: {}", e)))?;
-        Ok(Self { client })
+    pub fn new(redis_url: &str, config: &CacheConfig) -> AppResult<Self> {
+        let local = config
+            .local_enabled
+            .then(|| Arc::new(LocalCache::new(config.local_capacity, Duration::from_secs(config.local_ttl_secs))));
+        Ok(Self {
+            client: Client::open(redis_url)?,
+            connection: OnceCell::new(),
+            key_prefix: config.key_prefix.clone(),
+            local,
+            subscribed: Arc::new(AtomicBool::new(false)),
+            channel: config.invalidation_channel.clone(),
+            origin: Uuid::new_v4().to_string(),
+            load_lock_ttl: Duration::from_millis(config.load_lock_ttl_ms),
+            loads: StdMutex::new(HashMap::new()),
+            metrics: Arc::new(CacheMetrics::default()),
+        })
+    }
+
+    /// 启用进程内缓存层时订阅其他实例的失效通知，订阅成功前不使用进程内缓存层
+    pub fn start(&self) {
+        let Some(local) = self.local.clone() else {
+            return;
+        };
+        let client = self.client.clone();
+        let channel = self.channel.clone();
+        let origin = self.origin.clone();
+        let subscribed = self.subscribed.clone();
+        let metrics = self.metrics.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = listen(&client, &channel, &origin, &local, &subscribed, &metrics).await {
+                    tracing::warn!("Cache invalidation subscription stopped: {}", e);
+                }
+                subscribed.store(false, Ordering::Release);
+                tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+            }
+        });
+    }
+
+    async fn connection(&self) -> AppResult<ConnectionManager> {
+        let connection = self
+            .connection
+            .get_or_try_init(|| async {
+                let config = ConnectionManagerConfig::new()
+                    .set_number_of_retries(1)
+                    .set_connection_timeout(CONNECT_TIMEOUT)
+                    .set_response_timeout(RESPONSE_TIMEOUT);
+                ConnectionManager::new_with_config(self.client.clone(), config).await
+            })
+            .await
+            .map_err(|e| self.redis_error("connection", e))?;
+        Ok(connection.clone())
+    }
+
+    fn redis_error(&self, action: &str, e: redis::RedisError) -> AppError {
+        self.metrics.error();
+        AppError::System(format!("Redis {} error: {}", action, e))
+    }
+
+    fn redis_key(&self, key: &str) -> String {
+        format!("{}{}", self.key_prefix, key)
+    }
+
+    /// 可用的进程内缓存层
+    fn local(&self) -> Option<&LocalCache> {
+        self.local.as_deref().filter(|_| self.subscribed.load(Ordering::Acquire))
+    }
+
+    /// 读取 Redis 并回填进程内缓存层，不计入命中指标
+    async fn fetch(&self, key: &str) -> AppResult<Option<String>> {
+        let mut conn = self.connection().await?;
+        let value: Option<String> = conn.get(self.redis_key(key)).await.map_err(|e| self.redis_error("get", e))?;
+        if let (Some(local), Some(value)) = (self.local(), &value) {
+            local.insert(key, value.clone(), Duration::MAX);
+        }
+        Ok(value)
+    }
+
+    /// 写入 Redis 与进程内缓存层，不发送失效通知
+    async fn store(&self, key: &str, value: &str, ttl: Duration) -> AppResult<()> {
+        let mut conn = self.connection().await?;
+        let _: () =
+            conn.pset_ex(self.redis_key(key), value, ttl_millis(ttl)).await.map_err(|e| self.redis_error("set", e))?;
+        if let Some(local) = self.local() {
+            local.insert(key, value.to_string(), ttl);
+        }
+        Ok(())
+    }
+
+    /// 通知其他实例移除本地副本，失败时仅记录日志
+    async fn publish_invalidation(&self, keys: &[String]) {
+        let Ok(payload) = encode(&Invalidation { origin: self.origin.clone(), keys: keys.to_vec() }) else {
+            return;
+        };
+        let result = match self.connection().await {
+            Ok(mut conn) => {
+                conn.publish::<_, _, ()>(&self.channel, payload).await.map_err(|e| self.redis_error("publish", e))
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to publish cache invalidation of {} key(s): {}", keys.len(), e);
+        }
     }
 
-    fn get_conn(&self) -> AppResult<Connection> {
-        self.client.get_connection().map_err(|e| AppError::System(format!("Redis connection error: {}", e)))
+    async fn load<'a>(&self, loader: &(dyn Fn() -> CacheLoader<'a> + Send + Sync)) -> AppResult<String> {
+        self.metrics.load();
+        loader().await
+    }
+
+    /// 回源并写入缓存，写入失败时仅记录日志
+    async fn load_and_store<'a>(
+        &self,
+        key: &str,
+        ttl: Duration,
+        loader: &(dyn Fn() -> CacheLoader<'a> + Send + Sync),
+    ) -> AppResult<String> {
+        let value = self.load(loader).await?;
+        if let Err(e) = self.store(key, &value, ttl).await {
+            tracing::warn!("Failed to cache {}: {}", key, e);
+        }
+        Ok(value)
+    }
+
+    fn load_lock_key(&self, key: &str) -> String {
+        format!("{}#loading", self.redis_key(key))
+    }
+
+    /// 尝试取得跨实例回源锁，已被其他实例持有时返回 `None`
+    async fn acquire_load_lock(&self, key: &str) -> AppResult<Option<String>> {
+        let token = Uuid::new_v4().to_string();
+        let mut conn = self.connection().await?;
+        let acquired: Option<String> = redis::cmd("SET")
+            .arg(self.load_lock_key(key))
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl_millis(self.load_lock_ttl))
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.redis_error("set", e))?;
+        Ok(acquired.map(|_| token))
+    }
+
+    async fn release_load_lock(&self, key: &str, token: &str) {
+        let result = match self.connection().await {
+            Ok(mut conn) => Script::new(RELEASE_SCRIPT)
+                .key(self.load_lock_key(key))
+                .arg(token)
+                .invoke_async::<i64>(&mut conn)
+                .await
+                .map_err(|e| self.redis_error("release", e)),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to release cache load lock of {}: {}", key, e);
+        }
+    }
+
+    /// 等待持有回源锁的实例写入，超时返回 `None`
+    async fn wait_for_load(&self, key: &str) -> AppResult<Option<String>> {
+        let deadline = Instant::now() + self.load_lock_ttl;
+        while Instant::now() < deadline {
+            tokio::time::sleep(LOAD_POLL_INTERVAL).await;
+            if let Some(value) = self.fetch(key).await? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 取得本键的回源合并锁，结束后调用 `finish_load`
+    fn start_load(&self, key: &str) -> Arc<Mutex<()>> {
+        let mut loads = self.loads.lock().unwrap_or_else(PoisonError::into_inner);
+        loads.entry(key.to_string()).or_default().clone()
+    }
+
+    fn finish_load(&self, key: &str, flight: Arc<Mutex<()>>) {
+        let mut loads = self.loads.lock().unwrap_or_else(PoisonError::into_inner);
+        // 只剩表中与本次持有的引用时没有其他等待者
+        if Arc::strong_count(&flight) == 2 {
+            loads.remove(key);
+        }
     }
 }
 
+/// 订阅失效通知直至连接中断
+async fn listen(
+    client: &Client,
+    channel: &str,
+    origin: &str,
+    local: &LocalCache,
+    subscribed: &AtomicBool,
+    metrics: &CacheMetrics,
+) -> AppResult<()> {
+    let mut pubsub = client.get_async_pubsub().await?;
+    pubsub.subscribe(channel).await?;
+    // 订阅之前可能错过了通知
+    local.clear();
+    subscribed.store(true, Ordering::Release);
+
+    let mut messages = pubsub.on_message();
+    while let Some(message) = messages.next().await {
+        let invalidation = message.get_payload::<String>().map_err(AppError::from).and_then(|payload| {
+            serde_json::from_str::<Invalidation>(&payload).map_err(|e| AppError::System(e.to_string()))
+        });
+        match invalidation {
+            Ok(invalidation) if invalidation.origin != origin => {
+                metrics.invalidation();
+                for key in &invalidation.keys {
+                    local.remove(key);
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Ignoring malformed cache invalidation: {}", e),
+        }
+    }
+    Err(AppError::System("Cache invalidation subscription closed".to_string()))
+}
+
+fn ttl_millis(ttl: Duration) -> u64 {
+    (ttl.as_millis() as u64).max(1)
+}
+
 #[async_trait]
 impl Cache for RedisCache {
-    async fn set<T: Serialize + Send + Sync>(&self, key: &str, value: &T, ttl_seconds: u64) -> AppResult<()> {
-        let mut conn = self.get_conn()?;
-        let serialized =
-            serde_json::to_string(value).map_err(|e| AppError::System(format!("Serialization error: {}", e)))?;
-
-        let _: () = conn
-            .set_ex(key, serialized, ttl_seconds)
-            .map_err(|e| AppError::System(format!("Redis set error: {}", e)))?;
+    async fn get_raw(&self, key: &str) -> AppResult<Option<String>> {
+        if let Some(value) = self.local().and_then(|local| local.get(key)) {
+            self.metrics.local_hit();
+            return Ok(Some(value));
+        }
+        let value = self.fetch(key).await?;
+        match value {
+            Some(_) => self.metrics.redis_hit(),
+            None => self.metrics.miss(),
+        }
+        Ok(value)
+    }
+
+    async fn mget_raw(&self, keys: &[String]) -> AppResult<Vec<Option<String>>> {
+        let mut values = Vec::with_capacity(keys.len());
+        let mut missing = Vec::new();
+        for (i, key) in keys.iter().enumerate() {
+            let value = self.local().and_then(|local| local.get(key));
+            match value {
+                Some(_) => self.metrics.local_hit(),
+                None => missing.push(i),
+            }
+            values.push(value);
+        }
+        if missing.is_empty() {
+            return Ok(values);
+        }
+
+        let mut conn = self.connection().await?;
+        let redis_keys: Vec<String> = missing.iter().map(|&i| self.redis_key(&keys[i])).collect();
+        let fetched: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(&redis_keys)
+            .query_async(&mut conn)
+            .await
+            .map_err(|e| self.redis_error("mget", e))?;
+        for (i, value) in missing.into_iter().zip(fetched) {
+            match &value {
+                Some(value) => {
+                    self.metrics.redis_hit();
+                    if let Some(local) = self.local() {
+                        local.insert(&keys[i], value.clone(), Duration::MAX);
+                    }
+                }
+                None => self.metrics.miss(),
+            }
+            values[i] = value;
+        }
+        Ok(values)
+    }
+
+    async fn set_raw(&self, key: &str, value: String, ttl: Duration) -> AppResult<()> {
+        self.store(key, &value, ttl).await?;
+        self.publish_invalidation(&[key.to_string()]).await;
         Ok(())
     }
 
-    async fn get<T: DeserializeOwned + Send>(&self, key: &str) -> AppResult<Option<T>> {
-        let mut conn = self.get_conn()?;
-        let value: Option<String> = conn.get(key).map_err(|e| AppError::System(format!("Redis get error: {}", e)))?;
+    async fn mset_raw(&self, entries: Vec<(String, String)>, ttl: Duration) -> AppResult<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+        let mut conn = self.connection().await?;
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for (key, value) in &entries {
+            pipe.pset_ex(self.redis_key(key), value, ttl_millis(ttl)).ignore();
+        }
+        let _: () = pipe.query_async(&mut conn).await.map_err(|e| self.redis_error("mset", e))?;
 
-        match value {
-            Some(v) => {
-                let deserialized =
-                    serde_json::from_str(&v).map_err(|e| AppError::System(format!("Deserialization error: {}", e)))?;
-                Ok(Some(deserialized))
+        let keys: Vec<String> = entries.iter().map(|(key, _)| key.clone()).collect();
+        if let Some(local) = self.local() {
+            for (key, value) in entries {
+                local.insert(&key, value, ttl);
             }
-            None => Ok(None),
         }
+        self.publish_invalidation(&keys).await;
+        Ok(())
     }
 
-    async fn delete(&self, key: &str) -> AppResult<()> {
-        let mut conn = self.get_conn()?;
-        let _: () = conn.del(key).map_err(|e| AppError::System(format!("Redis delete error: {}", e)))?;
+    async fn delete_many(&self, keys: &[String]) -> AppResult<()> {
+        if keys.is_empty() {
+            return Ok(());
+        }
+        let mut conn = self.connection().await?;
+        let redis_keys: Vec<String> = keys.iter().map(|key| self.redis_key(key)).collect();
+        let _: () = conn.del(redis_keys).await.map_err(|e| self.redis_error("delete", e))?;
+        if let Some(local) = &self.local {
+            for key in keys {
+                local.remove(key);
+            }
+        }
+        self.publish_invalidation(keys).await;
         Ok(())
     }
+
+    async fn get_or_set_raw<'a>(
+        &'a self,
+        key: &'a str,
+        ttl: Duration,
+        loader: &'a (dyn Fn() -> CacheLoader<'a> + Send + Sync),
+    ) -> AppResult<String> {
+        match self.get_raw(key).await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => {}
+            Err(e) => {
+                tracing::warn!("Cache unavailable, loading {} directly: {}", key, e);
+                return self.load(loader).await;
+            }
+        }
+
+        let flight = self.start_load(key);
+        let result = async {
+            let _loading = flight.lock().await;
+            // 等待期间同一进程内的其他回源可能已写入
+            if let Some(value) = self.local().and_then(|local| local.get(key)) {
+                return Ok(value);
+            }
+            let lock = match self.fetch(key).await {
+                Ok(Some(value)) => return Ok(value),
+                Ok(None) => self.acquire_load_lock(key).await,
+                Err(e) => Err(e),
+            };
+            match lock {
+                Ok(Some(token)) => {
+                    let result = self.load_and_store(key, ttl, loader).await;
+                    self.release_load_lock(key, &token).await;
+                    result
+                }
+                Ok(None) => match self.wait_for_load(key).await {
+                    Ok(Some(value)) => Ok(value),
+                    Ok(None) => self.load_and_store(key, ttl, loader).await,
+                    Err(e) => {
+                        tracing::warn!("Cache unavailable, loading {} directly: {}", key, e);
+                        self.load(loader).await
+                    }
+                },
+                Err(e) => {
+                    tracing::warn!("Cache unavailable, loading {} directly: {}", key, e);
+                    self.load(loader).await
+                }
+            }
+        }
+        .await;
+        self.finish_load(key, flight);
+        result
+    }
+
+    fn metrics(&self) -> CacheMetricsSnapshot {
+        self.metrics.snapshot(self.local.as_ref().map_or(0, |local| local.len()))
+    }
 }
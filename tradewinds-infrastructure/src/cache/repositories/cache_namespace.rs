@@ -0,0 +1,143 @@
+use serde::{Serialize, de::DeserializeOwned};
+use std::collections::HashSet;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_error::AppResult;
+use uuid::Uuid;
+
+use crate::cache::{Cache, CacheExt};
+
+/// 代数键的过期时间，过期后生成新代数，相当于一次整体失效
+const GENERATION_TTL: Duration = Duration::from_secs(24 * 3600);
+
+/// 缓存命名空间
+///
+/// 命名空间内的键带有代数，失效时只需更换代数，旧代数下的键不再被读取并随过期时间清除。
+/// 按租户划分时每个租户各自一个代数，否则所有租户共用一个代数。
+/// 读写前先取得代数快照（[`CacheScope`]），回源结果写入同一快照下的键，回源期间发生的失效不会被旧数据覆盖。
+pub struct CacheNamespace {
+    cache: Arc<dyn Cache>,
+    name: &'static str,
+    per_tenant: bool,
+}
+
+impl CacheNamespace {
+    pub fn per_tenant(cache: Arc<dyn Cache>, name: &'static str) -> Self {
+        Self { cache, name, per_tenant: true }
+    }
+
+    pub fn global(cache: Arc<dyn Cache>, name: &'static str) -> Self {
+        Self { cache, name, per_tenant: false }
+    }
+
+    fn generation_key(&self) -> String {
+        if self.per_tenant {
+            format!("{}:{}:generation", self.name, current_tenant_id())
+        } else {
+            format!("{}:generation", self.name)
+        }
+    }
+
+    /// 当前租户在当前代数下的键空间，缓存不可用时返回 `None`，调用方直接访问数据库
+    pub async fn scope(&self) -> Option<CacheScope> {
+        self.try_scope()
+            .await
+            .inspect_err(|e| tracing::warn!("Cache namespace {} unavailable, bypassing cache: {}", self.name, e))
+            .ok()
+    }
+
+    async fn try_scope(&self) -> AppResult<CacheScope> {
+        let key = self.generation_key();
+        let generation = match self.cache.get_raw(&key).await? {
+            Some(generation) => generation,
+            None => {
+                let generation = Uuid::new_v4().simple().to_string();
+                self.cache.set_raw(&key, generation.clone(), GENERATION_TTL).await?;
+                generation
+            }
+        };
+        Ok(CacheScope {
+            cache: self.cache.clone(),
+            prefix: format!("{}:{}:{}:", self.name, current_tenant_id(), generation),
+        })
+    }
+
+    /// 更换代数，使当前租户（全局命名空间为全部租户）的缓存全部失效
+    ///
+    /// 在数据库写入成功后调用，失败时只记录错误，缓存最多滞后一个过期时间。
+    pub async fn invalidate(&self) {
+        let key = self.generation_key();
+        if let Err(e) = self.cache.set_raw(&key, Uuid::new_v4().simple().to_string(), GENERATION_TTL).await {
+            tracing::error!("Failed to invalidate cache namespace {}: {}", key, e);
+        }
+    }
+}
+
+/// 取得代数快照后的键空间
+pub struct CacheScope {
+    cache: Arc<dyn Cache>,
+    prefix: String,
+}
+
+impl CacheScope {
+    fn key(&self, suffix: &str) -> String {
+        format!("{}{}", self.prefix, suffix)
+    }
+
+    pub async fn get_or_set<T, F, Fut>(&self, suffix: &str, ttl: Duration, loader: F) -> AppResult<T>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: Fn() -> Fut + Send + Sync,
+        Fut: Future<Output = AppResult<T>> + Send,
+    {
+        self.cache.get_or_set(&self.key(suffix), ttl, loader).await
+    }
+
+    /// 批量读取，结果与 `suffixes` 一一对应
+    pub async fn get_many<T: DeserializeOwned + Send>(&self, suffixes: &[String]) -> AppResult<Vec<Option<T>>> {
+        let keys: Vec<String> = suffixes.iter().map(|suffix| self.key(suffix)).collect();
+        self.cache.mget(&keys).await
+    }
+
+    pub async fn set_many<T: Serialize + Sync>(&self, entries: Vec<(String, T)>, ttl: Duration) -> AppResult<()> {
+        let entries: Vec<(String, T)> = entries.into_iter().map(|(suffix, value)| (self.key(&suffix), value)).collect();
+        self.cache.mset(&entries, ttl).await
+    }
+
+    /// 批量读取，未命中的部分一次性回源后写入
+    ///
+    /// `loader` 接收未命中项在 `suffixes` 中的下标，按同样顺序返回对应的值。缓存不可用时全部回源。
+    pub async fn get_many_or_load<T, F, Fut>(&self, suffixes: &[String], ttl: Duration, loader: F) -> AppResult<Vec<T>>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: FnOnce(Vec<usize>) -> Fut + Send,
+        Fut: Future<Output = AppResult<Vec<T>>> + Send,
+    {
+        let mut values = self.get_many::<T>(suffixes).await.unwrap_or_else(|e| {
+            tracing::warn!("Failed to read {} cache entries, loading them directly: {}", suffixes.len(), e);
+            suffixes.iter().map(|_| None).collect()
+        });
+        let missing: Vec<usize> = (0..values.len()).filter(|&i| values[i].is_none()).collect();
+        if !missing.is_empty() {
+            let loaded = loader(missing.clone()).await?;
+            let entries: Vec<(String, &T)> =
+                missing.iter().zip(&loaded).map(|(&i, value)| (suffixes[i].clone(), value)).collect();
+            if let Err(e) = self.set_many(entries, ttl).await {
+                tracing::warn!("Failed to cache {} loaded entries: {}", missing.len(), e);
+            }
+            for (i, value) in missing.into_iter().zip(loaded) {
+                values[i] = Some(value);
+            }
+        }
+        Ok(values.into_iter().flatten().collect())
+    }
+}
+
+/// 去重并保持原有顺序，批量查询按键逐个缓存时避免同一条目重复出现
+pub(crate) fn distinct<T: Clone + Eq + Hash>(items: &[T]) -> Vec<T> {
+    let mut seen = HashSet::new();
+    items.iter().filter(|item| seen.insert(*item)).cloned().collect()
+}
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_domain::repositories::FeatureFlagRepository;
+use tradewinds_error::AppResult;
+
+use super::cache_namespace::CacheNamespace;
+
+/// 带缓存的功能开关仓储
+///
+/// 开关为平台级数据，使用全局命名空间，任何写入都使全部开关的缓存失效，其他实例在下次读取时取得新值。
+pub struct CachedFeatureFlagRepository {
+    inner: Arc<dyn FeatureFlagRepository>,
+    namespace: Arc<CacheNamespace>,
+    ttl: Duration,
+}
+
+impl CachedFeatureFlagRepository {
+    pub fn new(inner: Arc<dyn FeatureFlagRepository>, namespace: Arc<CacheNamespace>, ttl: Duration) -> Self {
+        Self { inner, namespace, ttl }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagRepository for CachedFeatureFlagRepository {
+    async fn create(&self, flag: &FeatureFlag) -> AppResult<()> {
+        self.inner.create(flag).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn update(&self, flag: &FeatureFlag) -> AppResult<()> {
+        self.inner.update(flag).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        self.inner.delete(key).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn find_by_key(&self, key: &str) -> AppResult<Option<FeatureFlag>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_key(key).await;
+        };
+        scope.get_or_set(&format!("flag:{}", key), self.ttl, || self.inner.find_by_key(key)).await
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<FeatureFlag>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_all().await;
+        };
+        scope.get_or_set("flags", self.ttl, || self.inner.find_all()).await
+    }
+}
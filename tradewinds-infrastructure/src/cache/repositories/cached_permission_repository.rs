@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_domain::aggregates::permission_aggregate::PermissionAggregate;
+use tradewinds_domain::entities::permission::Permission;
+use tradewinds_domain::repositories::{PermissionAggregateRepository, PermissionRepository};
+use tradewinds_domain::value_objects::permission::{
+    PermissionCode, PermissionId, PermissionName, PermissionStatus, PermissionType,
+};
+use tradewinds_domain::value_objects::user::UserId;
+use tradewinds_error::AppResult;
+
+use super::cache_namespace::{CacheNamespace, distinct};
+
+/// 带缓存的权限仓储
+///
+/// 与角色仓储共用命名空间：角色授权中带有权限码，删除权限也会级联删除角色上的授权，
+/// 权限的任何写入都使角色相关缓存一并失效。按用户查询与分页搜索直接访问数据库。
+pub struct CachedPermissionRepository {
+    inner: Arc<dyn PermissionRepository>,
+    aggregates: Arc<dyn PermissionAggregateRepository>,
+    namespace: Arc<CacheNamespace>,
+    ttl: Duration,
+}
+
+impl CachedPermissionRepository {
+    pub fn new(
+        inner: Arc<dyn PermissionRepository>,
+        aggregates: Arc<dyn PermissionAggregateRepository>,
+        namespace: Arc<CacheNamespace>,
+        ttl: Duration,
+    ) -> Self {
+        Self { inner, aggregates, namespace, ttl }
+    }
+}
+
+fn permission_key(id: &PermissionId) -> String {
+    format!("permission:{}", id)
+}
+
+#[async_trait]
+impl PermissionRepository for CachedPermissionRepository {
+    async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_id(id).await;
+        };
+        scope.get_or_set(&permission_key(id), self.ttl, || self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_name(&self, name: &PermissionName) -> AppResult<Option<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_name(name).await;
+        };
+        scope.get_or_set(&format!("permission_by_name:{}", name), self.ttl, || self.inner.find_by_name(name)).await
+    }
+
+    async fn find_by_code(&self, code: &PermissionCode) -> AppResult<Option<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_code(code).await;
+        };
+        scope.get_or_set(&format!("permission_by_code:{}", code), self.ttl, || self.inner.find_by_code(code)).await
+    }
+
+    async fn find_by_ids(&self, ids: &[PermissionId]) -> AppResult<Vec<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_ids(ids).await;
+        };
+        let ids = distinct(ids);
+        let keys: Vec<String> = ids.iter().map(permission_key).collect();
+        let permissions = scope
+            .get_many_or_load(&keys, self.ttl, |missing| async {
+                let missing: Vec<PermissionId> = missing.into_iter().map(|i| ids[i].clone()).collect();
+                let found = self.inner.find_by_ids(&missing).await?;
+                Ok(missing.iter().map(|id| found.iter().find(|permission| &permission.id == id).cloned()).collect())
+            })
+            .await?;
+        Ok(permissions.into_iter().flatten().collect())
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<Permission>> {
+        self.inner.find_by_user_id(user_id).await
+    }
+
+    async fn search(
+        &self,
+        name: Option<&PermissionName>,
+        code: Option<&PermissionCode>,
+        permission_type: Option<&PermissionType>,
+        status: Option<PermissionStatus>,
+        show_deleted: Option<bool>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Permission>, u64)> {
+        self.inner.search(name, code, permission_type, status, show_deleted, limit, offset).await
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_all().await;
+        };
+        scope.get_or_set("permissions", self.ttl, || self.inner.find_all()).await
+    }
+}
+
+#[async_trait]
+impl PermissionAggregateRepository for CachedPermissionRepository {
+    async fn create(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        self.aggregates.create(aggregate).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        self.aggregates.save(aggregate).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<PermissionAggregate>> {
+        self.aggregates.find_by_id(id).await
+    }
+
+    async fn delete(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        self.aggregates.delete(aggregate).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+}
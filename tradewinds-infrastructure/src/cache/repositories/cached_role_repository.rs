@@ -0,0 +1,201 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_domain::aggregates::role_aggregate::RoleAggregate;
+use tradewinds_domain::entities::{permission::Permission, role::Role};
+use tradewinds_domain::policies::PermissionGrant;
+use tradewinds_domain::repositories::{RoleAggregateRepository, RoleRepository};
+use tradewinds_domain::value_objects::DepartmentId;
+use tradewinds_domain::value_objects::permission::PermissionId;
+use tradewinds_domain::value_objects::role::{RoleCode, RoleId, RoleName};
+use tradewinds_error::AppResult;
+
+use super::cache_namespace::{CacheNamespace, distinct};
+
+/// 带缓存的角色仓储
+///
+/// 按角色逐个缓存角色、授权、权限与数据范围部门，批量查询只回源未命中的角色。
+/// 角色的写入经由本仓储的聚合接口完成，成功后使命名空间失效。
+/// 分页搜索与编辑用的查询直接访问数据库。
+pub struct CachedRoleRepository {
+    inner: Arc<dyn RoleRepository>,
+    aggregates: Arc<dyn RoleAggregateRepository>,
+    namespace: Arc<CacheNamespace>,
+    ttl: Duration,
+}
+
+impl CachedRoleRepository {
+    pub fn new(
+        inner: Arc<dyn RoleRepository>,
+        aggregates: Arc<dyn RoleAggregateRepository>,
+        namespace: Arc<CacheNamespace>,
+        ttl: Duration,
+    ) -> Self {
+        Self { inner, aggregates, namespace, ttl }
+    }
+}
+
+fn role_key(id: &RoleId) -> String {
+    format!("role:{}", id)
+}
+
+fn grants_key(id: &RoleId) -> String {
+    format!("role_grants:{}", id)
+}
+
+fn permissions_key(id: &RoleId) -> String {
+    format!("role_permissions:{}", id)
+}
+
+fn departments_key(id: &RoleId) -> String {
+    format!("role_departments:{}", id)
+}
+
+#[async_trait]
+impl RoleRepository for CachedRoleRepository {
+    async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<Role>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_id(id).await;
+        };
+        scope.get_or_set(&role_key(id), self.ttl, || self.inner.find_by_id(id)).await
+    }
+
+    async fn find_by_name(&self, name: &RoleName) -> AppResult<Option<Role>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_name(name).await;
+        };
+        scope.get_or_set(&format!("role_by_name:{}", name), self.ttl, || self.inner.find_by_name(name)).await
+    }
+
+    async fn find_by_code(&self, code: &RoleCode) -> AppResult<Option<Role>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_code(code).await;
+        };
+        scope.get_or_set(&format!("role_by_code:{}", code), self.ttl, || self.inner.find_by_code(code)).await
+    }
+
+    async fn find_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Role>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_by_ids(ids).await;
+        };
+        let ids = distinct(ids);
+        let keys: Vec<String> = ids.iter().map(role_key).collect();
+        let roles = scope
+            .get_many_or_load(&keys, self.ttl, |missing| async {
+                let missing: Vec<RoleId> = missing.into_iter().map(|i| ids[i].clone()).collect();
+                let found = self.inner.find_by_ids(&missing).await?;
+                Ok(missing.iter().map(|id| found.iter().find(|role| &role.id == id).cloned()).collect())
+            })
+            .await?;
+        Ok(roles.into_iter().flatten().collect())
+    }
+
+    async fn exists_by_id(&self, id: &RoleId) -> AppResult<bool> {
+        Ok(RoleRepository::find_by_id(self, id).await?.is_some())
+    }
+
+    async fn find_with_permissions(&self, id: &RoleId) -> AppResult<Option<(Role, Vec<PermissionId>)>> {
+        self.inner.find_with_permissions(id).await
+    }
+
+    async fn find_permissions(&self, id: &RoleId) -> AppResult<Vec<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_permissions(id).await;
+        };
+        scope.get_or_set(&permissions_key(id), self.ttl, || self.inner.find_permissions(id)).await
+    }
+
+    async fn find_permissions_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Permission>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_permissions_by_ids(ids).await;
+        };
+        let keys: Vec<String> = ids.iter().map(permissions_key).collect();
+        let permissions = scope
+            .get_many_or_load(&keys, self.ttl, |missing| async {
+                let mut loaded = Vec::with_capacity(missing.len());
+                for i in missing {
+                    loaded.push(self.inner.find_permissions(&ids[i]).await?);
+                }
+                Ok(loaded)
+            })
+            .await?;
+        Ok(permissions.into_iter().flatten().collect())
+    }
+
+    async fn find_permission_grants(&self, ids: &[RoleId]) -> AppResult<Vec<PermissionGrant>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_permission_grants(ids).await;
+        };
+        let ids = distinct(ids);
+        let keys: Vec<String> = ids.iter().map(grants_key).collect();
+        let grants = scope
+            .get_many_or_load(&keys, self.ttl, |missing| async {
+                let missing: Vec<RoleId> = missing.into_iter().map(|i| ids[i].clone()).collect();
+                let found = self.inner.find_permission_grants(&missing).await?;
+                Ok(missing
+                    .iter()
+                    .map(|id| found.iter().filter(|grant| &grant.role_id == id).cloned().collect::<Vec<_>>())
+                    .collect())
+            })
+            .await?;
+        Ok(grants.into_iter().flatten().collect())
+    }
+
+    async fn find_data_scope_departments(&self, ids: &[RoleId]) -> AppResult<Vec<DepartmentId>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_data_scope_departments(ids).await;
+        };
+        let keys: Vec<String> = ids.iter().map(departments_key).collect();
+        let departments = scope
+            .get_many_or_load(&keys, self.ttl, |missing| async {
+                // 返回结果为并集，无法区分所属角色，逐个角色回源
+                let mut loaded = Vec::with_capacity(missing.len());
+                for i in missing {
+                    loaded.push(self.inner.find_data_scope_departments(std::slice::from_ref(&ids[i])).await?);
+                }
+                Ok(loaded)
+            })
+            .await?;
+        let mut departments: Vec<DepartmentId> = departments.into_iter().flatten().collect();
+        departments.sort();
+        departments.dedup();
+        Ok(departments)
+    }
+
+    async fn search(
+        &self,
+        name: Option<&RoleName>,
+        code: Option<&str>,
+        status: Option<i32>,
+        show_deleted: Option<bool>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Role>, u64)> {
+        self.inner.search(name, code, status, show_deleted, limit, offset).await
+    }
+}
+
+#[async_trait]
+impl RoleAggregateRepository for CachedRoleRepository {
+    async fn create(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        self.aggregates.create(aggregate).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        self.aggregates.save(aggregate).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<RoleAggregate>> {
+        self.aggregates.find_by_id(id).await
+    }
+
+    async fn delete(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        self.aggregates.delete(aggregate).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_domain::entities::{outbox_message::OutboxMessage, system_setting::SystemSetting};
+use tradewinds_domain::repositories::system_setting_repository::SystemSettingRepository;
+use tradewinds_domain::value_objects::system_setting::{SystemSettingKey, SystemSettingValue};
+use tradewinds_error::AppResult;
+
+use super::cache_namespace::CacheNamespace;
+
+/// 带缓存的系统设置仓储
+///
+/// 租户未设置时回退到默认租户的值，默认租户的修改影响所有租户，因此使用全局命名空间，任何写入都使全部租户的设置缓存失效。
+pub struct CachedSystemSettingRepository {
+    inner: Arc<dyn SystemSettingRepository>,
+    namespace: Arc<CacheNamespace>,
+    ttl: Duration,
+}
+
+impl CachedSystemSettingRepository {
+    pub fn new(inner: Arc<dyn SystemSettingRepository>, namespace: Arc<CacheNamespace>, ttl: Duration) -> Self {
+        Self { inner, namespace, ttl }
+    }
+}
+
+#[async_trait]
+impl SystemSettingRepository for CachedSystemSettingRepository {
+    async fn get_by_key(&self, key: &SystemSettingKey) -> AppResult<Option<SystemSetting>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.get_by_key(key).await;
+        };
+        scope.get_or_set(&format!("setting:{}", key), self.ttl, || self.inner.get_by_key(key)).await
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<SystemSetting>> {
+        let Some(scope) = self.namespace.scope().await else {
+            return self.inner.find_all().await;
+        };
+        scope.get_or_set("settings", self.ttl, || self.inner.find_all()).await
+    }
+
+    async fn set_value(
+        &self,
+        key: &SystemSettingKey,
+        value: &SystemSettingValue,
+        events: &[OutboxMessage],
+    ) -> AppResult<()> {
+        self.inner.set_value(key, value, events).await?;
+        self.namespace.invalidate().await;
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+pub mod cache_namespace;
+pub mod cached_feature_flag_repository;
+pub mod cached_permission_repository;
+pub mod cached_role_repository;
+pub mod cached_system_setting_repository;
+
+pub use cache_namespace::{CacheNamespace, CacheScope};
+pub use cached_feature_flag_repository::CachedFeatureFlagRepository;
+pub use cached_permission_repository::CachedPermissionRepository;
+pub use cached_role_repository::CachedRoleRepository;
+pub use cached_system_setting_repository::CachedSystemSettingRepository;
@@ -1,3 +1,6 @@
 mod app_config;
 
-pub use app_config::AppConfig;
+pub use app_config::{
+    AppConfig, CacheConfig, EventBusBackend, EventBusConfig, RabbitMqConfig, RedisStreamsConfig, SchedulerConfig,
+    SchedulerLockBackend, SmtpConfig, WebhookConfig,
+};
@@ -3,10 +3,18 @@ use std::sync::Arc;
 use tradewinds_domain::services::{PasswordService, TokenService};
 
 // 应用层接口与服务
+use tradewinds_application::services::audit_log_service::AuditSnapshotRepos;
+use tradewinds_application::services::auth_service::AuthServiceDeps;
 use tradewinds_application::{
+    events::event_registry,
+    events::subscribers::{AccessRequestNotificationSubscriber, EventLogSubscriber, WebhookSubscriber},
     interfaces::{
-        auth_service::IAuthService, permission_service::IPermissionService, role_service::IRoleService,
-        system_setting_service::ISystemSettingService, user_service::IUserService,
+        access_request_service::IAccessRequestService, access_review_service::IAccessReviewService,
+        audit_log_service::IAuditLogService, auth_service::IAuthService, department_service::IDepartmentService,
+        feature_flag_service::IFeatureFlagService, group_service::IGroupService, job_service::IJobService,
+        login_log_service::ILoginLogService, outbox_service::IOutboxService, permission_service::IPermissionService,
+        policy_service::IPolicyService, role_service::IRoleService, system_setting_service::ISystemSettingService,
+        tenant_service::ITenantService, user_service::IUserService, webhook_service::IWebhookService,
     },
     services::{
         auth_service::AuthService, permission_service::PermissionService, role_service::RoleService,
@@ -30,6 +38,7 @@ use tradewinds_domain::repositories::{
 };
 
 // 基础设施服务
+use crate::event_bus::ConfiguredEventBus;
 use crate::services::auth::bcrypt_password_service::BcryptPasswordService;
 use crate::services::auth::jwt_token_service::JwtTokenService;
 
@@ -44,29 +53,155 @@ pub async fn init_application_service(
     Arc<dyn IPermissionService>,
     Arc<dyn TokenService>,
     Arc<dyn ISystemSettingService>,
+    Arc<dyn IDepartmentService>,
+    Arc<dyn IGroupService>,
+    Arc<dyn ITenantService>,
+    Arc<dyn IPolicyService>,
+    Arc<dyn IAccessRequestService>,
+    Arc<dyn IAccessReviewService>,
+    Arc<dyn IAuditLogService>,
+    Arc<dyn ILoginLogService>,
+    Arc<dyn IOutboxService>,
+    Arc<dyn IWebhookService>,
+    Arc<dyn IJobService>,
+    Arc<dyn IFeatureFlagService>,
 )> {
     use sea_orm::Database;
     let db = Database::connect(&config.database_url).await?;
+    // 事件总线按配置选择进程内、Redis Streams 或 RabbitMQ 实现，发件箱中继投递到同一总线
+    let configured_event_bus =
+        ConfiguredEventBus::new(&config.event_bus, &config.redis_url, &config.rabbitmq, event_registry()).await?;
+    let event_bus = configured_event_bus.event_bus();
 
-    let system_setting_service_bundle = di::system_setting_di::init_system_setting_service(&db);
-    let user_service_bundle =
-        di::user_di::init_user_service(&db, system_setting_service_bundle.system_setting_repo.clone());
-    let role_service_bundle = di::role_di::init_role_service(&db);
-    let permission_service_bundle = di::permission_di::init_permission_service(&db);
+    // 角色、权限、系统设置与功能开关的读取经由缓存，配置关闭时直接访问数据库
+    let cache_bundle = di::cache_di::init_cache(config)?;
+    let system_setting_service_bundle =
+        di::system_setting_di::init_system_setting_service(&db, config, cache_bundle.settings_namespace.clone());
+    let role_service_bundle = di::role_di::init_role_service(&db, config, cache_bundle.authz_namespace.clone());
+    let permission_service_bundle =
+        di::permission_di::init_permission_service(&db, config, cache_bundle.authz_namespace.clone());
+    let user_service_bundle = di::user_di::init_user_service(
+        &db,
+        role_service_bundle.role_repo.clone(),
+        permission_service_bundle.permission_repo.clone(),
+        system_setting_service_bundle.system_setting_repo.clone(),
+    );
+    let feature_flag_service_bundle = di::feature_flag_di::init_feature_flag_service(
+        &db,
+        config,
+        cache_bundle.feature_flag_namespace.clone(),
+        user_service_bundle.user_role_repo.clone(),
+        role_service_bundle.role_repo.clone(),
+    );
+    let department_service_bundle =
+        di::department_di::init_department_service(&db, user_service_bundle.user_repo.clone());
+    let group_service_bundle = di::group_di::init_group_service(
+        &db,
+        user_service_bundle.user_repo.clone(),
+        role_service_bundle.role_repo.clone(),
+        user_service_bundle.user_role_repo.clone(),
+        user_service_bundle.sod_rule_repo.clone(),
+    );
+    // 聚合事件经发件箱由中继投递到事件总线
+    let outbox_service_bundle = di::outbox_di::init_outbox_service(&db, config, event_bus.clone());
 
     let token_blacklist_repo = di::auth_di::init_token_blacklist_repo(&db);
     let jwt_token_service =
         Arc::new(JwtTokenService::new(config.clone(), token_blacklist_repo)) as Arc<dyn TokenService>;
     let bcrypt_password_service = Arc::new(BcryptPasswordService::new()) as Arc<dyn PasswordService>;
-    let auth_service: Arc<dyn IAuthService> = Arc::new(AuthService::new(
+    let login_log_service_bundle = di::login_log_di::init_login_log_service(&db, config);
+    let auth_service: Arc<dyn IAuthService> = Arc::new(AuthService::new(AuthServiceDeps {
+        user_repo: user_service_bundle.user_repo.clone(),
+        role_repo: role_service_bundle.role_repo.clone(),
+        permission_repo: permission_service_bundle.permission_repo.clone(),
+        user_role_repo: user_service_bundle.user_role_repo.clone(),
+        user_agg_repo: user_service_bundle.user_agg_repo.clone(),
+        login_log_repo: login_log_service_bundle.login_log_repo.clone(),
+        token_service: jwt_token_service.clone(),
+        password_service: bcrypt_password_service.clone(),
+        outbox_repo: outbox_service_bundle.outbox_repo.clone(),
+    }));
+    let tenant_service_bundle = di::tenant_di::init_tenant_service(
+        &db,
+        &user_service_bundle,
+        &role_service_bundle,
+        &permission_service_bundle,
+        bcrypt_password_service.clone(),
+    );
+    let policy_service_bundle = di::policy_di::init_policy_service(
+        user_service_bundle.access_policy_repo.clone(),
         user_service_bundle.user_repo.clone(),
         role_service_bundle.role_repo.clone(),
         permission_service_bundle.permission_repo.clone(),
         user_service_bundle.user_role_repo.clone(),
-        user_service_bundle.user_agg_repo.clone(),
-        jwt_token_service.clone(),
-        bcrypt_password_service.clone(),
-    ));
+    );
+    let notification_service = di::notification_di::init_notification_service(config)?;
+    let access_request_service_bundle = di::access_request_di::init_access_request_service(
+        &db,
+        user_service_bundle.user_repo.clone(),
+        role_service_bundle.role_repo.clone(),
+        user_service_bundle.user_role_repo.clone(),
+        user_service_bundle.service.clone(),
+    );
+    let access_review_service_bundle = di::access_review_di::init_access_review_service(
+        &db,
+        access_request_service_bundle.approver_repo.clone(),
+        user_service_bundle.user_repo.clone(),
+        role_service_bundle.role_repo.clone(),
+        user_service_bundle.user_role_repo.clone(),
+        user_service_bundle.service.clone(),
+        notification_service.clone(),
+    );
+    let webhook_service_bundle = di::webhook_di::init_webhook_service(&db, config)?;
+    let scheduler_service_bundle = di::scheduler_di::init_scheduler_service(
+        &db,
+        config,
+        tenant_service_bundle.tenant_repo.clone(),
+        access_request_service_bundle.service.clone(),
+        access_review_service_bundle.service.clone(),
+        login_log_service_bundle.service.clone(),
+    )?;
+    let audit_log_service_bundle = di::audit_log_di::init_audit_log_service(
+        &db,
+        config,
+        AuditSnapshotRepos {
+            user_repo: user_service_bundle.user_repo.clone(),
+            user_role_repo: user_service_bundle.user_role_repo.clone(),
+            role_repo: role_service_bundle.role_repo.clone(),
+            permission_repo: permission_service_bundle.permission_repo.clone(),
+            system_setting_repo: system_setting_service_bundle.system_setting_repo.clone(),
+            group_agg_repo: group_service_bundle.group_agg_repo.clone(),
+            department_repo: department_service_bundle.department_repo.clone(),
+            tenant_repo: tenant_service_bundle.tenant_repo.clone(),
+            access_policy_repo: user_service_bundle.access_policy_repo.clone(),
+            access_request_repo: access_request_service_bundle.access_request_repo.clone(),
+            access_review_repo: access_review_service_bundle.access_review_repo.clone(),
+            feature_flag_repo: feature_flag_service_bundle.feature_flag_repo.clone(),
+            webhook_repo: webhook_service_bundle.webhook_repo.clone(),
+            job_repo: scheduler_service_bundle.job_repo.clone(),
+            outbox_repo: outbox_service_bundle.outbox_repo.clone(),
+        },
+    );
+
+    // 事件订阅者；订阅名即 Redis 消费组名与 RabbitMQ 队列名后缀，修改会丢失未消费的消息
+    configured_event_bus.subscribe_all("EventLogSubscriber", EventLogSubscriber);
+    configured_event_bus.subscribe(
+        "AccessRequestNotificationSubscriber",
+        AccessRequestNotificationSubscriber::new(
+            access_request_service_bundle.approver_repo.clone(),
+            user_service_bundle.user_repo.clone(),
+            role_service_bundle.role_repo.clone(),
+            notification_service,
+        ),
+    );
+    configured_event_bus.subscribe_all(
+        "WebhookSubscriber",
+        WebhookSubscriber::new(
+            webhook_service_bundle.webhook_repo.clone(),
+            webhook_service_bundle.delivery_repo.clone(),
+        ),
+    );
+    configured_event_bus.start();
 
     Ok((
         auth_service,
@@ -75,5 +210,17 @@ pub async fn init_application_service(
         permission_service_bundle.service.clone(),
         jwt_token_service,
         system_setting_service_bundle.service.clone(),
+        department_service_bundle.service.clone(),
+        group_service_bundle.service.clone(),
+        tenant_service_bundle.service.clone(),
+        policy_service_bundle.service.clone(),
+        access_request_service_bundle.service.clone(),
+        access_review_service_bundle.service.clone(),
+        audit_log_service_bundle.service.clone(),
+        login_log_service_bundle.service.clone(),
+        outbox_service_bundle.service.clone(),
+        webhook_service_bundle.service.clone(),
+        scheduler_service_bundle.service.clone(),
+        feature_flag_service_bundle.service.clone(),
     ))
 }
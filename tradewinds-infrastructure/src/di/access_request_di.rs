@@ -0,0 +1,36 @@
+use crate::persistence::repositories::{SeaOrmAccessRequestRepository, SeaOrmRoleApproverRepository};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::{IAccessRequestService, IUserService};
+use tradewinds_application::services::access_request_service::AccessRequestService;
+use tradewinds_domain::repositories::{
+    AccessRequestRepository, RoleApproverRepository, RoleRepository, UserRepository, UserRoleRepository,
+};
+
+pub struct AccessRequestServiceBundle {
+    pub service: Arc<dyn IAccessRequestService>,
+    pub access_request_repo: Arc<dyn AccessRequestRepository>,
+    pub approver_repo: Arc<dyn RoleApproverRepository>,
+}
+
+/// 批准与到期收回经由用户服务的分配、撤销角色流程完成；申请事件随申请写入发件箱
+pub fn init_access_request_service(
+    db: &DatabaseConnection,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    user_service: Arc<dyn IUserService>,
+) -> AccessRequestServiceBundle {
+    let access_request_repo: Arc<dyn AccessRequestRepository> =
+        Arc::new(SeaOrmAccessRequestRepository::new(db.clone()));
+    let approver_repo: Arc<dyn RoleApproverRepository> = Arc::new(SeaOrmRoleApproverRepository::new(db.clone()));
+    let service = Arc::new(AccessRequestService::new(
+        access_request_repo.clone(),
+        approver_repo.clone(),
+        user_repo,
+        role_repo,
+        user_role_repo,
+        user_service,
+    )) as Arc<dyn IAccessRequestService>;
+    AccessRequestServiceBundle { service, access_request_repo, approver_repo }
+}
@@ -0,0 +1,36 @@
+use crate::persistence::repositories::SeaOrmAccessReviewRepository;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::{IAccessReviewService, INotificationService, IUserService};
+use tradewinds_application::services::access_review_service::AccessReviewService;
+use tradewinds_domain::repositories::{
+    AccessReviewRepository, RoleApproverRepository, RoleRepository, UserRepository, UserRoleRepository,
+};
+
+pub struct AccessReviewServiceBundle {
+    pub service: Arc<dyn IAccessReviewService>,
+    pub access_review_repo: Arc<dyn AccessReviewRepository>,
+}
+
+/// 复核人沿用权限申请的角色审批人，收回经由用户服务的撤销角色流程完成
+pub fn init_access_review_service(
+    db: &DatabaseConnection,
+    approver_repo: Arc<dyn RoleApproverRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    user_service: Arc<dyn IUserService>,
+    notifier: Arc<dyn INotificationService>,
+) -> AccessReviewServiceBundle {
+    let access_review_repo: Arc<dyn AccessReviewRepository> = Arc::new(SeaOrmAccessReviewRepository::new(db.clone()));
+    let service = Arc::new(AccessReviewService::new(
+        access_review_repo.clone(),
+        approver_repo,
+        user_repo,
+        role_repo,
+        user_role_repo,
+        user_service,
+        notifier,
+    )) as Arc<dyn IAccessReviewService>;
+    AccessReviewServiceBundle { service, access_review_repo }
+}
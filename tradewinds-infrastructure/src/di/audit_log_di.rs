@@ -0,0 +1,36 @@
+use crate::config::AppConfig;
+use crate::persistence::repositories::SeaOrmAuditLogRepository;
+use crate::services::audit::hmac_audit_signer::HmacAuditSigner;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::{IAuditChainService, IAuditLogService};
+use tradewinds_application::services::{
+    audit_chain_service::AuditChainService,
+    audit_log_service::{AuditLogService, AuditSnapshotRepos},
+};
+use tradewinds_domain::repositories::AuditLogRepository;
+use tradewinds_domain::services::AuditSigner;
+
+pub struct AuditLogServiceBundle {
+    pub service: Arc<dyn IAuditLogService>,
+    pub audit_log_repo: Arc<dyn AuditLogRepository>,
+}
+
+/// 审计哈希链服务，也供 verify-audit 命令单独使用
+pub fn init_audit_chain_service(db: &DatabaseConnection, config: &AppConfig) -> Arc<dyn IAuditChainService> {
+    let audit_log_repo: Arc<dyn AuditLogRepository> = Arc::new(SeaOrmAuditLogRepository::new(db.clone()));
+    let signer: Arc<dyn AuditSigner> = Arc::new(HmacAuditSigner::new(config.audit.signing_key.clone()));
+    Arc::new(AuditChainService::new(audit_log_repo, signer, config.audit.checkpoint_interval))
+}
+
+/// 各仓储用于在命令执行前后对操作对象取快照
+pub fn init_audit_log_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    repos: AuditSnapshotRepos,
+) -> AuditLogServiceBundle {
+    let audit_log_repo: Arc<dyn AuditLogRepository> = Arc::new(SeaOrmAuditLogRepository::new(db.clone()));
+    let service = Arc::new(AuditLogService::new(audit_log_repo.clone(), init_audit_chain_service(db, config), repos))
+        as Arc<dyn IAuditLogService>;
+    AuditLogServiceBundle { service, audit_log_repo }
+}
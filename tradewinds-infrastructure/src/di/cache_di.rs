@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use tradewinds_error::AppResult;
+
+use crate::cache::repositories::CacheNamespace;
+use crate::cache::{Cache, RedisCache};
+use crate::config::AppConfig;
+
+/// 缓存关闭时命名空间均为 `None`，仓储直接访问数据库
+pub struct CacheBundle {
+    /// 角色与权限共用的按租户命名空间
+    pub authz_namespace: Option<Arc<CacheNamespace>>,
+    /// 系统设置的全局命名空间
+    pub settings_namespace: Option<Arc<CacheNamespace>>,
+    /// 功能开关的全局命名空间
+    pub feature_flag_namespace: Option<Arc<CacheNamespace>>,
+}
+
+/// 启用缓存时创建 Redis 缓存并开始监听失效通知
+pub fn init_cache(config: &AppConfig) -> AppResult<CacheBundle> {
+    if !config.cache.enabled {
+        return Ok(CacheBundle { authz_namespace: None, settings_namespace: None, feature_flag_namespace: None });
+    }
+    let redis_cache = RedisCache::new(&config.redis_url, &config.cache)?;
+    redis_cache.start();
+    let cache: Arc<dyn Cache> = Arc::new(redis_cache);
+    Ok(CacheBundle {
+        authz_namespace: Some(Arc::new(CacheNamespace::per_tenant(cache.clone(), "authz"))),
+        settings_namespace: Some(Arc::new(CacheNamespace::global(cache.clone(), "settings"))),
+        feature_flag_namespace: Some(Arc::new(CacheNamespace::global(cache, "feature_flags"))),
+    })
+}
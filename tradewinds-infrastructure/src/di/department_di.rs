@@ -0,0 +1,21 @@
+use crate::persistence::repositories::{SeaOrmDepartmentAggregateRepository, SeaOrmDepartmentRepository};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::department_service::IDepartmentService;
+use tradewinds_application::services::department_service::DepartmentService;
+use tradewinds_domain::repositories::{DepartmentAggregateRepository, DepartmentRepository, UserRepository};
+
+pub struct DepartmentServiceBundle {
+    pub service: Arc<dyn IDepartmentService>,
+    pub department_repo: Arc<dyn DepartmentRepository>,
+    pub department_agg_repo: Arc<dyn DepartmentAggregateRepository>,
+}
+
+pub fn init_department_service(db: &DatabaseConnection, user_repo: Arc<dyn UserRepository>) -> DepartmentServiceBundle {
+    let department_repo: Arc<dyn DepartmentRepository> = Arc::new(SeaOrmDepartmentRepository::new(db.clone()));
+    let department_agg_repo: Arc<dyn DepartmentAggregateRepository> =
+        Arc::new(SeaOrmDepartmentAggregateRepository::new(db.clone()));
+    let service = Arc::new(DepartmentService::new(department_repo.clone(), department_agg_repo.clone(), user_repo))
+        as Arc<dyn IDepartmentService>;
+    DepartmentServiceBundle { service, department_repo, department_agg_repo }
+}
@@ -0,0 +1,32 @@
+use crate::cache::repositories::{CacheNamespace, CachedFeatureFlagRepository};
+use crate::config::AppConfig;
+use crate::persistence::repositories::SeaOrmFeatureFlagRepository;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_application::interfaces::feature_flag_service::IFeatureFlagService;
+use tradewinds_application::services::feature_flag_service::FeatureFlagService;
+use tradewinds_domain::repositories::{FeatureFlagRepository, RoleRepository, UserRoleRepository};
+
+pub struct FeatureFlagServiceBundle {
+    pub service: Arc<dyn IFeatureFlagService>,
+    pub feature_flag_repo: Arc<dyn FeatureFlagRepository>,
+}
+
+/// 启用缓存时开关的读取经过 [`CachedFeatureFlagRepository`]，写入后各实例的缓存随之失效
+pub fn init_feature_flag_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    feature_flag_namespace: Option<Arc<CacheNamespace>>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+) -> FeatureFlagServiceBundle {
+    let mut feature_flag_repo: Arc<dyn FeatureFlagRepository> = Arc::new(SeaOrmFeatureFlagRepository::new(db.clone()));
+    if let Some(namespace) = feature_flag_namespace {
+        let ttl = Duration::from_secs(config.cache.feature_flag_ttl_secs);
+        feature_flag_repo = Arc::new(CachedFeatureFlagRepository::new(feature_flag_repo, namespace, ttl));
+    }
+    let service = Arc::new(FeatureFlagService::new(feature_flag_repo.clone(), user_role_repo, role_repo))
+        as Arc<dyn IFeatureFlagService>;
+    FeatureFlagServiceBundle { service, feature_flag_repo }
+}
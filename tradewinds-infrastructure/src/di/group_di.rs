@@ -0,0 +1,34 @@
+use crate::persistence::repositories::{SeaOrmGroupAggregateRepository, SeaOrmGroupRepository};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::group_service::IGroupService;
+use tradewinds_application::services::group_service::GroupService;
+use tradewinds_domain::repositories::{
+    GroupAggregateRepository, GroupRepository, RoleRepository, SodRuleRepository, UserRepository, UserRoleRepository,
+};
+
+pub struct GroupServiceBundle {
+    pub service: Arc<dyn IGroupService>,
+    pub group_repo: Arc<dyn GroupRepository>,
+    pub group_agg_repo: Arc<dyn GroupAggregateRepository>,
+}
+
+pub fn init_group_service(
+    db: &DatabaseConnection,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+    sod_rule_repo: Arc<dyn SodRuleRepository>,
+) -> GroupServiceBundle {
+    let group_repo: Arc<dyn GroupRepository> = Arc::new(SeaOrmGroupRepository::new(db.clone()));
+    let group_agg_repo: Arc<dyn GroupAggregateRepository> = Arc::new(SeaOrmGroupAggregateRepository::new(db.clone()));
+    let service = Arc::new(GroupService::new(
+        group_repo.clone(),
+        group_agg_repo.clone(),
+        user_repo,
+        role_repo,
+        user_role_repo,
+        sod_rule_repo,
+    )) as Arc<dyn IGroupService>;
+    GroupServiceBundle { service, group_repo, group_agg_repo }
+}
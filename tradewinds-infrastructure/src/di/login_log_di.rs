@@ -0,0 +1,20 @@
+use crate::config::AppConfig;
+use crate::persistence::repositories::SeaOrmLoginLogRepository;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::ILoginLogService;
+use tradewinds_application::services::login_log_service::LoginLogService;
+use tradewinds_domain::repositories::LoginLogRepository;
+
+pub struct LoginLogServiceBundle {
+    pub service: Arc<dyn ILoginLogService>,
+    pub login_log_repo: Arc<dyn LoginLogRepository>,
+}
+
+/// 登录日志仓储同时供认证服务在登录时写入
+pub fn init_login_log_service(db: &DatabaseConnection, config: &AppConfig) -> LoginLogServiceBundle {
+    let login_log_repo: Arc<dyn LoginLogRepository> = Arc::new(SeaOrmLoginLogRepository::new(db.clone()));
+    let service = Arc::new(LoginLogService::new(login_log_repo.clone(), config.login_log_retention_days))
+        as Arc<dyn ILoginLogService>;
+    LoginLogServiceBundle { service, login_log_repo }
+}
@@ -1,5 +1,19 @@
+pub mod access_request_di;
+pub mod access_review_di;
+pub mod audit_log_di;
 pub mod auth_di;
+pub mod cache_di;
+pub mod department_di;
+pub mod feature_flag_di;
+pub mod group_di;
+pub mod login_log_di;
+pub mod notification_di;
+pub mod outbox_di;
 pub mod permission_di;
+pub mod policy_di;
 pub mod role_di;
+pub mod scheduler_di;
 pub mod system_setting_di;
+pub mod tenant_di;
 pub mod user_di;
+pub mod webhook_di;
@@ -0,0 +1,18 @@
+use std::sync::Arc;
+
+use tradewinds_application::interfaces::INotificationService;
+use tradewinds_error::AppResult;
+
+use crate::config::AppConfig;
+use crate::external::email_service::{EmailService, SmtpEmailService};
+use crate::external::notification_service::{EmailNotificationService, LogNotificationService};
+
+/// 配置了 SMTP 时以邮件发送通知，否则写入日志
+pub fn init_notification_service(config: &AppConfig) -> AppResult<Arc<dyn INotificationService>> {
+    let Some(smtp) = &config.smtp else {
+        return Ok(Arc::new(LogNotificationService));
+    };
+    let email_service: Arc<dyn EmailService> =
+        Arc::new(SmtpEmailService::new(&smtp.host, &smtp.username, &smtp.password, &smtp.from_email)?);
+    Ok(Arc::new(EmailNotificationService::new(email_service)))
+}
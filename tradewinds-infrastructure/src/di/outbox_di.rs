@@ -0,0 +1,59 @@
+use crate::config::AppConfig;
+use crate::persistence::repositories::SeaOrmOutboxRepository;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_application::events::event_registry;
+use tradewinds_application::interfaces::IOutboxService;
+use tradewinds_application::services::outbox_relay::{OutboxMetrics, OutboxRelay};
+use tradewinds_application::services::outbox_service::OutboxService;
+use tradewinds_domain::entities::outbox_message::OutboxRetryPolicy;
+use tradewinds_domain::repositories::OutboxRepository;
+use tradewinds_domain::services::EventBus;
+
+pub struct OutboxServiceBundle {
+    pub service: Arc<dyn IOutboxService>,
+    pub outbox_repo: Arc<dyn OutboxRepository>,
+}
+
+/// 初始化发件箱服务并启动中继任务，中继把发件箱消息投递到给定的事件总线
+pub fn init_outbox_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    event_bus: Arc<dyn EventBus>,
+) -> OutboxServiceBundle {
+    let outbox_repo: Arc<dyn OutboxRepository> = Arc::new(SeaOrmOutboxRepository::new(db.clone()));
+    let metrics = Arc::new(OutboxMetrics::default());
+    let retry_policy = OutboxRetryPolicy {
+        base_delay_secs: config.outbox.retry_base_secs,
+        max_delay_secs: config.outbox.retry_max_secs,
+        max_attempts: config.outbox.max_attempts,
+    };
+    let relay = OutboxRelay::new(
+        outbox_repo.clone(),
+        event_registry(),
+        event_bus,
+        retry_policy,
+        config.outbox.batch_size,
+        config.outbox.lease_secs,
+        metrics.clone(),
+    );
+    spawn_relay(relay, Duration::from_millis(config.outbox.poll_interval_ms));
+
+    let service = Arc::new(OutboxService::new(outbox_repo.clone(), metrics, config.outbox.stuck_after_secs))
+        as Arc<dyn IOutboxService>;
+    OutboxServiceBundle { service, outbox_repo }
+}
+
+fn spawn_relay(relay: OutboxRelay, poll_interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = relay.relay_once().await {
+                tracing::warn!("Outbox relay failed: {}", e);
+            }
+        }
+    });
+}
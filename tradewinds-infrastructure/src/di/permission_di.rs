@@ -1,6 +1,9 @@
+use crate::cache::repositories::{CacheNamespace, CachedPermissionRepository};
+use crate::config::AppConfig;
 use crate::persistence::repositories::{SeaOrmPermissionAggregateRepository, SeaOrmPermissionRepository};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use std::time::Duration;
 use tradewinds_application::interfaces::permission_service::IPermissionService;
 use tradewinds_application::services::permission_service::PermissionService;
 use tradewinds_domain::repositories::{PermissionAggregateRepository, PermissionRepository};
@@ -11,10 +14,21 @@ pub struct PermissionServiceBundle {
     pub permission_agg_repo: Arc<dyn PermissionAggregateRepository>,
 }
 
-pub fn init_permission_service(db: &DatabaseConnection) -> PermissionServiceBundle {
-    let permission_repo: Arc<dyn PermissionRepository> = Arc::new(SeaOrmPermissionRepository::new(db.clone()));
-    let permission_agg_repo: Arc<dyn PermissionAggregateRepository> =
+/// 启用缓存时权限的读取与写入都经过 [`CachedPermissionRepository`]，与角色共用命名空间
+pub fn init_permission_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    authz_namespace: Option<Arc<CacheNamespace>>,
+) -> PermissionServiceBundle {
+    let mut permission_repo: Arc<dyn PermissionRepository> = Arc::new(SeaOrmPermissionRepository::new(db.clone()));
+    let mut permission_agg_repo: Arc<dyn PermissionAggregateRepository> =
         Arc::new(SeaOrmPermissionAggregateRepository::new(db.clone()));
+    if let Some(namespace) = authz_namespace {
+        let ttl = Duration::from_secs(config.cache.permission_ttl_secs);
+        let cached = Arc::new(CachedPermissionRepository::new(permission_repo, permission_agg_repo, namespace, ttl));
+        permission_repo = cached.clone();
+        permission_agg_repo = cached;
+    }
     let service = Arc::new(PermissionService::new(permission_repo.clone(), permission_agg_repo.clone()))
         as Arc<dyn IPermissionService>;
     PermissionServiceBundle { service, permission_repo, permission_agg_repo }
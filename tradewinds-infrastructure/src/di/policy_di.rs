@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use tradewinds_application::interfaces::policy_service::IPolicyService;
+use tradewinds_application::services::policy_service::PolicyService;
+use tradewinds_domain::repositories::{
+    AccessPolicyRepository, PermissionRepository, RoleRepository, UserRepository, UserRoleRepository,
+};
+
+pub struct PolicyServiceBundle {
+    pub service: Arc<dyn IPolicyService>,
+}
+
+/// 访问策略仓储与用户服务共用，由 user_di 创建
+pub fn init_policy_service(
+    access_policy_repo: Arc<dyn AccessPolicyRepository>,
+    user_repo: Arc<dyn UserRepository>,
+    role_repo: Arc<dyn RoleRepository>,
+    permission_repo: Arc<dyn PermissionRepository>,
+    user_role_repo: Arc<dyn UserRoleRepository>,
+) -> PolicyServiceBundle {
+    let service =
+        Arc::new(PolicyService::new(access_policy_repo, user_repo, role_repo, permission_repo, user_role_repo))
+            as Arc<dyn IPolicyService>;
+    PolicyServiceBundle { service }
+}
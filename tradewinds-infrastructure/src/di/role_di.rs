@@ -1,6 +1,9 @@
+use crate::cache::repositories::{CacheNamespace, CachedRoleRepository};
+use crate::config::AppConfig;
 use crate::persistence::repositories::{SeaOrmRoleAggregateRepository, SeaOrmRoleRepository};
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use std::time::Duration;
 use tradewinds_application::interfaces::role_service::IRoleService;
 use tradewinds_application::services::role_service::RoleService;
 use tradewinds_domain::repositories::{RoleAggregateRepository, RoleRepository};
@@ -11,9 +14,20 @@ pub struct RoleServiceBundle {
     pub role_agg_repo: Arc<dyn RoleAggregateRepository>,
 }
 
-pub fn init_role_service(db: &DatabaseConnection) -> RoleServiceBundle {
-    let role_repo: Arc<dyn RoleRepository> = Arc::new(SeaOrmRoleRepository::new(db.clone()));
-    let role_agg_repo: Arc<dyn RoleAggregateRepository> = Arc::new(SeaOrmRoleAggregateRepository::new(db.clone()));
+/// 启用缓存时角色的读取与写入都经过 [`CachedRoleRepository`]，写入后使缓存失效
+pub fn init_role_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    authz_namespace: Option<Arc<CacheNamespace>>,
+) -> RoleServiceBundle {
+    let mut role_repo: Arc<dyn RoleRepository> = Arc::new(SeaOrmRoleRepository::new(db.clone()));
+    let mut role_agg_repo: Arc<dyn RoleAggregateRepository> = Arc::new(SeaOrmRoleAggregateRepository::new(db.clone()));
+    if let Some(namespace) = authz_namespace {
+        let ttl = Duration::from_secs(config.cache.role_ttl_secs);
+        let cached = Arc::new(CachedRoleRepository::new(role_repo, role_agg_repo, namespace, ttl));
+        role_repo = cached.clone();
+        role_agg_repo = cached;
+    }
     let service = Arc::new(RoleService::new(role_repo.clone(), role_agg_repo.clone())) as Arc<dyn IRoleService>;
     RoleServiceBundle { service, role_repo, role_agg_repo }
 }
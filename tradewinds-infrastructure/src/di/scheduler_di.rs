@@ -0,0 +1,128 @@
+use crate::config::{AppConfig, SchedulerLockBackend};
+use crate::di::auth_di::init_token_blacklist_repo;
+use crate::persistence::repositories::{SeaOrmJobRunRepository, SeaOrmScheduledJobRepository};
+use crate::scheduler::{
+    JobLock, JobRunRetentionJob, JobScheduler, LocalJobLock, RedisJobLock, TenantCommandJob, TokenBlacklistCleanupJob,
+};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_application::commands::access_request::{ExpireAccessRequestsCommand, ExpireAccessRequestsHandler};
+use tradewinds_application::commands::access_review::{CloseDueAccessReviewsCommand, CloseDueAccessReviewsHandler};
+use tradewinds_application::commands::login_log::{PurgeLoginLogsCommand, PurgeLoginLogsHandler};
+use tradewinds_application::interfaces::{IAccessRequestService, IAccessReviewService, IJobService, ILoginLogService};
+use tradewinds_application::services::job_service::JobService;
+use tradewinds_domain::repositories::{JobRunRepository, ScheduledJobRepository, TenantRepository};
+use tradewinds_domain::services::JobRunner;
+use tradewinds_error::AppResult;
+
+pub struct SchedulerServiceBundle {
+    pub service: Arc<dyn IJobService>,
+    pub job_repo: Arc<dyn ScheduledJobRepository>,
+}
+
+/// 初始化定时任务调度器，登记内置任务并按配置启动调度
+pub fn init_scheduler_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    tenant_repo: Arc<dyn TenantRepository>,
+    access_request_service: Arc<dyn IAccessRequestService>,
+    access_review_service: Arc<dyn IAccessReviewService>,
+    login_log_service: Arc<dyn ILoginLogService>,
+) -> AppResult<SchedulerServiceBundle> {
+    let job_repo: Arc<dyn ScheduledJobRepository> = Arc::new(SeaOrmScheduledJobRepository::new(db.clone()));
+    let run_repo: Arc<dyn JobRunRepository> = Arc::new(SeaOrmJobRunRepository::new(db.clone()));
+    let lock: Arc<dyn JobLock> = match config.scheduler.lock_backend {
+        SchedulerLockBackend::Local => Arc::new(LocalJobLock::new()),
+        SchedulerLockBackend::Redis => Arc::new(RedisJobLock::new(&config.redis_url, &config.scheduler.lock_prefix)?),
+    };
+    let mut scheduler = JobScheduler::new(
+        job_repo.clone(),
+        run_repo.clone(),
+        lock,
+        Duration::from_millis(config.scheduler.lock_ttl_ms),
+        config.scheduler.instance_name.clone(),
+    );
+
+    let cron = &config.scheduler;
+    scheduler.register(
+        "token_blacklist_cleanup",
+        "清理已过期的令牌黑名单",
+        &cron.token_blacklist_cleanup_cron,
+        Arc::new(TokenBlacklistCleanupJob::new(Arc::new(init_token_blacklist_repo(db)))),
+    )?;
+    scheduler.register(
+        "access_request_expiry",
+        "将超时未审批的权限申请置为过期，并收回授权到期的角色",
+        &cron.access_request_expiry_cron,
+        Arc::new(TenantCommandJob::new(
+            tenant_repo.clone(),
+            Arc::new(ExpireAccessRequestsHandler::new(access_request_service)),
+            ExpireAccessRequestsCommand { triggered_by: None },
+        )),
+    )?;
+    scheduler.register(
+        "access_review_close",
+        "关闭到期的访问复核活动，收回未确认的角色",
+        &cron.access_review_close_cron,
+        Arc::new(TenantCommandJob::new(
+            tenant_repo.clone(),
+            Arc::new(CloseDueAccessReviewsHandler::new(access_review_service)),
+            CloseDueAccessReviewsCommand { triggered_by: None },
+        )),
+    )?;
+    scheduler.register(
+        "login_log_retention",
+        "清理超过保留天数的登录日志",
+        &cron.login_log_retention_cron,
+        Arc::new(TenantCommandJob::new(
+            tenant_repo,
+            Arc::new(PurgeLoginLogsHandler::new(login_log_service)),
+            PurgeLoginLogsCommand { triggered_by: None },
+        )),
+    )?;
+    scheduler.register(
+        "job_run_retention",
+        "清理超过保留天数的定时任务执行记录",
+        &cron.job_run_retention_cron,
+        Arc::new(JobRunRetentionJob::new(run_repo.clone(), config.scheduler.run_retention_days)),
+    )?;
+
+    let scheduler = Arc::new(scheduler);
+    spawn_scheduler(
+        scheduler.clone(),
+        Duration::from_millis(config.scheduler.tick_interval_ms),
+        config.scheduler.enabled,
+    );
+
+    let service =
+        Arc::new(JobService::new(job_repo.clone(), run_repo, scheduler as Arc<dyn JobRunner>)) as Arc<dyn IJobService>;
+    Ok(SchedulerServiceBundle { service, job_repo })
+}
+
+/// 先将登记的任务写入任务表，成功后按间隔检查到期任务；本实例关闭调度时只登记任务，供手动触发
+fn spawn_scheduler(scheduler: Arc<JobScheduler>, tick_interval: Duration, enabled: bool) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(tick_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut synced = false;
+        loop {
+            ticker.tick().await;
+            if !synced {
+                match scheduler.sync().await {
+                    Ok(()) => synced = true,
+                    Err(e) => {
+                        tracing::warn!("Failed to register scheduled jobs: {}", e);
+                        continue;
+                    }
+                }
+            }
+            if !enabled {
+                return;
+            }
+            if let Err(e) = scheduler.tick().await {
+                tracing::warn!("Job scheduler failed: {}", e);
+            }
+        }
+    });
+}
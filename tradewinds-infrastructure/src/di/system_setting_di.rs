@@ -1,6 +1,9 @@
+use crate::cache::repositories::{CacheNamespace, CachedSystemSettingRepository};
+use crate::config::AppConfig;
 use crate::persistence::repositories::SeaOrmSystemSettingRepository;
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
+use std::time::Duration;
 use tradewinds_application::interfaces::system_setting_service::ISystemSettingService;
 use tradewinds_application::services::system_setting_service::SystemSettingService;
 use tradewinds_domain::repositories::system_setting_repository::SystemSettingRepository;
@@ -10,9 +13,17 @@ pub struct SystemSettingServiceBundle {
     pub system_setting_repo: Arc<dyn SystemSettingRepository>,
 }
 
-pub fn init_system_setting_service(db: &DatabaseConnection) -> SystemSettingServiceBundle {
-    let system_setting_repo: Arc<dyn SystemSettingRepository> =
+pub fn init_system_setting_service(
+    db: &DatabaseConnection,
+    config: &AppConfig,
+    settings_namespace: Option<Arc<CacheNamespace>>,
+) -> SystemSettingServiceBundle {
+    let mut system_setting_repo: Arc<dyn SystemSettingRepository> =
         Arc::new(SeaOrmSystemSettingRepository::new(db.clone()));
+    if let Some(namespace) = settings_namespace {
+        let ttl = Duration::from_secs(config.cache.system_setting_ttl_secs);
+        system_setting_repo = Arc::new(CachedSystemSettingRepository::new(system_setting_repo, namespace, ttl));
+    }
     let service = Arc::new(SystemSettingService::new(system_setting_repo.clone())) as Arc<dyn ISystemSettingService>;
     SystemSettingServiceBundle { service, system_setting_repo }
 }
@@ -0,0 +1,37 @@
+use crate::di::permission_di::PermissionServiceBundle;
+use crate::di::role_di::RoleServiceBundle;
+use crate::di::user_di::UserServiceBundle;
+use crate::persistence::repositories::SeaOrmTenantRepository;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use tradewinds_application::interfaces::tenant_service::ITenantService;
+use tradewinds_application::services::tenant_service::{TenantService, TenantServiceDeps};
+use tradewinds_domain::repositories::TenantRepository;
+use tradewinds_domain::services::PasswordService;
+
+pub struct TenantServiceBundle {
+    pub service: Arc<dyn ITenantService>,
+    pub tenant_repo: Arc<dyn TenantRepository>,
+}
+
+/// 创建租户时初始化其管理员、角色与权限，所需仓储取自用户、角色与权限服务
+pub fn init_tenant_service(
+    db: &DatabaseConnection,
+    user_bundle: &UserServiceBundle,
+    role_bundle: &RoleServiceBundle,
+    permission_bundle: &PermissionServiceBundle,
+    password_service: Arc<dyn PasswordService>,
+) -> TenantServiceBundle {
+    let tenant_repo: Arc<dyn TenantRepository> = Arc::new(SeaOrmTenantRepository::new(db.clone()));
+    let service = Arc::new(TenantService::new(TenantServiceDeps {
+        tenant_repo: tenant_repo.clone(),
+        user_repo: user_bundle.user_repo.clone(),
+        role_repo: role_bundle.role_repo.clone(),
+        user_role_repo: user_bundle.user_role_repo.clone(),
+        user_agg_repo: user_bundle.user_agg_repo.clone(),
+        role_agg_repo: role_bundle.role_agg_repo.clone(),
+        permission_agg_repo: permission_bundle.permission_agg_repo.clone(),
+        password_service,
+    })) as Arc<dyn ITenantService>;
+    TenantServiceBundle { service, tenant_repo }
+}
@@ -0,0 +1,58 @@
+use crate::config::AppConfig;
+use crate::external::webhook_sender::HttpWebhookSender;
+use crate::persistence::repositories::{SeaOrmWebhookDeliveryRepository, SeaOrmWebhookRepository};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+use std::time::Duration;
+use tradewinds_application::interfaces::IWebhookService;
+use tradewinds_application::services::webhook_dispatcher::{WebhookDispatchOptions, WebhookDispatcher};
+use tradewinds_application::services::webhook_service::WebhookService;
+use tradewinds_domain::entities::webhook_delivery::WebhookRetryPolicy;
+use tradewinds_domain::repositories::{WebhookDeliveryRepository, WebhookRepository};
+use tradewinds_domain::services::WebhookSender;
+use tradewinds_error::AppResult;
+
+pub struct WebhookServiceBundle {
+    pub service: Arc<dyn IWebhookService>,
+    pub webhook_repo: Arc<dyn WebhookRepository>,
+    pub delivery_repo: Arc<dyn WebhookDeliveryRepository>,
+}
+
+/// 初始化 Webhook 服务并启动投递任务
+pub fn init_webhook_service(db: &DatabaseConnection, config: &AppConfig) -> AppResult<WebhookServiceBundle> {
+    let webhook_repo: Arc<dyn WebhookRepository> = Arc::new(SeaOrmWebhookRepository::new(db.clone()));
+    let delivery_repo: Arc<dyn WebhookDeliveryRepository> = Arc::new(SeaOrmWebhookDeliveryRepository::new(db.clone()));
+    let send_timeout = Duration::from_secs(config.webhook.timeout_secs);
+    let sender: Arc<dyn WebhookSender> =
+        Arc::new(HttpWebhookSender::new(send_timeout, config.webhook.allowed_hosts.clone())?);
+    let options = WebhookDispatchOptions {
+        retry_policy: WebhookRetryPolicy {
+            base_delay_secs: config.webhook.retry_base_secs,
+            max_delay_secs: config.webhook.retry_max_secs,
+            max_attempts: config.webhook.max_attempts,
+        },
+        batch_size: config.webhook.batch_size,
+        disable_after_failures: config.webhook.disable_after_failures,
+        lease_secs: config.webhook.lease_secs,
+        send_timeout,
+    };
+    let dispatcher = WebhookDispatcher::new(webhook_repo.clone(), delivery_repo.clone(), sender, options);
+    spawn_dispatcher(dispatcher, Duration::from_millis(config.webhook.poll_interval_ms));
+
+    let service =
+        Arc::new(WebhookService::new(webhook_repo.clone(), delivery_repo.clone())) as Arc<dyn IWebhookService>;
+    Ok(WebhookServiceBundle { service, webhook_repo, delivery_repo })
+}
+
+fn spawn_dispatcher(dispatcher: WebhookDispatcher, poll_interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(poll_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(e) = dispatcher.dispatch_once().await {
+                tracing::warn!("Webhook dispatcher failed: {}", e);
+            }
+        }
+    });
+}
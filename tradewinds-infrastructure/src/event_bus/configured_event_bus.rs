@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use tradewinds_domain::services::{Event, EventBus, EventHandler, EventRegistry};
+use tradewinds_error::AppResult;
+
+use crate::config::{EventBusBackend, EventBusConfig, RabbitMqConfig};
+use crate::event_bus::{InProcessEventBus, RabbitMqEventBus, RedisEventBus};
+use crate::messaging::RabbitMQ;
+
+/// 按配置选择的事件总线
+///
+/// 统一订阅者的注册方式；订阅者注册完成后调用 `start` 开始消费
+pub enum ConfiguredEventBus {
+    InProcess(Arc<InProcessEventBus>),
+    Redis(Arc<RedisEventBus>),
+    RabbitMq(Arc<RabbitMqEventBus>),
+}
+
+impl ConfiguredEventBus {
+    pub async fn new(
+        config: &EventBusConfig,
+        redis_url: &str,
+        rabbitmq: &RabbitMqConfig,
+        registry: EventRegistry,
+    ) -> AppResult<Self> {
+        Ok(match config.backend {
+            EventBusBackend::InProcess => Self::InProcess(Arc::new(InProcessEventBus::new())),
+            EventBusBackend::Redis => {
+                Self::Redis(Arc::new(RedisEventBus::new(redis_url, config.redis_streams.clone(), registry)?))
+            }
+            EventBusBackend::RabbitMq => {
+                let mq = Arc::new(RabbitMQ::new(rabbitmq).await?);
+                Self::RabbitMq(Arc::new(RabbitMqEventBus::new(mq, registry)))
+            }
+        })
+    }
+
+    /// 供发布方使用的事件总线
+    pub fn event_bus(&self) -> Arc<dyn EventBus> {
+        match self {
+            Self::InProcess(bus) => bus.clone(),
+            Self::Redis(bus) => bus.clone(),
+            Self::RabbitMq(bus) => bus.clone(),
+        }
+    }
+
+    pub fn subscribe<E, H>(&self, name: &'static str, handler: H)
+    where
+        E: Event,
+        H: EventHandler<E> + 'static,
+    {
+        match self {
+            Self::InProcess(bus) => bus.subscribe(name, handler),
+            Self::Redis(bus) => bus.subscribe(name, handler),
+            Self::RabbitMq(bus) => bus.subscribe(name, handler),
+        }
+    }
+
+    pub fn subscribe_all<H>(&self, name: &'static str, handler: H)
+    where
+        H: EventHandler<dyn Event> + 'static,
+    {
+        match self {
+            Self::InProcess(bus) => bus.subscribe_all(name, handler),
+            Self::Redis(bus) => bus.subscribe_all(name, handler),
+            Self::RabbitMq(bus) => bus.subscribe_all(name, handler),
+        }
+    }
+
+    /// 开始消费；进程内总线在发布时直接分发，无需启动
+    pub fn start(&self) {
+        match self {
+            Self::InProcess(_) => {}
+            Self::Redis(bus) => bus.start(),
+            Self::RabbitMq(bus) => bus.start(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
+use tradewinds_common::request_context::{current_request_context, with_request_context};
+use tradewinds_common::tenant::{current_tenant_id, with_tenant};
+use tradewinds_domain::services::{Event, EventBus, EventHandler};
+use tradewinds_error::{AppError, AppResult};
+
+type HandlerFuture = Pin<Box<dyn Future<Output = AppResult<()>> + Send>>;
+
+/// 类型擦除后的订阅者
+struct Subscriber {
+    name: &'static str,
+    handle: Box<dyn Fn(Arc<dyn Event>) -> HandlerFuture + Send + Sync>,
+}
+
+/// 进程内事件总线
+///
+/// 订阅者按事件的具体类型注册，`subscribe_all` 注册的订阅者接收所有事件。
+/// 发布时为每个订阅者启动独立任务并发处理，沿用发布方的租户与请求上下文，并等待全部订阅者处理完成；
+/// 单个订阅者失败或 panic 不影响其他订阅者，但发布结果为失败，发件箱中继据此重试（订阅者需幂等）。
+#[derive(Default)]
+pub struct InProcessEventBus {
+    subscribers: RwLock<HashMap<TypeId, Vec<Arc<Subscriber>>>>,
+    wildcard_subscribers: RwLock<Vec<Arc<Subscriber>>>,
+}
+
+impl InProcessEventBus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 以 `name` 为订阅名订阅指定类型的事件
+    pub fn subscribe<E, H>(&self, name: &'static str, handler: H)
+    where
+        E: Event,
+        H: EventHandler<E> + 'static,
+    {
+        let handler = Arc::new(handler);
+        let subscriber = Subscriber {
+            name,
+            handle: Box::new(move |event| {
+                let handler = handler.clone();
+                Box::pin(async move {
+                    let any: &dyn Any = event.as_ref();
+                    match any.downcast_ref::<E>() {
+                        Some(event) => handler.handle(event).await,
+                        None => Ok(()),
+                    }
+                })
+            }),
+        };
+        self.subscribers
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(Arc::new(subscriber));
+    }
+
+    /// 以 `name` 为订阅名订阅所有事件
+    pub fn subscribe_all<H>(&self, name: &'static str, handler: H)
+    where
+        H: EventHandler<dyn Event> + 'static,
+    {
+        let handler = Arc::new(handler);
+        let subscriber = Subscriber {
+            name,
+            handle: Box::new(move |event| {
+                let handler = handler.clone();
+                Box::pin(async move { handler.handle(event.as_ref()).await })
+            }),
+        };
+        self.wildcard_subscribers.write().unwrap_or_else(PoisonError::into_inner).push(Arc::new(subscriber));
+    }
+
+    fn subscribers_of(&self, type_id: TypeId) -> Vec<Arc<Subscriber>> {
+        let subscribers = self.subscribers.read().unwrap_or_else(PoisonError::into_inner);
+        let wildcard_subscribers = self.wildcard_subscribers.read().unwrap_or_else(PoisonError::into_inner);
+        subscribers.get(&type_id).into_iter().flatten().chain(wildcard_subscribers.iter()).cloned().collect()
+    }
+}
+
+#[async_trait]
+impl EventBus for InProcessEventBus {
+    async fn publish(&self, event: Arc<dyn Event>) -> AppResult<()> {
+        let any: &dyn Any = event.as_ref();
+        let subscribers = self.subscribers_of(any.type_id());
+        if subscribers.is_empty() {
+            return Ok(());
+        }
+
+        let tenant_id = current_tenant_id();
+        let context = current_request_context();
+        let tasks: Vec<_> = subscribers
+            .into_iter()
+            .map(|subscriber| {
+                let task = with_tenant(
+                    tenant_id.clone(),
+                    with_request_context(context.clone(), (subscriber.handle)(event.clone())),
+                );
+                (subscriber.name, tokio::spawn(task))
+            })
+            .collect();
+
+        let mut failures = Vec::new();
+        for (name, task) in tasks {
+            match task.await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => {
+                    tracing::warn!("Event subscriber {} failed to handle {}: {}", name, event.event_type(), e);
+                    failures.push(format!("{}: {}", name, e));
+                }
+                Err(e) => {
+                    tracing::error!("Event subscriber {} aborted while handling {}: {}", name, event.event_type(), e);
+                    failures.push(format!("{}: {}", name, e));
+                }
+            }
+        }
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::Internal(format!(
+                "{} of the subscribers failed to handle {}: {}",
+                failures.len(),
+                event.event_type(),
+                failures.join("; ")
+            )))
+        }
+    }
+}
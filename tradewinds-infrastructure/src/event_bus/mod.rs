@@ -1,2 +1,9 @@
-// pub mod redis_event_bus;
-// pub use redis_event_bus::RedisEventBus;
+pub mod configured_event_bus;
+pub mod in_process_event_bus;
+pub mod rabbitmq_event_bus;
+pub mod redis_event_bus;
+
+pub use configured_event_bus::ConfiguredEventBus;
+pub use in_process_event_bus::InProcessEventBus;
+pub use rabbitmq_event_bus::RabbitMqEventBus;
+pub use redis_event_bus::RedisEventBus;
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::any::{Any, type_name};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
+use tradewinds_common::request_context::{RequestContext, current_request_context, with_request_context};
+use tradewinds_common::tenant::{current_tenant_id, with_tenant};
+use tradewinds_domain::services::{Event, EventBus, EventHandler, EventRegistry};
+use tradewinds_error::AppResult;
+
+use crate::messaging::{MessageHandler, MessageQueue, QueueBinding, RabbitMQ};
+
+type HandlerFuture = Pin<Box<dyn Future<Output = AppResult<()>> + Send>>;
+
+/// 经 RabbitMQ 传递的事件，携带发布方的租户与请求上下文
+#[derive(Debug, Serialize, Deserialize)]
+struct EventEnvelope {
+    event_type: String,
+    payload: String,
+    tenant_id: String,
+    client_ip: Option<String>,
+    user_agent: Option<String>,
+    request_id: Option<String>,
+    actor_id: Option<String>,
+}
+
+/// 订阅者，对应一个持久化队列
+struct Subscription {
+    binding: QueueBinding,
+    handle: Box<dyn Fn(Arc<dyn Event>) -> HandlerFuture + Send + Sync>,
+}
+
+/// 基于 RabbitMQ 的事件总线
+///
+/// 事件以事件类型为路由键发布到主题交换机，等待 broker 确认；无法路由（尚无订阅队列）或未被确认时发布失败，
+/// 发件箱中继据此重试。每个订阅者声明以其订阅名命名的持久化队列并绑定关注的事件类型，
+/// 多个实例共同消费同一队列，同一消息只由一个实例处理；处理失败经重试队列延迟重试，
+/// 超过最大重试次数后转入死信队列。投递语义为至少一次，订阅者须幂等。
+///
+/// 订阅者须在 `start` 之前注册，订阅名不可重复且须在部署间保持稳定。
+pub struct RabbitMqEventBus {
+    mq: Arc<RabbitMQ>,
+    registry: EventRegistry,
+    subscriptions: RwLock<Vec<Arc<Subscription>>>,
+}
+
+impl RabbitMqEventBus {
+    pub fn new(mq: Arc<RabbitMQ>, registry: EventRegistry) -> Self {
+        Self { mq, registry, subscriptions: RwLock::new(Vec::new()) }
+    }
+
+    /// 以 `name` 为订阅名订阅指定类型的事件，事件类型须已在注册表中登记
+    pub fn subscribe<E, H>(&self, name: &'static str, handler: H)
+    where
+        E: Event,
+        H: EventHandler<E> + 'static,
+    {
+        let event_type = self
+            .registry
+            .event_type_of::<E>()
+            .unwrap_or_else(|| panic!("Event {} is not registered in the event registry", type_name::<E>()));
+        let handler = Arc::new(handler);
+        self.add_subscription(
+            name,
+            vec![event_type],
+            Box::new(move |event| {
+                let handler = handler.clone();
+                Box::pin(async move {
+                    let any: &dyn Any = event.as_ref();
+                    match any.downcast_ref::<E>() {
+                        Some(event) => handler.handle(event).await,
+                        None => Ok(()),
+                    }
+                })
+            }),
+        );
+    }
+
+    /// 以 `name` 为订阅名订阅所有已注册类型的事件
+    pub fn subscribe_all<H>(&self, name: &'static str, handler: H)
+    where
+        H: EventHandler<dyn Event> + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.add_subscription(
+            name,
+            self.registry.event_types(),
+            Box::new(move |event| {
+                let handler = handler.clone();
+                Box::pin(async move { handler.handle(event.as_ref()).await })
+            }),
+        );
+    }
+
+    /// 声明各订阅者的队列并启动消费任务
+    pub fn start(self: &Arc<Self>) {
+        let subscriptions = self.subscriptions.read().unwrap_or_else(PoisonError::into_inner).clone();
+        for subscription in subscriptions {
+            let bus = self.clone();
+            tokio::spawn(async move {
+                let binding = subscription.binding.clone();
+                let consumer = SubscriptionConsumer { registry: bus.registry.clone(), subscription };
+                if let Err(e) = bus.mq.subscribe::<EventEnvelope, _>(binding.clone(), consumer).await {
+                    tracing::error!("Failed to start RabbitMQ event consumer on {}: {}", binding.queue, e);
+                }
+            });
+        }
+    }
+
+    fn add_subscription(
+        &self,
+        name: &'static str,
+        event_types: Vec<&'static str>,
+        handle: Box<dyn Fn(Arc<dyn Event>) -> HandlerFuture + Send + Sync>,
+    ) {
+        let queue = format!("events.{}", name);
+        let mut subscriptions = self.subscriptions.write().unwrap_or_else(PoisonError::into_inner);
+        if subscriptions.iter().any(|subscription| subscription.binding.queue == queue) {
+            panic!("Event subscription {} is already registered", name);
+        }
+        subscriptions.push(Arc::new(Subscription { binding: QueueBinding::new(queue, &event_types), handle }));
+    }
+}
+
+/// 把队列中的消息还原为事件，在发布方的租户与请求上下文内交给订阅者
+struct SubscriptionConsumer {
+    registry: EventRegistry,
+    subscription: Arc<Subscription>,
+}
+
+#[async_trait]
+impl MessageHandler<EventEnvelope> for SubscriptionConsumer {
+    async fn handle(&self, envelope: EventEnvelope) -> AppResult<()> {
+        let event = self.registry.decode(&envelope.event_type, &envelope.payload)?;
+        let context = RequestContext {
+            client_ip: envelope.client_ip,
+            user_agent: envelope.user_agent,
+            request_id: envelope.request_id,
+            actor_id: envelope.actor_id,
+        };
+        with_tenant(envelope.tenant_id, with_request_context(context, (self.subscription.handle)(event))).await
+    }
+}
+
+#[async_trait]
+impl EventBus for RabbitMqEventBus {
+    async fn publish(&self, event: Arc<dyn Event>) -> AppResult<()> {
+        let event_type = event.event_type();
+        let context = current_request_context();
+        let envelope = EventEnvelope {
+            event_type: event_type.to_string(),
+            payload: event.to_json()?,
+            tenant_id: current_tenant_id(),
+            client_ip: context.client_ip,
+            user_agent: context.user_agent,
+            request_id: context.request_id,
+            actor_id: context.actor_id,
+        };
+        self.mq.publish(event_type, &envelope).await
+    }
+}
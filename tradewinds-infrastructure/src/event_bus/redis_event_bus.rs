@@ -1,39 +1,304 @@
-// use async_trait::async_trait;
-// use redis::{Client, Commands, RedisError};
-// use std::sync::Arc;
-//
-// // use crate::{
-// //     application::interfaces::event::IEventBus,
-// //     domain::services::event_bus::{Event, EventBus},
-// //     shared::types::{AppError, AppResult},
-// // };
-//
-// pub struct RedisEventBus {
-//     // client: Client,
-// }
-//
-// impl RedisEventBus {
-//     // pub fn new(redis_url: &str) -> AppResult<Self> {
-//     //     let client = Client::open(redis_url)
-//     //         .map_err(|e| AppError::Initialisation(e.to_string()))?;
-//     //     Ok(Self { client })
-//     // }
-// }
-//
-// // impl EventBus for RedisEventBus {
-// //     async fn publish(&self, event: Arc<dyn Event>) -> AppResult<()> {
-// //         let mut conn = self.client.get_connection()
-// //             .map_err(|e| AppError::Initialisation(e.to_string()))?;
-// //         let payload = serde_json::to_string(event.as_ref())
-// //             .map_err(|e| AppError::Serialization(e.to_string()))?;
-// //         conn.publish(event.topic(), payload)
-// //             .map_err(|e| AppError::Database(e.to_string()))?;
-// //         Ok(())
-// //     }
-// // }
-//
-// // impl IEventBus for RedisEventBus {
-// //     async fn publish(&self, event: Arc<dyn Event>) -> AppResult<()> {
-// //         EventBus::publish(self, event).await
-// //     }
-// // }
+use async_trait::async_trait;
+use futures::FutureExt;
+use redis::aio::MultiplexedConnection;
+use redis::streams::{StreamAutoClaimOptions, StreamAutoClaimReply, StreamId, StreamMaxlen, StreamPendingCountReply};
+use redis::streams::{StreamReadOptions, StreamReadReply};
+use redis::{AsyncCommands, Client};
+use std::any::{Any, type_name};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tradewinds_common::request_context::{RequestContext, current_request_context, with_request_context};
+use tradewinds_common::tenant::{DEFAULT_TENANT_ID, current_tenant_id, with_tenant};
+use tradewinds_domain::services::{Event, EventBus, EventHandler, EventRegistry};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::config::RedisStreamsConfig;
+
+type HandlerFuture = Pin<Box<dyn Future<Output = AppResult<()>> + Send>>;
+
+/// 消费失败后重新连接前的等待时间
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// 订阅者，对应每个流上的一个消费组
+struct Subscription {
+    /// 消费组名称，取订阅时给出的订阅名，须在部署间保持稳定
+    group: String,
+    streams: Vec<String>,
+    handle: Box<dyn Fn(Arc<dyn Event>) -> HandlerFuture + Send + Sync>,
+}
+
+/// 基于 Redis Streams 的事件总线
+///
+/// 每种事件类型写入一个流（`XADD`，按 `max_len` 近似裁剪），消息携带发布方的租户与请求上下文。
+/// 每个订阅者在其关注的流上建立以订阅名命名的消费组，多个实例以不同消费者名称共同消费，同一消息只由一个实例处理。
+/// 处理成功后确认；失败或实例崩溃遗留的未确认消息在空闲超过 `reclaim_idle_ms` 后被重新认领，
+/// 超过 `max_deliveries` 次仍失败的消息记录错误后丢弃。投递语义为至少一次，订阅者须幂等。
+///
+/// 订阅者须在 `start` 之前注册，订阅名不可重复；更改订阅名会建立新的消费组并从最新消息开始消费。
+pub struct RedisEventBus {
+    client: Client,
+    connection: Mutex<Option<MultiplexedConnection>>,
+    registry: EventRegistry,
+    config: RedisStreamsConfig,
+    subscriptions: RwLock<Vec<Arc<Subscription>>>,
+}
+
+impl RedisEventBus {
+    pub fn new(redis_url: &str, config: RedisStreamsConfig, registry: EventRegistry) -> AppResult<Self> {
+        Ok(Self {
+            client: Client::open(redis_url)?,
+            connection: Mutex::new(None),
+            registry,
+            config,
+            subscriptions: RwLock::new(Vec::new()),
+        })
+    }
+
+    /// 以 `name` 为订阅名订阅指定类型的事件，事件类型须已在注册表中登记
+    pub fn subscribe<E, H>(&self, name: &'static str, handler: H)
+    where
+        E: Event,
+        H: EventHandler<E> + 'static,
+    {
+        let event_type = self
+            .registry
+            .event_type_of::<E>()
+            .unwrap_or_else(|| panic!("Event {} is not registered in the event registry", type_name::<E>()));
+        let handler = Arc::new(handler);
+        self.add_subscription(
+            name,
+            vec![self.stream_key(event_type)],
+            Box::new(move |event| {
+                let handler = handler.clone();
+                Box::pin(async move {
+                    let any: &dyn Any = event.as_ref();
+                    match any.downcast_ref::<E>() {
+                        Some(event) => handler.handle(event).await,
+                        None => Ok(()),
+                    }
+                })
+            }),
+        );
+    }
+
+    /// 以 `name` 为订阅名订阅所有已注册类型的事件
+    pub fn subscribe_all<H>(&self, name: &'static str, handler: H)
+    where
+        H: EventHandler<dyn Event> + 'static,
+    {
+        let handler = Arc::new(handler);
+        let streams = self.registry.event_types().into_iter().map(|event_type| self.stream_key(event_type)).collect();
+        self.add_subscription(
+            name,
+            streams,
+            Box::new(move |event| {
+                let handler = handler.clone();
+                Box::pin(async move { handler.handle(event.as_ref()).await })
+            }),
+        );
+    }
+
+    /// 为每个订阅者启动消费任务
+    pub fn start(self: &Arc<Self>) {
+        let subscriptions = self.subscriptions.read().unwrap_or_else(PoisonError::into_inner).clone();
+        for subscription in subscriptions {
+            let bus = self.clone();
+            tokio::spawn(async move {
+                loop {
+                    if let Err(e) = bus.consume(&subscription).await {
+                        tracing::warn!("Redis event consumer {} stopped: {}", subscription.group, e);
+                    }
+                    tokio::time::sleep(RECONNECT_DELAY).await;
+                }
+            });
+        }
+    }
+
+    fn add_subscription(
+        &self,
+        name: &'static str,
+        streams: Vec<String>,
+        handle: Box<dyn Fn(Arc<dyn Event>) -> HandlerFuture + Send + Sync>,
+    ) {
+        let mut subscriptions = self.subscriptions.write().unwrap_or_else(PoisonError::into_inner);
+        if subscriptions.iter().any(|subscription| subscription.group == name) {
+            panic!("Event subscription {} is already registered", name);
+        }
+        subscriptions.push(Arc::new(Subscription { group: name.to_string(), streams, handle }));
+    }
+
+    fn stream_key(&self, event_type: &str) -> String {
+        format!("{}{}", self.config.stream_prefix, event_type)
+    }
+
+    /// 发布使用的共享连接，断开后在下次发布时重建
+    async fn connection(&self) -> AppResult<MultiplexedConnection> {
+        let mut connection = self.connection.lock().await;
+        if let Some(conn) = connection.as_ref() {
+            return Ok(conn.clone());
+        }
+        let conn = self.client.get_multiplexed_async_connection().await?;
+        *connection = Some(conn.clone());
+        Ok(conn)
+    }
+
+    /// 持续消费一个订阅者的消息，连接出错时返回
+    ///
+    /// 阻塞读取会占用连接，每个订阅者使用独立连接
+    async fn consume(&self, subscription: &Subscription) -> AppResult<()> {
+        let mut conn = self.client.get_multiplexed_async_connection().await?;
+        for stream in &subscription.streams {
+            create_group(&mut conn, stream, &subscription.group).await?;
+        }
+
+        let reclaim_interval = Duration::from_millis(self.config.reclaim_idle_ms);
+        let mut last_reclaim: Option<Instant> = None;
+        let ids = vec![">"; subscription.streams.len()];
+        let options = StreamReadOptions::default()
+            .group(&subscription.group, &self.config.consumer_name)
+            .count(self.config.batch_size)
+            .block(self.config.block_ms as usize);
+        loop {
+            if last_reclaim.is_none_or(|at| at.elapsed() >= reclaim_interval) {
+                for stream in &subscription.streams {
+                    self.reclaim(&mut conn, subscription, stream).await?;
+                }
+                last_reclaim = Some(Instant::now());
+            }
+
+            let reply: Option<StreamReadReply> = conn.xread_options(&subscription.streams, &ids, &options).await?;
+            for key in reply.map(|reply| reply.keys).unwrap_or_default() {
+                for entry in key.ids {
+                    self.process(&mut conn, subscription, &key.key, entry).await?;
+                }
+            }
+        }
+    }
+
+    /// 认领空闲超时的未确认消息（含本消费者此前处理失败的），超过最大投递次数的直接丢弃
+    async fn reclaim(
+        &self,
+        conn: &mut MultiplexedConnection,
+        subscription: &Subscription,
+        stream: &str,
+    ) -> AppResult<()> {
+        let mut cursor = "0-0".to_string();
+        loop {
+            let reply: StreamAutoClaimReply = conn
+                .xautoclaim_options(
+                    stream,
+                    &subscription.group,
+                    &self.config.consumer_name,
+                    self.config.reclaim_idle_ms,
+                    &cursor,
+                    StreamAutoClaimOptions::default().count(self.config.batch_size),
+                )
+                .await?;
+            for entry in reply.claimed {
+                let pending: StreamPendingCountReply =
+                    conn.xpending_count(stream, &subscription.group, &entry.id, &entry.id, 1).await?;
+                let deliveries = pending.ids.first().map(|p| p.times_delivered as u64).unwrap_or_default();
+                if deliveries > self.config.max_deliveries {
+                    tracing::error!(
+                        "Event subscriber {} gave up on {} {} after {} deliveries",
+                        subscription.group,
+                        stream,
+                        entry.id,
+                        deliveries
+                    );
+                    let _: u64 = conn.xack(stream, &subscription.group, &[&entry.id]).await?;
+                    continue;
+                }
+                self.process(conn, subscription, stream, entry).await?;
+            }
+            if reply.next_stream_id == "0-0" {
+                return Ok(());
+            }
+            cursor = reply.next_stream_id;
+        }
+    }
+
+    /// 处理单条消息，成功后确认；订阅者失败时保留为未确认，等待重新认领
+    async fn process(
+        &self,
+        conn: &mut MultiplexedConnection,
+        subscription: &Subscription,
+        stream: &str,
+        entry: StreamId,
+    ) -> AppResult<()> {
+        let event_type: String = entry.get("event_type").unwrap_or_default();
+        let payload: String = entry.get("payload").unwrap_or_default();
+        let event = match self.registry.decode(&event_type, &payload) {
+            Ok(event) => event,
+            Err(e) => {
+                // 无法还原的消息重试也不会成功，确认后丢弃
+                tracing::error!("Dropping undecodable event {} {} from {}: {}", event_type, entry.id, stream, e);
+                let _: u64 = conn.xack(stream, &subscription.group, &[&entry.id]).await?;
+                return Ok(());
+            }
+        };
+
+        let tenant_id: String = entry.get("tenant_id").unwrap_or_else(|| DEFAULT_TENANT_ID.to_string());
+        let context = RequestContext {
+            client_ip: entry.get("client_ip"),
+            user_agent: entry.get("user_agent"),
+            request_id: entry.get("request_id"),
+            actor_id: entry.get("actor_id"),
+        };
+        let task = with_tenant(tenant_id, with_request_context(context, (subscription.handle)(event)));
+        match AssertUnwindSafe(task).catch_unwind().await {
+            Ok(Ok(())) => {
+                let _: u64 = conn.xack(stream, &subscription.group, &[&entry.id]).await?;
+            }
+            Ok(Err(e)) => {
+                tracing::warn!("Event subscriber {} failed to handle {}: {}", subscription.group, event_type, e)
+            }
+            Err(_) => {
+                tracing::error!("Event subscriber {} panicked while handling {}", subscription.group, event_type)
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 创建消费组，已存在时忽略；新建的消费组只接收此后发布的消息
+async fn create_group(conn: &mut MultiplexedConnection, stream: &str, group: &str) -> AppResult<()> {
+    let result: redis::RedisResult<()> = conn.xgroup_create_mkstream(stream, group, "$").await;
+    match result {
+        Err(e) if e.code() != Some("BUSYGROUP") => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+#[async_trait]
+impl EventBus for RedisEventBus {
+    async fn publish(&self, event: Arc<dyn Event>) -> AppResult<()> {
+        let event_type = event.event_type();
+        let payload = event.to_json()?;
+        let context = current_request_context();
+        let mut fields =
+            vec![("event_type", event_type.to_string()), ("payload", payload), ("tenant_id", current_tenant_id())];
+        let optional_fields = [
+            ("client_ip", context.client_ip),
+            ("user_agent", context.user_agent),
+            ("request_id", context.request_id),
+            ("actor_id", context.actor_id),
+        ];
+        fields.extend(optional_fields.into_iter().filter_map(|(name, value)| value.map(|value| (name, value))));
+
+        let mut conn = self.connection().await?;
+        let result: redis::RedisResult<String> = conn
+            .xadd_maxlen(self.stream_key(event_type), StreamMaxlen::Approx(self.config.max_len), "*", &fields)
+            .await;
+        if let Err(e) = result {
+            // 丢弃可能已断开的连接，下次发布时重建
+            self.connection.lock().await.take();
+            return Err(AppError::System(format!("Failed to publish {} to Redis: {}", event_type, e)));
+        }
+        Ok(())
+    }
+}
@@ -2,3 +2,5 @@
 // 用于集成第三方服务
 
 pub mod email_service;
+pub mod notification_service;
+pub mod webhook_sender;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use tradewinds_application::interfaces::INotificationService;
+use tradewinds_domain::entities::user::User;
+use tradewinds_error::AppResult;
+
+use crate::external::email_service::EmailService;
+
+/// 邮件通知，发送到用户邮箱
+pub struct EmailNotificationService {
+    email_service: Arc<dyn EmailService>,
+}
+
+impl EmailNotificationService {
+    pub fn new(email_service: Arc<dyn EmailService>) -> Self {
+        Self { email_service }
+    }
+}
+
+#[async_trait]
+impl INotificationService for EmailNotificationService {
+    async fn notify(&self, recipient: &User, subject: &str, body: &str) -> AppResult<()> {
+        self.email_service.send_email(recipient.email.value(), subject, body).await
+    }
+}
+
+/// 日志通知，未配置邮件服务时使用
+pub struct LogNotificationService;
+
+#[async_trait]
+impl INotificationService for LogNotificationService {
+    async fn notify(&self, recipient: &User, subject: &str, body: &str) -> AppResult<()> {
+        tracing::info!("Notification to {} <{}>: {} - {}", recipient.username, recipient.email.value(), subject, body);
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::{CONTENT_TYPE, HOST, USER_AGENT};
+use hyper::{Method, Request, Uri};
+use hyper_util::rt::TokioIo;
+use sha2::Sha256;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::ServerName;
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+use tradewinds_domain::entities::webhook::is_public_address;
+use tradewinds_domain::entities::{Webhook, WebhookDelivery};
+use tradewinds_domain::services::WebhookSender;
+use tradewinds_error::{AppError, AppResult};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 签名算法前缀，接收方据此选择校验方式
+const SIGNATURE_SCHEME: &str = "sha256";
+
+/// 基于 HTTP/1.1 的 Webhook 发送
+///
+/// 以 POST 发送投递记录的 JSON 请求体，并附带以下请求头：
+/// - `X-Webhook-Event`: 事件类型
+/// - `X-Webhook-Delivery`: 投递记录ID，重试时不变，接收方可据此去重
+/// - `X-Webhook-Timestamp`: 发送时间（Unix 秒）
+/// - `X-Webhook-Signature`: `sha256=<hex>`，以端点密钥对 `{timestamp}.{body}` 计算的 HMAC-SHA256
+///
+/// 接收方应校验签名并拒绝时间戳过旧的请求以防重放。HTTPS 使用内置的 Web PKI 根证书校验服务端证书。
+///
+/// 连接前解析主机名，任一地址为回环、私有、链路本地等非公网地址时拒绝发送（`allowed_hosts` 中的主机名除外），
+/// 并直接连接校验过的地址，避免解析结果在校验后被替换为内网地址。
+pub struct HttpWebhookSender {
+    tls: TlsConnector,
+    timeout: Duration,
+    allowed_hosts: Vec<String>,
+}
+
+impl HttpWebhookSender {
+    pub fn new(timeout: Duration, allowed_hosts: Vec<String>) -> AppResult<Self> {
+        let roots = RootCertStore { roots: webpki_roots::TLS_SERVER_ROOTS.to_vec() };
+        let provider = Arc::new(tokio_rustls::rustls::crypto::ring::default_provider());
+        let config = ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|e| AppError::System(format!("TLS configuration error: {}", e)))?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        Ok(Self { tls: TlsConnector::from(Arc::new(config)), timeout, allowed_hosts })
+    }
+
+    /// 解析主机名并校验地址，返回可连接的地址
+    async fn resolve(&self, host: &str, port: u16) -> AppResult<Vec<SocketAddr>> {
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| AppError::System(format!("Failed to resolve {}: {}", host, e)))?
+            .collect();
+        if addrs.is_empty() {
+            return Err(AppError::System(format!("Failed to resolve {}: no addresses", host)));
+        }
+        if !self.allowed_hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host))
+            && let Some(addr) = addrs.iter().find(|addr| !is_public_address(addr.ip()))
+        {
+            return Err(AppError::Validation(format!(
+                "Webhook host {} resolves to non-public address {}",
+                host,
+                addr.ip()
+            )));
+        }
+        Ok(addrs)
+    }
+
+    async fn post(&self, uri: &Uri, request: Request<Full<Bytes>>) -> AppResult<u16> {
+        let host = uri.host().ok_or_else(|| AppError::Validation(format!("Webhook url has no host: {}", uri)))?;
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let https = uri.scheme_str() == Some("https");
+        let port = uri.port_u16().unwrap_or(if https { 443 } else { 80 });
+        let addrs = self.resolve(host, port).await?;
+        let stream = TcpStream::connect(addrs.as_slice())
+            .await
+            .map_err(|e| AppError::System(format!("Failed to connect to {}:{}: {}", host, port, e)))?;
+        if !https {
+            return exchange(stream, request).await;
+        }
+        let server_name = ServerName::try_from(host.to_string())
+            .map_err(|e| AppError::Validation(format!("Invalid webhook host {}: {}", host, e)))?;
+        let stream = self
+            .tls
+            .connect(server_name, stream)
+            .await
+            .map_err(|e| AppError::System(format!("TLS handshake with {} failed: {}", host, e)))?;
+        exchange(stream, request).await
+    }
+}
+
+/// 在已建立的连接上发送请求，返回响应状态码
+async fn exchange<S>(stream: S, request: Request<Full<Bytes>>) -> AppResult<u16>
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+        .await
+        .map_err(|e| AppError::System(format!("HTTP handshake failed: {}", e)))?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::debug!("Webhook connection closed with error: {}", e);
+        }
+    });
+    let response =
+        sender.send_request(request).await.map_err(|e| AppError::System(format!("HTTP request failed: {}", e)))?;
+    Ok(response.status().as_u16())
+}
+
+/// 以端点密钥对 `{timestamp}.{body}` 签名
+fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("{}={}", SIGNATURE_SCHEME, hex::encode(mac.finalize().into_bytes()))
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, webhook: &Webhook, delivery: &WebhookDelivery) -> AppResult<u16> {
+        let uri: Uri = webhook
+            .url
+            .parse()
+            .map_err(|e| AppError::Validation(format!("Invalid webhook url {}: {}", webhook.url, e)))?;
+        let authority = uri.authority().map(|a| a.as_str().to_string()).unwrap_or_default();
+        let path = uri.path_and_query().map(|p| p.as_str()).unwrap_or("/");
+        let timestamp = Utc::now().timestamp();
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(path)
+            .header(HOST, authority)
+            .header(CONTENT_TYPE, "application/json")
+            .header(USER_AGENT, "tradewinds-webhooks")
+            .header("X-Webhook-Event", &delivery.event_type)
+            .header("X-Webhook-Delivery", delivery.id.value())
+            .header("X-Webhook-Timestamp", timestamp.to_string())
+            .header("X-Webhook-Signature", sign(&webhook.secret, timestamp, &delivery.payload))
+            .body(Full::new(Bytes::from(delivery.payload.clone())))
+            .map_err(|e| AppError::Internal(format!("Failed to build webhook request: {}", e)))?;
+
+        tokio::time::timeout(self.timeout, self.post(&uri, request))
+            .await
+            .map_err(|_| AppError::System(format!("Webhook request timed out after {}s", self.timeout.as_secs())))?
+    }
+}
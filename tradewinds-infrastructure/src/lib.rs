@@ -7,4 +7,5 @@ pub mod external;
 pub mod logging;
 pub mod messaging;
 pub mod persistence;
+pub mod scheduler;
 pub mod services;
@@ -0,0 +1,82 @@
+use lapin::options::ConfirmSelectOptions;
+use lapin::{Channel, Connection};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tradewinds_error::{AppError, AppResult};
+
+/// 发布通道池
+///
+/// 通道开启发布确认后复用，数量上限为 `size`；取出的通道归还时若已关闭则丢弃，下次按需重建
+pub struct ChannelPool {
+    connection: Arc<Connection>,
+    idle: Mutex<Vec<Channel>>,
+    permits: Semaphore,
+}
+
+/// 从通道池取出的通道，离开作用域时归还
+pub struct PooledChannel<'a> {
+    channel: Option<Channel>,
+    pool: &'a ChannelPool,
+    _permit: SemaphorePermit<'a>,
+}
+
+impl ChannelPool {
+    pub fn new(connection: Arc<Connection>, size: usize) -> Self {
+        Self { connection, idle: Mutex::new(Vec::with_capacity(size)), permits: Semaphore::new(size.max(1)) }
+    }
+
+    /// 取出一个可用通道，池中没有空闲通道时等待
+    pub async fn acquire(&self) -> AppResult<PooledChannel<'_>> {
+        let permit = self.permits.acquire().await.map_err(|_| AppError::System("Channel pool closed".to_string()))?;
+        let idle = {
+            let mut idle = self.idle.lock().unwrap_or_else(PoisonError::into_inner);
+            std::iter::from_fn(|| idle.pop()).find(|channel| channel.status().connected())
+        };
+        let channel = match idle {
+            Some(channel) => channel,
+            None => self.create_channel().await?,
+        };
+        Ok(PooledChannel { channel: Some(channel), pool: self, _permit: permit })
+    }
+
+    /// 关闭池中的空闲通道并拒绝之后的取用
+    pub async fn close(&self) {
+        self.permits.close();
+        let channels: Vec<Channel> = std::mem::take(&mut *self.idle.lock().unwrap_or_else(PoisonError::into_inner));
+        for channel in channels {
+            if let Err(e) = channel.close(200, "shutdown").await {
+                tracing::warn!("Failed to close RabbitMQ channel: {}", e);
+            }
+        }
+    }
+
+    async fn create_channel(&self) -> AppResult<Channel> {
+        let channel = self
+            .connection
+            .create_channel()
+            .await
+            .map_err(|e| AppError::System(format!("Channel creation error: {}", e)))?;
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await
+            .map_err(|e| AppError::System(format!("Confirm select error: {}", e)))?;
+        Ok(channel)
+    }
+}
+
+impl Deref for PooledChannel<'_> {
+    type Target = Channel;
+
+    fn deref(&self) -> &Channel {
+        self.channel.as_ref().expect("pooled channel already returned")
+    }
+}
+
+impl Drop for PooledChannel<'_> {
+    fn drop(&mut self) {
+        if let Some(channel) = self.channel.take().filter(|channel| channel.status().connected()) {
+            self.pool.idle.lock().unwrap_or_else(PoisonError::into_inner).push(channel);
+        }
+    }
+}
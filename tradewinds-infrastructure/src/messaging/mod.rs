@@ -1,4 +1,8 @@
 // 消息队列模块
 // 用于处理异步消息和事件
 
+pub mod channel_pool;
 pub mod rabbitmq;
+
+pub use channel_pool::ChannelPool;
+pub use rabbitmq::{FailureAction, MessageHandler, MessageQueue, QueueBinding, RabbitMQ, failure_action};
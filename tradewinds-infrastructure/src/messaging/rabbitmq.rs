@@ -1,97 +1,401 @@
 use async_trait::async_trait;
+use futures::FutureExt;
 use futures_util::StreamExt;
 use lapin::{
-    BasicProperties, Connection, ConnectionProperties,
-    options::{BasicPublishOptions, QueueDeclareOptions},
-    types::FieldTable,
+    BasicProperties, Channel, Connection, ConnectionProperties, Consumer, ExchangeKind,
+    message::Delivery,
+    options::{
+        BasicAckOptions, BasicCancelOptions, BasicConsumeOptions, BasicNackOptions, BasicPublishOptions,
+        BasicQosOptions, ExchangeDeclareOptions, QueueBindOptions, QueueDeclareOptions,
+    },
+    publisher_confirm::Confirmation,
+    types::{AMQPValue, FieldTable, ShortString},
 };
 use serde::{Serialize, de::DeserializeOwned};
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
 use tradewinds_error::{AppError, AppResult};
 
+use crate::config::RabbitMqConfig;
+use crate::messaging::channel_pool::ChannelPool;
+
+/// 重试次数消息头
+pub const RETRY_COUNT_HEADER: &str = "x-retry-count";
+
+/// 优雅停机时等待消费任务处理完当前消息的最长时间
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 持久化投递模式
+const PERSISTENT: u8 = 2;
+
 #[async_trait]
 pub trait MessageQueue: Send + Sync {
-    async fn publish<T: Serialize + Send + Sync>(&self, queue: &str, message: &T) -> AppResult<()>;
-    async fn subscribe<T: DeserializeOwned + Send + 'static>(
-        &self,
-        queue: &str,
-        callback: impl Fn(T) -> AppResult<()> + Send + Sync + 'static,
-    ) -> AppResult<()>;
+    /// 以持久化消息发布到交换机，等待 broker 确认
+    async fn publish<T: Serialize + Send + Sync>(&self, routing_key: &str, message: &T) -> AppResult<()>;
+
+    /// 声明队列并启动消费任务，立即返回
+    async fn subscribe<T, H>(&self, binding: QueueBinding, handler: H) -> AppResult<()>
+    where
+        T: DeserializeOwned + Send + 'static,
+        H: MessageHandler<T> + 'static;
+}
+
+/// 消息处理者
+#[async_trait]
+pub trait MessageHandler<T>: Send + Sync {
+    async fn handle(&self, message: T) -> AppResult<()>;
+}
+
+/// 队列及其在交换机上绑定的路由键
+#[derive(Debug, Clone)]
+pub struct QueueBinding {
+    pub queue: String,
+    pub routing_keys: Vec<String>,
+}
+
+impl QueueBinding {
+    pub fn new(queue: impl Into<String>, routing_keys: &[&str]) -> Self {
+        Self { queue: queue.into(), routing_keys: routing_keys.iter().map(|key| key.to_string()).collect() }
+    }
+
+    /// 死信队列名称
+    pub fn dead_letter_queue(&self) -> String {
+        format!("{}.dlq", self.queue)
+    }
+
+    /// 重试队列名称，消息在其中等待 `retry_delay_ms` 后回到本队列
+    pub fn retry_queue(&self) -> String {
+        format!("{}.retry", self.queue)
+    }
+}
+
+/// 消息处理失败后的去向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureAction {
+    /// 以新的重试次数放入重试队列
+    Retry(u32),
+    /// 转入死信队列
+    DeadLetter,
+}
+
+/// 按消息头中已重试的次数决定失败消息的去向
+pub fn failure_action(properties: &BasicProperties, max_retries: u32) -> FailureAction {
+    let retries = retry_count(properties);
+    if retries >= max_retries { FailureAction::DeadLetter } else { FailureAction::Retry(retries + 1) }
 }
 
+/// RabbitMQ 消息队列
+///
+/// - 发布经通道池复用开启确认的通道，消息持久化且设置 mandatory，未被确认或无法路由时返回错误
+/// - 交换机、队列均为持久化；每个队列配有死信队列，经死信交换机按队列名路由
+/// - 每个队列另配重试队列，消息在其中停留 `retry_delay_ms` 毫秒后过期，经默认交换机回到原队列
+/// - 消费在独立任务中进行，按 `prefetch` 限制未确认消息数；处理失败时带上递增的重试次数头
+///   发布到重试队列（AMQP 的 nack 无法修改消息头，因此以重新发布并确认原消息实现），
+///   达到最大重试次数或无法解析的消息转入死信队列
+/// - `shutdown` 停止接收新消息，等待当前消息处理完毕后关闭通道与连接
 pub struct RabbitMQ {
-    connection: Connection,
+    connection: Arc<Connection>,
+    channels: Arc<ChannelPool>,
+    config: RabbitMqConfig,
+    shutdown: watch::Sender<bool>,
+    consumers: Mutex<Vec<JoinHandle<()>>>,
 }
 
 impl RabbitMQ {
-    pub async fn new(amqp_url: &str) -> AppResult<Self> {
-        let connection = Connection::connect(amqp_url, ConnectionProperties::default())
+    pub async fn new(config: &RabbitMqConfig) -> AppResult<Self> {
+        let connection = Connection::connect(&config.url, ConnectionProperties::default())
             .await
             .map_err(|e| AppError::System(format!("RabbitMQ connection error: {}", e)))?;
+        let connection = Arc::new(connection);
+        let channels = Arc::new(ChannelPool::new(connection.clone(), config.channel_pool_size));
 
-        Ok(Self { connection })
+        let mq = Self {
+            connection,
+            channels,
+            config: config.clone(),
+            shutdown: watch::Sender::new(false),
+            consumers: Mutex::new(Vec::new()),
+        };
+        mq.declare_exchanges().await?;
+        Ok(mq)
     }
-}
 
-#[async_trait]
-impl MessageQueue for RabbitMQ {
-    async fn publish<T: Serialize + Send + Sync>(&self, queue: &str, message: &T) -> AppResult<()> {
-        let channel = self
-            .connection
-            .create_channel()
+    fn dead_letter_exchange(&self) -> String {
+        format!("{}.dlx", self.config.exchange)
+    }
+
+    async fn declare_exchanges(&self) -> AppResult<()> {
+        let channel = self.channels.acquire().await?;
+        let options = ExchangeDeclareOptions { durable: true, ..ExchangeDeclareOptions::default() };
+        channel
+            .exchange_declare(&self.config.exchange, ExchangeKind::Topic, options, FieldTable::default())
             .await
-            .map_err(|e| AppError::System(format!("Channel creation error: {}", e)))?;
+            .map_err(|e| AppError::System(format!("Exchange declare error: {}", e)))?;
+        channel
+            .exchange_declare(&self.dead_letter_exchange(), ExchangeKind::Direct, options, FieldTable::default())
+            .await
+            .map_err(|e| AppError::System(format!("Exchange declare error: {}", e)))?;
+        Ok(())
+    }
+
+    /// 声明持久化队列、重试队列、死信队列及路由键绑定
+    pub async fn declare_queue(&self, binding: &QueueBinding) -> AppResult<()> {
+        let channel = self.channels.acquire().await?;
+        let options = QueueDeclareOptions { durable: true, ..QueueDeclareOptions::default() };
+        let dead_letter_exchange = self.dead_letter_exchange();
 
+        let dead_letter_queue = binding.dead_letter_queue();
         channel
-            .queue_declare(queue, QueueDeclareOptions::default(), FieldTable::default())
+            .queue_declare(&dead_letter_queue, options, FieldTable::default())
             .await
             .map_err(|e| AppError::System(format!("Queue declare error: {}", e)))?;
+        channel
+            .queue_bind(
+                &dead_letter_queue,
+                &dead_letter_exchange,
+                &binding.queue,
+                QueueBindOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .map_err(|e| AppError::System(format!("Queue bind error: {}", e)))?;
 
-        let payload =
-            serde_json::to_vec(message).map_err(|e| AppError::System(format!("Message serialization error: {}", e)))?;
-
+        // 重试队列无消费者，消息过期后经默认交换机按队列名回到原队列
+        let mut retry_arguments = FieldTable::default();
+        retry_arguments.insert("x-message-ttl".into(), AMQPValue::LongUInt(self.config.retry_delay_ms));
+        retry_arguments.insert("x-dead-letter-exchange".into(), AMQPValue::LongString("".into()));
+        retry_arguments.insert("x-dead-letter-routing-key".into(), AMQPValue::LongString(binding.queue.clone().into()));
         channel
-            .basic_publish("", queue, BasicPublishOptions::default(), &payload, BasicProperties::default())
+            .queue_declare(&binding.retry_queue(), options, retry_arguments)
             .await
-            .map_err(|e| AppError::System(format!("Message publish error: {}", e)))?;
+            .map_err(|e| AppError::System(format!("Queue declare error: {}", e)))?;
 
+        let mut arguments = FieldTable::default();
+        arguments.insert("x-dead-letter-exchange".into(), AMQPValue::LongString(dead_letter_exchange.into()));
+        arguments.insert("x-dead-letter-routing-key".into(), AMQPValue::LongString(binding.queue.clone().into()));
+        channel
+            .queue_declare(&binding.queue, options, arguments)
+            .await
+            .map_err(|e| AppError::System(format!("Queue declare error: {}", e)))?;
+        for routing_key in &binding.routing_keys {
+            channel
+                .queue_bind(
+                    &binding.queue,
+                    &self.config.exchange,
+                    routing_key,
+                    QueueBindOptions::default(),
+                    FieldTable::default(),
+                )
+                .await
+                .map_err(|e| AppError::System(format!("Queue bind error: {}", e)))?;
+        }
         Ok(())
     }
 
-    async fn subscribe<T: DeserializeOwned + Send + 'static>(
-        &self,
-        queue: &str,
-        callback: impl Fn(T) -> AppResult<()> + Send + Sync + 'static,
-    ) -> AppResult<()> {
+    /// 优雅停机：通知消费任务停止，等待当前消息处理完毕，再关闭通道与连接
+    pub async fn shutdown(&self) -> AppResult<()> {
+        self.shutdown.send_replace(true);
+        let consumers = std::mem::take(&mut *self.consumers.lock().unwrap_or_else(PoisonError::into_inner));
+        for consumer in consumers {
+            if tokio::time::timeout(SHUTDOWN_TIMEOUT, consumer).await.is_err() {
+                tracing::warn!("RabbitMQ consumer did not stop within {:?}", SHUTDOWN_TIMEOUT);
+            }
+        }
+        self.channels.close().await;
+        self.connection
+            .close(200, "shutdown")
+            .await
+            .map_err(|e| AppError::System(format!("RabbitMQ connection close error: {}", e)))
+    }
+}
+
+#[async_trait]
+impl MessageQueue for RabbitMQ {
+    async fn publish<T: Serialize + Send + Sync>(&self, routing_key: &str, message: &T) -> AppResult<()> {
+        let payload =
+            serde_json::to_vec(message).map_err(|e| AppError::System(format!("Message serialization error: {}", e)))?;
+        let properties = BasicProperties::default()
+            .with_content_type(ShortString::from("application/json"))
+            .with_delivery_mode(PERSISTENT);
+        publish_confirmed(&self.channels, &self.config.exchange, routing_key, &payload, properties).await
+    }
+
+    async fn subscribe<T, H>(&self, binding: QueueBinding, handler: H) -> AppResult<()>
+    where
+        T: DeserializeOwned + Send + 'static,
+        H: MessageHandler<T> + 'static,
+    {
+        self.declare_queue(&binding).await?;
+
+        // 消费使用独立通道，不占用发布通道池
         let channel = self
             .connection
             .create_channel()
             .await
             .map_err(|e| AppError::System(format!("Channel creation error: {}", e)))?;
-
         channel
-            .queue_declare(queue, QueueDeclareOptions::default(), FieldTable::default())
+            .basic_qos(self.config.prefetch, BasicQosOptions::default())
             .await
-            .map_err(|e| AppError::System(format!("Queue declare error: {}", e)))?;
-
-        let mut consumer = channel
-            .basic_consume(queue, "", lapin::options::BasicConsumeOptions::default(), FieldTable::default())
+            .map_err(|e| AppError::System(format!("Basic qos error: {}", e)))?;
+        let consumer = channel
+            .basic_consume(&binding.queue, "", BasicConsumeOptions::default(), FieldTable::default())
             .await
             .map_err(|e| AppError::System(format!("Consumer creation error: {}", e)))?;
 
-        while let Some(delivery) = consumer.next().await {
-            if let Ok(delivery) = delivery {
-                if let Ok(message) = serde_json::from_slice::<T>(&delivery.data) {
-                    if let Err(e) = callback(message) {
-                        tracing::error!("Message processing error: {}", e);
+        let worker = ConsumerWorker {
+            channel,
+            binding,
+            handler,
+            channels: self.channels.clone(),
+            max_retries: self.config.max_retries,
+            _message: std::marker::PhantomData,
+        };
+        let task = tokio::spawn(worker.run(consumer, self.shutdown.subscribe()));
+        self.consumers.lock().unwrap_or_else(PoisonError::into_inner).push(task);
+        Ok(())
+    }
+}
+
+/// 单个队列的消费任务
+struct ConsumerWorker<T, H> {
+    channel: Channel,
+    binding: QueueBinding,
+    handler: H,
+    channels: Arc<ChannelPool>,
+    max_retries: u32,
+    _message: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T, H> ConsumerWorker<T, H>
+where
+    T: DeserializeOwned + Send + 'static,
+    H: MessageHandler<T> + 'static,
+{
+    async fn run(self, mut consumer: Consumer, mut shutdown: watch::Receiver<bool>) {
+        loop {
+            tokio::select! {
+                _ = shutdown.changed() => break,
+                delivery = consumer.next() => match delivery {
+                    Some(Ok(delivery)) => self.process(delivery).await,
+                    Some(Err(e)) => {
+                        tracing::error!("RabbitMQ consumer on {} failed: {}", self.binding.queue, e);
+                        break;
                     }
+                    None => break,
+                },
+            }
+        }
+
+        // 取消消费并关闭通道，已预取未确认的消息由 broker 重新入队
+        let tag = consumer.tag();
+        if let Err(e) = self.channel.basic_cancel(tag.as_str(), BasicCancelOptions::default()).await {
+            tracing::warn!("Failed to cancel RabbitMQ consumer on {}: {}", self.binding.queue, e);
+        }
+        if let Err(e) = self.channel.close(200, "shutdown").await {
+            tracing::warn!("Failed to close RabbitMQ channel for {}: {}", self.binding.queue, e);
+        }
+    }
+
+    async fn process(&self, delivery: Delivery) {
+        let queue = &self.binding.queue;
+        let message = match serde_json::from_slice::<T>(&delivery.data) {
+            Ok(message) => message,
+            Err(e) => {
+                // 无法解析的消息重试也不会成功，直接转入死信队列
+                tracing::error!("Dead-lettering undecodable message on {}: {}", queue, e);
+                return self.dead_letter(&delivery).await;
+            }
+        };
+
+        let error = match AssertUnwindSafe(self.handler.handle(message)).catch_unwind().await {
+            Ok(Ok(())) => {
+                if let Err(e) = delivery.acker.ack(BasicAckOptions::default()).await {
+                    tracing::warn!("Failed to ack message on {}: {}", queue, e);
                 }
-                delivery
-                    .ack(lapin::options::BasicAckOptions::default())
-                    .await
-                    .map_err(|e| AppError::System(format!("Message acknowledgement error: {}", e)))?;
+                return;
+            }
+            Ok(Err(e)) => e.to_string(),
+            Err(_) => "handler panicked".to_string(),
+        };
+
+        let retries = match failure_action(&delivery.properties, self.max_retries) {
+            FailureAction::Retry(retries) => retries,
+            FailureAction::DeadLetter => {
+                tracing::error!("Dead-lettering message on {} after {} retries: {}", queue, self.max_retries, error);
+                return self.dead_letter(&delivery).await;
+            }
+        };
+
+        tracing::warn!("Message on {} failed (retry {}/{}): {}", queue, retries, self.max_retries, error);
+        if let Err(e) = self.requeue(&delivery, retries).await {
+            // 重新发布失败时退回原消息，重试次数不变
+            tracing::warn!("Failed to requeue message on {}: {}", queue, e);
+            let options = BasicNackOptions { requeue: true, ..BasicNackOptions::default() };
+            if let Err(e) = delivery.acker.nack(options).await {
+                tracing::warn!("Failed to nack message on {}: {}", queue, e);
             }
         }
+    }
 
-        Ok(())
+    /// 带上新的重试次数把消息发布到重试队列，确认后再确认原消息
+    async fn requeue(&self, delivery: &Delivery, retries: u32) -> AppResult<()> {
+        let mut headers = delivery.properties.headers().clone().unwrap_or_default();
+        headers.insert(RETRY_COUNT_HEADER.into(), AMQPValue::LongUInt(retries));
+        let properties = delivery.properties.clone().with_headers(headers).with_delivery_mode(PERSISTENT);
+        // 经默认交换机按队列名直达重试队列，不会重复投递给绑定同一路由键的其他队列
+        publish_confirmed(&self.channels, "", &self.binding.retry_queue(), &delivery.data, properties).await?;
+        delivery
+            .acker
+            .ack(BasicAckOptions::default())
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::System(format!("Message acknowledgement error: {}", e)))
+    }
+
+    /// 拒绝且不重新入队，由死信交换机转入死信队列
+    async fn dead_letter(&self, delivery: &Delivery) {
+        if let Err(e) = delivery.acker.nack(BasicNackOptions::default()).await {
+            tracing::warn!("Failed to dead-letter message on {}: {}", self.binding.queue, e);
+        }
+    }
+}
+
+/// 经通道池发布并等待 broker 确认
+async fn publish_confirmed(
+    channels: &ChannelPool,
+    exchange: &str,
+    routing_key: &str,
+    payload: &[u8],
+    properties: BasicProperties,
+) -> AppResult<()> {
+    let channel = channels.acquire().await?;
+    let options = BasicPublishOptions { mandatory: true, ..BasicPublishOptions::default() };
+    let confirmation = channel
+        .basic_publish(exchange, routing_key, options, payload, properties)
+        .await
+        .map_err(|e| AppError::System(format!("Message publish error: {}", e)))?
+        .await
+        .map_err(|e| AppError::System(format!("Message confirm error: {}", e)))?;
+    match confirmation {
+        Confirmation::Ack(None) => Ok(()),
+        Confirmation::Ack(Some(_)) => {
+            Err(AppError::System(format!("Message to {} with routing key {} was unroutable", exchange, routing_key)))
+        }
+        Confirmation::Nack(_) => Err(AppError::System(format!("Message with routing key {} was nacked", routing_key))),
+        Confirmation::NotRequested => Err(AppError::System("Publisher confirms are not enabled".to_string())),
+    }
+}
+
+/// 读取消息头中的重试次数
+fn retry_count(properties: &BasicProperties) -> u32 {
+    let value = properties.headers().as_ref().and_then(|headers| headers.inner().get(RETRY_COUNT_HEADER));
+    match value {
+        Some(AMQPValue::LongUInt(count)) => *count,
+        Some(AMQPValue::LongInt(count)) => u32::try_from(*count).unwrap_or_default(),
+        Some(AMQPValue::LongLongInt(count)) => u32::try_from(*count).unwrap_or_default(),
+        _ => 0,
     }
 }
@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 访问策略（ABAC）
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "access_policies")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// 作用的操作（权限码，支持通配）
+    pub action: String,
+    /// 效果：0-允许，1-拒绝
+    pub effect: i32,
+    /// 条件表达式
+    #[sea_orm(column_type = "Text")]
+    pub condition: String,
+    pub status: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
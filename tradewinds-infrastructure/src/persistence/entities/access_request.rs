@@ -0,0 +1,40 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 权限申请
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "access_requests")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
+    pub requester_id: String,
+    pub role_id: String,
+    pub justification: String,
+    /// 授权时长（天）
+    pub duration_days: i32,
+    /// 状态：0-待审批，1-已批准，2-已驳回，3-已撤回，4-已过期
+    pub status: i32,
+    pub decided_by: Option<String>,
+    pub decision_comment: Option<String>,
+    pub decided_at: Option<DateTimeWithTimeZone>,
+    /// 待审批截止时间
+    pub expires_at: DateTimeWithTimeZone,
+    /// 授权到期时间
+    pub grant_expires_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -0,0 +1,33 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 访问复核活动
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "access_reviews")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
+    pub name: String,
+    /// 截止时间
+    pub deadline: DateTimeWithTimeZone,
+    /// 状态：0-进行中，1-已完成
+    pub status: i32,
+    pub created_by: String,
+    pub completed_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
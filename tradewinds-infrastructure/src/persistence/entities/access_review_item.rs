@@ -0,0 +1,32 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 访问复核项
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "access_review_items")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    /// 所属租户，与所属复核活动一致
+    pub tenant_id: String,
+    pub review_id: String,
+    pub user_id: String,
+    pub role_id: String,
+    pub reviewer_id: String,
+    /// 结论：0-待复核，1-确认保留，2-收回，3-到期自动收回
+    pub decision: i32,
+    pub comment: Option<String>,
+    pub decided_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
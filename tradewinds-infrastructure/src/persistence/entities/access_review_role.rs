@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 访问复核范围内的角色
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "access_review_roles")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    /// 所属租户，与所属复核活动一致
+    pub tenant_id: String,
+    pub review_id: String,
+    pub role_id: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -0,0 +1,49 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 审计日志
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "audit_logs")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
+    pub actor_id: Option<String>,
+    pub actor_name: Option<String>,
+    /// 操作名称，如 user.create
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    /// 命令参数（JSON）
+    #[sea_orm(column_type = "Text", nullable)]
+    pub params: Option<String>,
+    /// 字段变更（JSON）
+    #[sea_orm(column_type = "Text", nullable)]
+    pub changes: Option<String>,
+    pub client_ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    /// 结果：0-成功，1-失败
+    pub outcome: i32,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error_message: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    /// 租户内哈希链序号，从 1 开始连续递增
+    pub seq: i64,
+    pub prev_hash: String,
+    /// 记录内容与 prev_hash 的 SHA-256（十六进制）
+    pub hash: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -0,0 +1,26 @@
+use sea_orm::entity::prelude::*;
+
+/// 功能开关（平台级数据，不带 tenant_id）
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "feature_flags")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub key: String,
+    pub description: String,
+    pub enabled: bool,
+    /// 灰度比例，0-100
+    pub rollout_percentage: i32,
+    /// 白名单角色编码，JSON 数组
+    #[sea_orm(column_type = "Text")]
+    pub allowed_roles: String,
+    /// 白名单租户ID，JSON 数组
+    #[sea_orm(column_type = "Text")]
+    pub allowed_tenants: String,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,29 @@
+use sea_orm::entity::prelude::*;
+
+/// 定时任务执行记录（平台级数据，不带 tenant_id）
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "job_runs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub id: String,
+    pub job_name: String,
+    /// 触发方式：0-按计划，1-手动
+    pub trigger: i32,
+    /// 状态：0-执行中，1-成功，2-失败
+    pub status: i32,
+    pub triggered_by: Option<String>,
+    /// 执行所在的实例
+    pub instance: String,
+    pub started_at: DateTimeWithTimeZone,
+    pub finished_at: Option<DateTimeWithTimeZone>,
+    pub duration_ms: Option<i64>,
+    /// 处理的记录数
+    pub affected: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub error: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
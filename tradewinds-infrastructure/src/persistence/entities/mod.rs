@@ -1,8 +1,31 @@
+pub mod access_policy;
+pub mod access_request;
+pub mod access_review;
+pub mod access_review_item;
+pub mod access_review_role;
+pub mod audit_checkpoint;
+pub mod audit_log;
+pub mod department;
+pub mod feature_flag;
+pub mod job_run;
+pub mod login_log;
+pub mod outbox_message;
 pub mod permission;
 pub mod role;
+pub mod role_approver;
+pub mod role_department;
 pub mod role_permission;
+pub mod scheduled_job;
+pub mod sod_rule;
+pub mod sod_rule_role;
 pub mod system_setting;
+pub mod tenant;
 pub mod token_blacklist;
 pub mod user;
+pub mod user_group;
+pub mod user_group_member;
+pub mod user_group_role;
 pub mod user_role;
+pub mod webhook;
+pub mod webhook_delivery;
 
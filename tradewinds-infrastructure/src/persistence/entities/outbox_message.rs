@@ -0,0 +1,42 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// 发件箱消息
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "outbox_messages")]
+pub struct Model {
+    /// 自增序号，决定投递顺序
+    #[sea_orm(primary_key)]
+    pub seq: i64,
+    #[sea_orm(unique)]
+    pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
+    /// 聚合类型，如 user
+    pub aggregate_type: String,
+    pub aggregate_id: String,
+    pub event_type: String,
+    /// 事件 JSON
+    #[sea_orm(column_type = "Text")]
+    pub payload: String,
+    /// 状态：0-待投递，1-已投递，2-死信
+    pub status: i32,
+    pub attempts: i32,
+    pub next_attempt_at: DateTimeWithTimeZone,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub published_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -1,10 +1,14 @@
 use sea_orm::entity::prelude::*;
 
+use crate::persistence::tenant_scope::TenantEntity;
+
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "permissions")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
     pub name: String,
     pub code: Option<String>,
     #[sea_orm(column_name = "type")]
@@ -15,6 +19,8 @@ pub struct Model {
     pub icon: Option<String>,
     pub sort: i32,
     pub status: i32,
+    /// 是否内置权限
+    pub built_in: bool,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -30,3 +36,9 @@ pub enum Relation {
 }
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
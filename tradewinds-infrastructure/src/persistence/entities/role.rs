@@ -1,14 +1,22 @@
 use sea_orm::entity::prelude::*;
 
+use crate::persistence::tenant_scope::TenantEntity;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "roles")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
     pub code: String, // 新增唯一标识字段
     pub name: String,
     pub description: Option<String>,
     pub status: i32,
+    /// 数据范围：0 全部，1 自定义，2 本部门，3 本部门及子部门，4 仅本人
+    pub data_scope: i32,
+    /// 是否内置角色
+    pub built_in: bool,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -17,3 +25,9 @@ pub struct Model {
 pub enum Relation {}
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -1,12 +1,18 @@
 use sea_orm::entity::prelude::*;
 
+use crate::persistence::tenant_scope::TenantEntity;
+
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "role_permissions")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
     pub role_id: String,
     pub permission_id: String,
+    /// 授权效果：0 允许，1 拒绝
+    pub effect: i32,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
 }
@@ -30,3 +36,9 @@ impl Related<super::permission::Entity> for Entity {
         Relation::Permission.def()
     }
 }
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -0,0 +1,23 @@
+use sea_orm::entity::prelude::*;
+
+/// 定时任务（平台级数据，不带 tenant_id）
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "scheduled_jobs")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub name: String,
+    pub description: String,
+    /// cron 表达式：秒 分 时 日 月 周
+    pub cron: String,
+    pub paused: bool,
+    pub next_run_at: Option<DateTimeWithTimeZone>,
+    pub last_run_at: Option<DateTimeWithTimeZone>,
+    /// 最近一次执行状态：0-执行中，1-成功，2-失败
+    pub last_status: Option<i32>,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,10 +1,14 @@
 use sea_orm::entity::prelude::*;
 
+use crate::persistence::tenant_scope::TenantEntity;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "system_settings")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
     pub key: String,
     pub value: String,
     pub description: Option<String>,
@@ -21,3 +25,9 @@ impl sea_orm::RelationTrait for Relation {
 }
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -0,0 +1,22 @@
+use sea_orm::entity::prelude::*;
+
+/// 租户（平台级数据，不带 tenant_id）
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "tenants")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: String,
+    #[sea_orm(unique)]
+    pub code: String,
+    pub name: String,
+    #[sea_orm(unique)]
+    pub host: Option<String>,
+    pub status: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
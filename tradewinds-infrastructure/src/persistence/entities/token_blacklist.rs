@@ -1,5 +1,6 @@
 use sea_orm::entity::prelude::*;
 
+/// 已注销令牌（平台级数据，不带 tenant_id；令牌 jti 全局唯一）
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "token_blacklist")]
 pub struct Model {
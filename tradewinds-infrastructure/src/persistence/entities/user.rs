@@ -1,10 +1,14 @@
 use sea_orm::entity::prelude::*;
 
+use crate::persistence::tenant_scope::TenantEntity;
+
 #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
 #[sea_orm(table_name = "users")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
     pub username: String,
     pub email: String,
     pub password: String,
@@ -12,11 +16,22 @@ pub struct Model {
     pub phone: Option<String>,
     pub avatar: Option<String>,
     pub status: i32,
+    pub department_id: Option<String>,
+    pub created_by: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
+    /// 最近一次成功登录
+    pub last_login_at: Option<DateTimeWithTimeZone>,
+    pub last_login_ip: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {}
 
 impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
@@ -0,0 +1,44 @@
+use sea_orm::entity::prelude::*;
+
+use crate::persistence::tenant_scope::TenantEntity;
+
+/// Webhook 投递记录
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[sea_orm(table_name = "webhook_deliveries")]
+pub struct Model {
+    /// 自增序号，决定投递顺序
+    #[sea_orm(primary_key)]
+    pub seq: i64,
+    #[sea_orm(unique)]
+    pub id: String,
+    /// 所属租户
+    pub tenant_id: String,
+    pub webhook_id: String,
+    pub event_type: String,
+    /// 请求体 JSON
+    #[sea_orm(column_type = "Text")]
+    pub payload: String,
+    /// 状态：0-待投递，1-成功，2-失败
+    pub status: i32,
+    pub attempts: i32,
+    pub next_attempt_at: DateTimeWithTimeZone,
+    /// 最近一次响应的 HTTP 状态码
+    pub response_status: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub delivered_at: Option<DateTimeWithTimeZone>,
+    /// 重新投递时指向原投递记录
+    pub redelivery_of: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl TenantEntity for Entity {
+    fn tenant_column() -> Column {
+        Column::TenantId
+    }
+}
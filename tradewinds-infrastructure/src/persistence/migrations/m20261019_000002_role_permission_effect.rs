@@ -0,0 +1,27 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 角色权限关联增加授权效果：0 允许，1 拒绝
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("role_permissions"))
+                    .add_column(ColumnDef::new(Alias::new("effect")).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter().table(Alias::new("role_permissions")).drop_column(Alias::new("effect")).to_owned(),
+            )
+            .await
+    }
+}
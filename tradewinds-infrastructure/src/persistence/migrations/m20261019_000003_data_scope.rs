@@ -0,0 +1,79 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 用户所属部门与创建者
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("users"))
+                    .add_column(ColumnDef::new(Alias::new("department_id")).string().null())
+                    .add_column(ColumnDef::new(Alias::new("created_by")).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 角色数据范围：0 全部，1 自定义，2 本部门，3 本部门及子部门，4 仅本人
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("roles"))
+                    .add_column(ColumnDef::new(Alias::new("data_scope")).integer().not_null().default(0))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 角色自定义数据范围的部门集合
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("role_departments"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("department_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("role_departments"))
+                            .from_col(Alias::new("role_id"))
+                            .to_tbl(Alias::new("roles"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_users_department_id")
+                    .table(Alias::new("users"))
+                    .col(Alias::new("department_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("role_departments")).to_owned()).await?;
+        manager
+            .alter_table(Table::alter().table(Alias::new("roles")).drop_column(Alias::new("data_scope")).to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("users"))
+                    .drop_column(Alias::new("department_id"))
+                    .drop_column(Alias::new("created_by"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 部门表：通过 parent_id 组成树
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("departments"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("parent_id")).string().null())
+                    .col(ColumnDef::new(Alias::new("sort")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("leader_id")).string().null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_departments_parent_id")
+                    .table(Alias::new("departments"))
+                    .col(Alias::new("parent_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("departments")).to_owned()).await
+    }
+}
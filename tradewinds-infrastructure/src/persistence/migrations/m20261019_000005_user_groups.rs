@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 用户组
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("user_groups"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("description")).string().null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 用户组成员
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("user_group_members"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("group_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("user_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("user_group_members"))
+                            .from_col(Alias::new("group_id"))
+                            .to_tbl(Alias::new("user_groups"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("user_group_members"))
+                            .from_col(Alias::new("user_id"))
+                            .to_tbl(Alias::new("users"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_user_group_members_user_id")
+                    .table(Alias::new("user_group_members"))
+                    .col(Alias::new("user_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 用户组携带的角色
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("user_group_roles"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("group_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("user_group_roles"))
+                            .from_col(Alias::new("group_id"))
+                            .to_tbl(Alias::new("user_groups"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("user_group_roles"))
+                            .from_col(Alias::new("role_id"))
+                            .to_tbl(Alias::new("roles"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("user_group_roles")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("user_group_members")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("user_groups")).to_owned()).await
+    }
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 职责分离规则
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("sod_rules"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("rule_type")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("max_count")).integer().not_null().default(1))
+                    .col(ColumnDef::new(Alias::new("enabled")).boolean().not_null().default(true))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 规则约束的角色
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("sod_rule_roles"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("rule_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("sod_rule_roles"))
+                            .from_col(Alias::new("rule_id"))
+                            .to_tbl(Alias::new("sod_rules"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("sod_rule_roles"))
+                            .from_col(Alias::new("role_id"))
+                            .to_tbl(Alias::new("roles"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("sod_rule_roles")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("sod_rules")).to_owned()).await
+    }
+}
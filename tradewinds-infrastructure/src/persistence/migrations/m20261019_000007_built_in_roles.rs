@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 内置角色与权限标记
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("roles"))
+                    .add_column(ColumnDef::new(Alias::new("built_in")).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("permissions"))
+                    .add_column(ColumnDef::new(Alias::new("built_in")).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 超级管理员角色及系统菜单权限为内置
+        manager
+            .get_connection()
+            .execute_unprepared("UPDATE roles SET built_in = TRUE WHERE code = 'super_admin';")
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE permissions SET built_in = TRUE WHERE code LIKE 'super_admin%' \
+                 OR code IN ('system', 'user_management', 'user:list', 'role:list', 'permission:list');",
+            )
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(Table::alter().table(Alias::new("permissions")).drop_column(Alias::new("built_in")).to_owned())
+            .await?;
+        manager
+            .alter_table(Table::alter().table(Alias::new("roles")).drop_column(Alias::new("built_in")).to_owned())
+            .await
+    }
+}
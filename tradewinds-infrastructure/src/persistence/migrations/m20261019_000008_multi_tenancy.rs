@@ -0,0 +1,136 @@
+use sea_orm_migration::prelude::*;
+
+/// 带租户字段的业务表
+const TENANT_TABLES: [&str; 9] = [
+    "users",
+    "roles",
+    "permissions",
+    "role_permissions",
+    "user_roles",
+    "system_settings",
+    "departments",
+    "user_groups",
+    "sod_rules",
+];
+
+/// 原全局唯一索引：(表, 可能的索引名, 租户内唯一索引名, 列)
+const TENANT_UNIQUE_KEYS: [(&str, [&str; 2], &str, &str); 5] = [
+    ("users", ["username", "idx_username"], "uk_users_tenant_username", "username"),
+    ("users", ["email", "idx_email"], "uk_users_tenant_email", "email"),
+    ("roles", ["name", "idx_name"], "uk_roles_tenant_name", "name"),
+    ("roles", ["code", "idx_code"], "uk_roles_tenant_code", "code"),
+    ("system_settings", ["key", "idx_key"], "uk_system_settings_tenant_key", "key"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 租户
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("tenants"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("code")).string_len(32).not_null().unique_key())
+                    .col(ColumnDef::new(Alias::new("name")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("host")).string().null().unique_key())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO tenants (id, code, name, host, status, created_at, updated_at) \
+                 VALUES ('default', 'default', '平台', NULL, 0, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP);",
+            )
+            .await?;
+
+        // 已有数据全部归属平台默认租户
+        for table in TENANT_TABLES {
+            if !manager.has_table(table).await? {
+                continue;
+            }
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new(table))
+                        .add_column(
+                            ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .create_index(
+                    Index::create()
+                        .name(format!("idx_{}_tenant_id", table))
+                        .table(Alias::new(table))
+                        .col(Alias::new("tenant_id"))
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        // 用户名、邮箱、角色名称、角色编码与参数名改为租户内唯一
+        for (table, old_names, new_name, column) in TENANT_UNIQUE_KEYS {
+            if !manager.has_table(table).await? || !manager.has_column(table, column).await? {
+                continue;
+            }
+            for old_name in old_names {
+                if manager.has_index(table, old_name).await? {
+                    manager.drop_index(Index::drop().name(old_name).table(Alias::new(table)).to_owned()).await?;
+                }
+            }
+            manager
+                .create_index(
+                    Index::create()
+                        .name(new_name)
+                        .table(Alias::new(table))
+                        .col(Alias::new("tenant_id"))
+                        .col(Alias::new(column))
+                        .unique()
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (table, old_names, new_name, column) in TENANT_UNIQUE_KEYS {
+            if !manager.has_index(table, new_name).await? {
+                continue;
+            }
+            manager.drop_index(Index::drop().name(new_name).table(Alias::new(table)).to_owned()).await?;
+            manager
+                .create_index(
+                    Index::create()
+                        .name(old_names[1])
+                        .table(Alias::new(table))
+                        .col(Alias::new(column))
+                        .unique()
+                        .to_owned(),
+                )
+                .await?;
+        }
+        for table in TENANT_TABLES {
+            if !manager.has_column(table, "tenant_id").await? {
+                continue;
+            }
+            manager
+                .drop_index(Index::drop().name(format!("idx_{}_tenant_id", table)).table(Alias::new(table)).to_owned())
+                .await?;
+            manager
+                .alter_table(Table::alter().table(Alias::new(table)).drop_column(Alias::new("tenant_id")).to_owned())
+                .await?;
+        }
+        manager.drop_table(Table::drop().table(Alias::new("tenants")).to_owned()).await
+    }
+}
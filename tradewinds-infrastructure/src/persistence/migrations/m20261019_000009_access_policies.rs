@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 访问策略（ABAC），已删除策略保留记录，名称唯一性由应用层按未删除策略校验
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("access_policies"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("name")).string_len(50).not_null())
+                    .col(ColumnDef::new(Alias::new("description")).string().null())
+                    .col(ColumnDef::new(Alias::new("action")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("effect")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("condition")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_policies_tenant_id")
+                    .table(Alias::new("access_policies"))
+                    .col(Alias::new("tenant_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("access_policies")).to_owned()).await
+    }
+}
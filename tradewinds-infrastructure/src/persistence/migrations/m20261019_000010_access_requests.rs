@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 权限申请
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("access_requests"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("requester_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("justification")).string_len(500).not_null())
+                    .col(ColumnDef::new(Alias::new("duration_days")).integer().not_null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("decided_by")).string().null())
+                    .col(ColumnDef::new(Alias::new("decision_comment")).string().null())
+                    .col(ColumnDef::new(Alias::new("decided_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("expires_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("grant_expires_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_requests_tenant_status")
+                    .table(Alias::new("access_requests"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("status"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_requests_requester_id")
+                    .table(Alias::new("access_requests"))
+                    .col(Alias::new("requester_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 角色审批人
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("role_approvers"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("user_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("role_approvers"))
+                            .from_col(Alias::new("role_id"))
+                            .to_tbl(Alias::new("roles"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("role_approvers"))
+                            .from_col(Alias::new("user_id"))
+                            .to_tbl(Alias::new("users"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_role_approver")
+                    .table(Alias::new("role_approvers"))
+                    .col(Alias::new("role_id"))
+                    .col(Alias::new("user_id"))
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("role_approvers")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("access_requests")).to_owned()).await
+    }
+}
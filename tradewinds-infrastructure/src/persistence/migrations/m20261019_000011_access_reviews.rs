@@ -0,0 +1,120 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 访问复核活动
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("access_reviews"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("name")).string_len(100).not_null())
+                    .col(ColumnDef::new(Alias::new("deadline")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("created_by")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("completed_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_reviews_tenant_status")
+                    .table(Alias::new("access_reviews"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("status"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 复核范围内的角色
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("access_review_roles"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("review_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("access_review_roles"))
+                            .from_col(Alias::new("review_id"))
+                            .to_tbl(Alias::new("access_reviews"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_review_role")
+                    .table(Alias::new("access_review_roles"))
+                    .col(Alias::new("review_id"))
+                    .col(Alias::new("role_id"))
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // 复核项，用户与角色不设外键，以便角色删除后仍保留复核记录
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("access_review_items"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("review_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("user_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("role_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("reviewer_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("decision")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("comment")).string().null())
+                    .col(ColumnDef::new(Alias::new("decided_at")).timestamp_with_time_zone().null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from_tbl(Alias::new("access_review_items"))
+                            .from_col(Alias::new("review_id"))
+                            .to_tbl(Alias::new("access_reviews"))
+                            .to_col(Alias::new("id"))
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_review_items_review_id")
+                    .table(Alias::new("access_review_items"))
+                    .col(Alias::new("review_id"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_access_review_items_reviewer_id")
+                    .table(Alias::new("access_review_items"))
+                    .col(Alias::new("reviewer_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("access_review_items")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("access_review_roles")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("access_reviews")).to_owned()).await
+    }
+}
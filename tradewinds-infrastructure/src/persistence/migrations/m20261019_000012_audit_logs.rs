@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 审计日志，操作人与操作对象不设外键，以便对象删除后仍保留记录
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("audit_logs"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("actor_id")).string().null())
+                    .col(ColumnDef::new(Alias::new("actor_name")).string().null())
+                    .col(ColumnDef::new(Alias::new("action")).string_len(100).not_null())
+                    .col(ColumnDef::new(Alias::new("target_type")).string_len(50).not_null())
+                    .col(ColumnDef::new(Alias::new("target_id")).string().null())
+                    .col(ColumnDef::new(Alias::new("params")).text().null())
+                    .col(ColumnDef::new(Alias::new("changes")).text().null())
+                    .col(ColumnDef::new(Alias::new("client_ip")).string_len(64).null())
+                    .col(ColumnDef::new(Alias::new("user_agent")).string_len(512).null())
+                    .col(ColumnDef::new(Alias::new("request_id")).string_len(64).null())
+                    .col(ColumnDef::new(Alias::new("outcome")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("error_message")).text().null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_logs_tenant_created_at")
+                    .table(Alias::new("audit_logs"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("created_at"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_logs_actor_id")
+                    .table(Alias::new("audit_logs"))
+                    .col(Alias::new("actor_id"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_logs_target")
+                    .table(Alias::new("audit_logs"))
+                    .col(Alias::new("target_type"))
+                    .col(Alias::new("target_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("audit_logs")).to_owned()).await
+    }
+}
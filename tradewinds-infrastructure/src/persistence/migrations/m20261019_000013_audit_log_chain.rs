@@ -0,0 +1,165 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sea_orm::{ConnectionTrait, FromQueryResult, Statement};
+use sea_orm_migration::prelude::*;
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
+
+/// 哈希链首条记录的前一哈希
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 补链时读取的审计日志字段，按本迁移时的表结构固定，不随实体变化
+#[derive(Debug, FromQueryResult)]
+struct AuditLogRow {
+    id: String,
+    tenant_id: String,
+    actor_id: Option<String>,
+    actor_name: Option<String>,
+    action: String,
+    target_type: String,
+    target_id: Option<String>,
+    params: Option<String>,
+    changes: Option<String>,
+    client_ip: Option<String>,
+    user_agent: Option<String>,
+    request_id: Option<String>,
+    outcome: i32,
+    error_message: Option<String>,
+    created_at: DateTimeWithTimeZone,
+}
+
+impl AuditLogRow {
+    /// 按本迁移时的哈希算法计算记录哈希：SHA-256(前一哈希 + 规范化内容)，十六进制小写
+    fn hash(&self, seq: u64, prev_hash: &str) -> Result<String, DbErr> {
+        let content = json!([
+            seq,
+            self.id,
+            self.actor_id,
+            self.actor_name,
+            self.action,
+            self.target_type,
+            self.target_id,
+            normalize_json(self.params.as_deref())?,
+            normalize_json(self.changes.as_deref())?,
+            self.client_ip,
+            self.user_agent,
+            self.request_id,
+            self.outcome,
+            self.error_message,
+            self.created_at.timestamp(),
+        ]);
+        let mut hasher = Sha256::new();
+        hasher.update(prev_hash.as_bytes());
+        hasher.update(content.to_string().as_bytes());
+        Ok(hex::encode(hasher.finalize()))
+    }
+}
+
+/// 参数与变更按解析后重新序列化的文本参与哈希
+fn normalize_json(raw: Option<&str>) -> Result<Option<String>, DbErr> {
+    raw.map(|raw| serde_json::from_str::<Value>(raw).map(|value| value.to_string()))
+        .transpose()
+        .map_err(|e| DbErr::Migration(format!("Invalid audit log payload: {}", e)))
+}
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 审计日志哈希链字段
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("audit_logs"))
+                    .add_column(ColumnDef::new(Alias::new("seq")).big_integer().not_null().default(0))
+                    .add_column(ColumnDef::new(Alias::new("prev_hash")).string_len(64).not_null().default(""))
+                    .add_column(ColumnDef::new(Alias::new("hash")).string_len(64).not_null().default(""))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 已有记录按租户、时间顺序补链
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+        let rows = AuditLogRow::find_by_statement(Statement::from_string(
+            backend,
+            "SELECT id, tenant_id, actor_id, actor_name, action, target_type, target_id, params, changes, client_ip, \
+             user_agent, request_id, outcome, error_message, created_at \
+             FROM audit_logs ORDER BY tenant_id, created_at, id",
+        ))
+        .all(db)
+        .await?;
+        let mut tail: Option<(String, u64, String)> = None;
+        for row in rows {
+            let (seq, prev_hash) = match &tail {
+                Some((tenant, seq, hash)) if *tenant == row.tenant_id => (seq + 1, hash.clone()),
+                _ => (1, GENESIS_HASH.to_string()),
+            };
+            let hash = row.hash(seq, &prev_hash)?;
+            db.execute(Statement::from_sql_and_values(
+                backend,
+                "UPDATE audit_logs SET seq = ?, prev_hash = ?, hash = ? WHERE id = ?",
+                [(seq as i64).into(), prev_hash.into(), hash.clone().into(), row.id.clone().into()],
+            ))
+            .await?;
+            tail = Some((row.tenant_id, seq, hash));
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_audit_logs_tenant_seq")
+                    .table(Alias::new("audit_logs"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("seq"))
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // 签名检查点
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("audit_checkpoints"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("seq")).big_integer().not_null())
+                    .col(ColumnDef::new(Alias::new("hash")).string_len(64).not_null())
+                    .col(ColumnDef::new(Alias::new("signature")).string_len(128).not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_audit_checkpoints_tenant_seq")
+                    .table(Alias::new("audit_checkpoints"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("seq"))
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("audit_checkpoints")).to_owned()).await?;
+        manager
+            .drop_index(Index::drop().name("uk_audit_logs_tenant_seq").table(Alias::new("audit_logs")).to_owned())
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("audit_logs"))
+                    .drop_column(Alias::new("seq"))
+                    .drop_column(Alias::new("prev_hash"))
+                    .drop_column(Alias::new("hash"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 用户最近登录信息
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("users"))
+                    .add_column(ColumnDef::new(Alias::new("last_login_at")).timestamp_with_time_zone().null())
+                    .add_column(ColumnDef::new(Alias::new("last_login_ip")).string_len(64).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 登录日志，用户不存在的登录尝试也会记录，因此不设外键
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("login_logs"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("user_id")).string().null())
+                    .col(ColumnDef::new(Alias::new("username")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("method")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("success")).boolean().not_null())
+                    .col(ColumnDef::new(Alias::new("failure_reason")).string_len(50).null())
+                    .col(ColumnDef::new(Alias::new("client_ip")).string_len(64).null())
+                    .col(ColumnDef::new(Alias::new("user_agent")).string_len(512).null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_login_logs_tenant_created_at")
+                    .table(Alias::new("login_logs"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("created_at"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_login_logs_user_id")
+                    .table(Alias::new("login_logs"))
+                    .col(Alias::new("user_id"))
+                    .col(Alias::new("created_at"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("login_logs")).to_owned()).await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("users"))
+                    .drop_column(Alias::new("last_login_at"))
+                    .drop_column(Alias::new("last_login_ip"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
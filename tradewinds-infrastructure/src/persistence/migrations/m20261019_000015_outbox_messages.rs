@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 发件箱，与聚合变更同事务写入，由中继任务按序号顺序投递
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("outbox_messages"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("seq")).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().unique_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("aggregate_type")).string_len(50).not_null())
+                    .col(ColumnDef::new(Alias::new("aggregate_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("event_type")).string_len(100).not_null())
+                    .col(ColumnDef::new(Alias::new("payload")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("attempts")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("next_attempt_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("last_error")).text().null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("published_at")).timestamp_with_time_zone().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_messages_status_tenant_seq")
+                    .table(Alias::new("outbox_messages"))
+                    .col(Alias::new("status"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("seq"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_messages_tenant_created_at")
+                    .table(Alias::new("outbox_messages"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("created_at"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("outbox_messages")).to_owned()).await
+    }
+}
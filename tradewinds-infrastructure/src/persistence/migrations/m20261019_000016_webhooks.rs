@@ -0,0 +1,89 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Webhook 端点
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("webhooks"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("name")).string_len(100).not_null())
+                    .col(ColumnDef::new(Alias::new("url")).string_len(2048).not_null())
+                    .col(ColumnDef::new(Alias::new("secret")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("event_types")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("consecutive_failures")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("disabled_reason")).string().null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhooks_tenant_id")
+                    .table(Alias::new("webhooks"))
+                    .col(Alias::new("tenant_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 投递记录，由投递任务按序号顺序发送，同时作为投递日志
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("webhook_deliveries"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("seq")).big_integer().not_null().auto_increment().primary_key())
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().unique_key())
+                    .col(ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"))
+                    .col(ColumnDef::new(Alias::new("webhook_id")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("event_type")).string_len(100).not_null())
+                    .col(ColumnDef::new(Alias::new("payload")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("attempts")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("next_attempt_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("response_status")).integer().null())
+                    .col(ColumnDef::new(Alias::new("last_error")).text().null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("delivered_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("redelivery_of")).string().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_status_next_attempt_at")
+                    .table(Alias::new("webhook_deliveries"))
+                    .col(Alias::new("status"))
+                    .col(Alias::new("next_attempt_at"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_tenant_webhook_seq")
+                    .table(Alias::new("webhook_deliveries"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("webhook_id"))
+                    .col(Alias::new("seq"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("webhook_deliveries")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("webhooks")).to_owned()).await
+    }
+}
@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 定时任务，由调度器启动时登记，保存暂停状态与下次执行时间
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("scheduled_jobs"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("name")).string_len(64).not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("description")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("cron")).string_len(100).not_null())
+                    .col(ColumnDef::new(Alias::new("paused")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("next_run_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("last_run_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("last_status")).integer().null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 执行记录
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("job_runs"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("id")).string().not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("job_name")).string_len(64).not_null())
+                    .col(ColumnDef::new(Alias::new("trigger")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("status")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("triggered_by")).string().null())
+                    .col(ColumnDef::new(Alias::new("instance")).string().not_null())
+                    .col(ColumnDef::new(Alias::new("started_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("finished_at")).timestamp_with_time_zone().null())
+                    .col(ColumnDef::new(Alias::new("duration_ms")).big_integer().null())
+                    .col(ColumnDef::new(Alias::new("affected")).big_integer().null())
+                    .col(ColumnDef::new(Alias::new("error")).text().null())
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_runs_job_name_started_at")
+                    .table(Alias::new("job_runs"))
+                    .col(Alias::new("job_name"))
+                    .col(Alias::new("started_at"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_job_runs_started_at")
+                    .table(Alias::new("job_runs"))
+                    .col(Alias::new("started_at"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 黑名单清理改由应用内调度器执行，移除原 MySQL 定时事件
+        manager.get_connection().execute_unprepared("DROP EVENT IF EXISTS clean_token_blacklist;").await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("job_runs")).to_owned()).await?;
+        manager.drop_table(Table::drop().table(Alias::new("scheduled_jobs")).to_owned()).await
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 功能开关，平台级数据，白名单以 JSON 数组保存
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("feature_flags"))
+                    .if_not_exists()
+                    .col(ColumnDef::new(Alias::new("key")).string_len(64).not_null().primary_key())
+                    .col(ColumnDef::new(Alias::new("description")).string().not_null().default(""))
+                    .col(ColumnDef::new(Alias::new("enabled")).boolean().not_null().default(false))
+                    .col(ColumnDef::new(Alias::new("rollout_percentage")).integer().not_null().default(0))
+                    .col(ColumnDef::new(Alias::new("allowed_roles")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("allowed_tenants")).text().not_null())
+                    .col(ColumnDef::new(Alias::new("created_at")).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(Alias::new("updated_at")).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager.drop_table(Table::drop().table(Alias::new("feature_flags")).to_owned()).await
+    }
+}
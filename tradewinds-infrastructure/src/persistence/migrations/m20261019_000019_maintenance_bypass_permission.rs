@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::*;
+
+/// 维护模式豁免权限的ID
+const PERMISSION_ID: &str = "550e8400-e29b-41d4-a716-446655440017";
+/// 超级管理员持有维护模式豁免权限的关联ID
+const ROLE_PERMISSION_ID: &str = "550e8400-e29b-41d4-a716-446655440026";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 维护模式与只读模式的豁免权限只在平台默认租户内生效，内置并授予超级管理员
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "INSERT INTO permissions (id, tenant_id, name, code, type, sort, status, built_in, created_at, updated_at) \
+                 SELECT '{id}', 'default', '维护模式豁免', 'maintenance:bypass', 2, 0, 1, TRUE, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP \
+                 WHERE NOT EXISTS (SELECT 1 FROM permissions WHERE tenant_id = 'default' AND code = 'maintenance:bypass');",
+                id = PERMISSION_ID
+            ))
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "INSERT INTO role_permissions (id, tenant_id, role_id, permission_id, created_at, updated_at) \
+                 SELECT '{id}', 'default', r.id, p.id, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP \
+                 FROM roles r, permissions p \
+                 WHERE r.tenant_id = 'default' AND r.code = 'super_admin' \
+                 AND p.tenant_id = 'default' AND p.code = 'maintenance:bypass' \
+                 AND NOT EXISTS (SELECT 1 FROM role_permissions rp WHERE rp.role_id = r.id AND rp.permission_id = p.id);",
+                id = ROLE_PERMISSION_ID
+            ))
+            .await?;
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(&format!("DELETE FROM role_permissions WHERE id = '{}';", ROLE_PERMISSION_ID))
+            .await?;
+        manager
+            .get_connection()
+            .execute_unprepared(&format!("DELETE FROM permissions WHERE id = '{}';", PERMISSION_ID))
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 中继按聚合认领队首消息时查找同一聚合中更早的待投递消息
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_messages_aggregate_status_seq")
+                    .table(Alias::new("outbox_messages"))
+                    .col(Alias::new("tenant_id"))
+                    .col(Alias::new("aggregate_type"))
+                    .col(Alias::new("aggregate_id"))
+                    .col(Alias::new("status"))
+                    .col(Alias::new("seq"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_outbox_messages_aggregate_status_seq")
+                    .table(Alias::new("outbox_messages"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+/// 补充租户字段的关联表：(表, 外键列, 所属表)
+const CHILD_TABLES: [(&str, &str, &str); 7] = [
+    ("role_approvers", "role_id", "roles"),
+    ("role_departments", "role_id", "roles"),
+    ("user_group_members", "group_id", "user_groups"),
+    ("user_group_roles", "group_id", "user_groups"),
+    ("access_review_items", "review_id", "access_reviews"),
+    ("access_review_roles", "review_id", "access_reviews"),
+    ("sod_rule_roles", "rule_id", "sod_rules"),
+];
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (table, foreign_key, parent) in CHILD_TABLES {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Alias::new(table))
+                        .add_column(
+                            ColumnDef::new(Alias::new("tenant_id")).string_len(64).not_null().default("default"),
+                        )
+                        .to_owned(),
+                )
+                .await?;
+            manager
+                .create_index(
+                    Index::create()
+                        .name(format!("idx_{}_tenant_id", table))
+                        .table(Alias::new(table))
+                        .col(Alias::new("tenant_id"))
+                        .to_owned(),
+                )
+                .await?;
+
+            // 已有数据沿用所属记录的租户
+            manager
+                .get_connection()
+                .execute_unprepared(&format!(
+                    "UPDATE {table} c JOIN {parent} p ON c.{foreign_key} = p.id SET c.tenant_id = p.tenant_id;"
+                ))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for (table, _, _) in CHILD_TABLES {
+            manager
+                .drop_index(Index::drop().name(format!("idx_{}_tenant_id", table)).table(Alias::new(table)).to_owned())
+                .await?;
+            manager
+                .alter_table(Table::alter().table(Alias::new(table)).drop_column(Alias::new("tenant_id")).to_owned())
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 记录经权限申请授予的角色关联对应的申请，申请到期时只收回由该申请授予的角色
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("user_roles"))
+                    .add_column(ColumnDef::new(Alias::new("access_request_id")).string_len(255).null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter().table(Alias::new("user_roles")).drop_column(Alias::new("access_request_id")).to_owned(),
+            )
+            .await
+    }
+}
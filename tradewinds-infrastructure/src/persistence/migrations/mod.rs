@@ -5,8 +5,52 @@ pub struct Migrator;
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20240626_000001_init::Migration)]
+        vec![
+            Box::new(m20240626_000001_init::Migration),
+            Box::new(m20261019_000002_role_permission_effect::Migration),
+            Box::new(m20261019_000003_data_scope::Migration),
+            Box::new(m20261019_000004_departments::Migration),
+            Box::new(m20261019_000005_user_groups::Migration),
+            Box::new(m20261019_000006_sod_rules::Migration),
+            Box::new(m20261019_000007_built_in_roles::Migration),
+            Box::new(m20261019_000008_multi_tenancy::Migration),
+            Box::new(m20261019_000009_access_policies::Migration),
+            Box::new(m20261019_000010_access_requests::Migration),
+            Box::new(m20261019_000011_access_reviews::Migration),
+            Box::new(m20261019_000012_audit_logs::Migration),
+            Box::new(m20261019_000013_audit_log_chain::Migration),
+            Box::new(m20261019_000014_login_logs::Migration),
+            Box::new(m20261019_000015_outbox_messages::Migration),
+            Box::new(m20261019_000016_webhooks::Migration),
+            Box::new(m20261019_000017_scheduled_jobs::Migration),
+            Box::new(m20261019_000018_feature_flags::Migration),
+            Box::new(m20261019_000019_maintenance_bypass_permission::Migration),
+            Box::new(m20261019_000020_outbox_aggregate_index::Migration),
+            Box::new(m20261019_000021_tenant_child_tables::Migration),
+            Box::new(m20261019_000022_user_role_access_request::Migration),
+        ]
     }
 }
 
 pub mod m20240626_000001_init;
+pub mod m20261019_000002_role_permission_effect;
+pub mod m20261019_000003_data_scope;
+pub mod m20261019_000004_departments;
+pub mod m20261019_000005_user_groups;
+pub mod m20261019_000006_sod_rules;
+pub mod m20261019_000007_built_in_roles;
+pub mod m20261019_000008_multi_tenancy;
+pub mod m20261019_000009_access_policies;
+pub mod m20261019_000010_access_requests;
+pub mod m20261019_000011_access_reviews;
+pub mod m20261019_000012_audit_logs;
+pub mod m20261019_000013_audit_log_chain;
+pub mod m20261019_000014_login_logs;
+pub mod m20261019_000015_outbox_messages;
+pub mod m20261019_000016_webhooks;
+pub mod m20261019_000017_scheduled_jobs;
+pub mod m20261019_000018_feature_flags;
+pub mod m20261019_000019_maintenance_bypass_permission;
+pub mod m20261019_000020_outbox_aggregate_index;
+pub mod m20261019_000021_tenant_child_tables;
+pub mod m20261019_000022_user_role_access_request;
@@ -1,3 +1,4 @@
 pub mod entities;
 pub mod repositories;
 pub mod migrations;
+pub(crate) mod tenant_scope;
@@ -1,21 +1,57 @@
+pub mod sea_orm_access_policy_repository;
+pub mod sea_orm_access_request_repository;
+pub mod sea_orm_access_review_repository;
+pub mod sea_orm_audit_log_repository;
+pub mod sea_orm_department_aggregate_repository;
+pub mod sea_orm_department_repository;
+pub mod sea_orm_feature_flag_repository;
+pub mod sea_orm_group_aggregate_repository;
+pub mod sea_orm_group_repository;
+pub mod sea_orm_job_run_repository;
+pub mod sea_orm_login_log_repository;
+pub mod sea_orm_outbox_repository;
 pub mod sea_orm_permission_aggregate_repository;
 pub mod sea_orm_permission_repository;
 pub mod sea_orm_role_aggregate_repository;
+pub mod sea_orm_role_approver_repository;
 pub mod sea_orm_role_permission_repository;
 pub mod sea_orm_role_repository;
+pub mod sea_orm_scheduled_job_repository;
+pub mod sea_orm_sod_rule_repository;
+pub mod sea_orm_tenant_repository;
 pub mod sea_orm_token_blacklist_repository;
 pub mod sea_orm_user_aggregate_repository;
 pub mod sea_orm_user_repository;
 pub mod sea_orm_user_role_repository;
+pub mod sea_orm_webhook_delivery_repository;
+pub mod sea_orm_webhook_repository;
 pub mod sea_orm_system_setting_repository;
 
+pub use sea_orm_access_policy_repository::*;
+pub use sea_orm_access_request_repository::*;
+pub use sea_orm_access_review_repository::*;
+pub use sea_orm_audit_log_repository::*;
+pub use sea_orm_department_aggregate_repository::*;
+pub use sea_orm_department_repository::*;
+pub use sea_orm_feature_flag_repository::*;
+pub use sea_orm_group_aggregate_repository::*;
+pub use sea_orm_group_repository::*;
+pub use sea_orm_job_run_repository::*;
+pub use sea_orm_login_log_repository::*;
+pub use sea_orm_outbox_repository::*;
 pub use sea_orm_permission_aggregate_repository::*;
 pub use sea_orm_permission_repository::*;
 pub use sea_orm_role_aggregate_repository::*;
+pub use sea_orm_role_approver_repository::*;
 pub use sea_orm_role_permission_repository::*;
 pub use sea_orm_role_repository::*;
+pub use sea_orm_scheduled_job_repository::*;
+pub use sea_orm_sod_rule_repository::*;
+pub use sea_orm_tenant_repository::*;
 pub use sea_orm_token_blacklist_repository::*;
 pub use sea_orm_user_aggregate_repository::*;
 pub use sea_orm_user_repository::*;
 pub use sea_orm_user_role_repository::*;
+pub use sea_orm_webhook_delivery_repository::*;
+pub use sea_orm_webhook_repository::*;
 pub use sea_orm_system_setting_repository::*;
\ No newline at end of file
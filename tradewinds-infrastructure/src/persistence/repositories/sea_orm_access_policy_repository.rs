@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::access_policy::AccessPolicy;
+use tradewinds_domain::repositories::AccessPolicyRepository;
+use tradewinds_domain::value_objects::permission::PermissionCode;
+use tradewinds_domain::value_objects::policy::{
+    AccessPolicyId, AccessPolicyName, AccessPolicyStatus, PolicyExpression,
+};
+use tradewinds_domain::value_objects::role_permission::PermissionEffect;
+
+use crate::persistence::entities::access_policy;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn policy_from_model(model: access_policy::Model) -> AppResult<AccessPolicy> {
+    Ok(AccessPolicy {
+        id: AccessPolicyId::new(model.id)?,
+        name: AccessPolicyName::new(model.name)?,
+        description: model.description,
+        action: PermissionCode::new(model.action)?,
+        effect: PermissionEffect::from_i32(model.effect)?,
+        condition: PolicyExpression::new(model.condition)?,
+        status: AccessPolicyStatus::from_i32(model.status)?,
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn policy_to_active_model(policy: &AccessPolicy) -> access_policy::ActiveModel {
+    let now: DateTime<Utc> = Utc::now();
+    let created_at = DateTime::from_timestamp(policy.created_at, 0).unwrap_or(now);
+    access_policy::ActiveModel {
+        id: Set(policy.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        name: Set(policy.name.value().to_string()),
+        description: Set(policy.description.clone()),
+        action: Set(policy.action.value().to_string()),
+        effect: Set(policy.effect.value()),
+        condition: Set(policy.condition.value().to_string()),
+        status: Set(policy.status.value()),
+        created_at: Set(created_at.into()),
+        updated_at: Set(now.into()),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmAccessPolicyRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAccessPolicyRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn find_one(&self, condition: Condition) -> AppResult<Option<AccessPolicy>> {
+        access_policy::Entity::find()
+            .tenant_scoped()
+            .filter(condition)
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find access policy failed: {}", e)))?
+            .map(policy_from_model)
+            .transpose()
+    }
+}
+
+#[async_trait]
+impl AccessPolicyRepository for SeaOrmAccessPolicyRepository {
+    async fn create(&self, policy: &AccessPolicy) -> AppResult<()> {
+        policy_to_active_model(policy)
+            .insert(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Create access policy failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn save(&self, policy: &AccessPolicy) -> AppResult<()> {
+        access_policy::Entity::update(policy_to_active_model(policy))
+            .tenant_scoped()
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update access policy failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &AccessPolicyId) -> AppResult<Option<AccessPolicy>> {
+        self.find_one(Condition::all().add(access_policy::Column::Id.eq(id.value()))).await
+    }
+
+    async fn find_by_name(&self, name: &AccessPolicyName) -> AppResult<Option<AccessPolicy>> {
+        self.find_one(
+            Condition::all()
+                .add(access_policy::Column::Name.eq(name.value()))
+                .add(access_policy::Column::Status.ne(AccessPolicyStatus::Deleted.value())),
+        )
+        .await
+    }
+
+    async fn find_active(&self) -> AppResult<Vec<AccessPolicy>> {
+        let models = access_policy::Entity::find()
+            .tenant_scoped()
+            .filter(access_policy::Column::Status.eq(AccessPolicyStatus::Active.value()))
+            .order_by_asc(access_policy::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List access policies failed: {}", e)))?;
+        models.into_iter().map(policy_from_model).collect()
+    }
+
+    async fn search(
+        &self,
+        keyword: Option<&str>,
+        status: Option<AccessPolicyStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessPolicy>, u64)> {
+        let mut query = access_policy::Entity::find().tenant_scoped();
+        if let Some(keyword) = keyword {
+            query = query.filter(
+                Condition::any()
+                    .add(access_policy::Column::Name.contains(keyword))
+                    .add(access_policy::Column::Action.contains(keyword)),
+            );
+        }
+        if let Some(status) = status {
+            query = query.filter(access_policy::Column::Status.eq(status.value()));
+        } else {
+            query = query.filter(access_policy::Column::Status.ne(AccessPolicyStatus::Deleted.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count access policies failed: {}", e)))?;
+        let models = query
+            .order_by_desc(access_policy::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List access policies failed: {}", e)))?;
+        let policies = models.into_iter().map(policy_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((policies, total))
+    }
+}
@@ -0,0 +1,195 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::aggregates::access_request_aggregate::AccessRequestAggregate;
+use tradewinds_domain::entities::access_request::AccessRequest;
+use tradewinds_domain::repositories::AccessRequestRepository;
+use tradewinds_domain::value_objects::access_request::{
+    AccessDuration, AccessJustification, AccessRequestId, AccessRequestStatus,
+};
+use tradewinds_domain::value_objects::{role::RoleId, user::UserId};
+
+use crate::persistence::entities::access_request;
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn request_from_model(model: access_request::Model) -> AppResult<AccessRequest> {
+    Ok(AccessRequest {
+        id: AccessRequestId::new(model.id)?,
+        requester_id: UserId::new(model.requester_id)?,
+        role_id: RoleId::new(model.role_id)?,
+        justification: AccessJustification::new(model.justification)?,
+        duration: AccessDuration::from_days(u32::try_from(model.duration_days).unwrap_or_default())?,
+        status: AccessRequestStatus::from_i32(model.status)?,
+        decided_by: model.decided_by.map(UserId::new).transpose()?,
+        decision_comment: model.decision_comment,
+        decided_at: model.decided_at.map(|at| at.timestamp()),
+        expires_at: model.expires_at.timestamp(),
+        grant_expires_at: model.grant_expires_at.map(|at| at.timestamp()),
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+fn request_to_active_model(request: &AccessRequest) -> access_request::ActiveModel {
+    access_request::ActiveModel {
+        id: Set(request.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        requester_id: Set(request.requester_id.value().to_string()),
+        role_id: Set(request.role_id.value().to_string()),
+        justification: Set(request.justification.value().to_string()),
+        duration_days: Set(request.duration.days() as i32),
+        status: Set(request.status.value()),
+        decided_by: Set(request.decided_by.as_ref().map(|u| u.value().to_string())),
+        decision_comment: Set(request.decision_comment.clone()),
+        decided_at: Set(request.decided_at.map(|at| timestamp(at).into())),
+        expires_at: Set(timestamp(request.expires_at).into()),
+        grant_expires_at: Set(request.grant_expires_at.map(|at| timestamp(at).into())),
+        created_at: Set(timestamp(request.created_at).into()),
+        updated_at: Set(Utc::now().into()),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmAccessRequestRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAccessRequestRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AccessRequestRepository for SeaOrmAccessRequestRepository {
+    async fn create(&self, aggregate: &AccessRequestAggregate) -> AppResult<()> {
+        let model = request_to_active_model(&aggregate.request);
+        let messages = aggregate.outbox_messages()?;
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    model.insert(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Create access request failed: {}", e))
+            })
+    }
+
+    async fn save(&self, aggregate: &AccessRequestAggregate) -> AppResult<()> {
+        let model = request_to_active_model(&aggregate.request);
+        let messages = aggregate.outbox_messages()?;
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    access_request::Entity::update(model).tenant_scoped().exec(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Update access request failed: {}", e))
+            })
+    }
+
+    async fn find_by_id(&self, id: &AccessRequestId) -> AppResult<Option<AccessRequest>> {
+        access_request::Entity::find_by_id(id.value())
+            .tenant_scoped()
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find access request failed: {}", e)))?
+            .map(request_from_model)
+            .transpose()
+    }
+
+    async fn find_pending(&self, requester_id: &UserId, role_id: &RoleId) -> AppResult<Option<AccessRequest>> {
+        access_request::Entity::find()
+            .tenant_scoped()
+            .filter(access_request::Column::RequesterId.eq(requester_id.value()))
+            .filter(access_request::Column::RoleId.eq(role_id.value()))
+            .filter(access_request::Column::Status.eq(AccessRequestStatus::Pending.value()))
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find pending access request failed: {}", e)))?
+            .map(request_from_model)
+            .transpose()
+    }
+
+    async fn find_due_for_expiry(&self, now: i64) -> AppResult<Vec<AccessRequest>> {
+        let now = timestamp(now);
+        let models = access_request::Entity::find()
+            .tenant_scoped()
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(access_request::Column::Status.eq(AccessRequestStatus::Pending.value()))
+                            .add(access_request::Column::ExpiresAt.lte(now)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(access_request::Column::Status.eq(AccessRequestStatus::Approved.value()))
+                            .add(access_request::Column::GrantExpiresAt.lte(now)),
+                    ),
+            )
+            .order_by_asc(access_request::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find expiring access requests failed: {}", e)))?;
+        models.into_iter().map(request_from_model).collect()
+    }
+
+    async fn search(
+        &self,
+        requester_id: Option<&UserId>,
+        role_ids: Option<&[RoleId]>,
+        status: Option<AccessRequestStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessRequest>, u64)> {
+        let mut query = access_request::Entity::find().tenant_scoped();
+        if let Some(requester_id) = requester_id {
+            query = query.filter(access_request::Column::RequesterId.eq(requester_id.value()));
+        }
+        if let Some(role_ids) = role_ids {
+            if role_ids.is_empty() {
+                return Ok((Vec::new(), 0));
+            }
+            query = query.filter(access_request::Column::RoleId.is_in(role_ids.iter().map(|r| r.value().to_string())));
+        }
+        if let Some(status) = status {
+            query = query.filter(access_request::Column::Status.eq(status.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count access requests failed: {}", e)))?;
+        let models = query
+            .order_by_desc(access_request::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List access requests failed: {}", e)))?;
+        let requests = models.into_iter().map(request_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((requests, total))
+    }
+}
@@ -0,0 +1,251 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use uuid::Uuid;
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_domain::entities::access_review::{AccessReviewCampaign, AccessReviewItem};
+use tradewinds_domain::repositories::AccessReviewRepository;
+use tradewinds_domain::value_objects::access_review::{
+    AccessReviewId, AccessReviewItemId, AccessReviewName, AccessReviewStatus, ReviewDecision,
+};
+use tradewinds_domain::value_objects::{role::RoleId, user::UserId};
+
+use crate::persistence::entities::{access_review, access_review_item, access_review_role};
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+fn campaign_from_model(model: access_review::Model, role_ids: Vec<RoleId>) -> AppResult<AccessReviewCampaign> {
+    Ok(AccessReviewCampaign {
+        id: AccessReviewId::new(model.id)?,
+        name: AccessReviewName::new(model.name)?,
+        role_ids,
+        deadline: model.deadline.timestamp(),
+        status: AccessReviewStatus::from_i32(model.status)?,
+        created_by: UserId::new(model.created_by)?,
+        completed_at: model.completed_at.map(|at| at.timestamp()),
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn campaign_to_active_model(campaign: &AccessReviewCampaign) -> access_review::ActiveModel {
+    access_review::ActiveModel {
+        id: Set(campaign.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        name: Set(campaign.name.value().to_string()),
+        deadline: Set(timestamp(campaign.deadline).into()),
+        status: Set(campaign.status.value()),
+        created_by: Set(campaign.created_by.value().to_string()),
+        completed_at: Set(campaign.completed_at.map(|at| timestamp(at).into())),
+        created_at: Set(timestamp(campaign.created_at).into()),
+        updated_at: Set(Utc::now().into()),
+    }
+}
+
+fn item_from_model(model: access_review_item::Model) -> AppResult<AccessReviewItem> {
+    Ok(AccessReviewItem {
+        id: AccessReviewItemId::new(model.id)?,
+        user_id: UserId::new(model.user_id)?,
+        role_id: RoleId::new(model.role_id)?,
+        reviewer_id: UserId::new(model.reviewer_id)?,
+        decision: ReviewDecision::from_i32(model.decision)?,
+        comment: model.comment,
+        decided_at: model.decided_at.map(|at| at.timestamp()),
+    })
+}
+
+fn item_to_active_model(review_id: &AccessReviewId, item: &AccessReviewItem) -> access_review_item::ActiveModel {
+    access_review_item::ActiveModel {
+        id: Set(item.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        review_id: Set(review_id.value().to_string()),
+        user_id: Set(item.user_id.value().to_string()),
+        role_id: Set(item.role_id.value().to_string()),
+        reviewer_id: Set(item.reviewer_id.value().to_string()),
+        decision: Set(item.decision.value()),
+        comment: Set(item.comment.clone()),
+        decided_at: Set(item.decided_at.map(|at| timestamp(at).into())),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmAccessReviewRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAccessReviewRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    /// 批量加载活动的角色范围与复核项并组装聚合
+    async fn load(&self, models: Vec<access_review::Model>) -> AppResult<Vec<AccessReviewAggregate>> {
+        if models.is_empty() {
+            return Ok(Vec::new());
+        }
+        let review_ids: Vec<String> = models.iter().map(|m| m.id.clone()).collect();
+        let roles = access_review_role::Entity::find()
+            .tenant_scoped()
+            .filter(access_review_role::Column::ReviewId.is_in(review_ids.clone()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find access review roles failed: {}", e)))?;
+        let items = access_review_item::Entity::find()
+            .tenant_scoped()
+            .filter(access_review_item::Column::ReviewId.is_in(review_ids))
+            .order_by_asc(access_review_item::Column::RoleId)
+            .order_by_asc(access_review_item::Column::UserId)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find access review items failed: {}", e)))?;
+
+        models
+            .into_iter()
+            .map(|model| {
+                let role_ids = roles
+                    .iter()
+                    .filter(|r| r.review_id == model.id)
+                    .map(|r| RoleId::new(r.role_id.clone()))
+                    .collect::<AppResult<Vec<_>>>()?;
+                let review_items = items
+                    .iter()
+                    .filter(|i| i.review_id == model.id)
+                    .cloned()
+                    .map(item_from_model)
+                    .collect::<AppResult<Vec<_>>>()?;
+                Ok(AccessReviewAggregate::from_existing(campaign_from_model(model, role_ids)?, review_items))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl AccessReviewRepository for SeaOrmAccessReviewRepository {
+    async fn create(&self, aggregate: &AccessReviewAggregate) -> AppResult<()> {
+        let campaign = &aggregate.campaign;
+        let campaign_model = campaign_to_active_model(campaign);
+        let role_models: Vec<access_review_role::ActiveModel> = campaign
+            .role_ids
+            .iter()
+            .map(|role_id| access_review_role::ActiveModel {
+                id: Set(Uuid::new_v4().to_string()),
+                tenant_id: Set(current_tenant_id()),
+                review_id: Set(campaign.id.value().to_string()),
+                role_id: Set(role_id.value().to_string()),
+            })
+            .collect();
+        let item_models: Vec<access_review_item::ActiveModel> =
+            aggregate.items.iter().map(|item| item_to_active_model(&campaign.id, item)).collect();
+
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    campaign_model.insert(txn).await?;
+                    if !role_models.is_empty() {
+                        access_review_role::Entity::insert_many(role_models).exec(txn).await?;
+                    }
+                    if !item_models.is_empty() {
+                        access_review_item::Entity::insert_many(item_models).exec(txn).await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Create access review failed: {}", e))
+            })
+    }
+
+    async fn save(&self, aggregate: &AccessReviewAggregate) -> AppResult<()> {
+        let campaign_model = campaign_to_active_model(&aggregate.campaign);
+        let item_models: Vec<access_review_item::ActiveModel> =
+            aggregate.items.iter().map(|item| item_to_active_model(&aggregate.campaign.id, item)).collect();
+
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    access_review::Entity::update(campaign_model).tenant_scoped().exec(txn).await?;
+                    for item_model in item_models {
+                        access_review_item::Entity::update(item_model).tenant_scoped().exec(txn).await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Update access review failed: {}", e))
+            })
+    }
+
+    async fn find_by_id(&self, id: &AccessReviewId) -> AppResult<Option<AccessReviewAggregate>> {
+        let model = access_review::Entity::find_by_id(id.value())
+            .tenant_scoped()
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find access review failed: {}", e)))?;
+        Ok(self.load(model.into_iter().collect()).await?.pop())
+    }
+
+    async fn find_due(&self, now: i64) -> AppResult<Vec<AccessReviewAggregate>> {
+        let models = access_review::Entity::find()
+            .tenant_scoped()
+            .filter(access_review::Column::Status.eq(AccessReviewStatus::Active.value()))
+            .filter(access_review::Column::Deadline.lte(timestamp(now)))
+            .order_by_asc(access_review::Column::Deadline)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find due access reviews failed: {}", e)))?;
+        self.load(models).await
+    }
+
+    async fn search(
+        &self,
+        reviewer_id: Option<&UserId>,
+        status: Option<AccessReviewStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<AccessReviewAggregate>, u64)> {
+        let mut query = access_review::Entity::find().tenant_scoped();
+        if let Some(reviewer_id) = reviewer_id {
+            let review_ids: Vec<String> = access_review_item::Entity::find()
+                .tenant_scoped()
+                .filter(access_review_item::Column::ReviewerId.eq(reviewer_id.value()))
+                .select_only()
+                .column(access_review_item::Column::ReviewId)
+                .distinct()
+                .into_tuple()
+                .all(&self.db)
+                .await
+                .map_err(|e| AppError::DatabaseError(format!("Find reviewer assignments failed: {}", e)))?;
+            if review_ids.is_empty() {
+                return Ok((Vec::new(), 0));
+            }
+            query = query.filter(access_review::Column::Id.is_in(review_ids));
+        }
+        if let Some(status) = status {
+            query = query.filter(access_review::Column::Status.eq(status.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count access reviews failed: {}", e)))?;
+        let models = query
+            .order_by_desc(access_review::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List access reviews failed: {}", e)))?;
+        Ok((self.load(models).await?, total))
+    }
+}
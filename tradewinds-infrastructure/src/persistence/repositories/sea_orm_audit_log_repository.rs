@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+use serde_json::Value;
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::{audit_checkpoint::AuditCheckpoint, audit_log::AuditLog};
+use tradewinds_domain::repositories::{AuditLogFilter, AuditLogRepository};
+use tradewinds_domain::value_objects::audit::{AuditLogId, AuditOutcome};
+use tradewinds_domain::value_objects::user::UserId;
+
+use crate::persistence::entities::{audit_checkpoint, audit_log};
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn parse_json(raw: Option<String>) -> AppResult<Option<Value>> {
+    raw.map(|raw| serde_json::from_str(&raw))
+        .transpose()
+        .map_err(|e| AppError::DatabaseError(format!("Invalid audit log payload: {}", e)))
+}
+
+fn log_from_model(model: audit_log::Model) -> AppResult<AuditLog> {
+    Ok(AuditLog {
+        id: AuditLogId::new(model.id)?,
+        actor_id: model.actor_id.map(UserId::new).transpose()?,
+        actor_name: model.actor_name,
+        action: model.action,
+        target_type: model.target_type,
+        target_id: model.target_id,
+        params: parse_json(model.params)?,
+        changes: parse_json(model.changes)?,
+        client_ip: model.client_ip,
+        user_agent: model.user_agent,
+        request_id: model.request_id,
+        outcome: AuditOutcome::from_i32(model.outcome)?,
+        error_message: model.error_message,
+        created_at: model.created_at.timestamp(),
+        seq: model.seq as u64,
+        prev_hash: model.prev_hash,
+        hash: model.hash,
+    })
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+fn log_to_active_model(log: &AuditLog) -> audit_log::ActiveModel {
+    audit_log::ActiveModel {
+        id: Set(log.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        actor_id: Set(log.actor_id.as_ref().map(|u| u.value().to_string())),
+        actor_name: Set(log.actor_name.clone()),
+        action: Set(log.action.clone()),
+        target_type: Set(log.target_type.clone()),
+        target_id: Set(log.target_id.clone()),
+        params: Set(log.params.as_ref().map(Value::to_string)),
+        changes: Set(log.changes.as_ref().map(Value::to_string)),
+        client_ip: Set(log.client_ip.clone()),
+        user_agent: Set(log.user_agent.clone()),
+        request_id: Set(log.request_id.clone()),
+        outcome: Set(log.outcome.value()),
+        error_message: Set(log.error_message.clone()),
+        created_at: Set(timestamp(log.created_at).into()),
+        seq: Set(log.seq as i64),
+        prev_hash: Set(log.prev_hash.clone()),
+        hash: Set(log.hash.clone()),
+    }
+}
+
+fn checkpoint_from_model(model: audit_checkpoint::Model) -> AuditCheckpoint {
+    AuditCheckpoint {
+        seq: model.seq as u64,
+        hash: model.hash,
+        signature: model.signature,
+        created_at: model.created_at.timestamp(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmAuditLogRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmAuditLogRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl AuditLogRepository for SeaOrmAuditLogRepository {
+    /// 锁定链尾后链接并写入；链为空时无行可锁，并发的首条写入由 (tenant_id, seq) 唯一索引拒绝
+    async fn append(&self, mut log: AuditLog) -> AppResult<AuditLog> {
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    let tail = audit_log::Entity::find()
+                        .tenant_scoped()
+                        .order_by_desc(audit_log::Column::Seq)
+                        .lock_exclusive()
+                        .one(txn)
+                        .await?;
+                    log.link(tail.as_ref().map(|tail| (tail.seq as u64, tail.hash.as_str())));
+                    log_to_active_model(&log).insert(txn).await?;
+                    Ok(log)
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Append audit log failed: {}", e))
+            })
+    }
+
+    async fn search(&self, filter: &AuditLogFilter, limit: u64, offset: u64) -> AppResult<(Vec<AuditLog>, u64)> {
+        let mut query = audit_log::Entity::find().tenant_scoped();
+        if let Some(actor_id) = &filter.actor_id {
+            query = query.filter(audit_log::Column::ActorId.eq(actor_id.value()));
+        }
+        if let Some(action) = &filter.action {
+            query = query.filter(audit_log::Column::Action.starts_with(action.as_str()));
+        }
+        if let Some(target_type) = &filter.target_type {
+            query = query.filter(audit_log::Column::TargetType.eq(target_type.as_str()));
+        }
+        if let Some(target_id) = &filter.target_id {
+            query = query.filter(audit_log::Column::TargetId.eq(target_id.as_str()));
+        }
+        if let Some(outcome) = filter.outcome {
+            query = query.filter(audit_log::Column::Outcome.eq(outcome.value()));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(audit_log::Column::CreatedAt.gte(timestamp(from)));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(audit_log::Column::CreatedAt.lt(timestamp(to)));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count audit logs failed: {}", e)))?;
+        let models = query
+            .order_by_desc(audit_log::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List audit logs failed: {}", e)))?;
+        let logs = models.into_iter().map(log_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((logs, total))
+    }
+
+    async fn find_chain(&self, after_seq: u64, limit: u64) -> AppResult<Vec<AuditLog>> {
+        let models = audit_log::Entity::find()
+            .tenant_scoped()
+            .filter(audit_log::Column::Seq.gt(after_seq as i64))
+            .order_by_asc(audit_log::Column::Seq)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find audit chain failed: {}", e)))?;
+        models.into_iter().map(log_from_model).collect()
+    }
+
+    async fn create_checkpoint(&self, checkpoint: &AuditCheckpoint) -> AppResult<()> {
+        audit_checkpoint::ActiveModel {
+            id: Set(uuid::Uuid::new_v4().to_string()),
+            tenant_id: Set(current_tenant_id()),
+            seq: Set(checkpoint.seq as i64),
+            hash: Set(checkpoint.hash.clone()),
+            signature: Set(checkpoint.signature.clone()),
+            created_at: Set(timestamp(checkpoint.created_at).into()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Create audit checkpoint failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn find_checkpoints(&self) -> AppResult<Vec<AuditCheckpoint>> {
+        let models = audit_checkpoint::Entity::find()
+            .tenant_scoped()
+            .order_by_asc(audit_checkpoint::Column::Seq)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find audit checkpoints failed: {}", e)))?;
+        Ok(models.into_iter().map(checkpoint_from_model).collect())
+    }
+
+    async fn find_tenant_ids(&self) -> AppResult<Vec<String>> {
+        audit_log::Entity::find()
+            .select_only()
+            .column(audit_log::Column::TenantId)
+            .distinct()
+            .order_by_asc(audit_log::Column::TenantId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find audit log tenants failed: {}", e)))
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, TransactionTrait};
+
+use crate::persistence::entities::department;
+use crate::persistence::repositories::sea_orm_department_repository::{
+    department_from_model, department_to_active_model,
+};
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_domain::aggregates::department_aggregate::DepartmentAggregate;
+use tradewinds_domain::repositories::DepartmentAggregateRepository;
+use tradewinds_domain::value_objects::department::DepartmentId;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Clone)]
+pub struct SeaOrmDepartmentAggregateRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmDepartmentAggregateRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DepartmentAggregateRepository for SeaOrmDepartmentAggregateRepository {
+    async fn create(&self, aggregate: &DepartmentAggregate) -> AppResult<()> {
+        let model = department_to_active_model(&aggregate.department);
+        let messages = aggregate.events().to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    model.insert(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to create department: {}", e))
+            })
+    }
+
+    async fn save(&self, aggregate: &DepartmentAggregate) -> AppResult<()> {
+        let model = department_to_active_model(&aggregate.department);
+        let messages = aggregate.events().to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    department::Entity::update(model).tenant_scoped().exec(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to save department: {}", e))
+            })
+    }
+
+    async fn find_by_id(&self, id: &DepartmentId) -> AppResult<Option<DepartmentAggregate>> {
+        let model = department::Entity::find_by_id(id.value())
+            .tenant_scoped()
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find department by id: {}", e)))?;
+
+        let Some(model) = model else {
+            return Ok(None);
+        };
+        Ok(Some(DepartmentAggregate::from_existing(department_from_model(model)?)))
+    }
+}
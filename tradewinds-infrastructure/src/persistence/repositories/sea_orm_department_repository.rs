@@ -0,0 +1,196 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::department::Department;
+use tradewinds_domain::repositories::DepartmentRepository;
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_domain::value_objects::department::{DepartmentId, DepartmentName, DepartmentSort, DepartmentStatus};
+
+use crate::persistence::entities::department;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+/// 将部门表记录转换为领域实体
+pub(crate) fn department_from_model(model: department::Model) -> AppResult<Department> {
+    Ok(Department {
+        id: DepartmentId::new(model.id)?,
+        name: DepartmentName::new(model.name)?,
+        parent_id: model.parent_id.map(DepartmentId::new).transpose()?,
+        sort: DepartmentSort::new(model.sort)?,
+        leader_id: model.leader_id.map(UserId::new).transpose()?,
+        status: DepartmentStatus::from_i32(model.status)?,
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+pub(crate) fn department_to_active_model(department: &Department) -> department::ActiveModel {
+    let now: DateTime<Utc> = Utc::now();
+    let created_at = DateTime::from_timestamp(department.created_at, 0).unwrap_or(now);
+    department::ActiveModel {
+        id: Set(department.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        name: Set(department.name.value().to_string()),
+        parent_id: Set(department.parent_id.as_ref().map(|p| p.value().to_string())),
+        sort: Set(department.sort.value()),
+        leader_id: Set(department.leader_id.as_ref().map(|l| l.value().to_string())),
+        status: Set(department.status.value()),
+        created_at: Set(created_at.into()),
+        updated_at: Set(now.into()),
+    }
+}
+
+/// 查询所有未删除的部门
+async fn find_active_departments<C: ConnectionTrait>(db: &C) -> AppResult<Vec<Department>> {
+    department::Entity::find()
+        .tenant_scoped()
+        .filter(department::Column::Status.ne(DepartmentStatus::Deleted.value()))
+        .order_by_asc(department::Column::Sort)
+        .all(db)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Find departments failed: {}", e)))?
+        .into_iter()
+        .map(department_from_model)
+        .collect()
+}
+
+/// 将部门展开为“自身及全部子部门”的ID集合，供按部门树过滤的查询复用
+pub(crate) async fn expand_department_trees<C: ConnectionTrait>(
+    db: &C,
+    roots: &[DepartmentId],
+) -> AppResult<Vec<DepartmentId>> {
+    if roots.is_empty() {
+        return Ok(vec![]);
+    }
+    let departments = find_active_departments(db).await?;
+    let mut expanded = Vec::new();
+    for root in roots {
+        for id in std::iter::once(root.clone()).chain(Department::descendants_of(&departments, root)) {
+            if !expanded.contains(&id) {
+                expanded.push(id);
+            }
+        }
+    }
+    Ok(expanded)
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmDepartmentRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmDepartmentRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl DepartmentRepository for SeaOrmDepartmentRepository {
+    async fn find_by_id(&self, id: &DepartmentId) -> AppResult<Option<Department>> {
+        department::Entity::find_by_id(id.value())
+            .tenant_scoped()
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find department by id failed: {}", e)))?
+            .map(department_from_model)
+            .transpose()
+    }
+
+    async fn find_by_ids(&self, ids: &[DepartmentId]) -> AppResult<Vec<Department>> {
+        let id_strs: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        department::Entity::find()
+            .tenant_scoped()
+            .filter(department::Column::Id.is_in(id_strs))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find departments by ids failed: {}", e)))?
+            .into_iter()
+            .map(department_from_model)
+            .collect()
+    }
+
+    async fn find_by_name(
+        &self,
+        parent_id: Option<&DepartmentId>,
+        name: &DepartmentName,
+    ) -> AppResult<Option<Department>> {
+        let mut query = department::Entity::find()
+            .tenant_scoped()
+            .filter(department::Column::Name.eq(name.value()))
+            .filter(department::Column::Status.ne(DepartmentStatus::Deleted.value()));
+        query = match parent_id {
+            Some(parent_id) => query.filter(department::Column::ParentId.eq(parent_id.value())),
+            None => query.filter(department::Column::ParentId.is_null()),
+        };
+        query
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find department by name failed: {}", e)))?
+            .map(department_from_model)
+            .transpose()
+    }
+
+    async fn find_descendant_ids(&self, id: &DepartmentId) -> AppResult<Vec<DepartmentId>> {
+        let departments = find_active_departments(&self.db).await?;
+        Ok(Department::descendants_of(&departments, id))
+    }
+
+    async fn count_children(&self, id: &DepartmentId) -> AppResult<u64> {
+        department::Entity::find()
+            .tenant_scoped()
+            .filter(department::Column::ParentId.eq(id.value()))
+            .filter(department::Column::Status.ne(DepartmentStatus::Deleted.value()))
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count child departments failed: {}", e)))
+    }
+
+    async fn search(
+        &self,
+        name: Option<&DepartmentName>,
+        parent_id: Option<&DepartmentId>,
+        status: Option<DepartmentStatus>,
+        show_deleted: Option<bool>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Department>, u64)> {
+        let mut query = department::Entity::find().tenant_scoped();
+        if let Some(name) = name {
+            query = query.filter(department::Column::Name.contains(name.value()));
+        }
+        if let Some(parent_id) = parent_id {
+            query = query.filter(department::Column::ParentId.eq(parent_id.value()));
+        }
+        if let Some(status) = status {
+            query = query.filter(department::Column::Status.eq(status.value()));
+        } else if show_deleted == Some(true) {
+            // 不加 status 过滤，查全部
+        } else {
+            query = query.filter(department::Column::Status.ne(DepartmentStatus::Deleted.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count departments failed: {}", e)))?;
+        let models = query
+            .order_by_asc(department::Column::Sort)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List departments failed: {}", e)))?;
+        let departments = models.into_iter().map(department_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((departments, total))
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Department>> {
+        find_active_departments(&self.db).await
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, QueryOrder, Set};
+
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_domain::repositories::FeatureFlagRepository;
+
+use crate::persistence::entities::feature_flag;
+use tradewinds_error::{AppError, AppResult};
+
+fn flag_from_model(model: feature_flag::Model) -> AppResult<FeatureFlag> {
+    Ok(FeatureFlag {
+        key: model.key,
+        description: model.description,
+        enabled: model.enabled,
+        rollout_percentage: model.rollout_percentage.clamp(0, 100) as u8,
+        allowed_roles: serde_json::from_str(&model.allowed_roles)?,
+        allowed_tenants: serde_json::from_str(&model.allowed_tenants)?,
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn flag_to_active_model(flag: &FeatureFlag) -> AppResult<feature_flag::ActiveModel> {
+    let now: DateTime<Utc> = Utc::now();
+    let created_at = DateTime::from_timestamp(flag.created_at, 0).unwrap_or(now);
+    let updated_at = DateTime::from_timestamp(flag.updated_at, 0).unwrap_or(now);
+    Ok(feature_flag::ActiveModel {
+        key: Set(flag.key.clone()),
+        description: Set(flag.description.clone()),
+        enabled: Set(flag.enabled),
+        rollout_percentage: Set(i32::from(flag.rollout_percentage)),
+        allowed_roles: Set(serde_json::to_string(&flag.allowed_roles)?),
+        allowed_tenants: Set(serde_json::to_string(&flag.allowed_tenants)?),
+        created_at: Set(created_at.into()),
+        updated_at: Set(updated_at.into()),
+    })
+}
+
+/// 功能开关仓储，功能开关为平台级数据，查询不做租户范围限制
+#[derive(Debug, Clone)]
+pub struct SeaOrmFeatureFlagRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmFeatureFlagRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl FeatureFlagRepository for SeaOrmFeatureFlagRepository {
+    async fn create(&self, flag: &FeatureFlag) -> AppResult<()> {
+        flag_to_active_model(flag)?
+            .insert(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Create feature flag failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn update(&self, flag: &FeatureFlag) -> AppResult<()> {
+        flag_to_active_model(flag)?
+            .update(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update feature flag failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        feature_flag::Entity::delete_by_id(key.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Delete feature flag failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn find_by_key(&self, key: &str) -> AppResult<Option<FeatureFlag>> {
+        feature_flag::Entity::find_by_id(key.to_string())
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find feature flag failed: {}", e)))?
+            .map(flag_from_model)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<FeatureFlag>> {
+        let models = feature_flag::Entity::find()
+            .order_by_asc(feature_flag::Column::Key)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List feature flags failed: {}", e)))?;
+        models.into_iter().map(flag_from_model).collect()
+    }
+}
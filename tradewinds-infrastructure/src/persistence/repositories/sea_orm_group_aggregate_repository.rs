@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, TransactionTrait};
+
+use crate::persistence::entities::{user_group, user_group_member, user_group_role};
+use crate::persistence::repositories::sea_orm_group_repository::{group_from_model, group_to_active_model};
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::aggregates::group_aggregate::GroupAggregate;
+use tradewinds_domain::repositories::GroupAggregateRepository;
+use tradewinds_domain::value_objects::{GroupId, RoleId, UserId};
+use tradewinds_error::{AppError, AppResult};
+use uuid::Uuid;
+
+#[derive(Clone)]
+pub struct SeaOrmGroupAggregateRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmGroupAggregateRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    fn member_models(&self, aggregate: &GroupAggregate) -> Vec<user_group_member::ActiveModel> {
+        let now = Utc::now();
+        let tenant_id = current_tenant_id();
+        aggregate
+            .members
+            .iter()
+            .map(|user_id| user_group_member::ActiveModel {
+                id: Set(Uuid::new_v4().to_string()),
+                tenant_id: Set(tenant_id.clone()),
+                group_id: Set(aggregate.group.id.value().to_string()),
+                user_id: Set(user_id.value().to_string()),
+                created_at: Set(now.into()),
+                updated_at: Set(now.into()),
+            })
+            .collect()
+    }
+
+    fn role_models(&self, aggregate: &GroupAggregate) -> Vec<user_group_role::ActiveModel> {
+        let now = Utc::now();
+        let tenant_id = current_tenant_id();
+        aggregate
+            .roles
+            .iter()
+            .map(|role_id| user_group_role::ActiveModel {
+                id: Set(Uuid::new_v4().to_string()),
+                tenant_id: Set(tenant_id.clone()),
+                group_id: Set(aggregate.group.id.value().to_string()),
+                role_id: Set(role_id.value().to_string()),
+                created_at: Set(now.into()),
+                updated_at: Set(now.into()),
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl GroupAggregateRepository for SeaOrmGroupAggregateRepository {
+    async fn create(&self, aggregate: &GroupAggregate) -> AppResult<()> {
+        let group_model = group_to_active_model(&aggregate.group);
+        let member_models = self.member_models(aggregate);
+        let role_models = self.role_models(aggregate);
+        let messages = aggregate.events().to_vec();
+
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    group_model.insert(txn).await?;
+                    if !member_models.is_empty() {
+                        user_group_member::Entity::insert_many(member_models).exec(txn).await?;
+                    }
+                    if !role_models.is_empty() {
+                        user_group_role::Entity::insert_many(role_models).exec(txn).await?;
+                    }
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to create group aggregate: {}", e))
+            })
+    }
+
+    async fn save(&self, aggregate: &GroupAggregate) -> AppResult<()> {
+        let group_model = group_to_active_model(&aggregate.group);
+        let member_models = self.member_models(aggregate);
+        let role_models = self.role_models(aggregate);
+        let group_id = aggregate.group.id.value().to_string();
+        let messages = aggregate.events().to_vec();
+
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    user_group::Entity::update(group_model).tenant_scoped().exec(txn).await?;
+
+                    // 成员与角色：整体替换
+                    user_group_member::Entity::delete_many()
+                        .tenant_scoped()
+                        .filter(user_group_member::Column::GroupId.eq(group_id.clone()))
+                        .exec(txn)
+                        .await?;
+                    if !member_models.is_empty() {
+                        user_group_member::Entity::insert_many(member_models).exec(txn).await?;
+                    }
+
+                    user_group_role::Entity::delete_many()
+                        .tenant_scoped()
+                        .filter(user_group_role::Column::GroupId.eq(group_id.clone()))
+                        .exec(txn)
+                        .await?;
+                    if !role_models.is_empty() {
+                        user_group_role::Entity::insert_many(role_models).exec(txn).await?;
+                    }
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to save group aggregate: {}", e))
+            })
+    }
+
+    async fn find_by_id(&self, id: &GroupId) -> AppResult<Option<GroupAggregate>> {
+        let model = user_group::Entity::find_by_id(id.value())
+            .tenant_scoped()
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find group by id: {}", e)))?;
+        let Some(model) = model else {
+            return Ok(None);
+        };
+
+        let members = user_group_member::Entity::find()
+            .tenant_scoped()
+            .filter(user_group_member::Column::GroupId.eq(id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find group members: {}", e)))?
+            .into_iter()
+            .map(|m| UserId::new(m.user_id))
+            .collect::<AppResult<Vec<_>>>()?;
+        let roles = user_group_role::Entity::find()
+            .tenant_scoped()
+            .filter(user_group_role::Column::GroupId.eq(id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find group roles: {}", e)))?
+            .into_iter()
+            .map(|m| RoleId::new(m.role_id))
+            .collect::<AppResult<Vec<_>>>()?;
+
+        Ok(Some(GroupAggregate::from_existing(group_from_model(model)?, members, roles)))
+    }
+}
@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::group::Group;
+use tradewinds_domain::repositories::GroupRepository;
+use tradewinds_domain::value_objects::UserId;
+use tradewinds_domain::value_objects::group::{GroupDescription, GroupId, GroupName, GroupStatus};
+
+use crate::persistence::entities::{user_group, user_group_member};
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+/// 将用户组表记录转换为领域实体
+pub(crate) fn group_from_model(model: user_group::Model) -> AppResult<Group> {
+    Ok(Group {
+        id: GroupId::new(model.id)?,
+        name: GroupName::new(model.name)?,
+        description: model.description.map(GroupDescription::new).transpose()?,
+        status: GroupStatus::from_i32(model.status)?,
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+pub(crate) fn group_to_active_model(group: &Group) -> user_group::ActiveModel {
+    let now: DateTime<Utc> = Utc::now();
+    let created_at = DateTime::from_timestamp(group.created_at, 0).unwrap_or(now);
+    user_group::ActiveModel {
+        id: Set(group.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        name: Set(group.name.value().to_string()),
+        description: Set(group.description.as_ref().map(|d| d.value().to_string())),
+        status: Set(group.status.value()),
+        created_at: Set(created_at.into()),
+        updated_at: Set(now.into()),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmGroupRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmGroupRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl GroupRepository for SeaOrmGroupRepository {
+    async fn find_by_id(&self, id: &GroupId) -> AppResult<Option<Group>> {
+        user_group::Entity::find_by_id(id.value())
+            .tenant_scoped()
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find group by id failed: {}", e)))?
+            .map(group_from_model)
+            .transpose()
+    }
+
+    async fn find_by_name(&self, name: &GroupName) -> AppResult<Option<Group>> {
+        user_group::Entity::find()
+            .tenant_scoped()
+            .filter(user_group::Column::Name.eq(name.value()))
+            .filter(user_group::Column::Status.ne(GroupStatus::Deleted.value()))
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find group by name failed: {}", e)))?
+            .map(group_from_model)
+            .transpose()
+    }
+
+    async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<Group>> {
+        let group_ids: Vec<String> = user_group_member::Entity::find()
+            .tenant_scoped()
+            .filter(user_group_member::Column::UserId.eq(user_id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find group memberships failed: {}", e)))?
+            .into_iter()
+            .map(|m| m.group_id)
+            .collect();
+        if group_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        user_group::Entity::find()
+            .tenant_scoped()
+            .filter(user_group::Column::Id.is_in(group_ids))
+            .filter(user_group::Column::Status.ne(GroupStatus::Deleted.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find groups by user id failed: {}", e)))?
+            .into_iter()
+            .map(group_from_model)
+            .collect()
+    }
+
+    async fn search(
+        &self,
+        name: Option<&GroupName>,
+        status: Option<GroupStatus>,
+        show_deleted: Option<bool>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Group>, u64)> {
+        let mut query = user_group::Entity::find().tenant_scoped();
+        if let Some(name) = name {
+            query = query.filter(user_group::Column::Name.contains(name.value()));
+        }
+        if let Some(status) = status {
+            query = query.filter(user_group::Column::Status.eq(status.value()));
+        } else if show_deleted == Some(true) {
+            // 不加 status 过滤，查全部
+        } else {
+            query = query.filter(user_group::Column::Status.ne(GroupStatus::Deleted.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count groups failed: {}", e)))?;
+        let models = query
+            .order_by_asc(user_group::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List groups failed: {}", e)))?;
+        let groups = models.into_iter().map(group_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((groups, total))
+    }
+}
@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_domain::repositories::{JobRunFilter, JobRunRepository};
+use tradewinds_domain::value_objects::job::{JobRunId, JobRunStatus, JobTrigger};
+use tradewinds_domain::value_objects::user::UserId;
+
+use crate::persistence::entities::job_run;
+use tradewinds_error::{AppError, AppResult};
+
+fn run_from_model(model: job_run::Model) -> AppResult<JobRun> {
+    Ok(JobRun {
+        id: JobRunId::new(model.id)?,
+        job_name: model.job_name,
+        trigger: JobTrigger::from_i32(model.trigger)?,
+        status: JobRunStatus::from_i32(model.status)?,
+        triggered_by: model.triggered_by.map(UserId::new).transpose()?,
+        instance: model.instance,
+        started_at: model.started_at.timestamp(),
+        finished_at: model.finished_at.map(|t| t.timestamp()),
+        duration_ms: model.duration_ms.map(|ms| ms.max(0) as u64),
+        affected: model.affected.map(|count| count.max(0) as u64),
+        error: model.error,
+    })
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+/// 定时任务执行记录仓储，平台级数据，查询不做租户范围限制
+#[derive(Debug, Clone)]
+pub struct SeaOrmJobRunRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmJobRunRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl JobRunRepository for SeaOrmJobRunRepository {
+    async fn create(&self, run: &JobRun) -> AppResult<()> {
+        job_run::ActiveModel {
+            id: Set(run.id.value().to_string()),
+            job_name: Set(run.job_name.clone()),
+            trigger: Set(run.trigger.value()),
+            status: Set(run.status.value()),
+            triggered_by: Set(run.triggered_by.as_ref().map(|id| id.to_string())),
+            instance: Set(run.instance.clone()),
+            started_at: Set(timestamp(run.started_at).into()),
+            finished_at: Set(run.finished_at.map(|t| timestamp(t).into())),
+            duration_ms: Set(run.duration_ms.map(|ms| ms as i64)),
+            affected: Set(run.affected.map(|count| count as i64)),
+            error: Set(run.error.clone()),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Create job run failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn update(&self, run: &JobRun) -> AppResult<()> {
+        job_run::Entity::update_many()
+            .col_expr(job_run::Column::Status, run.status.value().into())
+            .col_expr(job_run::Column::FinishedAt, sea_orm::sea_query::Expr::value(run.finished_at.map(timestamp)))
+            .col_expr(job_run::Column::DurationMs, run.duration_ms.map(|ms| ms as i64).into())
+            .col_expr(job_run::Column::Affected, run.affected.map(|count| count as i64).into())
+            .col_expr(job_run::Column::Error, run.error.clone().into())
+            .filter(job_run::Column::Id.eq(run.id.value()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update job run failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn search(&self, filter: &JobRunFilter, limit: u64, offset: u64) -> AppResult<(Vec<JobRun>, u64)> {
+        let mut query = job_run::Entity::find();
+        if let Some(job_name) = &filter.job_name {
+            query = query.filter(job_run::Column::JobName.eq(job_name.as_str()));
+        }
+        if let Some(status) = filter.status {
+            query = query.filter(job_run::Column::Status.eq(status.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count job runs failed: {}", e)))?;
+        let models = query
+            .order_by_desc(job_run::Column::StartedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List job runs failed: {}", e)))?;
+        let runs = models.into_iter().map(run_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((runs, total))
+    }
+
+    async fn delete_before(&self, before: i64) -> AppResult<u64> {
+        let result = job_run::Entity::delete_many()
+            .filter(job_run::Column::StartedAt.lt(timestamp(before)))
+            .filter(job_run::Column::Status.ne(JobRunStatus::Running.value()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Delete job runs failed: {}", e)))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use std::str::FromStr;
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::login_log::LoginLog;
+use tradewinds_domain::repositories::{LoginLogFilter, LoginLogRepository};
+use tradewinds_domain::value_objects::login_log::{LoginFailureReason, LoginLogId, LoginMethod};
+use tradewinds_domain::value_objects::user::UserId;
+
+use crate::persistence::entities::login_log;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn log_from_model(model: login_log::Model) -> AppResult<LoginLog> {
+    Ok(LoginLog {
+        id: LoginLogId::new(model.id)?,
+        user_id: model.user_id.map(UserId::new).transpose()?,
+        username: model.username,
+        method: LoginMethod::from_i32(model.method)?,
+        success: model.success,
+        failure_reason: model.failure_reason.as_deref().map(LoginFailureReason::from_str).transpose()?,
+        client_ip: model.client_ip,
+        user_agent: model.user_agent,
+        created_at: model.created_at.timestamp(),
+    })
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+fn log_to_active_model(log: &LoginLog) -> login_log::ActiveModel {
+    login_log::ActiveModel {
+        id: Set(log.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        user_id: Set(log.user_id.as_ref().map(|u| u.value().to_string())),
+        username: Set(log.username.clone()),
+        method: Set(log.method.value()),
+        success: Set(log.success),
+        failure_reason: Set(log.failure_reason.map(|r| r.as_str().to_string())),
+        client_ip: Set(log.client_ip.clone()),
+        user_agent: Set(log.user_agent.clone()),
+        created_at: Set(timestamp(log.created_at).into()),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmLoginLogRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmLoginLogRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl LoginLogRepository for SeaOrmLoginLogRepository {
+    async fn create(&self, log: &LoginLog) -> AppResult<()> {
+        log_to_active_model(log)
+            .insert(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Create login log failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn search(&self, filter: &LoginLogFilter, limit: u64, offset: u64) -> AppResult<(Vec<LoginLog>, u64)> {
+        let mut query = login_log::Entity::find().tenant_scoped();
+        if let Some(user_id) = &filter.user_id {
+            query = query.filter(login_log::Column::UserId.eq(user_id.value()));
+        }
+        if let Some(username) = &filter.username {
+            query = query.filter(login_log::Column::Username.eq(username.as_str()));
+        }
+        if let Some(success) = filter.success {
+            query = query.filter(login_log::Column::Success.eq(success));
+        }
+        if let Some(client_ip) = &filter.client_ip {
+            query = query.filter(login_log::Column::ClientIp.eq(client_ip.as_str()));
+        }
+        if let Some(from) = filter.from {
+            query = query.filter(login_log::Column::CreatedAt.gte(timestamp(from)));
+        }
+        if let Some(to) = filter.to {
+            query = query.filter(login_log::Column::CreatedAt.lt(timestamp(to)));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count login logs failed: {}", e)))?;
+        let models = query
+            .order_by_desc(login_log::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List login logs failed: {}", e)))?;
+        let logs = models.into_iter().map(log_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((logs, total))
+    }
+
+    async fn delete_before(&self, before: i64) -> AppResult<u64> {
+        let result = login_log::Entity::delete_many()
+            .filter(login_log::Column::CreatedAt.lt(timestamp(before)))
+            .tenant_scoped()
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Delete login logs failed: {}", e)))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,239 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::{Alias, Expr, Query};
+use sea_orm::{
+    ActiveValue::NotSet, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_domain::repositories::{OutboxFilter, OutboxRepository};
+use tradewinds_domain::value_objects::outbox::{OutboxMessageId, OutboxStatus};
+
+use crate::persistence::entities::outbox_message;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn message_from_model(model: outbox_message::Model) -> AppResult<OutboxMessage> {
+    Ok(OutboxMessage {
+        id: OutboxMessageId::new(model.id)?,
+        aggregate_type: model.aggregate_type,
+        aggregate_id: model.aggregate_id,
+        event_type: model.event_type,
+        payload: model.payload,
+        status: OutboxStatus::from_i32(model.status)?,
+        attempts: model.attempts.max(0) as u32,
+        next_attempt_at: model.next_attempt_at.timestamp(),
+        last_error: model.last_error,
+        created_at: model.created_at.timestamp(),
+        published_at: model.published_at.map(|t| t.timestamp()),
+    })
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+/// 在给定连接（通常是聚合仓储的事务）内写入发件箱消息
+pub(crate) async fn insert_outbox_messages<C: ConnectionTrait>(
+    conn: &C,
+    messages: &[OutboxMessage],
+) -> Result<(), DbErr> {
+    if messages.is_empty() {
+        return Ok(());
+    }
+    let models = messages.iter().map(|message| outbox_message::ActiveModel {
+        seq: NotSet,
+        id: Set(message.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        aggregate_type: Set(message.aggregate_type.clone()),
+        aggregate_id: Set(message.aggregate_id.clone()),
+        event_type: Set(message.event_type.clone()),
+        payload: Set(message.payload.clone()),
+        status: Set(message.status.value()),
+        attempts: Set(message.attempts as i32),
+        next_attempt_at: Set(timestamp(message.next_attempt_at).into()),
+        last_error: Set(message.last_error.clone()),
+        created_at: Set(timestamp(message.created_at).into()),
+        published_at: Set(message.published_at.map(|t| timestamp(t).into())),
+    });
+    outbox_message::Entity::insert_many(models).exec(conn).await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmOutboxRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmOutboxRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl OutboxRepository for SeaOrmOutboxRepository {
+    async fn find_tenant_ids_with_pending(&self) -> AppResult<Vec<String>> {
+        outbox_message::Entity::find()
+            .select_only()
+            .column(outbox_message::Column::TenantId)
+            .filter(outbox_message::Column::Status.eq(OutboxStatus::Pending.value()))
+            .distinct()
+            .order_by_asc(outbox_message::Column::TenantId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find outbox tenants failed: {}", e)))
+    }
+
+    async fn claim_due(&self, now: i64, lease_secs: i64, limit: u64) -> AppResult<Vec<OutboxMessage>> {
+        // 同一聚合中序号更小的待投递消息，存在时说明当前消息不是该聚合的队首
+        let earlier = Alias::new("earlier");
+        let earlier_pending = Query::select()
+            .expr(Expr::val(1))
+            .from_as(outbox_message::Entity, earlier.clone())
+            .and_where(
+                Expr::col((earlier.clone(), outbox_message::Column::TenantId))
+                    .equals((outbox_message::Entity, outbox_message::Column::TenantId)),
+            )
+            .and_where(
+                Expr::col((earlier.clone(), outbox_message::Column::AggregateType))
+                    .equals((outbox_message::Entity, outbox_message::Column::AggregateType)),
+            )
+            .and_where(
+                Expr::col((earlier.clone(), outbox_message::Column::AggregateId))
+                    .equals((outbox_message::Entity, outbox_message::Column::AggregateId)),
+            )
+            .and_where(Expr::col((earlier.clone(), outbox_message::Column::Status)).eq(OutboxStatus::Pending.value()))
+            .and_where(
+                Expr::col((earlier, outbox_message::Column::Seq))
+                    .lt(Expr::col((outbox_message::Entity, outbox_message::Column::Seq))),
+            )
+            .to_owned();
+        let models = outbox_message::Entity::find()
+            .tenant_scoped()
+            .filter(outbox_message::Column::Status.eq(OutboxStatus::Pending.value()))
+            .filter(outbox_message::Column::NextAttemptAt.lte(timestamp(now)))
+            .filter(Expr::exists(earlier_pending).not())
+            .order_by_asc(outbox_message::Column::Seq)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find due outbox messages failed: {}", e)))?;
+
+        let mut claimed = Vec::with_capacity(models.len());
+        for model in models {
+            let mut message = message_from_model(model)?;
+            // 仅当下次投递时间未被其他实例改写时认领成功
+            let result = outbox_message::Entity::update_many()
+                .col_expr(outbox_message::Column::NextAttemptAt, Expr::value(timestamp(now + lease_secs)))
+                .filter(outbox_message::Column::Id.eq(message.id.value()))
+                .filter(outbox_message::Column::Status.eq(OutboxStatus::Pending.value()))
+                .filter(outbox_message::Column::NextAttemptAt.eq(timestamp(message.next_attempt_at)))
+                .tenant_scoped()
+                .exec(&self.db)
+                .await
+                .map_err(|e| AppError::DatabaseError(format!("Claim outbox message failed: {}", e)))?;
+            if result.rows_affected == 1 {
+                message.lease(now, lease_secs);
+                claimed.push(message);
+            }
+        }
+        Ok(claimed)
+    }
+
+    async fn append(&self, messages: &[OutboxMessage]) -> AppResult<()> {
+        insert_outbox_messages(&self.db, messages)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Insert outbox messages failed: {}", e)))
+    }
+
+    async fn find_by_id(&self, id: &OutboxMessageId) -> AppResult<Option<OutboxMessage>> {
+        outbox_message::Entity::find()
+            .tenant_scoped()
+            .filter(outbox_message::Column::Id.eq(id.value()))
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find outbox message failed: {}", e)))?
+            .map(message_from_model)
+            .transpose()
+    }
+
+    async fn update(&self, message: &OutboxMessage) -> AppResult<()> {
+        outbox_message::Entity::update_many()
+            .col_expr(outbox_message::Column::Status, message.status.value().into())
+            .col_expr(outbox_message::Column::Attempts, (message.attempts as i32).into())
+            .col_expr(outbox_message::Column::NextAttemptAt, Expr::value(timestamp(message.next_attempt_at)))
+            .col_expr(outbox_message::Column::LastError, message.last_error.clone().into())
+            .col_expr(outbox_message::Column::PublishedAt, Expr::value(message.published_at.map(timestamp)))
+            .filter(outbox_message::Column::Id.eq(message.id.value()))
+            .tenant_scoped()
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update outbox message failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn search(&self, filter: &OutboxFilter, limit: u64, offset: u64) -> AppResult<(Vec<OutboxMessage>, u64)> {
+        let mut query = outbox_message::Entity::find().tenant_scoped();
+        if let Some(status) = filter.status {
+            query = query.filter(outbox_message::Column::Status.eq(status.value()));
+        }
+        if let Some(event_type) = &filter.event_type {
+            query = query.filter(outbox_message::Column::EventType.eq(event_type.as_str()));
+        }
+        if let Some(aggregate_id) = &filter.aggregate_id {
+            query = query.filter(outbox_message::Column::AggregateId.eq(aggregate_id.as_str()));
+        }
+        if let Some(stuck_before) = filter.stuck_before {
+            query = query.filter(
+                Condition::any().add(outbox_message::Column::Status.eq(OutboxStatus::Dead.value())).add(
+                    Condition::all()
+                        .add(outbox_message::Column::Status.eq(OutboxStatus::Pending.value()))
+                        .add(outbox_message::Column::CreatedAt.lt(timestamp(stuck_before))),
+                ),
+            );
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count outbox messages failed: {}", e)))?;
+        let models = query
+            .order_by_desc(outbox_message::Column::Seq)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List outbox messages failed: {}", e)))?;
+        let messages = models.into_iter().map(message_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((messages, total))
+    }
+
+    async fn count_by_status(&self) -> AppResult<Vec<(OutboxStatus, u64)>> {
+        let rows: Vec<(i32, i64)> = outbox_message::Entity::find()
+            .tenant_scoped()
+            .select_only()
+            .column(outbox_message::Column::Status)
+            .column_as(outbox_message::Column::Seq.count(), "count")
+            .group_by(outbox_message::Column::Status)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count outbox messages failed: {}", e)))?;
+        rows.into_iter().map(|(status, count)| Ok((OutboxStatus::from_i32(status)?, count.max(0) as u64))).collect()
+    }
+
+    async fn oldest_pending_at(&self) -> AppResult<Option<i64>> {
+        let oldest = outbox_message::Entity::find()
+            .tenant_scoped()
+            .filter(outbox_message::Column::Status.eq(OutboxStatus::Pending.value()))
+            .order_by_asc(outbox_message::Column::Seq)
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find oldest outbox message failed: {}", e)))?;
+        Ok(oldest.map(|m| m.created_at.timestamp()))
+    }
+}
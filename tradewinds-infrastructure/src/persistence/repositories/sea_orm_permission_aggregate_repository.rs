@@ -5,6 +5,9 @@ use sea_orm::{
 };
 
 use crate::persistence::entities::permission;
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::aggregates::permission_aggregate::PermissionAggregate;
 use tradewinds_domain::entities::permission::Permission;
 use tradewinds_domain::repositories::permission_aggregate_repository::PermissionAggregateRepository;
@@ -36,6 +39,7 @@ impl SeaOrmPermissionAggregateRepository {
             icon: model.icon.map(PermissionIcon::new).transpose()?,
             sort: PermissionSort::new(model.sort)?,
             status: PermissionStatus::from_i32(model.status)?,
+            built_in: model.built_in,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -45,6 +49,7 @@ impl SeaOrmPermissionAggregateRepository {
         let now: DateTime<Utc> = Utc::now();
         permission::ActiveModel {
             id: Set(permission.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             name: Set(permission.name.value().to_string()),
             code: Set(permission.code.as_ref().map(|c| c.value().to_string())),
             type_: Set(permission.type_.value()),
@@ -54,6 +59,7 @@ impl SeaOrmPermissionAggregateRepository {
             icon: Set(permission.icon.as_ref().map(|i| i.value().to_string())),
             sort: Set(permission.sort.value()),
             status: Set(permission.status.value()),
+            built_in: Set(permission.built_in),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
@@ -64,6 +70,7 @@ impl SeaOrmPermissionAggregateRepository {
 impl PermissionAggregateRepository for SeaOrmPermissionAggregateRepository {
     async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<PermissionAggregate>> {
         let model = permission::Entity::find_by_id(id.value())
+            .tenant_scoped()
             .one(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Failed to find permission by id: {}", e)))?;
@@ -73,56 +80,108 @@ impl PermissionAggregateRepository for SeaOrmPermissionAggregateRepository {
         };
 
         let entity = self.from_model(model)?;
-        Ok(Some(PermissionAggregate { permission: entity }))
+        Ok(Some(PermissionAggregate::from_existing(entity)))
     }
 
     async fn create(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
         let model = self.to_active_model(&aggregate.permission);
         let permission_id = aggregate.permission.id.value().to_string();
         let code = aggregate.permission.code.as_ref().map(|c| c.value().to_string());
-        match model.insert(&self.db).await {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                // 插入报错时查验是否已存在
-                let exists = permission::Entity::find()
-                    .filter(permission::Column::Id.eq(permission_id.clone()))
-                    .one(&self.db)
-                    .await
-                    .map_err(|e| AppError::DatabaseError(format!("Failed to verify permission insert: {}", e)))?
-                    .is_some()
-                    || (code.is_some()
-                        && permission::Entity::find()
-                            .filter(permission::Column::Code.eq(code.clone().unwrap()))
-                            .one(&self.db)
+        let messages = aggregate.events().to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    if let Err(e) = model.insert(txn).await {
+                        // 插入报错时查验是否已存在
+                        let exists = permission::Entity::find()
+                            .tenant_scoped()
+                            .filter(permission::Column::Id.eq(permission_id.clone()))
+                            .one(txn)
                             .await
-                            .map_err(|e| {
-                                AppError::DatabaseError(format!("Failed to verify permission code insert: {}", e))
-                            })?
-                            .is_some());
-                if exists {
+                            .map_err(|e| AppError::DatabaseError(format!("Failed to verify permission insert: {}", e)))?
+                            .is_some()
+                            || (code.is_some()
+                                && permission::Entity::find()
+                                    .tenant_scoped()
+                                    .filter(permission::Column::Code.eq(code.clone().unwrap()))
+                                    .one(txn)
+                                    .await
+                                    .map_err(|e| {
+                                        AppError::DatabaseError(format!(
+                                            "Failed to verify permission code insert: {}",
+                                            e
+                                        ))
+                                    })?
+                                    .is_some());
+                        if !exists {
+                            return Err(AppError::DatabaseError(format!("Failed to create permission: {}", e)));
+                        }
+                    }
+
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to insert outbox messages: {}", e)))?;
+
                     Ok(())
-                } else {
-                    Err(AppError::DatabaseError(format!("Failed to create permission: {}", e)))
-                }
-            }
-        }
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to create permission aggregate: {}", e))
+            })
     }
 
     async fn save(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
         let model = self.to_active_model(&aggregate.permission);
-        permission::Entity::update(model)
-            .exec(&self.db)
+        let messages = aggregate.events().to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    permission::Entity::update(model)
+                        .tenant_scoped()
+                        .exec(txn)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to save permission: {}", e)))?;
+
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to insert outbox messages: {}", e)))?;
+
+                    Ok(())
+                })
+            })
             .await
-            .map_err(|e| AppError::DatabaseError(format!("Failed to save permission: {}", e)))?;
-        Ok(())
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to save permission aggregate: {}", e))
+            })
     }
 
-    async fn delete_by_id(&self, id: &PermissionId) -> AppResult<()> {
-        use sea_orm::EntityTrait;
-        permission::Entity::delete_by_id(id.value())
-            .exec(&self.db)
+    async fn delete(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        let permission_id = aggregate.permission.id.value().to_string();
+        let messages = aggregate.events().to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    permission::Entity::delete_many()
+                        .filter(permission::Column::Id.eq(&permission_id))
+                        .tenant_scoped()
+                        .exec(txn)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Delete permission by id failed: {}", e)))?;
+
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to insert outbox messages: {}", e)))?;
+
+                    Ok(())
+                })
+            })
             .await
-            .map_err(|e| AppError::DatabaseError(format!("Delete permission by id failed: {}", e)))?;
-        Ok(())
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to delete permission aggregate: {}", e))
+            })
     }
 }
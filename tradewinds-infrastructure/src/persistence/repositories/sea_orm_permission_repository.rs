@@ -6,15 +6,17 @@ use sea_orm::{
     QuerySelect, RelationTrait, Set,
 };
 
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::entities::permission::Permission;
 use tradewinds_domain::repositories::PermissionRepository;
-use tradewinds_domain::value_objects::UserId;
 use tradewinds_domain::value_objects::permission::{
     PermissionCode, PermissionComponent, PermissionIcon, PermissionId, PermissionName, PermissionPath, PermissionSort,
     PermissionStatus, PermissionType,
 };
+use tradewinds_domain::value_objects::{PermissionEffect, UserId};
 
 use crate::persistence::entities::{permission, role, role_permission, user_role};
+use crate::persistence::tenant_scope::TenantScoped;
 use tradewinds_error::{AppError, AppResult};
 
 #[derive(Debug, Clone)]
@@ -39,6 +41,7 @@ impl SeaOrmPermissionRepository {
             icon: model.icon.map(PermissionIcon::new).transpose()?,
             sort: PermissionSort::new(model.sort)?,
             status: PermissionStatus::from_i32(model.status)?,
+            built_in: model.built_in,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -48,6 +51,7 @@ impl SeaOrmPermissionRepository {
         let now: DateTime<Utc> = Utc::now();
         permission::ActiveModel {
             id: Set(permission.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             name: Set(permission.name.value().to_string()),
             code: Set(permission.code.as_ref().map(|c| c.value().to_string())),
             type_: Set(permission.type_.value()),
@@ -57,6 +61,7 @@ impl SeaOrmPermissionRepository {
             icon: Set(permission.icon.as_ref().map(|i| i.value().to_string())),
             sort: Set(permission.sort.value()),
             status: Set(permission.status.value()),
+            built_in: Set(permission.built_in),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
@@ -67,6 +72,7 @@ impl SeaOrmPermissionRepository {
 impl PermissionRepository for SeaOrmPermissionRepository {
     async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<Permission>> {
         permission::Entity::find_by_id(id.to_string())
+            .tenant_scoped()
             .one(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Find permission by id failed: {}", e)))?
@@ -76,6 +82,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
 
     async fn find_by_name(&self, name: &PermissionName) -> AppResult<Option<Permission>> {
         permission::Entity::find()
+            .tenant_scoped()
             .filter(permission::Column::Name.eq(name.value()))
             .one(&self.db)
             .await
@@ -86,6 +93,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
 
     async fn find_by_code(&self, code: &PermissionCode) -> AppResult<Option<Permission>> {
         permission::Entity::find()
+            .tenant_scoped()
             .filter(permission::Column::Code.eq(code.value()))
             .one(&self.db)
             .await
@@ -97,6 +105,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
     async fn find_by_ids(&self, ids: &[PermissionId]) -> AppResult<Vec<Permission>> {
         let id_strs: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
         permission::Entity::find()
+            .tenant_scoped()
             .filter(permission::Column::Id.is_in(id_strs))
             .all(&self.db)
             .await
@@ -111,6 +120,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
 
         // 1. 查找用户的角色ID
         let role_ids: Vec<String> = user_role::Entity::find()
+            .tenant_scoped()
             .filter(user_role::Column::UserId.eq(user_id.to_string()))
             .all(&self.db)
             .await
@@ -125,7 +135,9 @@ impl PermissionRepository for SeaOrmPermissionRepository {
 
         // 2. 查找角色的权限ID
         let permission_ids: Vec<String> = role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.is_in(role_ids))
+            .filter(role_permission::Column::Effect.eq(PermissionEffect::Allow.to_i32()))
             .all(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Find role permissions failed: {}", e)))?
@@ -139,6 +151,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
 
         // 3. 查找权限详情
         let permissions = permission::Entity::find()
+            .tenant_scoped()
             .filter(permission::Column::Id.is_in(permission_ids))
             .all(&self.db)
             .await
@@ -148,7 +161,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
     }
 
     async fn find_all(&self) -> AppResult<Vec<Permission>> {
-        let models = permission::Entity::find().all(&self.db).await?;
+        let models = permission::Entity::find().tenant_scoped().all(&self.db).await?;
         models.into_iter().map(|m| self.from_model(m)).collect()
     }
 
@@ -162,7 +175,7 @@ impl PermissionRepository for SeaOrmPermissionRepository {
         limit: u64,
         offset: u64,
     ) -> AppResult<(Vec<Permission>, u64)> {
-        let mut query = permission::Entity::find();
+        let mut query = permission::Entity::find().tenant_scoped();
         if let Some(name) = name {
             query = query.filter(permission::Column::Name.contains(name.value()));
         }
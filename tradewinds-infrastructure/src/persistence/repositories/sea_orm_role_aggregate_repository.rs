@@ -4,12 +4,15 @@ use sea_orm::{
     ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, TransactionTrait,
 };
 
-use crate::persistence::entities::{role, role_permission};
+use crate::persistence::entities::{role, role_department, role_permission};
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::aggregates::role_aggregate::RoleAggregate;
 use tradewinds_domain::entities::{role::Role, role_permission::RolePermission};
 use tradewinds_domain::repositories::role_aggregate_repository::RoleAggregateRepository;
 use tradewinds_domain::value_objects::{
-    RoleDescription, RoleName, RoleStatus, permission::permission_id::PermissionId, role::RoleCode,
+    DataScopeType, DepartmentId, PermissionEffect, RoleDescription, RoleName, RoleStatus, permission::permission_id::PermissionId, role::RoleCode,
     role::role_id::RoleId, role_permission::role_permission_id::RolePermissionId,
 };
 use tradewinds_error::{AppError, AppResult};
@@ -31,6 +34,8 @@ impl SeaOrmRoleAggregateRepository {
             name: RoleName::new(model.name)?,
             description: model.description.map(RoleDescription::new).transpose()?,
             status: RoleStatus::from_i32(model.status)?,
+            data_scope: DataScopeType::from_i32(model.data_scope)?,
+            built_in: model.built_in,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -40,10 +45,13 @@ impl SeaOrmRoleAggregateRepository {
         let now = chrono::Utc::now();
         role::ActiveModel {
             id: Set(role.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             code: Set(role.code.value().to_string()),
             name: Set(role.name.value().to_string()),
             description: Set(role.description.as_ref().map(|d| d.value().to_string())),
             status: Set(role.status.value()),
+            data_scope: Set(role.data_scope.value()),
+            built_in: Set(role.built_in),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
@@ -54,6 +62,7 @@ impl SeaOrmRoleAggregateRepository {
             id: RolePermissionId::new(model.id)?,
             role_id: RoleId::new(model.role_id)?,
             permission_id: PermissionId::new(model.permission_id)?,
+            effect: PermissionEffect::from_i32(model.effect)?,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -63,18 +72,57 @@ impl SeaOrmRoleAggregateRepository {
         let now: DateTime<Utc> = Utc::now();
         role_permission::ActiveModel {
             id: Set(role_permission.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             role_id: Set(role_permission.role_id.value().to_string()),
             permission_id: Set(role_permission.permission_id.value().to_string()),
+            effect: Set(role_permission.effect.to_i32()),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
     }
+
+    /// 将聚合中的允许/拒绝权限展开为关联记录
+    fn permission_models(&self, aggregate: &RoleAggregate) -> Vec<role_permission::ActiveModel> {
+        let tenant_id = current_tenant_id();
+        let allowed = aggregate.permissions.iter().map(|pid| (pid, PermissionEffect::Allow));
+        let denied = aggregate.denied_permissions.iter().map(|pid| (pid, PermissionEffect::Deny));
+        allowed
+            .chain(denied)
+            .map(|(pid, effect)| role_permission::ActiveModel {
+                id: Set(uuid::Uuid::new_v4().to_string()),
+                tenant_id: Set(tenant_id.clone()),
+                role_id: Set(aggregate.role.id.value().to_string()),
+                permission_id: Set(pid.to_string()),
+                effect: Set(effect.to_i32()),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// 将聚合中自定义数据范围的部门展开为关联记录
+    fn department_models(&self, aggregate: &RoleAggregate) -> Vec<role_department::ActiveModel> {
+        let now: DateTime<Utc> = Utc::now();
+        let tenant_id = current_tenant_id();
+        aggregate
+            .data_scope_departments
+            .iter()
+            .map(|department_id| role_department::ActiveModel {
+                id: Set(uuid::Uuid::new_v4().to_string()),
+                tenant_id: Set(tenant_id.clone()),
+                role_id: Set(aggregate.role.id.value().to_string()),
+                department_id: Set(department_id.value().to_string()),
+                created_at: Set(now.into()),
+                updated_at: Set(now.into()),
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
 impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
     async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<RoleAggregate>> {
         let role_model = role::Entity::find_by_id(id.value())
+            .tenant_scoped()
             .one(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Failed to find role by id: {}", e)))?;
@@ -83,48 +131,64 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
             return Ok(None);
         };
 
-        let permission_ids = role_permission::Entity::find()
+        let role_permissions = role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.eq(id.value()))
             .all(&self.db)
             .await
-            .map_err(|e| AppError::DatabaseError(format!("Failed to find permissions for role: {}", e)))?
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find permissions for role: {}", e)))?;
+
+        let mut permission_ids = Vec::new();
+        let mut denied_permission_ids = Vec::new();
+        for rp in role_permissions {
+            let permission_id = PermissionId::new(rp.permission_id)?;
+            if PermissionEffect::from_i32(rp.effect)?.is_deny() {
+                denied_permission_ids.push(permission_id);
+            } else {
+                permission_ids.push(permission_id);
+            }
+        }
+
+        let data_scope_departments = role_department::Entity::find()
+            .tenant_scoped()
+            .filter(role_department::Column::RoleId.eq(id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find data scope departments for role: {}", e)))?
             .into_iter()
-            .map(|rp| PermissionId::new(rp.permission_id))
+            .map(|rd| DepartmentId::new(rd.department_id))
             .collect::<AppResult<Vec<_>>>()?;
 
         let role_entity = self.role_from_model(role_model)?;
 
-        let aggregate = RoleAggregate::from_existing(role_entity, permission_ids);
+        let aggregate =
+            RoleAggregate::from_existing(role_entity, permission_ids, denied_permission_ids, data_scope_departments);
 
         Ok(Some(aggregate))
     }
 
     async fn create(&self, aggregate: &RoleAggregate) -> AppResult<()> {
         let role_model = self.role_to_active_model(&aggregate.role);
-        let permission_models: Vec<role_permission::ActiveModel> = aggregate
-            .permissions
-            .iter()
-            .map(|pid| role_permission::ActiveModel {
-                id: Set(uuid::Uuid::new_v4().to_string()), // 修复：为每条记录分配唯一id
-                role_id: Set(aggregate.role.id.value().to_string()),
-                permission_id: Set(pid.to_string()),
-                ..Default::default()
-            })
-            .collect();
+        let permission_models = self.permission_models(aggregate);
+        let department_models = self.department_models(aggregate);
         let role_id = aggregate.role.id.value().to_string();
+        let messages = aggregate.events().to_vec();
         let role_model_cloned = role_model.clone();
         let permission_models_cloned = permission_models.clone();
         self.db
             .transaction(move |txn| {
                 let role_model = role_model_cloned.clone();
                 let permission_models = permission_models_cloned.clone();
+                let department_models = department_models.clone();
                 let role_id = role_id.clone();
+                let messages = messages.clone();
                 Box::pin(async move {
                     // 角色插入幂等兼容
                     match role_model.insert(txn).await {
                         Ok(_) => {}
                         Err(e) => {
                             let exists = role::Entity::find_by_id(role_id.clone())
+                                .tenant_scoped()
                                 .one(txn)
                                 .await
                                 .map_err(|e| AppError::DatabaseError(format!("Failed to verify role insert: {}", e)))?
@@ -145,6 +209,7 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
                             Err(e) => {
                                 // 查验主键是否已存在
                                 let exists = role_permission::Entity::find()
+                                    .tenant_scoped()
                                     .filter(role_permission::Column::RoleId.eq(role_id_val.clone()))
                                     .filter(role_permission::Column::PermissionId.eq(permission_id_val.clone()))
                                     .one(txn)
@@ -165,6 +230,16 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
                             }
                         }
                     }
+                    // 自定义数据范围的部门
+                    if !department_models.is_empty() {
+                        role_department::Entity::insert_many(department_models).exec(txn).await.map_err(|e| {
+                            AppError::DatabaseError(format!("Failed to insert role_departments: {}", e))
+                        })?;
+                    }
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to insert outbox messages: {}", e)))?;
                     Ok(())
                 })
             })
@@ -177,25 +252,18 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
     async fn save(&self, aggregate: &RoleAggregate) -> AppResult<()> {
         let role_model = self.role_to_active_model(&aggregate.role);
 
-        let permission_models: Vec<role_permission::ActiveModel> = aggregate
-            .permissions
-            .iter()
-            .map(|pid| role_permission::ActiveModel {
-                id: Set(uuid::Uuid::new_v4().to_string()),
-                role_id: Set(aggregate.role.id.value().to_string()),
-                permission_id: Set(pid.to_string()),
-                ..Default::default()
-            })
-            .collect();
+        let permission_models = self.permission_models(aggregate);
+        let department_models = self.department_models(aggregate);
 
         let role_id = aggregate.role.id.value().to_string();
         let permission_models = permission_models.clone();
+        let messages = aggregate.events().to_vec();
         self.db
             .transaction(|txn| {
                 Box::pin(async move {
                     // 幂等兼容：只要update不报错或RecordNotUpdated就认为成功
                     use sea_orm::DbErr;
-                    let update_result = role::Entity::update(role_model).exec(txn).await;
+                    let update_result = role::Entity::update(role_model).tenant_scoped().exec(txn).await;
                     match update_result {
                         Ok(_) => {}
                         Err(DbErr::RecordNotUpdated) => {
@@ -205,12 +273,14 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
                     }
 
                     // 验证角色是否确实存在，避免外键约束失败
-                    let role_exists = role::Entity::find_by_id(role_id.clone()).one(txn).await?.is_some();
+                    let role_exists =
+                        role::Entity::find_by_id(role_id.clone()).tenant_scoped().one(txn).await?.is_some();
                     if !role_exists {
                         return Err(AppError::DatabaseError(format!("Role with id {} does not exist", role_id)));
                     }
 
                     role_permission::Entity::delete_many()
+                        .tenant_scoped()
                         .filter(role_permission::Column::RoleId.eq(role_id.clone()))
                         .exec(txn)
                         .await?;
@@ -235,6 +305,21 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
                         }
                     }
 
+                    // 自定义数据范围的部门：整体替换
+                    role_department::Entity::delete_many()
+                        .tenant_scoped()
+                        .filter(role_department::Column::RoleId.eq(role_id.clone()))
+                        .exec(txn)
+                        .await?;
+                    if !department_models.is_empty() {
+                        role_department::Entity::insert_many(department_models).exec(txn).await?;
+                    }
+
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to insert outbox messages: {}", e)))?;
+
                     Ok(())
                 })
             })
@@ -244,14 +329,31 @@ impl RoleAggregateRepository for SeaOrmRoleAggregateRepository {
             })
     }
 
-    async fn delete_by_id(&self, id: &RoleId) -> AppResult<()> {
-        use crate::persistence::entities::role;
-        use sea_orm::EntityTrait;
-        role::Entity::delete_by_id(id.value())
-            .exec(&self.db)
+    async fn delete(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        let role_id = aggregate.role.id.value().to_string();
+        let messages = aggregate.events().to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    role::Entity::delete_many()
+                        .filter(role::Column::Id.eq(&role_id))
+                        .tenant_scoped()
+                        .exec(txn)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Delete role by id failed: {}", e)))?;
+
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| AppError::DatabaseError(format!("Failed to insert outbox messages: {}", e)))?;
+
+                    Ok(())
+                })
+            })
             .await
-            .map_err(|e| AppError::DatabaseError(format!("Delete role by id failed: {}", e)))?;
-        Ok(())
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to delete role aggregate: {}", e))
+            })
     }
 }
 
@@ -270,6 +372,7 @@ where
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
         }
         let result = role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.eq(role_id))
             .filter(role_permission::Column::PermissionId.eq(permission_id))
             .one(conn)
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, TransactionTrait};
+use uuid::Uuid;
+
+use tradewinds_domain::repositories::RoleApproverRepository;
+use tradewinds_domain::value_objects::{role::RoleId, user::UserId};
+
+use crate::persistence::entities::role_approver;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmRoleApproverRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmRoleApproverRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl RoleApproverRepository for SeaOrmRoleApproverRepository {
+    async fn find_approver_ids(&self, role_id: &RoleId) -> AppResult<Vec<UserId>> {
+        role_approver::Entity::find()
+            .tenant_scoped()
+            .filter(role_approver::Column::RoleId.eq(role_id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find role approvers failed: {}", e)))?
+            .into_iter()
+            .map(|m| UserId::new(m.user_id))
+            .collect()
+    }
+
+    async fn find_role_ids_by_approver(&self, user_id: &UserId) -> AppResult<Vec<RoleId>> {
+        role_approver::Entity::find()
+            .tenant_scoped()
+            .filter(role_approver::Column::UserId.eq(user_id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find approved roles failed: {}", e)))?
+            .into_iter()
+            .map(|m| RoleId::new(m.role_id))
+            .collect()
+    }
+
+    async fn replace(&self, role_id: &RoleId, approver_ids: &[UserId]) -> AppResult<()> {
+        let role_id = role_id.value().to_string();
+        let now = Utc::now();
+        let tenant_id = current_tenant_id();
+        let models: Vec<role_approver::ActiveModel> = approver_ids
+            .iter()
+            .map(|user_id| role_approver::ActiveModel {
+                id: Set(Uuid::new_v4().to_string()),
+                tenant_id: Set(tenant_id.clone()),
+                role_id: Set(role_id.clone()),
+                user_id: Set(user_id.value().to_string()),
+                created_at: Set(now.into()),
+            })
+            .collect();
+
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    role_approver::Entity::delete_many()
+                        .tenant_scoped()
+                        .filter(role_approver::Column::RoleId.eq(role_id))
+                        .exec(txn)
+                        .await?;
+                    if !models.is_empty() {
+                        role_approver::Entity::insert_many(models).exec(txn).await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Failed to replace role approvers: {}", e))
+            })
+    }
+}
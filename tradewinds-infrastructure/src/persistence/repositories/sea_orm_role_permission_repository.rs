@@ -6,9 +6,11 @@ use sea_orm::{
 };
 
 use crate::persistence::entities::role_permission;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::entities::RolePermission;
 use tradewinds_domain::repositories::RolePermissionRepository;
-use tradewinds_domain::value_objects::{PermissionId, RoleId, RolePermissionId};
+use tradewinds_domain::value_objects::{PermissionEffect, PermissionId, RoleId, RolePermissionId};
 use tradewinds_error::{AppError, AppResult};
 
 #[derive(Clone)]
@@ -26,6 +28,7 @@ impl SeaOrmRolePermissionRepository {
             id: RolePermissionId::new(model.id)?,
             role_id: RoleId::new(model.role_id)?,
             permission_id: PermissionId::new(model.permission_id)?,
+            effect: PermissionEffect::from_i32(model.effect)?,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -35,8 +38,10 @@ impl SeaOrmRolePermissionRepository {
         let now: DateTime<Utc> = Utc::now();
         role_permission::ActiveModel {
             id: Set(role_permission.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             role_id: Set(role_permission.role_id.value().to_string()),
             permission_id: Set(role_permission.permission_id.value().to_string()),
+            effect: Set(role_permission.effect.to_i32()),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
@@ -58,6 +63,7 @@ impl RolePermissionRepository for SeaOrmRolePermissionRepository {
 
     async fn delete(&self, role_id: &RoleId, permission_id: &PermissionId) -> AppResult<()> {
         role_permission::Entity::delete_many()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.eq(role_id.value()))
             .filter(role_permission::Column::PermissionId.eq(permission_id.value()))
             .exec(&self.db)
@@ -68,6 +74,7 @@ impl RolePermissionRepository for SeaOrmRolePermissionRepository {
 
     async fn find_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<RolePermission>> {
         role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.eq(role_id.value()))
             .all(&self.db)
             .await
@@ -79,6 +86,7 @@ impl RolePermissionRepository for SeaOrmRolePermissionRepository {
 
     async fn find_by_permission_id(&self, permission_id: &PermissionId) -> AppResult<Vec<RolePermission>> {
         role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::PermissionId.eq(permission_id.value()))
             .all(&self.db)
             .await
@@ -90,6 +98,7 @@ impl RolePermissionRepository for SeaOrmRolePermissionRepository {
 
     async fn exists(&self, role_id: &RoleId, permission_id: &PermissionId) -> AppResult<bool> {
         let count = role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.eq(role_id.value()))
             .filter(role_permission::Column::PermissionId.eq(permission_id.value()))
             .count(&self.db)
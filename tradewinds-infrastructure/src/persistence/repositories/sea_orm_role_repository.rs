@@ -5,16 +5,19 @@ use sea_orm::{
     RelationTrait, Set,
 };
 
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::entities::{permission::Permission, role::Role};
+use tradewinds_domain::policies::PermissionGrant;
 use tradewinds_domain::repositories::RoleRepository;
 use tradewinds_domain::value_objects::permission::{
     PermissionCode, PermissionComponent, PermissionIcon, PermissionId, PermissionName, PermissionPath, PermissionSort,
     PermissionStatus, PermissionType,
 };
 use tradewinds_domain::value_objects::role::{RoleCode, RoleId, RoleName};
-use tradewinds_domain::value_objects::{RoleDescription, RoleStatus};
+use tradewinds_domain::value_objects::{DataScopeType, DepartmentId, PermissionEffect, RoleDescription, RoleStatus};
 
-use crate::persistence::entities::{role, role_permission};
+use crate::persistence::entities::{role, role_department, role_permission};
+use crate::persistence::tenant_scope::TenantScoped;
 use tradewinds_error::{AppError, AppResult};
 
 #[derive(Debug, Clone)]
@@ -34,6 +37,8 @@ impl SeaOrmRoleRepository {
             name: RoleName::new(model.name)?,
             description: model.description.map(RoleDescription::new).transpose()?,
             status: RoleStatus::from_i32(model.status)?,
+            data_scope: DataScopeType::from_i32(model.data_scope)?,
+            built_in: model.built_in,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -43,10 +48,13 @@ impl SeaOrmRoleRepository {
         let now = chrono::Utc::now();
         role::ActiveModel {
             id: Set(role.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             code: Set(role.code.value().to_string()),
             name: Set(role.name.value().to_string()),
             description: Set(role.description.as_ref().map(|d| d.value().to_string())),
             status: Set(role.status.value()),
+            data_scope: Set(role.data_scope.value()),
+            built_in: Set(role.built_in),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
@@ -57,6 +65,7 @@ impl SeaOrmRoleRepository {
 impl RoleRepository for SeaOrmRoleRepository {
     async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<Role>> {
         role::Entity::find_by_id(id.value())
+            .tenant_scoped()
             .one(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Find role by id failed: {}", e)))?
@@ -66,6 +75,7 @@ impl RoleRepository for SeaOrmRoleRepository {
 
     async fn find_by_name(&self, name: &RoleName) -> AppResult<Option<Role>> {
         role::Entity::find()
+            .tenant_scoped()
             .filter(role::Column::Name.eq(name.value()))
             .one(&self.db)
             .await
@@ -74,9 +84,21 @@ impl RoleRepository for SeaOrmRoleRepository {
             .transpose()
     }
 
+    async fn find_by_code(&self, code: &RoleCode) -> AppResult<Option<Role>> {
+        role::Entity::find()
+            .tenant_scoped()
+            .filter(role::Column::Code.eq(code.value()))
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find role by code failed: {}", e)))?
+            .map(|model| self.from_model(model))
+            .transpose()
+    }
+
     async fn find_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Role>> {
         let id_strs: Vec<String> = ids.iter().map(|id| id.value().to_string()).collect();
         role::Entity::find()
+            .tenant_scoped()
             .filter(role::Column::Id.is_in(id_strs))
             .all(&self.db)
             .await
@@ -88,6 +110,7 @@ impl RoleRepository for SeaOrmRoleRepository {
 
     async fn exists_by_id(&self, id: &RoleId) -> AppResult<bool> {
         let count = role::Entity::find_by_id(id.value())
+            .tenant_scoped()
             .count(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Check if role exists by id failed: {}", e)))?;
@@ -104,7 +127,9 @@ impl RoleRepository for SeaOrmRoleRepository {
         use crate::persistence::entities::permission;
         use crate::persistence::entities::role_permission;
         let perms = role_permission::Entity::find()
+            .tenant_scoped()
             .filter(role_permission::Column::RoleId.eq(id.value()))
+            .filter(role_permission::Column::Effect.eq(PermissionEffect::Allow.to_i32()))
             .find_also_related(permission::Entity)
             .all(&self.db)
             .await
@@ -123,6 +148,7 @@ impl RoleRepository for SeaOrmRoleRepository {
                     icon: p_model.icon.map(PermissionIcon::new).transpose()?,
                     sort: PermissionSort::new(p_model.sort)?,
                     status: PermissionStatus::from_i32(p_model.status)?,
+                    built_in: p_model.built_in,
                     created_at: p_model.created_at.timestamp(),
                     updated_at: p_model.updated_at.timestamp(),
                 })
@@ -145,6 +171,56 @@ impl RoleRepository for SeaOrmRoleRepository {
         Ok(all_perms)
     }
 
+    async fn find_permission_grants(&self, ids: &[RoleId]) -> AppResult<Vec<PermissionGrant>> {
+        use crate::persistence::entities::permission;
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let id_strs: Vec<String> = ids.iter().map(|id| id.value().to_string()).collect();
+        role_permission::Entity::find()
+            .tenant_scoped()
+            .filter(role_permission::Column::RoleId.is_in(id_strs))
+            .find_also_related(permission::Entity)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find permission grants for roles failed: {}", e)))?
+            .into_iter()
+            // 返回全部授权并附上权限状态，由权限策略剔除禁用权限上的授权；权限记录缺失时视为已删除
+            .map(|(rp, perm)| {
+                let status = match &perm {
+                    Some(p) => PermissionStatus::from_i32(p.status)?,
+                    None => PermissionStatus::Deleted,
+                };
+                Ok(PermissionGrant::new(
+                    RoleId::new(rp.role_id)?,
+                    PermissionId::new(rp.permission_id)?,
+                    perm.and_then(|p| p.code).map(PermissionCode::new).transpose()?,
+                    PermissionEffect::from_i32(rp.effect)?,
+                    status,
+                ))
+            })
+            .collect()
+    }
+
+    async fn find_data_scope_departments(&self, ids: &[RoleId]) -> AppResult<Vec<DepartmentId>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let id_strs: Vec<String> = ids.iter().map(|id| id.value().to_string()).collect();
+        let mut departments = role_department::Entity::find()
+            .tenant_scoped()
+            .filter(role_department::Column::RoleId.is_in(id_strs))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find data scope departments for roles failed: {}", e)))?
+            .into_iter()
+            .map(|rd| DepartmentId::new(rd.department_id))
+            .collect::<AppResult<Vec<_>>>()?;
+        departments.sort();
+        departments.dedup();
+        Ok(departments)
+    }
+
     async fn search(
         &self,
         name: Option<&RoleName>,
@@ -154,7 +230,7 @@ impl RoleRepository for SeaOrmRoleRepository {
         limit: u64,
         offset: u64,
     ) -> AppResult<(Vec<Role>, u64)> {
-        let mut query = role::Entity::find();
+        let mut query = role::Entity::find().tenant_scoped();
         if let Some(name) = name {
             query = query.filter(role::Column::Name.contains(name.value()));
         }
@@ -0,0 +1,107 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set};
+
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_domain::entities::scheduled_job::ScheduledJob;
+use tradewinds_domain::repositories::ScheduledJobRepository;
+use tradewinds_domain::value_objects::job::JobRunStatus;
+
+use crate::persistence::entities::scheduled_job;
+use tradewinds_error::{AppError, AppResult};
+
+fn job_from_model(model: scheduled_job::Model) -> AppResult<ScheduledJob> {
+    Ok(ScheduledJob {
+        name: model.name,
+        description: model.description,
+        cron: model.cron,
+        paused: model.paused,
+        next_run_at: model.next_run_at.map(|t| t.timestamp()),
+        last_run_at: model.last_run_at.map(|t| t.timestamp()),
+        last_status: model.last_status.map(JobRunStatus::from_i32).transpose()?,
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn job_to_active_model(job: &ScheduledJob) -> scheduled_job::ActiveModel {
+    scheduled_job::ActiveModel {
+        name: Set(job.name.clone()),
+        description: Set(job.description.clone()),
+        cron: Set(job.cron.clone()),
+        paused: Set(job.paused),
+        next_run_at: Set(job.next_run_at.map(|t| timestamp(t).into())),
+        last_run_at: Set(job.last_run_at.map(|t| timestamp(t).into())),
+        last_status: Set(job.last_status.map(|status| status.value())),
+        updated_at: Set(timestamp(job.updated_at).into()),
+    }
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+/// 定时任务仓储，定时任务为平台级数据，查询不做租户范围限制
+#[derive(Debug, Clone)]
+pub struct SeaOrmScheduledJobRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmScheduledJobRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl ScheduledJobRepository for SeaOrmScheduledJobRepository {
+    async fn save(&self, job: &ScheduledJob) -> AppResult<()> {
+        let exists = scheduled_job::Entity::find_by_id(job.name.clone())
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find scheduled job failed: {}", e)))?
+            .is_some();
+        let model = job_to_active_model(job);
+        let result = if exists { model.update(&self.db).await } else { model.insert(&self.db).await };
+        result.map_err(|e| AppError::DatabaseError(format!("Save scheduled job failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn find_by_name(&self, name: &str) -> AppResult<Option<ScheduledJob>> {
+        scheduled_job::Entity::find_by_id(name.to_string())
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find scheduled job failed: {}", e)))?
+            .map(job_from_model)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<ScheduledJob>> {
+        let models = scheduled_job::Entity::find()
+            .order_by_asc(scheduled_job::Column::Name)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List scheduled jobs failed: {}", e)))?;
+        models.into_iter().map(job_from_model).collect()
+    }
+
+    async fn set_next_run_at(&self, name: &str, next_run_at: Option<i64>) -> AppResult<()> {
+        scheduled_job::Entity::update_many()
+            .col_expr(scheduled_job::Column::NextRunAt, sea_orm::sea_query::Expr::value(next_run_at.map(timestamp)))
+            .filter(scheduled_job::Column::Name.eq(name))
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update scheduled job failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn record_run(&self, run: &JobRun) -> AppResult<()> {
+        scheduled_job::Entity::update_many()
+            .col_expr(scheduled_job::Column::LastRunAt, sea_orm::sea_query::Expr::value(timestamp(run.started_at)))
+            .col_expr(scheduled_job::Column::LastStatus, run.status.value().into())
+            .filter(scheduled_job::Column::Name.eq(run.job_name.as_str()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update scheduled job failed: {}", e)))?;
+        Ok(())
+    }
+}
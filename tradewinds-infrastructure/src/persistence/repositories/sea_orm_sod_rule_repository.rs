@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter};
+
+use tradewinds_domain::entities::sod_rule::SodRule;
+use tradewinds_domain::repositories::SodRuleRepository;
+use tradewinds_domain::value_objects::{RoleId, SodRuleId, SodRuleName, SodRuleType};
+
+use crate::persistence::entities::{sod_rule, sod_rule_role};
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmSodRuleRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmSodRuleRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl SodRuleRepository for SeaOrmSodRuleRepository {
+    async fn find_enabled(&self) -> AppResult<Vec<SodRule>> {
+        let rules = sod_rule::Entity::find()
+            .tenant_scoped()
+            .filter(sod_rule::Column::Enabled.eq(true))
+            .find_with_related(sod_rule_role::Entity)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find SoD rules failed: {}", e)))?;
+
+        rules
+            .into_iter()
+            .map(|(rule, roles)| {
+                Ok(SodRule {
+                    id: SodRuleId::new(rule.id)?,
+                    name: SodRuleName::new(rule.name)?,
+                    rule_type: SodRuleType::from_i32(rule.rule_type)?,
+                    role_ids: roles.into_iter().map(|r| RoleId::new(r.role_id)).collect::<AppResult<Vec<_>>>()?,
+                    max_count: u32::try_from(rule.max_count)
+                        .map_err(|_| AppError::Validation(format!("Invalid SoD rule max count: {}", rule.max_count)))?,
+                    enabled: rule.enabled,
+                    created_at: rule.created_at.timestamp(),
+                    updated_at: rule.updated_at.timestamp(),
+                })
+            })
+            .collect()
+    }
+}
@@ -1,9 +1,15 @@
-use crate::persistence::entities::system_setting::{ActiveModel, Column, Entity};
-use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
-use tradewinds_domain::entities::system_setting::SystemSetting;
+use crate::persistence::entities::system_setting::{ActiveModel, Column, Entity, Model};
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+use std::collections::HashMap;
+use tradewinds_common::tenant::{DEFAULT_TENANT_ID, current_tenant_id};
+use tradewinds_domain::entities::{outbox_message::OutboxMessage, system_setting::SystemSetting};
 use tradewinds_domain::repositories::system_setting_repository::SystemSettingRepository;
 use tradewinds_domain::value_objects::system_setting::{SystemSettingId, SystemSettingKey, SystemSettingValue};
-use tradewinds_error::AppResult;
+use tradewinds_error::{AppError, AppResult};
+use uuid::Uuid;
 
 #[derive(Clone)]
 pub struct SeaOrmSystemSettingRepository {
@@ -14,29 +20,111 @@ impl SeaOrmSystemSettingRepository {
     pub fn new(db: DatabaseConnection) -> Self {
         Self { db }
     }
-}
 
-#[async_trait::async_trait]
-impl SystemSettingRepository for SeaOrmSystemSettingRepository {
-    async fn get_by_key(&self, key: &SystemSettingKey) -> AppResult<Option<SystemSetting>> {
-        let result = Entity::find().filter(Column::Key.eq(key.value())).one(&self.db).await?;
-        Ok(result.map(|m| SystemSetting {
+    /// 查找当前租户的参数，租户未单独设置时回退到平台默认租户的参数
+    async fn find_effective(&self, key: &SystemSettingKey) -> AppResult<Option<Model>> {
+        let tenant_id = current_tenant_id();
+        let models = Entity::find()
+            .filter(Column::Key.eq(key.value()))
+            .filter(Column::TenantId.is_in([tenant_id.clone(), DEFAULT_TENANT_ID.to_string()]))
+            .order_by_asc(Column::TenantId)
+            .all(&self.db)
+            .await?;
+        let (own, fallback): (Vec<Model>, Vec<Model>) = models.into_iter().partition(|m| m.tenant_id == tenant_id);
+        Ok(own.into_iter().next().or_else(|| fallback.into_iter().next()))
+    }
+
+    fn from_model(m: Model) -> SystemSetting {
+        SystemSetting {
             id: SystemSettingId::new(m.id).unwrap(),
             key: SystemSettingKey::new(m.key).unwrap(),
             value: SystemSettingValue::new(m.value).unwrap(),
             description: m.description,
             updated_at: m.updated_at.naive_utc(),
-        }))
+        }
     }
+}
 
-    async fn set_value(&self, key: &SystemSettingKey, value: &SystemSettingValue) -> AppResult<()> {
-        use sea_orm::ActiveValue::Set as AVSet;
-        let setting = Entity::find().filter(Column::Key.eq(key.value())).one(&self.db).await?;
-        if let Some(m) = setting {
-            let mut am: ActiveModel = m.into();
-            am.value = AVSet(value.value().to_string());
-            am.update(&self.db).await?;
+#[async_trait::async_trait]
+impl SystemSettingRepository for SeaOrmSystemSettingRepository {
+    async fn get_by_key(&self, key: &SystemSettingKey) -> AppResult<Option<SystemSetting>> {
+        let result = self.find_effective(key).await?;
+        Ok(result.map(Self::from_model))
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<SystemSetting>> {
+        let tenant_id = current_tenant_id();
+        let models = Entity::find()
+            .filter(Column::TenantId.is_in([tenant_id.clone(), DEFAULT_TENANT_ID.to_string()]))
+            .all(&self.db)
+            .await?;
+        let mut effective: HashMap<String, Model> = HashMap::new();
+        for model in models {
+            if model.tenant_id == tenant_id || !effective.contains_key(&model.key) {
+                effective.insert(model.key.clone(), model);
+            }
         }
-        Ok(())
+        Ok(effective.into_values().map(Self::from_model).collect())
+    }
+
+    /// 写入当前租户的参数；租户首次覆盖平台默认值或设置首次被修改时新建一行
+    async fn set_value(
+        &self,
+        key: &SystemSettingKey,
+        value: &SystemSettingValue,
+        events: &[OutboxMessage],
+    ) -> AppResult<()> {
+        use sea_orm::ActiveValue::Set as AVSet;
+        let tenant_id = current_tenant_id();
+        let setting = self.find_effective(key).await?;
+        // 本租户已有记录则更新，否则新增租户级覆盖
+        let (am, exists) = match setting {
+            Some(m) if m.tenant_id == tenant_id => {
+                let mut am: ActiveModel = m.into();
+                am.value = AVSet(value.value().to_string());
+                am.updated_at = AVSet(chrono::Utc::now());
+                (am, true)
+            }
+            Some(m) => {
+                let am = ActiveModel {
+                    id: Set(Uuid::new_v4().to_string()),
+                    tenant_id: Set(tenant_id),
+                    key: Set(m.key),
+                    value: Set(value.value().to_string()),
+                    description: Set(m.description),
+                    updated_at: Set(chrono::Utc::now()),
+                };
+                (am, false)
+            }
+            None => {
+                let am = ActiveModel {
+                    id: Set(Uuid::new_v4().to_string()),
+                    tenant_id: Set(tenant_id),
+                    key: Set(key.value().to_string()),
+                    value: Set(value.value().to_string()),
+                    description: Set(None),
+                    updated_at: Set(chrono::Utc::now()),
+                };
+                (am, false)
+            }
+        };
+        let messages = events.to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    if exists {
+                        am.update(txn).await?;
+                    } else {
+                        am.insert(txn).await?;
+                    }
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Set system setting failed: {}", e))
+            })
     }
 }
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set, TransactionTrait,
+};
+
+use tradewinds_domain::entities::{outbox_message::OutboxMessage, tenant::Tenant};
+use tradewinds_domain::repositories::TenantRepository;
+use tradewinds_domain::value_objects::tenant::{TenantCode, TenantHost, TenantId, TenantName, TenantStatus};
+
+use crate::persistence::entities::tenant;
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use tradewinds_error::{AppError, AppResult};
+
+fn tenant_from_model(model: tenant::Model) -> AppResult<Tenant> {
+    Ok(Tenant {
+        id: TenantId::new(model.id)?,
+        code: TenantCode::new(model.code)?,
+        name: TenantName::new(model.name)?,
+        host: model.host.map(TenantHost::new).transpose()?,
+        status: TenantStatus::from_i32(model.status)?,
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn tenant_to_active_model(tenant: &Tenant) -> tenant::ActiveModel {
+    let now: DateTime<Utc> = Utc::now();
+    let created_at = DateTime::from_timestamp(tenant.created_at, 0).unwrap_or(now);
+    tenant::ActiveModel {
+        id: Set(tenant.id.value().to_string()),
+        code: Set(tenant.code.value().to_string()),
+        name: Set(tenant.name.value().to_string()),
+        host: Set(tenant.host.as_ref().map(|h| h.value().to_string())),
+        status: Set(tenant.status.value()),
+        created_at: Set(created_at.into()),
+        updated_at: Set(now.into()),
+    }
+}
+
+/// 租户仓储，租户表为平台级数据，查询不做租户范围限制
+#[derive(Debug, Clone)]
+pub struct SeaOrmTenantRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmTenantRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+
+    async fn find_one(&self, condition: Condition) -> AppResult<Option<Tenant>> {
+        tenant::Entity::find()
+            .filter(condition)
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find tenant failed: {}", e)))?
+            .map(tenant_from_model)
+            .transpose()
+    }
+}
+
+#[async_trait]
+impl TenantRepository for SeaOrmTenantRepository {
+    async fn create(&self, tenant: &Tenant, events: &[OutboxMessage]) -> AppResult<()> {
+        let model = tenant_to_active_model(tenant);
+        let messages = events.to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    model.insert(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Create tenant failed: {}", e))
+            })
+    }
+
+    async fn save(&self, tenant: &Tenant, events: &[OutboxMessage]) -> AppResult<()> {
+        let model = tenant_to_active_model(tenant);
+        let messages = events.to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    model.update(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Update tenant failed: {}", e))
+            })
+    }
+
+    async fn find_by_id(&self, id: &TenantId) -> AppResult<Option<Tenant>> {
+        self.find_one(Condition::all().add(tenant::Column::Id.eq(id.value()))).await
+    }
+
+    async fn find_by_code(&self, code: &TenantCode) -> AppResult<Option<Tenant>> {
+        self.find_one(Condition::all().add(tenant::Column::Code.eq(code.value()))).await
+    }
+
+    async fn find_by_host(&self, host: &TenantHost) -> AppResult<Option<Tenant>> {
+        self.find_one(Condition::all().add(tenant::Column::Host.eq(host.value()))).await
+    }
+
+    async fn find_all_ids(&self) -> AppResult<Vec<TenantId>> {
+        let ids: Vec<String> = tenant::Entity::find()
+            .select_only()
+            .column(tenant::Column::Id)
+            .filter(tenant::Column::Status.ne(TenantStatus::Deleted.value()))
+            .order_by_asc(tenant::Column::CreatedAt)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List tenant ids failed: {}", e)))?;
+        ids.into_iter().map(TenantId::new).collect()
+    }
+
+    async fn search(
+        &self,
+        keyword: Option<&str>,
+        status: Option<TenantStatus>,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<Tenant>, u64)> {
+        let mut query = tenant::Entity::find();
+        if let Some(keyword) = keyword {
+            query = query.filter(
+                Condition::any()
+                    .add(tenant::Column::Code.contains(keyword))
+                    .add(tenant::Column::Name.contains(keyword)),
+            );
+        }
+        if let Some(status) = status {
+            query = query.filter(tenant::Column::Status.eq(status.value()));
+        } else {
+            query = query.filter(tenant::Column::Status.ne(TenantStatus::Deleted.value()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count tenants failed: {}", e)))?;
+        let models = query
+            .order_by_asc(tenant::Column::CreatedAt)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List tenants failed: {}", e)))?;
+        let tenants = models.into_iter().map(tenant_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((tenants, total))
+    }
+}
@@ -57,8 +57,12 @@ impl TokenBlacklistRepository for SeaOrmTokenBlacklistRepository {
             .map_err(|e| AppError::DatabaseError(e.to_string()))
     }
 
-    async fn cleanup(&self) -> AppResult<()> {
-        // Implementation for cleanup
-        Ok(())
+    async fn cleanup(&self) -> AppResult<u64> {
+        let result = token_blacklist::Entity::delete_many()
+            .filter(token_blacklist::Column::ExpiresAt.lt(Utc::now()))
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Cleanup token blacklist failed: {}", e)))?;
+        Ok(result.rows_affected)
     }
 }
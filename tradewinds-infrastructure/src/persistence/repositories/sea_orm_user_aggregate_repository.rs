@@ -4,23 +4,36 @@ use sea_orm::{
     ColumnTrait, ConnectionTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set, TransactionError,
     TransactionTrait,
 };
+use std::collections::HashMap;
 use tokio::time::sleep;
 use uuid::Uuid;
 
 use crate::persistence::entities::{user, user_role};
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::value_objects::auth::{AuthUsername, Password};
 use tradewinds_domain::value_objects::user::{Avatar, Email, Phone, RealName, UserStatus};
 use tradewinds_domain::{
     aggregates::user_aggregate::UserAggregate,
     entities::{user::User, user_role::UserRole},
     repositories::user_aggregate_repository::UserAggregateRepository,
-    value_objects::{role::RoleId, user::UserId},
+    value_objects::{access_request::AccessRequestId, department::DepartmentId, role::RoleId, user::UserId},
 };
 use tradewinds_error::{AppError, AppResult};
 
 const MAX_RETRIES: u32 = 3;
 const RETRY_DELAY_MS: u64 = 100;
 
+/// 经权限申请授予的角色ID到申请ID的映射，随角色关联一并写入
+fn request_grant_ids(aggregate: &UserAggregate) -> HashMap<String, String> {
+    aggregate
+        .request_grants
+        .iter()
+        .map(|(role_id, request_id)| (role_id.value().to_string(), request_id.value().to_string()))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct SeaOrmUserAggregateRepository {
     db: DatabaseConnection,
@@ -43,6 +56,10 @@ impl SeaOrmUserAggregateRepository {
             real_name: model.real_name.map(RealName::new).transpose()?,
             avatar: model.avatar.map(Avatar::new).transpose()?,
             phone: model.phone.map(Phone::new).transpose()?,
+            department_id: model.department_id.map(DepartmentId::new).transpose()?,
+            created_by: model.created_by.map(UserId::new).transpose()?,
+            last_login_at: model.last_login_at.map(|t| t.timestamp()),
+            last_login_ip: model.last_login_ip,
         })
     }
 
@@ -53,6 +70,7 @@ impl SeaOrmUserAggregateRepository {
 
         user::ActiveModel {
             id: Set(user_entity.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             username: Set(user_entity.username.value().to_string()),
             email: Set(user_entity.email.value().to_string()),
             password: Set(password),
@@ -60,8 +78,12 @@ impl SeaOrmUserAggregateRepository {
             real_name: Set(user_entity.real_name.as_ref().map(|r| r.value().to_string())),
             avatar: Set(user_entity.avatar.as_ref().map(|a| a.value().to_string())),
             phone: Set(user_entity.phone.as_ref().map(|p| p.value().to_string())),
+            department_id: Set(user_entity.department_id.as_ref().map(|d| d.value().to_string())),
+            created_by: Set(user_entity.created_by.as_ref().map(|u| u.value().to_string())),
             created_at: Set(created_at),
             updated_at: Set(updated_at),
+            last_login_at: Set(user_entity.last_login_at.and_then(|t| DateTime::from_timestamp(t, 0)).map(Into::into)),
+            last_login_ip: Set(user_entity.last_login_ip.clone()),
         }
     }
 
@@ -74,7 +96,7 @@ impl SeaOrmUserAggregateRepository {
                 sleep(tokio::time::Duration::from_millis(RETRY_DELAY_MS)).await;
             }
 
-            match user::Entity::find_by_id(user_id).one(conn).await? {
+            match user::Entity::find_by_id(user_id).tenant_scoped().one(conn).await? {
                 Some(_) => return Ok(true),
                 None if attempt == retry_count - 1 => return Ok(false),
                 None => continue,
@@ -99,6 +121,7 @@ impl SeaOrmUserAggregateRepository {
             }
 
             let result = user_role::Entity::find()
+                .tenant_scoped()
                 .filter(user_role::Column::UserId.eq(user_id))
                 .filter(user_role::Column::RoleId.eq(role_id))
                 .one(conn)
@@ -118,6 +141,7 @@ impl SeaOrmUserAggregateRepository {
 impl UserAggregateRepository for SeaOrmUserAggregateRepository {
     async fn find_by_id(&self, id: &UserId) -> AppResult<Option<UserAggregate>> {
         let user_model = user::Entity::find_by_id(id.value())
+            .tenant_scoped()
             .one(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Failed to find user by id: {}", e)))?;
@@ -126,17 +150,25 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
             return Ok(None);
         };
 
-        let role_ids = user_role::Entity::find()
+        let role_models = user_role::Entity::find()
+            .tenant_scoped()
             .filter(user_role::Column::UserId.eq(id.value()))
             .all(&self.db)
             .await
-            .map_err(|e| AppError::DatabaseError(format!("Failed to find roles for user: {}", e)))?
-            .into_iter()
-            .map(|ur| RoleId::new(ur.role_id).unwrap())
-            .collect::<Vec<_>>();
+            .map_err(|e| AppError::DatabaseError(format!("Failed to find roles for user: {}", e)))?;
+
+        let mut role_ids = Vec::with_capacity(role_models.len());
+        let mut request_grants = HashMap::new();
+        for ur in role_models {
+            let role_id = RoleId::new(ur.role_id)?;
+            if let Some(request_id) = ur.access_request_id {
+                request_grants.insert(role_id.clone(), AccessRequestId::new(request_id)?);
+            }
+            role_ids.push(role_id);
+        }
 
         let user_entity = self.user_from_model(user_model)?;
-        let aggregate = UserAggregate { user: user_entity, roles: role_ids };
+        let aggregate = UserAggregate::from_existing(user_entity, role_ids).with_request_grants(request_grants);
         Ok(Some(aggregate))
     }
 
@@ -144,6 +176,8 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
         let user_model = self.user_to_active_model(&aggregate.user);
         let role_count = aggregate.roles.len();
         let role_models = UserRole::create_associations(aggregate.user.id.clone(), &aggregate.roles);
+        let request_grants = request_grant_ids(aggregate);
+        let messages = aggregate.events().to_vec();
         let repo = self.clone();
 
         self.db
@@ -174,8 +208,10 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
                             let now: chrono::DateTime<chrono::FixedOffset> = Utc::now().into();
                             let role_model = user_role::ActiveModel {
                                 id: Set(id),
+                                tenant_id: Set(current_tenant_id()),
                                 user_id: Set(user_id.clone()),
                                 role_id: Set(role_id.clone()),
+                                access_request_id: Set(request_grants.get(&role_id).cloned()),
                                 created_at: Set(now),
                                 updated_at: Set(now),
                             };
@@ -208,6 +244,11 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
                         }
                     }
 
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| DbErr::Custom(format!("Failed to insert outbox messages: {}", e)))?;
+
                     Ok(())
                 })
             })
@@ -220,18 +261,22 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
         let user_model = self.user_to_active_model(&aggregate.user);
         let user_id = aggregate.user.id.value().to_string();
         let role_models = UserRole::create_associations(aggregate.user.id.clone(), &aggregate.roles);
+        let request_grants = request_grant_ids(aggregate);
+        let messages = aggregate.events().to_vec();
 
         self.db
             .transaction::<_, (), DbErr>(|txn| {
                 Box::pin(async move {
                     // 更新用户
                     user::Entity::update(user_model)
+                        .tenant_scoped()
                         .exec(txn)
                         .await
                         .map_err(|e| DbErr::Custom(format!("Failed to update user: {}", e)))?;
 
                     // 删除现有角色关联
                     user_role::Entity::delete_many()
+                        .tenant_scoped()
                         .filter(user_role::Column::UserId.eq(&user_id))
                         .exec(txn)
                         .await
@@ -242,8 +287,10 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
                         let now: chrono::DateTime<chrono::FixedOffset> = Utc::now().into();
                         let role_model = user_role::ActiveModel {
                             id: Set(id),
+                            tenant_id: Set(current_tenant_id()),
                             user_id: Set(user_id.clone()),
                             role_id: Set(role_id.clone()),
+                            access_request_id: Set(request_grants.get(&role_id).cloned()),
                             created_at: Set(now),
                             updated_at: Set(now),
                         };
@@ -272,6 +319,11 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
                         }
                     }
 
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| DbErr::Custom(format!("Failed to insert outbox messages: {}", e)))?;
+
                     Ok(())
                 })
             })
@@ -279,13 +331,29 @@ impl UserAggregateRepository for SeaOrmUserAggregateRepository {
             .map_err(|e| AppError::DatabaseError(format!("Transaction failed: {}", e)))
     }
 
-    async fn delete_by_id(&self, id: &UserId) -> AppResult<()> {
-        use crate::persistence::entities::user;
-        use sea_orm::EntityTrait;
-        user::Entity::delete_by_id(id.value())
-            .exec(&self.db)
+    async fn delete(&self, aggregate: &UserAggregate) -> AppResult<()> {
+        let user_id = aggregate.user.id.value().to_string();
+        let messages = aggregate.events().to_vec();
+
+        self.db
+            .transaction::<_, (), DbErr>(|txn| {
+                Box::pin(async move {
+                    user::Entity::delete_many()
+                        .filter(user::Column::Id.eq(&user_id))
+                        .tenant_scoped()
+                        .exec(txn)
+                        .await
+                        .map_err(|e| DbErr::Custom(format!("Failed to delete user: {}", e)))?;
+
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages)
+                        .await
+                        .map_err(|e| DbErr::Custom(format!("Failed to insert outbox messages: {}", e)))?;
+
+                    Ok(())
+                })
+            })
             .await
-            .map_err(|e| AppError::DatabaseError(format!("Delete user by id failed: {}", e)))?;
-        Ok(())
+            .map_err(|e| AppError::DatabaseError(format!("Delete user failed: {}", e)))
     }
 }
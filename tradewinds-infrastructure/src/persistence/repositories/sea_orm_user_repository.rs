@@ -1,18 +1,24 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseBackend, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QuerySelect, QueryTrait, Set,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseBackend, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QuerySelect, QueryTrait, Set, TransactionTrait,
 };
 
-use tradewinds_domain::entities::user::User;
-use tradewinds_domain::repositories::UserRepository;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::{outbox_message::OutboxMessage, user::User};
+use tradewinds_domain::repositories::{UserRepository, UserSearchFilter};
 use tradewinds_domain::value_objects::user::{UserId, UserStatus};
 use tradewinds_domain::value_objects::{
-    Avatar, Password, Phone, RealName, auth::auth_username::AuthUsername, user::user_email::Email,
+    Avatar, DataScope, DepartmentId, Password, Phone, RealName, auth::auth_username::AuthUsername,
+    user::user_email::Email,
 };
 
 use crate::persistence::entities::user;
+use crate::persistence::repositories::sea_orm_department_repository::expand_department_trees;
+use crate::persistence::repositories::sea_orm_outbox_repository::insert_outbox_messages;
+use crate::persistence::tenant_scope::TenantScoped;
 use tradewinds_error::{AppError, AppResult};
 
 #[derive(Debug, Clone)]
@@ -35,8 +41,12 @@ impl SeaOrmUserRepository {
             phone: model.phone.map(Phone::new).transpose()?,
             avatar: model.avatar.map(Avatar::new).transpose()?,
             status: UserStatus::from_i32(model.status)?,
+            department_id: model.department_id.map(DepartmentId::new).transpose()?,
+            created_by: model.created_by.map(UserId::new).transpose()?,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
+            last_login_at: model.last_login_at.map(|t| t.timestamp()),
+            last_login_ip: model.last_login_ip,
         })
     }
 
@@ -44,6 +54,7 @@ impl SeaOrmUserRepository {
         let now: DateTime<Utc> = Utc::now();
         user::ActiveModel {
             id: Set(user.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             username: Set(user.username.value().to_string()),
             email: Set(user.email.value().to_string()),
             password: Set(user.password.value().to_string()),
@@ -51,10 +62,35 @@ impl SeaOrmUserRepository {
             phone: Set(user.phone.as_ref().map(|v| v.value().to_string())),
             avatar: Set(user.avatar.as_ref().map(|v| v.value().to_string())),
             status: Set(user.status.value()),
+            department_id: Set(user.department_id.as_ref().map(|v| v.value().to_string())),
+            created_by: Set(user.created_by.as_ref().map(|v| v.value().to_string())),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
+            last_login_at: Set(user.last_login_at.and_then(|t| DateTime::from_timestamp(t, 0)).map(Into::into)),
+            last_login_ip: Set(user.last_login_ip.clone()),
         }
     }
+
+    /// 将数据范围转换为查询条件，命中任一条件即可见
+    async fn data_scope_condition(&self, data_scope: &DataScope) -> AppResult<Option<Condition>> {
+        let DataScope::Restricted { departments, department_trees, owner } = data_scope else {
+            return Ok(None);
+        };
+        let trees: Vec<DepartmentId> = department_trees.iter().cloned().collect();
+        let expanded = expand_department_trees(&self.db, &trees).await?;
+        let mut department_ids: Vec<String> = departments.iter().map(|d| d.value().to_string()).collect();
+        department_ids.extend(expanded.iter().map(|d| d.value().to_string()));
+
+        let mut condition = Condition::any();
+        if !department_ids.is_empty() {
+            condition = condition.add(user::Column::DepartmentId.is_in(department_ids));
+        }
+        if let Some(owner) = owner {
+            condition =
+                condition.add(user::Column::Id.eq(owner.value())).add(user::Column::CreatedBy.eq(owner.value()));
+        }
+        Ok(Some(condition))
+    }
 }
 
 // 实现 UserRepository 接口，使用 SeaORM 作为数据库操作的实现
@@ -62,6 +98,7 @@ impl SeaOrmUserRepository {
 impl UserRepository for SeaOrmUserRepository {
     async fn find_by_id(&self, id: &UserId) -> AppResult<Option<User>> {
         user::Entity::find_by_id(id.value())
+            .tenant_scoped()
             .one(&self.db)
             .await
             .map_err(|e| AppError::DatabaseError(format!("Find user by id failed: {}", e)))?
@@ -71,6 +108,7 @@ impl UserRepository for SeaOrmUserRepository {
 
     async fn find_by_email(&self, email: &Email) -> AppResult<Option<User>> {
         user::Entity::find()
+            .tenant_scoped()
             .filter(user::Column::Email.eq(email.value()))
             .one(&self.db)
             .await
@@ -81,6 +119,7 @@ impl UserRepository for SeaOrmUserRepository {
 
     async fn find_by_username(&self, username: &AuthUsername) -> AppResult<Option<User>> {
         user::Entity::find()
+            .tenant_scoped()
             .filter(user::Column::Username.eq(username.value()))
             .one(&self.db)
             .await
@@ -92,6 +131,7 @@ impl UserRepository for SeaOrmUserRepository {
     async fn find_by_ids(&self, ids: &[UserId]) -> AppResult<Vec<User>> {
         let id_strs: Vec<String> = ids.iter().map(|id| id.value().to_string()).collect();
         user::Entity::find()
+            .tenant_scoped()
             .filter(user::Column::Id.is_in(id_strs))
             .all(&self.db)
             .await
@@ -101,8 +141,34 @@ impl UserRepository for SeaOrmUserRepository {
             .collect()
     }
 
+    async fn update_last_login(&self, user: &User, events: &[OutboxMessage]) -> AppResult<()> {
+        let last_login_at: Option<DateTime<Utc>> = user.last_login_at.and_then(|t| DateTime::from_timestamp(t, 0));
+        // 显式保留 updated_at，避免 ON UPDATE CURRENT_TIMESTAMP 把登录记为资料变更
+        let update = user::Entity::update_many()
+            .col_expr(user::Column::LastLoginAt, Expr::value(last_login_at))
+            .col_expr(user::Column::LastLoginIp, Expr::value(user.last_login_ip.clone()))
+            .col_expr(user::Column::UpdatedAt, Expr::col(user::Column::UpdatedAt).into())
+            .filter(user::Column::Id.eq(user.id.value()))
+            .tenant_scoped();
+        let messages = events.to_vec();
+        self.db
+            .transaction(|txn| {
+                Box::pin(async move {
+                    update.exec(txn).await?;
+                    // 写入发件箱
+                    insert_outbox_messages(txn, &messages).await?;
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e: sea_orm::TransactionError<AppError>| {
+                AppError::DatabaseError(format!("Update last login failed: {}", e))
+            })
+    }
+
     async fn exists_by_username(&self, username: &AuthUsername) -> AppResult<bool> {
         let count = user::Entity::find()
+            .tenant_scoped()
             .filter(user::Column::Username.eq(username.value()))
             .count(&self.db)
             .await
@@ -112,6 +178,7 @@ impl UserRepository for SeaOrmUserRepository {
 
     async fn exists_by_email(&self, email: &Email) -> AppResult<bool> {
         let count = user::Entity::find()
+            .tenant_scoped()
             .filter(user::Column::Email.eq(email.value()))
             .count(&self.db)
             .await
@@ -120,32 +187,69 @@ impl UserRepository for SeaOrmUserRepository {
     }
 
     async fn count(&self) -> AppResult<u64> {
-        user::Entity::find().count(&self.db).await.map_err(|e| AppError::DatabaseError(format!("Count users failed: {}", e)))
+        user::Entity::find()
+            .tenant_scoped()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count users failed: {}", e)))
+    }
+
+    async fn count_by_departments(&self, department_ids: &[DepartmentId]) -> AppResult<u64> {
+        let id_strs: Vec<String> = department_ids.iter().map(|id| id.value().to_string()).collect();
+        user::Entity::find()
+            .tenant_scoped()
+            .filter(user::Column::DepartmentId.is_in(id_strs))
+            .filter(user::Column::Status.ne(UserStatus::Deleted.value()))
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count users by departments failed: {}", e)))
     }
 
-    async fn search(
-        &self,
-        username: Option<&AuthUsername>,
-        phone: Option<&str>,
-        email: Option<&Email>,
-        status: Option<UserStatus>,
-        show_deleted: Option<bool>,
-        limit: u64,
-        offset: u64,
-    ) -> AppResult<(Vec<User>, u64)> {
-        let mut query = user::Entity::find();
-        if let Some(username) = username {
+    async fn is_visible(&self, data_scope: &DataScope, id: &UserId) -> AppResult<bool> {
+        let Some(condition) = self.data_scope_condition(data_scope).await? else {
+            return Ok(true);
+        };
+        let count = user::Entity::find()
+            .tenant_scoped()
+            .filter(user::Column::Id.eq(id.value()))
+            .filter(condition)
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Check user visibility failed: {}", e)))?;
+        Ok(count > 0)
+    }
+
+    async fn search(&self, filter: &UserSearchFilter, limit: u64, offset: u64) -> AppResult<(Vec<User>, u64)> {
+        let mut query = user::Entity::find().tenant_scoped();
+        if let Some(condition) = self.data_scope_condition(&filter.data_scope).await? {
+            query = query.filter(condition);
+        }
+        if let Some(department_id) = &filter.department_tree {
+            let department_ids: Vec<String> = expand_department_trees(&self.db, std::slice::from_ref(department_id))
+                .await?
+                .iter()
+                .map(|d| d.value().to_string())
+                .collect();
+            query = query.filter(user::Column::DepartmentId.is_in(department_ids));
+        }
+        if let Some(username) = &filter.username {
             query = query.filter(user::Column::Username.contains(username.value()));
         }
-        if let Some(phone) = phone {
+        if let Some(phone) = &filter.phone {
             query = query.filter(user::Column::Phone.contains(phone));
         }
-        if let Some(email) = email {
+        if let Some(email) = &filter.email {
             query = query.filter(user::Column::Email.contains(email.value()));
         }
-        if let Some(status) = status {
+        if let Some(since) = filter.created_since.and_then(|t| DateTime::<Utc>::from_timestamp(t, 0)) {
+            query = query.filter(user::Column::CreatedAt.gte(since));
+        }
+        if let Some(since) = filter.last_login_since.and_then(|t| DateTime::<Utc>::from_timestamp(t, 0)) {
+            query = query.filter(user::Column::LastLoginAt.gte(since));
+        }
+        if let Some(status) = filter.status {
             query = query.filter(user::Column::Status.eq(status.value()));
-        } else if show_deleted == Some(true) {
+        } else if filter.show_deleted == Some(true) {
             // 未传 status，且要求显示已删除用户，查所有
             // 不加 status 过滤
         } else {
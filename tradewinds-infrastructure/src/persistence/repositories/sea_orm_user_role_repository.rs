@@ -1,17 +1,18 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, ModelTrait, PaginatorTrait, QueryFilter,
-    QuerySelect, Set,
-};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, Set};
 
 use crate::persistence::{
-    entities::{role, user, user_role},
+    entities::{user, user_group, user_group_member, user_group_role, user_role},
     repositories::sea_orm_user_repository::SeaOrmUserRepository,
+    tenant_scope::TenantScoped,
 };
+use tradewinds_common::tenant::current_tenant_id;
 use tradewinds_domain::entities::{User, UserRole};
 use tradewinds_domain::repositories::UserRoleRepository;
-use tradewinds_domain::value_objects::{RoleId, UserId, UserRoleId};
+use tradewinds_domain::value_objects::{
+    AccessRequestId, GroupId, GroupName, GroupStatus, RoleAssignment, RoleId, UserId, UserRoleId, UserStatus,
+};
 use tradewinds_error::{AppError, AppResult};
 
 #[derive(Clone)]
@@ -29,6 +30,7 @@ impl SeaOrmUserRoleRepository {
             id: UserRoleId::new(model.id)?,
             user_id: UserId::new(model.user_id)?,
             role_id: RoleId::new(model.role_id)?,
+            access_request_id: model.access_request_id.map(AccessRequestId::new).transpose()?,
             created_at: model.created_at.timestamp(),
             updated_at: model.updated_at.timestamp(),
         })
@@ -38,8 +40,10 @@ impl SeaOrmUserRoleRepository {
         let now: DateTime<Utc> = Utc::now();
         user_role::ActiveModel {
             id: Set(user_role.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
             user_id: Set(user_role.user_id.value().to_string()),
             role_id: Set(user_role.role_id.value().to_string()),
+            access_request_id: Set(user_role.access_request_id.as_ref().map(|id| id.value().to_string())),
             created_at: Set(now.into()),
             updated_at: Set(now.into()),
         }
@@ -56,6 +60,7 @@ impl UserRoleRepository for SeaOrmUserRoleRepository {
 
     async fn delete(&self, user_id: &UserId, role_id: &RoleId) -> AppResult<()> {
         user_role::Entity::delete_many()
+            .tenant_scoped()
             .filter(user_role::Column::UserId.eq(user_id.value()))
             .filter(user_role::Column::RoleId.eq(role_id.value()))
             .exec(&self.db)
@@ -66,6 +71,7 @@ impl UserRoleRepository for SeaOrmUserRoleRepository {
 
     async fn find_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<UserRole>> {
         user_role::Entity::find()
+            .tenant_scoped()
             .filter(user_role::Column::UserId.eq(user_id.value()))
             .all(&self.db)
             .await
@@ -75,23 +81,142 @@ impl UserRoleRepository for SeaOrmUserRoleRepository {
             .collect()
     }
 
-    async fn find_users_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<User>> {
-        let role_model = role::Entity::find_by_id(role_id.value())
-            .one(&self.db)
+    async fn find_assignments_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<RoleAssignment>> {
+        let mut assignments: Vec<RoleAssignment> =
+            self.find_by_user_id(user_id).await?.into_iter().map(|ur| RoleAssignment::direct(ur.role_id)).collect();
+
+        // 仅启用状态的用户组向成员传递角色
+        let group_ids: Vec<String> = user_group_member::Entity::find()
+            .tenant_scoped()
+            .filter(user_group_member::Column::UserId.eq(user_id.value()))
+            .all(&self.db)
             .await
-            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+            .map_err(|e| AppError::DatabaseError(format!("Find group memberships failed: {}", e)))?
+            .into_iter()
+            .map(|m| m.group_id)
+            .collect();
+        if group_ids.is_empty() {
+            return Ok(assignments);
+        }
+        let groups = user_group::Entity::find()
+            .tenant_scoped()
+            .filter(user_group::Column::Id.is_in(group_ids))
+            .filter(user_group::Column::Status.eq(GroupStatus::Active.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find groups failed: {}", e)))?;
+
+        for group in groups {
+            let group_roles = user_group_role::Entity::find()
+                .tenant_scoped()
+                .filter(user_group_role::Column::GroupId.eq(group.id.clone()))
+                .all(&self.db)
+                .await
+                .map_err(|e| AppError::DatabaseError(format!("Find group roles failed: {}", e)))?;
+            let group_id = GroupId::new(group.id)?;
+            let group_name = GroupName::new(group.name)?;
+            for group_role in group_roles {
+                assignments.push(RoleAssignment::via_group(
+                    RoleId::new(group_role.role_id)?,
+                    group_id.clone(),
+                    group_name.clone(),
+                ));
+            }
+        }
+        Ok(assignments)
+    }
 
-        let user_models = if let Some(model) = role_model {
-            vec![] // TODO: 实现join查询
-        } else {
-            vec![]
-        };
+    async fn find_holder_ids_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<UserId>> {
+        let mut user_ids: Vec<String> = user_role::Entity::find()
+            .tenant_scoped()
+            .filter(user_role::Column::RoleId.eq(role_id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find role holders failed: {}", e)))?
+            .into_iter()
+            .map(|ur| ur.user_id)
+            .collect();
 
-        Ok(user_models)
+        // 经由启用用户组获得该角色的成员
+        let group_ids: Vec<String> = user_group_role::Entity::find()
+            .tenant_scoped()
+            .filter(user_group_role::Column::RoleId.eq(role_id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find group roles failed: {}", e)))?
+            .into_iter()
+            .map(|gr| gr.group_id)
+            .collect();
+        if !group_ids.is_empty() {
+            let active_group_ids: Vec<String> = user_group::Entity::find()
+                .tenant_scoped()
+                .filter(user_group::Column::Id.is_in(group_ids))
+                .filter(user_group::Column::Status.eq(GroupStatus::Active.value()))
+                .all(&self.db)
+                .await
+                .map_err(|e| AppError::DatabaseError(format!("Find groups failed: {}", e)))?
+                .into_iter()
+                .map(|g| g.id)
+                .collect();
+            if !active_group_ids.is_empty() {
+                let members = user_group_member::Entity::find()
+                    .tenant_scoped()
+                    .filter(user_group_member::Column::GroupId.is_in(active_group_ids))
+                    .all(&self.db)
+                    .await
+                    .map_err(|e| AppError::DatabaseError(format!("Find group members failed: {}", e)))?;
+                user_ids.extend(members.into_iter().map(|m| m.user_id));
+            }
+        }
+        user_ids.sort();
+        user_ids.dedup();
+        if user_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        // 已删除的用户不计入持有人
+        user::Entity::find()
+            .tenant_scoped()
+            .filter(user::Column::Id.is_in(user_ids))
+            .filter(user::Column::Status.ne(UserStatus::Deleted.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find role holders failed: {}", e)))?
+            .into_iter()
+            .map(|u| UserId::new(u.id))
+            .collect()
+    }
+
+    async fn find_users_by_role_id(&self, role_id: &RoleId) -> AppResult<Vec<User>> {
+        let user_ids: Vec<String> = user_role::Entity::find()
+            .tenant_scoped()
+            .filter(user_role::Column::RoleId.eq(role_id.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find role users failed: {}", e)))?
+            .into_iter()
+            .map(|ur| ur.user_id)
+            .collect();
+        if user_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let user_repo = SeaOrmUserRepository::new(self.db.clone());
+        user::Entity::find()
+            .tenant_scoped()
+            .filter(user::Column::Id.is_in(user_ids))
+            .filter(user::Column::Status.ne(UserStatus::Deleted.value()))
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find role users failed: {}", e)))?
+            .into_iter()
+            .map(|m| user_repo.from_model(m))
+            .collect()
     }
 
     async fn exists(&self, user_id: &UserId, role_id: &RoleId) -> AppResult<bool> {
         let count = user_role::Entity::find()
+            .tenant_scoped()
             .filter(user_role::Column::UserId.eq(user_id.value()))
             .filter(user_role::Column::RoleId.eq(role_id.value()))
             .count(&self.db)
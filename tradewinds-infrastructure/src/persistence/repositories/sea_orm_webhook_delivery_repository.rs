@@ -0,0 +1,179 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::NotSet, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::webhook_delivery::WebhookDelivery;
+use tradewinds_domain::repositories::{WebhookDeliveryFilter, WebhookDeliveryRepository};
+use tradewinds_domain::value_objects::webhook::{WebhookDeliveryId, WebhookDeliveryStatus, WebhookId};
+
+use crate::persistence::entities::webhook_delivery;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn delivery_from_model(model: webhook_delivery::Model) -> AppResult<WebhookDelivery> {
+    Ok(WebhookDelivery {
+        id: WebhookDeliveryId::new(model.id)?,
+        webhook_id: WebhookId::new(model.webhook_id)?,
+        event_type: model.event_type,
+        payload: model.payload,
+        status: WebhookDeliveryStatus::from_i32(model.status)?,
+        attempts: model.attempts.max(0) as u32,
+        next_attempt_at: model.next_attempt_at.timestamp(),
+        response_status: model.response_status.and_then(|status| u16::try_from(status).ok()),
+        last_error: model.last_error,
+        created_at: model.created_at.timestamp(),
+        delivered_at: model.delivered_at.map(|t| t.timestamp()),
+        redelivery_of: model.redelivery_of.map(WebhookDeliveryId::new).transpose()?,
+    })
+}
+
+fn timestamp(secs: i64) -> DateTime<Utc> {
+    DateTime::from_timestamp(secs, 0).unwrap_or_else(Utc::now)
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmWebhookDeliveryRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmWebhookDeliveryRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for SeaOrmWebhookDeliveryRepository {
+    async fn create(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        webhook_delivery::ActiveModel {
+            seq: NotSet,
+            id: Set(delivery.id.value().to_string()),
+            tenant_id: Set(current_tenant_id()),
+            webhook_id: Set(delivery.webhook_id.value().to_string()),
+            event_type: Set(delivery.event_type.clone()),
+            payload: Set(delivery.payload.clone()),
+            status: Set(delivery.status.value()),
+            attempts: Set(delivery.attempts as i32),
+            next_attempt_at: Set(timestamp(delivery.next_attempt_at).into()),
+            response_status: Set(delivery.response_status.map(i32::from)),
+            last_error: Set(delivery.last_error.clone()),
+            created_at: Set(timestamp(delivery.created_at).into()),
+            delivered_at: Set(delivery.delivered_at.map(|t| timestamp(t).into())),
+            redelivery_of: Set(delivery.redelivery_of.as_ref().map(|id| id.value().to_string())),
+        }
+        .insert(&self.db)
+        .await
+        .map_err(|e| AppError::DatabaseError(format!("Create webhook delivery failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn update(&self, delivery: &WebhookDelivery) -> AppResult<()> {
+        webhook_delivery::Entity::update_many()
+            .col_expr(webhook_delivery::Column::Status, delivery.status.value().into())
+            .col_expr(webhook_delivery::Column::Attempts, (delivery.attempts as i32).into())
+            .col_expr(webhook_delivery::Column::NextAttemptAt, Expr::value(timestamp(delivery.next_attempt_at)))
+            .col_expr(webhook_delivery::Column::ResponseStatus, delivery.response_status.map(i32::from).into())
+            .col_expr(webhook_delivery::Column::LastError, delivery.last_error.clone().into())
+            .col_expr(webhook_delivery::Column::DeliveredAt, Expr::value(delivery.delivered_at.map(timestamp)))
+            .filter(webhook_delivery::Column::Id.eq(delivery.id.value()))
+            .tenant_scoped()
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update webhook delivery failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &WebhookDeliveryId) -> AppResult<Option<WebhookDelivery>> {
+        webhook_delivery::Entity::find()
+            .tenant_scoped()
+            .filter(webhook_delivery::Column::Id.eq(id.value()))
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find webhook delivery failed: {}", e)))?
+            .map(delivery_from_model)
+            .transpose()
+    }
+
+    async fn find_tenant_ids_with_due(&self, now: i64) -> AppResult<Vec<String>> {
+        webhook_delivery::Entity::find()
+            .select_only()
+            .column(webhook_delivery::Column::TenantId)
+            .filter(webhook_delivery::Column::Status.eq(WebhookDeliveryStatus::Pending.value()))
+            .filter(webhook_delivery::Column::NextAttemptAt.lte(timestamp(now)))
+            .distinct()
+            .order_by_asc(webhook_delivery::Column::TenantId)
+            .into_tuple()
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find webhook delivery tenants failed: {}", e)))
+    }
+
+    async fn claim_due(&self, now: i64, lease_secs: i64, limit: u64) -> AppResult<Vec<WebhookDelivery>> {
+        let models = webhook_delivery::Entity::find()
+            .tenant_scoped()
+            .filter(webhook_delivery::Column::Status.eq(WebhookDeliveryStatus::Pending.value()))
+            .filter(webhook_delivery::Column::NextAttemptAt.lte(timestamp(now)))
+            .order_by_asc(webhook_delivery::Column::Seq)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find due webhook deliveries failed: {}", e)))?;
+
+        let mut claimed = Vec::with_capacity(models.len());
+        for model in models {
+            let mut delivery = delivery_from_model(model)?;
+            // 仅当下次投递时间未被其他实例改写时认领成功
+            let result = webhook_delivery::Entity::update_many()
+                .col_expr(webhook_delivery::Column::NextAttemptAt, Expr::value(timestamp(now + lease_secs)))
+                .filter(webhook_delivery::Column::Id.eq(delivery.id.value()))
+                .filter(webhook_delivery::Column::Status.eq(WebhookDeliveryStatus::Pending.value()))
+                .filter(webhook_delivery::Column::NextAttemptAt.eq(timestamp(delivery.next_attempt_at)))
+                .tenant_scoped()
+                .exec(&self.db)
+                .await
+                .map_err(|e| AppError::DatabaseError(format!("Claim webhook delivery failed: {}", e)))?;
+            if result.rows_affected == 1 {
+                delivery.lease(now, lease_secs);
+                claimed.push(delivery);
+            }
+        }
+        Ok(claimed)
+    }
+
+    async fn search(
+        &self,
+        filter: &WebhookDeliveryFilter,
+        limit: u64,
+        offset: u64,
+    ) -> AppResult<(Vec<WebhookDelivery>, u64)> {
+        let mut query = webhook_delivery::Entity::find().tenant_scoped();
+        if let Some(webhook_id) = &filter.webhook_id {
+            query = query.filter(webhook_delivery::Column::WebhookId.eq(webhook_id.value()));
+        }
+        if let Some(status) = filter.status {
+            query = query.filter(webhook_delivery::Column::Status.eq(status.value()));
+        }
+        if let Some(event_type) = &filter.event_type {
+            query = query.filter(webhook_delivery::Column::EventType.eq(event_type.as_str()));
+        }
+        let total = query
+            .clone()
+            .count(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Count webhook deliveries failed: {}", e)))?;
+        let models = query
+            .order_by_desc(webhook_delivery::Column::Seq)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List webhook deliveries failed: {}", e)))?;
+        let deliveries = models.into_iter().map(delivery_from_model).collect::<AppResult<Vec<_>>>()?;
+        Ok((deliveries, total))
+    }
+}
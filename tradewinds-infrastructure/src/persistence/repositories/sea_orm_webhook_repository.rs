@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set};
+
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::entities::webhook::Webhook;
+use tradewinds_domain::repositories::WebhookRepository;
+use tradewinds_domain::value_objects::webhook::{WebhookId, WebhookStatus};
+
+use crate::persistence::entities::webhook;
+use crate::persistence::tenant_scope::TenantScoped;
+use tradewinds_error::{AppError, AppResult};
+
+fn webhook_from_model(model: webhook::Model) -> AppResult<Webhook> {
+    Ok(Webhook {
+        id: WebhookId::new(model.id)?,
+        name: model.name,
+        url: model.url,
+        secret: model.secret,
+        event_types: serde_json::from_str(&model.event_types)?,
+        status: WebhookStatus::from_i32(model.status)?,
+        consecutive_failures: model.consecutive_failures.max(0) as u32,
+        disabled_reason: model.disabled_reason,
+        created_at: model.created_at.timestamp(),
+        updated_at: model.updated_at.timestamp(),
+    })
+}
+
+fn webhook_to_active_model(webhook: &Webhook) -> AppResult<webhook::ActiveModel> {
+    let now: DateTime<Utc> = Utc::now();
+    let created_at = DateTime::from_timestamp(webhook.created_at, 0).unwrap_or(now);
+    let updated_at = DateTime::from_timestamp(webhook.updated_at, 0).unwrap_or(now);
+    Ok(webhook::ActiveModel {
+        id: Set(webhook.id.value().to_string()),
+        tenant_id: Set(current_tenant_id()),
+        name: Set(webhook.name.clone()),
+        url: Set(webhook.url.clone()),
+        secret: Set(webhook.secret.clone()),
+        event_types: Set(serde_json::to_string(&webhook.event_types)?),
+        status: Set(webhook.status.value()),
+        consecutive_failures: Set(webhook.consecutive_failures as i32),
+        disabled_reason: Set(webhook.disabled_reason.clone()),
+        created_at: Set(created_at.into()),
+        updated_at: Set(updated_at.into()),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct SeaOrmWebhookRepository {
+    db: DatabaseConnection,
+}
+
+impl SeaOrmWebhookRepository {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for SeaOrmWebhookRepository {
+    async fn create(&self, webhook: &Webhook) -> AppResult<()> {
+        webhook_to_active_model(webhook)?
+            .insert(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Create webhook failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn update(&self, webhook: &Webhook) -> AppResult<()> {
+        webhook::Entity::update(webhook_to_active_model(webhook)?)
+            .tenant_scoped()
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Update webhook failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &WebhookId) -> AppResult<()> {
+        webhook::Entity::delete_many()
+            .filter(webhook::Column::Id.eq(id.value()))
+            .tenant_scoped()
+            .exec(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Delete webhook failed: {}", e)))?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &WebhookId) -> AppResult<Option<Webhook>> {
+        webhook::Entity::find()
+            .tenant_scoped()
+            .filter(webhook::Column::Id.eq(id.value()))
+            .one(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("Find webhook failed: {}", e)))?
+            .map(webhook_from_model)
+            .transpose()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Webhook>> {
+        let models = webhook::Entity::find()
+            .tenant_scoped()
+            .order_by_asc(webhook::Column::CreatedAt)
+            .all(&self.db)
+            .await
+            .map_err(|e| AppError::DatabaseError(format!("List webhooks failed: {}", e)))?;
+        models.into_iter().map(webhook_from_model).collect()
+    }
+}
@@ -0,0 +1,54 @@
+//! 租户范围限定
+//!
+//! 带 `tenant_id` 列的实体实现 `TenantEntity`，仓储对其查询、更新与删除
+//! 统一调用 `tenant_scoped()`，按当前请求的租户过滤；写入时以 `current_tenant_id()` 填充租户列。
+//! 关联表（角色审批人、用户组成员等）同样带租户列，与所属记录一致，不依赖父表过滤。
+//!
+//! 以下为平台级数据，有意不带 `tenant_id`，由仅限平台租户调用的服务或平台内部任务访问：
+//! - `tenants`：租户本身
+//! - `feature_flags`：功能开关，按租户的投放规则保存在开关内
+//! - `scheduled_jobs`、`job_runs`：定时任务及其执行记录
+//! - `token_blacklist`：已注销令牌，jti 全局唯一
+//!
+//! 需要跨租户枚举的后台任务（发件箱中继、审计校验）只查询租户列本身，再逐租户进入 `with_tenant` 处理。
+
+use sea_orm::{ActiveModelTrait, ColumnTrait, DeleteMany, EntityTrait, QueryFilter, Select, UpdateMany, UpdateOne};
+use tradewinds_common::tenant::current_tenant_id;
+
+/// 带租户列的实体
+pub(crate) trait TenantEntity: EntityTrait {
+    fn tenant_column() -> Self::Column;
+}
+
+/// 按当前租户限定范围
+pub(crate) trait TenantScoped {
+    fn tenant_scoped(self) -> Self;
+}
+
+impl<E: TenantEntity> TenantScoped for Select<E> {
+    fn tenant_scoped(self) -> Self {
+        self.filter(E::tenant_column().eq(current_tenant_id()))
+    }
+}
+
+impl<E: TenantEntity> TenantScoped for UpdateMany<E> {
+    fn tenant_scoped(self) -> Self {
+        self.filter(E::tenant_column().eq(current_tenant_id()))
+    }
+}
+
+impl<A> TenantScoped for UpdateOne<A>
+where
+    A: ActiveModelTrait,
+    A::Entity: TenantEntity,
+{
+    fn tenant_scoped(self) -> Self {
+        self.filter(<A::Entity as TenantEntity>::tenant_column().eq(current_tenant_id()))
+    }
+}
+
+impl<E: TenantEntity> TenantScoped for DeleteMany<E> {
+    fn tenant_scoped(self) -> Self {
+        self.filter(E::tenant_column().eq(current_tenant_id()))
+    }
+}
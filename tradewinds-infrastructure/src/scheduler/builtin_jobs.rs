@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use std::sync::Arc;
+use tradewinds_application::CommandHandler;
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_domain::repositories::{JobRunRepository, TenantRepository, TokenBlacklistRepository};
+use tradewinds_error::{AppError, AppResult};
+
+use crate::scheduler::job_scheduler::Job;
+
+/// 清理已过期的令牌黑名单
+pub struct TokenBlacklistCleanupJob {
+    blacklist_repo: Arc<dyn TokenBlacklistRepository>,
+}
+
+impl TokenBlacklistCleanupJob {
+    pub fn new(blacklist_repo: Arc<dyn TokenBlacklistRepository>) -> Self {
+        Self { blacklist_repo }
+    }
+}
+
+#[async_trait]
+impl Job for TokenBlacklistCleanupJob {
+    async fn run(&self) -> AppResult<u64> {
+        self.blacklist_repo.cleanup().await
+    }
+}
+
+/// 逐个租户执行命令，用于按租户隔离的数据
+///
+/// 单个租户失败不影响其余租户，全部执行完后汇总失败的租户
+pub struct TenantCommandJob<C> {
+    tenant_repo: Arc<dyn TenantRepository>,
+    handler: Arc<dyn CommandHandler<C, u64>>,
+    command: C,
+}
+
+impl<C> TenantCommandJob<C> {
+    pub fn new(tenant_repo: Arc<dyn TenantRepository>, handler: Arc<dyn CommandHandler<C, u64>>, command: C) -> Self {
+        Self { tenant_repo, handler, command }
+    }
+}
+
+#[async_trait]
+impl<C: Clone + Send + Sync + 'static> Job for TenantCommandJob<C> {
+    async fn run(&self) -> AppResult<u64> {
+        let mut affected = 0;
+        let mut failures = Vec::new();
+        for tenant_id in self.tenant_repo.find_all_ids().await? {
+            match with_tenant(tenant_id.value().to_string(), self.handler.handle(self.command.clone())).await {
+                Ok(count) => affected += count,
+                Err(e) => failures.push(format!("{}: {}", tenant_id, e)),
+            }
+        }
+        if !failures.is_empty() {
+            return Err(AppError::Internal(format!(
+                "Failed for {} tenant(s) after {} record(s) affected: {}",
+                failures.len(),
+                affected,
+                failures.join("; ")
+            )));
+        }
+        Ok(affected)
+    }
+}
+
+/// 清理超过保留天数的执行记录
+pub struct JobRunRetentionJob {
+    run_repo: Arc<dyn JobRunRepository>,
+    retention_days: i64,
+}
+
+impl JobRunRetentionJob {
+    pub fn new(run_repo: Arc<dyn JobRunRepository>, retention_days: i64) -> Self {
+        Self { run_repo, retention_days }
+    }
+}
+
+#[async_trait]
+impl Job for JobRunRetentionJob {
+    async fn run(&self) -> AppResult<u64> {
+        let before = (Utc::now() - Duration::days(self.retention_days)).timestamp();
+        self.run_repo.delete_before(before).await
+    }
+}
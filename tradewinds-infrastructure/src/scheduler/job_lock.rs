@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use redis::aio::MultiplexedConnection;
+use redis::{Client, Script};
+use std::collections::HashMap;
+use std::sync::{Mutex as StdMutex, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tradewinds_error::{AppError, AppResult};
+use uuid::Uuid;
+
+/// 仍由持有者持有时续期
+const EXTEND_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+/// 仍由持有者持有时释放
+const RELEASE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+/// 已取得的任务锁，释放或续期时校验持有者
+#[derive(Debug)]
+pub struct LockGuard {
+    name: String,
+    token: String,
+}
+
+/// 定时任务锁
+///
+/// 保证同一任务同一时刻只在一个实例执行。锁带过期时间，实例崩溃后自动释放；
+/// 执行时间较长的任务在执行期间定期续期。
+#[async_trait]
+pub trait JobLock: Send + Sync {
+    /// 尝试加锁，已被持有时返回 `None`
+    async fn acquire(&self, name: &str, ttl: Duration) -> AppResult<Option<LockGuard>>;
+
+    /// 续期，锁已过期并被其他持有者取得时返回错误
+    async fn extend(&self, guard: &LockGuard, ttl: Duration) -> AppResult<()>;
+
+    async fn release(&self, guard: LockGuard) -> AppResult<()>;
+}
+
+/// 基于 Redis `SET NX PX` 的分布式锁，多个实例共享
+pub struct RedisJobLock {
+    client: Client,
+    connection: Mutex<Option<MultiplexedConnection>>,
+    key_prefix: String,
+}
+
+impl RedisJobLock {
+    pub fn new(redis_url: &str, key_prefix: &str) -> AppResult<Self> {
+        Ok(Self { client: Client::open(redis_url)?, connection: Mutex::new(None), key_prefix: key_prefix.to_string() })
+    }
+
+    fn key(&self, name: &str) -> String {
+        format!("{}{}", self.key_prefix, name)
+    }
+
+    /// 共享连接，出错后在下次使用时重建
+    async fn connection(&self) -> AppResult<MultiplexedConnection> {
+        let mut connection = self.connection.lock().await;
+        if let Some(conn) = connection.as_ref() {
+            return Ok(conn.clone());
+        }
+        let conn = self.client.get_multiplexed_async_connection().await?;
+        *connection = Some(conn.clone());
+        Ok(conn)
+    }
+
+    async fn on_error(&self, action: &str, name: &str, e: redis::RedisError) -> AppError {
+        self.connection.lock().await.take();
+        AppError::System(format!("Failed to {} lock of job {}: {}", action, name, e))
+    }
+}
+
+#[async_trait]
+impl JobLock for RedisJobLock {
+    async fn acquire(&self, name: &str, ttl: Duration) -> AppResult<Option<LockGuard>> {
+        let token = Uuid::new_v4().to_string();
+        let mut conn = self.connection().await?;
+        let result: redis::RedisResult<Option<String>> = redis::cmd("SET")
+            .arg(self.key(name))
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut conn)
+            .await;
+        match result {
+            Ok(Some(_)) => Ok(Some(LockGuard { name: name.to_string(), token })),
+            Ok(None) => Ok(None),
+            Err(e) => Err(self.on_error("acquire", name, e).await),
+        }
+    }
+
+    async fn extend(&self, guard: &LockGuard, ttl: Duration) -> AppResult<()> {
+        let mut conn = self.connection().await?;
+        let result: redis::RedisResult<i64> = Script::new(EXTEND_SCRIPT)
+            .key(self.key(&guard.name))
+            .arg(&guard.token)
+            .arg(ttl.as_millis() as u64)
+            .invoke_async(&mut conn)
+            .await;
+        match result {
+            Ok(1) => Ok(()),
+            Ok(_) => Err(AppError::Conflict(format!("Lock of job {} has been lost", guard.name))),
+            Err(e) => Err(self.on_error("extend", &guard.name, e).await),
+        }
+    }
+
+    async fn release(&self, guard: LockGuard) -> AppResult<()> {
+        let mut conn = self.connection().await?;
+        let result: redis::RedisResult<i64> =
+            Script::new(RELEASE_SCRIPT).key(self.key(&guard.name)).arg(&guard.token).invoke_async(&mut conn).await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => Err(self.on_error("release", &guard.name, e).await),
+        }
+    }
+}
+
+/// 进程内锁，仅保证单实例内不重复执行，适用于单实例部署或本地开发
+#[derive(Default)]
+pub struct LocalJobLock {
+    /// 任务名称 -> (持有者, 过期时间)
+    locks: StdMutex<HashMap<String, (String, Instant)>>,
+}
+
+impl LocalJobLock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl JobLock for LocalJobLock {
+    async fn acquire(&self, name: &str, ttl: Duration) -> AppResult<Option<LockGuard>> {
+        let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+        let now = Instant::now();
+        if locks.get(name).is_some_and(|(_, expires_at)| *expires_at > now) {
+            return Ok(None);
+        }
+        let token = Uuid::new_v4().to_string();
+        locks.insert(name.to_string(), (token.clone(), now + ttl));
+        Ok(Some(LockGuard { name: name.to_string(), token }))
+    }
+
+    async fn extend(&self, guard: &LockGuard, ttl: Duration) -> AppResult<()> {
+        let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+        match locks.get_mut(&guard.name) {
+            Some((token, expires_at)) if *token == guard.token => {
+                *expires_at = Instant::now() + ttl;
+                Ok(())
+            }
+            _ => Err(AppError::Conflict(format!("Lock of job {} has been lost", guard.name))),
+        }
+    }
+
+    async fn release(&self, guard: LockGuard) -> AppResult<()> {
+        let mut locks = self.locks.lock().unwrap_or_else(PoisonError::into_inner);
+        if locks.get(&guard.name).is_some_and(|(token, _)| *token == guard.token) {
+            locks.remove(&guard.name);
+        }
+        Ok(())
+    }
+}
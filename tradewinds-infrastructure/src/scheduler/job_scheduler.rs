@@ -0,0 +1,231 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use cron::Schedule;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tradewinds_domain::entities::job_run::JobRun;
+use tradewinds_domain::entities::scheduled_job::ScheduledJob;
+use tradewinds_domain::repositories::{JobRunRepository, ScheduledJobRepository};
+use tradewinds_domain::services::JobRunner;
+use tradewinds_domain::value_objects::job::JobTrigger;
+use tradewinds_domain::value_objects::user::UserId;
+use tradewinds_error::{AppError, AppResult};
+
+use crate::scheduler::job_lock::{JobLock, LockGuard};
+
+/// 可调度的任务
+#[async_trait]
+pub trait Job: Send + Sync {
+    /// 执行一次，返回处理的记录数
+    async fn run(&self) -> AppResult<u64>;
+}
+
+/// 已登记的任务
+struct RegisteredJob {
+    name: String,
+    description: String,
+    cron: String,
+    schedule: Schedule,
+    job: Arc<dyn Job>,
+}
+
+impl RegisteredJob {
+    /// `after` 之后的下一次执行时间
+    fn next_run_at(&self, after: DateTime<Utc>) -> Option<i64> {
+        self.schedule.after(&after).next().map(|t| t.timestamp())
+    }
+}
+
+/// 定时任务调度器
+///
+/// 任务在 DI 层通过 `register` 登记，`sync` 将登记信息写入任务表，之后由 `tick` 定期检查到期任务。
+/// 暂停状态与下次执行时间保存在任务表中，多个实例共享；到期任务须先取得任务锁，
+/// 取得后重新确认仍然到期并推进下次执行时间，再在后台执行，因此每个计划时间点只执行一次。
+/// 错过的多个计划时间点（如全部实例停机期间）合并为一次执行。
+#[derive(Clone)]
+pub struct JobScheduler {
+    jobs: Vec<Arc<RegisteredJob>>,
+    job_repo: Arc<dyn ScheduledJobRepository>,
+    run_repo: Arc<dyn JobRunRepository>,
+    lock: Arc<dyn JobLock>,
+    lock_ttl: Duration,
+    instance: String,
+}
+
+impl JobScheduler {
+    pub fn new(
+        job_repo: Arc<dyn ScheduledJobRepository>,
+        run_repo: Arc<dyn JobRunRepository>,
+        lock: Arc<dyn JobLock>,
+        lock_ttl: Duration,
+        instance: String,
+    ) -> Self {
+        Self { jobs: Vec::new(), job_repo, run_repo, lock, lock_ttl, instance }
+    }
+
+    /// 登记任务，cron 表达式为 `秒 分 时 日 月 周`，按 UTC 解析
+    pub fn register(&mut self, name: &str, description: &str, cron: &str, job: Arc<dyn Job>) -> AppResult<()> {
+        if self.find(name).is_some() {
+            return Err(AppError::System(format!("Job {} is already registered", name)));
+        }
+        let schedule = Schedule::from_str(cron)
+            .map_err(|e| AppError::System(format!("Invalid cron expression of job {}: {}", name, e)))?;
+        self.jobs.push(Arc::new(RegisteredJob {
+            name: name.to_string(),
+            description: description.to_string(),
+            cron: cron.to_string(),
+            schedule,
+            job,
+        }));
+        Ok(())
+    }
+
+    fn find(&self, name: &str) -> Option<Arc<RegisteredJob>> {
+        self.jobs.iter().find(|job| job.name == name).cloned()
+    }
+
+    /// 将登记的任务写入任务表，保留已有的暂停状态
+    pub async fn sync(&self) -> AppResult<()> {
+        for job in &self.jobs {
+            match self.job_repo.find_by_name(&job.name).await? {
+                Some(mut existing) => {
+                    if existing.redefine(&job.description, &job.cron) {
+                        self.job_repo.save(&existing).await?;
+                    }
+                }
+                None => {
+                    let registered =
+                        ScheduledJob::register(job.name.clone(), job.description.clone(), job.cron.clone())?;
+                    self.job_repo.save(&registered).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 检查一轮到期任务，到期的任务在后台执行
+    pub async fn tick(&self) -> AppResult<()> {
+        let now = Utc::now();
+        let states: HashMap<String, ScheduledJob> =
+            self.job_repo.find_all().await?.into_iter().map(|job| (job.name.clone(), job)).collect();
+        for job in &self.jobs {
+            let Some(state) = states.get(&job.name).filter(|state| !state.paused) else {
+                continue;
+            };
+            if state.next_run_at.is_none() {
+                self.job_repo.set_next_run_at(&job.name, job.next_run_at(now)).await?;
+            } else if state.is_due(now.timestamp()) {
+                let scheduler = self.clone();
+                let job = job.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = scheduler.run_scheduled(job.clone()).await {
+                        tracing::warn!("Failed to run scheduled job {}: {}", job.name, e);
+                    }
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// 按计划执行；其他实例正在执行或已执行本次计划时跳过
+    async fn run_scheduled(&self, job: Arc<RegisteredJob>) -> AppResult<()> {
+        let Some(guard) = self.lock.acquire(&job.name, self.lock_ttl).await? else {
+            return Ok(());
+        };
+        match self.start_scheduled(&job).await {
+            Ok(Some(run)) => {
+                self.execute(job, run, guard).await;
+                Ok(())
+            }
+            Ok(None) => self.lock.release(guard).await,
+            Err(e) => {
+                self.release(guard).await;
+                Err(e)
+            }
+        }
+    }
+
+    /// 取得锁后重新确认仍然到期，推进下次执行时间并写入执行记录
+    async fn start_scheduled(&self, job: &RegisteredJob) -> AppResult<Option<JobRun>> {
+        let now = Utc::now();
+        let due = self.job_repo.find_by_name(&job.name).await?.is_some_and(|state| state.is_due(now.timestamp()));
+        if !due {
+            return Ok(None);
+        }
+        self.job_repo.set_next_run_at(&job.name, job.next_run_at(now)).await?;
+        let run = JobRun::start(&job.name, JobTrigger::Schedule, None, &self.instance);
+        self.run_repo.create(&run).await?;
+        Ok(Some(run))
+    }
+
+    /// 执行任务并记录结果，执行期间定期续期任务锁，结束后释放
+    async fn execute(&self, job: Arc<RegisteredJob>, mut run: JobRun, guard: LockGuard) {
+        let started = Instant::now();
+        let task = job.job.clone();
+        // 在独立任务中执行，任务 panic 时记录为失败
+        let mut handle = tokio::spawn(async move { task.run().await });
+        let mut renew = tokio::time::interval((self.lock_ttl / 3).max(Duration::from_secs(1)));
+        renew.tick().await;
+        let result = loop {
+            tokio::select! {
+                result = &mut handle => {
+                    break result.unwrap_or_else(|e| Err(AppError::Internal(format!("Job panicked: {}", e))));
+                }
+                _ = renew.tick() => {
+                    if let Err(e) = self.lock.extend(&guard, self.lock_ttl).await {
+                        tracing::warn!("Failed to extend lock of job {}: {}", job.name, e);
+                    }
+                }
+            }
+        };
+
+        let duration_ms = started.elapsed().as_millis() as u64;
+        match result {
+            Ok(affected) => {
+                tracing::info!("Job {} finished in {} ms, {} record(s) affected", job.name, duration_ms, affected);
+                run.succeed(affected, duration_ms);
+            }
+            Err(e) => {
+                tracing::warn!("Job {} failed after {} ms: {}", job.name, duration_ms, e);
+                run.fail(&e.to_string(), duration_ms);
+            }
+        }
+        if let Err(e) = self.run_repo.update(&run).await {
+            tracing::warn!("Failed to save run of job {}: {}", job.name, e);
+        }
+        if let Err(e) = self.job_repo.record_run(&run).await {
+            tracing::warn!("Failed to record last run of job {}: {}", job.name, e);
+        }
+        self.release(guard).await;
+    }
+
+    async fn release(&self, guard: LockGuard) {
+        if let Err(e) = self.lock.release(guard).await {
+            tracing::warn!("Failed to release job lock: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl JobRunner for JobScheduler {
+    async fn run_now(&self, name: &str, triggered_by: Option<UserId>) -> AppResult<JobRun> {
+        let job = self.find(name).ok_or_else(|| AppError::NotFound(format!("Job {} not found", name)))?;
+        let guard = self
+            .lock
+            .acquire(&job.name, self.lock_ttl)
+            .await?
+            .ok_or_else(|| AppError::Conflict(format!("Job {} is already running", name)))?;
+        let run = JobRun::start(&job.name, JobTrigger::Manual, triggered_by, &self.instance);
+        if let Err(e) = self.run_repo.create(&run).await {
+            self.release(guard).await;
+            return Err(e);
+        }
+
+        let scheduler = self.clone();
+        let started = run.clone();
+        tokio::spawn(async move { scheduler.execute(job, started, guard).await });
+        Ok(run)
+    }
+}
@@ -0,0 +1,10 @@
+// 定时任务调度
+// 按 cron 表达式执行登记的任务，多实例部署时由任务锁保证单实例执行
+
+pub mod builtin_jobs;
+pub mod job_lock;
+pub mod job_scheduler;
+
+pub use builtin_jobs::{JobRunRetentionJob, TenantCommandJob, TokenBlacklistCleanupJob};
+pub use job_lock::{JobLock, LocalJobLock, LockGuard, RedisJobLock};
+pub use job_scheduler::{Job, JobScheduler};
@@ -0,0 +1,36 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use tradewinds_domain::services::AuditSigner;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// 基于 HMAC-SHA256 的审计检查点签名
+pub struct HmacAuditSigner {
+    key: Vec<u8>,
+}
+
+impl HmacAuditSigner {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn mac(&self, payload: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("HMAC accepts keys of any length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+impl AuditSigner for HmacAuditSigner {
+    fn sign(&self, payload: &str) -> String {
+        hex::encode(self.mac(payload).finalize().into_bytes())
+    }
+
+    /// 常量时间比较，避免通过耗时推测签名
+    fn verify(&self, payload: &str, signature: &str) -> bool {
+        match hex::decode(signature) {
+            Ok(signature) => self.mac(payload).verify_slice(&signature).is_ok(),
+            Err(_) => false,
+        }
+    }
+}
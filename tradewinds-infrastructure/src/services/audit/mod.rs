@@ -0,0 +1 @@
+pub mod hmac_audit_signer;
@@ -4,7 +4,8 @@ use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, deco
 use serde::{Deserialize, Serialize};
 use tradewinds_domain::repositories::TokenBlacklistRepository;
 use tradewinds_domain::services::auth::{TokenService, token_service::TokenClaims};
-use tradewinds_domain::value_objects::{auth::auth_token::Token, user::UserId};
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::value_objects::{auth::auth_token::Token, tenant::TenantId, user::UserId};
 use tradewinds_error::{AppError, AppResult};
 
 
@@ -13,6 +14,8 @@ use tradewinds_error::{AppError, AppResult};
 struct Claims {
     sub: String, // user_id
     exp: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tid: Option<String>, // tenant_id
 }
 
 /// JWT 实现    
@@ -34,6 +37,7 @@ impl<B: TokenBlacklistRepository + Clone + 'static> TokenService for JwtTokenSer
         let claims = Claims {
             sub: user_id.value().to_string(),
             exp: (Utc::now() + Duration::minutes(self.config.jwt_expiration)).timestamp(),
+            tid: Some(current_tenant_id()),
         };
         let token = encode(&Header::default(), &claims, &EncodingKey::from_secret(self.config.jwt_secret.as_ref()))
             .map_err(|e| AppError::Internal(e.to_string()))?;
@@ -81,4 +85,14 @@ impl<B: TokenBlacklistRepository + Clone + 'static> TokenService for JwtTokenSer
         .map_err(|e| AppError::Authentication(format!("Invalid token: {}", e)))?;
         Ok(UserId::new(token_data.claims.sub)?)
     }
+
+    async fn get_tenant_id_from_token(&self, token: &Token) -> AppResult<Option<TenantId>> {
+        let token_data = decode::<Claims>(
+            token.value(),
+            &DecodingKey::from_secret(self.config.jwt_secret.as_ref()),
+            &Validation::new(Algorithm::HS256),
+        )
+        .map_err(|e| AppError::Authentication(format!("Invalid token: {}", e)))?;
+        token_data.claims.tid.map(TenantId::new).transpose()
+    }
 }
@@ -0,0 +1,102 @@
+//! 缓存命名空间测试
+//!
+//! 覆盖批量读取按请求顺序返回、更换代数后重新回源，以及按租户与全局命名空间的失效范围
+
+mod common;
+
+use common::MemoryCache;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_error::{AppError, AppResult};
+use tradewinds_infrastructure::cache::repositories::CacheNamespace;
+
+const TTL: Duration = Duration::from_secs(60);
+
+fn keys(keys: &[&str]) -> Vec<String> {
+    keys.iter().map(|key| key.to_string()).collect()
+}
+
+/// 读取 `key`，未命中时回源为 `value`，返回读到的值与本次是否回源
+async fn read(namespace: &CacheNamespace, key: &str, value: u32) -> (u32, bool) {
+    let loads = AtomicUsize::new(0);
+    let scope = namespace.scope().await.unwrap();
+    let read = scope
+        .get_or_set(key, TTL, || async {
+            loads.fetch_add(1, Ordering::SeqCst);
+            AppResult::Ok(value)
+        })
+        .await
+        .unwrap();
+    (read, loads.load(Ordering::SeqCst) > 0)
+}
+
+#[tokio::test]
+async fn get_many_or_load_returns_values_in_request_order() {
+    let namespace = CacheNamespace::per_tenant(Arc::new(MemoryCache::default()), "test");
+    let scope = namespace.scope().await.unwrap();
+    scope.set_many(vec![("b".to_string(), 2u32)], TTL).await.unwrap();
+
+    let values = scope
+        .get_many_or_load(&keys(&["c", "b", "a"]), TTL, |missing| async move {
+            assert_eq!(missing, vec![0, 2]);
+            Ok(vec![3u32, 1])
+        })
+        .await
+        .unwrap();
+    assert_eq!(values, vec![3, 2, 1]);
+
+    // 回源结果已写入，再次读取全部命中
+    let values = scope
+        .get_many_or_load::<u32, _, _>(&keys(&["a", "b", "c"]), TTL, |missing| async move {
+            Err(AppError::Internal(format!("unexpected load of {:?}", missing)))
+        })
+        .await
+        .unwrap();
+    assert_eq!(values, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn invalidate_reloads_on_next_read() {
+    let namespace = CacheNamespace::per_tenant(Arc::new(MemoryCache::default()), "test");
+    assert_eq!(read(&namespace, "k", 1).await, (1, true));
+    assert_eq!(read(&namespace, "k", 2).await, (1, false));
+
+    namespace.invalidate().await;
+
+    assert_eq!(read(&namespace, "k", 2).await, (2, true));
+}
+
+#[tokio::test]
+async fn per_tenant_namespace_is_isolated_between_tenants() {
+    let namespace = CacheNamespace::per_tenant(Arc::new(MemoryCache::default()), "test");
+    assert_eq!(with_tenant("a", read(&namespace, "k", 1)).await, (1, true));
+    assert_eq!(with_tenant("b", read(&namespace, "k", 2)).await, (2, true));
+
+    with_tenant("a", namespace.invalidate()).await;
+
+    assert_eq!(with_tenant("a", read(&namespace, "k", 3)).await, (3, true));
+    assert_eq!(with_tenant("b", read(&namespace, "k", 4)).await, (2, false));
+}
+
+#[tokio::test]
+async fn global_namespace_invalidation_applies_to_every_tenant() {
+    let namespace = CacheNamespace::global(Arc::new(MemoryCache::default()), "test");
+    assert_eq!(with_tenant("a", read(&namespace, "k", 1)).await, (1, true));
+    assert_eq!(with_tenant("b", read(&namespace, "k", 2)).await, (2, true));
+
+    with_tenant("a", namespace.invalidate()).await;
+
+    assert_eq!(with_tenant("a", read(&namespace, "k", 3)).await, (3, true));
+    assert_eq!(with_tenant("b", read(&namespace, "k", 4)).await, (4, true));
+}
+
+#[tokio::test]
+async fn unavailable_cache_bypasses_namespace() {
+    let cache = Arc::new(MemoryCache::default());
+    cache.set_unavailable(true);
+    let namespace = CacheNamespace::per_tenant(cache, "test");
+
+    assert!(namespace.scope().await.is_none());
+}
@@ -0,0 +1,492 @@
+//! 带缓存的仓储测试
+//!
+//! 以内存缓存与记录回源次数的内存仓储验证：读取命中缓存、写入后下一次读取取得新值、
+//! 批量查询按请求顺序返回并只回源未命中的条目，以及按租户与全局命名空间的失效范围
+
+mod common;
+
+use async_trait::async_trait;
+use common::MemoryCache;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tradewinds_common::tenant::with_tenant;
+use tradewinds_domain::aggregates::permission_aggregate::PermissionAggregate;
+use tradewinds_domain::aggregates::role_aggregate::RoleAggregate;
+use tradewinds_domain::entities::feature_flag::FeatureFlag;
+use tradewinds_domain::entities::outbox_message::OutboxMessage;
+use tradewinds_domain::entities::permission::Permission;
+use tradewinds_domain::entities::role::Role;
+use tradewinds_domain::entities::system_setting::SystemSetting;
+use tradewinds_domain::policies::PermissionGrant;
+use tradewinds_domain::repositories::system_setting_repository::SystemSettingRepository;
+use tradewinds_domain::repositories::{
+    FeatureFlagRepository, PermissionAggregateRepository, PermissionRepository, RoleAggregateRepository, RoleRepository,
+};
+use tradewinds_domain::value_objects::permission::{
+    PermissionCode, PermissionId, PermissionName, PermissionSort, PermissionStatus, PermissionType,
+};
+use tradewinds_domain::value_objects::role::{RoleCode, RoleId, RoleName};
+use tradewinds_domain::value_objects::system_setting::{SystemSettingId, SystemSettingKey, SystemSettingValue};
+use tradewinds_domain::value_objects::user::UserId;
+use tradewinds_domain::value_objects::{DepartmentId, RoleStatus};
+use tradewinds_error::AppResult;
+use tradewinds_infrastructure::cache::repositories::{
+    CacheNamespace, CachedFeatureFlagRepository, CachedPermissionRepository, CachedRoleRepository,
+    CachedSystemSettingRepository,
+};
+
+const TTL: Duration = Duration::from_secs(60);
+
+fn per_tenant(name: &'static str) -> Arc<CacheNamespace> {
+    Arc::new(CacheNamespace::per_tenant(Arc::new(MemoryCache::default()), name))
+}
+
+fn global(name: &'static str) -> Arc<CacheNamespace> {
+    Arc::new(CacheNamespace::global(Arc::new(MemoryCache::default()), name))
+}
+
+#[derive(Default)]
+struct FlagStore {
+    flags: Mutex<Vec<FeatureFlag>>,
+    reads: AtomicUsize,
+}
+
+#[async_trait]
+impl FeatureFlagRepository for FlagStore {
+    async fn create(&self, flag: &FeatureFlag) -> AppResult<()> {
+        self.flags.lock().unwrap().push(flag.clone());
+        Ok(())
+    }
+
+    async fn update(&self, flag: &FeatureFlag) -> AppResult<()> {
+        for existing in self.flags.lock().unwrap().iter_mut().filter(|f| f.key == flag.key) {
+            *existing = flag.clone();
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> AppResult<()> {
+        self.flags.lock().unwrap().retain(|f| f.key != key);
+        Ok(())
+    }
+
+    async fn find_by_key(&self, key: &str) -> AppResult<Option<FeatureFlag>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.flags.lock().unwrap().iter().find(|f| f.key == key).cloned())
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<FeatureFlag>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.flags.lock().unwrap().clone())
+    }
+}
+
+#[tokio::test]
+async fn feature_flag_write_invalidates_next_read() {
+    let store = Arc::new(FlagStore::default());
+    let repo = CachedFeatureFlagRepository::new(store.clone(), global("feature_flags"), TTL);
+    let mut flag = FeatureFlag::create("new_dashboard".into(), String::new(), true, 0, vec![], vec![]).unwrap();
+    repo.create(&flag).await.unwrap();
+
+    assert!(repo.find_by_key("new_dashboard").await.unwrap().unwrap().enabled);
+    assert!(repo.find_by_key("new_dashboard").await.unwrap().unwrap().enabled);
+    assert_eq!(store.reads.load(Ordering::SeqCst), 1);
+
+    flag.update(None, Some(false), None, None, None).unwrap();
+    repo.update(&flag).await.unwrap();
+
+    assert!(!repo.find_by_key("new_dashboard").await.unwrap().unwrap().enabled);
+    assert_eq!(store.reads.load(Ordering::SeqCst), 2);
+
+    repo.delete("new_dashboard").await.unwrap();
+    assert!(repo.find_by_key("new_dashboard").await.unwrap().is_none());
+    assert!(repo.find_all().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn unavailable_cache_reads_from_the_inner_repository() {
+    let store = Arc::new(FlagStore::default());
+    let cache = Arc::new(MemoryCache::default());
+    let namespace = Arc::new(CacheNamespace::global(cache.clone(), "feature_flags"));
+    let repo = CachedFeatureFlagRepository::new(store.clone(), namespace, TTL);
+    let flag = FeatureFlag::create("new_dashboard".into(), String::new(), true, 0, vec![], vec![]).unwrap();
+    repo.create(&flag).await.unwrap();
+    repo.find_by_key("new_dashboard").await.unwrap();
+
+    cache.set_unavailable(true);
+
+    assert!(repo.find_by_key("new_dashboard").await.unwrap().is_some());
+    assert!(repo.find_by_key("new_dashboard").await.unwrap().is_some());
+    assert_eq!(store.reads.load(Ordering::SeqCst), 3);
+}
+
+#[derive(Default)]
+struct SettingStore {
+    settings: Mutex<Vec<SystemSetting>>,
+    reads: AtomicUsize,
+}
+
+#[async_trait]
+impl SystemSettingRepository for SettingStore {
+    async fn get_by_key(&self, key: &SystemSettingKey) -> AppResult<Option<SystemSetting>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.settings.lock().unwrap().iter().find(|s| &s.key == key).cloned())
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<SystemSetting>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.settings.lock().unwrap().clone())
+    }
+
+    async fn set_value(
+        &self,
+        key: &SystemSettingKey,
+        value: &SystemSettingValue,
+        _events: &[OutboxMessage],
+    ) -> AppResult<()> {
+        for setting in self.settings.lock().unwrap().iter_mut().filter(|s| &s.key == key) {
+            setting.value = value.clone();
+        }
+        Ok(())
+    }
+}
+
+fn setting_key() -> SystemSettingKey {
+    SystemSettingKey::new("site_name".to_string()).unwrap()
+}
+
+fn setting_value(value: &str) -> SystemSettingValue {
+    SystemSettingValue::new(value.to_string()).unwrap()
+}
+
+async fn site_name(repo: &CachedSystemSettingRepository) -> String {
+    repo.get_by_key(&setting_key()).await.unwrap().unwrap().value.to_string()
+}
+
+#[tokio::test]
+async fn system_setting_write_invalidates_every_tenant() {
+    let store = Arc::new(SettingStore::default());
+    store.settings.lock().unwrap().push(SystemSetting {
+        id: SystemSettingId::new("s1".to_string()).unwrap(),
+        key: setting_key(),
+        value: setting_value("Tradewinds"),
+        description: None,
+        updated_at: chrono::Utc::now().naive_utc(),
+    });
+    let repo = CachedSystemSettingRepository::new(store.clone(), global("settings"), TTL);
+
+    assert_eq!(with_tenant("acme", site_name(&repo)).await, "Tradewinds");
+    assert_eq!(with_tenant("acme", site_name(&repo)).await, "Tradewinds");
+    assert_eq!(store.reads.load(Ordering::SeqCst), 1);
+
+    // 平台租户修改默认值，其他租户下一次读取取得新值
+    with_tenant("default", repo.set_value(&setting_key(), &setting_value("Harbor"), &[])).await.unwrap();
+
+    assert_eq!(with_tenant("acme", site_name(&repo)).await, "Harbor");
+    assert_eq!(store.reads.load(Ordering::SeqCst), 2);
+}
+
+/// 角色与权限的内存仓储，记录批量回源时请求的ID
+#[derive(Default)]
+struct AuthzStore {
+    roles: Mutex<Vec<Role>>,
+    permissions: Mutex<Vec<Permission>>,
+    reads: AtomicUsize,
+    loaded_role_ids: Mutex<Vec<Vec<RoleId>>>,
+    loaded_permission_ids: Mutex<Vec<Vec<PermissionId>>>,
+}
+
+impl AuthzStore {
+    fn add_role(&self, code: &str) -> RoleId {
+        let role = Role::create(
+            RoleId::new_v4(),
+            RoleCode::new(code.to_string()).unwrap(),
+            RoleName::new(code).unwrap(),
+            None,
+            RoleStatus::Active,
+            0,
+            0,
+        );
+        let id = role.id.clone();
+        self.roles.lock().unwrap().push(role);
+        id
+    }
+
+    fn add_permission(&self, name: &str) -> PermissionId {
+        let permission = Permission::create(
+            PermissionName::new(name).unwrap(),
+            None,
+            PermissionType::Api,
+            None,
+            None,
+            None,
+            None,
+            PermissionSort::new(0).unwrap(),
+        )
+        .unwrap();
+        let id = permission.id.clone();
+        self.permissions.lock().unwrap().push(permission);
+        id
+    }
+}
+
+#[async_trait]
+impl RoleRepository for AuthzStore {
+    async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<Role>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.roles.lock().unwrap().iter().find(|r| &r.id == id).cloned())
+    }
+
+    async fn find_by_name(&self, _name: &RoleName) -> AppResult<Option<Role>> {
+        unimplemented!()
+    }
+
+    async fn find_by_code(&self, _code: &RoleCode) -> AppResult<Option<Role>> {
+        unimplemented!()
+    }
+
+    async fn find_by_ids(&self, ids: &[RoleId]) -> AppResult<Vec<Role>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.loaded_role_ids.lock().unwrap().push(ids.to_vec());
+        // 与数据库一样不保证按请求顺序返回
+        Ok(self.roles.lock().unwrap().iter().filter(|r| ids.contains(&r.id)).cloned().collect())
+    }
+
+    async fn exists_by_id(&self, _id: &RoleId) -> AppResult<bool> {
+        unimplemented!()
+    }
+
+    async fn find_with_permissions(&self, _id: &RoleId) -> AppResult<Option<(Role, Vec<PermissionId>)>> {
+        unimplemented!()
+    }
+
+    async fn find_permissions(&self, _id: &RoleId) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_permissions_by_ids(&self, _ids: &[RoleId]) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_permission_grants(&self, _ids: &[RoleId]) -> AppResult<Vec<PermissionGrant>> {
+        unimplemented!()
+    }
+
+    async fn find_data_scope_departments(&self, _ids: &[RoleId]) -> AppResult<Vec<DepartmentId>> {
+        unimplemented!()
+    }
+
+    async fn search(
+        &self,
+        _name: Option<&RoleName>,
+        _code: Option<&str>,
+        _status: Option<i32>,
+        _show_deleted: Option<bool>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Role>, u64)> {
+        unimplemented!()
+    }
+}
+
+#[async_trait]
+impl RoleAggregateRepository for AuthzStore {
+    async fn create(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        self.roles.lock().unwrap().push(aggregate.role.clone());
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        for role in self.roles.lock().unwrap().iter_mut().filter(|r| r.id == aggregate.role.id) {
+            *role = aggregate.role.clone();
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &RoleId) -> AppResult<Option<RoleAggregate>> {
+        let role = self.roles.lock().unwrap().iter().find(|r| &r.id == id).cloned();
+        Ok(role.map(|role| RoleAggregate::from_existing(role, vec![], vec![], vec![])))
+    }
+
+    async fn delete(&self, aggregate: &RoleAggregate) -> AppResult<()> {
+        self.roles.lock().unwrap().retain(|r| r.id != aggregate.role.id);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PermissionRepository for AuthzStore {
+    async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<Permission>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.permissions.lock().unwrap().iter().find(|p| &p.id == id).cloned())
+    }
+
+    async fn find_by_name(&self, _name: &PermissionName) -> AppResult<Option<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_by_code(&self, _code: &PermissionCode) -> AppResult<Option<Permission>> {
+        unimplemented!()
+    }
+
+    async fn find_by_ids(&self, ids: &[PermissionId]) -> AppResult<Vec<Permission>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.loaded_permission_ids.lock().unwrap().push(ids.to_vec());
+        Ok(self.permissions.lock().unwrap().iter().filter(|p| ids.contains(&p.id)).cloned().collect())
+    }
+
+    async fn find_by_user_id(&self, _user_id: &UserId) -> AppResult<Vec<Permission>> {
+        unimplemented!()
+    }
+
+    async fn search(
+        &self,
+        _name: Option<&PermissionName>,
+        _code: Option<&PermissionCode>,
+        _permission_type: Option<&PermissionType>,
+        _status: Option<PermissionStatus>,
+        _show_deleted: Option<bool>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Permission>, u64)> {
+        unimplemented!()
+    }
+
+    async fn find_all(&self) -> AppResult<Vec<Permission>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        Ok(self.permissions.lock().unwrap().clone())
+    }
+}
+
+#[async_trait]
+impl PermissionAggregateRepository for AuthzStore {
+    async fn create(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        self.permissions.lock().unwrap().push(aggregate.permission.clone());
+        Ok(())
+    }
+
+    async fn save(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        for permission in self.permissions.lock().unwrap().iter_mut().filter(|p| p.id == aggregate.permission.id) {
+            *permission = aggregate.permission.clone();
+        }
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: &PermissionId) -> AppResult<Option<PermissionAggregate>> {
+        let permission = self.permissions.lock().unwrap().iter().find(|p| &p.id == id).cloned();
+        Ok(permission.map(PermissionAggregate::from_existing))
+    }
+
+    async fn delete(&self, aggregate: &PermissionAggregate) -> AppResult<()> {
+        self.permissions.lock().unwrap().retain(|p| p.id != aggregate.permission.id);
+        Ok(())
+    }
+}
+
+/// 角色与权限仓储共用同一个 authz 命名空间
+fn authz_repositories(store: &Arc<AuthzStore>) -> (CachedRoleRepository, CachedPermissionRepository) {
+    let namespace = per_tenant("authz");
+    let roles = CachedRoleRepository::new(store.clone(), store.clone(), namespace.clone(), TTL);
+    let permissions = CachedPermissionRepository::new(store.clone(), store.clone(), namespace, TTL);
+    (roles, permissions)
+}
+
+fn codes(roles: &[Role]) -> Vec<String> {
+    roles.iter().map(|role| role.code.to_string()).collect()
+}
+
+#[tokio::test]
+async fn role_find_by_ids_returns_request_order_and_loads_only_misses() {
+    let store = Arc::new(AuthzStore::default());
+    let first = store.add_role("first");
+    let second = store.add_role("second");
+    let third = store.add_role("third");
+    let (roles, _) = authz_repositories(&store);
+
+    RoleRepository::find_by_id(&roles, &second).await.unwrap();
+
+    let found = roles.find_by_ids(&[third.clone(), second.clone(), first.clone(), third.clone()]).await.unwrap();
+    assert_eq!(codes(&found), vec!["third", "second", "first"]);
+    assert_eq!(store.loaded_role_ids.lock().unwrap().last().unwrap(), &vec![third.clone(), first.clone()]);
+
+    let found = roles.find_by_ids(&[first, second, third]).await.unwrap();
+    assert_eq!(codes(&found), vec!["first", "second", "third"]);
+    assert_eq!(store.loaded_role_ids.lock().unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn role_write_invalidates_next_read() {
+    let store = Arc::new(AuthzStore::default());
+    let id = store.add_role("auditor");
+    let (roles, _) = authz_repositories(&store);
+
+    let role = RoleRepository::find_by_id(&roles, &id).await.unwrap().unwrap();
+    assert_eq!(role.name.value(), "auditor");
+
+    let mut aggregate = RoleAggregateRepository::find_by_id(&roles, &id).await.unwrap().unwrap();
+    aggregate.role.set_name(RoleName::new("Auditor").unwrap());
+    RoleAggregateRepository::save(&roles, &aggregate).await.unwrap();
+
+    let role = RoleRepository::find_by_id(&roles, &id).await.unwrap().unwrap();
+    assert_eq!(role.name.value(), "Auditor");
+
+    RoleAggregateRepository::delete(&roles, &aggregate).await.unwrap();
+    assert!(RoleRepository::find_by_id(&roles, &id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn role_cache_is_kept_per_tenant() {
+    let store = Arc::new(AuthzStore::default());
+    let id = store.add_role("auditor");
+    let (roles, _) = authz_repositories(&store);
+
+    with_tenant("a", RoleRepository::find_by_id(&roles, &id)).await.unwrap();
+    with_tenant("a", RoleRepository::find_by_id(&roles, &id)).await.unwrap();
+    assert_eq!(store.reads.load(Ordering::SeqCst), 1);
+
+    // 其他租户不读取租户 a 的缓存，租户 a 的写入也不使其他租户失效
+    with_tenant("b", RoleRepository::find_by_id(&roles, &id)).await.unwrap();
+    assert_eq!(store.reads.load(Ordering::SeqCst), 2);
+
+    let aggregate = RoleAggregateRepository::find_by_id(&roles, &id).await.unwrap().unwrap();
+    with_tenant("a", RoleAggregateRepository::save(&roles, &aggregate)).await.unwrap();
+    with_tenant("b", RoleRepository::find_by_id(&roles, &id)).await.unwrap();
+    assert_eq!(store.reads.load(Ordering::SeqCst), 2);
+    with_tenant("a", RoleRepository::find_by_id(&roles, &id)).await.unwrap();
+    assert_eq!(store.reads.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn permission_find_by_ids_returns_request_order_and_loads_only_misses() {
+    let store = Arc::new(AuthzStore::default());
+    let read = store.add_permission("read");
+    let write = store.add_permission("write");
+    let (_, permissions) = authz_repositories(&store);
+
+    PermissionRepository::find_by_id(&permissions, &write).await.unwrap();
+
+    let found = permissions.find_by_ids(&[write.clone(), read.clone()]).await.unwrap();
+    let names: Vec<&str> = found.iter().map(|p| p.name.value()).collect();
+    assert_eq!(names, vec!["write", "read"]);
+    assert_eq!(store.loaded_permission_ids.lock().unwrap().clone(), vec![vec![read]]);
+}
+
+#[tokio::test]
+async fn permission_write_also_invalidates_cached_roles() {
+    let store = Arc::new(AuthzStore::default());
+    let role_id = store.add_role("auditor");
+    let permission_id = store.add_permission("read");
+    let (roles, permissions) = authz_repositories(&store);
+
+    RoleRepository::find_by_id(&roles, &role_id).await.unwrap();
+    permissions.find_all().await.unwrap();
+    assert_eq!(store.reads.load(Ordering::SeqCst), 2);
+
+    let aggregate = PermissionAggregateRepository::find_by_id(&permissions, &permission_id).await.unwrap().unwrap();
+    PermissionAggregateRepository::save(&permissions, &aggregate).await.unwrap();
+
+    RoleRepository::find_by_id(&roles, &role_id).await.unwrap();
+    permissions.find_all().await.unwrap();
+    assert_eq!(store.reads.load(Ordering::SeqCst), 4);
+}
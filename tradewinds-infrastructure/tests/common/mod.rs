@@ -0,0 +1,86 @@
+//! 基础设施测试共用的内存缓存
+//!
+//! `MemoryCache` 以 `HashMap` 实现缓存接口，不处理过期时间；置为不可用后所有操作返回错误，模拟 Redis 故障。
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tradewinds_error::{AppError, AppResult};
+use tradewinds_infrastructure::cache::{Cache, CacheLoader, CacheMetricsSnapshot};
+
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, String>>,
+    unavailable: AtomicBool,
+}
+
+impl MemoryCache {
+    pub fn set_unavailable(&self, unavailable: bool) {
+        self.unavailable.store(unavailable, Ordering::SeqCst);
+    }
+
+    fn check(&self) -> AppResult<()> {
+        if self.unavailable.load(Ordering::SeqCst) {
+            return Err(AppError::Internal("cache unavailable".into()));
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Cache for MemoryCache {
+    async fn get_raw(&self, key: &str) -> AppResult<Option<String>> {
+        self.check()?;
+        Ok(self.entries.lock().unwrap().get(key).cloned())
+    }
+
+    async fn mget_raw(&self, keys: &[String]) -> AppResult<Vec<Option<String>>> {
+        self.check()?;
+        let entries = self.entries.lock().unwrap();
+        Ok(keys.iter().map(|key| entries.get(key).cloned()).collect())
+    }
+
+    async fn set_raw(&self, key: &str, value: String, _ttl: Duration) -> AppResult<()> {
+        self.check()?;
+        self.entries.lock().unwrap().insert(key.to_string(), value);
+        Ok(())
+    }
+
+    async fn mset_raw(&self, entries: Vec<(String, String)>, _ttl: Duration) -> AppResult<()> {
+        self.check()?;
+        self.entries.lock().unwrap().extend(entries);
+        Ok(())
+    }
+
+    async fn delete_many(&self, keys: &[String]) -> AppResult<()> {
+        self.check()?;
+        let mut entries = self.entries.lock().unwrap();
+        for key in keys {
+            entries.remove(key);
+        }
+        Ok(())
+    }
+
+    async fn get_or_set_raw<'a>(
+        &'a self,
+        key: &'a str,
+        _ttl: Duration,
+        loader: &'a (dyn Fn() -> CacheLoader<'a> + Send + Sync),
+    ) -> AppResult<String> {
+        if self.check().is_err() {
+            return loader().await;
+        }
+        if let Some(value) = self.entries.lock().unwrap().get(key).cloned() {
+            return Ok(value);
+        }
+        let value = loader().await?;
+        self.entries.lock().unwrap().insert(key.to_string(), value.clone());
+        Ok(value)
+    }
+
+    fn metrics(&self) -> CacheMetricsSnapshot {
+        CacheMetricsSnapshot::default()
+    }
+}
@@ -0,0 +1,162 @@
+//! 进程内事件总线测试
+//!
+//! 覆盖按类型分发、订阅全部事件、订阅者失败与 panic 的隔离，以及租户上下文的传递
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tradewinds_common::tenant::{current_tenant_id, with_tenant};
+use tradewinds_domain::services::{Event, EventBus, EventHandler};
+use tradewinds_error::{AppError, AppResult};
+use tradewinds_infrastructure::event_bus::InProcessEventBus;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PingEvent {
+    value: u32,
+}
+
+impl Event for PingEvent {
+    fn event_type(&self) -> &'static str {
+        "test.ping"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PongEvent {
+    value: u32,
+}
+
+impl Event for PongEvent {
+    fn event_type(&self) -> &'static str {
+        "test.pong"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// 统计收到的事件数
+#[derive(Clone, Default)]
+struct Counter(Arc<AtomicUsize>);
+
+impl Counter {
+    fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl<E: Event + ?Sized> EventHandler<E> for Counter {
+    async fn handle(&self, _event: &E) -> AppResult<()> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+/// 记录收到的 Ping 值与当时的租户
+#[derive(Clone, Default)]
+struct PingRecorder(Arc<Mutex<Vec<(u32, String)>>>);
+
+#[async_trait]
+impl EventHandler<PingEvent> for PingRecorder {
+    async fn handle(&self, event: &PingEvent) -> AppResult<()> {
+        self.0.lock().unwrap().push((event.value, current_tenant_id()));
+        Ok(())
+    }
+}
+
+struct Failing;
+
+#[async_trait]
+impl EventHandler<PingEvent> for Failing {
+    async fn handle(&self, _event: &PingEvent) -> AppResult<()> {
+        Err(AppError::Internal("subscriber failed".into()))
+    }
+}
+
+struct Panicking;
+
+#[async_trait]
+impl EventHandler<PingEvent> for Panicking {
+    async fn handle(&self, _event: &PingEvent) -> AppResult<()> {
+        panic!("subscriber panicked")
+    }
+}
+
+#[tokio::test]
+async fn events_are_dispatched_by_concrete_type() {
+    let bus = InProcessEventBus::new();
+    let pings = Counter::default();
+    let pongs = Counter::default();
+    let all = Counter::default();
+    bus.subscribe::<PingEvent, _>("pings", pings.clone());
+    bus.subscribe::<PongEvent, _>("pongs", pongs.clone());
+    bus.subscribe_all("all", all.clone());
+
+    bus.publish(Arc::new(PingEvent { value: 1 })).await.unwrap();
+    bus.publish(Arc::new(PingEvent { value: 2 })).await.unwrap();
+    bus.publish(Arc::new(PongEvent { value: 3 })).await.unwrap();
+
+    assert_eq!(pings.count(), 2);
+    assert_eq!(pongs.count(), 1);
+    assert_eq!(all.count(), 3);
+}
+
+#[tokio::test]
+async fn publishing_without_subscribers_succeeds() {
+    let bus = InProcessEventBus::new();
+    let pongs = Counter::default();
+    bus.subscribe::<PongEvent, _>("pongs", pongs.clone());
+
+    bus.publish(Arc::new(PingEvent { value: 1 })).await.unwrap();
+
+    assert_eq!(pongs.count(), 0);
+}
+
+#[tokio::test]
+async fn failing_subscriber_fails_the_publish_but_not_the_others() {
+    let bus = InProcessEventBus::new();
+    let pings = Counter::default();
+    bus.subscribe::<PingEvent, _>("failing", Failing);
+    bus.subscribe::<PingEvent, _>("pings", pings.clone());
+
+    let result = bus.publish(Arc::new(PingEvent { value: 1 })).await;
+
+    assert!(matches!(result, Err(AppError::Internal(_))));
+    assert_eq!(pings.count(), 1);
+}
+
+#[tokio::test]
+async fn panicking_subscriber_is_isolated() {
+    let bus = InProcessEventBus::new();
+    let pings = Counter::default();
+    bus.subscribe::<PingEvent, _>("panicking", Panicking);
+    bus.subscribe::<PingEvent, _>("pings", pings.clone());
+
+    let result = bus.publish(Arc::new(PingEvent { value: 1 })).await;
+
+    assert!(result.is_err());
+    assert_eq!(pings.count(), 1);
+
+    // 总线在订阅者 panic 后仍可继续使用
+    let result = bus.publish(Arc::new(PingEvent { value: 2 })).await;
+    assert!(result.is_err());
+    assert_eq!(pings.count(), 2);
+}
+
+#[tokio::test]
+async fn subscribers_run_in_the_publisher_tenant() {
+    let bus = InProcessEventBus::new();
+    let recorder = PingRecorder::default();
+    bus.subscribe::<PingEvent, _>("recorder", recorder.clone());
+
+    with_tenant("tenant-a", bus.publish(Arc::new(PingEvent { value: 7 }))).await.unwrap();
+
+    assert_eq!(*recorder.0.lock().unwrap(), vec![(7, "tenant-a".to_string())]);
+}
@@ -0,0 +1,108 @@
+//! 进程内缓存层测试
+//!
+//! 覆盖按容量淘汰最久未使用的条目、读取刷新使用顺序、保留时间受上限约束，以及删除与清空
+
+use std::thread::sleep;
+use std::time::Duration;
+use tradewinds_infrastructure::cache::LocalCache;
+
+const HOUR: Duration = Duration::from_secs(3600);
+
+fn value(s: &str) -> String {
+    s.to_string()
+}
+
+#[test]
+fn evicts_least_recently_inserted_entry_when_full() {
+    let cache = LocalCache::new(2, HOUR);
+    cache.insert("a", value("1"), HOUR);
+    cache.insert("b", value("2"), HOUR);
+    cache.insert("c", value("3"), HOUR);
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get("a"), None);
+    assert_eq!(cache.get("b"), Some(value("2")));
+    assert_eq!(cache.get("c"), Some(value("3")));
+}
+
+#[test]
+fn reading_an_entry_keeps_it_from_eviction() {
+    let cache = LocalCache::new(2, HOUR);
+    cache.insert("a", value("1"), HOUR);
+    cache.insert("b", value("2"), HOUR);
+
+    assert_eq!(cache.get("a"), Some(value("1")));
+    cache.insert("c", value("3"), HOUR);
+
+    assert_eq!(cache.get("a"), Some(value("1")));
+    assert_eq!(cache.get("b"), None);
+    assert_eq!(cache.get("c"), Some(value("3")));
+}
+
+#[test]
+fn overwriting_an_entry_refreshes_its_recency() {
+    let cache = LocalCache::new(2, HOUR);
+    cache.insert("a", value("1"), HOUR);
+    cache.insert("b", value("2"), HOUR);
+    cache.insert("a", value("10"), HOUR);
+    cache.insert("c", value("3"), HOUR);
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get("a"), Some(value("10")));
+    assert_eq!(cache.get("b"), None);
+}
+
+#[test]
+fn ttl_is_capped_by_max_ttl() {
+    let cache = LocalCache::new(10, Duration::from_millis(20));
+    cache.insert("a", value("1"), HOUR);
+    assert_eq!(cache.get("a"), Some(value("1")));
+
+    sleep(Duration::from_millis(50));
+
+    assert_eq!(cache.get("a"), None);
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn shorter_ttl_than_max_is_kept() {
+    let cache = LocalCache::new(10, HOUR);
+    cache.insert("short", value("1"), Duration::from_millis(20));
+    cache.insert("long", value("2"), HOUR);
+
+    sleep(Duration::from_millis(50));
+
+    assert_eq!(cache.get("short"), None);
+    assert_eq!(cache.get("long"), Some(value("2")));
+}
+
+#[test]
+fn remove_and_clear_drop_entries() {
+    let cache = LocalCache::new(10, HOUR);
+    cache.insert("a", value("1"), HOUR);
+    cache.insert("b", value("2"), HOUR);
+    cache.insert("c", value("3"), HOUR);
+
+    cache.remove("a");
+    cache.remove("missing");
+    assert_eq!(cache.get("a"), None);
+    assert_eq!(cache.len(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.get("b"), None);
+
+    // 清空后仍按容量正常工作
+    cache.insert("d", value("4"), HOUR);
+    assert_eq!(cache.get("d"), Some(value("4")));
+}
+
+#[test]
+fn zero_capacity_keeps_one_entry() {
+    let cache = LocalCache::new(0, HOUR);
+    cache.insert("a", value("1"), HOUR);
+    cache.insert("b", value("2"), HOUR);
+
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.get("b"), Some(value("2")));
+}
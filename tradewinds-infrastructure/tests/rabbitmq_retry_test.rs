@@ -0,0 +1,55 @@
+//! RabbitMQ 失败消息去向测试
+//!
+//! 覆盖按重试次数消息头决定重试或转入死信，以及重试队列、死信队列的命名
+
+use lapin::BasicProperties;
+use lapin::types::{AMQPValue, FieldTable};
+use tradewinds_infrastructure::messaging::rabbitmq::RETRY_COUNT_HEADER;
+use tradewinds_infrastructure::messaging::{FailureAction, QueueBinding, failure_action};
+
+fn with_retry_header(value: AMQPValue) -> BasicProperties {
+    let mut headers = FieldTable::default();
+    headers.insert(RETRY_COUNT_HEADER.into(), value);
+    BasicProperties::default().with_headers(headers)
+}
+
+#[test]
+fn first_failure_is_retried_once() {
+    assert_eq!(failure_action(&BasicProperties::default(), 3), FailureAction::Retry(1));
+}
+
+#[test]
+fn retry_count_increments_until_the_limit() {
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongUInt(1)), 3), FailureAction::Retry(2));
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongUInt(2)), 3), FailureAction::Retry(3));
+}
+
+#[test]
+fn message_is_dead_lettered_after_max_retries() {
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongUInt(3)), 3), FailureAction::DeadLetter);
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongUInt(10)), 3), FailureAction::DeadLetter);
+}
+
+#[test]
+fn zero_max_retries_dead_letters_immediately() {
+    assert_eq!(failure_action(&BasicProperties::default(), 0), FailureAction::DeadLetter);
+}
+
+#[test]
+fn retry_header_written_by_other_clients_is_accepted() {
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongInt(2)), 3), FailureAction::Retry(3));
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongLongInt(3)), 3), FailureAction::DeadLetter);
+}
+
+#[test]
+fn invalid_retry_header_counts_as_no_retries() {
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongInt(-1)), 3), FailureAction::Retry(1));
+    assert_eq!(failure_action(&with_retry_header(AMQPValue::LongString("2".into())), 3), FailureAction::Retry(1));
+}
+
+#[test]
+fn retry_and_dead_letter_queues_are_named_after_the_queue() {
+    let binding = QueueBinding::new("events.audit", &["user.created"]);
+    assert_eq!(binding.retry_queue(), "events.audit.retry");
+    assert_eq!(binding.dead_letter_queue(), "events.audit.dlq");
+}
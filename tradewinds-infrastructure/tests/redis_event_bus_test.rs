@@ -0,0 +1,204 @@
+//! Redis Streams 事件总线集成测试
+//!
+//! 需要本地 Redis（默认 redis://127.0.0.1:6379，可通过 REDIS_URL 指定），默认忽略，运行方式：
+//! `cargo test -p tradewinds-infrastructure --test redis_event_bus_test -- --ignored`
+
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tradewinds_common::tenant::{current_tenant_id, with_tenant};
+use tradewinds_domain::services::{Event, EventBus, EventHandler, EventRegistry};
+use tradewinds_error::{AppError, AppResult};
+use tradewinds_infrastructure::config::RedisStreamsConfig;
+use tradewinds_infrastructure::event_bus::RedisEventBus;
+
+const RECEIVE_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PingEvent {
+    value: u32,
+}
+
+impl Event for PingEvent {
+    fn event_type(&self) -> &'static str {
+        "test.ping"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct PongEvent {
+    value: u32,
+}
+
+impl Event for PongEvent {
+    fn event_type(&self) -> &'static str {
+        "test.pong"
+    }
+
+    fn to_json(&self) -> AppResult<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// 把收到的事件值与租户转发到通道
+struct PingRecorder(mpsc::UnboundedSender<(u32, String)>);
+
+#[async_trait]
+impl EventHandler<PingEvent> for PingRecorder {
+    async fn handle(&self, event: &PingEvent) -> AppResult<()> {
+        let _ = self.0.send((event.value, current_tenant_id()));
+        Ok(())
+    }
+}
+
+/// 记录收到的所有事件类型
+struct AllRecorder(mpsc::UnboundedSender<&'static str>);
+
+#[async_trait]
+impl EventHandler<dyn Event> for AllRecorder {
+    async fn handle(&self, event: &dyn Event) -> AppResult<()> {
+        let _ = self.0.send(event.event_type());
+        Ok(())
+    }
+}
+
+/// 第一次处理失败，之后成功
+struct FlakyRecorder {
+    attempts: AtomicUsize,
+    sender: mpsc::UnboundedSender<usize>,
+}
+
+#[async_trait]
+impl EventHandler<PingEvent> for FlakyRecorder {
+    async fn handle(&self, _event: &PingEvent) -> AppResult<()> {
+        let attempt = self.attempts.fetch_add(1, Ordering::SeqCst) + 1;
+        let _ = self.sender.send(attempt);
+        if attempt == 1 { Err(AppError::Internal("transient failure".to_string())) } else { Ok(()) }
+    }
+}
+
+fn redis_url() -> String {
+    std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string())
+}
+
+fn registry() -> EventRegistry {
+    let mut registry = EventRegistry::new();
+    registry.register::<PingEvent>("test.ping").register::<PongEvent>("test.pong");
+    registry
+}
+
+/// 每个测试使用独立的流前缀，避免相互干扰
+fn config(max_len: usize, reclaim_idle_ms: u64) -> RedisStreamsConfig {
+    RedisStreamsConfig {
+        stream_prefix: format!("tradewinds:test:{}:", uuid::Uuid::new_v4()),
+        max_len,
+        consumer_name: "test-consumer".to_string(),
+        batch_size: 10,
+        block_ms: 100,
+        reclaim_idle_ms,
+        max_deliveries: 5,
+    }
+}
+
+fn bus(config: RedisStreamsConfig) -> Arc<RedisEventBus> {
+    Arc::new(RedisEventBus::new(&redis_url(), config, registry()).expect("invalid REDIS_URL"))
+}
+
+/// 等待消费组建立，之后发布的消息才会被消费
+async fn wait_for_groups() {
+    tokio::time::sleep(Duration::from_millis(300)).await;
+}
+
+async fn receive<T>(receiver: &mut mpsc::UnboundedReceiver<T>) -> T {
+    tokio::time::timeout(RECEIVE_TIMEOUT, receiver.recv())
+        .await
+        .expect("timed out waiting for event")
+        .expect("channel closed")
+}
+
+#[tokio::test]
+#[ignore = "requires a local Redis"]
+async fn delivers_events_to_typed_subscriber_with_tenant() {
+    let bus = bus(config(1000, 60_000));
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    bus.subscribe("ping-recorder", PingRecorder(sender));
+    bus.start();
+    wait_for_groups().await;
+
+    with_tenant("tenant-a", bus.publish(Arc::new(PingEvent { value: 7 }))).await.unwrap();
+    bus.publish(Arc::new(PongEvent { value: 8 })).await.unwrap();
+
+    assert_eq!(receive(&mut receiver).await, (7, "tenant-a".to_string()));
+    // 未订阅的事件类型不会投递给该订阅者
+    assert!(tokio::time::timeout(Duration::from_millis(500), receiver.recv()).await.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires a local Redis"]
+async fn subscribe_all_receives_every_registered_event_type() {
+    let bus = bus(config(1000, 60_000));
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    bus.subscribe_all("all-recorder", AllRecorder(sender));
+    bus.start();
+    wait_for_groups().await;
+
+    bus.publish(Arc::new(PingEvent { value: 1 })).await.unwrap();
+    bus.publish(Arc::new(PongEvent { value: 2 })).await.unwrap();
+
+    let mut received = vec![receive(&mut receiver).await, receive(&mut receiver).await];
+    received.sort_unstable();
+    assert_eq!(received, vec!["test.ping", "test.pong"]);
+}
+
+#[tokio::test]
+#[ignore = "requires a local Redis"]
+async fn failed_events_are_reclaimed_and_redelivered() {
+    let bus = bus(config(1000, 200));
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    bus.subscribe("flaky-recorder", FlakyRecorder { attempts: AtomicUsize::new(0), sender });
+    bus.start();
+    wait_for_groups().await;
+
+    bus.publish(Arc::new(PingEvent { value: 1 })).await.unwrap();
+
+    assert_eq!(receive(&mut receiver).await, 1);
+    assert_eq!(receive(&mut receiver).await, 2);
+    // 第二次处理成功后已确认，不再重新投递
+    assert!(tokio::time::timeout(Duration::from_millis(800), receiver.recv()).await.is_err());
+}
+
+#[tokio::test]
+#[ignore = "requires a local Redis"]
+async fn streams_are_trimmed_to_max_len() {
+    let config = config(10, 60_000);
+    let stream = format!("{}test.ping", config.stream_prefix);
+    let bus = bus(config);
+
+    for value in 0..1000 {
+        bus.publish(Arc::new(PingEvent { value })).await.unwrap();
+    }
+
+    let client = redis::Client::open(redis_url()).unwrap();
+    let mut conn = client.get_multiplexed_async_connection().await.unwrap();
+    let len: usize = conn.xlen(&stream).await.unwrap();
+    // 近似裁剪按节点回收，长度会略大于上限但远小于写入总数
+    assert!(len < 1000, "stream length {} was not trimmed", len);
+    let _: () = conn.del(&stream).await.unwrap();
+}
+
+#[test]
+#[should_panic(expected = "already registered")]
+fn subscription_names_must_be_unique() {
+    let bus = bus(config(1000, 60_000));
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    bus.subscribe_all("recorder", AllRecorder(sender.clone()));
+    bus.subscribe_all("recorder", AllRecorder(sender));
+}
@@ -0,0 +1,198 @@
+//! 按租户执行的定时任务测试
+//!
+//! 覆盖调度器登记的权限申请过期与访问复核关闭任务逐个租户调用应用服务、汇总处理数，以及单个租户失败不影响其余租户
+
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+use tradewinds_application::commands::access_request::*;
+use tradewinds_application::commands::access_review::*;
+use tradewinds_application::interfaces::{IAccessRequestService, IAccessReviewService};
+use tradewinds_application::queries::access_request::*;
+use tradewinds_application::queries::access_review::*;
+use tradewinds_common::PaginatedResult;
+use tradewinds_common::tenant::current_tenant_id;
+use tradewinds_domain::aggregates::access_review_aggregate::AccessReviewAggregate;
+use tradewinds_domain::entities::access_review::AccessReviewItem;
+use tradewinds_domain::entities::tenant::Tenant;
+use tradewinds_domain::entities::{access_request::AccessRequest, outbox_message::OutboxMessage, user::User};
+use tradewinds_domain::repositories::TenantRepository;
+use tradewinds_domain::value_objects::{TenantCode, TenantHost, TenantId, TenantStatus};
+use tradewinds_error::{AppError, AppResult};
+use tradewinds_infrastructure::scheduler::{Job, TenantCommandJob};
+
+struct Tenants(Vec<&'static str>);
+
+#[async_trait]
+impl TenantRepository for Tenants {
+    async fn create(&self, _tenant: &Tenant, _events: &[OutboxMessage]) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn save(&self, _tenant: &Tenant, _events: &[OutboxMessage]) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn find_by_id(&self, _id: &TenantId) -> AppResult<Option<Tenant>> {
+        unimplemented!()
+    }
+
+    async fn find_by_code(&self, _code: &TenantCode) -> AppResult<Option<Tenant>> {
+        unimplemented!()
+    }
+
+    async fn find_by_host(&self, _host: &TenantHost) -> AppResult<Option<Tenant>> {
+        unimplemented!()
+    }
+
+    async fn find_all_ids(&self) -> AppResult<Vec<TenantId>> {
+        Ok(self.0.iter().map(|id| TenantId::new(id.to_string()).unwrap()).collect())
+    }
+
+    async fn search(
+        &self,
+        _keyword: Option<&str>,
+        _status: Option<TenantStatus>,
+        _limit: u64,
+        _offset: u64,
+    ) -> AppResult<(Vec<Tenant>, u64)> {
+        unimplemented!()
+    }
+}
+
+/// 记录每次执行所在的租户；`failing` 中的租户执行失败，其余租户各过期 `expired` 条
+#[derive(Default)]
+struct AccessRequests {
+    expired: u64,
+    failing: Vec<&'static str>,
+    calls: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl IAccessRequestService for AccessRequests {
+    async fn submit_request(&self, _cmd: SubmitAccessRequestCommand) -> AppResult<AccessRequest> {
+        unimplemented!()
+    }
+
+    async fn approve_request(&self, _cmd: ApproveAccessRequestCommand) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn reject_request(&self, _cmd: RejectAccessRequestCommand) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn cancel_request(&self, _cmd: CancelAccessRequestCommand) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn expire_requests(&self, cmd: ExpireAccessRequestsCommand) -> AppResult<u64> {
+        assert!(cmd.triggered_by.is_none());
+        let tenant_id = current_tenant_id();
+        self.calls.lock().unwrap().push(tenant_id.clone());
+        if self.failing.contains(&tenant_id.as_str()) {
+            return Err(AppError::Internal("database unavailable".into()));
+        }
+        Ok(self.expired)
+    }
+
+    async fn get_request_by_id(&self, _query: GetAccessRequestByIdQuery) -> AppResult<AccessRequest> {
+        unimplemented!()
+    }
+
+    async fn list_requests(&self, _query: ListAccessRequestsQuery) -> AppResult<PaginatedResult<AccessRequest>> {
+        unimplemented!()
+    }
+
+    async fn get_role_approvers(&self, _query: GetRoleApproversQuery) -> AppResult<Vec<User>> {
+        unimplemented!()
+    }
+
+    async fn set_role_approvers(&self, _cmd: SetRoleApproversCommand) -> AppResult<()> {
+        unimplemented!()
+    }
+}
+
+/// 记录每次执行所在的租户，每个租户完成 `closed` 个活动
+#[derive(Default)]
+struct AccessReviews {
+    closed: u64,
+    calls: Mutex<Vec<String>>,
+}
+
+#[async_trait]
+impl IAccessReviewService for AccessReviews {
+    async fn launch_review(&self, _cmd: LaunchAccessReviewCommand) -> AppResult<AccessReviewAggregate> {
+        unimplemented!()
+    }
+
+    async fn decide_item(&self, _cmd: DecideAccessReviewItemCommand) -> AppResult<()> {
+        unimplemented!()
+    }
+
+    async fn close_due_reviews(&self, cmd: CloseDueAccessReviewsCommand) -> AppResult<u64> {
+        assert!(cmd.triggered_by.is_none());
+        self.calls.lock().unwrap().push(current_tenant_id());
+        Ok(self.closed)
+    }
+
+    async fn get_review_by_id(&self, _query: GetAccessReviewByIdQuery) -> AppResult<AccessReviewAggregate> {
+        unimplemented!()
+    }
+
+    async fn list_reviews(&self, _query: ListAccessReviewsQuery) -> AppResult<PaginatedResult<AccessReviewAggregate>> {
+        unimplemented!()
+    }
+
+    async fn list_items(&self, _query: ListAccessReviewItemsQuery) -> AppResult<Vec<AccessReviewItem>> {
+        unimplemented!()
+    }
+
+    async fn get_report(&self, _query: GetAccessReviewReportQuery) -> AppResult<AccessReviewReport> {
+        unimplemented!()
+    }
+}
+
+/// 与调度器登记的 `access_request_expiry` 任务相同的组装
+fn access_request_expiry(
+    tenants: &[&'static str],
+    service: Arc<AccessRequests>,
+) -> TenantCommandJob<ExpireAccessRequestsCommand> {
+    TenantCommandJob::new(
+        Arc::new(Tenants(tenants.to_vec())),
+        Arc::new(ExpireAccessRequestsHandler::new(service)),
+        ExpireAccessRequestsCommand { triggered_by: None },
+    )
+}
+
+#[tokio::test]
+async fn access_request_expiry_runs_in_every_tenant() {
+    let service = Arc::new(AccessRequests { expired: 2, ..Default::default() });
+    let job = access_request_expiry(&["default", "acme", "globex"], service.clone());
+
+    assert_eq!(job.run().await.unwrap(), 6);
+    assert_eq!(*service.calls.lock().unwrap(), vec!["default", "acme", "globex"]);
+}
+
+#[tokio::test]
+async fn access_request_expiry_continues_after_a_tenant_fails() {
+    let service = Arc::new(AccessRequests { expired: 1, failing: vec!["acme"], ..Default::default() });
+    let job = access_request_expiry(&["default", "acme", "globex"], service.clone());
+
+    let result = job.run().await;
+
+    assert!(matches!(&result, Err(AppError::Internal(msg)) if msg.contains("acme")), "{:?}", result);
+    assert_eq!(*service.calls.lock().unwrap(), vec!["default", "acme", "globex"]);
+}
+
+#[tokio::test]
+async fn access_review_close_runs_in_every_tenant() {
+    let service = Arc::new(AccessReviews { closed: 1, ..Default::default() });
+    let job = TenantCommandJob::new(
+        Arc::new(Tenants(vec!["default", "acme"])),
+        Arc::new(CloseDueAccessReviewsHandler::new(service.clone())),
+        CloseDueAccessReviewsCommand { triggered_by: None },
+    );
+
+    assert_eq!(job.run().await.unwrap(), 2);
+    assert_eq!(*service.calls.lock().unwrap(), vec!["default", "acme"]);
+}
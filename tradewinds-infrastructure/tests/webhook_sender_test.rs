@@ -0,0 +1,68 @@
+//! Webhook 发送测试
+//!
+//! 覆盖解析到内网地址的主机被拒绝，以及允许列表中的主机可正常投递
+
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tradewinds_domain::entities::{Webhook, WebhookDelivery};
+use tradewinds_domain::services::WebhookSender;
+use tradewinds_domain::value_objects::webhook::{WebhookId, WebhookStatus};
+use tradewinds_error::AppError;
+use tradewinds_infrastructure::external::webhook_sender::HttpWebhookSender;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 绕过创建时的校验，模拟已保存的端点或解析结果变化后的域名
+fn webhook(url: String) -> Webhook {
+    Webhook {
+        id: WebhookId::new_v4(),
+        name: "HR".to_string(),
+        url,
+        secret: "0123456789abcdef".to_string(),
+        event_types: vec!["user.created".to_string()],
+        status: WebhookStatus::Active,
+        consecutive_failures: 0,
+        disabled_reason: None,
+        created_at: 0,
+        updated_at: 0,
+    }
+}
+
+fn delivery(webhook: &Webhook) -> WebhookDelivery {
+    WebhookDelivery::create(webhook.id.clone(), "user.created", r#"{"user_id":"u1"}"#, "default").unwrap()
+}
+
+/// 在本地端口上接受一个请求并返回 204
+async fn serve_once() -> u16 {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0; 8192];
+        let _ = stream.read(&mut buffer).await.unwrap();
+        stream.write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n").await.unwrap();
+    });
+    port
+}
+
+#[tokio::test]
+async fn hosts_resolving_to_private_addresses_are_rejected() {
+    let sender = HttpWebhookSender::new(TIMEOUT, Vec::new()).unwrap();
+    for url in ["http://localhost:9/hooks", "http://127.0.0.1:9/hooks", "http://[::1]:9/hooks"] {
+        let webhook = webhook(url.to_string());
+        let result = sender.send(&webhook, &delivery(&webhook)).await;
+        assert!(matches!(result, Err(AppError::Validation(_))), "{} was not rejected: {:?}", url, result);
+    }
+}
+
+#[tokio::test]
+async fn allowed_hosts_may_resolve_to_private_addresses() {
+    let port = serve_once().await;
+    let sender = HttpWebhookSender::new(TIMEOUT, vec!["localhost".to_string()]).unwrap();
+    let webhook = webhook(format!("http://localhost:{}/hooks", port));
+
+    let status = sender.send(&webhook, &delivery(&webhook)).await.unwrap();
+
+    assert_eq!(status, 204);
+}